@@ -0,0 +1,506 @@
+//! Helpers for mapping the JSON elements of a resource between FHIR versions.
+
+use serde_json::{Map, Value};
+
+use super::{ConversionReport, DroppedElement, Release};
+
+/// JSON object of a FHIR resource or element.
+pub(super) type Object = Map<String, Value>;
+
+/// Type of an element's value when stored in a cross-version extension.
+#[derive(Debug, Clone, Copy)]
+pub(super) enum ValueType {
+	/// Element of a FHIR data type, stored in `value[x]`. Holds the type name
+	/// as used in the `value[x]` key, e.g. `CodeableConcept` or `Boolean`.
+	Typed(&'static str),
+	/// Backbone element, stored in nested extensions per child element.
+	Complex(&'static [Element]),
+}
+
+/// Element that can be moved into and restored from a cross-version
+/// extension.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Element {
+	/// Element path, e.g. `Patient.animal`. Children of complex elements only
+	/// use their name, as it is the URL of their nested extension.
+	pub path: &'static str,
+	/// JSON key of the element in its parent object.
+	pub key: &'static str,
+	/// Type of the element's value.
+	pub value_type: ValueType,
+	/// Whether the element can repeat.
+	pub repeats: bool,
+}
+
+impl Element {
+	/// Element of a FHIR data type, stored in `value[x]` of the extension.
+	pub const fn typed(
+		path: &'static str,
+		key: &'static str,
+		ty: &'static str,
+		repeats: bool,
+	) -> Self {
+		Self { path, key, value_type: ValueType::Typed(ty), repeats }
+	}
+
+	/// Backbone element, stored in nested extensions per child element.
+	pub const fn complex(
+		path: &'static str,
+		key: &'static str,
+		children: &'static [Element],
+		repeats: bool,
+	) -> Self {
+		Self { path, key, value_type: ValueType::Complex(children), repeats }
+	}
+}
+
+/// Context of a single conversion step between two adjacent FHIR versions.
+#[derive(Debug)]
+pub(super) struct Context<'a> {
+	/// The FHIR version the data is converted from in this step.
+	source: Release,
+	/// Report to record the conversion results in.
+	report: &'a mut ConversionReport,
+}
+
+impl<'a> Context<'a> {
+	/// Create a new context for a conversion step from the given version.
+	pub fn new(source: Release, report: &'a mut ConversionReport) -> Self {
+		Self { source, report }
+	}
+
+	/// Record that an element could not be converted.
+	pub fn drop_element(&mut self, path: impl Into<String>, value: Value) {
+		self.report.dropped.push(DroppedElement { path: path.into(), value });
+	}
+
+	/// Move the element from the object into a cross-version extension of the
+	/// source version.
+	pub fn move_to_extension(&mut self, object: &mut Object, element: &Element) {
+		let Some(value) = object.remove(element.key) else {
+			return;
+		};
+		let primitive = object.remove(&format!("_{}", element.key));
+		self.add_extension(object, element, value, primitive);
+	}
+
+	/// Add a cross-version extension of the source version holding the given
+	/// value of the element to the object.
+	pub fn add_extension(
+		&mut self,
+		object: &mut Object,
+		element: &Element,
+		value: Value,
+		primitive: Option<Value>,
+	) {
+		let url = extension_url(self.source, element.path);
+		let extensions = self.element_extensions(&url, element, value, primitive);
+		if extensions.is_empty() {
+			return;
+		}
+
+		push_values(object, "extension", extensions);
+		if !self.report.extensions.iter().any(|path| path == element.path) {
+			self.report.extensions.push(element.path.to_owned());
+		}
+	}
+
+	/// Restore the element from its cross-version extension of the given
+	/// version, if present.
+	pub fn restore_extension(&mut self, object: &mut Object, element: &Element, release: Release) {
+		let url = extension_url(release, element.path);
+		let (matching, rest): (Vec<_>, Vec<_>) = take_values(object, "extension")
+			.into_iter()
+			.partition(|extension| is_extension_of(extension, &url, element));
+		push_values(object, "extension", rest);
+
+		let (values, primitives) =
+			matching.into_iter().map(|extension| read_extension(extension, element)).unzip();
+		self.insert_values(object, element, values, primitives);
+	}
+
+	/// Turn a list of values into its first value, reporting the others as
+	/// dropped.
+	pub fn unwrap_first(&mut self, object: &mut Object, key: &str, path: &str) {
+		for key in [key.to_owned(), format!("_{key}")] {
+			let Some(Value::Array(values)) = object.remove(&key) else {
+				continue;
+			};
+			let mut values = values.into_iter();
+			if let Some(first) = values.next().filter(|first| !first.is_null()) {
+				object.insert(key, first);
+			}
+			for value in values {
+				self.drop_element(path, value);
+			}
+		}
+	}
+
+	/// Map the code value of the element. The original code is kept in a
+	/// cross-version extension if it changed or has no counterpart.
+	pub fn map_code(&mut self, object: &mut Object, element: &Element, mapping: &[(&str, &str)]) {
+		let Some(code) = object.get(element.key).and_then(Value::as_str).map(ToOwned::to_owned)
+		else {
+			return;
+		};
+
+		match mapping.iter().find(|(from, _)| *from == code) {
+			Some((from, to)) if from == to => {}
+			Some((_, to)) => {
+				object.insert(element.key.to_owned(), Value::String((*to).to_owned()));
+				self.add_extension(object, element, Value::String(code), None);
+			}
+			None => self.move_to_extension(object, element),
+		}
+	}
+
+	/// Map the code value of the element like [`Self::map_code`], unless the
+	/// original code of the target version can be restored from its
+	/// cross-version extension.
+	pub fn map_code_or_restore(
+		&mut self,
+		object: &mut Object,
+		element: &Element,
+		mapping: &[(&str, &str)],
+		target: Release,
+	) {
+		let current = object.remove(element.key);
+		self.restore_extension(object, element, target);
+		if object.contains_key(element.key) {
+			return;
+		}
+
+		if let Some(current) = current {
+			object.insert(element.key.to_owned(), current);
+		}
+		self.map_code(object, element, mapping);
+	}
+
+	/// Replace a `CodeableConcept` by its code in the given system, or its
+	/// first code if there is none in the system. Reports the concept as
+	/// dropped if it held more information than the code.
+	pub fn concept_to_code(&mut self, object: &mut Object, key: &str, system: &str, path: &str) {
+		let Some(concept) = object.remove(key) else {
+			return;
+		};
+
+		let codings =
+			concept.get("coding").and_then(Value::as_array).map_or(&[][..], Vec::as_slice);
+		let coding = codings
+			.iter()
+			.find(|coding| coding.get("system").and_then(Value::as_str) == Some(system))
+			.or_else(|| codings.iter().find(|coding| coding.get("code").is_some()));
+		let code = coding.and_then(|coding| coding.get("code")).cloned();
+		let lossless = concept.as_object().is_some_and(|concept| concept.len() == 1)
+			&& codings.len() == 1
+			&& coding
+				.and_then(Value::as_object)
+				.is_some_and(|coding| coding.keys().all(|key| key == "system" || key == "code"));
+
+		if !lossless {
+			self.drop_element(path, concept);
+		}
+		if let Some(code) = code {
+			object.insert(key.to_owned(), code);
+		}
+	}
+
+	/// Replace a list of `CodeableConcept`s by their first coding. Reports the
+	/// rest as dropped.
+	pub fn concepts_to_coding(&mut self, object: &mut Object, key: &str, path: &str) {
+		let mut codings = Vec::new();
+		for mut concept in take_values(object, key) {
+			if let Some(Value::Array(concept_codings)) = concept.get_mut("coding").map(Value::take)
+			{
+				codings.extend(concept_codings);
+			}
+			if let Some(text) = concept.get_mut("text").map(Value::take) {
+				self.drop_element(path, text);
+			}
+		}
+
+		let mut codings = codings.into_iter();
+		if let Some(coding) = codings.next() {
+			object.insert(key.to_owned(), coding);
+		}
+		for coding in codings {
+			self.drop_element(path, coding);
+		}
+	}
+
+	/// Convert an `Attachment.size` from an integer64, which is represented as
+	/// string in JSON, to an unsignedInt.
+	pub fn size_to_unsigned_int(&mut self, attachment: &mut Object, path: &str) {
+		let Some(Value::String(size)) = attachment.remove("size") else {
+			return;
+		};
+		match size.parse::<u32>() {
+			Ok(size) => {
+				attachment.insert("size".to_owned(), Value::from(size));
+			}
+			Err(_) => self.drop_element(path, Value::String(size)),
+		}
+	}
+
+	/// Create the extensions for all values of an element.
+	fn element_extensions(
+		&mut self,
+		url: &str,
+		element: &Element,
+		value: Value,
+		primitive: Option<Value>,
+	) -> Vec<Value> {
+		let Value::Array(values) = value else {
+			return self.value_extension(url, element, value, primitive).into_iter().collect();
+		};
+
+		let mut primitives = match primitive {
+			Some(Value::Array(primitives)) => primitives.into_iter(),
+			_ => Vec::new().into_iter(),
+		};
+		values
+			.into_iter()
+			.filter_map(|value| {
+				let primitive = primitives.next().filter(|primitive| !primitive.is_null());
+				self.value_extension(url, element, value, primitive)
+			})
+			.collect()
+	}
+
+	/// Create the extension for a single value of an element.
+	fn value_extension(
+		&mut self,
+		url: &str,
+		element: &Element,
+		value: Value,
+		primitive: Option<Value>,
+	) -> Option<Value> {
+		if value.is_null() && primitive.is_none() {
+			return None;
+		}
+
+		let mut extension = Object::new();
+		extension.insert("url".to_owned(), Value::String(url.to_owned()));
+		match element.value_type {
+			ValueType::Typed(ty) => {
+				if !value.is_null() {
+					extension.insert(format!("value{ty}"), value);
+				}
+				if let Some(primitive) = primitive {
+					extension.insert(format!("_value{ty}"), primitive);
+				}
+			}
+			ValueType::Complex(children) => {
+				let Value::Object(mut object) = value else {
+					self.drop_element(element.path, value);
+					return None;
+				};
+
+				let mut nested = Vec::new();
+				for child in children {
+					if let Some(value) = object.remove(child.key) {
+						let primitive = object.remove(&format!("_{}", child.key));
+						nested.extend(self.element_extensions(child.path, child, value, primitive));
+					}
+				}
+				nested.extend(take_values(&mut object, "extension"));
+				for (key, value) in object {
+					self.drop_element(format!("{}.{key}", element.path), value);
+				}
+				extension.insert("extension".to_owned(), Value::Array(nested));
+			}
+		}
+		Some(Value::Object(extension))
+	}
+
+	/// Insert the values read from extensions into the object, according to
+	/// the element's cardinality.
+	fn insert_values(
+		&mut self,
+		object: &mut Object,
+		element: &Element,
+		values: Vec<Value>,
+		primitives: Vec<Option<Value>>,
+	) {
+		if values.is_empty() {
+			return;
+		}
+
+		let primitive_key = format!("_{}", element.key);
+		if element.repeats {
+			// Append to values that might already be present.
+			let mut list = take_values(object, element.key);
+			let offset = list.len();
+			list.extend(values);
+			object.insert(element.key.to_owned(), Value::Array(list));
+
+			if primitives.iter().any(Option::is_some) {
+				let mut list = take_values(object, &primitive_key);
+				list.resize(offset, Value::Null);
+				list.extend(primitives.into_iter().map(Option::unwrap_or_default));
+				object.insert(primitive_key, Value::Array(list));
+			}
+		} else {
+			let mut values = values.into_iter();
+			if let Some(value) = values.next().filter(|value| !value.is_null()) {
+				object.insert(element.key.to_owned(), value);
+			}
+			if let Some(primitive) = primitives.into_iter().next().flatten() {
+				object.insert(primitive_key, primitive);
+			}
+			for value in values {
+				self.drop_element(element.path, value);
+			}
+		}
+	}
+}
+
+/// Get the URL of the cross-version extension for an element of the given
+/// FHIR version.
+fn extension_url(release: Release, path: &str) -> String {
+	format!(
+		"http://hl7.org/fhir/{}/StructureDefinition/extension-{path}",
+		release.extension_version()
+	)
+}
+
+/// Whether the extension holds a value of the element. Choice elements share
+/// the same URL for all types, so the value's type is checked as well.
+fn is_extension_of(extension: &Value, url: &str, element: &Element) -> bool {
+	if extension.get("url").and_then(Value::as_str) != Some(url) {
+		return false;
+	}
+	match element.value_type {
+		ValueType::Typed(ty) => {
+			extension.get(format!("value{ty}")).is_some()
+				|| extension.get(format!("_value{ty}")).is_some()
+		}
+		ValueType::Complex(_) => true,
+	}
+}
+
+/// Read the value of the element from the extension.
+fn read_extension(extension: Value, element: &Element) -> (Value, Option<Value>) {
+	let Value::Object(mut extension) = extension else {
+		return (Value::Null, None);
+	};
+
+	match element.value_type {
+		ValueType::Typed(ty) => {
+			let value = extension.remove(&format!("value{ty}")).unwrap_or_default();
+			let primitive = extension.remove(&format!("_value{ty}"));
+			(value, primitive)
+		}
+		ValueType::Complex(children) => {
+			let mut nested = take_values(&mut extension, "extension");
+			let mut object = Object::new();
+			for child in children {
+				let (matching, rest): (Vec<_>, Vec<_>) = nested
+					.into_iter()
+					.partition(|nested| is_extension_of(nested, child.path, child));
+				nested = rest;
+
+				let (values, primitives): (Vec<_>, Vec<_>) =
+					matching.into_iter().map(|nested| read_extension(nested, child)).unzip();
+				if child.repeats && !values.is_empty() {
+					object.insert(child.key.to_owned(), Value::Array(values));
+				} else if let Some(value) = values.into_iter().next() {
+					object.insert(child.key.to_owned(), value);
+				}
+				if let Some(primitive) = primitives.into_iter().flatten().next() {
+					object.insert(format!("_{}", child.key), primitive);
+				}
+			}
+			push_values(&mut object, "extension", nested);
+			(Value::Object(object), None)
+		}
+	}
+}
+
+/// Rename the element in the object, including its primitive extensions.
+pub(super) fn rename(object: &mut Object, from: &str, to: &str) {
+	if let Some(value) = object.remove(from) {
+		object.insert(to.to_owned(), value);
+	}
+	if let Some(primitive) = object.remove(&format!("_{from}")) {
+		object.insert(format!("_{to}"), primitive);
+	}
+}
+
+/// Turn a single value into a list containing this value.
+pub(super) fn wrap_list(object: &mut Object, key: &str) {
+	for key in [key.to_owned(), format!("_{key}")] {
+		if let Some(value) = object.get_mut(&key) {
+			if !value.is_array() {
+				*value = Value::Array(vec![value.take()]);
+			}
+		}
+	}
+}
+
+/// Replace a code by a `CodeableConcept` holding the code in the given
+/// system.
+pub(super) fn code_to_concept(object: &mut Object, key: &str, system: &str) {
+	let Some(code) = object.remove(key) else {
+		return;
+	};
+
+	let mut coding = Object::new();
+	coding.insert("system".to_owned(), Value::String(system.to_owned()));
+	coding.insert("code".to_owned(), code);
+	if let Some(primitive) = object.remove(&format!("_{key}")) {
+		coding.insert("_code".to_owned(), primitive);
+	}
+	object.insert(key.to_owned(), object_with("coding", Value::Array(vec![Value::Object(coding)])));
+}
+
+/// Convert an `Attachment.size` from an unsignedInt to an integer64, which is
+/// represented as string in JSON.
+pub(super) fn size_to_integer64(attachment: &mut Object) {
+	if let Some(Value::Number(size)) = attachment.get("size") {
+		let size = size.to_string();
+		attachment.insert("size".to_owned(), Value::String(size));
+	}
+}
+
+/// Get mutable borrows to all objects in the element, which may be a single
+/// object or a list of objects.
+pub(super) fn objects_mut<'a>(
+	object: &'a mut Object,
+	key: &str,
+) -> impl Iterator<Item = &'a mut Object> {
+	let values: Vec<&mut Value> = match object.get_mut(key) {
+		Some(Value::Array(values)) => values.iter_mut().collect(),
+		Some(value) => vec![value],
+		None => Vec::new(),
+	};
+	values.into_iter().filter_map(Value::as_object_mut)
+}
+
+/// Take the element's values out of the object as list, no matter whether it
+/// was a single value or a list.
+pub(super) fn take_values(object: &mut Object, key: &str) -> Vec<Value> {
+	match object.remove(key) {
+		Some(Value::Array(values)) => values,
+		Some(value) => vec![value],
+		None => Vec::new(),
+	}
+}
+
+/// Append values to the list element of the object.
+pub(super) fn push_values(object: &mut Object, key: &str, values: Vec<Value>) {
+	if values.is_empty() {
+		return;
+	}
+
+	let mut list = take_values(object, key);
+	list.extend(values);
+	object.insert(key.to_owned(), Value::Array(list));
+}
+
+/// Create a JSON object with a single entry.
+pub(super) fn object_with(key: &str, value: Value) -> Value {
+	let mut object = Object::new();
+	object.insert(key.to_owned(), value);
+	Value::Object(object)
+}
@@ -0,0 +1,321 @@
+//! Conversion of resources between FHIR versions.
+//!
+//! Resources are converted on their JSON representation, step by step between
+//! adjacent versions (STU3 <-> R4B <-> R5). Elements that did not change
+//! between the versions have the same JSON representation and are taken over
+//! as they are. Elements that changed are mapped following the official
+//! cross-version maps. Elements without counterpart in the target version are
+//! kept in the standard cross-version extensions
+//! (`http://hl7.org/fhir/{version}/StructureDefinition/extension-{path}`) and
+//! are restored when converting back.
+//!
+//! Everything that could be neither mapped nor kept in an extension is
+//! listed in the [`ConversionReport`].
+//!
+//! The element mappings cover the `Patient`, `Practitioner`, `Organization`,
+//! `Encounter`, `Observation` and `Condition` resources, which have typed
+//! conversions. Other resource types can only be converted via the `Resource`
+//! enums and are taken over as they are. Their changed elements are not
+//! mapped, so the resource types are listed in
+//! [`ConversionReport::unmapped`].
+//!
+//! ```ignore
+//! use fhir_model::convert::ConvertFrom;
+//!
+//! let converted = fhir_model::r5::resources::Patient::convert_from(stu3_patient)?;
+//! assert!(converted.report.is_lossless());
+//! let patient = converted.resource;
+//! ```
+
+mod mapping;
+mod r4b_r5;
+mod stu3_r4b;
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use self::mapping::{objects_mut, Context, Object};
+pub use crate::error::ConversionError;
+
+/// FHIR versions that can be converted between, in order of release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Release {
+	/// FHIR STU3.
+	Stu3,
+	/// FHIR R4B.
+	R4B,
+	/// FHIR R5.
+	R5,
+}
+
+impl Release {
+	/// Version as used in the URLs of the cross-version extensions.
+	const fn extension_version(self) -> &'static str {
+		match self {
+			Self::Stu3 => "3.0",
+			Self::R4B => "4.3",
+			Self::R5 => "5.0",
+		}
+	}
+
+	/// The adjacent version in the direction of the target version.
+	fn step_towards(self, target: Self) -> Self {
+		match (self, target) {
+			(Self::Stu3, Self::R4B | Self::R5) | (Self::R5, Self::Stu3) => Self::R4B,
+			(Self::R4B, Self::R5) => Self::R5,
+			(Self::R4B, Self::Stu3) => Self::Stu3,
+			_ => target,
+		}
+	}
+}
+
+/// Resource types with element mappings between the versions, see
+/// `impl_convert!`.
+const MAPPED_RESOURCE_TYPES: &[&str] =
+	&["Patient", "Practitioner", "Organization", "Encounter", "Observation", "Condition"];
+
+/// Report of a conversion between FHIR versions.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConversionReport {
+	/// Paths of the elements that were stored in cross-version extensions,
+	/// e.g. `Patient.animal`.
+	pub extensions: Vec<String>,
+	/// Data that could not be represented in the target version and was
+	/// dropped.
+	pub dropped: Vec<DroppedElement>,
+	/// Resource types that were converted without element mappings, e.g.
+	/// `MedicationRequest`. Their elements that changed between the versions
+	/// might be dropped or misinterpreted.
+	pub unmapped: Vec<String>,
+}
+
+impl ConversionReport {
+	/// Whether no data was dropped during the conversion and all resource
+	/// types were mapped.
+	#[must_use]
+	pub fn is_lossless(&self) -> bool {
+		self.dropped.is_empty() && self.unmapped.is_empty()
+	}
+}
+
+/// Data that was dropped during a conversion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DroppedElement {
+	/// Element path of the dropped data, e.g. `Encounter.reason.use`.
+	pub path: String,
+	/// Dropped JSON value.
+	pub value: Value,
+}
+
+/// Converted resource together with the conversion report.
+#[derive(Debug, Clone)]
+pub struct Converted<T> {
+	/// The converted resource.
+	pub resource: T,
+	/// Report of the conversion.
+	pub report: ConversionReport,
+}
+
+/// Conversion of a resource from another FHIR version. The [`TryFrom`]
+/// implementations between the versions discard the report.
+pub trait ConvertFrom<T>: Sized {
+	/// Convert the resource, reporting lossy elements.
+	fn convert_from(source: T) -> Result<Converted<Self>, ConversionError>;
+}
+
+/// Conversion of a resource into another FHIR version. Implemented for all
+/// [`ConvertFrom`] implementations.
+pub trait ConvertInto<T> {
+	/// Convert the resource, reporting lossy elements.
+	fn convert_into(self) -> Result<Converted<T>, ConversionError>;
+}
+
+impl<S, T: ConvertFrom<S>> ConvertInto<T> for S {
+	fn convert_into(self) -> Result<Converted<T>, ConversionError> {
+		T::convert_from(self)
+	}
+}
+
+/// Convert a resource between FHIR versions.
+fn convert<S, T>(source: &S, from: Release, to: Release) -> Result<Converted<T>, ConversionError>
+where
+	S: Serialize,
+	T: Serialize + DeserializeOwned,
+{
+	let mut report = ConversionReport::default();
+	let mut value = serde_json::to_value(source)?;
+
+	let mut current = from;
+	while current != to {
+		let next = current.step_towards(to);
+		if let Value::Object(resource) = &mut value {
+			convert_resource(resource, current, next, &mut report);
+		}
+		current = next;
+	}
+
+	let resource: T = serde_json::from_value(value.clone())?;
+	let converted = serde_json::to_value(&resource)?;
+	let path = value.get("resourceType").and_then(Value::as_str).unwrap_or_default().to_owned();
+	find_dropped(value, &converted, &path, &mut report.dropped);
+
+	Ok(Converted { resource, report })
+}
+
+/// Convert the JSON object of a resource and its contained resources to the
+/// adjacent FHIR version.
+fn convert_resource(
+	resource: &mut Object,
+	from: Release,
+	to: Release,
+	report: &mut ConversionReport,
+) {
+	let Some(resource_type) = resource.get("resourceType").and_then(Value::as_str) else {
+		return;
+	};
+	let resource_type = resource_type.to_owned();
+
+	for contained in objects_mut(resource, "contained") {
+		convert_resource(contained, from, to, report);
+	}
+	if resource_type == "Bundle" {
+		for entry in objects_mut(resource, "entry") {
+			if let Some(Value::Object(entry_resource)) = entry.get_mut("resource") {
+				convert_resource(entry_resource, from, to, report);
+			}
+		}
+	}
+
+	if resource_type != "Bundle"
+		&& !MAPPED_RESOURCE_TYPES.contains(&resource_type.as_str())
+		&& !report.unmapped.contains(&resource_type)
+	{
+		report.unmapped.push(resource_type.clone());
+	}
+
+	let mut context = Context::new(from, report);
+	match (from, to) {
+		(Release::Stu3, Release::R4B) => stu3_r4b::upgrade(&resource_type, resource, &mut context),
+		(Release::R4B, Release::Stu3) => {
+			stu3_r4b::downgrade(&resource_type, resource, &mut context);
+		}
+		(Release::R4B, Release::R5) => r4b_r5::upgrade(&resource_type, resource, &mut context),
+		(Release::R5, Release::R4B) => r4b_r5::downgrade(&resource_type, resource, &mut context),
+		_ => {}
+	}
+}
+
+/// Find the data of the converted JSON that did not survive the
+/// deserialization into the target version's models, i.e. unknown elements
+/// and values of the wrong type.
+fn find_dropped(original: Value, converted: &Value, path: &str, dropped: &mut Vec<DroppedElement>) {
+	match (original, converted) {
+		(Value::Object(original), Value::Object(converted)) => {
+			for (key, value) in original {
+				let child_path = format!("{path}.{}", key.trim_start_matches('_'));
+				match converted.get(&key) {
+					Some(converted) => find_dropped(value, converted, &child_path, dropped),
+					None if is_empty(&value) => {}
+					None => dropped.push(DroppedElement { path: child_path, value }),
+				}
+			}
+		}
+		(Value::Array(original), Value::Array(converted)) => {
+			for (index, value) in original.into_iter().enumerate() {
+				match converted.get(index) {
+					Some(converted) => find_dropped(value, converted, path, dropped),
+					None if is_empty(&value) => {}
+					None => dropped.push(DroppedElement { path: path.to_owned(), value }),
+				}
+			}
+		}
+		(Value::Number(original), Value::Number(converted)) => {
+			if original.as_f64().map(f64::to_bits) != converted.as_f64().map(f64::to_bits) {
+				dropped.push(DroppedElement { path: path.to_owned(), value: original.into() });
+			}
+		}
+		(original, converted) => {
+			if std::mem::discriminant(&original) != std::mem::discriminant(converted) {
+				dropped.push(DroppedElement { path: path.to_owned(), value: original });
+			}
+		}
+	}
+}
+
+/// Whether the JSON value holds no data.
+fn is_empty(value: &Value) -> bool {
+	match value {
+		Value::Null => true,
+		Value::Array(values) => values.iter().all(is_empty),
+		Value::Object(object) => object.values().all(is_empty),
+		_ => false,
+	}
+}
+
+/// Implement the conversions of the supported resources and the `Resource`
+/// enum between two FHIR versions. The supported resources are the
+/// `MAPPED_RESOURCE_TYPES`.
+macro_rules! impl_convert {
+	($from:ident: $from_release:ident => $to:ident: $to_release:ident) => {
+		impl_convert!(
+			$from: $from_release => $to: $to_release,
+			[Patient, Practitioner, Organization, Encounter, Observation, Condition]
+		);
+	};
+	($from:ident: $from_release:ident => $to:ident: $to_release:ident, [$($resource:ident),*]) => {
+		$(
+			impl ConvertFrom<crate::$from::resources::$resource>
+				for crate::$to::resources::$resource
+			{
+				fn convert_from(
+					source: crate::$from::resources::$resource,
+				) -> Result<Converted<Self>, ConversionError> {
+					convert(&source, Release::$from_release, Release::$to_release)
+				}
+			}
+
+			impl TryFrom<crate::$from::resources::$resource> for crate::$to::resources::$resource {
+				type Error = ConversionError;
+
+				fn try_from(
+					source: crate::$from::resources::$resource,
+				) -> Result<Self, Self::Error> {
+					Ok(Self::convert_from(source)?.resource)
+				}
+			}
+		)*
+
+		impl ConvertFrom<crate::$from::resources::Resource> for crate::$to::resources::Resource {
+			fn convert_from(
+				source: crate::$from::resources::Resource,
+			) -> Result<Converted<Self>, ConversionError> {
+				let resource_type = source.resource_type().as_str();
+				if resource_type.parse::<crate::$to::resources::ResourceType>().is_err() {
+					return Err(ConversionError::UnsupportedResourceType(resource_type.to_owned()));
+				}
+				convert(&source, Release::$from_release, Release::$to_release)
+			}
+		}
+
+		impl TryFrom<crate::$from::resources::Resource> for crate::$to::resources::Resource {
+			type Error = ConversionError;
+
+			fn try_from(source: crate::$from::resources::Resource) -> Result<Self, Self::Error> {
+				Ok(Self::convert_from(source)?.resource)
+			}
+		}
+	};
+}
+
+#[cfg(all(feature = "stu3", feature = "r4b"))]
+impl_convert!(stu3: Stu3 => r4b: R4B);
+#[cfg(all(feature = "stu3", feature = "r4b"))]
+impl_convert!(r4b: R4B => stu3: Stu3);
+#[cfg(all(feature = "stu3", feature = "r5"))]
+impl_convert!(stu3: Stu3 => r5: R5);
+#[cfg(all(feature = "stu3", feature = "r5"))]
+impl_convert!(r5: R5 => stu3: Stu3);
+#[cfg(all(feature = "r4b", feature = "r5"))]
+impl_convert!(r4b: R4B => r5: R5);
+#[cfg(all(feature = "r4b", feature = "r5"))]
+impl_convert!(r5: R5 => r4b: R4B);
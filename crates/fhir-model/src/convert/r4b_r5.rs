@@ -0,0 +1,510 @@
+//! Mappings between R4B and R5, following the official R4 <-> R5 maps.
+
+use serde_json::Value;
+
+use super::{
+	mapping::{
+		object_with, objects_mut, push_values, rename, size_to_integer64, take_values, wrap_list,
+		Context, Element, Object,
+	},
+	stu3_r4b::OBSERVATION_VALUE_ATTACHMENT,
+	Release,
+};
+
+/// Code system of `Condition.participant.function` in R5.
+const PARTICIPANT_TYPE: &str = "http://terminology.hl7.org/CodeSystem/provenance-participant-type";
+
+/// R5 `Practitioner.communication.preferred`, kept in the R4B language
+/// concept.
+const PRACTITIONER_COMMUNICATION_PREFERRED: Element =
+	Element::typed("Practitioner.communication.preferred", "preferred", "Boolean", false);
+/// R5 `Practitioner.deceasedBoolean`.
+const PRACTITIONER_DECEASED_BOOLEAN: Element =
+	Element::typed("Practitioner.deceased", "deceasedBoolean", "Boolean", false);
+/// R5 `Practitioner.deceasedDateTime`.
+const PRACTITIONER_DECEASED_DATE_TIME: Element =
+	Element::typed("Practitioner.deceased", "deceasedDateTime", "DateTime", false);
+
+/// R5 `Organization.description`.
+const ORGANIZATION_DESCRIPTION: Element =
+	Element::typed("Organization.description", "description", "Markdown", false);
+
+/// `Encounter.status`, holding codes that changed.
+const ENCOUNTER_STATUS: Element = Element::typed("Encounter.status", "status", "Code", false);
+/// R4B `Encounter.statusHistory`.
+const ENCOUNTER_STATUS_HISTORY: Element = Element::complex(
+	"Encounter.statusHistory",
+	"statusHistory",
+	&[
+		Element::typed("status", "status", "Code", false),
+		Element::typed("period", "period", "Period", false),
+	],
+	true,
+);
+/// R4B `Encounter.classHistory`.
+const ENCOUNTER_CLASS_HISTORY: Element = Element::complex(
+	"Encounter.classHistory",
+	"classHistory",
+	&[
+		Element::typed("class", "class", "Coding", false),
+		Element::typed("period", "period", "Period", false),
+	],
+	true,
+);
+/// R4B `Encounter.diagnosis.rank`.
+const ENCOUNTER_DIAGNOSIS_RANK: Element =
+	Element::typed("Encounter.diagnosis.rank", "rank", "PositiveInt", false);
+/// R5 elements of the Encounter without counterpart in R4B.
+const ENCOUNTER_R5_ELEMENTS: &[Element] = &[
+	Element::typed("Encounter.subjectStatus", "subjectStatus", "CodeableConcept", false),
+	Element::typed("Encounter.careTeam", "careTeam", "Reference", true),
+	Element::typed("Encounter.plannedStartDate", "plannedStartDate", "DateTime", false),
+	Element::typed("Encounter.plannedEndDate", "plannedEndDate", "DateTime", false),
+];
+/// Elements of the R4B `Encounter.hospitalization`, which are on the
+/// Encounter itself in R5.
+const ENCOUNTER_MOVED_ADMISSION_ELEMENTS: [&str; 3] =
+	["dietPreference", "specialCourtesy", "specialArrangement"];
+
+/// R5 `Observation.valueReference`.
+const OBSERVATION_VALUE_REFERENCE: Element =
+	Element::typed("Observation.value", "valueReference", "Reference", false);
+
+/// Encounter status mapping from R4B to R5.
+const ENCOUNTER_STATUS_R4B_TO_R5: &[(&str, &str)] = &[
+	("planned", "planned"),
+	("arrived", "in-progress"),
+	("triaged", "in-progress"),
+	("in-progress", "in-progress"),
+	("onleave", "on-hold"),
+	("finished", "completed"),
+	("cancelled", "cancelled"),
+	("entered-in-error", "entered-in-error"),
+	("unknown", "unknown"),
+];
+/// Encounter status mapping from R5 to R4B.
+const ENCOUNTER_STATUS_R5_TO_R4B: &[(&str, &str)] = &[
+	("planned", "planned"),
+	("in-progress", "in-progress"),
+	("on-hold", "onleave"),
+	("discharged", "finished"),
+	("completed", "finished"),
+	("cancelled", "cancelled"),
+	("discontinued", "cancelled"),
+	("entered-in-error", "entered-in-error"),
+	("unknown", "unknown"),
+];
+
+/// Convert a resource from R4B to R5.
+pub(super) fn upgrade(resource_type: &str, resource: &mut Object, context: &mut Context<'_>) {
+	match resource_type {
+		"Patient" => {
+			for photo in objects_mut(resource, "photo") {
+				size_to_integer64(photo);
+			}
+		}
+		"Practitioner" => upgrade_practitioner(resource, context),
+		"Organization" => upgrade_organization(resource, context),
+		"Encounter" => upgrade_encounter(resource, context),
+		"Observation" => upgrade_observation(resource, context),
+		"Condition" => upgrade_condition(resource),
+		_ => {}
+	}
+}
+
+/// Convert a resource from R5 to R4B.
+pub(super) fn downgrade(resource_type: &str, resource: &mut Object, context: &mut Context<'_>) {
+	match resource_type {
+		"Patient" => {
+			for photo in objects_mut(resource, "photo") {
+				context.size_to_unsigned_int(photo, "Patient.photo.size");
+			}
+		}
+		"Practitioner" => downgrade_practitioner(resource, context),
+		"Organization" => downgrade_organization(resource, context),
+		"Encounter" => downgrade_encounter(resource, context),
+		"Observation" => downgrade_observation(resource, context),
+		"Condition" => downgrade_condition(resource, context),
+		_ => {}
+	}
+}
+
+/// Convert a Practitioner from R4B to R5.
+fn upgrade_practitioner(practitioner: &mut Object, context: &mut Context<'_>) {
+	for photo in objects_mut(practitioner, "photo") {
+		size_to_integer64(photo);
+	}
+
+	let communication = take_values(practitioner, "communication")
+		.into_iter()
+		.map(|mut language| {
+			let mut communication = Object::new();
+			if let Some(language) = language.as_object_mut() {
+				context.restore_extension(
+					language,
+					&PRACTITIONER_COMMUNICATION_PREFERRED,
+					Release::R5,
+				);
+				if let Some(preferred) = language.remove("preferred") {
+					communication.insert("preferred".to_owned(), preferred);
+				}
+			}
+			communication.insert("language".to_owned(), language);
+			Value::Object(communication)
+		})
+		.collect();
+	push_values(practitioner, "communication", communication);
+
+	context.restore_extension(practitioner, &PRACTITIONER_DECEASED_BOOLEAN, Release::R5);
+	context.restore_extension(practitioner, &PRACTITIONER_DECEASED_DATE_TIME, Release::R5);
+}
+
+/// Convert a Practitioner from R5 to R4B.
+fn downgrade_practitioner(practitioner: &mut Object, context: &mut Context<'_>) {
+	for photo in objects_mut(practitioner, "photo") {
+		context.size_to_unsigned_int(photo, "Practitioner.photo.size");
+	}
+
+	let mut languages = Vec::new();
+	for communication in take_values(practitioner, "communication") {
+		let Value::Object(mut communication) = communication else {
+			continue;
+		};
+		let Some(Value::Object(mut language)) = communication.remove("language") else {
+			context.drop_element("Practitioner.communication", Value::Object(communication));
+			continue;
+		};
+
+		if let Some(preferred) = communication.remove("preferred") {
+			context.add_extension(
+				&mut language,
+				&PRACTITIONER_COMMUNICATION_PREFERRED,
+				preferred,
+				communication.remove("_preferred"),
+			);
+		}
+		for (key, value) in communication {
+			context.drop_element(format!("Practitioner.communication.{key}"), value);
+		}
+		languages.push(Value::Object(language));
+	}
+	push_values(practitioner, "communication", languages);
+
+	context.move_to_extension(practitioner, &PRACTITIONER_DECEASED_BOOLEAN);
+	context.move_to_extension(practitioner, &PRACTITIONER_DECEASED_DATE_TIME);
+}
+
+/// Convert an Organization from R4B to R5. Telecoms and addresses are
+/// contacts in R5.
+fn upgrade_organization(organization: &mut Object, context: &mut Context<'_>) {
+	let mut contacts = take_values(organization, "contact");
+	for contact in contacts.iter_mut().filter_map(Value::as_object_mut) {
+		wrap_list(contact, "name");
+	}
+
+	let telecom = take_values(organization, "telecom");
+	if !telecom.is_empty() {
+		contacts.push(object_with("telecom", Value::Array(telecom)));
+	}
+	for address in take_values(organization, "address") {
+		contacts.push(object_with("address", address));
+	}
+	push_values(organization, "contact", contacts);
+
+	context.restore_extension(organization, &ORGANIZATION_DESCRIPTION, Release::R5);
+}
+
+/// Convert an Organization from R5 to R4B. Contacts without purpose and name
+/// become the organization's telecoms and addresses.
+fn downgrade_organization(organization: &mut Object, context: &mut Context<'_>) {
+	for contact in take_values(organization, "contact") {
+		let Value::Object(mut contact) = contact else {
+			continue;
+		};
+
+		if contact.contains_key("purpose") || contact.contains_key("name") {
+			context.unwrap_first(&mut contact, "name", "Organization.contact.name");
+			push_values(organization, "contact", vec![Value::Object(contact)]);
+		} else {
+			let telecom = take_values(&mut contact, "telecom");
+			push_values(organization, "telecom", telecom);
+			let address = take_values(&mut contact, "address");
+			push_values(organization, "address", address);
+			for (key, value) in contact {
+				context.drop_element(format!("Organization.contact.{key}"), value);
+			}
+		}
+	}
+
+	context.move_to_extension(organization, &ORGANIZATION_DESCRIPTION);
+}
+
+/// Convert an Encounter from R4B to R5.
+fn upgrade_encounter(encounter: &mut Object, context: &mut Context<'_>) {
+	context.map_code_or_restore(
+		encounter,
+		&ENCOUNTER_STATUS,
+		ENCOUNTER_STATUS_R4B_TO_R5,
+		Release::R5,
+	);
+	context.move_to_extension(encounter, &ENCOUNTER_STATUS_HISTORY);
+	context.move_to_extension(encounter, &ENCOUNTER_CLASS_HISTORY);
+
+	if let Some(class) = encounter.remove("class") {
+		let concept = object_with("coding", Value::Array(vec![class]));
+		encounter.insert("class".to_owned(), Value::Array(vec![concept]));
+	}
+	if let Some(service_type) = encounter.remove("serviceType") {
+		let service_type = object_with("concept", service_type);
+		encounter.insert("serviceType".to_owned(), Value::Array(vec![service_type]));
+	}
+	for participant in objects_mut(encounter, "participant") {
+		rename(participant, "individual", "actor");
+	}
+	rename(encounter, "period", "actualPeriod");
+	for location in objects_mut(encounter, "location") {
+		rename(location, "physicalType", "form");
+	}
+
+	let mut reasons = Vec::new();
+	for (key, value_key) in [("reasonCode", "concept"), ("reasonReference", "reference")] {
+		for value in take_values(encounter, key) {
+			let value = object_with(value_key, value);
+			reasons.push(object_with("value", Value::Array(vec![value])));
+		}
+	}
+	push_values(encounter, "reason", reasons);
+
+	for diagnosis in objects_mut(encounter, "diagnosis") {
+		if let Some(condition) = diagnosis.remove("condition") {
+			let condition = object_with("reference", condition);
+			diagnosis.insert("condition".to_owned(), Value::Array(vec![condition]));
+		}
+		wrap_list(diagnosis, "use");
+		context.move_to_extension(diagnosis, &ENCOUNTER_DIAGNOSIS_RANK);
+	}
+
+	rename(encounter, "hospitalization", "admission");
+	if let Some(Value::Object(admission)) = encounter.get_mut("admission") {
+		let moved: Vec<_> = ENCOUNTER_MOVED_ADMISSION_ELEMENTS
+			.iter()
+			.filter_map(|key| Some((*key, admission.remove(*key)?)))
+			.collect();
+		for (key, value) in moved {
+			encounter.insert(key.to_owned(), value);
+		}
+	}
+
+	for element in ENCOUNTER_R5_ELEMENTS {
+		context.restore_extension(encounter, element, Release::R5);
+	}
+}
+
+/// Convert an Encounter from R5 to R4B.
+fn downgrade_encounter(encounter: &mut Object, context: &mut Context<'_>) {
+	context.map_code_or_restore(
+		encounter,
+		&ENCOUNTER_STATUS,
+		ENCOUNTER_STATUS_R5_TO_R4B,
+		Release::R4B,
+	);
+	context.restore_extension(encounter, &ENCOUNTER_STATUS_HISTORY, Release::R4B);
+	context.restore_extension(encounter, &ENCOUNTER_CLASS_HISTORY, Release::R4B);
+
+	context.concepts_to_coding(encounter, "class", "Encounter.class");
+	let mut service_types = Vec::new();
+	for mut service_type in take_values(encounter, "serviceType") {
+		match service_type.get_mut("concept").map(Value::take) {
+			Some(concept) => service_types.push(concept),
+			None => context.drop_element("Encounter.serviceType", service_type),
+		}
+	}
+	let mut service_types = service_types.into_iter();
+	if let Some(service_type) = service_types.next() {
+		encounter.insert("serviceType".to_owned(), service_type);
+	}
+	for service_type in service_types {
+		context.drop_element("Encounter.serviceType", service_type);
+	}
+
+	for participant in objects_mut(encounter, "participant") {
+		rename(participant, "actor", "individual");
+	}
+	rename(encounter, "actualPeriod", "period");
+	for location in objects_mut(encounter, "location") {
+		rename(location, "form", "physicalType");
+	}
+
+	downgrade_encounter_reasons(encounter, context);
+	downgrade_encounter_diagnoses(encounter, context);
+
+	let moved: Vec<_> = ENCOUNTER_MOVED_ADMISSION_ELEMENTS
+		.iter()
+		.filter_map(|key| Some((*key, encounter.remove(*key)?)))
+		.collect();
+	rename(encounter, "admission", "hospitalization");
+	if !moved.is_empty() {
+		let hospitalization =
+			encounter.entry("hospitalization").or_insert_with(|| Value::Object(Object::new()));
+		if let Value::Object(hospitalization) = hospitalization {
+			for (key, value) in moved {
+				hospitalization.insert(key.to_owned(), value);
+			}
+		}
+	}
+
+	for element in ENCOUNTER_R5_ELEMENTS {
+		context.move_to_extension(encounter, element);
+	}
+}
+
+/// Convert the R5 `Encounter.reason` to the R4B reason codes and references.
+fn downgrade_encounter_reasons(encounter: &mut Object, context: &mut Context<'_>) {
+	let mut codes = Vec::new();
+	let mut references = Vec::new();
+	for reason in take_values(encounter, "reason") {
+		let Value::Object(mut reason) = reason else {
+			continue;
+		};
+		for mut value in take_values(&mut reason, "value") {
+			if let Some(concept) = value.get_mut("concept").map(Value::take) {
+				codes.push(concept);
+			}
+			if let Some(reference) = value.get_mut("reference").map(Value::take) {
+				references.push(reference);
+			}
+		}
+		for (key, value) in reason {
+			context.drop_element(format!("Encounter.reason.{key}"), value);
+		}
+	}
+	push_values(encounter, "reasonCode", codes);
+	push_values(encounter, "reasonReference", references);
+}
+
+/// Convert the R5 `Encounter.diagnosis` to R4B, which has one diagnosis per
+/// referenced condition.
+fn downgrade_encounter_diagnoses(encounter: &mut Object, context: &mut Context<'_>) {
+	let mut diagnoses = Vec::new();
+	for diagnosis in take_values(encounter, "diagnosis") {
+		let Value::Object(mut diagnosis) = diagnosis else {
+			continue;
+		};
+		context.restore_extension(&mut diagnosis, &ENCOUNTER_DIAGNOSIS_RANK, Release::R4B);
+		context.unwrap_first(&mut diagnosis, "use", "Encounter.diagnosis.use");
+
+		for mut condition in take_values(&mut diagnosis, "condition") {
+			let Some(reference) = condition.get_mut("reference").map(Value::take) else {
+				context.drop_element("Encounter.diagnosis.condition", condition);
+				continue;
+			};
+			let mut converted = diagnosis.clone();
+			converted.insert("condition".to_owned(), reference);
+			diagnoses.push(Value::Object(converted));
+		}
+	}
+	push_values(encounter, "diagnosis", diagnoses);
+}
+
+/// Convert an Observation from R4B to R5.
+fn upgrade_observation(observation: &mut Object, context: &mut Context<'_>) {
+	context.restore_extension(observation, &OBSERVATION_VALUE_ATTACHMENT, Release::Stu3);
+	context.restore_extension(observation, &OBSERVATION_VALUE_ATTACHMENT, Release::R5);
+	if let Some(Value::Object(attachment)) = observation.get_mut("valueAttachment") {
+		size_to_integer64(attachment);
+	}
+	context.restore_extension(observation, &OBSERVATION_VALUE_REFERENCE, Release::R5);
+}
+
+/// Convert an Observation from R5 to R4B.
+fn downgrade_observation(observation: &mut Object, context: &mut Context<'_>) {
+	if let Some(Value::Object(attachment)) = observation.get_mut("valueAttachment") {
+		context.size_to_unsigned_int(attachment, "Observation.valueAttachment.size");
+	}
+	context.move_to_extension(observation, &OBSERVATION_VALUE_ATTACHMENT);
+	context.move_to_extension(observation, &OBSERVATION_VALUE_REFERENCE);
+}
+
+/// Convert a Condition from R4B to R5. Asserter and recorder are participants
+/// in R5 and the evidence uses `CodeableReference`s.
+fn upgrade_condition(condition: &mut Object) {
+	let mut participants = Vec::new();
+	for (key, function) in [("recorder", "enterer"), ("asserter", "informant")] {
+		if let Some(actor) = condition.remove(key) {
+			let mut participant = Object::new();
+			participant.insert("function".to_owned(), concept(PARTICIPANT_TYPE, function));
+			participant.insert("actor".to_owned(), actor);
+			participants.push(Value::Object(participant));
+		}
+	}
+	push_values(condition, "participant", participants);
+
+	let mut evidence = Vec::new();
+	for old_evidence in take_values(condition, "evidence") {
+		let Value::Object(mut old_evidence) = old_evidence else {
+			continue;
+		};
+		for code in take_values(&mut old_evidence, "code") {
+			evidence.push(object_with("concept", code));
+		}
+		for detail in take_values(&mut old_evidence, "detail") {
+			evidence.push(object_with("reference", detail));
+		}
+	}
+	push_values(condition, "evidence", evidence);
+}
+
+/// Convert a Condition from R5 to R4B.
+fn downgrade_condition(condition: &mut Object, context: &mut Context<'_>) {
+	for participant in take_values(condition, "participant") {
+		let function = participant
+			.pointer("/function/coding")
+			.and_then(Value::as_array)
+			.into_iter()
+			.flatten()
+			.find(|coding| coding.get("system").and_then(Value::as_str) == Some(PARTICIPANT_TYPE))
+			.and_then(|coding| coding.get("code"))
+			.and_then(Value::as_str)
+			.map(str::to_owned);
+		let key = match function.as_deref() {
+			Some("enterer") => "recorder",
+			Some("informant") => "asserter",
+			_ => {
+				context.drop_element("Condition.participant", participant);
+				continue;
+			}
+		};
+
+		match participant.get("actor") {
+			Some(actor) if !condition.contains_key(key) => {
+				condition.insert(key.to_owned(), actor.clone());
+			}
+			_ => context.drop_element("Condition.participant", participant),
+		}
+	}
+
+	let mut codes = Vec::new();
+	let mut details = Vec::new();
+	for mut evidence in take_values(condition, "evidence") {
+		if let Some(concept) = evidence.get_mut("concept").map(Value::take) {
+			codes.push(concept);
+		}
+		if let Some(reference) = evidence.get_mut("reference").map(Value::take) {
+			details.push(reference);
+		}
+	}
+	if !codes.is_empty() || !details.is_empty() {
+		let mut evidence = Object::new();
+		push_values(&mut evidence, "code", codes);
+		push_values(&mut evidence, "detail", details);
+		condition.insert("evidence".to_owned(), Value::Array(vec![Value::Object(evidence)]));
+	}
+}
+
+/// Create a `CodeableConcept` JSON object with a single coding.
+fn concept(system: &str, code: &str) -> Value {
+	let mut coding = Object::new();
+	coding.insert("system".to_owned(), Value::String(system.to_owned()));
+	coding.insert("code".to_owned(), Value::String(code.to_owned()));
+	object_with("coding", Value::Array(vec![Value::Object(coding)]))
+}
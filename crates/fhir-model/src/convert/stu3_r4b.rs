@@ -0,0 +1,308 @@
+//! Mappings between STU3 and R4B, following the official STU3 <-> R4 maps.
+//! Practitioner and Organization did not change in a relevant way.
+
+use serde_json::Value;
+
+use super::{
+	mapping::{
+		code_to_concept, objects_mut, push_values, rename, take_values, wrap_list, Context,
+		Element, Object,
+	},
+	Release,
+};
+
+/// Code system of `Condition.clinicalStatus` in R4B.
+const CONDITION_CLINICAL: &str = "http://terminology.hl7.org/CodeSystem/condition-clinical";
+/// Code system of `Condition.verificationStatus` in R4B.
+const CONDITION_VERIFICATION: &str = "http://terminology.hl7.org/CodeSystem/condition-ver-status";
+
+/// STU3 `Patient.animal`.
+const PATIENT_ANIMAL: Element = Element::complex(
+	"Patient.animal",
+	"animal",
+	&[
+		Element::typed("species", "species", "CodeableConcept", false),
+		Element::typed("breed", "breed", "CodeableConcept", false),
+		Element::typed("genderStatus", "genderStatus", "CodeableConcept", false),
+	],
+	false,
+);
+
+/// R4B `Encounter.serviceType`.
+const ENCOUNTER_SERVICE_TYPE: Element =
+	Element::typed("Encounter.serviceType", "serviceType", "CodeableConcept", false);
+/// R4B `Encounter.reasonReference`.
+const ENCOUNTER_REASON_REFERENCE: Element =
+	Element::typed("Encounter.reasonReference", "reasonReference", "Reference", true);
+/// R4B `Encounter.location.physicalType`.
+const ENCOUNTER_LOCATION_PHYSICAL_TYPE: Element =
+	Element::typed("Encounter.location.physicalType", "physicalType", "CodeableConcept", false);
+
+/// STU3 `Observation.related` of types without counterpart in R4B.
+const OBSERVATION_RELATED: Element = Element::complex(
+	"Observation.related",
+	"related",
+	&[
+		Element::typed("type", "type", "Code", false),
+		Element::typed("target", "target", "Reference", false),
+	],
+	true,
+);
+/// STU3 and R5 `Observation.valueAttachment`.
+pub(super) const OBSERVATION_VALUE_ATTACHMENT: Element =
+	Element::typed("Observation.value", "valueAttachment", "Attachment", false);
+/// R4B `Observation.valueInteger`.
+const OBSERVATION_VALUE_INTEGER: Element =
+	Element::typed("Observation.value", "valueInteger", "Integer", false);
+/// R4B `Observation.component.valueInteger`.
+const OBSERVATION_COMPONENT_VALUE_INTEGER: Element =
+	Element::typed("Observation.component.value", "valueInteger", "Integer", false);
+/// R4B `Observation.effectiveTiming`.
+const OBSERVATION_EFFECTIVE_TIMING: Element =
+	Element::typed("Observation.effective", "effectiveTiming", "Timing", false);
+/// R4B `Observation.effectiveInstant`.
+const OBSERVATION_EFFECTIVE_INSTANT: Element =
+	Element::typed("Observation.effective", "effectiveInstant", "Instant", false);
+/// R4B `Observation.partOf`.
+const OBSERVATION_PART_OF: Element =
+	Element::typed("Observation.partOf", "partOf", "Reference", true);
+/// R4B `Observation.focus`.
+const OBSERVATION_FOCUS: Element = Element::typed("Observation.focus", "focus", "Reference", true);
+
+/// `Condition.clinicalStatus`, holding codes without counterpart.
+const CONDITION_CLINICAL_STATUS: Element =
+	Element::typed("Condition.clinicalStatus", "clinicalStatus", "Code", false);
+/// `Condition.verificationStatus`, holding codes without counterpart.
+const CONDITION_VERIFICATION_STATUS: Element =
+	Element::typed("Condition.verificationStatus", "verificationStatus", "Code", false);
+/// STU3 `Condition.abatementBoolean`.
+const CONDITION_ABATEMENT_BOOLEAN: Element =
+	Element::typed("Condition.abatement", "abatementBoolean", "Boolean", false);
+/// R4B `Condition.recorder`.
+const CONDITION_RECORDER: Element =
+	Element::typed("Condition.recorder", "recorder", "Reference", false);
+
+/// Convert a resource from STU3 to R4B.
+pub(super) fn upgrade(resource_type: &str, resource: &mut Object, context: &mut Context<'_>) {
+	match resource_type {
+		"Patient" => context.move_to_extension(resource, &PATIENT_ANIMAL),
+		"Encounter" => upgrade_encounter(resource, context),
+		"Observation" => upgrade_observation(resource, context),
+		"Condition" => upgrade_condition(resource, context),
+		_ => {}
+	}
+}
+
+/// Convert a resource from R4B to STU3.
+pub(super) fn downgrade(resource_type: &str, resource: &mut Object, context: &mut Context<'_>) {
+	match resource_type {
+		"Patient" => context.restore_extension(resource, &PATIENT_ANIMAL, Release::Stu3),
+		"Encounter" => downgrade_encounter(resource, context),
+		"Observation" => downgrade_observation(resource, context),
+		"Condition" => downgrade_condition(resource, context),
+		_ => {}
+	}
+}
+
+/// Convert an Encounter from STU3 to R4B.
+fn upgrade_encounter(encounter: &mut Object, context: &mut Context<'_>) {
+	rename(encounter, "incomingReferral", "basedOn");
+	wrap_list(encounter, "appointment");
+	rename(encounter, "reason", "reasonCode");
+	for diagnosis in objects_mut(encounter, "diagnosis") {
+		rename(diagnosis, "role", "use");
+	}
+
+	context.restore_extension(encounter, &ENCOUNTER_SERVICE_TYPE, Release::R4B);
+	context.restore_extension(encounter, &ENCOUNTER_REASON_REFERENCE, Release::R4B);
+	for location in objects_mut(encounter, "location") {
+		context.restore_extension(location, &ENCOUNTER_LOCATION_PHYSICAL_TYPE, Release::R4B);
+	}
+}
+
+/// Convert an Encounter from R4B to STU3.
+fn downgrade_encounter(encounter: &mut Object, context: &mut Context<'_>) {
+	rename(encounter, "basedOn", "incomingReferral");
+	context.unwrap_first(encounter, "appointment", "Encounter.appointment");
+	rename(encounter, "reasonCode", "reason");
+	for diagnosis in objects_mut(encounter, "diagnosis") {
+		rename(diagnosis, "use", "role");
+	}
+
+	context.move_to_extension(encounter, &ENCOUNTER_SERVICE_TYPE);
+	context.move_to_extension(encounter, &ENCOUNTER_REASON_REFERENCE);
+	for location in objects_mut(encounter, "location") {
+		context.move_to_extension(location, &ENCOUNTER_LOCATION_PHYSICAL_TYPE);
+	}
+}
+
+/// Convert an Observation from STU3 to R4B.
+fn upgrade_observation(observation: &mut Object, context: &mut Context<'_>) {
+	rename(observation, "context", "encounter");
+	wrap_list(observation, "interpretation");
+	for component in objects_mut(observation, "component") {
+		wrap_list(component, "interpretation");
+		context.restore_extension(component, &OBSERVATION_COMPONENT_VALUE_INTEGER, Release::R4B);
+	}
+
+	if let Some(comment) = observation.remove("comment") {
+		let mut note = Object::new();
+		note.insert("text".to_owned(), comment);
+		if let Some(primitive) = observation.remove("_comment") {
+			note.insert("_text".to_owned(), primitive);
+		}
+		push_values(observation, "note", vec![Value::Object(note)]);
+	}
+
+	for related in take_values(observation, "related") {
+		let target = related.get("target").cloned().unwrap_or_default();
+		let related_type = related.get("type").and_then(Value::as_str).map(ToOwned::to_owned);
+		match related_type.as_deref() {
+			Some("has-member") => push_values(observation, "hasMember", vec![target]),
+			Some("derived-from") => push_values(observation, "derivedFrom", vec![target]),
+			_ => context.add_extension(observation, &OBSERVATION_RELATED, related, None),
+		}
+	}
+
+	context.move_to_extension(observation, &OBSERVATION_VALUE_ATTACHMENT);
+	for element in [
+		&OBSERVATION_VALUE_INTEGER,
+		&OBSERVATION_EFFECTIVE_TIMING,
+		&OBSERVATION_EFFECTIVE_INSTANT,
+		&OBSERVATION_PART_OF,
+		&OBSERVATION_FOCUS,
+	] {
+		context.restore_extension(observation, element, Release::R4B);
+	}
+}
+
+/// Convert an Observation from R4B to STU3.
+fn downgrade_observation(observation: &mut Object, context: &mut Context<'_>) {
+	rename(observation, "encounter", "context");
+	context.unwrap_first(observation, "interpretation", "Observation.interpretation");
+	for component in objects_mut(observation, "component") {
+		context.unwrap_first(component, "interpretation", "Observation.component.interpretation");
+		context.move_to_extension(component, &OBSERVATION_COMPONENT_VALUE_INTEGER);
+	}
+
+	let mut comments = Vec::new();
+	for mut note in take_values(observation, "note") {
+		if let Some(Value::String(text)) = note.get_mut("text").map(Value::take) {
+			comments.push(text);
+		}
+		if note.as_object().is_some_and(|note| note.values().any(|value| !value.is_null())) {
+			context.drop_element("Observation.note", note);
+		}
+	}
+	if !comments.is_empty() {
+		observation.insert("comment".to_owned(), Value::String(comments.join("\n\n")));
+	}
+
+	let mut related = Vec::new();
+	for (key, related_type) in [("hasMember", "has-member"), ("derivedFrom", "derived-from")] {
+		for target in take_values(observation, key) {
+			let mut entry = Object::new();
+			entry.insert("type".to_owned(), Value::String(related_type.to_owned()));
+			entry.insert("target".to_owned(), target);
+			related.push(Value::Object(entry));
+		}
+	}
+	push_values(observation, "related", related);
+	context.restore_extension(observation, &OBSERVATION_RELATED, Release::Stu3);
+
+	context.restore_extension(observation, &OBSERVATION_VALUE_ATTACHMENT, Release::Stu3);
+	context.restore_extension(observation, &OBSERVATION_VALUE_ATTACHMENT, Release::R5);
+	for element in [
+		&OBSERVATION_VALUE_INTEGER,
+		&OBSERVATION_EFFECTIVE_TIMING,
+		&OBSERVATION_EFFECTIVE_INSTANT,
+		&OBSERVATION_PART_OF,
+		&OBSERVATION_FOCUS,
+	] {
+		context.move_to_extension(observation, element);
+	}
+}
+
+/// Convert a Condition from STU3 to R4B.
+fn upgrade_condition(condition: &mut Object, context: &mut Context<'_>) {
+	context.map_code_or_restore(
+		condition,
+		&CONDITION_CLINICAL_STATUS,
+		&[
+			("active", "active"),
+			("recurrence", "recurrence"),
+			("inactive", "inactive"),
+			("remission", "remission"),
+			("resolved", "resolved"),
+		],
+		Release::R4B,
+	);
+	context.map_code_or_restore(
+		condition,
+		&CONDITION_VERIFICATION_STATUS,
+		&[
+			("provisional", "provisional"),
+			("differential", "differential"),
+			("confirmed", "confirmed"),
+			("refuted", "refuted"),
+			("entered-in-error", "entered-in-error"),
+		],
+		Release::R4B,
+	);
+	code_to_concept(condition, "clinicalStatus", CONDITION_CLINICAL);
+	code_to_concept(condition, "verificationStatus", CONDITION_VERIFICATION);
+
+	rename(condition, "context", "encounter");
+	rename(condition, "assertedDate", "recordedDate");
+	wrap_list(condition, "stage");
+	context.move_to_extension(condition, &CONDITION_ABATEMENT_BOOLEAN);
+	context.restore_extension(condition, &CONDITION_RECORDER, Release::R4B);
+}
+
+/// Convert a Condition from R4B to STU3.
+fn downgrade_condition(condition: &mut Object, context: &mut Context<'_>) {
+	context.concept_to_code(
+		condition,
+		"clinicalStatus",
+		CONDITION_CLINICAL,
+		"Condition.clinicalStatus",
+	);
+	context.concept_to_code(
+		condition,
+		"verificationStatus",
+		CONDITION_VERIFICATION,
+		"Condition.verificationStatus",
+	);
+	context.map_code_or_restore(
+		condition,
+		&CONDITION_CLINICAL_STATUS,
+		&[
+			("active", "active"),
+			("recurrence", "recurrence"),
+			("relapse", "recurrence"),
+			("inactive", "inactive"),
+			("remission", "remission"),
+			("resolved", "resolved"),
+		],
+		Release::Stu3,
+	);
+	context.map_code_or_restore(
+		condition,
+		&CONDITION_VERIFICATION_STATUS,
+		&[
+			("unconfirmed", "provisional"),
+			("provisional", "provisional"),
+			("differential", "differential"),
+			("confirmed", "confirmed"),
+			("refuted", "refuted"),
+			("entered-in-error", "entered-in-error"),
+		],
+		Release::Stu3,
+	);
+
+	rename(condition, "encounter", "context");
+	rename(condition, "recordedDate", "assertedDate");
+	context.unwrap_first(condition, "stage", "Condition.stage");
+	context.restore_extension(condition, &CONDITION_ABATEMENT_BOOLEAN, Release::Stu3);
+	context.move_to_extension(condition, &CONDITION_RECORDER);
+}
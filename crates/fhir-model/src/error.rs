@@ -82,3 +82,31 @@ impl std::fmt::Display for UnknownResourceType {
 	}
 }
 impl std::error::Error for UnknownResourceType {}
+
+/// Error when converting a resource between FHIR versions.
+#[derive(Debug)]
+pub enum ConversionError {
+	/// The resource type does not exist in the target FHIR version.
+	UnsupportedResourceType(String),
+	/// The converted JSON could not be (de-)serialized.
+	Json(serde_json::Error),
+}
+
+impl std::fmt::Display for ConversionError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::UnsupportedResourceType(resource_type) => {
+				write!(f, "Resource type '{resource_type}' does not exist in the target version")
+			}
+			Self::Json(err) => write!(f, "Couldn't convert the resource JSON: {err}"),
+		}
+	}
+}
+
+impl std::error::Error for ConversionError {}
+
+impl From<serde_json::Error> for ConversionError {
+	fn from(err: serde_json::Error) -> Self {
+		Self::Json(err)
+	}
+}
@@ -5,6 +5,12 @@
 
 #![recursion_limit = "1024"]
 
+#[cfg(any(
+	all(feature = "stu3", feature = "r4b"),
+	all(feature = "stu3", feature = "r5"),
+	all(feature = "r4b", feature = "r5"),
+))]
+pub mod convert;
 mod date_time;
 mod error;
 #[cfg(feature = "search-params")]
@@ -13,6 +19,8 @@ mod params;
 pub mod r4b;
 #[cfg(feature = "r5")]
 pub mod r5;
+#[cfg(any(feature = "stu3", feature = "r4b", feature = "r5"))]
+mod release;
 #[cfg(feature = "stu3")]
 pub mod stu3;
 
@@ -29,6 +37,8 @@ use crate::error::UnknownResourceType;
 pub use self::date_time::*;
 #[cfg(feature = "search-params")]
 pub use self::params::*;
+#[cfg(any(feature = "stu3", feature = "r4b", feature = "r5"))]
+pub use self::release::*;
 pub use bigdecimal;
 pub use time;
 
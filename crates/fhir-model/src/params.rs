@@ -49,9 +49,36 @@ pub trait ResourceSearchParameterDefinition {
 	}
 }
 
+/// A search parameter of type reference, typed with the resources it can
+/// refer to
+pub trait ReferenceSearchParameter {
+	/// The resource type the search parameter is defined on
+	type Resource: SearchableResource;
+
+	/// Type all resources the search parameter can refer to convert into:
+	/// the resource itself if there is only one, a generated enum of the
+	/// target resources or the `Resource` enum if it can refer to any resource.
+	type Target;
+
+	/// The search parameter's definition
+	fn parameter(&self) -> <Self::Resource as SearchableResource>::Params;
+}
+
 /// A resource that supports resolving a search parameter on it
 pub trait Resolve: SearchableResource {
 	/// Resolve a search parameter on this resource, returning the value of the
 	/// first corresponding field found.
 	fn resolve(&self, param: &Self::Params) -> Option<impl Ord>;
 }
+
+/// A resource type whose top-level elements can be selected via `_elements`
+pub trait SelectableResource {
+	/// Enum of elements that can be selected
+	type Elements: ResourceElementDefinition;
+}
+
+/// Trait for a resource's top-level element definition
+pub trait ResourceElementDefinition {
+	/// The element's name as used in the `_elements` search parameter.
+	fn name(&self) -> &'static str;
+}
@@ -11,6 +11,11 @@ use self::{
 	types::{Reference, ReferenceInner},
 };
 
+/// Marker type of FHIR R4B, implementing [`FhirRelease`](crate::FhirRelease)
+/// for writing code generic over the FHIR versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct R4B;
+
 /// Create relative [`Reference`] to the given resource.
 pub fn reference_to<R>(resource: &R) -> Option<Reference>
 where
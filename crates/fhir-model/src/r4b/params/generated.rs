@@ -6935,6 +6935,11215 @@ impl ResourceSearchParameterDefinition for VisionPrescriptionSearchParameter {
         }
     }
 }
+/// The owner reference search parameter of the Account resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AccountOwnerSearchReference;
+impl ReferenceSearchParameter for AccountOwnerSearchReference {
+    type Resource = resources::Account;
+    type Target = resources::Organization;
+    fn parameter(&self) -> AccountSearchParameter {
+        AccountSearchParameter::Owner
+    }
+}
+/// The patient reference search parameter of the Account resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AccountPatientSearchReference;
+impl ReferenceSearchParameter for AccountPatientSearchReference {
+    type Resource = resources::Account;
+    type Target = resources::Patient;
+    fn parameter(&self) -> AccountSearchParameter {
+        AccountSearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the Account resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AccountSubjectSearchReference;
+impl ReferenceSearchParameter for AccountSubjectSearchReference {
+    type Resource = resources::Account;
+    type Target = AccountSubjectSearchTarget;
+    fn parameter(&self) -> AccountSearchParameter {
+        AccountSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Account resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AccountSubjectSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Practitioner> for AccountSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> AccountSubjectSearchTarget {
+        AccountSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for AccountSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> AccountSubjectSearchTarget {
+        AccountSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for AccountSubjectSearchTarget {
+    fn from(resource: resources::Device) -> AccountSubjectSearchTarget {
+        AccountSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for AccountSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> AccountSubjectSearchTarget {
+        AccountSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::HealthcareService> for AccountSubjectSearchTarget {
+    fn from(resource: resources::HealthcareService) -> AccountSubjectSearchTarget {
+        AccountSubjectSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::PractitionerRole> for AccountSubjectSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> AccountSubjectSearchTarget {
+        AccountSubjectSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Location> for AccountSubjectSearchTarget {
+    fn from(resource: resources::Location) -> AccountSubjectSearchTarget {
+        AccountSubjectSearchTarget::Location(resource)
+    }
+}
+/// The composed-of reference search parameter of the ActivityDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ActivityDefinitionComposedOfSearchReference;
+impl ReferenceSearchParameter for ActivityDefinitionComposedOfSearchReference {
+    type Resource = resources::ActivityDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ActivityDefinitionSearchParameter {
+        ActivityDefinitionSearchParameter::ComposedOf
+    }
+}
+/// The depends-on reference search parameter of the ActivityDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ActivityDefinitionDependsOnSearchReference;
+impl ReferenceSearchParameter for ActivityDefinitionDependsOnSearchReference {
+    type Resource = resources::ActivityDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ActivityDefinitionSearchParameter {
+        ActivityDefinitionSearchParameter::DependsOn
+    }
+}
+/// The derived-from reference search parameter of the ActivityDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ActivityDefinitionDerivedFromSearchReference;
+impl ReferenceSearchParameter for ActivityDefinitionDerivedFromSearchReference {
+    type Resource = resources::ActivityDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ActivityDefinitionSearchParameter {
+        ActivityDefinitionSearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the ActivityDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ActivityDefinitionPredecessorSearchReference;
+impl ReferenceSearchParameter for ActivityDefinitionPredecessorSearchReference {
+    type Resource = resources::ActivityDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ActivityDefinitionSearchParameter {
+        ActivityDefinitionSearchParameter::Predecessor
+    }
+}
+/// The successor reference search parameter of the ActivityDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ActivityDefinitionSuccessorSearchReference;
+impl ReferenceSearchParameter for ActivityDefinitionSuccessorSearchReference {
+    type Resource = resources::ActivityDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ActivityDefinitionSearchParameter {
+        ActivityDefinitionSearchParameter::Successor
+    }
+}
+/// The location reference search parameter of the AdverseEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdverseEventLocationSearchReference;
+impl ReferenceSearchParameter for AdverseEventLocationSearchReference {
+    type Resource = resources::AdverseEvent;
+    type Target = resources::Location;
+    fn parameter(&self) -> AdverseEventSearchParameter {
+        AdverseEventSearchParameter::Location
+    }
+}
+/// The recorder reference search parameter of the AdverseEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdverseEventRecorderSearchReference;
+impl ReferenceSearchParameter for AdverseEventRecorderSearchReference {
+    type Resource = resources::AdverseEvent;
+    type Target = AdverseEventRecorderSearchTarget;
+    fn parameter(&self) -> AdverseEventSearchParameter {
+        AdverseEventSearchParameter::Recorder
+    }
+}
+/// Target resources of the recorder reference search parameter of the AdverseEvent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AdverseEventRecorderSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for AdverseEventRecorderSearchTarget {
+    fn from(resource: resources::Practitioner) -> AdverseEventRecorderSearchTarget {
+        AdverseEventRecorderSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for AdverseEventRecorderSearchTarget {
+    fn from(resource: resources::Patient) -> AdverseEventRecorderSearchTarget {
+        AdverseEventRecorderSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for AdverseEventRecorderSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> AdverseEventRecorderSearchTarget {
+        AdverseEventRecorderSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for AdverseEventRecorderSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AdverseEventRecorderSearchTarget {
+        AdverseEventRecorderSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The resultingcondition reference search parameter of the AdverseEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdverseEventResultingconditionSearchReference;
+impl ReferenceSearchParameter for AdverseEventResultingconditionSearchReference {
+    type Resource = resources::AdverseEvent;
+    type Target = resources::Condition;
+    fn parameter(&self) -> AdverseEventSearchParameter {
+        AdverseEventSearchParameter::Resultingcondition
+    }
+}
+/// The study reference search parameter of the AdverseEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdverseEventStudySearchReference;
+impl ReferenceSearchParameter for AdverseEventStudySearchReference {
+    type Resource = resources::AdverseEvent;
+    type Target = resources::ResearchStudy;
+    fn parameter(&self) -> AdverseEventSearchParameter {
+        AdverseEventSearchParameter::Study
+    }
+}
+/// The subject reference search parameter of the AdverseEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdverseEventSubjectSearchReference;
+impl ReferenceSearchParameter for AdverseEventSubjectSearchReference {
+    type Resource = resources::AdverseEvent;
+    type Target = AdverseEventSubjectSearchTarget;
+    fn parameter(&self) -> AdverseEventSearchParameter {
+        AdverseEventSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the AdverseEvent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AdverseEventSubjectSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for AdverseEventSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> AdverseEventSubjectSearchTarget {
+        AdverseEventSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for AdverseEventSubjectSearchTarget {
+    fn from(resource: resources::Group) -> AdverseEventSubjectSearchTarget {
+        AdverseEventSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for AdverseEventSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> AdverseEventSubjectSearchTarget {
+        AdverseEventSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for AdverseEventSubjectSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AdverseEventSubjectSearchTarget {
+        AdverseEventSubjectSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The substance reference search parameter of the AdverseEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdverseEventSubstanceSearchReference;
+impl ReferenceSearchParameter for AdverseEventSubstanceSearchReference {
+    type Resource = resources::AdverseEvent;
+    type Target = AdverseEventSubstanceSearchTarget;
+    fn parameter(&self) -> AdverseEventSearchParameter {
+        AdverseEventSearchParameter::Substance
+    }
+}
+/// Target resources of the substance reference search parameter of the AdverseEvent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AdverseEventSubstanceSearchTarget {
+    /// Variant for Immunization target resources
+    Immunization(resources::Immunization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+    /// Variant for MedicationAdministration target resources
+    MedicationAdministration(resources::MedicationAdministration),
+    /// Variant for MedicationStatement target resources
+    MedicationStatement(resources::MedicationStatement),
+}
+impl From<resources::Immunization> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::Immunization) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::Immunization(resource)
+    }
+}
+impl From<resources::Device> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::Device) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Medication> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::Medication) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::Medication(resource)
+    }
+}
+impl From<resources::Procedure> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::Procedure) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::Substance> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::Substance) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::Substance(resource)
+    }
+}
+impl From<resources::MedicationAdministration> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::MedicationAdministration) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::MedicationAdministration(resource)
+    }
+}
+impl From<resources::MedicationStatement> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::MedicationStatement) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::MedicationStatement(resource)
+    }
+}
+/// The asserter reference search parameter of the AllergyIntolerance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AllergyIntoleranceAsserterSearchReference;
+impl ReferenceSearchParameter for AllergyIntoleranceAsserterSearchReference {
+    type Resource = resources::AllergyIntolerance;
+    type Target = AllergyIntoleranceAsserterSearchTarget;
+    fn parameter(&self) -> AllergyIntoleranceSearchParameter {
+        AllergyIntoleranceSearchParameter::Asserter
+    }
+}
+/// Target resources of the asserter reference search parameter of the AllergyIntolerance resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AllergyIntoleranceAsserterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for AllergyIntoleranceAsserterSearchTarget {
+    fn from(resource: resources::Practitioner) -> AllergyIntoleranceAsserterSearchTarget {
+        AllergyIntoleranceAsserterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for AllergyIntoleranceAsserterSearchTarget {
+    fn from(resource: resources::Patient) -> AllergyIntoleranceAsserterSearchTarget {
+        AllergyIntoleranceAsserterSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for AllergyIntoleranceAsserterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> AllergyIntoleranceAsserterSearchTarget {
+        AllergyIntoleranceAsserterSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for AllergyIntoleranceAsserterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AllergyIntoleranceAsserterSearchTarget {
+        AllergyIntoleranceAsserterSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The patient reference search parameter of the AllergyIntolerance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AllergyIntolerancePatientSearchReference;
+impl ReferenceSearchParameter for AllergyIntolerancePatientSearchReference {
+    type Resource = resources::AllergyIntolerance;
+    type Target = resources::Patient;
+    fn parameter(&self) -> AllergyIntoleranceSearchParameter {
+        AllergyIntoleranceSearchParameter::Patient
+    }
+}
+/// The recorder reference search parameter of the AllergyIntolerance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AllergyIntoleranceRecorderSearchReference;
+impl ReferenceSearchParameter for AllergyIntoleranceRecorderSearchReference {
+    type Resource = resources::AllergyIntolerance;
+    type Target = AllergyIntoleranceRecorderSearchTarget;
+    fn parameter(&self) -> AllergyIntoleranceSearchParameter {
+        AllergyIntoleranceSearchParameter::Recorder
+    }
+}
+/// Target resources of the recorder reference search parameter of the AllergyIntolerance resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AllergyIntoleranceRecorderSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for AllergyIntoleranceRecorderSearchTarget {
+    fn from(resource: resources::Practitioner) -> AllergyIntoleranceRecorderSearchTarget {
+        AllergyIntoleranceRecorderSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for AllergyIntoleranceRecorderSearchTarget {
+    fn from(resource: resources::Patient) -> AllergyIntoleranceRecorderSearchTarget {
+        AllergyIntoleranceRecorderSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for AllergyIntoleranceRecorderSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> AllergyIntoleranceRecorderSearchTarget {
+        AllergyIntoleranceRecorderSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for AllergyIntoleranceRecorderSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AllergyIntoleranceRecorderSearchTarget {
+        AllergyIntoleranceRecorderSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The actor reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentActorSearchReference;
+impl ReferenceSearchParameter for AppointmentActorSearchReference {
+    type Resource = resources::Appointment;
+    type Target = AppointmentActorSearchTarget;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::Actor
+    }
+}
+/// Target resources of the actor reference search parameter of the Appointment resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AppointmentActorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Practitioner> for AppointmentActorSearchTarget {
+    fn from(resource: resources::Practitioner) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Device> for AppointmentActorSearchTarget {
+    fn from(resource: resources::Device) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for AppointmentActorSearchTarget {
+    fn from(resource: resources::Patient) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::HealthcareService> for AppointmentActorSearchTarget {
+    fn from(resource: resources::HealthcareService) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::PractitionerRole> for AppointmentActorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for AppointmentActorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::Location> for AppointmentActorSearchTarget {
+    fn from(resource: resources::Location) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::Location(resource)
+    }
+}
+/// The based-on reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentBasedOnSearchReference;
+impl ReferenceSearchParameter for AppointmentBasedOnSearchReference {
+    type Resource = resources::Appointment;
+    type Target = resources::ServiceRequest;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::BasedOn
+    }
+}
+/// The location reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentLocationSearchReference;
+impl ReferenceSearchParameter for AppointmentLocationSearchReference {
+    type Resource = resources::Appointment;
+    type Target = resources::Location;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::Location
+    }
+}
+/// The patient reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentPatientSearchReference;
+impl ReferenceSearchParameter for AppointmentPatientSearchReference {
+    type Resource = resources::Appointment;
+    type Target = resources::Patient;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::Patient
+    }
+}
+/// The practitioner reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentPractitionerSearchReference;
+impl ReferenceSearchParameter for AppointmentPractitionerSearchReference {
+    type Resource = resources::Appointment;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::Practitioner
+    }
+}
+/// The reason-reference reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentReasonReferenceSearchReference;
+impl ReferenceSearchParameter for AppointmentReasonReferenceSearchReference {
+    type Resource = resources::Appointment;
+    type Target = AppointmentReasonReferenceSearchTarget;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::ReasonReference
+    }
+}
+/// Target resources of the reason-reference reference search parameter of the Appointment resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AppointmentReasonReferenceSearchTarget {
+    /// Variant for Condition target resources
+    Condition(resources::Condition),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for ImmunizationRecommendation target resources
+    ImmunizationRecommendation(resources::ImmunizationRecommendation),
+}
+impl From<resources::Condition> for AppointmentReasonReferenceSearchTarget {
+    fn from(resource: resources::Condition) -> AppointmentReasonReferenceSearchTarget {
+        AppointmentReasonReferenceSearchTarget::Condition(resource)
+    }
+}
+impl From<resources::Observation> for AppointmentReasonReferenceSearchTarget {
+    fn from(resource: resources::Observation) -> AppointmentReasonReferenceSearchTarget {
+        AppointmentReasonReferenceSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::Procedure> for AppointmentReasonReferenceSearchTarget {
+    fn from(resource: resources::Procedure) -> AppointmentReasonReferenceSearchTarget {
+        AppointmentReasonReferenceSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::ImmunizationRecommendation> for AppointmentReasonReferenceSearchTarget {
+    fn from(resource: resources::ImmunizationRecommendation) -> AppointmentReasonReferenceSearchTarget {
+        AppointmentReasonReferenceSearchTarget::ImmunizationRecommendation(resource)
+    }
+}
+/// The slot reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentSlotSearchReference;
+impl ReferenceSearchParameter for AppointmentSlotSearchReference {
+    type Resource = resources::Appointment;
+    type Target = resources::Slot;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::Slot
+    }
+}
+/// The supporting-info reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentSupportingInfoSearchReference;
+impl ReferenceSearchParameter for AppointmentSupportingInfoSearchReference {
+    type Resource = resources::Appointment;
+    type Target = resources::Resource;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::SupportingInfo
+    }
+}
+/// The actor reference search parameter of the AppointmentResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentResponseActorSearchReference;
+impl ReferenceSearchParameter for AppointmentResponseActorSearchReference {
+    type Resource = resources::AppointmentResponse;
+    type Target = AppointmentResponseActorSearchTarget;
+    fn parameter(&self) -> AppointmentResponseSearchParameter {
+        AppointmentResponseSearchParameter::Actor
+    }
+}
+/// Target resources of the actor reference search parameter of the AppointmentResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AppointmentResponseActorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Practitioner> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::Practitioner) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Device> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::Device) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::Patient) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::HealthcareService> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::HealthcareService) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::PractitionerRole> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::Location> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::Location) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::Location(resource)
+    }
+}
+/// The appointment reference search parameter of the AppointmentResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentResponseAppointmentSearchReference;
+impl ReferenceSearchParameter for AppointmentResponseAppointmentSearchReference {
+    type Resource = resources::AppointmentResponse;
+    type Target = resources::Appointment;
+    fn parameter(&self) -> AppointmentResponseSearchParameter {
+        AppointmentResponseSearchParameter::Appointment
+    }
+}
+/// The location reference search parameter of the AppointmentResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentResponseLocationSearchReference;
+impl ReferenceSearchParameter for AppointmentResponseLocationSearchReference {
+    type Resource = resources::AppointmentResponse;
+    type Target = resources::Location;
+    fn parameter(&self) -> AppointmentResponseSearchParameter {
+        AppointmentResponseSearchParameter::Location
+    }
+}
+/// The patient reference search parameter of the AppointmentResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentResponsePatientSearchReference;
+impl ReferenceSearchParameter for AppointmentResponsePatientSearchReference {
+    type Resource = resources::AppointmentResponse;
+    type Target = resources::Patient;
+    fn parameter(&self) -> AppointmentResponseSearchParameter {
+        AppointmentResponseSearchParameter::Patient
+    }
+}
+/// The practitioner reference search parameter of the AppointmentResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentResponsePractitionerSearchReference;
+impl ReferenceSearchParameter for AppointmentResponsePractitionerSearchReference {
+    type Resource = resources::AppointmentResponse;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> AppointmentResponseSearchParameter {
+        AppointmentResponseSearchParameter::Practitioner
+    }
+}
+/// The agent reference search parameter of the AuditEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AuditEventAgentSearchReference;
+impl ReferenceSearchParameter for AuditEventAgentSearchReference {
+    type Resource = resources::AuditEvent;
+    type Target = AuditEventAgentSearchTarget;
+    fn parameter(&self) -> AuditEventSearchParameter {
+        AuditEventSearchParameter::Agent
+    }
+}
+/// Target resources of the agent reference search parameter of the AuditEvent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AuditEventAgentSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for AuditEventAgentSearchTarget {
+    fn from(resource: resources::Practitioner) -> AuditEventAgentSearchTarget {
+        AuditEventAgentSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for AuditEventAgentSearchTarget {
+    fn from(resource: resources::Organization) -> AuditEventAgentSearchTarget {
+        AuditEventAgentSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for AuditEventAgentSearchTarget {
+    fn from(resource: resources::Device) -> AuditEventAgentSearchTarget {
+        AuditEventAgentSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for AuditEventAgentSearchTarget {
+    fn from(resource: resources::Patient) -> AuditEventAgentSearchTarget {
+        AuditEventAgentSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for AuditEventAgentSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> AuditEventAgentSearchTarget {
+        AuditEventAgentSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for AuditEventAgentSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AuditEventAgentSearchTarget {
+        AuditEventAgentSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The entity reference search parameter of the AuditEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AuditEventEntitySearchReference;
+impl ReferenceSearchParameter for AuditEventEntitySearchReference {
+    type Resource = resources::AuditEvent;
+    type Target = resources::Resource;
+    fn parameter(&self) -> AuditEventSearchParameter {
+        AuditEventSearchParameter::Entity
+    }
+}
+/// The patient reference search parameter of the AuditEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AuditEventPatientSearchReference;
+impl ReferenceSearchParameter for AuditEventPatientSearchReference {
+    type Resource = resources::AuditEvent;
+    type Target = resources::Patient;
+    fn parameter(&self) -> AuditEventSearchParameter {
+        AuditEventSearchParameter::Patient
+    }
+}
+/// The source reference search parameter of the AuditEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AuditEventSourceSearchReference;
+impl ReferenceSearchParameter for AuditEventSourceSearchReference {
+    type Resource = resources::AuditEvent;
+    type Target = AuditEventSourceSearchTarget;
+    fn parameter(&self) -> AuditEventSearchParameter {
+        AuditEventSearchParameter::Source
+    }
+}
+/// Target resources of the source reference search parameter of the AuditEvent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AuditEventSourceSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for AuditEventSourceSearchTarget {
+    fn from(resource: resources::Practitioner) -> AuditEventSourceSearchTarget {
+        AuditEventSourceSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for AuditEventSourceSearchTarget {
+    fn from(resource: resources::Organization) -> AuditEventSourceSearchTarget {
+        AuditEventSourceSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for AuditEventSourceSearchTarget {
+    fn from(resource: resources::Device) -> AuditEventSourceSearchTarget {
+        AuditEventSourceSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for AuditEventSourceSearchTarget {
+    fn from(resource: resources::Patient) -> AuditEventSourceSearchTarget {
+        AuditEventSourceSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for AuditEventSourceSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> AuditEventSourceSearchTarget {
+        AuditEventSourceSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for AuditEventSourceSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AuditEventSourceSearchTarget {
+        AuditEventSourceSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The author reference search parameter of the Basic resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BasicAuthorSearchReference;
+impl ReferenceSearchParameter for BasicAuthorSearchReference {
+    type Resource = resources::Basic;
+    type Target = BasicAuthorSearchTarget;
+    fn parameter(&self) -> BasicSearchParameter {
+        BasicSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the Basic resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BasicAuthorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for BasicAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> BasicAuthorSearchTarget {
+        BasicAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for BasicAuthorSearchTarget {
+    fn from(resource: resources::Organization) -> BasicAuthorSearchTarget {
+        BasicAuthorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for BasicAuthorSearchTarget {
+    fn from(resource: resources::Patient) -> BasicAuthorSearchTarget {
+        BasicAuthorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for BasicAuthorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> BasicAuthorSearchTarget {
+        BasicAuthorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for BasicAuthorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> BasicAuthorSearchTarget {
+        BasicAuthorSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The patient reference search parameter of the Basic resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BasicPatientSearchReference;
+impl ReferenceSearchParameter for BasicPatientSearchReference {
+    type Resource = resources::Basic;
+    type Target = resources::Patient;
+    fn parameter(&self) -> BasicSearchParameter {
+        BasicSearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the Basic resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BasicSubjectSearchReference;
+impl ReferenceSearchParameter for BasicSubjectSearchReference {
+    type Resource = resources::Basic;
+    type Target = resources::Resource;
+    fn parameter(&self) -> BasicSearchParameter {
+        BasicSearchParameter::Subject
+    }
+}
+/// The patient reference search parameter of the BodyStructure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BodyStructurePatientSearchReference;
+impl ReferenceSearchParameter for BodyStructurePatientSearchReference {
+    type Resource = resources::BodyStructure;
+    type Target = resources::Patient;
+    fn parameter(&self) -> BodyStructureSearchParameter {
+        BodyStructureSearchParameter::Patient
+    }
+}
+/// The composition reference search parameter of the Bundle resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BundleCompositionSearchReference;
+impl ReferenceSearchParameter for BundleCompositionSearchReference {
+    type Resource = resources::Bundle;
+    type Target = resources::Resource;
+    fn parameter(&self) -> BundleSearchParameter {
+        BundleSearchParameter::Composition
+    }
+}
+/// The message reference search parameter of the Bundle resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BundleMessageSearchReference;
+impl ReferenceSearchParameter for BundleMessageSearchReference {
+    type Resource = resources::Bundle;
+    type Target = resources::Resource;
+    fn parameter(&self) -> BundleSearchParameter {
+        BundleSearchParameter::Message
+    }
+}
+/// The guide reference search parameter of the CapabilityStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CapabilityStatementGuideSearchReference;
+impl ReferenceSearchParameter for CapabilityStatementGuideSearchReference {
+    type Resource = resources::CapabilityStatement;
+    type Target = resources::ImplementationGuide;
+    fn parameter(&self) -> CapabilityStatementSearchParameter {
+        CapabilityStatementSearchParameter::Guide
+    }
+}
+/// The resource-profile reference search parameter of the CapabilityStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CapabilityStatementResourceProfileSearchReference;
+impl ReferenceSearchParameter for CapabilityStatementResourceProfileSearchReference {
+    type Resource = resources::CapabilityStatement;
+    type Target = resources::StructureDefinition;
+    fn parameter(&self) -> CapabilityStatementSearchParameter {
+        CapabilityStatementSearchParameter::ResourceProfile
+    }
+}
+/// The supported-profile reference search parameter of the CapabilityStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CapabilityStatementSupportedProfileSearchReference;
+impl ReferenceSearchParameter for CapabilityStatementSupportedProfileSearchReference {
+    type Resource = resources::CapabilityStatement;
+    type Target = resources::StructureDefinition;
+    fn parameter(&self) -> CapabilityStatementSearchParameter {
+        CapabilityStatementSearchParameter::SupportedProfile
+    }
+}
+/// The patient reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanPatientSearchReference;
+impl ReferenceSearchParameter for CarePlanPatientSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Patient
+    }
+}
+/// The activity-reference reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanActivityReferenceSearchReference;
+impl ReferenceSearchParameter for CarePlanActivityReferenceSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = CarePlanActivityReferenceSearchTarget;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::ActivityReference
+    }
+}
+/// Target resources of the activity-reference reference search parameter of the CarePlan resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CarePlanActivityReferenceSearchTarget {
+    /// Variant for Appointment target resources
+    Appointment(resources::Appointment),
+    /// Variant for MedicationRequest target resources
+    MedicationRequest(resources::MedicationRequest),
+    /// Variant for Task target resources
+    Task(resources::Task),
+    /// Variant for NutritionOrder target resources
+    NutritionOrder(resources::NutritionOrder),
+    /// Variant for RequestGroup target resources
+    RequestGroup(resources::RequestGroup),
+    /// Variant for VisionPrescription target resources
+    VisionPrescription(resources::VisionPrescription),
+    /// Variant for DeviceRequest target resources
+    DeviceRequest(resources::DeviceRequest),
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+    /// Variant for CommunicationRequest target resources
+    CommunicationRequest(resources::CommunicationRequest),
+}
+impl From<resources::Appointment> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::Appointment) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::Appointment(resource)
+    }
+}
+impl From<resources::MedicationRequest> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::MedicationRequest) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::MedicationRequest(resource)
+    }
+}
+impl From<resources::Task> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::Task) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::Task(resource)
+    }
+}
+impl From<resources::NutritionOrder> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::NutritionOrder) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::NutritionOrder(resource)
+    }
+}
+impl From<resources::RequestGroup> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::RequestGroup) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::RequestGroup(resource)
+    }
+}
+impl From<resources::VisionPrescription> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::VisionPrescription) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::VisionPrescription(resource)
+    }
+}
+impl From<resources::DeviceRequest> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::DeviceRequest) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::DeviceRequest(resource)
+    }
+}
+impl From<resources::ServiceRequest> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::ServiceRequest(resource)
+    }
+}
+impl From<resources::CommunicationRequest> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::CommunicationRequest) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::CommunicationRequest(resource)
+    }
+}
+/// The based-on reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanBasedOnSearchReference;
+impl ReferenceSearchParameter for CarePlanBasedOnSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::CarePlan;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::BasedOn
+    }
+}
+/// The care-team reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanCareTeamSearchReference;
+impl ReferenceSearchParameter for CarePlanCareTeamSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::CareTeam;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::CareTeam
+    }
+}
+/// The condition reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanConditionSearchReference;
+impl ReferenceSearchParameter for CarePlanConditionSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::Condition;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Condition
+    }
+}
+/// The encounter reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanEncounterSearchReference;
+impl ReferenceSearchParameter for CarePlanEncounterSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Encounter
+    }
+}
+/// The goal reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanGoalSearchReference;
+impl ReferenceSearchParameter for CarePlanGoalSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::Goal;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Goal
+    }
+}
+/// The instantiates-canonical reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanInstantiatesCanonicalSearchReference;
+impl ReferenceSearchParameter for CarePlanInstantiatesCanonicalSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = CarePlanInstantiatesCanonicalSearchTarget;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::InstantiatesCanonical
+    }
+}
+/// Target resources of the instantiates-canonical reference search parameter of the CarePlan resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CarePlanInstantiatesCanonicalSearchTarget {
+    /// Variant for Questionnaire target resources
+    Questionnaire(resources::Questionnaire),
+    /// Variant for Measure target resources
+    Measure(resources::Measure),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for OperationDefinition target resources
+    OperationDefinition(resources::OperationDefinition),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+}
+impl From<resources::Questionnaire> for CarePlanInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Questionnaire) -> CarePlanInstantiatesCanonicalSearchTarget {
+        CarePlanInstantiatesCanonicalSearchTarget::Questionnaire(resource)
+    }
+}
+impl From<resources::Measure> for CarePlanInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Measure) -> CarePlanInstantiatesCanonicalSearchTarget {
+        CarePlanInstantiatesCanonicalSearchTarget::Measure(resource)
+    }
+}
+impl From<resources::PlanDefinition> for CarePlanInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> CarePlanInstantiatesCanonicalSearchTarget {
+        CarePlanInstantiatesCanonicalSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::OperationDefinition> for CarePlanInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::OperationDefinition) -> CarePlanInstantiatesCanonicalSearchTarget {
+        CarePlanInstantiatesCanonicalSearchTarget::OperationDefinition(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for CarePlanInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> CarePlanInstantiatesCanonicalSearchTarget {
+        CarePlanInstantiatesCanonicalSearchTarget::ActivityDefinition(resource)
+    }
+}
+/// The part-of reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanPartOfSearchReference;
+impl ReferenceSearchParameter for CarePlanPartOfSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::CarePlan;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::PartOf
+    }
+}
+/// The performer reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanPerformerSearchReference;
+impl ReferenceSearchParameter for CarePlanPerformerSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = CarePlanPerformerSearchTarget;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the CarePlan resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CarePlanPerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for CarePlanPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> CarePlanPerformerSearchTarget {
+        CarePlanPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for CarePlanPerformerSearchTarget {
+    fn from(resource: resources::Organization) -> CarePlanPerformerSearchTarget {
+        CarePlanPerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for CarePlanPerformerSearchTarget {
+    fn from(resource: resources::CareTeam) -> CarePlanPerformerSearchTarget {
+        CarePlanPerformerSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for CarePlanPerformerSearchTarget {
+    fn from(resource: resources::Device) -> CarePlanPerformerSearchTarget {
+        CarePlanPerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for CarePlanPerformerSearchTarget {
+    fn from(resource: resources::Patient) -> CarePlanPerformerSearchTarget {
+        CarePlanPerformerSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::HealthcareService> for CarePlanPerformerSearchTarget {
+    fn from(resource: resources::HealthcareService) -> CarePlanPerformerSearchTarget {
+        CarePlanPerformerSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CarePlanPerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CarePlanPerformerSearchTarget {
+        CarePlanPerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CarePlanPerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CarePlanPerformerSearchTarget {
+        CarePlanPerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The replaces reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanReplacesSearchReference;
+impl ReferenceSearchParameter for CarePlanReplacesSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::CarePlan;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Replaces
+    }
+}
+/// The subject reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanSubjectSearchReference;
+impl ReferenceSearchParameter for CarePlanSubjectSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = CarePlanSubjectSearchTarget;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the CarePlan resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CarePlanSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for CarePlanSubjectSearchTarget {
+    fn from(resource: resources::Group) -> CarePlanSubjectSearchTarget {
+        CarePlanSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for CarePlanSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> CarePlanSubjectSearchTarget {
+        CarePlanSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the CareTeam resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CareTeamPatientSearchReference;
+impl ReferenceSearchParameter for CareTeamPatientSearchReference {
+    type Resource = resources::CareTeam;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CareTeamSearchParameter {
+        CareTeamSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the CareTeam resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CareTeamEncounterSearchReference;
+impl ReferenceSearchParameter for CareTeamEncounterSearchReference {
+    type Resource = resources::CareTeam;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> CareTeamSearchParameter {
+        CareTeamSearchParameter::Encounter
+    }
+}
+/// The participant reference search parameter of the CareTeam resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CareTeamParticipantSearchReference;
+impl ReferenceSearchParameter for CareTeamParticipantSearchReference {
+    type Resource = resources::CareTeam;
+    type Target = CareTeamParticipantSearchTarget;
+    fn parameter(&self) -> CareTeamSearchParameter {
+        CareTeamSearchParameter::Participant
+    }
+}
+/// Target resources of the participant reference search parameter of the CareTeam resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CareTeamParticipantSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for CareTeamParticipantSearchTarget {
+    fn from(resource: resources::Practitioner) -> CareTeamParticipantSearchTarget {
+        CareTeamParticipantSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for CareTeamParticipantSearchTarget {
+    fn from(resource: resources::Organization) -> CareTeamParticipantSearchTarget {
+        CareTeamParticipantSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for CareTeamParticipantSearchTarget {
+    fn from(resource: resources::CareTeam) -> CareTeamParticipantSearchTarget {
+        CareTeamParticipantSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Patient> for CareTeamParticipantSearchTarget {
+    fn from(resource: resources::Patient) -> CareTeamParticipantSearchTarget {
+        CareTeamParticipantSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CareTeamParticipantSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CareTeamParticipantSearchTarget {
+        CareTeamParticipantSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CareTeamParticipantSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CareTeamParticipantSearchTarget {
+        CareTeamParticipantSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subject reference search parameter of the CareTeam resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CareTeamSubjectSearchReference;
+impl ReferenceSearchParameter for CareTeamSubjectSearchReference {
+    type Resource = resources::CareTeam;
+    type Target = CareTeamSubjectSearchTarget;
+    fn parameter(&self) -> CareTeamSearchParameter {
+        CareTeamSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the CareTeam resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CareTeamSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for CareTeamSubjectSearchTarget {
+    fn from(resource: resources::Group) -> CareTeamSubjectSearchTarget {
+        CareTeamSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for CareTeamSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> CareTeamSubjectSearchTarget {
+        CareTeamSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The account reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemAccountSearchReference;
+impl ReferenceSearchParameter for ChargeItemAccountSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = resources::Account;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::Account
+    }
+}
+/// The context reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemContextSearchReference;
+impl ReferenceSearchParameter for ChargeItemContextSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = ChargeItemContextSearchTarget;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the ChargeItem resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChargeItemContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for ChargeItemContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> ChargeItemContextSearchTarget {
+        ChargeItemContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for ChargeItemContextSearchTarget {
+    fn from(resource: resources::Encounter) -> ChargeItemContextSearchTarget {
+        ChargeItemContextSearchTarget::Encounter(resource)
+    }
+}
+/// The enterer reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemEntererSearchReference;
+impl ReferenceSearchParameter for ChargeItemEntererSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = ChargeItemEntererSearchTarget;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::Enterer
+    }
+}
+/// Target resources of the enterer reference search parameter of the ChargeItem resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChargeItemEntererSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ChargeItemEntererSearchTarget {
+    fn from(resource: resources::Practitioner) -> ChargeItemEntererSearchTarget {
+        ChargeItemEntererSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ChargeItemEntererSearchTarget {
+    fn from(resource: resources::Organization) -> ChargeItemEntererSearchTarget {
+        ChargeItemEntererSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for ChargeItemEntererSearchTarget {
+    fn from(resource: resources::Device) -> ChargeItemEntererSearchTarget {
+        ChargeItemEntererSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ChargeItemEntererSearchTarget {
+    fn from(resource: resources::Patient) -> ChargeItemEntererSearchTarget {
+        ChargeItemEntererSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ChargeItemEntererSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ChargeItemEntererSearchTarget {
+        ChargeItemEntererSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ChargeItemEntererSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ChargeItemEntererSearchTarget {
+        ChargeItemEntererSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The patient reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemPatientSearchReference;
+impl ReferenceSearchParameter for ChargeItemPatientSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::Patient
+    }
+}
+/// The performer-actor reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemPerformerActorSearchReference;
+impl ReferenceSearchParameter for ChargeItemPerformerActorSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = ChargeItemPerformerActorSearchTarget;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::PerformerActor
+    }
+}
+/// Target resources of the performer-actor reference search parameter of the ChargeItem resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChargeItemPerformerActorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ChargeItemPerformerActorSearchTarget {
+    fn from(resource: resources::Practitioner) -> ChargeItemPerformerActorSearchTarget {
+        ChargeItemPerformerActorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ChargeItemPerformerActorSearchTarget {
+    fn from(resource: resources::Organization) -> ChargeItemPerformerActorSearchTarget {
+        ChargeItemPerformerActorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for ChargeItemPerformerActorSearchTarget {
+    fn from(resource: resources::CareTeam) -> ChargeItemPerformerActorSearchTarget {
+        ChargeItemPerformerActorSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for ChargeItemPerformerActorSearchTarget {
+    fn from(resource: resources::Device) -> ChargeItemPerformerActorSearchTarget {
+        ChargeItemPerformerActorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ChargeItemPerformerActorSearchTarget {
+    fn from(resource: resources::Patient) -> ChargeItemPerformerActorSearchTarget {
+        ChargeItemPerformerActorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ChargeItemPerformerActorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ChargeItemPerformerActorSearchTarget {
+        ChargeItemPerformerActorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ChargeItemPerformerActorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ChargeItemPerformerActorSearchTarget {
+        ChargeItemPerformerActorSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The performing-organization reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemPerformingOrganizationSearchReference;
+impl ReferenceSearchParameter for ChargeItemPerformingOrganizationSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::PerformingOrganization
+    }
+}
+/// The requesting-organization reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemRequestingOrganizationSearchReference;
+impl ReferenceSearchParameter for ChargeItemRequestingOrganizationSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::RequestingOrganization
+    }
+}
+/// The service reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemServiceSearchReference;
+impl ReferenceSearchParameter for ChargeItemServiceSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = ChargeItemServiceSearchTarget;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::Service
+    }
+}
+/// Target resources of the service reference search parameter of the ChargeItem resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChargeItemServiceSearchTarget {
+    /// Variant for Immunization target resources
+    Immunization(resources::Immunization),
+    /// Variant for MedicationDispense target resources
+    MedicationDispense(resources::MedicationDispense),
+    /// Variant for SupplyDelivery target resources
+    SupplyDelivery(resources::SupplyDelivery),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for DiagnosticReport target resources
+    DiagnosticReport(resources::DiagnosticReport),
+    /// Variant for ImagingStudy target resources
+    ImagingStudy(resources::ImagingStudy),
+    /// Variant for MedicationAdministration target resources
+    MedicationAdministration(resources::MedicationAdministration),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+}
+impl From<resources::Immunization> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::Immunization) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::Immunization(resource)
+    }
+}
+impl From<resources::MedicationDispense> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::MedicationDispense) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::MedicationDispense(resource)
+    }
+}
+impl From<resources::SupplyDelivery> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::SupplyDelivery) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::SupplyDelivery(resource)
+    }
+}
+impl From<resources::Observation> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::Observation) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::DiagnosticReport> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::DiagnosticReport) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::DiagnosticReport(resource)
+    }
+}
+impl From<resources::ImagingStudy> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::ImagingStudy) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::ImagingStudy(resource)
+    }
+}
+impl From<resources::MedicationAdministration> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::MedicationAdministration) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::MedicationAdministration(resource)
+    }
+}
+impl From<resources::Procedure> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::Procedure) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::Procedure(resource)
+    }
+}
+/// The subject reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemSubjectSearchReference;
+impl ReferenceSearchParameter for ChargeItemSubjectSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = ChargeItemSubjectSearchTarget;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the ChargeItem resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChargeItemSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for ChargeItemSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ChargeItemSubjectSearchTarget {
+        ChargeItemSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for ChargeItemSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ChargeItemSubjectSearchTarget {
+        ChargeItemSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The care-team reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimCareTeamSearchReference;
+impl ReferenceSearchParameter for ClaimCareTeamSearchReference {
+    type Resource = resources::Claim;
+    type Target = ClaimCareTeamSearchTarget;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::CareTeam
+    }
+}
+/// Target resources of the care-team reference search parameter of the Claim resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClaimCareTeamSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for ClaimCareTeamSearchTarget {
+    fn from(resource: resources::Practitioner) -> ClaimCareTeamSearchTarget {
+        ClaimCareTeamSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ClaimCareTeamSearchTarget {
+    fn from(resource: resources::Organization) -> ClaimCareTeamSearchTarget {
+        ClaimCareTeamSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ClaimCareTeamSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ClaimCareTeamSearchTarget {
+        ClaimCareTeamSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The detail-udi reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimDetailUdiSearchReference;
+impl ReferenceSearchParameter for ClaimDetailUdiSearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Device;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::DetailUdi
+    }
+}
+/// The encounter reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimEncounterSearchReference;
+impl ReferenceSearchParameter for ClaimEncounterSearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Encounter
+    }
+}
+/// The enterer reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimEntererSearchReference;
+impl ReferenceSearchParameter for ClaimEntererSearchReference {
+    type Resource = resources::Claim;
+    type Target = ClaimEntererSearchTarget;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Enterer
+    }
+}
+/// Target resources of the enterer reference search parameter of the Claim resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClaimEntererSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for ClaimEntererSearchTarget {
+    fn from(resource: resources::Practitioner) -> ClaimEntererSearchTarget {
+        ClaimEntererSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ClaimEntererSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ClaimEntererSearchTarget {
+        ClaimEntererSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The facility reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimFacilitySearchReference;
+impl ReferenceSearchParameter for ClaimFacilitySearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Location;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Facility
+    }
+}
+/// The insurer reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimInsurerSearchReference;
+impl ReferenceSearchParameter for ClaimInsurerSearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Insurer
+    }
+}
+/// The item-udi reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimItemUdiSearchReference;
+impl ReferenceSearchParameter for ClaimItemUdiSearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Device;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::ItemUdi
+    }
+}
+/// The patient reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimPatientSearchReference;
+impl ReferenceSearchParameter for ClaimPatientSearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Patient
+    }
+}
+/// The payee reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimPayeeSearchReference;
+impl ReferenceSearchParameter for ClaimPayeeSearchReference {
+    type Resource = resources::Claim;
+    type Target = ClaimPayeeSearchTarget;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Payee
+    }
+}
+/// Target resources of the payee reference search parameter of the Claim resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClaimPayeeSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ClaimPayeeSearchTarget {
+    fn from(resource: resources::Practitioner) -> ClaimPayeeSearchTarget {
+        ClaimPayeeSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ClaimPayeeSearchTarget {
+    fn from(resource: resources::Organization) -> ClaimPayeeSearchTarget {
+        ClaimPayeeSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for ClaimPayeeSearchTarget {
+    fn from(resource: resources::Patient) -> ClaimPayeeSearchTarget {
+        ClaimPayeeSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ClaimPayeeSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ClaimPayeeSearchTarget {
+        ClaimPayeeSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ClaimPayeeSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ClaimPayeeSearchTarget {
+        ClaimPayeeSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The procedure-udi reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimProcedureUdiSearchReference;
+impl ReferenceSearchParameter for ClaimProcedureUdiSearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Device;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::ProcedureUdi
+    }
+}
+/// The provider reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimProviderSearchReference;
+impl ReferenceSearchParameter for ClaimProviderSearchReference {
+    type Resource = resources::Claim;
+    type Target = ClaimProviderSearchTarget;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Provider
+    }
+}
+/// Target resources of the provider reference search parameter of the Claim resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClaimProviderSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for ClaimProviderSearchTarget {
+    fn from(resource: resources::Practitioner) -> ClaimProviderSearchTarget {
+        ClaimProviderSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ClaimProviderSearchTarget {
+    fn from(resource: resources::Organization) -> ClaimProviderSearchTarget {
+        ClaimProviderSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ClaimProviderSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ClaimProviderSearchTarget {
+        ClaimProviderSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The subdetail-udi reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimSubdetailUdiSearchReference;
+impl ReferenceSearchParameter for ClaimSubdetailUdiSearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Device;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::SubdetailUdi
+    }
+}
+/// The insurer reference search parameter of the ClaimResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimResponseInsurerSearchReference;
+impl ReferenceSearchParameter for ClaimResponseInsurerSearchReference {
+    type Resource = resources::ClaimResponse;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ClaimResponseSearchParameter {
+        ClaimResponseSearchParameter::Insurer
+    }
+}
+/// The patient reference search parameter of the ClaimResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimResponsePatientSearchReference;
+impl ReferenceSearchParameter for ClaimResponsePatientSearchReference {
+    type Resource = resources::ClaimResponse;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ClaimResponseSearchParameter {
+        ClaimResponseSearchParameter::Patient
+    }
+}
+/// The request reference search parameter of the ClaimResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimResponseRequestSearchReference;
+impl ReferenceSearchParameter for ClaimResponseRequestSearchReference {
+    type Resource = resources::ClaimResponse;
+    type Target = resources::Claim;
+    fn parameter(&self) -> ClaimResponseSearchParameter {
+        ClaimResponseSearchParameter::Request
+    }
+}
+/// The requestor reference search parameter of the ClaimResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimResponseRequestorSearchReference;
+impl ReferenceSearchParameter for ClaimResponseRequestorSearchReference {
+    type Resource = resources::ClaimResponse;
+    type Target = ClaimResponseRequestorSearchTarget;
+    fn parameter(&self) -> ClaimResponseSearchParameter {
+        ClaimResponseSearchParameter::Requestor
+    }
+}
+/// Target resources of the requestor reference search parameter of the ClaimResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClaimResponseRequestorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for ClaimResponseRequestorSearchTarget {
+    fn from(resource: resources::Practitioner) -> ClaimResponseRequestorSearchTarget {
+        ClaimResponseRequestorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ClaimResponseRequestorSearchTarget {
+    fn from(resource: resources::Organization) -> ClaimResponseRequestorSearchTarget {
+        ClaimResponseRequestorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ClaimResponseRequestorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ClaimResponseRequestorSearchTarget {
+        ClaimResponseRequestorSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The patient reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionPatientSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionPatientSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Patient
+    }
+}
+/// The assessor reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionAssessorSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionAssessorSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = ClinicalImpressionAssessorSearchTarget;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Assessor
+    }
+}
+/// Target resources of the assessor reference search parameter of the ClinicalImpression resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClinicalImpressionAssessorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for ClinicalImpressionAssessorSearchTarget {
+    fn from(resource: resources::Practitioner) -> ClinicalImpressionAssessorSearchTarget {
+        ClinicalImpressionAssessorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ClinicalImpressionAssessorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ClinicalImpressionAssessorSearchTarget {
+        ClinicalImpressionAssessorSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The encounter reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionEncounterSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionEncounterSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Encounter
+    }
+}
+/// The finding-ref reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionFindingRefSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionFindingRefSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = ClinicalImpressionFindingRefSearchTarget;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::FindingRef
+    }
+}
+/// Target resources of the finding-ref reference search parameter of the ClinicalImpression resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClinicalImpressionFindingRefSearchTarget {
+    /// Variant for Condition target resources
+    Condition(resources::Condition),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for Media target resources
+    Media(resources::Media),
+}
+impl From<resources::Condition> for ClinicalImpressionFindingRefSearchTarget {
+    fn from(resource: resources::Condition) -> ClinicalImpressionFindingRefSearchTarget {
+        ClinicalImpressionFindingRefSearchTarget::Condition(resource)
+    }
+}
+impl From<resources::Observation> for ClinicalImpressionFindingRefSearchTarget {
+    fn from(resource: resources::Observation) -> ClinicalImpressionFindingRefSearchTarget {
+        ClinicalImpressionFindingRefSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::Media> for ClinicalImpressionFindingRefSearchTarget {
+    fn from(resource: resources::Media) -> ClinicalImpressionFindingRefSearchTarget {
+        ClinicalImpressionFindingRefSearchTarget::Media(resource)
+    }
+}
+/// The investigation reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionInvestigationSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionInvestigationSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = ClinicalImpressionInvestigationSearchTarget;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Investigation
+    }
+}
+/// Target resources of the investigation reference search parameter of the ClinicalImpression resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClinicalImpressionInvestigationSearchTarget {
+    /// Variant for RiskAssessment target resources
+    RiskAssessment(resources::RiskAssessment),
+    /// Variant for FamilyMemberHistory target resources
+    FamilyMemberHistory(resources::FamilyMemberHistory),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for Media target resources
+    Media(resources::Media),
+    /// Variant for DiagnosticReport target resources
+    DiagnosticReport(resources::DiagnosticReport),
+    /// Variant for ImagingStudy target resources
+    ImagingStudy(resources::ImagingStudy),
+    /// Variant for QuestionnaireResponse target resources
+    QuestionnaireResponse(resources::QuestionnaireResponse),
+}
+impl From<resources::RiskAssessment> for ClinicalImpressionInvestigationSearchTarget {
+    fn from(resource: resources::RiskAssessment) -> ClinicalImpressionInvestigationSearchTarget {
+        ClinicalImpressionInvestigationSearchTarget::RiskAssessment(resource)
+    }
+}
+impl From<resources::FamilyMemberHistory> for ClinicalImpressionInvestigationSearchTarget {
+    fn from(resource: resources::FamilyMemberHistory) -> ClinicalImpressionInvestigationSearchTarget {
+        ClinicalImpressionInvestigationSearchTarget::FamilyMemberHistory(resource)
+    }
+}
+impl From<resources::Observation> for ClinicalImpressionInvestigationSearchTarget {
+    fn from(resource: resources::Observation) -> ClinicalImpressionInvestigationSearchTarget {
+        ClinicalImpressionInvestigationSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::Media> for ClinicalImpressionInvestigationSearchTarget {
+    fn from(resource: resources::Media) -> ClinicalImpressionInvestigationSearchTarget {
+        ClinicalImpressionInvestigationSearchTarget::Media(resource)
+    }
+}
+impl From<resources::DiagnosticReport> for ClinicalImpressionInvestigationSearchTarget {
+    fn from(resource: resources::DiagnosticReport) -> ClinicalImpressionInvestigationSearchTarget {
+        ClinicalImpressionInvestigationSearchTarget::DiagnosticReport(resource)
+    }
+}
+impl From<resources::ImagingStudy> for ClinicalImpressionInvestigationSearchTarget {
+    fn from(resource: resources::ImagingStudy) -> ClinicalImpressionInvestigationSearchTarget {
+        ClinicalImpressionInvestigationSearchTarget::ImagingStudy(resource)
+    }
+}
+impl From<resources::QuestionnaireResponse> for ClinicalImpressionInvestigationSearchTarget {
+    fn from(resource: resources::QuestionnaireResponse) -> ClinicalImpressionInvestigationSearchTarget {
+        ClinicalImpressionInvestigationSearchTarget::QuestionnaireResponse(resource)
+    }
+}
+/// The previous reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionPreviousSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionPreviousSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = resources::ClinicalImpression;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Previous
+    }
+}
+/// The problem reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionProblemSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionProblemSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = ClinicalImpressionProblemSearchTarget;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Problem
+    }
+}
+/// Target resources of the problem reference search parameter of the ClinicalImpression resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClinicalImpressionProblemSearchTarget {
+    /// Variant for Condition target resources
+    Condition(resources::Condition),
+    /// Variant for AllergyIntolerance target resources
+    AllergyIntolerance(resources::AllergyIntolerance),
+}
+impl From<resources::Condition> for ClinicalImpressionProblemSearchTarget {
+    fn from(resource: resources::Condition) -> ClinicalImpressionProblemSearchTarget {
+        ClinicalImpressionProblemSearchTarget::Condition(resource)
+    }
+}
+impl From<resources::AllergyIntolerance> for ClinicalImpressionProblemSearchTarget {
+    fn from(resource: resources::AllergyIntolerance) -> ClinicalImpressionProblemSearchTarget {
+        ClinicalImpressionProblemSearchTarget::AllergyIntolerance(resource)
+    }
+}
+/// The subject reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionSubjectSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionSubjectSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = ClinicalImpressionSubjectSearchTarget;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the ClinicalImpression resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClinicalImpressionSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for ClinicalImpressionSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ClinicalImpressionSubjectSearchTarget {
+        ClinicalImpressionSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for ClinicalImpressionSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ClinicalImpressionSubjectSearchTarget {
+        ClinicalImpressionSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The supporting-info reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionSupportingInfoSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionSupportingInfoSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::SupportingInfo
+    }
+}
+/// The contraindication-reference reference search parameter of the ClinicalUseDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalUseDefinitionContraindicationReferenceSearchReference;
+impl ReferenceSearchParameter for ClinicalUseDefinitionContraindicationReferenceSearchReference {
+    type Resource = resources::ClinicalUseDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ClinicalUseDefinitionSearchParameter {
+        ClinicalUseDefinitionSearchParameter::ContraindicationReference
+    }
+}
+/// The effect-reference reference search parameter of the ClinicalUseDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalUseDefinitionEffectReferenceSearchReference;
+impl ReferenceSearchParameter for ClinicalUseDefinitionEffectReferenceSearchReference {
+    type Resource = resources::ClinicalUseDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ClinicalUseDefinitionSearchParameter {
+        ClinicalUseDefinitionSearchParameter::EffectReference
+    }
+}
+/// The indication-reference reference search parameter of the ClinicalUseDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalUseDefinitionIndicationReferenceSearchReference;
+impl ReferenceSearchParameter for ClinicalUseDefinitionIndicationReferenceSearchReference {
+    type Resource = resources::ClinicalUseDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ClinicalUseDefinitionSearchParameter {
+        ClinicalUseDefinitionSearchParameter::IndicationReference
+    }
+}
+/// The product reference search parameter of the ClinicalUseDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalUseDefinitionProductSearchReference;
+impl ReferenceSearchParameter for ClinicalUseDefinitionProductSearchReference {
+    type Resource = resources::ClinicalUseDefinition;
+    type Target = resources::MedicinalProductDefinition;
+    fn parameter(&self) -> ClinicalUseDefinitionSearchParameter {
+        ClinicalUseDefinitionSearchParameter::Product
+    }
+}
+/// The subject reference search parameter of the ClinicalUseDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalUseDefinitionSubjectSearchReference;
+impl ReferenceSearchParameter for ClinicalUseDefinitionSubjectSearchReference {
+    type Resource = resources::ClinicalUseDefinition;
+    type Target = ClinicalUseDefinitionSubjectSearchTarget;
+    fn parameter(&self) -> ClinicalUseDefinitionSearchParameter {
+        ClinicalUseDefinitionSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the ClinicalUseDefinition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClinicalUseDefinitionSubjectSearchTarget {
+    /// Variant for MedicinalProductDefinition target resources
+    MedicinalProductDefinition(resources::MedicinalProductDefinition),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+    /// Variant for DeviceDefinition target resources
+    DeviceDefinition(resources::DeviceDefinition),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+}
+impl From<resources::MedicinalProductDefinition> for ClinicalUseDefinitionSubjectSearchTarget {
+    fn from(resource: resources::MedicinalProductDefinition) -> ClinicalUseDefinitionSubjectSearchTarget {
+        ClinicalUseDefinitionSubjectSearchTarget::MedicinalProductDefinition(resource)
+    }
+}
+impl From<resources::Device> for ClinicalUseDefinitionSubjectSearchTarget {
+    fn from(resource: resources::Device) -> ClinicalUseDefinitionSubjectSearchTarget {
+        ClinicalUseDefinitionSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Medication> for ClinicalUseDefinitionSubjectSearchTarget {
+    fn from(resource: resources::Medication) -> ClinicalUseDefinitionSubjectSearchTarget {
+        ClinicalUseDefinitionSubjectSearchTarget::Medication(resource)
+    }
+}
+impl From<resources::DeviceDefinition> for ClinicalUseDefinitionSubjectSearchTarget {
+    fn from(resource: resources::DeviceDefinition) -> ClinicalUseDefinitionSubjectSearchTarget {
+        ClinicalUseDefinitionSubjectSearchTarget::DeviceDefinition(resource)
+    }
+}
+impl From<resources::PlanDefinition> for ClinicalUseDefinitionSubjectSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> ClinicalUseDefinitionSubjectSearchTarget {
+        ClinicalUseDefinitionSubjectSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::Substance> for ClinicalUseDefinitionSubjectSearchTarget {
+    fn from(resource: resources::Substance) -> ClinicalUseDefinitionSubjectSearchTarget {
+        ClinicalUseDefinitionSubjectSearchTarget::Substance(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for ClinicalUseDefinitionSubjectSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> ClinicalUseDefinitionSubjectSearchTarget {
+        ClinicalUseDefinitionSubjectSearchTarget::ActivityDefinition(resource)
+    }
+}
+/// The supplements reference search parameter of the CodeSystem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CodeSystemSupplementsSearchReference;
+impl ReferenceSearchParameter for CodeSystemSupplementsSearchReference {
+    type Resource = resources::CodeSystem;
+    type Target = resources::CodeSystem;
+    fn parameter(&self) -> CodeSystemSearchParameter {
+        CodeSystemSearchParameter::Supplements
+    }
+}
+/// The based-on reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationBasedOnSearchReference;
+impl ReferenceSearchParameter for CommunicationBasedOnSearchReference {
+    type Resource = resources::Communication;
+    type Target = resources::Resource;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::BasedOn
+    }
+}
+/// The encounter reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationEncounterSearchReference;
+impl ReferenceSearchParameter for CommunicationEncounterSearchReference {
+    type Resource = resources::Communication;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::Encounter
+    }
+}
+/// The instantiates-canonical reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationInstantiatesCanonicalSearchReference;
+impl ReferenceSearchParameter for CommunicationInstantiatesCanonicalSearchReference {
+    type Resource = resources::Communication;
+    type Target = CommunicationInstantiatesCanonicalSearchTarget;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::InstantiatesCanonical
+    }
+}
+/// Target resources of the instantiates-canonical reference search parameter of the Communication resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationInstantiatesCanonicalSearchTarget {
+    /// Variant for Questionnaire target resources
+    Questionnaire(resources::Questionnaire),
+    /// Variant for Measure target resources
+    Measure(resources::Measure),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for OperationDefinition target resources
+    OperationDefinition(resources::OperationDefinition),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+}
+impl From<resources::Questionnaire> for CommunicationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Questionnaire) -> CommunicationInstantiatesCanonicalSearchTarget {
+        CommunicationInstantiatesCanonicalSearchTarget::Questionnaire(resource)
+    }
+}
+impl From<resources::Measure> for CommunicationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Measure) -> CommunicationInstantiatesCanonicalSearchTarget {
+        CommunicationInstantiatesCanonicalSearchTarget::Measure(resource)
+    }
+}
+impl From<resources::PlanDefinition> for CommunicationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> CommunicationInstantiatesCanonicalSearchTarget {
+        CommunicationInstantiatesCanonicalSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::OperationDefinition> for CommunicationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::OperationDefinition) -> CommunicationInstantiatesCanonicalSearchTarget {
+        CommunicationInstantiatesCanonicalSearchTarget::OperationDefinition(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for CommunicationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> CommunicationInstantiatesCanonicalSearchTarget {
+        CommunicationInstantiatesCanonicalSearchTarget::ActivityDefinition(resource)
+    }
+}
+/// The part-of reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationPartOfSearchReference;
+impl ReferenceSearchParameter for CommunicationPartOfSearchReference {
+    type Resource = resources::Communication;
+    type Target = resources::Resource;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::PartOf
+    }
+}
+/// The patient reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationPatientSearchReference;
+impl ReferenceSearchParameter for CommunicationPatientSearchReference {
+    type Resource = resources::Communication;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::Patient
+    }
+}
+/// The recipient reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRecipientSearchReference;
+impl ReferenceSearchParameter for CommunicationRecipientSearchReference {
+    type Resource = resources::Communication;
+    type Target = CommunicationRecipientSearchTarget;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::Recipient
+    }
+}
+/// Target resources of the recipient reference search parameter of the Communication resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationRecipientSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::Practitioner) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::Group) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Organization> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::Organization) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::CareTeam) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::Device) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::HealthcareService> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::HealthcareService) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The sender reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationSenderSearchReference;
+impl ReferenceSearchParameter for CommunicationSenderSearchReference {
+    type Resource = resources::Communication;
+    type Target = CommunicationSenderSearchTarget;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::Sender
+    }
+}
+/// Target resources of the sender reference search parameter of the Communication resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationSenderSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::Practitioner) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::Organization) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::Device) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::HealthcareService> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::HealthcareService) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subject reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationSubjectSearchReference;
+impl ReferenceSearchParameter for CommunicationSubjectSearchReference {
+    type Resource = resources::Communication;
+    type Target = CommunicationSubjectSearchTarget;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Communication resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for CommunicationSubjectSearchTarget {
+    fn from(resource: resources::Group) -> CommunicationSubjectSearchTarget {
+        CommunicationSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationSubjectSearchTarget {
+        CommunicationSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The based-on reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestBasedOnSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestBasedOnSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = resources::Resource;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::BasedOn
+    }
+}
+/// The encounter reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestEncounterSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestEncounterSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Encounter
+    }
+}
+/// The patient reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestPatientSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestPatientSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Patient
+    }
+}
+/// The recipient reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestRecipientSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestRecipientSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = CommunicationRequestRecipientSearchTarget;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Recipient
+    }
+}
+/// Target resources of the recipient reference search parameter of the CommunicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationRequestRecipientSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::Practitioner) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::Group) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Organization> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::Organization) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::CareTeam) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::Device) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::HealthcareService> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::HealthcareService) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The replaces reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestReplacesSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestReplacesSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = resources::CommunicationRequest;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Replaces
+    }
+}
+/// The requester reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestRequesterSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestRequesterSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = CommunicationRequestRequesterSearchTarget;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Requester
+    }
+}
+/// Target resources of the requester reference search parameter of the CommunicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationRequestRequesterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for CommunicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> CommunicationRequestRequesterSearchTarget {
+        CommunicationRequestRequesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for CommunicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Organization) -> CommunicationRequestRequesterSearchTarget {
+        CommunicationRequestRequesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for CommunicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Device) -> CommunicationRequestRequesterSearchTarget {
+        CommunicationRequestRequesterSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationRequestRequesterSearchTarget {
+        CommunicationRequestRequesterSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CommunicationRequestRequesterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CommunicationRequestRequesterSearchTarget {
+        CommunicationRequestRequesterSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CommunicationRequestRequesterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CommunicationRequestRequesterSearchTarget {
+        CommunicationRequestRequesterSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The sender reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestSenderSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestSenderSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = CommunicationRequestSenderSearchTarget;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Sender
+    }
+}
+/// Target resources of the sender reference search parameter of the CommunicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationRequestSenderSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for CommunicationRequestSenderSearchTarget {
+    fn from(resource: resources::Practitioner) -> CommunicationRequestSenderSearchTarget {
+        CommunicationRequestSenderSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for CommunicationRequestSenderSearchTarget {
+    fn from(resource: resources::Organization) -> CommunicationRequestSenderSearchTarget {
+        CommunicationRequestSenderSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for CommunicationRequestSenderSearchTarget {
+    fn from(resource: resources::Device) -> CommunicationRequestSenderSearchTarget {
+        CommunicationRequestSenderSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationRequestSenderSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationRequestSenderSearchTarget {
+        CommunicationRequestSenderSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::HealthcareService> for CommunicationRequestSenderSearchTarget {
+    fn from(resource: resources::HealthcareService) -> CommunicationRequestSenderSearchTarget {
+        CommunicationRequestSenderSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CommunicationRequestSenderSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CommunicationRequestSenderSearchTarget {
+        CommunicationRequestSenderSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CommunicationRequestSenderSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CommunicationRequestSenderSearchTarget {
+        CommunicationRequestSenderSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subject reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestSubjectSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestSubjectSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = CommunicationRequestSubjectSearchTarget;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the CommunicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationRequestSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for CommunicationRequestSubjectSearchTarget {
+    fn from(resource: resources::Group) -> CommunicationRequestSubjectSearchTarget {
+        CommunicationRequestSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationRequestSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationRequestSubjectSearchTarget {
+        CommunicationRequestSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionPatientSearchReference;
+impl ReferenceSearchParameter for CompositionPatientSearchReference {
+    type Resource = resources::Composition;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::Patient
+    }
+}
+/// The attester reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionAttesterSearchReference;
+impl ReferenceSearchParameter for CompositionAttesterSearchReference {
+    type Resource = resources::Composition;
+    type Target = CompositionAttesterSearchTarget;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::Attester
+    }
+}
+/// Target resources of the attester reference search parameter of the Composition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CompositionAttesterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for CompositionAttesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> CompositionAttesterSearchTarget {
+        CompositionAttesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for CompositionAttesterSearchTarget {
+    fn from(resource: resources::Organization) -> CompositionAttesterSearchTarget {
+        CompositionAttesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for CompositionAttesterSearchTarget {
+    fn from(resource: resources::Patient) -> CompositionAttesterSearchTarget {
+        CompositionAttesterSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CompositionAttesterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CompositionAttesterSearchTarget {
+        CompositionAttesterSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CompositionAttesterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CompositionAttesterSearchTarget {
+        CompositionAttesterSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The author reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionAuthorSearchReference;
+impl ReferenceSearchParameter for CompositionAuthorSearchReference {
+    type Resource = resources::Composition;
+    type Target = CompositionAuthorSearchTarget;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the Composition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CompositionAuthorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for CompositionAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> CompositionAuthorSearchTarget {
+        CompositionAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for CompositionAuthorSearchTarget {
+    fn from(resource: resources::Organization) -> CompositionAuthorSearchTarget {
+        CompositionAuthorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for CompositionAuthorSearchTarget {
+    fn from(resource: resources::Device) -> CompositionAuthorSearchTarget {
+        CompositionAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for CompositionAuthorSearchTarget {
+    fn from(resource: resources::Patient) -> CompositionAuthorSearchTarget {
+        CompositionAuthorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CompositionAuthorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CompositionAuthorSearchTarget {
+        CompositionAuthorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CompositionAuthorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CompositionAuthorSearchTarget {
+        CompositionAuthorSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The encounter reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionEncounterSearchReference;
+impl ReferenceSearchParameter for CompositionEncounterSearchReference {
+    type Resource = resources::Composition;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::Encounter
+    }
+}
+/// The entry reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionEntrySearchReference;
+impl ReferenceSearchParameter for CompositionEntrySearchReference {
+    type Resource = resources::Composition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::Entry
+    }
+}
+/// The related-ref reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionRelatedRefSearchReference;
+impl ReferenceSearchParameter for CompositionRelatedRefSearchReference {
+    type Resource = resources::Composition;
+    type Target = resources::Composition;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::RelatedRef
+    }
+}
+/// The subject reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionSubjectSearchReference;
+impl ReferenceSearchParameter for CompositionSubjectSearchReference {
+    type Resource = resources::Composition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::Subject
+    }
+}
+/// The other reference search parameter of the ConceptMap resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConceptMapOtherSearchReference;
+impl ReferenceSearchParameter for ConceptMapOtherSearchReference {
+    type Resource = resources::ConceptMap;
+    type Target = resources::ConceptMap;
+    fn parameter(&self) -> ConceptMapSearchParameter {
+        ConceptMapSearchParameter::Other
+    }
+}
+/// The source reference search parameter of the ConceptMap resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConceptMapSourceSearchReference;
+impl ReferenceSearchParameter for ConceptMapSourceSearchReference {
+    type Resource = resources::ConceptMap;
+    type Target = resources::ValueSet;
+    fn parameter(&self) -> ConceptMapSearchParameter {
+        ConceptMapSearchParameter::Source
+    }
+}
+/// The source-uri reference search parameter of the ConceptMap resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConceptMapSourceUriSearchReference;
+impl ReferenceSearchParameter for ConceptMapSourceUriSearchReference {
+    type Resource = resources::ConceptMap;
+    type Target = resources::ValueSet;
+    fn parameter(&self) -> ConceptMapSearchParameter {
+        ConceptMapSearchParameter::SourceUri
+    }
+}
+/// The target reference search parameter of the ConceptMap resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConceptMapTargetSearchReference;
+impl ReferenceSearchParameter for ConceptMapTargetSearchReference {
+    type Resource = resources::ConceptMap;
+    type Target = resources::ValueSet;
+    fn parameter(&self) -> ConceptMapSearchParameter {
+        ConceptMapSearchParameter::Target
+    }
+}
+/// The target-uri reference search parameter of the ConceptMap resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConceptMapTargetUriSearchReference;
+impl ReferenceSearchParameter for ConceptMapTargetUriSearchReference {
+    type Resource = resources::ConceptMap;
+    type Target = resources::ValueSet;
+    fn parameter(&self) -> ConceptMapSearchParameter {
+        ConceptMapSearchParameter::TargetUri
+    }
+}
+/// The patient reference search parameter of the Condition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConditionPatientSearchReference;
+impl ReferenceSearchParameter for ConditionPatientSearchReference {
+    type Resource = resources::Condition;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ConditionSearchParameter {
+        ConditionSearchParameter::Patient
+    }
+}
+/// The asserter reference search parameter of the Condition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConditionAsserterSearchReference;
+impl ReferenceSearchParameter for ConditionAsserterSearchReference {
+    type Resource = resources::Condition;
+    type Target = ConditionAsserterSearchTarget;
+    fn parameter(&self) -> ConditionSearchParameter {
+        ConditionSearchParameter::Asserter
+    }
+}
+/// Target resources of the asserter reference search parameter of the Condition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConditionAsserterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ConditionAsserterSearchTarget {
+    fn from(resource: resources::Practitioner) -> ConditionAsserterSearchTarget {
+        ConditionAsserterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ConditionAsserterSearchTarget {
+    fn from(resource: resources::Patient) -> ConditionAsserterSearchTarget {
+        ConditionAsserterSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ConditionAsserterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ConditionAsserterSearchTarget {
+        ConditionAsserterSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ConditionAsserterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ConditionAsserterSearchTarget {
+        ConditionAsserterSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The encounter reference search parameter of the Condition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConditionEncounterSearchReference;
+impl ReferenceSearchParameter for ConditionEncounterSearchReference {
+    type Resource = resources::Condition;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ConditionSearchParameter {
+        ConditionSearchParameter::Encounter
+    }
+}
+/// The evidence-detail reference search parameter of the Condition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConditionEvidenceDetailSearchReference;
+impl ReferenceSearchParameter for ConditionEvidenceDetailSearchReference {
+    type Resource = resources::Condition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ConditionSearchParameter {
+        ConditionSearchParameter::EvidenceDetail
+    }
+}
+/// The subject reference search parameter of the Condition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConditionSubjectSearchReference;
+impl ReferenceSearchParameter for ConditionSubjectSearchReference {
+    type Resource = resources::Condition;
+    type Target = ConditionSubjectSearchTarget;
+    fn parameter(&self) -> ConditionSearchParameter {
+        ConditionSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Condition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConditionSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for ConditionSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ConditionSubjectSearchTarget {
+        ConditionSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for ConditionSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ConditionSubjectSearchTarget {
+        ConditionSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the Consent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsentPatientSearchReference;
+impl ReferenceSearchParameter for ConsentPatientSearchReference {
+    type Resource = resources::Consent;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ConsentSearchParameter {
+        ConsentSearchParameter::Patient
+    }
+}
+/// The actor reference search parameter of the Consent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsentActorSearchReference;
+impl ReferenceSearchParameter for ConsentActorSearchReference {
+    type Resource = resources::Consent;
+    type Target = ConsentActorSearchTarget;
+    fn parameter(&self) -> ConsentSearchParameter {
+        ConsentSearchParameter::Actor
+    }
+}
+/// Target resources of the actor reference search parameter of the Consent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConsentActorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ConsentActorSearchTarget {
+    fn from(resource: resources::Practitioner) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for ConsentActorSearchTarget {
+    fn from(resource: resources::Group) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Organization> for ConsentActorSearchTarget {
+    fn from(resource: resources::Organization) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for ConsentActorSearchTarget {
+    fn from(resource: resources::CareTeam) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for ConsentActorSearchTarget {
+    fn from(resource: resources::Device) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ConsentActorSearchTarget {
+    fn from(resource: resources::Patient) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ConsentActorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ConsentActorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The consentor reference search parameter of the Consent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsentConsentorSearchReference;
+impl ReferenceSearchParameter for ConsentConsentorSearchReference {
+    type Resource = resources::Consent;
+    type Target = ConsentConsentorSearchTarget;
+    fn parameter(&self) -> ConsentSearchParameter {
+        ConsentSearchParameter::Consentor
+    }
+}
+/// Target resources of the consentor reference search parameter of the Consent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConsentConsentorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ConsentConsentorSearchTarget {
+    fn from(resource: resources::Practitioner) -> ConsentConsentorSearchTarget {
+        ConsentConsentorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ConsentConsentorSearchTarget {
+    fn from(resource: resources::Organization) -> ConsentConsentorSearchTarget {
+        ConsentConsentorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for ConsentConsentorSearchTarget {
+    fn from(resource: resources::Patient) -> ConsentConsentorSearchTarget {
+        ConsentConsentorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ConsentConsentorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ConsentConsentorSearchTarget {
+        ConsentConsentorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ConsentConsentorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ConsentConsentorSearchTarget {
+        ConsentConsentorSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The data reference search parameter of the Consent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsentDataSearchReference;
+impl ReferenceSearchParameter for ConsentDataSearchReference {
+    type Resource = resources::Consent;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ConsentSearchParameter {
+        ConsentSearchParameter::Data
+    }
+}
+/// The organization reference search parameter of the Consent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsentOrganizationSearchReference;
+impl ReferenceSearchParameter for ConsentOrganizationSearchReference {
+    type Resource = resources::Consent;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ConsentSearchParameter {
+        ConsentSearchParameter::Organization
+    }
+}
+/// The source-reference reference search parameter of the Consent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsentSourceReferenceSearchReference;
+impl ReferenceSearchParameter for ConsentSourceReferenceSearchReference {
+    type Resource = resources::Consent;
+    type Target = ConsentSourceReferenceSearchTarget;
+    fn parameter(&self) -> ConsentSearchParameter {
+        ConsentSearchParameter::SourceReference
+    }
+}
+/// Target resources of the source-reference reference search parameter of the Consent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConsentSourceReferenceSearchTarget {
+    /// Variant for Consent target resources
+    Consent(resources::Consent),
+    /// Variant for Contract target resources
+    Contract(resources::Contract),
+    /// Variant for QuestionnaireResponse target resources
+    QuestionnaireResponse(resources::QuestionnaireResponse),
+    /// Variant for DocumentReference target resources
+    DocumentReference(resources::DocumentReference),
+}
+impl From<resources::Consent> for ConsentSourceReferenceSearchTarget {
+    fn from(resource: resources::Consent) -> ConsentSourceReferenceSearchTarget {
+        ConsentSourceReferenceSearchTarget::Consent(resource)
+    }
+}
+impl From<resources::Contract> for ConsentSourceReferenceSearchTarget {
+    fn from(resource: resources::Contract) -> ConsentSourceReferenceSearchTarget {
+        ConsentSourceReferenceSearchTarget::Contract(resource)
+    }
+}
+impl From<resources::QuestionnaireResponse> for ConsentSourceReferenceSearchTarget {
+    fn from(resource: resources::QuestionnaireResponse) -> ConsentSourceReferenceSearchTarget {
+        ConsentSourceReferenceSearchTarget::QuestionnaireResponse(resource)
+    }
+}
+impl From<resources::DocumentReference> for ConsentSourceReferenceSearchTarget {
+    fn from(resource: resources::DocumentReference) -> ConsentSourceReferenceSearchTarget {
+        ConsentSourceReferenceSearchTarget::DocumentReference(resource)
+    }
+}
+/// The authority reference search parameter of the Contract resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContractAuthoritySearchReference;
+impl ReferenceSearchParameter for ContractAuthoritySearchReference {
+    type Resource = resources::Contract;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ContractSearchParameter {
+        ContractSearchParameter::Authority
+    }
+}
+/// The domain reference search parameter of the Contract resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContractDomainSearchReference;
+impl ReferenceSearchParameter for ContractDomainSearchReference {
+    type Resource = resources::Contract;
+    type Target = resources::Location;
+    fn parameter(&self) -> ContractSearchParameter {
+        ContractSearchParameter::Domain
+    }
+}
+/// The patient reference search parameter of the Contract resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContractPatientSearchReference;
+impl ReferenceSearchParameter for ContractPatientSearchReference {
+    type Resource = resources::Contract;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ContractSearchParameter {
+        ContractSearchParameter::Patient
+    }
+}
+/// The signer reference search parameter of the Contract resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContractSignerSearchReference;
+impl ReferenceSearchParameter for ContractSignerSearchReference {
+    type Resource = resources::Contract;
+    type Target = ContractSignerSearchTarget;
+    fn parameter(&self) -> ContractSearchParameter {
+        ContractSearchParameter::Signer
+    }
+}
+/// Target resources of the signer reference search parameter of the Contract resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ContractSignerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ContractSignerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ContractSignerSearchTarget {
+        ContractSignerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ContractSignerSearchTarget {
+    fn from(resource: resources::Organization) -> ContractSignerSearchTarget {
+        ContractSignerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for ContractSignerSearchTarget {
+    fn from(resource: resources::Patient) -> ContractSignerSearchTarget {
+        ContractSignerSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ContractSignerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ContractSignerSearchTarget {
+        ContractSignerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ContractSignerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ContractSignerSearchTarget {
+        ContractSignerSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subject reference search parameter of the Contract resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContractSubjectSearchReference;
+impl ReferenceSearchParameter for ContractSubjectSearchReference {
+    type Resource = resources::Contract;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ContractSearchParameter {
+        ContractSearchParameter::Subject
+    }
+}
+/// The beneficiary reference search parameter of the Coverage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageBeneficiarySearchReference;
+impl ReferenceSearchParameter for CoverageBeneficiarySearchReference {
+    type Resource = resources::Coverage;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CoverageSearchParameter {
+        CoverageSearchParameter::Beneficiary
+    }
+}
+/// The patient reference search parameter of the Coverage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoveragePatientSearchReference;
+impl ReferenceSearchParameter for CoveragePatientSearchReference {
+    type Resource = resources::Coverage;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CoverageSearchParameter {
+        CoverageSearchParameter::Patient
+    }
+}
+/// The payor reference search parameter of the Coverage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoveragePayorSearchReference;
+impl ReferenceSearchParameter for CoveragePayorSearchReference {
+    type Resource = resources::Coverage;
+    type Target = CoveragePayorSearchTarget;
+    fn parameter(&self) -> CoverageSearchParameter {
+        CoverageSearchParameter::Payor
+    }
+}
+/// Target resources of the payor reference search parameter of the Coverage resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CoveragePayorSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Organization> for CoveragePayorSearchTarget {
+    fn from(resource: resources::Organization) -> CoveragePayorSearchTarget {
+        CoveragePayorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for CoveragePayorSearchTarget {
+    fn from(resource: resources::Patient) -> CoveragePayorSearchTarget {
+        CoveragePayorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CoveragePayorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CoveragePayorSearchTarget {
+        CoveragePayorSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The policy-holder reference search parameter of the Coverage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoveragePolicyHolderSearchReference;
+impl ReferenceSearchParameter for CoveragePolicyHolderSearchReference {
+    type Resource = resources::Coverage;
+    type Target = CoveragePolicyHolderSearchTarget;
+    fn parameter(&self) -> CoverageSearchParameter {
+        CoverageSearchParameter::PolicyHolder
+    }
+}
+/// Target resources of the policy-holder reference search parameter of the Coverage resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CoveragePolicyHolderSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Organization> for CoveragePolicyHolderSearchTarget {
+    fn from(resource: resources::Organization) -> CoveragePolicyHolderSearchTarget {
+        CoveragePolicyHolderSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for CoveragePolicyHolderSearchTarget {
+    fn from(resource: resources::Patient) -> CoveragePolicyHolderSearchTarget {
+        CoveragePolicyHolderSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CoveragePolicyHolderSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CoveragePolicyHolderSearchTarget {
+        CoveragePolicyHolderSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subscriber reference search parameter of the Coverage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageSubscriberSearchReference;
+impl ReferenceSearchParameter for CoverageSubscriberSearchReference {
+    type Resource = resources::Coverage;
+    type Target = CoverageSubscriberSearchTarget;
+    fn parameter(&self) -> CoverageSearchParameter {
+        CoverageSearchParameter::Subscriber
+    }
+}
+/// Target resources of the subscriber reference search parameter of the Coverage resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CoverageSubscriberSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Patient> for CoverageSubscriberSearchTarget {
+    fn from(resource: resources::Patient) -> CoverageSubscriberSearchTarget {
+        CoverageSubscriberSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CoverageSubscriberSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CoverageSubscriberSearchTarget {
+        CoverageSubscriberSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The enterer reference search parameter of the CoverageEligibilityRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageEligibilityRequestEntererSearchReference;
+impl ReferenceSearchParameter for CoverageEligibilityRequestEntererSearchReference {
+    type Resource = resources::CoverageEligibilityRequest;
+    type Target = CoverageEligibilityRequestEntererSearchTarget;
+    fn parameter(&self) -> CoverageEligibilityRequestSearchParameter {
+        CoverageEligibilityRequestSearchParameter::Enterer
+    }
+}
+/// Target resources of the enterer reference search parameter of the CoverageEligibilityRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CoverageEligibilityRequestEntererSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for CoverageEligibilityRequestEntererSearchTarget {
+    fn from(resource: resources::Practitioner) -> CoverageEligibilityRequestEntererSearchTarget {
+        CoverageEligibilityRequestEntererSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CoverageEligibilityRequestEntererSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CoverageEligibilityRequestEntererSearchTarget {
+        CoverageEligibilityRequestEntererSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The facility reference search parameter of the CoverageEligibilityRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageEligibilityRequestFacilitySearchReference;
+impl ReferenceSearchParameter for CoverageEligibilityRequestFacilitySearchReference {
+    type Resource = resources::CoverageEligibilityRequest;
+    type Target = resources::Location;
+    fn parameter(&self) -> CoverageEligibilityRequestSearchParameter {
+        CoverageEligibilityRequestSearchParameter::Facility
+    }
+}
+/// The patient reference search parameter of the CoverageEligibilityRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageEligibilityRequestPatientSearchReference;
+impl ReferenceSearchParameter for CoverageEligibilityRequestPatientSearchReference {
+    type Resource = resources::CoverageEligibilityRequest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CoverageEligibilityRequestSearchParameter {
+        CoverageEligibilityRequestSearchParameter::Patient
+    }
+}
+/// The provider reference search parameter of the CoverageEligibilityRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageEligibilityRequestProviderSearchReference;
+impl ReferenceSearchParameter for CoverageEligibilityRequestProviderSearchReference {
+    type Resource = resources::CoverageEligibilityRequest;
+    type Target = CoverageEligibilityRequestProviderSearchTarget;
+    fn parameter(&self) -> CoverageEligibilityRequestSearchParameter {
+        CoverageEligibilityRequestSearchParameter::Provider
+    }
+}
+/// Target resources of the provider reference search parameter of the CoverageEligibilityRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CoverageEligibilityRequestProviderSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for CoverageEligibilityRequestProviderSearchTarget {
+    fn from(resource: resources::Practitioner) -> CoverageEligibilityRequestProviderSearchTarget {
+        CoverageEligibilityRequestProviderSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for CoverageEligibilityRequestProviderSearchTarget {
+    fn from(resource: resources::Organization) -> CoverageEligibilityRequestProviderSearchTarget {
+        CoverageEligibilityRequestProviderSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CoverageEligibilityRequestProviderSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CoverageEligibilityRequestProviderSearchTarget {
+        CoverageEligibilityRequestProviderSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The insurer reference search parameter of the CoverageEligibilityResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageEligibilityResponseInsurerSearchReference;
+impl ReferenceSearchParameter for CoverageEligibilityResponseInsurerSearchReference {
+    type Resource = resources::CoverageEligibilityResponse;
+    type Target = resources::Organization;
+    fn parameter(&self) -> CoverageEligibilityResponseSearchParameter {
+        CoverageEligibilityResponseSearchParameter::Insurer
+    }
+}
+/// The patient reference search parameter of the CoverageEligibilityResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageEligibilityResponsePatientSearchReference;
+impl ReferenceSearchParameter for CoverageEligibilityResponsePatientSearchReference {
+    type Resource = resources::CoverageEligibilityResponse;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CoverageEligibilityResponseSearchParameter {
+        CoverageEligibilityResponseSearchParameter::Patient
+    }
+}
+/// The request reference search parameter of the CoverageEligibilityResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageEligibilityResponseRequestSearchReference;
+impl ReferenceSearchParameter for CoverageEligibilityResponseRequestSearchReference {
+    type Resource = resources::CoverageEligibilityResponse;
+    type Target = resources::CoverageEligibilityRequest;
+    fn parameter(&self) -> CoverageEligibilityResponseSearchParameter {
+        CoverageEligibilityResponseSearchParameter::Request
+    }
+}
+/// The requestor reference search parameter of the CoverageEligibilityResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageEligibilityResponseRequestorSearchReference;
+impl ReferenceSearchParameter for CoverageEligibilityResponseRequestorSearchReference {
+    type Resource = resources::CoverageEligibilityResponse;
+    type Target = CoverageEligibilityResponseRequestorSearchTarget;
+    fn parameter(&self) -> CoverageEligibilityResponseSearchParameter {
+        CoverageEligibilityResponseSearchParameter::Requestor
+    }
+}
+/// Target resources of the requestor reference search parameter of the CoverageEligibilityResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CoverageEligibilityResponseRequestorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for CoverageEligibilityResponseRequestorSearchTarget {
+    fn from(resource: resources::Practitioner) -> CoverageEligibilityResponseRequestorSearchTarget {
+        CoverageEligibilityResponseRequestorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for CoverageEligibilityResponseRequestorSearchTarget {
+    fn from(resource: resources::Organization) -> CoverageEligibilityResponseRequestorSearchTarget {
+        CoverageEligibilityResponseRequestorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CoverageEligibilityResponseRequestorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CoverageEligibilityResponseRequestorSearchTarget {
+        CoverageEligibilityResponseRequestorSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The patient reference search parameter of the DetectedIssue resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DetectedIssuePatientSearchReference;
+impl ReferenceSearchParameter for DetectedIssuePatientSearchReference {
+    type Resource = resources::DetectedIssue;
+    type Target = resources::Patient;
+    fn parameter(&self) -> DetectedIssueSearchParameter {
+        DetectedIssueSearchParameter::Patient
+    }
+}
+/// The author reference search parameter of the DetectedIssue resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DetectedIssueAuthorSearchReference;
+impl ReferenceSearchParameter for DetectedIssueAuthorSearchReference {
+    type Resource = resources::DetectedIssue;
+    type Target = DetectedIssueAuthorSearchTarget;
+    fn parameter(&self) -> DetectedIssueSearchParameter {
+        DetectedIssueSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the DetectedIssue resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DetectedIssueAuthorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for DetectedIssueAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> DetectedIssueAuthorSearchTarget {
+        DetectedIssueAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Device> for DetectedIssueAuthorSearchTarget {
+    fn from(resource: resources::Device) -> DetectedIssueAuthorSearchTarget {
+        DetectedIssueAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::PractitionerRole> for DetectedIssueAuthorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> DetectedIssueAuthorSearchTarget {
+        DetectedIssueAuthorSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The implicated reference search parameter of the DetectedIssue resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DetectedIssueImplicatedSearchReference;
+impl ReferenceSearchParameter for DetectedIssueImplicatedSearchReference {
+    type Resource = resources::DetectedIssue;
+    type Target = resources::Resource;
+    fn parameter(&self) -> DetectedIssueSearchParameter {
+        DetectedIssueSearchParameter::Implicated
+    }
+}
+/// The location reference search parameter of the Device resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceLocationSearchReference;
+impl ReferenceSearchParameter for DeviceLocationSearchReference {
+    type Resource = resources::Device;
+    type Target = resources::Location;
+    fn parameter(&self) -> DeviceSearchParameter {
+        DeviceSearchParameter::Location
+    }
+}
+/// The organization reference search parameter of the Device resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceOrganizationSearchReference;
+impl ReferenceSearchParameter for DeviceOrganizationSearchReference {
+    type Resource = resources::Device;
+    type Target = resources::Organization;
+    fn parameter(&self) -> DeviceSearchParameter {
+        DeviceSearchParameter::Organization
+    }
+}
+/// The patient reference search parameter of the Device resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DevicePatientSearchReference;
+impl ReferenceSearchParameter for DevicePatientSearchReference {
+    type Resource = resources::Device;
+    type Target = resources::Patient;
+    fn parameter(&self) -> DeviceSearchParameter {
+        DeviceSearchParameter::Patient
+    }
+}
+/// The parent reference search parameter of the DeviceDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceDefinitionParentSearchReference;
+impl ReferenceSearchParameter for DeviceDefinitionParentSearchReference {
+    type Resource = resources::DeviceDefinition;
+    type Target = resources::DeviceDefinition;
+    fn parameter(&self) -> DeviceDefinitionSearchParameter {
+        DeviceDefinitionSearchParameter::Parent
+    }
+}
+/// The parent reference search parameter of the DeviceMetric resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceMetricParentSearchReference;
+impl ReferenceSearchParameter for DeviceMetricParentSearchReference {
+    type Resource = resources::DeviceMetric;
+    type Target = resources::Device;
+    fn parameter(&self) -> DeviceMetricSearchParameter {
+        DeviceMetricSearchParameter::Parent
+    }
+}
+/// The source reference search parameter of the DeviceMetric resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceMetricSourceSearchReference;
+impl ReferenceSearchParameter for DeviceMetricSourceSearchReference {
+    type Resource = resources::DeviceMetric;
+    type Target = resources::Device;
+    fn parameter(&self) -> DeviceMetricSearchParameter {
+        DeviceMetricSearchParameter::Source
+    }
+}
+/// The patient reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestPatientSearchReference;
+impl ReferenceSearchParameter for DeviceRequestPatientSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestEncounterSearchReference;
+impl ReferenceSearchParameter for DeviceRequestEncounterSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Encounter
+    }
+}
+/// The based-on reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestBasedOnSearchReference;
+impl ReferenceSearchParameter for DeviceRequestBasedOnSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = resources::Resource;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::BasedOn
+    }
+}
+/// The device reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestDeviceSearchReference;
+impl ReferenceSearchParameter for DeviceRequestDeviceSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = resources::Device;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Device
+    }
+}
+/// The instantiates-canonical reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestInstantiatesCanonicalSearchReference;
+impl ReferenceSearchParameter for DeviceRequestInstantiatesCanonicalSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = DeviceRequestInstantiatesCanonicalSearchTarget;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::InstantiatesCanonical
+    }
+}
+/// Target resources of the instantiates-canonical reference search parameter of the DeviceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceRequestInstantiatesCanonicalSearchTarget {
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+}
+impl From<resources::PlanDefinition> for DeviceRequestInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> DeviceRequestInstantiatesCanonicalSearchTarget {
+        DeviceRequestInstantiatesCanonicalSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for DeviceRequestInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> DeviceRequestInstantiatesCanonicalSearchTarget {
+        DeviceRequestInstantiatesCanonicalSearchTarget::ActivityDefinition(resource)
+    }
+}
+/// The insurance reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestInsuranceSearchReference;
+impl ReferenceSearchParameter for DeviceRequestInsuranceSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = DeviceRequestInsuranceSearchTarget;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Insurance
+    }
+}
+/// Target resources of the insurance reference search parameter of the DeviceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceRequestInsuranceSearchTarget {
+    /// Variant for ClaimResponse target resources
+    ClaimResponse(resources::ClaimResponse),
+    /// Variant for Coverage target resources
+    Coverage(resources::Coverage),
+}
+impl From<resources::ClaimResponse> for DeviceRequestInsuranceSearchTarget {
+    fn from(resource: resources::ClaimResponse) -> DeviceRequestInsuranceSearchTarget {
+        DeviceRequestInsuranceSearchTarget::ClaimResponse(resource)
+    }
+}
+impl From<resources::Coverage> for DeviceRequestInsuranceSearchTarget {
+    fn from(resource: resources::Coverage) -> DeviceRequestInsuranceSearchTarget {
+        DeviceRequestInsuranceSearchTarget::Coverage(resource)
+    }
+}
+/// The performer reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestPerformerSearchReference;
+impl ReferenceSearchParameter for DeviceRequestPerformerSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = DeviceRequestPerformerSearchTarget;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the DeviceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceRequestPerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::Organization) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::CareTeam) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::Device) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::Patient) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::HealthcareService> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::HealthcareService) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::PractitionerRole> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The prior-request reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestPriorRequestSearchReference;
+impl ReferenceSearchParameter for DeviceRequestPriorRequestSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = resources::Resource;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::PriorRequest
+    }
+}
+/// The requester reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestRequesterSearchReference;
+impl ReferenceSearchParameter for DeviceRequestRequesterSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = DeviceRequestRequesterSearchTarget;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Requester
+    }
+}
+/// Target resources of the requester reference search parameter of the DeviceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceRequestRequesterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for DeviceRequestRequesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> DeviceRequestRequesterSearchTarget {
+        DeviceRequestRequesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for DeviceRequestRequesterSearchTarget {
+    fn from(resource: resources::Organization) -> DeviceRequestRequesterSearchTarget {
+        DeviceRequestRequesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for DeviceRequestRequesterSearchTarget {
+    fn from(resource: resources::Device) -> DeviceRequestRequesterSearchTarget {
+        DeviceRequestRequesterSearchTarget::Device(resource)
+    }
+}
+impl From<resources::PractitionerRole> for DeviceRequestRequesterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> DeviceRequestRequesterSearchTarget {
+        DeviceRequestRequesterSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The subject reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestSubjectSearchReference;
+impl ReferenceSearchParameter for DeviceRequestSubjectSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = DeviceRequestSubjectSearchTarget;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the DeviceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceRequestSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Group> for DeviceRequestSubjectSearchTarget {
+    fn from(resource: resources::Group) -> DeviceRequestSubjectSearchTarget {
+        DeviceRequestSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Device> for DeviceRequestSubjectSearchTarget {
+    fn from(resource: resources::Device) -> DeviceRequestSubjectSearchTarget {
+        DeviceRequestSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for DeviceRequestSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> DeviceRequestSubjectSearchTarget {
+        DeviceRequestSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Location> for DeviceRequestSubjectSearchTarget {
+    fn from(resource: resources::Location) -> DeviceRequestSubjectSearchTarget {
+        DeviceRequestSubjectSearchTarget::Location(resource)
+    }
+}
+/// The patient reference search parameter of the DeviceUseStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceUseStatementPatientSearchReference;
+impl ReferenceSearchParameter for DeviceUseStatementPatientSearchReference {
+    type Resource = resources::DeviceUseStatement;
+    type Target = resources::Patient;
+    fn parameter(&self) -> DeviceUseStatementSearchParameter {
+        DeviceUseStatementSearchParameter::Patient
+    }
+}
+/// The device reference search parameter of the DeviceUseStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceUseStatementDeviceSearchReference;
+impl ReferenceSearchParameter for DeviceUseStatementDeviceSearchReference {
+    type Resource = resources::DeviceUseStatement;
+    type Target = resources::Device;
+    fn parameter(&self) -> DeviceUseStatementSearchParameter {
+        DeviceUseStatementSearchParameter::Device
+    }
+}
+/// The subject reference search parameter of the DeviceUseStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceUseStatementSubjectSearchReference;
+impl ReferenceSearchParameter for DeviceUseStatementSubjectSearchReference {
+    type Resource = resources::DeviceUseStatement;
+    type Target = DeviceUseStatementSubjectSearchTarget;
+    fn parameter(&self) -> DeviceUseStatementSearchParameter {
+        DeviceUseStatementSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the DeviceUseStatement resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceUseStatementSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for DeviceUseStatementSubjectSearchTarget {
+    fn from(resource: resources::Group) -> DeviceUseStatementSubjectSearchTarget {
+        DeviceUseStatementSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for DeviceUseStatementSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> DeviceUseStatementSubjectSearchTarget {
+        DeviceUseStatementSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportPatientSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportPatientSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = resources::Patient;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportEncounterSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportEncounterSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Encounter
+    }
+}
+/// The based-on reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportBasedOnSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportBasedOnSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = DiagnosticReportBasedOnSearchTarget;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the DiagnosticReport resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DiagnosticReportBasedOnSearchTarget {
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for MedicationRequest target resources
+    MedicationRequest(resources::MedicationRequest),
+    /// Variant for NutritionOrder target resources
+    NutritionOrder(resources::NutritionOrder),
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+    /// Variant for ImmunizationRecommendation target resources
+    ImmunizationRecommendation(resources::ImmunizationRecommendation),
+}
+impl From<resources::CarePlan> for DiagnosticReportBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> DiagnosticReportBasedOnSearchTarget {
+        DiagnosticReportBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::MedicationRequest> for DiagnosticReportBasedOnSearchTarget {
+    fn from(resource: resources::MedicationRequest) -> DiagnosticReportBasedOnSearchTarget {
+        DiagnosticReportBasedOnSearchTarget::MedicationRequest(resource)
+    }
+}
+impl From<resources::NutritionOrder> for DiagnosticReportBasedOnSearchTarget {
+    fn from(resource: resources::NutritionOrder) -> DiagnosticReportBasedOnSearchTarget {
+        DiagnosticReportBasedOnSearchTarget::NutritionOrder(resource)
+    }
+}
+impl From<resources::ServiceRequest> for DiagnosticReportBasedOnSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> DiagnosticReportBasedOnSearchTarget {
+        DiagnosticReportBasedOnSearchTarget::ServiceRequest(resource)
+    }
+}
+impl From<resources::ImmunizationRecommendation> for DiagnosticReportBasedOnSearchTarget {
+    fn from(resource: resources::ImmunizationRecommendation) -> DiagnosticReportBasedOnSearchTarget {
+        DiagnosticReportBasedOnSearchTarget::ImmunizationRecommendation(resource)
+    }
+}
+/// The media reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportMediaSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportMediaSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = resources::Media;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Media
+    }
+}
+/// The performer reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportPerformerSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportPerformerSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = DiagnosticReportPerformerSearchTarget;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the DiagnosticReport resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DiagnosticReportPerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for DiagnosticReportPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> DiagnosticReportPerformerSearchTarget {
+        DiagnosticReportPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for DiagnosticReportPerformerSearchTarget {
+    fn from(resource: resources::Organization) -> DiagnosticReportPerformerSearchTarget {
+        DiagnosticReportPerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for DiagnosticReportPerformerSearchTarget {
+    fn from(resource: resources::CareTeam) -> DiagnosticReportPerformerSearchTarget {
+        DiagnosticReportPerformerSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::PractitionerRole> for DiagnosticReportPerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> DiagnosticReportPerformerSearchTarget {
+        DiagnosticReportPerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The result reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportResultSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportResultSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = resources::Observation;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Result
+    }
+}
+/// The results-interpreter reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportResultsInterpreterSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportResultsInterpreterSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = DiagnosticReportResultsInterpreterSearchTarget;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::ResultsInterpreter
+    }
+}
+/// Target resources of the results-interpreter reference search parameter of the DiagnosticReport resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DiagnosticReportResultsInterpreterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for DiagnosticReportResultsInterpreterSearchTarget {
+    fn from(resource: resources::Practitioner) -> DiagnosticReportResultsInterpreterSearchTarget {
+        DiagnosticReportResultsInterpreterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for DiagnosticReportResultsInterpreterSearchTarget {
+    fn from(resource: resources::Organization) -> DiagnosticReportResultsInterpreterSearchTarget {
+        DiagnosticReportResultsInterpreterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for DiagnosticReportResultsInterpreterSearchTarget {
+    fn from(resource: resources::CareTeam) -> DiagnosticReportResultsInterpreterSearchTarget {
+        DiagnosticReportResultsInterpreterSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::PractitionerRole> for DiagnosticReportResultsInterpreterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> DiagnosticReportResultsInterpreterSearchTarget {
+        DiagnosticReportResultsInterpreterSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The specimen reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportSpecimenSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportSpecimenSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = resources::Specimen;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Specimen
+    }
+}
+/// The subject reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportSubjectSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportSubjectSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = DiagnosticReportSubjectSearchTarget;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the DiagnosticReport resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DiagnosticReportSubjectSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Practitioner> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Group) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Organization> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Device) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Medication> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Medication) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Medication(resource)
+    }
+}
+impl From<resources::Patient> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Procedure> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Procedure) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::Substance> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Substance) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Substance(resource)
+    }
+}
+impl From<resources::Location> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Location) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Location(resource)
+    }
+}
+/// The patient reference search parameter of the DocumentManifest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentManifestPatientSearchReference;
+impl ReferenceSearchParameter for DocumentManifestPatientSearchReference {
+    type Resource = resources::DocumentManifest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> DocumentManifestSearchParameter {
+        DocumentManifestSearchParameter::Patient
+    }
+}
+/// The author reference search parameter of the DocumentManifest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentManifestAuthorSearchReference;
+impl ReferenceSearchParameter for DocumentManifestAuthorSearchReference {
+    type Resource = resources::DocumentManifest;
+    type Target = DocumentManifestAuthorSearchTarget;
+    fn parameter(&self) -> DocumentManifestSearchParameter {
+        DocumentManifestSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the DocumentManifest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentManifestAuthorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for DocumentManifestAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> DocumentManifestAuthorSearchTarget {
+        DocumentManifestAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for DocumentManifestAuthorSearchTarget {
+    fn from(resource: resources::Organization) -> DocumentManifestAuthorSearchTarget {
+        DocumentManifestAuthorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for DocumentManifestAuthorSearchTarget {
+    fn from(resource: resources::Device) -> DocumentManifestAuthorSearchTarget {
+        DocumentManifestAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for DocumentManifestAuthorSearchTarget {
+    fn from(resource: resources::Patient) -> DocumentManifestAuthorSearchTarget {
+        DocumentManifestAuthorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for DocumentManifestAuthorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> DocumentManifestAuthorSearchTarget {
+        DocumentManifestAuthorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for DocumentManifestAuthorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> DocumentManifestAuthorSearchTarget {
+        DocumentManifestAuthorSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The item reference search parameter of the DocumentManifest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentManifestItemSearchReference;
+impl ReferenceSearchParameter for DocumentManifestItemSearchReference {
+    type Resource = resources::DocumentManifest;
+    type Target = resources::Resource;
+    fn parameter(&self) -> DocumentManifestSearchParameter {
+        DocumentManifestSearchParameter::Item
+    }
+}
+/// The recipient reference search parameter of the DocumentManifest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentManifestRecipientSearchReference;
+impl ReferenceSearchParameter for DocumentManifestRecipientSearchReference {
+    type Resource = resources::DocumentManifest;
+    type Target = DocumentManifestRecipientSearchTarget;
+    fn parameter(&self) -> DocumentManifestSearchParameter {
+        DocumentManifestSearchParameter::Recipient
+    }
+}
+/// Target resources of the recipient reference search parameter of the DocumentManifest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentManifestRecipientSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for DocumentManifestRecipientSearchTarget {
+    fn from(resource: resources::Practitioner) -> DocumentManifestRecipientSearchTarget {
+        DocumentManifestRecipientSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for DocumentManifestRecipientSearchTarget {
+    fn from(resource: resources::Organization) -> DocumentManifestRecipientSearchTarget {
+        DocumentManifestRecipientSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for DocumentManifestRecipientSearchTarget {
+    fn from(resource: resources::Patient) -> DocumentManifestRecipientSearchTarget {
+        DocumentManifestRecipientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for DocumentManifestRecipientSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> DocumentManifestRecipientSearchTarget {
+        DocumentManifestRecipientSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for DocumentManifestRecipientSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> DocumentManifestRecipientSearchTarget {
+        DocumentManifestRecipientSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The related-ref reference search parameter of the DocumentManifest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentManifestRelatedRefSearchReference;
+impl ReferenceSearchParameter for DocumentManifestRelatedRefSearchReference {
+    type Resource = resources::DocumentManifest;
+    type Target = resources::Resource;
+    fn parameter(&self) -> DocumentManifestSearchParameter {
+        DocumentManifestSearchParameter::RelatedRef
+    }
+}
+/// The subject reference search parameter of the DocumentManifest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentManifestSubjectSearchReference;
+impl ReferenceSearchParameter for DocumentManifestSubjectSearchReference {
+    type Resource = resources::DocumentManifest;
+    type Target = DocumentManifestSubjectSearchTarget;
+    fn parameter(&self) -> DocumentManifestSearchParameter {
+        DocumentManifestSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the DocumentManifest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentManifestSubjectSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Practitioner> for DocumentManifestSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> DocumentManifestSubjectSearchTarget {
+        DocumentManifestSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for DocumentManifestSubjectSearchTarget {
+    fn from(resource: resources::Group) -> DocumentManifestSubjectSearchTarget {
+        DocumentManifestSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Device> for DocumentManifestSubjectSearchTarget {
+    fn from(resource: resources::Device) -> DocumentManifestSubjectSearchTarget {
+        DocumentManifestSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for DocumentManifestSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> DocumentManifestSubjectSearchTarget {
+        DocumentManifestSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferencePatientSearchReference;
+impl ReferenceSearchParameter for DocumentReferencePatientSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = resources::Patient;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceEncounterSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceEncounterSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Encounter
+    }
+}
+/// The authenticator reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceAuthenticatorSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceAuthenticatorSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = DocumentReferenceAuthenticatorSearchTarget;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Authenticator
+    }
+}
+/// Target resources of the authenticator reference search parameter of the DocumentReference resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentReferenceAuthenticatorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for DocumentReferenceAuthenticatorSearchTarget {
+    fn from(resource: resources::Practitioner) -> DocumentReferenceAuthenticatorSearchTarget {
+        DocumentReferenceAuthenticatorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for DocumentReferenceAuthenticatorSearchTarget {
+    fn from(resource: resources::Organization) -> DocumentReferenceAuthenticatorSearchTarget {
+        DocumentReferenceAuthenticatorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for DocumentReferenceAuthenticatorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> DocumentReferenceAuthenticatorSearchTarget {
+        DocumentReferenceAuthenticatorSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The author reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceAuthorSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceAuthorSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = DocumentReferenceAuthorSearchTarget;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the DocumentReference resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentReferenceAuthorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for DocumentReferenceAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> DocumentReferenceAuthorSearchTarget {
+        DocumentReferenceAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for DocumentReferenceAuthorSearchTarget {
+    fn from(resource: resources::Organization) -> DocumentReferenceAuthorSearchTarget {
+        DocumentReferenceAuthorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for DocumentReferenceAuthorSearchTarget {
+    fn from(resource: resources::Device) -> DocumentReferenceAuthorSearchTarget {
+        DocumentReferenceAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for DocumentReferenceAuthorSearchTarget {
+    fn from(resource: resources::Patient) -> DocumentReferenceAuthorSearchTarget {
+        DocumentReferenceAuthorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for DocumentReferenceAuthorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> DocumentReferenceAuthorSearchTarget {
+        DocumentReferenceAuthorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for DocumentReferenceAuthorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> DocumentReferenceAuthorSearchTarget {
+        DocumentReferenceAuthorSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The custodian reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceCustodianSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceCustodianSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = resources::Organization;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Custodian
+    }
+}
+/// The related reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceRelatedSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceRelatedSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = resources::Resource;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Related
+    }
+}
+/// The relatesto reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceRelatestoSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceRelatestoSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = resources::DocumentReference;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Relatesto
+    }
+}
+/// The subject reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceSubjectSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceSubjectSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = DocumentReferenceSubjectSearchTarget;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the DocumentReference resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentReferenceSubjectSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Practitioner> for DocumentReferenceSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> DocumentReferenceSubjectSearchTarget {
+        DocumentReferenceSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for DocumentReferenceSubjectSearchTarget {
+    fn from(resource: resources::Group) -> DocumentReferenceSubjectSearchTarget {
+        DocumentReferenceSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Device> for DocumentReferenceSubjectSearchTarget {
+    fn from(resource: resources::Device) -> DocumentReferenceSubjectSearchTarget {
+        DocumentReferenceSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for DocumentReferenceSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> DocumentReferenceSubjectSearchTarget {
+        DocumentReferenceSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterPatientSearchReference;
+impl ReferenceSearchParameter for EncounterPatientSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::Patient;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Patient
+    }
+}
+/// The account reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterAccountSearchReference;
+impl ReferenceSearchParameter for EncounterAccountSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::Account;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Account
+    }
+}
+/// The appointment reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterAppointmentSearchReference;
+impl ReferenceSearchParameter for EncounterAppointmentSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::Appointment;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Appointment
+    }
+}
+/// The based-on reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterBasedOnSearchReference;
+impl ReferenceSearchParameter for EncounterBasedOnSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::ServiceRequest;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::BasedOn
+    }
+}
+/// The diagnosis reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterDiagnosisSearchReference;
+impl ReferenceSearchParameter for EncounterDiagnosisSearchReference {
+    type Resource = resources::Encounter;
+    type Target = EncounterDiagnosisSearchTarget;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Diagnosis
+    }
+}
+/// Target resources of the diagnosis reference search parameter of the Encounter resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EncounterDiagnosisSearchTarget {
+    /// Variant for Condition target resources
+    Condition(resources::Condition),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+}
+impl From<resources::Condition> for EncounterDiagnosisSearchTarget {
+    fn from(resource: resources::Condition) -> EncounterDiagnosisSearchTarget {
+        EncounterDiagnosisSearchTarget::Condition(resource)
+    }
+}
+impl From<resources::Procedure> for EncounterDiagnosisSearchTarget {
+    fn from(resource: resources::Procedure) -> EncounterDiagnosisSearchTarget {
+        EncounterDiagnosisSearchTarget::Procedure(resource)
+    }
+}
+/// The episode-of-care reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterEpisodeOfCareSearchReference;
+impl ReferenceSearchParameter for EncounterEpisodeOfCareSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::EpisodeOfCare;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::EpisodeOfCare
+    }
+}
+/// The location reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterLocationSearchReference;
+impl ReferenceSearchParameter for EncounterLocationSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::Location;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Location
+    }
+}
+/// The part-of reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterPartOfSearchReference;
+impl ReferenceSearchParameter for EncounterPartOfSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::PartOf
+    }
+}
+/// The participant reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterParticipantSearchReference;
+impl ReferenceSearchParameter for EncounterParticipantSearchReference {
+    type Resource = resources::Encounter;
+    type Target = EncounterParticipantSearchTarget;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Participant
+    }
+}
+/// Target resources of the participant reference search parameter of the Encounter resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EncounterParticipantSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for EncounterParticipantSearchTarget {
+    fn from(resource: resources::Practitioner) -> EncounterParticipantSearchTarget {
+        EncounterParticipantSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::PractitionerRole> for EncounterParticipantSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> EncounterParticipantSearchTarget {
+        EncounterParticipantSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for EncounterParticipantSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> EncounterParticipantSearchTarget {
+        EncounterParticipantSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The practitioner reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterPractitionerSearchReference;
+impl ReferenceSearchParameter for EncounterPractitionerSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Practitioner
+    }
+}
+/// The reason-reference reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterReasonReferenceSearchReference;
+impl ReferenceSearchParameter for EncounterReasonReferenceSearchReference {
+    type Resource = resources::Encounter;
+    type Target = EncounterReasonReferenceSearchTarget;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::ReasonReference
+    }
+}
+/// Target resources of the reason-reference reference search parameter of the Encounter resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EncounterReasonReferenceSearchTarget {
+    /// Variant for Condition target resources
+    Condition(resources::Condition),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for ImmunizationRecommendation target resources
+    ImmunizationRecommendation(resources::ImmunizationRecommendation),
+}
+impl From<resources::Condition> for EncounterReasonReferenceSearchTarget {
+    fn from(resource: resources::Condition) -> EncounterReasonReferenceSearchTarget {
+        EncounterReasonReferenceSearchTarget::Condition(resource)
+    }
+}
+impl From<resources::Observation> for EncounterReasonReferenceSearchTarget {
+    fn from(resource: resources::Observation) -> EncounterReasonReferenceSearchTarget {
+        EncounterReasonReferenceSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::Procedure> for EncounterReasonReferenceSearchTarget {
+    fn from(resource: resources::Procedure) -> EncounterReasonReferenceSearchTarget {
+        EncounterReasonReferenceSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::ImmunizationRecommendation> for EncounterReasonReferenceSearchTarget {
+    fn from(resource: resources::ImmunizationRecommendation) -> EncounterReasonReferenceSearchTarget {
+        EncounterReasonReferenceSearchTarget::ImmunizationRecommendation(resource)
+    }
+}
+/// The service-provider reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterServiceProviderSearchReference;
+impl ReferenceSearchParameter for EncounterServiceProviderSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::Organization;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::ServiceProvider
+    }
+}
+/// The subject reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterSubjectSearchReference;
+impl ReferenceSearchParameter for EncounterSubjectSearchReference {
+    type Resource = resources::Encounter;
+    type Target = EncounterSubjectSearchTarget;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Encounter resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EncounterSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for EncounterSubjectSearchTarget {
+    fn from(resource: resources::Group) -> EncounterSubjectSearchTarget {
+        EncounterSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for EncounterSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> EncounterSubjectSearchTarget {
+        EncounterSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The organization reference search parameter of the Endpoint resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EndpointOrganizationSearchReference;
+impl ReferenceSearchParameter for EndpointOrganizationSearchReference {
+    type Resource = resources::Endpoint;
+    type Target = resources::Organization;
+    fn parameter(&self) -> EndpointSearchParameter {
+        EndpointSearchParameter::Organization
+    }
+}
+/// The patient reference search parameter of the EnrollmentRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EnrollmentRequestPatientSearchReference;
+impl ReferenceSearchParameter for EnrollmentRequestPatientSearchReference {
+    type Resource = resources::EnrollmentRequest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> EnrollmentRequestSearchParameter {
+        EnrollmentRequestSearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the EnrollmentRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EnrollmentRequestSubjectSearchReference;
+impl ReferenceSearchParameter for EnrollmentRequestSubjectSearchReference {
+    type Resource = resources::EnrollmentRequest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> EnrollmentRequestSearchParameter {
+        EnrollmentRequestSearchParameter::Subject
+    }
+}
+/// The request reference search parameter of the EnrollmentResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EnrollmentResponseRequestSearchReference;
+impl ReferenceSearchParameter for EnrollmentResponseRequestSearchReference {
+    type Resource = resources::EnrollmentResponse;
+    type Target = resources::EnrollmentRequest;
+    fn parameter(&self) -> EnrollmentResponseSearchParameter {
+        EnrollmentResponseSearchParameter::Request
+    }
+}
+/// The patient reference search parameter of the EpisodeOfCare resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EpisodeOfCarePatientSearchReference;
+impl ReferenceSearchParameter for EpisodeOfCarePatientSearchReference {
+    type Resource = resources::EpisodeOfCare;
+    type Target = resources::Patient;
+    fn parameter(&self) -> EpisodeOfCareSearchParameter {
+        EpisodeOfCareSearchParameter::Patient
+    }
+}
+/// The care-manager reference search parameter of the EpisodeOfCare resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EpisodeOfCareCareManagerSearchReference;
+impl ReferenceSearchParameter for EpisodeOfCareCareManagerSearchReference {
+    type Resource = resources::EpisodeOfCare;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> EpisodeOfCareSearchParameter {
+        EpisodeOfCareSearchParameter::CareManager
+    }
+}
+/// The condition reference search parameter of the EpisodeOfCare resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EpisodeOfCareConditionSearchReference;
+impl ReferenceSearchParameter for EpisodeOfCareConditionSearchReference {
+    type Resource = resources::EpisodeOfCare;
+    type Target = resources::Condition;
+    fn parameter(&self) -> EpisodeOfCareSearchParameter {
+        EpisodeOfCareSearchParameter::Condition
+    }
+}
+/// The incoming-referral reference search parameter of the EpisodeOfCare resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EpisodeOfCareIncomingReferralSearchReference;
+impl ReferenceSearchParameter for EpisodeOfCareIncomingReferralSearchReference {
+    type Resource = resources::EpisodeOfCare;
+    type Target = resources::ServiceRequest;
+    fn parameter(&self) -> EpisodeOfCareSearchParameter {
+        EpisodeOfCareSearchParameter::IncomingReferral
+    }
+}
+/// The organization reference search parameter of the EpisodeOfCare resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EpisodeOfCareOrganizationSearchReference;
+impl ReferenceSearchParameter for EpisodeOfCareOrganizationSearchReference {
+    type Resource = resources::EpisodeOfCare;
+    type Target = resources::Organization;
+    fn parameter(&self) -> EpisodeOfCareSearchParameter {
+        EpisodeOfCareSearchParameter::Organization
+    }
+}
+/// The composed-of reference search parameter of the EventDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EventDefinitionComposedOfSearchReference;
+impl ReferenceSearchParameter for EventDefinitionComposedOfSearchReference {
+    type Resource = resources::EventDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> EventDefinitionSearchParameter {
+        EventDefinitionSearchParameter::ComposedOf
+    }
+}
+/// The depends-on reference search parameter of the EventDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EventDefinitionDependsOnSearchReference;
+impl ReferenceSearchParameter for EventDefinitionDependsOnSearchReference {
+    type Resource = resources::EventDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> EventDefinitionSearchParameter {
+        EventDefinitionSearchParameter::DependsOn
+    }
+}
+/// The derived-from reference search parameter of the EventDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EventDefinitionDerivedFromSearchReference;
+impl ReferenceSearchParameter for EventDefinitionDerivedFromSearchReference {
+    type Resource = resources::EventDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> EventDefinitionSearchParameter {
+        EventDefinitionSearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the EventDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EventDefinitionPredecessorSearchReference;
+impl ReferenceSearchParameter for EventDefinitionPredecessorSearchReference {
+    type Resource = resources::EventDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> EventDefinitionSearchParameter {
+        EventDefinitionSearchParameter::Predecessor
+    }
+}
+/// The successor reference search parameter of the EventDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EventDefinitionSuccessorSearchReference;
+impl ReferenceSearchParameter for EventDefinitionSuccessorSearchReference {
+    type Resource = resources::EventDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> EventDefinitionSearchParameter {
+        EventDefinitionSearchParameter::Successor
+    }
+}
+/// The care-team reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitCareTeamSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitCareTeamSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = ExplanationOfBenefitCareTeamSearchTarget;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::CareTeam
+    }
+}
+/// Target resources of the care-team reference search parameter of the ExplanationOfBenefit resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExplanationOfBenefitCareTeamSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for ExplanationOfBenefitCareTeamSearchTarget {
+    fn from(resource: resources::Practitioner) -> ExplanationOfBenefitCareTeamSearchTarget {
+        ExplanationOfBenefitCareTeamSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ExplanationOfBenefitCareTeamSearchTarget {
+    fn from(resource: resources::Organization) -> ExplanationOfBenefitCareTeamSearchTarget {
+        ExplanationOfBenefitCareTeamSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ExplanationOfBenefitCareTeamSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ExplanationOfBenefitCareTeamSearchTarget {
+        ExplanationOfBenefitCareTeamSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The claim reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitClaimSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitClaimSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Claim;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Claim
+    }
+}
+/// The coverage reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitCoverageSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitCoverageSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Coverage;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Coverage
+    }
+}
+/// The detail-udi reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitDetailUdiSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitDetailUdiSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Device;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::DetailUdi
+    }
+}
+/// The encounter reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitEncounterSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitEncounterSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Encounter
+    }
+}
+/// The enterer reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitEntererSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitEntererSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = ExplanationOfBenefitEntererSearchTarget;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Enterer
+    }
+}
+/// Target resources of the enterer reference search parameter of the ExplanationOfBenefit resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExplanationOfBenefitEntererSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for ExplanationOfBenefitEntererSearchTarget {
+    fn from(resource: resources::Practitioner) -> ExplanationOfBenefitEntererSearchTarget {
+        ExplanationOfBenefitEntererSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ExplanationOfBenefitEntererSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ExplanationOfBenefitEntererSearchTarget {
+        ExplanationOfBenefitEntererSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The facility reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitFacilitySearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitFacilitySearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Location;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Facility
+    }
+}
+/// The item-udi reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitItemUdiSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitItemUdiSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Device;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::ItemUdi
+    }
+}
+/// The patient reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitPatientSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitPatientSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Patient
+    }
+}
+/// The payee reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitPayeeSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitPayeeSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = ExplanationOfBenefitPayeeSearchTarget;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Payee
+    }
+}
+/// Target resources of the payee reference search parameter of the ExplanationOfBenefit resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExplanationOfBenefitPayeeSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ExplanationOfBenefitPayeeSearchTarget {
+    fn from(resource: resources::Practitioner) -> ExplanationOfBenefitPayeeSearchTarget {
+        ExplanationOfBenefitPayeeSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ExplanationOfBenefitPayeeSearchTarget {
+    fn from(resource: resources::Organization) -> ExplanationOfBenefitPayeeSearchTarget {
+        ExplanationOfBenefitPayeeSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for ExplanationOfBenefitPayeeSearchTarget {
+    fn from(resource: resources::Patient) -> ExplanationOfBenefitPayeeSearchTarget {
+        ExplanationOfBenefitPayeeSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ExplanationOfBenefitPayeeSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ExplanationOfBenefitPayeeSearchTarget {
+        ExplanationOfBenefitPayeeSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ExplanationOfBenefitPayeeSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ExplanationOfBenefitPayeeSearchTarget {
+        ExplanationOfBenefitPayeeSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The procedure-udi reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitProcedureUdiSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitProcedureUdiSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Device;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::ProcedureUdi
+    }
+}
+/// The provider reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitProviderSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitProviderSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = ExplanationOfBenefitProviderSearchTarget;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Provider
+    }
+}
+/// Target resources of the provider reference search parameter of the ExplanationOfBenefit resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExplanationOfBenefitProviderSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for ExplanationOfBenefitProviderSearchTarget {
+    fn from(resource: resources::Practitioner) -> ExplanationOfBenefitProviderSearchTarget {
+        ExplanationOfBenefitProviderSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ExplanationOfBenefitProviderSearchTarget {
+    fn from(resource: resources::Organization) -> ExplanationOfBenefitProviderSearchTarget {
+        ExplanationOfBenefitProviderSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ExplanationOfBenefitProviderSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ExplanationOfBenefitProviderSearchTarget {
+        ExplanationOfBenefitProviderSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The subdetail-udi reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitSubdetailUdiSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitSubdetailUdiSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Device;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::SubdetailUdi
+    }
+}
+/// The patient reference search parameter of the FamilyMemberHistory resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FamilyMemberHistoryPatientSearchReference;
+impl ReferenceSearchParameter for FamilyMemberHistoryPatientSearchReference {
+    type Resource = resources::FamilyMemberHistory;
+    type Target = resources::Patient;
+    fn parameter(&self) -> FamilyMemberHistorySearchParameter {
+        FamilyMemberHistorySearchParameter::Patient
+    }
+}
+/// The instantiates-canonical reference search parameter of the FamilyMemberHistory resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FamilyMemberHistoryInstantiatesCanonicalSearchReference;
+impl ReferenceSearchParameter for FamilyMemberHistoryInstantiatesCanonicalSearchReference {
+    type Resource = resources::FamilyMemberHistory;
+    type Target = FamilyMemberHistoryInstantiatesCanonicalSearchTarget;
+    fn parameter(&self) -> FamilyMemberHistorySearchParameter {
+        FamilyMemberHistorySearchParameter::InstantiatesCanonical
+    }
+}
+/// Target resources of the instantiates-canonical reference search parameter of the FamilyMemberHistory resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+    /// Variant for Questionnaire target resources
+    Questionnaire(resources::Questionnaire),
+    /// Variant for Measure target resources
+    Measure(resources::Measure),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for OperationDefinition target resources
+    OperationDefinition(resources::OperationDefinition),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+}
+impl From<resources::Questionnaire> for FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Questionnaire) -> FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+        FamilyMemberHistoryInstantiatesCanonicalSearchTarget::Questionnaire(resource)
+    }
+}
+impl From<resources::Measure> for FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Measure) -> FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+        FamilyMemberHistoryInstantiatesCanonicalSearchTarget::Measure(resource)
+    }
+}
+impl From<resources::PlanDefinition> for FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+        FamilyMemberHistoryInstantiatesCanonicalSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::OperationDefinition> for FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::OperationDefinition) -> FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+        FamilyMemberHistoryInstantiatesCanonicalSearchTarget::OperationDefinition(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+        FamilyMemberHistoryInstantiatesCanonicalSearchTarget::ActivityDefinition(resource)
+    }
+}
+/// The patient reference search parameter of the Flag resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FlagPatientSearchReference;
+impl ReferenceSearchParameter for FlagPatientSearchReference {
+    type Resource = resources::Flag;
+    type Target = resources::Patient;
+    fn parameter(&self) -> FlagSearchParameter {
+        FlagSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the Flag resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FlagEncounterSearchReference;
+impl ReferenceSearchParameter for FlagEncounterSearchReference {
+    type Resource = resources::Flag;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> FlagSearchParameter {
+        FlagSearchParameter::Encounter
+    }
+}
+/// The author reference search parameter of the Flag resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FlagAuthorSearchReference;
+impl ReferenceSearchParameter for FlagAuthorSearchReference {
+    type Resource = resources::Flag;
+    type Target = FlagAuthorSearchTarget;
+    fn parameter(&self) -> FlagSearchParameter {
+        FlagSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the Flag resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FlagAuthorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for FlagAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> FlagAuthorSearchTarget {
+        FlagAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for FlagAuthorSearchTarget {
+    fn from(resource: resources::Organization) -> FlagAuthorSearchTarget {
+        FlagAuthorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for FlagAuthorSearchTarget {
+    fn from(resource: resources::Device) -> FlagAuthorSearchTarget {
+        FlagAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for FlagAuthorSearchTarget {
+    fn from(resource: resources::Patient) -> FlagAuthorSearchTarget {
+        FlagAuthorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for FlagAuthorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> FlagAuthorSearchTarget {
+        FlagAuthorSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The subject reference search parameter of the Flag resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FlagSubjectSearchReference;
+impl ReferenceSearchParameter for FlagSubjectSearchReference {
+    type Resource = resources::Flag;
+    type Target = FlagSubjectSearchTarget;
+    fn parameter(&self) -> FlagSearchParameter {
+        FlagSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Flag resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FlagSubjectSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Practitioner> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Group) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Organization> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Medication> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Medication) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Medication(resource)
+    }
+}
+impl From<resources::Patient> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PlanDefinition> for FlagSubjectSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::Procedure> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Procedure) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::Location> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Location) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Location(resource)
+    }
+}
+/// The patient reference search parameter of the Goal resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GoalPatientSearchReference;
+impl ReferenceSearchParameter for GoalPatientSearchReference {
+    type Resource = resources::Goal;
+    type Target = resources::Patient;
+    fn parameter(&self) -> GoalSearchParameter {
+        GoalSearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the Goal resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GoalSubjectSearchReference;
+impl ReferenceSearchParameter for GoalSubjectSearchReference {
+    type Resource = resources::Goal;
+    type Target = GoalSubjectSearchTarget;
+    fn parameter(&self) -> GoalSearchParameter {
+        GoalSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Goal resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GoalSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for GoalSubjectSearchTarget {
+    fn from(resource: resources::Group) -> GoalSubjectSearchTarget {
+        GoalSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Organization> for GoalSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> GoalSubjectSearchTarget {
+        GoalSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for GoalSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> GoalSubjectSearchTarget {
+        GoalSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The managing-entity reference search parameter of the Group resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GroupManagingEntitySearchReference;
+impl ReferenceSearchParameter for GroupManagingEntitySearchReference {
+    type Resource = resources::Group;
+    type Target = GroupManagingEntitySearchTarget;
+    fn parameter(&self) -> GroupSearchParameter {
+        GroupSearchParameter::ManagingEntity
+    }
+}
+/// Target resources of the managing-entity reference search parameter of the Group resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GroupManagingEntitySearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for GroupManagingEntitySearchTarget {
+    fn from(resource: resources::Practitioner) -> GroupManagingEntitySearchTarget {
+        GroupManagingEntitySearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for GroupManagingEntitySearchTarget {
+    fn from(resource: resources::Organization) -> GroupManagingEntitySearchTarget {
+        GroupManagingEntitySearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for GroupManagingEntitySearchTarget {
+    fn from(resource: resources::PractitionerRole) -> GroupManagingEntitySearchTarget {
+        GroupManagingEntitySearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for GroupManagingEntitySearchTarget {
+    fn from(resource: resources::RelatedPerson) -> GroupManagingEntitySearchTarget {
+        GroupManagingEntitySearchTarget::RelatedPerson(resource)
+    }
+}
+/// The member reference search parameter of the Group resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GroupMemberSearchReference;
+impl ReferenceSearchParameter for GroupMemberSearchReference {
+    type Resource = resources::Group;
+    type Target = GroupMemberSearchTarget;
+    fn parameter(&self) -> GroupSearchParameter {
+        GroupSearchParameter::Member
+    }
+}
+/// Target resources of the member reference search parameter of the Group resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GroupMemberSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for GroupMemberSearchTarget {
+    fn from(resource: resources::Practitioner) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for GroupMemberSearchTarget {
+    fn from(resource: resources::Group) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Device> for GroupMemberSearchTarget {
+    fn from(resource: resources::Device) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Medication> for GroupMemberSearchTarget {
+    fn from(resource: resources::Medication) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::Medication(resource)
+    }
+}
+impl From<resources::Patient> for GroupMemberSearchTarget {
+    fn from(resource: resources::Patient) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Substance> for GroupMemberSearchTarget {
+    fn from(resource: resources::Substance) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::Substance(resource)
+    }
+}
+impl From<resources::PractitionerRole> for GroupMemberSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for GroupMemberSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The patient reference search parameter of the GuidanceResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GuidanceResponsePatientSearchReference;
+impl ReferenceSearchParameter for GuidanceResponsePatientSearchReference {
+    type Resource = resources::GuidanceResponse;
+    type Target = resources::Patient;
+    fn parameter(&self) -> GuidanceResponseSearchParameter {
+        GuidanceResponseSearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the GuidanceResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GuidanceResponseSubjectSearchReference;
+impl ReferenceSearchParameter for GuidanceResponseSubjectSearchReference {
+    type Resource = resources::GuidanceResponse;
+    type Target = GuidanceResponseSubjectSearchTarget;
+    fn parameter(&self) -> GuidanceResponseSearchParameter {
+        GuidanceResponseSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the GuidanceResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GuidanceResponseSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for GuidanceResponseSubjectSearchTarget {
+    fn from(resource: resources::Group) -> GuidanceResponseSubjectSearchTarget {
+        GuidanceResponseSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for GuidanceResponseSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> GuidanceResponseSubjectSearchTarget {
+        GuidanceResponseSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The coverage-area reference search parameter of the HealthcareService resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HealthcareServiceCoverageAreaSearchReference;
+impl ReferenceSearchParameter for HealthcareServiceCoverageAreaSearchReference {
+    type Resource = resources::HealthcareService;
+    type Target = resources::Location;
+    fn parameter(&self) -> HealthcareServiceSearchParameter {
+        HealthcareServiceSearchParameter::CoverageArea
+    }
+}
+/// The endpoint reference search parameter of the HealthcareService resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HealthcareServiceEndpointSearchReference;
+impl ReferenceSearchParameter for HealthcareServiceEndpointSearchReference {
+    type Resource = resources::HealthcareService;
+    type Target = resources::Endpoint;
+    fn parameter(&self) -> HealthcareServiceSearchParameter {
+        HealthcareServiceSearchParameter::Endpoint
+    }
+}
+/// The location reference search parameter of the HealthcareService resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HealthcareServiceLocationSearchReference;
+impl ReferenceSearchParameter for HealthcareServiceLocationSearchReference {
+    type Resource = resources::HealthcareService;
+    type Target = resources::Location;
+    fn parameter(&self) -> HealthcareServiceSearchParameter {
+        HealthcareServiceSearchParameter::Location
+    }
+}
+/// The organization reference search parameter of the HealthcareService resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HealthcareServiceOrganizationSearchReference;
+impl ReferenceSearchParameter for HealthcareServiceOrganizationSearchReference {
+    type Resource = resources::HealthcareService;
+    type Target = resources::Organization;
+    fn parameter(&self) -> HealthcareServiceSearchParameter {
+        HealthcareServiceSearchParameter::Organization
+    }
+}
+/// The patient reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudyPatientSearchReference;
+impl ReferenceSearchParameter for ImagingStudyPatientSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::Patient
+    }
+}
+/// The basedon reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudyBasedonSearchReference;
+impl ReferenceSearchParameter for ImagingStudyBasedonSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = ImagingStudyBasedonSearchTarget;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::Basedon
+    }
+}
+/// Target resources of the basedon reference search parameter of the ImagingStudy resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImagingStudyBasedonSearchTarget {
+    /// Variant for Appointment target resources
+    Appointment(resources::Appointment),
+    /// Variant for AppointmentResponse target resources
+    AppointmentResponse(resources::AppointmentResponse),
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for Task target resources
+    Task(resources::Task),
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+}
+impl From<resources::Appointment> for ImagingStudyBasedonSearchTarget {
+    fn from(resource: resources::Appointment) -> ImagingStudyBasedonSearchTarget {
+        ImagingStudyBasedonSearchTarget::Appointment(resource)
+    }
+}
+impl From<resources::AppointmentResponse> for ImagingStudyBasedonSearchTarget {
+    fn from(resource: resources::AppointmentResponse) -> ImagingStudyBasedonSearchTarget {
+        ImagingStudyBasedonSearchTarget::AppointmentResponse(resource)
+    }
+}
+impl From<resources::CarePlan> for ImagingStudyBasedonSearchTarget {
+    fn from(resource: resources::CarePlan) -> ImagingStudyBasedonSearchTarget {
+        ImagingStudyBasedonSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::Task> for ImagingStudyBasedonSearchTarget {
+    fn from(resource: resources::Task) -> ImagingStudyBasedonSearchTarget {
+        ImagingStudyBasedonSearchTarget::Task(resource)
+    }
+}
+impl From<resources::ServiceRequest> for ImagingStudyBasedonSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> ImagingStudyBasedonSearchTarget {
+        ImagingStudyBasedonSearchTarget::ServiceRequest(resource)
+    }
+}
+/// The encounter reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudyEncounterSearchReference;
+impl ReferenceSearchParameter for ImagingStudyEncounterSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::Encounter
+    }
+}
+/// The endpoint reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudyEndpointSearchReference;
+impl ReferenceSearchParameter for ImagingStudyEndpointSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = resources::Endpoint;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::Endpoint
+    }
+}
+/// The interpreter reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudyInterpreterSearchReference;
+impl ReferenceSearchParameter for ImagingStudyInterpreterSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = ImagingStudyInterpreterSearchTarget;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::Interpreter
+    }
+}
+/// Target resources of the interpreter reference search parameter of the ImagingStudy resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImagingStudyInterpreterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for ImagingStudyInterpreterSearchTarget {
+    fn from(resource: resources::Practitioner) -> ImagingStudyInterpreterSearchTarget {
+        ImagingStudyInterpreterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ImagingStudyInterpreterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ImagingStudyInterpreterSearchTarget {
+        ImagingStudyInterpreterSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The performer reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudyPerformerSearchReference;
+impl ReferenceSearchParameter for ImagingStudyPerformerSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = ImagingStudyPerformerSearchTarget;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the ImagingStudy resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImagingStudyPerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ImagingStudyPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ImagingStudyPerformerSearchTarget {
+        ImagingStudyPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ImagingStudyPerformerSearchTarget {
+    fn from(resource: resources::Organization) -> ImagingStudyPerformerSearchTarget {
+        ImagingStudyPerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for ImagingStudyPerformerSearchTarget {
+    fn from(resource: resources::CareTeam) -> ImagingStudyPerformerSearchTarget {
+        ImagingStudyPerformerSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for ImagingStudyPerformerSearchTarget {
+    fn from(resource: resources::Device) -> ImagingStudyPerformerSearchTarget {
+        ImagingStudyPerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ImagingStudyPerformerSearchTarget {
+    fn from(resource: resources::Patient) -> ImagingStudyPerformerSearchTarget {
+        ImagingStudyPerformerSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ImagingStudyPerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ImagingStudyPerformerSearchTarget {
+        ImagingStudyPerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ImagingStudyPerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ImagingStudyPerformerSearchTarget {
+        ImagingStudyPerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The referrer reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudyReferrerSearchReference;
+impl ReferenceSearchParameter for ImagingStudyReferrerSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = ImagingStudyReferrerSearchTarget;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::Referrer
+    }
+}
+/// Target resources of the referrer reference search parameter of the ImagingStudy resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImagingStudyReferrerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for ImagingStudyReferrerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ImagingStudyReferrerSearchTarget {
+        ImagingStudyReferrerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ImagingStudyReferrerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ImagingStudyReferrerSearchTarget {
+        ImagingStudyReferrerSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The subject reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudySubjectSearchReference;
+impl ReferenceSearchParameter for ImagingStudySubjectSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = ImagingStudySubjectSearchTarget;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the ImagingStudy resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImagingStudySubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for ImagingStudySubjectSearchTarget {
+    fn from(resource: resources::Group) -> ImagingStudySubjectSearchTarget {
+        ImagingStudySubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Device> for ImagingStudySubjectSearchTarget {
+    fn from(resource: resources::Device) -> ImagingStudySubjectSearchTarget {
+        ImagingStudySubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ImagingStudySubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ImagingStudySubjectSearchTarget {
+        ImagingStudySubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the Immunization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationPatientSearchReference;
+impl ReferenceSearchParameter for ImmunizationPatientSearchReference {
+    type Resource = resources::Immunization;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ImmunizationSearchParameter {
+        ImmunizationSearchParameter::Patient
+    }
+}
+/// The location reference search parameter of the Immunization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationLocationSearchReference;
+impl ReferenceSearchParameter for ImmunizationLocationSearchReference {
+    type Resource = resources::Immunization;
+    type Target = resources::Location;
+    fn parameter(&self) -> ImmunizationSearchParameter {
+        ImmunizationSearchParameter::Location
+    }
+}
+/// The manufacturer reference search parameter of the Immunization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationManufacturerSearchReference;
+impl ReferenceSearchParameter for ImmunizationManufacturerSearchReference {
+    type Resource = resources::Immunization;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ImmunizationSearchParameter {
+        ImmunizationSearchParameter::Manufacturer
+    }
+}
+/// The performer reference search parameter of the Immunization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationPerformerSearchReference;
+impl ReferenceSearchParameter for ImmunizationPerformerSearchReference {
+    type Resource = resources::Immunization;
+    type Target = ImmunizationPerformerSearchTarget;
+    fn parameter(&self) -> ImmunizationSearchParameter {
+        ImmunizationSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the Immunization resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImmunizationPerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for ImmunizationPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ImmunizationPerformerSearchTarget {
+        ImmunizationPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ImmunizationPerformerSearchTarget {
+    fn from(resource: resources::Organization) -> ImmunizationPerformerSearchTarget {
+        ImmunizationPerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ImmunizationPerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ImmunizationPerformerSearchTarget {
+        ImmunizationPerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The reaction reference search parameter of the Immunization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationReactionSearchReference;
+impl ReferenceSearchParameter for ImmunizationReactionSearchReference {
+    type Resource = resources::Immunization;
+    type Target = resources::Observation;
+    fn parameter(&self) -> ImmunizationSearchParameter {
+        ImmunizationSearchParameter::Reaction
+    }
+}
+/// The reason-reference reference search parameter of the Immunization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationReasonReferenceSearchReference;
+impl ReferenceSearchParameter for ImmunizationReasonReferenceSearchReference {
+    type Resource = resources::Immunization;
+    type Target = ImmunizationReasonReferenceSearchTarget;
+    fn parameter(&self) -> ImmunizationSearchParameter {
+        ImmunizationSearchParameter::ReasonReference
+    }
+}
+/// Target resources of the reason-reference reference search parameter of the Immunization resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImmunizationReasonReferenceSearchTarget {
+    /// Variant for Condition target resources
+    Condition(resources::Condition),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for DiagnosticReport target resources
+    DiagnosticReport(resources::DiagnosticReport),
+}
+impl From<resources::Condition> for ImmunizationReasonReferenceSearchTarget {
+    fn from(resource: resources::Condition) -> ImmunizationReasonReferenceSearchTarget {
+        ImmunizationReasonReferenceSearchTarget::Condition(resource)
+    }
+}
+impl From<resources::Observation> for ImmunizationReasonReferenceSearchTarget {
+    fn from(resource: resources::Observation) -> ImmunizationReasonReferenceSearchTarget {
+        ImmunizationReasonReferenceSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::DiagnosticReport> for ImmunizationReasonReferenceSearchTarget {
+    fn from(resource: resources::DiagnosticReport) -> ImmunizationReasonReferenceSearchTarget {
+        ImmunizationReasonReferenceSearchTarget::DiagnosticReport(resource)
+    }
+}
+/// The immunization-event reference search parameter of the ImmunizationEvaluation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationEvaluationImmunizationEventSearchReference;
+impl ReferenceSearchParameter for ImmunizationEvaluationImmunizationEventSearchReference {
+    type Resource = resources::ImmunizationEvaluation;
+    type Target = resources::Immunization;
+    fn parameter(&self) -> ImmunizationEvaluationSearchParameter {
+        ImmunizationEvaluationSearchParameter::ImmunizationEvent
+    }
+}
+/// The patient reference search parameter of the ImmunizationEvaluation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationEvaluationPatientSearchReference;
+impl ReferenceSearchParameter for ImmunizationEvaluationPatientSearchReference {
+    type Resource = resources::ImmunizationEvaluation;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ImmunizationEvaluationSearchParameter {
+        ImmunizationEvaluationSearchParameter::Patient
+    }
+}
+/// The information reference search parameter of the ImmunizationRecommendation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationRecommendationInformationSearchReference;
+impl ReferenceSearchParameter for ImmunizationRecommendationInformationSearchReference {
+    type Resource = resources::ImmunizationRecommendation;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ImmunizationRecommendationSearchParameter {
+        ImmunizationRecommendationSearchParameter::Information
+    }
+}
+/// The patient reference search parameter of the ImmunizationRecommendation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationRecommendationPatientSearchReference;
+impl ReferenceSearchParameter for ImmunizationRecommendationPatientSearchReference {
+    type Resource = resources::ImmunizationRecommendation;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ImmunizationRecommendationSearchParameter {
+        ImmunizationRecommendationSearchParameter::Patient
+    }
+}
+/// The support reference search parameter of the ImmunizationRecommendation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationRecommendationSupportSearchReference;
+impl ReferenceSearchParameter for ImmunizationRecommendationSupportSearchReference {
+    type Resource = resources::ImmunizationRecommendation;
+    type Target = ImmunizationRecommendationSupportSearchTarget;
+    fn parameter(&self) -> ImmunizationRecommendationSearchParameter {
+        ImmunizationRecommendationSearchParameter::Support
+    }
+}
+/// Target resources of the support reference search parameter of the ImmunizationRecommendation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImmunizationRecommendationSupportSearchTarget {
+    /// Variant for Immunization target resources
+    Immunization(resources::Immunization),
+    /// Variant for ImmunizationEvaluation target resources
+    ImmunizationEvaluation(resources::ImmunizationEvaluation),
+}
+impl From<resources::Immunization> for ImmunizationRecommendationSupportSearchTarget {
+    fn from(resource: resources::Immunization) -> ImmunizationRecommendationSupportSearchTarget {
+        ImmunizationRecommendationSupportSearchTarget::Immunization(resource)
+    }
+}
+impl From<resources::ImmunizationEvaluation> for ImmunizationRecommendationSupportSearchTarget {
+    fn from(resource: resources::ImmunizationEvaluation) -> ImmunizationRecommendationSupportSearchTarget {
+        ImmunizationRecommendationSupportSearchTarget::ImmunizationEvaluation(resource)
+    }
+}
+/// The depends-on reference search parameter of the ImplementationGuide resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImplementationGuideDependsOnSearchReference;
+impl ReferenceSearchParameter for ImplementationGuideDependsOnSearchReference {
+    type Resource = resources::ImplementationGuide;
+    type Target = resources::ImplementationGuide;
+    fn parameter(&self) -> ImplementationGuideSearchParameter {
+        ImplementationGuideSearchParameter::DependsOn
+    }
+}
+/// The global reference search parameter of the ImplementationGuide resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImplementationGuideGlobalSearchReference;
+impl ReferenceSearchParameter for ImplementationGuideGlobalSearchReference {
+    type Resource = resources::ImplementationGuide;
+    type Target = resources::StructureDefinition;
+    fn parameter(&self) -> ImplementationGuideSearchParameter {
+        ImplementationGuideSearchParameter::Global
+    }
+}
+/// The resource reference search parameter of the ImplementationGuide resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImplementationGuideResourceSearchReference;
+impl ReferenceSearchParameter for ImplementationGuideResourceSearchReference {
+    type Resource = resources::ImplementationGuide;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ImplementationGuideSearchParameter {
+        ImplementationGuideSearchParameter::Resource
+    }
+}
+/// The for reference search parameter of the Ingredient resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IngredientForSearchReference;
+impl ReferenceSearchParameter for IngredientForSearchReference {
+    type Resource = resources::Ingredient;
+    type Target = IngredientForSearchTarget;
+    fn parameter(&self) -> IngredientSearchParameter {
+        IngredientSearchParameter::For
+    }
+}
+/// Target resources of the for reference search parameter of the Ingredient resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum IngredientForSearchTarget {
+    /// Variant for AdministrableProductDefinition target resources
+    AdministrableProductDefinition(resources::AdministrableProductDefinition),
+    /// Variant for ManufacturedItemDefinition target resources
+    ManufacturedItemDefinition(resources::ManufacturedItemDefinition),
+    /// Variant for MedicinalProductDefinition target resources
+    MedicinalProductDefinition(resources::MedicinalProductDefinition),
+}
+impl From<resources::AdministrableProductDefinition> for IngredientForSearchTarget {
+    fn from(resource: resources::AdministrableProductDefinition) -> IngredientForSearchTarget {
+        IngredientForSearchTarget::AdministrableProductDefinition(resource)
+    }
+}
+impl From<resources::ManufacturedItemDefinition> for IngredientForSearchTarget {
+    fn from(resource: resources::ManufacturedItemDefinition) -> IngredientForSearchTarget {
+        IngredientForSearchTarget::ManufacturedItemDefinition(resource)
+    }
+}
+impl From<resources::MedicinalProductDefinition> for IngredientForSearchTarget {
+    fn from(resource: resources::MedicinalProductDefinition) -> IngredientForSearchTarget {
+        IngredientForSearchTarget::MedicinalProductDefinition(resource)
+    }
+}
+/// The manufacturer reference search parameter of the Ingredient resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IngredientManufacturerSearchReference;
+impl ReferenceSearchParameter for IngredientManufacturerSearchReference {
+    type Resource = resources::Ingredient;
+    type Target = resources::Resource;
+    fn parameter(&self) -> IngredientSearchParameter {
+        IngredientSearchParameter::Manufacturer
+    }
+}
+/// The substance reference search parameter of the Ingredient resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IngredientSubstanceSearchReference;
+impl ReferenceSearchParameter for IngredientSubstanceSearchReference {
+    type Resource = resources::Ingredient;
+    type Target = resources::Resource;
+    fn parameter(&self) -> IngredientSearchParameter {
+        IngredientSearchParameter::Substance
+    }
+}
+/// The substance-definition reference search parameter of the Ingredient resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IngredientSubstanceDefinitionSearchReference;
+impl ReferenceSearchParameter for IngredientSubstanceDefinitionSearchReference {
+    type Resource = resources::Ingredient;
+    type Target = resources::Resource;
+    fn parameter(&self) -> IngredientSearchParameter {
+        IngredientSearchParameter::SubstanceDefinition
+    }
+}
+/// The administered-by reference search parameter of the InsurancePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InsurancePlanAdministeredBySearchReference;
+impl ReferenceSearchParameter for InsurancePlanAdministeredBySearchReference {
+    type Resource = resources::InsurancePlan;
+    type Target = resources::Organization;
+    fn parameter(&self) -> InsurancePlanSearchParameter {
+        InsurancePlanSearchParameter::AdministeredBy
+    }
+}
+/// The endpoint reference search parameter of the InsurancePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InsurancePlanEndpointSearchReference;
+impl ReferenceSearchParameter for InsurancePlanEndpointSearchReference {
+    type Resource = resources::InsurancePlan;
+    type Target = resources::Endpoint;
+    fn parameter(&self) -> InsurancePlanSearchParameter {
+        InsurancePlanSearchParameter::Endpoint
+    }
+}
+/// The owned-by reference search parameter of the InsurancePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InsurancePlanOwnedBySearchReference;
+impl ReferenceSearchParameter for InsurancePlanOwnedBySearchReference {
+    type Resource = resources::InsurancePlan;
+    type Target = resources::Organization;
+    fn parameter(&self) -> InsurancePlanSearchParameter {
+        InsurancePlanSearchParameter::OwnedBy
+    }
+}
+/// The account reference search parameter of the Invoice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InvoiceAccountSearchReference;
+impl ReferenceSearchParameter for InvoiceAccountSearchReference {
+    type Resource = resources::Invoice;
+    type Target = resources::Account;
+    fn parameter(&self) -> InvoiceSearchParameter {
+        InvoiceSearchParameter::Account
+    }
+}
+/// The issuer reference search parameter of the Invoice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InvoiceIssuerSearchReference;
+impl ReferenceSearchParameter for InvoiceIssuerSearchReference {
+    type Resource = resources::Invoice;
+    type Target = resources::Organization;
+    fn parameter(&self) -> InvoiceSearchParameter {
+        InvoiceSearchParameter::Issuer
+    }
+}
+/// The participant reference search parameter of the Invoice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InvoiceParticipantSearchReference;
+impl ReferenceSearchParameter for InvoiceParticipantSearchReference {
+    type Resource = resources::Invoice;
+    type Target = InvoiceParticipantSearchTarget;
+    fn parameter(&self) -> InvoiceSearchParameter {
+        InvoiceSearchParameter::Participant
+    }
+}
+/// Target resources of the participant reference search parameter of the Invoice resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InvoiceParticipantSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for InvoiceParticipantSearchTarget {
+    fn from(resource: resources::Practitioner) -> InvoiceParticipantSearchTarget {
+        InvoiceParticipantSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for InvoiceParticipantSearchTarget {
+    fn from(resource: resources::Organization) -> InvoiceParticipantSearchTarget {
+        InvoiceParticipantSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for InvoiceParticipantSearchTarget {
+    fn from(resource: resources::Device) -> InvoiceParticipantSearchTarget {
+        InvoiceParticipantSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for InvoiceParticipantSearchTarget {
+    fn from(resource: resources::Patient) -> InvoiceParticipantSearchTarget {
+        InvoiceParticipantSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for InvoiceParticipantSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> InvoiceParticipantSearchTarget {
+        InvoiceParticipantSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for InvoiceParticipantSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> InvoiceParticipantSearchTarget {
+        InvoiceParticipantSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The patient reference search parameter of the Invoice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InvoicePatientSearchReference;
+impl ReferenceSearchParameter for InvoicePatientSearchReference {
+    type Resource = resources::Invoice;
+    type Target = resources::Patient;
+    fn parameter(&self) -> InvoiceSearchParameter {
+        InvoiceSearchParameter::Patient
+    }
+}
+/// The recipient reference search parameter of the Invoice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InvoiceRecipientSearchReference;
+impl ReferenceSearchParameter for InvoiceRecipientSearchReference {
+    type Resource = resources::Invoice;
+    type Target = InvoiceRecipientSearchTarget;
+    fn parameter(&self) -> InvoiceSearchParameter {
+        InvoiceSearchParameter::Recipient
+    }
+}
+/// Target resources of the recipient reference search parameter of the Invoice resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InvoiceRecipientSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Organization> for InvoiceRecipientSearchTarget {
+    fn from(resource: resources::Organization) -> InvoiceRecipientSearchTarget {
+        InvoiceRecipientSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for InvoiceRecipientSearchTarget {
+    fn from(resource: resources::Patient) -> InvoiceRecipientSearchTarget {
+        InvoiceRecipientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for InvoiceRecipientSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> InvoiceRecipientSearchTarget {
+        InvoiceRecipientSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subject reference search parameter of the Invoice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InvoiceSubjectSearchReference;
+impl ReferenceSearchParameter for InvoiceSubjectSearchReference {
+    type Resource = resources::Invoice;
+    type Target = InvoiceSubjectSearchTarget;
+    fn parameter(&self) -> InvoiceSearchParameter {
+        InvoiceSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Invoice resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InvoiceSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for InvoiceSubjectSearchTarget {
+    fn from(resource: resources::Group) -> InvoiceSubjectSearchTarget {
+        InvoiceSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for InvoiceSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> InvoiceSubjectSearchTarget {
+        InvoiceSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The composed-of reference search parameter of the Library resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LibraryComposedOfSearchReference;
+impl ReferenceSearchParameter for LibraryComposedOfSearchReference {
+    type Resource = resources::Library;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LibrarySearchParameter {
+        LibrarySearchParameter::ComposedOf
+    }
+}
+/// The depends-on reference search parameter of the Library resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LibraryDependsOnSearchReference;
+impl ReferenceSearchParameter for LibraryDependsOnSearchReference {
+    type Resource = resources::Library;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LibrarySearchParameter {
+        LibrarySearchParameter::DependsOn
+    }
+}
+/// The derived-from reference search parameter of the Library resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LibraryDerivedFromSearchReference;
+impl ReferenceSearchParameter for LibraryDerivedFromSearchReference {
+    type Resource = resources::Library;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LibrarySearchParameter {
+        LibrarySearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the Library resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LibraryPredecessorSearchReference;
+impl ReferenceSearchParameter for LibraryPredecessorSearchReference {
+    type Resource = resources::Library;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LibrarySearchParameter {
+        LibrarySearchParameter::Predecessor
+    }
+}
+/// The successor reference search parameter of the Library resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LibrarySuccessorSearchReference;
+impl ReferenceSearchParameter for LibrarySuccessorSearchReference {
+    type Resource = resources::Library;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LibrarySearchParameter {
+        LibrarySearchParameter::Successor
+    }
+}
+/// The author reference search parameter of the Linkage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LinkageAuthorSearchReference;
+impl ReferenceSearchParameter for LinkageAuthorSearchReference {
+    type Resource = resources::Linkage;
+    type Target = LinkageAuthorSearchTarget;
+    fn parameter(&self) -> LinkageSearchParameter {
+        LinkageSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the Linkage resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LinkageAuthorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for LinkageAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> LinkageAuthorSearchTarget {
+        LinkageAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for LinkageAuthorSearchTarget {
+    fn from(resource: resources::Organization) -> LinkageAuthorSearchTarget {
+        LinkageAuthorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for LinkageAuthorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> LinkageAuthorSearchTarget {
+        LinkageAuthorSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The item reference search parameter of the Linkage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LinkageItemSearchReference;
+impl ReferenceSearchParameter for LinkageItemSearchReference {
+    type Resource = resources::Linkage;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LinkageSearchParameter {
+        LinkageSearchParameter::Item
+    }
+}
+/// The source reference search parameter of the Linkage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LinkageSourceSearchReference;
+impl ReferenceSearchParameter for LinkageSourceSearchReference {
+    type Resource = resources::Linkage;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LinkageSearchParameter {
+        LinkageSearchParameter::Source
+    }
+}
+/// The patient reference search parameter of the List resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListPatientSearchReference;
+impl ReferenceSearchParameter for ListPatientSearchReference {
+    type Resource = resources::List;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ListSearchParameter {
+        ListSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the List resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListEncounterSearchReference;
+impl ReferenceSearchParameter for ListEncounterSearchReference {
+    type Resource = resources::List;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ListSearchParameter {
+        ListSearchParameter::Encounter
+    }
+}
+/// The item reference search parameter of the List resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListItemSearchReference;
+impl ReferenceSearchParameter for ListItemSearchReference {
+    type Resource = resources::List;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ListSearchParameter {
+        ListSearchParameter::Item
+    }
+}
+/// The source reference search parameter of the List resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListSourceSearchReference;
+impl ReferenceSearchParameter for ListSourceSearchReference {
+    type Resource = resources::List;
+    type Target = ListSourceSearchTarget;
+    fn parameter(&self) -> ListSearchParameter {
+        ListSearchParameter::Source
+    }
+}
+/// Target resources of the source reference search parameter of the List resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ListSourceSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for ListSourceSearchTarget {
+    fn from(resource: resources::Practitioner) -> ListSourceSearchTarget {
+        ListSourceSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Device> for ListSourceSearchTarget {
+    fn from(resource: resources::Device) -> ListSourceSearchTarget {
+        ListSourceSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ListSourceSearchTarget {
+    fn from(resource: resources::Patient) -> ListSourceSearchTarget {
+        ListSourceSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ListSourceSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ListSourceSearchTarget {
+        ListSourceSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The subject reference search parameter of the List resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListSubjectSearchReference;
+impl ReferenceSearchParameter for ListSubjectSearchReference {
+    type Resource = resources::List;
+    type Target = ListSubjectSearchTarget;
+    fn parameter(&self) -> ListSearchParameter {
+        ListSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the List resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ListSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Group> for ListSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ListSubjectSearchTarget {
+        ListSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Device> for ListSubjectSearchTarget {
+    fn from(resource: resources::Device) -> ListSubjectSearchTarget {
+        ListSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ListSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ListSubjectSearchTarget {
+        ListSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Location> for ListSubjectSearchTarget {
+    fn from(resource: resources::Location) -> ListSubjectSearchTarget {
+        ListSubjectSearchTarget::Location(resource)
+    }
+}
+/// The endpoint reference search parameter of the Location resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LocationEndpointSearchReference;
+impl ReferenceSearchParameter for LocationEndpointSearchReference {
+    type Resource = resources::Location;
+    type Target = resources::Endpoint;
+    fn parameter(&self) -> LocationSearchParameter {
+        LocationSearchParameter::Endpoint
+    }
+}
+/// The organization reference search parameter of the Location resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LocationOrganizationSearchReference;
+impl ReferenceSearchParameter for LocationOrganizationSearchReference {
+    type Resource = resources::Location;
+    type Target = resources::Organization;
+    fn parameter(&self) -> LocationSearchParameter {
+        LocationSearchParameter::Organization
+    }
+}
+/// The partof reference search parameter of the Location resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LocationPartofSearchReference;
+impl ReferenceSearchParameter for LocationPartofSearchReference {
+    type Resource = resources::Location;
+    type Target = resources::Location;
+    fn parameter(&self) -> LocationSearchParameter {
+        LocationSearchParameter::Partof
+    }
+}
+/// The composed-of reference search parameter of the Measure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureComposedOfSearchReference;
+impl ReferenceSearchParameter for MeasureComposedOfSearchReference {
+    type Resource = resources::Measure;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MeasureSearchParameter {
+        MeasureSearchParameter::ComposedOf
+    }
+}
+/// The depends-on reference search parameter of the Measure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureDependsOnSearchReference;
+impl ReferenceSearchParameter for MeasureDependsOnSearchReference {
+    type Resource = resources::Measure;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MeasureSearchParameter {
+        MeasureSearchParameter::DependsOn
+    }
+}
+/// The derived-from reference search parameter of the Measure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureDerivedFromSearchReference;
+impl ReferenceSearchParameter for MeasureDerivedFromSearchReference {
+    type Resource = resources::Measure;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MeasureSearchParameter {
+        MeasureSearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the Measure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasurePredecessorSearchReference;
+impl ReferenceSearchParameter for MeasurePredecessorSearchReference {
+    type Resource = resources::Measure;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MeasureSearchParameter {
+        MeasureSearchParameter::Predecessor
+    }
+}
+/// The successor reference search parameter of the Measure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureSuccessorSearchReference;
+impl ReferenceSearchParameter for MeasureSuccessorSearchReference {
+    type Resource = resources::Measure;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MeasureSearchParameter {
+        MeasureSearchParameter::Successor
+    }
+}
+/// The evaluated-resource reference search parameter of the MeasureReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureReportEvaluatedResourceSearchReference;
+impl ReferenceSearchParameter for MeasureReportEvaluatedResourceSearchReference {
+    type Resource = resources::MeasureReport;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MeasureReportSearchParameter {
+        MeasureReportSearchParameter::EvaluatedResource
+    }
+}
+/// The measure reference search parameter of the MeasureReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureReportMeasureSearchReference;
+impl ReferenceSearchParameter for MeasureReportMeasureSearchReference {
+    type Resource = resources::MeasureReport;
+    type Target = resources::Measure;
+    fn parameter(&self) -> MeasureReportSearchParameter {
+        MeasureReportSearchParameter::Measure
+    }
+}
+/// The patient reference search parameter of the MeasureReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureReportPatientSearchReference;
+impl ReferenceSearchParameter for MeasureReportPatientSearchReference {
+    type Resource = resources::MeasureReport;
+    type Target = resources::Patient;
+    fn parameter(&self) -> MeasureReportSearchParameter {
+        MeasureReportSearchParameter::Patient
+    }
+}
+/// The reporter reference search parameter of the MeasureReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureReportReporterSearchReference;
+impl ReferenceSearchParameter for MeasureReportReporterSearchReference {
+    type Resource = resources::MeasureReport;
+    type Target = MeasureReportReporterSearchTarget;
+    fn parameter(&self) -> MeasureReportSearchParameter {
+        MeasureReportSearchParameter::Reporter
+    }
+}
+/// Target resources of the reporter reference search parameter of the MeasureReport resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MeasureReportReporterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Practitioner> for MeasureReportReporterSearchTarget {
+    fn from(resource: resources::Practitioner) -> MeasureReportReporterSearchTarget {
+        MeasureReportReporterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for MeasureReportReporterSearchTarget {
+    fn from(resource: resources::Organization) -> MeasureReportReporterSearchTarget {
+        MeasureReportReporterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MeasureReportReporterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MeasureReportReporterSearchTarget {
+        MeasureReportReporterSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Location> for MeasureReportReporterSearchTarget {
+    fn from(resource: resources::Location) -> MeasureReportReporterSearchTarget {
+        MeasureReportReporterSearchTarget::Location(resource)
+    }
+}
+/// The subject reference search parameter of the MeasureReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureReportSubjectSearchReference;
+impl ReferenceSearchParameter for MeasureReportSubjectSearchReference {
+    type Resource = resources::MeasureReport;
+    type Target = MeasureReportSubjectSearchTarget;
+    fn parameter(&self) -> MeasureReportSearchParameter {
+        MeasureReportSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the MeasureReport resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MeasureReportSubjectSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Practitioner> for MeasureReportSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> MeasureReportSubjectSearchTarget {
+        MeasureReportSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for MeasureReportSubjectSearchTarget {
+    fn from(resource: resources::Group) -> MeasureReportSubjectSearchTarget {
+        MeasureReportSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Device> for MeasureReportSubjectSearchTarget {
+    fn from(resource: resources::Device) -> MeasureReportSubjectSearchTarget {
+        MeasureReportSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for MeasureReportSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> MeasureReportSubjectSearchTarget {
+        MeasureReportSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MeasureReportSubjectSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MeasureReportSubjectSearchTarget {
+        MeasureReportSubjectSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for MeasureReportSubjectSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> MeasureReportSubjectSearchTarget {
+        MeasureReportSubjectSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::Location> for MeasureReportSubjectSearchTarget {
+    fn from(resource: resources::Location) -> MeasureReportSubjectSearchTarget {
+        MeasureReportSubjectSearchTarget::Location(resource)
+    }
+}
+/// The based-on reference search parameter of the Media resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MediaBasedOnSearchReference;
+impl ReferenceSearchParameter for MediaBasedOnSearchReference {
+    type Resource = resources::Media;
+    type Target = MediaBasedOnSearchTarget;
+    fn parameter(&self) -> MediaSearchParameter {
+        MediaSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the Media resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MediaBasedOnSearchTarget {
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+}
+impl From<resources::CarePlan> for MediaBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> MediaBasedOnSearchTarget {
+        MediaBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::ServiceRequest> for MediaBasedOnSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> MediaBasedOnSearchTarget {
+        MediaBasedOnSearchTarget::ServiceRequest(resource)
+    }
+}
+/// The device reference search parameter of the Media resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MediaDeviceSearchReference;
+impl ReferenceSearchParameter for MediaDeviceSearchReference {
+    type Resource = resources::Media;
+    type Target = MediaDeviceSearchTarget;
+    fn parameter(&self) -> MediaSearchParameter {
+        MediaSearchParameter::Device
+    }
+}
+/// Target resources of the device reference search parameter of the Media resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MediaDeviceSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for DeviceMetric target resources
+    DeviceMetric(resources::DeviceMetric),
+}
+impl From<resources::Device> for MediaDeviceSearchTarget {
+    fn from(resource: resources::Device) -> MediaDeviceSearchTarget {
+        MediaDeviceSearchTarget::Device(resource)
+    }
+}
+impl From<resources::DeviceMetric> for MediaDeviceSearchTarget {
+    fn from(resource: resources::DeviceMetric) -> MediaDeviceSearchTarget {
+        MediaDeviceSearchTarget::DeviceMetric(resource)
+    }
+}
+/// The encounter reference search parameter of the Media resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MediaEncounterSearchReference;
+impl ReferenceSearchParameter for MediaEncounterSearchReference {
+    type Resource = resources::Media;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> MediaSearchParameter {
+        MediaSearchParameter::Encounter
+    }
+}
+/// The operator reference search parameter of the Media resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MediaOperatorSearchReference;
+impl ReferenceSearchParameter for MediaOperatorSearchReference {
+    type Resource = resources::Media;
+    type Target = MediaOperatorSearchTarget;
+    fn parameter(&self) -> MediaSearchParameter {
+        MediaSearchParameter::Operator
+    }
+}
+/// Target resources of the operator reference search parameter of the Media resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MediaOperatorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for MediaOperatorSearchTarget {
+    fn from(resource: resources::Practitioner) -> MediaOperatorSearchTarget {
+        MediaOperatorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for MediaOperatorSearchTarget {
+    fn from(resource: resources::Organization) -> MediaOperatorSearchTarget {
+        MediaOperatorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for MediaOperatorSearchTarget {
+    fn from(resource: resources::CareTeam) -> MediaOperatorSearchTarget {
+        MediaOperatorSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for MediaOperatorSearchTarget {
+    fn from(resource: resources::Device) -> MediaOperatorSearchTarget {
+        MediaOperatorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for MediaOperatorSearchTarget {
+    fn from(resource: resources::Patient) -> MediaOperatorSearchTarget {
+        MediaOperatorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MediaOperatorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MediaOperatorSearchTarget {
+        MediaOperatorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for MediaOperatorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> MediaOperatorSearchTarget {
+        MediaOperatorSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The patient reference search parameter of the Media resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MediaPatientSearchReference;
+impl ReferenceSearchParameter for MediaPatientSearchReference {
+    type Resource = resources::Media;
+    type Target = resources::Patient;
+    fn parameter(&self) -> MediaSearchParameter {
+        MediaSearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the Media resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MediaSubjectSearchReference;
+impl ReferenceSearchParameter for MediaSubjectSearchReference {
+    type Resource = resources::Media;
+    type Target = MediaSubjectSearchTarget;
+    fn parameter(&self) -> MediaSearchParameter {
+        MediaSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Media resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MediaSubjectSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Specimen target resources
+    Specimen(resources::Specimen),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Practitioner> for MediaSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> MediaSubjectSearchTarget {
+        MediaSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for MediaSubjectSearchTarget {
+    fn from(resource: resources::Group) -> MediaSubjectSearchTarget {
+        MediaSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Specimen> for MediaSubjectSearchTarget {
+    fn from(resource: resources::Specimen) -> MediaSubjectSearchTarget {
+        MediaSubjectSearchTarget::Specimen(resource)
+    }
+}
+impl From<resources::Device> for MediaSubjectSearchTarget {
+    fn from(resource: resources::Device) -> MediaSubjectSearchTarget {
+        MediaSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for MediaSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> MediaSubjectSearchTarget {
+        MediaSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MediaSubjectSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MediaSubjectSearchTarget {
+        MediaSubjectSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Location> for MediaSubjectSearchTarget {
+    fn from(resource: resources::Location) -> MediaSubjectSearchTarget {
+        MediaSubjectSearchTarget::Location(resource)
+    }
+}
+/// The ingredient reference search parameter of the Medication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationIngredientSearchReference;
+impl ReferenceSearchParameter for MedicationIngredientSearchReference {
+    type Resource = resources::Medication;
+    type Target = MedicationIngredientSearchTarget;
+    fn parameter(&self) -> MedicationSearchParameter {
+        MedicationSearchParameter::Ingredient
+    }
+}
+/// Target resources of the ingredient reference search parameter of the Medication resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationIngredientSearchTarget {
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+}
+impl From<resources::Medication> for MedicationIngredientSearchTarget {
+    fn from(resource: resources::Medication) -> MedicationIngredientSearchTarget {
+        MedicationIngredientSearchTarget::Medication(resource)
+    }
+}
+impl From<resources::Substance> for MedicationIngredientSearchTarget {
+    fn from(resource: resources::Substance) -> MedicationIngredientSearchTarget {
+        MedicationIngredientSearchTarget::Substance(resource)
+    }
+}
+/// The manufacturer reference search parameter of the Medication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationManufacturerSearchReference;
+impl ReferenceSearchParameter for MedicationManufacturerSearchReference {
+    type Resource = resources::Medication;
+    type Target = resources::Organization;
+    fn parameter(&self) -> MedicationSearchParameter {
+        MedicationSearchParameter::Manufacturer
+    }
+}
+/// The patient reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationPatientSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationPatientSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = resources::Patient;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Patient
+    }
+}
+/// The context reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationContextSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationContextSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = MedicationAdministrationContextSearchTarget;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the MedicationAdministration resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationAdministrationContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for MedicationAdministrationContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> MedicationAdministrationContextSearchTarget {
+        MedicationAdministrationContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for MedicationAdministrationContextSearchTarget {
+    fn from(resource: resources::Encounter) -> MedicationAdministrationContextSearchTarget {
+        MedicationAdministrationContextSearchTarget::Encounter(resource)
+    }
+}
+/// The device reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationDeviceSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationDeviceSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = resources::Device;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Device
+    }
+}
+/// The medication reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationMedicationSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationMedicationSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = resources::Medication;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Medication
+    }
+}
+/// The performer reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationPerformerSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationPerformerSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = MedicationAdministrationPerformerSearchTarget;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the MedicationAdministration resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationAdministrationPerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for MedicationAdministrationPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> MedicationAdministrationPerformerSearchTarget {
+        MedicationAdministrationPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Device> for MedicationAdministrationPerformerSearchTarget {
+    fn from(resource: resources::Device) -> MedicationAdministrationPerformerSearchTarget {
+        MedicationAdministrationPerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for MedicationAdministrationPerformerSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationAdministrationPerformerSearchTarget {
+        MedicationAdministrationPerformerSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MedicationAdministrationPerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MedicationAdministrationPerformerSearchTarget {
+        MedicationAdministrationPerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for MedicationAdministrationPerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> MedicationAdministrationPerformerSearchTarget {
+        MedicationAdministrationPerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The request reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationRequestSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationRequestSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = resources::MedicationRequest;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Request
+    }
+}
+/// The subject reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationSubjectSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationSubjectSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = MedicationAdministrationSubjectSearchTarget;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the MedicationAdministration resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationAdministrationSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for MedicationAdministrationSubjectSearchTarget {
+    fn from(resource: resources::Group) -> MedicationAdministrationSubjectSearchTarget {
+        MedicationAdministrationSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for MedicationAdministrationSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationAdministrationSubjectSearchTarget {
+        MedicationAdministrationSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispensePatientSearchReference;
+impl ReferenceSearchParameter for MedicationDispensePatientSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = resources::Patient;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Patient
+    }
+}
+/// The medication reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispenseMedicationSearchReference;
+impl ReferenceSearchParameter for MedicationDispenseMedicationSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = resources::Medication;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Medication
+    }
+}
+/// The context reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispenseContextSearchReference;
+impl ReferenceSearchParameter for MedicationDispenseContextSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = MedicationDispenseContextSearchTarget;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the MedicationDispense resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationDispenseContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for MedicationDispenseContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> MedicationDispenseContextSearchTarget {
+        MedicationDispenseContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for MedicationDispenseContextSearchTarget {
+    fn from(resource: resources::Encounter) -> MedicationDispenseContextSearchTarget {
+        MedicationDispenseContextSearchTarget::Encounter(resource)
+    }
+}
+/// The destination reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispenseDestinationSearchReference;
+impl ReferenceSearchParameter for MedicationDispenseDestinationSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = resources::Location;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Destination
+    }
+}
+/// The performer reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispensePerformerSearchReference;
+impl ReferenceSearchParameter for MedicationDispensePerformerSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = MedicationDispensePerformerSearchTarget;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the MedicationDispense resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationDispensePerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for MedicationDispensePerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> MedicationDispensePerformerSearchTarget {
+        MedicationDispensePerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for MedicationDispensePerformerSearchTarget {
+    fn from(resource: resources::Organization) -> MedicationDispensePerformerSearchTarget {
+        MedicationDispensePerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for MedicationDispensePerformerSearchTarget {
+    fn from(resource: resources::Device) -> MedicationDispensePerformerSearchTarget {
+        MedicationDispensePerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for MedicationDispensePerformerSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationDispensePerformerSearchTarget {
+        MedicationDispensePerformerSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MedicationDispensePerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MedicationDispensePerformerSearchTarget {
+        MedicationDispensePerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for MedicationDispensePerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> MedicationDispensePerformerSearchTarget {
+        MedicationDispensePerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The prescription reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispensePrescriptionSearchReference;
+impl ReferenceSearchParameter for MedicationDispensePrescriptionSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = resources::MedicationRequest;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Prescription
+    }
+}
+/// The receiver reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispenseReceiverSearchReference;
+impl ReferenceSearchParameter for MedicationDispenseReceiverSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = MedicationDispenseReceiverSearchTarget;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Receiver
+    }
+}
+/// Target resources of the receiver reference search parameter of the MedicationDispense resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationDispenseReceiverSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Practitioner> for MedicationDispenseReceiverSearchTarget {
+    fn from(resource: resources::Practitioner) -> MedicationDispenseReceiverSearchTarget {
+        MedicationDispenseReceiverSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for MedicationDispenseReceiverSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationDispenseReceiverSearchTarget {
+        MedicationDispenseReceiverSearchTarget::Patient(resource)
+    }
+}
+/// The responsibleparty reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispenseResponsiblepartySearchReference;
+impl ReferenceSearchParameter for MedicationDispenseResponsiblepartySearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = MedicationDispenseResponsiblepartySearchTarget;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Responsibleparty
+    }
+}
+/// Target resources of the responsibleparty reference search parameter of the MedicationDispense resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationDispenseResponsiblepartySearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for MedicationDispenseResponsiblepartySearchTarget {
+    fn from(resource: resources::Practitioner) -> MedicationDispenseResponsiblepartySearchTarget {
+        MedicationDispenseResponsiblepartySearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MedicationDispenseResponsiblepartySearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MedicationDispenseResponsiblepartySearchTarget {
+        MedicationDispenseResponsiblepartySearchTarget::PractitionerRole(resource)
+    }
+}
+/// The subject reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispenseSubjectSearchReference;
+impl ReferenceSearchParameter for MedicationDispenseSubjectSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = MedicationDispenseSubjectSearchTarget;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the MedicationDispense resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationDispenseSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for MedicationDispenseSubjectSearchTarget {
+    fn from(resource: resources::Group) -> MedicationDispenseSubjectSearchTarget {
+        MedicationDispenseSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for MedicationDispenseSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationDispenseSubjectSearchTarget {
+        MedicationDispenseSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The ingredient reference search parameter of the MedicationKnowledge resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationKnowledgeIngredientSearchReference;
+impl ReferenceSearchParameter for MedicationKnowledgeIngredientSearchReference {
+    type Resource = resources::MedicationKnowledge;
+    type Target = resources::Substance;
+    fn parameter(&self) -> MedicationKnowledgeSearchParameter {
+        MedicationKnowledgeSearchParameter::Ingredient
+    }
+}
+/// The manufacturer reference search parameter of the MedicationKnowledge resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationKnowledgeManufacturerSearchReference;
+impl ReferenceSearchParameter for MedicationKnowledgeManufacturerSearchReference {
+    type Resource = resources::MedicationKnowledge;
+    type Target = resources::Organization;
+    fn parameter(&self) -> MedicationKnowledgeSearchParameter {
+        MedicationKnowledgeSearchParameter::Manufacturer
+    }
+}
+/// The monograph reference search parameter of the MedicationKnowledge resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationKnowledgeMonographSearchReference;
+impl ReferenceSearchParameter for MedicationKnowledgeMonographSearchReference {
+    type Resource = resources::MedicationKnowledge;
+    type Target = MedicationKnowledgeMonographSearchTarget;
+    fn parameter(&self) -> MedicationKnowledgeSearchParameter {
+        MedicationKnowledgeSearchParameter::Monograph
+    }
+}
+/// Target resources of the monograph reference search parameter of the MedicationKnowledge resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationKnowledgeMonographSearchTarget {
+    /// Variant for Media target resources
+    Media(resources::Media),
+    /// Variant for DocumentReference target resources
+    DocumentReference(resources::DocumentReference),
+}
+impl From<resources::Media> for MedicationKnowledgeMonographSearchTarget {
+    fn from(resource: resources::Media) -> MedicationKnowledgeMonographSearchTarget {
+        MedicationKnowledgeMonographSearchTarget::Media(resource)
+    }
+}
+impl From<resources::DocumentReference> for MedicationKnowledgeMonographSearchTarget {
+    fn from(resource: resources::DocumentReference) -> MedicationKnowledgeMonographSearchTarget {
+        MedicationKnowledgeMonographSearchTarget::DocumentReference(resource)
+    }
+}
+/// The patient reference search parameter of the MedicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationRequestPatientSearchReference;
+impl ReferenceSearchParameter for MedicationRequestPatientSearchReference {
+    type Resource = resources::MedicationRequest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> MedicationRequestSearchParameter {
+        MedicationRequestSearchParameter::Patient
+    }
+}
+/// The medication reference search parameter of the MedicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationRequestMedicationSearchReference;
+impl ReferenceSearchParameter for MedicationRequestMedicationSearchReference {
+    type Resource = resources::MedicationRequest;
+    type Target = resources::Medication;
+    fn parameter(&self) -> MedicationRequestSearchParameter {
+        MedicationRequestSearchParameter::Medication
+    }
+}
+/// The encounter reference search parameter of the MedicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationRequestEncounterSearchReference;
+impl ReferenceSearchParameter for MedicationRequestEncounterSearchReference {
+    type Resource = resources::MedicationRequest;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> MedicationRequestSearchParameter {
+        MedicationRequestSearchParameter::Encounter
+    }
+}
+/// The intended-dispenser reference search parameter of the MedicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationRequestIntendedDispenserSearchReference;
+impl ReferenceSearchParameter for MedicationRequestIntendedDispenserSearchReference {
+    type Resource = resources::MedicationRequest;
+    type Target = resources::Organization;
+    fn parameter(&self) -> MedicationRequestSearchParameter {
+        MedicationRequestSearchParameter::IntendedDispenser
+    }
+}
+/// The intended-performer reference search parameter of the MedicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationRequestIntendedPerformerSearchReference;
+impl ReferenceSearchParameter for MedicationRequestIntendedPerformerSearchReference {
+    type Resource = resources::MedicationRequest;
+    type Target = MedicationRequestIntendedPerformerSearchTarget;
+    fn parameter(&self) -> MedicationRequestSearchParameter {
+        MedicationRequestSearchParameter::IntendedPerformer
+    }
+}
+/// Target resources of the intended-performer reference search parameter of the MedicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationRequestIntendedPerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for MedicationRequestIntendedPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> MedicationRequestIntendedPerformerSearchTarget {
+        MedicationRequestIntendedPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for MedicationRequestIntendedPerformerSearchTarget {
+    fn from(resource: resources::Organization) -> MedicationRequestIntendedPerformerSearchTarget {
+        MedicationRequestIntendedPerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for MedicationRequestIntendedPerformerSearchTarget {
+    fn from(resource: resources::CareTeam) -> MedicationRequestIntendedPerformerSearchTarget {
+        MedicationRequestIntendedPerformerSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for MedicationRequestIntendedPerformerSearchTarget {
+    fn from(resource: resources::Device) -> MedicationRequestIntendedPerformerSearchTarget {
+        MedicationRequestIntendedPerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for MedicationRequestIntendedPerformerSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationRequestIntendedPerformerSearchTarget {
+        MedicationRequestIntendedPerformerSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MedicationRequestIntendedPerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MedicationRequestIntendedPerformerSearchTarget {
+        MedicationRequestIntendedPerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for MedicationRequestIntendedPerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> MedicationRequestIntendedPerformerSearchTarget {
+        MedicationRequestIntendedPerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The requester reference search parameter of the MedicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationRequestRequesterSearchReference;
+impl ReferenceSearchParameter for MedicationRequestRequesterSearchReference {
+    type Resource = resources::MedicationRequest;
+    type Target = MedicationRequestRequesterSearchTarget;
+    fn parameter(&self) -> MedicationRequestSearchParameter {
+        MedicationRequestSearchParameter::Requester
+    }
+}
+/// Target resources of the requester reference search parameter of the MedicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationRequestRequesterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for MedicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> MedicationRequestRequesterSearchTarget {
+        MedicationRequestRequesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for MedicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Organization) -> MedicationRequestRequesterSearchTarget {
+        MedicationRequestRequesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for MedicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Device) -> MedicationRequestRequesterSearchTarget {
+        MedicationRequestRequesterSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for MedicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationRequestRequesterSearchTarget {
+        MedicationRequestRequesterSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MedicationRequestRequesterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MedicationRequestRequesterSearchTarget {
+        MedicationRequestRequesterSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for MedicationRequestRequesterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> MedicationRequestRequesterSearchTarget {
+        MedicationRequestRequesterSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subject reference search parameter of the MedicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationRequestSubjectSearchReference;
+impl ReferenceSearchParameter for MedicationRequestSubjectSearchReference {
+    type Resource = resources::MedicationRequest;
+    type Target = MedicationRequestSubjectSearchTarget;
+    fn parameter(&self) -> MedicationRequestSearchParameter {
+        MedicationRequestSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the MedicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationRequestSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for MedicationRequestSubjectSearchTarget {
+    fn from(resource: resources::Group) -> MedicationRequestSubjectSearchTarget {
+        MedicationRequestSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for MedicationRequestSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationRequestSubjectSearchTarget {
+        MedicationRequestSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the MedicationStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationStatementPatientSearchReference;
+impl ReferenceSearchParameter for MedicationStatementPatientSearchReference {
+    type Resource = resources::MedicationStatement;
+    type Target = resources::Patient;
+    fn parameter(&self) -> MedicationStatementSearchParameter {
+        MedicationStatementSearchParameter::Patient
+    }
+}
+/// The medication reference search parameter of the MedicationStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationStatementMedicationSearchReference;
+impl ReferenceSearchParameter for MedicationStatementMedicationSearchReference {
+    type Resource = resources::MedicationStatement;
+    type Target = resources::Medication;
+    fn parameter(&self) -> MedicationStatementSearchParameter {
+        MedicationStatementSearchParameter::Medication
+    }
+}
+/// The context reference search parameter of the MedicationStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationStatementContextSearchReference;
+impl ReferenceSearchParameter for MedicationStatementContextSearchReference {
+    type Resource = resources::MedicationStatement;
+    type Target = MedicationStatementContextSearchTarget;
+    fn parameter(&self) -> MedicationStatementSearchParameter {
+        MedicationStatementSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the MedicationStatement resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationStatementContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for MedicationStatementContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> MedicationStatementContextSearchTarget {
+        MedicationStatementContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for MedicationStatementContextSearchTarget {
+    fn from(resource: resources::Encounter) -> MedicationStatementContextSearchTarget {
+        MedicationStatementContextSearchTarget::Encounter(resource)
+    }
+}
+/// The part-of reference search parameter of the MedicationStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationStatementPartOfSearchReference;
+impl ReferenceSearchParameter for MedicationStatementPartOfSearchReference {
+    type Resource = resources::MedicationStatement;
+    type Target = MedicationStatementPartOfSearchTarget;
+    fn parameter(&self) -> MedicationStatementSearchParameter {
+        MedicationStatementSearchParameter::PartOf
+    }
+}
+/// Target resources of the part-of reference search parameter of the MedicationStatement resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationStatementPartOfSearchTarget {
+    /// Variant for MedicationDispense target resources
+    MedicationDispense(resources::MedicationDispense),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for MedicationAdministration target resources
+    MedicationAdministration(resources::MedicationAdministration),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for MedicationStatement target resources
+    MedicationStatement(resources::MedicationStatement),
+}
+impl From<resources::MedicationDispense> for MedicationStatementPartOfSearchTarget {
+    fn from(resource: resources::MedicationDispense) -> MedicationStatementPartOfSearchTarget {
+        MedicationStatementPartOfSearchTarget::MedicationDispense(resource)
+    }
+}
+impl From<resources::Observation> for MedicationStatementPartOfSearchTarget {
+    fn from(resource: resources::Observation) -> MedicationStatementPartOfSearchTarget {
+        MedicationStatementPartOfSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::MedicationAdministration> for MedicationStatementPartOfSearchTarget {
+    fn from(resource: resources::MedicationAdministration) -> MedicationStatementPartOfSearchTarget {
+        MedicationStatementPartOfSearchTarget::MedicationAdministration(resource)
+    }
+}
+impl From<resources::Procedure> for MedicationStatementPartOfSearchTarget {
+    fn from(resource: resources::Procedure) -> MedicationStatementPartOfSearchTarget {
+        MedicationStatementPartOfSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::MedicationStatement> for MedicationStatementPartOfSearchTarget {
+    fn from(resource: resources::MedicationStatement) -> MedicationStatementPartOfSearchTarget {
+        MedicationStatementPartOfSearchTarget::MedicationStatement(resource)
+    }
+}
+/// The source reference search parameter of the MedicationStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationStatementSourceSearchReference;
+impl ReferenceSearchParameter for MedicationStatementSourceSearchReference {
+    type Resource = resources::MedicationStatement;
+    type Target = MedicationStatementSourceSearchTarget;
+    fn parameter(&self) -> MedicationStatementSearchParameter {
+        MedicationStatementSearchParameter::Source
+    }
+}
+/// Target resources of the source reference search parameter of the MedicationStatement resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationStatementSourceSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for MedicationStatementSourceSearchTarget {
+    fn from(resource: resources::Practitioner) -> MedicationStatementSourceSearchTarget {
+        MedicationStatementSourceSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for MedicationStatementSourceSearchTarget {
+    fn from(resource: resources::Organization) -> MedicationStatementSourceSearchTarget {
+        MedicationStatementSourceSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for MedicationStatementSourceSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationStatementSourceSearchTarget {
+        MedicationStatementSourceSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MedicationStatementSourceSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MedicationStatementSourceSearchTarget {
+        MedicationStatementSourceSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for MedicationStatementSourceSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> MedicationStatementSourceSearchTarget {
+        MedicationStatementSourceSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subject reference search parameter of the MedicationStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationStatementSubjectSearchReference;
+impl ReferenceSearchParameter for MedicationStatementSubjectSearchReference {
+    type Resource = resources::MedicationStatement;
+    type Target = MedicationStatementSubjectSearchTarget;
+    fn parameter(&self) -> MedicationStatementSearchParameter {
+        MedicationStatementSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the MedicationStatement resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationStatementSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for MedicationStatementSubjectSearchTarget {
+    fn from(resource: resources::Group) -> MedicationStatementSubjectSearchTarget {
+        MedicationStatementSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for MedicationStatementSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationStatementSubjectSearchTarget {
+        MedicationStatementSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The contact reference search parameter of the MedicinalProductDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicinalProductDefinitionContactSearchReference;
+impl ReferenceSearchParameter for MedicinalProductDefinitionContactSearchReference {
+    type Resource = resources::MedicinalProductDefinition;
+    type Target = MedicinalProductDefinitionContactSearchTarget;
+    fn parameter(&self) -> MedicinalProductDefinitionSearchParameter {
+        MedicinalProductDefinitionSearchParameter::Contact
+    }
+}
+/// Target resources of the contact reference search parameter of the MedicinalProductDefinition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicinalProductDefinitionContactSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Organization> for MedicinalProductDefinitionContactSearchTarget {
+    fn from(resource: resources::Organization) -> MedicinalProductDefinitionContactSearchTarget {
+        MedicinalProductDefinitionContactSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MedicinalProductDefinitionContactSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MedicinalProductDefinitionContactSearchTarget {
+        MedicinalProductDefinitionContactSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The master-file reference search parameter of the MedicinalProductDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicinalProductDefinitionMasterFileSearchReference;
+impl ReferenceSearchParameter for MedicinalProductDefinitionMasterFileSearchReference {
+    type Resource = resources::MedicinalProductDefinition;
+    type Target = resources::DocumentReference;
+    fn parameter(&self) -> MedicinalProductDefinitionSearchParameter {
+        MedicinalProductDefinitionSearchParameter::MasterFile
+    }
+}
+/// The parent reference search parameter of the MessageDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageDefinitionParentSearchReference;
+impl ReferenceSearchParameter for MessageDefinitionParentSearchReference {
+    type Resource = resources::MessageDefinition;
+    type Target = MessageDefinitionParentSearchTarget;
+    fn parameter(&self) -> MessageDefinitionSearchParameter {
+        MessageDefinitionSearchParameter::Parent
+    }
+}
+/// Target resources of the parent reference search parameter of the MessageDefinition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MessageDefinitionParentSearchTarget {
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+}
+impl From<resources::PlanDefinition> for MessageDefinitionParentSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> MessageDefinitionParentSearchTarget {
+        MessageDefinitionParentSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for MessageDefinitionParentSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> MessageDefinitionParentSearchTarget {
+        MessageDefinitionParentSearchTarget::ActivityDefinition(resource)
+    }
+}
+/// The author reference search parameter of the MessageHeader resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageHeaderAuthorSearchReference;
+impl ReferenceSearchParameter for MessageHeaderAuthorSearchReference {
+    type Resource = resources::MessageHeader;
+    type Target = MessageHeaderAuthorSearchTarget;
+    fn parameter(&self) -> MessageHeaderSearchParameter {
+        MessageHeaderSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the MessageHeader resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MessageHeaderAuthorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for MessageHeaderAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> MessageHeaderAuthorSearchTarget {
+        MessageHeaderAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MessageHeaderAuthorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MessageHeaderAuthorSearchTarget {
+        MessageHeaderAuthorSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The enterer reference search parameter of the MessageHeader resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageHeaderEntererSearchReference;
+impl ReferenceSearchParameter for MessageHeaderEntererSearchReference {
+    type Resource = resources::MessageHeader;
+    type Target = MessageHeaderEntererSearchTarget;
+    fn parameter(&self) -> MessageHeaderSearchParameter {
+        MessageHeaderSearchParameter::Enterer
+    }
+}
+/// Target resources of the enterer reference search parameter of the MessageHeader resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MessageHeaderEntererSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for MessageHeaderEntererSearchTarget {
+    fn from(resource: resources::Practitioner) -> MessageHeaderEntererSearchTarget {
+        MessageHeaderEntererSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MessageHeaderEntererSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MessageHeaderEntererSearchTarget {
+        MessageHeaderEntererSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The focus reference search parameter of the MessageHeader resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageHeaderFocusSearchReference;
+impl ReferenceSearchParameter for MessageHeaderFocusSearchReference {
+    type Resource = resources::MessageHeader;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MessageHeaderSearchParameter {
+        MessageHeaderSearchParameter::Focus
+    }
+}
+/// The receiver reference search parameter of the MessageHeader resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageHeaderReceiverSearchReference;
+impl ReferenceSearchParameter for MessageHeaderReceiverSearchReference {
+    type Resource = resources::MessageHeader;
+    type Target = MessageHeaderReceiverSearchTarget;
+    fn parameter(&self) -> MessageHeaderSearchParameter {
+        MessageHeaderSearchParameter::Receiver
+    }
+}
+/// Target resources of the receiver reference search parameter of the MessageHeader resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MessageHeaderReceiverSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for MessageHeaderReceiverSearchTarget {
+    fn from(resource: resources::Practitioner) -> MessageHeaderReceiverSearchTarget {
+        MessageHeaderReceiverSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for MessageHeaderReceiverSearchTarget {
+    fn from(resource: resources::Organization) -> MessageHeaderReceiverSearchTarget {
+        MessageHeaderReceiverSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MessageHeaderReceiverSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MessageHeaderReceiverSearchTarget {
+        MessageHeaderReceiverSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The responsible reference search parameter of the MessageHeader resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageHeaderResponsibleSearchReference;
+impl ReferenceSearchParameter for MessageHeaderResponsibleSearchReference {
+    type Resource = resources::MessageHeader;
+    type Target = MessageHeaderResponsibleSearchTarget;
+    fn parameter(&self) -> MessageHeaderSearchParameter {
+        MessageHeaderSearchParameter::Responsible
+    }
+}
+/// Target resources of the responsible reference search parameter of the MessageHeader resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MessageHeaderResponsibleSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for MessageHeaderResponsibleSearchTarget {
+    fn from(resource: resources::Practitioner) -> MessageHeaderResponsibleSearchTarget {
+        MessageHeaderResponsibleSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for MessageHeaderResponsibleSearchTarget {
+    fn from(resource: resources::Organization) -> MessageHeaderResponsibleSearchTarget {
+        MessageHeaderResponsibleSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MessageHeaderResponsibleSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MessageHeaderResponsibleSearchTarget {
+        MessageHeaderResponsibleSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The sender reference search parameter of the MessageHeader resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageHeaderSenderSearchReference;
+impl ReferenceSearchParameter for MessageHeaderSenderSearchReference {
+    type Resource = resources::MessageHeader;
+    type Target = MessageHeaderSenderSearchTarget;
+    fn parameter(&self) -> MessageHeaderSearchParameter {
+        MessageHeaderSearchParameter::Sender
+    }
+}
+/// Target resources of the sender reference search parameter of the MessageHeader resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MessageHeaderSenderSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for MessageHeaderSenderSearchTarget {
+    fn from(resource: resources::Practitioner) -> MessageHeaderSenderSearchTarget {
+        MessageHeaderSenderSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for MessageHeaderSenderSearchTarget {
+    fn from(resource: resources::Organization) -> MessageHeaderSenderSearchTarget {
+        MessageHeaderSenderSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MessageHeaderSenderSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MessageHeaderSenderSearchTarget {
+        MessageHeaderSenderSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The target reference search parameter of the MessageHeader resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageHeaderTargetSearchReference;
+impl ReferenceSearchParameter for MessageHeaderTargetSearchReference {
+    type Resource = resources::MessageHeader;
+    type Target = resources::Device;
+    fn parameter(&self) -> MessageHeaderSearchParameter {
+        MessageHeaderSearchParameter::Target
+    }
+}
+/// The patient reference search parameter of the MolecularSequence resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MolecularSequencePatientSearchReference;
+impl ReferenceSearchParameter for MolecularSequencePatientSearchReference {
+    type Resource = resources::MolecularSequence;
+    type Target = resources::Patient;
+    fn parameter(&self) -> MolecularSequenceSearchParameter {
+        MolecularSequenceSearchParameter::Patient
+    }
+}
+/// The patient reference search parameter of the NutritionOrder resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NutritionOrderPatientSearchReference;
+impl ReferenceSearchParameter for NutritionOrderPatientSearchReference {
+    type Resource = resources::NutritionOrder;
+    type Target = resources::Patient;
+    fn parameter(&self) -> NutritionOrderSearchParameter {
+        NutritionOrderSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the NutritionOrder resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NutritionOrderEncounterSearchReference;
+impl ReferenceSearchParameter for NutritionOrderEncounterSearchReference {
+    type Resource = resources::NutritionOrder;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> NutritionOrderSearchParameter {
+        NutritionOrderSearchParameter::Encounter
+    }
+}
+/// The instantiates-canonical reference search parameter of the NutritionOrder resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NutritionOrderInstantiatesCanonicalSearchReference;
+impl ReferenceSearchParameter for NutritionOrderInstantiatesCanonicalSearchReference {
+    type Resource = resources::NutritionOrder;
+    type Target = NutritionOrderInstantiatesCanonicalSearchTarget;
+    fn parameter(&self) -> NutritionOrderSearchParameter {
+        NutritionOrderSearchParameter::InstantiatesCanonical
+    }
+}
+/// Target resources of the instantiates-canonical reference search parameter of the NutritionOrder resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NutritionOrderInstantiatesCanonicalSearchTarget {
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+}
+impl From<resources::PlanDefinition> for NutritionOrderInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> NutritionOrderInstantiatesCanonicalSearchTarget {
+        NutritionOrderInstantiatesCanonicalSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for NutritionOrderInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> NutritionOrderInstantiatesCanonicalSearchTarget {
+        NutritionOrderInstantiatesCanonicalSearchTarget::ActivityDefinition(resource)
+    }
+}
+/// The provider reference search parameter of the NutritionOrder resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NutritionOrderProviderSearchReference;
+impl ReferenceSearchParameter for NutritionOrderProviderSearchReference {
+    type Resource = resources::NutritionOrder;
+    type Target = NutritionOrderProviderSearchTarget;
+    fn parameter(&self) -> NutritionOrderSearchParameter {
+        NutritionOrderSearchParameter::Provider
+    }
+}
+/// Target resources of the provider reference search parameter of the NutritionOrder resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NutritionOrderProviderSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for NutritionOrderProviderSearchTarget {
+    fn from(resource: resources::Practitioner) -> NutritionOrderProviderSearchTarget {
+        NutritionOrderProviderSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::PractitionerRole> for NutritionOrderProviderSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> NutritionOrderProviderSearchTarget {
+        NutritionOrderProviderSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The patient reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationPatientSearchReference;
+impl ReferenceSearchParameter for ObservationPatientSearchReference {
+    type Resource = resources::Observation;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationEncounterSearchReference;
+impl ReferenceSearchParameter for ObservationEncounterSearchReference {
+    type Resource = resources::Observation;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Encounter
+    }
+}
+/// The based-on reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationBasedOnSearchReference;
+impl ReferenceSearchParameter for ObservationBasedOnSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationBasedOnSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationBasedOnSearchTarget {
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for MedicationRequest target resources
+    MedicationRequest(resources::MedicationRequest),
+    /// Variant for NutritionOrder target resources
+    NutritionOrder(resources::NutritionOrder),
+    /// Variant for DeviceRequest target resources
+    DeviceRequest(resources::DeviceRequest),
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+    /// Variant for ImmunizationRecommendation target resources
+    ImmunizationRecommendation(resources::ImmunizationRecommendation),
+}
+impl From<resources::CarePlan> for ObservationBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> ObservationBasedOnSearchTarget {
+        ObservationBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::MedicationRequest> for ObservationBasedOnSearchTarget {
+    fn from(resource: resources::MedicationRequest) -> ObservationBasedOnSearchTarget {
+        ObservationBasedOnSearchTarget::MedicationRequest(resource)
+    }
+}
+impl From<resources::NutritionOrder> for ObservationBasedOnSearchTarget {
+    fn from(resource: resources::NutritionOrder) -> ObservationBasedOnSearchTarget {
+        ObservationBasedOnSearchTarget::NutritionOrder(resource)
+    }
+}
+impl From<resources::DeviceRequest> for ObservationBasedOnSearchTarget {
+    fn from(resource: resources::DeviceRequest) -> ObservationBasedOnSearchTarget {
+        ObservationBasedOnSearchTarget::DeviceRequest(resource)
+    }
+}
+impl From<resources::ServiceRequest> for ObservationBasedOnSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> ObservationBasedOnSearchTarget {
+        ObservationBasedOnSearchTarget::ServiceRequest(resource)
+    }
+}
+impl From<resources::ImmunizationRecommendation> for ObservationBasedOnSearchTarget {
+    fn from(resource: resources::ImmunizationRecommendation) -> ObservationBasedOnSearchTarget {
+        ObservationBasedOnSearchTarget::ImmunizationRecommendation(resource)
+    }
+}
+/// The derived-from reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationDerivedFromSearchReference;
+impl ReferenceSearchParameter for ObservationDerivedFromSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationDerivedFromSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::DerivedFrom
+    }
+}
+/// Target resources of the derived-from reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationDerivedFromSearchTarget {
+    /// Variant for Media target resources
+    Media(resources::Media),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for ImagingStudy target resources
+    ImagingStudy(resources::ImagingStudy),
+    /// Variant for MolecularSequence target resources
+    MolecularSequence(resources::MolecularSequence),
+    /// Variant for QuestionnaireResponse target resources
+    QuestionnaireResponse(resources::QuestionnaireResponse),
+    /// Variant for DocumentReference target resources
+    DocumentReference(resources::DocumentReference),
+}
+impl From<resources::Media> for ObservationDerivedFromSearchTarget {
+    fn from(resource: resources::Media) -> ObservationDerivedFromSearchTarget {
+        ObservationDerivedFromSearchTarget::Media(resource)
+    }
+}
+impl From<resources::Observation> for ObservationDerivedFromSearchTarget {
+    fn from(resource: resources::Observation) -> ObservationDerivedFromSearchTarget {
+        ObservationDerivedFromSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::ImagingStudy> for ObservationDerivedFromSearchTarget {
+    fn from(resource: resources::ImagingStudy) -> ObservationDerivedFromSearchTarget {
+        ObservationDerivedFromSearchTarget::ImagingStudy(resource)
+    }
+}
+impl From<resources::MolecularSequence> for ObservationDerivedFromSearchTarget {
+    fn from(resource: resources::MolecularSequence) -> ObservationDerivedFromSearchTarget {
+        ObservationDerivedFromSearchTarget::MolecularSequence(resource)
+    }
+}
+impl From<resources::QuestionnaireResponse> for ObservationDerivedFromSearchTarget {
+    fn from(resource: resources::QuestionnaireResponse) -> ObservationDerivedFromSearchTarget {
+        ObservationDerivedFromSearchTarget::QuestionnaireResponse(resource)
+    }
+}
+impl From<resources::DocumentReference> for ObservationDerivedFromSearchTarget {
+    fn from(resource: resources::DocumentReference) -> ObservationDerivedFromSearchTarget {
+        ObservationDerivedFromSearchTarget::DocumentReference(resource)
+    }
+}
+/// The device reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationDeviceSearchReference;
+impl ReferenceSearchParameter for ObservationDeviceSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationDeviceSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Device
+    }
+}
+/// Target resources of the device reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationDeviceSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for DeviceMetric target resources
+    DeviceMetric(resources::DeviceMetric),
+}
+impl From<resources::Device> for ObservationDeviceSearchTarget {
+    fn from(resource: resources::Device) -> ObservationDeviceSearchTarget {
+        ObservationDeviceSearchTarget::Device(resource)
+    }
+}
+impl From<resources::DeviceMetric> for ObservationDeviceSearchTarget {
+    fn from(resource: resources::DeviceMetric) -> ObservationDeviceSearchTarget {
+        ObservationDeviceSearchTarget::DeviceMetric(resource)
+    }
+}
+/// The focus reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationFocusSearchReference;
+impl ReferenceSearchParameter for ObservationFocusSearchReference {
+    type Resource = resources::Observation;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Focus
+    }
+}
+/// The has-member reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationHasMemberSearchReference;
+impl ReferenceSearchParameter for ObservationHasMemberSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationHasMemberSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::HasMember
+    }
+}
+/// Target resources of the has-member reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationHasMemberSearchTarget {
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for MolecularSequence target resources
+    MolecularSequence(resources::MolecularSequence),
+    /// Variant for QuestionnaireResponse target resources
+    QuestionnaireResponse(resources::QuestionnaireResponse),
+}
+impl From<resources::Observation> for ObservationHasMemberSearchTarget {
+    fn from(resource: resources::Observation) -> ObservationHasMemberSearchTarget {
+        ObservationHasMemberSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::MolecularSequence> for ObservationHasMemberSearchTarget {
+    fn from(resource: resources::MolecularSequence) -> ObservationHasMemberSearchTarget {
+        ObservationHasMemberSearchTarget::MolecularSequence(resource)
+    }
+}
+impl From<resources::QuestionnaireResponse> for ObservationHasMemberSearchTarget {
+    fn from(resource: resources::QuestionnaireResponse) -> ObservationHasMemberSearchTarget {
+        ObservationHasMemberSearchTarget::QuestionnaireResponse(resource)
+    }
+}
+/// The part-of reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationPartOfSearchReference;
+impl ReferenceSearchParameter for ObservationPartOfSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationPartOfSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::PartOf
+    }
+}
+/// Target resources of the part-of reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationPartOfSearchTarget {
+    /// Variant for Immunization target resources
+    Immunization(resources::Immunization),
+    /// Variant for MedicationDispense target resources
+    MedicationDispense(resources::MedicationDispense),
+    /// Variant for MedicationAdministration target resources
+    MedicationAdministration(resources::MedicationAdministration),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for ImagingStudy target resources
+    ImagingStudy(resources::ImagingStudy),
+    /// Variant for MedicationStatement target resources
+    MedicationStatement(resources::MedicationStatement),
+}
+impl From<resources::Immunization> for ObservationPartOfSearchTarget {
+    fn from(resource: resources::Immunization) -> ObservationPartOfSearchTarget {
+        ObservationPartOfSearchTarget::Immunization(resource)
+    }
+}
+impl From<resources::MedicationDispense> for ObservationPartOfSearchTarget {
+    fn from(resource: resources::MedicationDispense) -> ObservationPartOfSearchTarget {
+        ObservationPartOfSearchTarget::MedicationDispense(resource)
+    }
+}
+impl From<resources::MedicationAdministration> for ObservationPartOfSearchTarget {
+    fn from(resource: resources::MedicationAdministration) -> ObservationPartOfSearchTarget {
+        ObservationPartOfSearchTarget::MedicationAdministration(resource)
+    }
+}
+impl From<resources::Procedure> for ObservationPartOfSearchTarget {
+    fn from(resource: resources::Procedure) -> ObservationPartOfSearchTarget {
+        ObservationPartOfSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::ImagingStudy> for ObservationPartOfSearchTarget {
+    fn from(resource: resources::ImagingStudy) -> ObservationPartOfSearchTarget {
+        ObservationPartOfSearchTarget::ImagingStudy(resource)
+    }
+}
+impl From<resources::MedicationStatement> for ObservationPartOfSearchTarget {
+    fn from(resource: resources::MedicationStatement) -> ObservationPartOfSearchTarget {
+        ObservationPartOfSearchTarget::MedicationStatement(resource)
+    }
+}
+/// The performer reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationPerformerSearchReference;
+impl ReferenceSearchParameter for ObservationPerformerSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationPerformerSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationPerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ObservationPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ObservationPerformerSearchTarget {
+        ObservationPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ObservationPerformerSearchTarget {
+    fn from(resource: resources::Organization) -> ObservationPerformerSearchTarget {
+        ObservationPerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for ObservationPerformerSearchTarget {
+    fn from(resource: resources::CareTeam) -> ObservationPerformerSearchTarget {
+        ObservationPerformerSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Patient> for ObservationPerformerSearchTarget {
+    fn from(resource: resources::Patient) -> ObservationPerformerSearchTarget {
+        ObservationPerformerSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ObservationPerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ObservationPerformerSearchTarget {
+        ObservationPerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ObservationPerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ObservationPerformerSearchTarget {
+        ObservationPerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The specimen reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationSpecimenSearchReference;
+impl ReferenceSearchParameter for ObservationSpecimenSearchReference {
+    type Resource = resources::Observation;
+    type Target = resources::Specimen;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Specimen
+    }
+}
+/// The subject reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationSubjectSearchReference;
+impl ReferenceSearchParameter for ObservationSubjectSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationSubjectSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationSubjectSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Practitioner> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Organization> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Device) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Medication> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Medication) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Medication(resource)
+    }
+}
+impl From<resources::Patient> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Procedure> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Procedure) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::Substance> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Substance) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Substance(resource)
+    }
+}
+impl From<resources::Location> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Location) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Location(resource)
+    }
+}
+/// The base reference search parameter of the OperationDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OperationDefinitionBaseSearchReference;
+impl ReferenceSearchParameter for OperationDefinitionBaseSearchReference {
+    type Resource = resources::OperationDefinition;
+    type Target = resources::OperationDefinition;
+    fn parameter(&self) -> OperationDefinitionSearchParameter {
+        OperationDefinitionSearchParameter::Base
+    }
+}
+/// The input-profile reference search parameter of the OperationDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OperationDefinitionInputProfileSearchReference;
+impl ReferenceSearchParameter for OperationDefinitionInputProfileSearchReference {
+    type Resource = resources::OperationDefinition;
+    type Target = resources::StructureDefinition;
+    fn parameter(&self) -> OperationDefinitionSearchParameter {
+        OperationDefinitionSearchParameter::InputProfile
+    }
+}
+/// The output-profile reference search parameter of the OperationDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OperationDefinitionOutputProfileSearchReference;
+impl ReferenceSearchParameter for OperationDefinitionOutputProfileSearchReference {
+    type Resource = resources::OperationDefinition;
+    type Target = resources::StructureDefinition;
+    fn parameter(&self) -> OperationDefinitionSearchParameter {
+        OperationDefinitionSearchParameter::OutputProfile
+    }
+}
+/// The endpoint reference search parameter of the Organization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrganizationEndpointSearchReference;
+impl ReferenceSearchParameter for OrganizationEndpointSearchReference {
+    type Resource = resources::Organization;
+    type Target = resources::Endpoint;
+    fn parameter(&self) -> OrganizationSearchParameter {
+        OrganizationSearchParameter::Endpoint
+    }
+}
+/// The partof reference search parameter of the Organization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrganizationPartofSearchReference;
+impl ReferenceSearchParameter for OrganizationPartofSearchReference {
+    type Resource = resources::Organization;
+    type Target = resources::Organization;
+    fn parameter(&self) -> OrganizationSearchParameter {
+        OrganizationSearchParameter::Partof
+    }
+}
+/// The endpoint reference search parameter of the OrganizationAffiliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrganizationAffiliationEndpointSearchReference;
+impl ReferenceSearchParameter for OrganizationAffiliationEndpointSearchReference {
+    type Resource = resources::OrganizationAffiliation;
+    type Target = resources::Endpoint;
+    fn parameter(&self) -> OrganizationAffiliationSearchParameter {
+        OrganizationAffiliationSearchParameter::Endpoint
+    }
+}
+/// The location reference search parameter of the OrganizationAffiliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrganizationAffiliationLocationSearchReference;
+impl ReferenceSearchParameter for OrganizationAffiliationLocationSearchReference {
+    type Resource = resources::OrganizationAffiliation;
+    type Target = resources::Location;
+    fn parameter(&self) -> OrganizationAffiliationSearchParameter {
+        OrganizationAffiliationSearchParameter::Location
+    }
+}
+/// The network reference search parameter of the OrganizationAffiliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrganizationAffiliationNetworkSearchReference;
+impl ReferenceSearchParameter for OrganizationAffiliationNetworkSearchReference {
+    type Resource = resources::OrganizationAffiliation;
+    type Target = resources::Organization;
+    fn parameter(&self) -> OrganizationAffiliationSearchParameter {
+        OrganizationAffiliationSearchParameter::Network
+    }
+}
+/// The participating-organization reference search parameter of the OrganizationAffiliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrganizationAffiliationParticipatingOrganizationSearchReference;
+impl ReferenceSearchParameter for OrganizationAffiliationParticipatingOrganizationSearchReference {
+    type Resource = resources::OrganizationAffiliation;
+    type Target = resources::Organization;
+    fn parameter(&self) -> OrganizationAffiliationSearchParameter {
+        OrganizationAffiliationSearchParameter::ParticipatingOrganization
+    }
+}
+/// The primary-organization reference search parameter of the OrganizationAffiliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrganizationAffiliationPrimaryOrganizationSearchReference;
+impl ReferenceSearchParameter for OrganizationAffiliationPrimaryOrganizationSearchReference {
+    type Resource = resources::OrganizationAffiliation;
+    type Target = resources::Organization;
+    fn parameter(&self) -> OrganizationAffiliationSearchParameter {
+        OrganizationAffiliationSearchParameter::PrimaryOrganization
+    }
+}
+/// The service reference search parameter of the OrganizationAffiliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrganizationAffiliationServiceSearchReference;
+impl ReferenceSearchParameter for OrganizationAffiliationServiceSearchReference {
+    type Resource = resources::OrganizationAffiliation;
+    type Target = resources::HealthcareService;
+    fn parameter(&self) -> OrganizationAffiliationSearchParameter {
+        OrganizationAffiliationSearchParameter::Service
+    }
+}
+/// The biological reference search parameter of the PackagedProductDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PackagedProductDefinitionBiologicalSearchReference;
+impl ReferenceSearchParameter for PackagedProductDefinitionBiologicalSearchReference {
+    type Resource = resources::PackagedProductDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PackagedProductDefinitionSearchParameter {
+        PackagedProductDefinitionSearchParameter::Biological
+    }
+}
+/// The contained-item reference search parameter of the PackagedProductDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PackagedProductDefinitionContainedItemSearchReference;
+impl ReferenceSearchParameter for PackagedProductDefinitionContainedItemSearchReference {
+    type Resource = resources::PackagedProductDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PackagedProductDefinitionSearchParameter {
+        PackagedProductDefinitionSearchParameter::ContainedItem
+    }
+}
+/// The device reference search parameter of the PackagedProductDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PackagedProductDefinitionDeviceSearchReference;
+impl ReferenceSearchParameter for PackagedProductDefinitionDeviceSearchReference {
+    type Resource = resources::PackagedProductDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PackagedProductDefinitionSearchParameter {
+        PackagedProductDefinitionSearchParameter::Device
+    }
+}
+/// The manufactured-item reference search parameter of the PackagedProductDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PackagedProductDefinitionManufacturedItemSearchReference;
+impl ReferenceSearchParameter for PackagedProductDefinitionManufacturedItemSearchReference {
+    type Resource = resources::PackagedProductDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PackagedProductDefinitionSearchParameter {
+        PackagedProductDefinitionSearchParameter::ManufacturedItem
+    }
+}
+/// The medication reference search parameter of the PackagedProductDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PackagedProductDefinitionMedicationSearchReference;
+impl ReferenceSearchParameter for PackagedProductDefinitionMedicationSearchReference {
+    type Resource = resources::PackagedProductDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PackagedProductDefinitionSearchParameter {
+        PackagedProductDefinitionSearchParameter::Medication
+    }
+}
+/// The nutrition reference search parameter of the PackagedProductDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PackagedProductDefinitionNutritionSearchReference;
+impl ReferenceSearchParameter for PackagedProductDefinitionNutritionSearchReference {
+    type Resource = resources::PackagedProductDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PackagedProductDefinitionSearchParameter {
+        PackagedProductDefinitionSearchParameter::Nutrition
+    }
+}
+/// The package reference search parameter of the PackagedProductDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PackagedProductDefinitionPackageSearchReference;
+impl ReferenceSearchParameter for PackagedProductDefinitionPackageSearchReference {
+    type Resource = resources::PackagedProductDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PackagedProductDefinitionSearchParameter {
+        PackagedProductDefinitionSearchParameter::Package
+    }
+}
+/// The package-for reference search parameter of the PackagedProductDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PackagedProductDefinitionPackageForSearchReference;
+impl ReferenceSearchParameter for PackagedProductDefinitionPackageForSearchReference {
+    type Resource = resources::PackagedProductDefinition;
+    type Target = resources::MedicinalProductDefinition;
+    fn parameter(&self) -> PackagedProductDefinitionSearchParameter {
+        PackagedProductDefinitionSearchParameter::PackageFor
+    }
+}
+/// The general-practitioner reference search parameter of the Patient resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PatientGeneralPractitionerSearchReference;
+impl ReferenceSearchParameter for PatientGeneralPractitionerSearchReference {
+    type Resource = resources::Patient;
+    type Target = PatientGeneralPractitionerSearchTarget;
+    fn parameter(&self) -> PatientSearchParameter {
+        PatientSearchParameter::GeneralPractitioner
+    }
+}
+/// Target resources of the general-practitioner reference search parameter of the Patient resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PatientGeneralPractitionerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for PatientGeneralPractitionerSearchTarget {
+    fn from(resource: resources::Practitioner) -> PatientGeneralPractitionerSearchTarget {
+        PatientGeneralPractitionerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for PatientGeneralPractitionerSearchTarget {
+    fn from(resource: resources::Organization) -> PatientGeneralPractitionerSearchTarget {
+        PatientGeneralPractitionerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for PatientGeneralPractitionerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> PatientGeneralPractitionerSearchTarget {
+        PatientGeneralPractitionerSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The link reference search parameter of the Patient resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PatientLinkSearchReference;
+impl ReferenceSearchParameter for PatientLinkSearchReference {
+    type Resource = resources::Patient;
+    type Target = PatientLinkSearchTarget;
+    fn parameter(&self) -> PatientSearchParameter {
+        PatientSearchParameter::Link
+    }
+}
+/// Target resources of the link reference search parameter of the Patient resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PatientLinkSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Patient> for PatientLinkSearchTarget {
+    fn from(resource: resources::Patient) -> PatientLinkSearchTarget {
+        PatientLinkSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for PatientLinkSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> PatientLinkSearchTarget {
+        PatientLinkSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The organization reference search parameter of the Patient resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PatientOrganizationSearchReference;
+impl ReferenceSearchParameter for PatientOrganizationSearchReference {
+    type Resource = resources::Patient;
+    type Target = resources::Organization;
+    fn parameter(&self) -> PatientSearchParameter {
+        PatientSearchParameter::Organization
+    }
+}
+/// The provider reference search parameter of the PaymentNotice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentNoticeProviderSearchReference;
+impl ReferenceSearchParameter for PaymentNoticeProviderSearchReference {
+    type Resource = resources::PaymentNotice;
+    type Target = PaymentNoticeProviderSearchTarget;
+    fn parameter(&self) -> PaymentNoticeSearchParameter {
+        PaymentNoticeSearchParameter::Provider
+    }
+}
+/// Target resources of the provider reference search parameter of the PaymentNotice resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PaymentNoticeProviderSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for PaymentNoticeProviderSearchTarget {
+    fn from(resource: resources::Practitioner) -> PaymentNoticeProviderSearchTarget {
+        PaymentNoticeProviderSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for PaymentNoticeProviderSearchTarget {
+    fn from(resource: resources::Organization) -> PaymentNoticeProviderSearchTarget {
+        PaymentNoticeProviderSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for PaymentNoticeProviderSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> PaymentNoticeProviderSearchTarget {
+        PaymentNoticeProviderSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The request reference search parameter of the PaymentNotice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentNoticeRequestSearchReference;
+impl ReferenceSearchParameter for PaymentNoticeRequestSearchReference {
+    type Resource = resources::PaymentNotice;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PaymentNoticeSearchParameter {
+        PaymentNoticeSearchParameter::Request
+    }
+}
+/// The response reference search parameter of the PaymentNotice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentNoticeResponseSearchReference;
+impl ReferenceSearchParameter for PaymentNoticeResponseSearchReference {
+    type Resource = resources::PaymentNotice;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PaymentNoticeSearchParameter {
+        PaymentNoticeSearchParameter::Response
+    }
+}
+/// The payment-issuer reference search parameter of the PaymentReconciliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentReconciliationPaymentIssuerSearchReference;
+impl ReferenceSearchParameter for PaymentReconciliationPaymentIssuerSearchReference {
+    type Resource = resources::PaymentReconciliation;
+    type Target = resources::Organization;
+    fn parameter(&self) -> PaymentReconciliationSearchParameter {
+        PaymentReconciliationSearchParameter::PaymentIssuer
+    }
+}
+/// The request reference search parameter of the PaymentReconciliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentReconciliationRequestSearchReference;
+impl ReferenceSearchParameter for PaymentReconciliationRequestSearchReference {
+    type Resource = resources::PaymentReconciliation;
+    type Target = resources::Task;
+    fn parameter(&self) -> PaymentReconciliationSearchParameter {
+        PaymentReconciliationSearchParameter::Request
+    }
+}
+/// The requestor reference search parameter of the PaymentReconciliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentReconciliationRequestorSearchReference;
+impl ReferenceSearchParameter for PaymentReconciliationRequestorSearchReference {
+    type Resource = resources::PaymentReconciliation;
+    type Target = PaymentReconciliationRequestorSearchTarget;
+    fn parameter(&self) -> PaymentReconciliationSearchParameter {
+        PaymentReconciliationSearchParameter::Requestor
+    }
+}
+/// Target resources of the requestor reference search parameter of the PaymentReconciliation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PaymentReconciliationRequestorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for PaymentReconciliationRequestorSearchTarget {
+    fn from(resource: resources::Practitioner) -> PaymentReconciliationRequestorSearchTarget {
+        PaymentReconciliationRequestorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for PaymentReconciliationRequestorSearchTarget {
+    fn from(resource: resources::Organization) -> PaymentReconciliationRequestorSearchTarget {
+        PaymentReconciliationRequestorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for PaymentReconciliationRequestorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> PaymentReconciliationRequestorSearchTarget {
+        PaymentReconciliationRequestorSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The link reference search parameter of the Person resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PersonLinkSearchReference;
+impl ReferenceSearchParameter for PersonLinkSearchReference {
+    type Resource = resources::Person;
+    type Target = PersonLinkSearchTarget;
+    fn parameter(&self) -> PersonSearchParameter {
+        PersonSearchParameter::Link
+    }
+}
+/// Target resources of the link reference search parameter of the Person resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PersonLinkSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Person target resources
+    Person(resources::Person),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for PersonLinkSearchTarget {
+    fn from(resource: resources::Practitioner) -> PersonLinkSearchTarget {
+        PersonLinkSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for PersonLinkSearchTarget {
+    fn from(resource: resources::Patient) -> PersonLinkSearchTarget {
+        PersonLinkSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Person> for PersonLinkSearchTarget {
+    fn from(resource: resources::Person) -> PersonLinkSearchTarget {
+        PersonLinkSearchTarget::Person(resource)
+    }
+}
+impl From<resources::RelatedPerson> for PersonLinkSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> PersonLinkSearchTarget {
+        PersonLinkSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The organization reference search parameter of the Person resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PersonOrganizationSearchReference;
+impl ReferenceSearchParameter for PersonOrganizationSearchReference {
+    type Resource = resources::Person;
+    type Target = resources::Organization;
+    fn parameter(&self) -> PersonSearchParameter {
+        PersonSearchParameter::Organization
+    }
+}
+/// The patient reference search parameter of the Person resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PersonPatientSearchReference;
+impl ReferenceSearchParameter for PersonPatientSearchReference {
+    type Resource = resources::Person;
+    type Target = resources::Patient;
+    fn parameter(&self) -> PersonSearchParameter {
+        PersonSearchParameter::Patient
+    }
+}
+/// The practitioner reference search parameter of the Person resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PersonPractitionerSearchReference;
+impl ReferenceSearchParameter for PersonPractitionerSearchReference {
+    type Resource = resources::Person;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> PersonSearchParameter {
+        PersonSearchParameter::Practitioner
+    }
+}
+/// The relatedperson reference search parameter of the Person resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PersonRelatedpersonSearchReference;
+impl ReferenceSearchParameter for PersonRelatedpersonSearchReference {
+    type Resource = resources::Person;
+    type Target = resources::RelatedPerson;
+    fn parameter(&self) -> PersonSearchParameter {
+        PersonSearchParameter::Relatedperson
+    }
+}
+/// The composed-of reference search parameter of the PlanDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlanDefinitionComposedOfSearchReference;
+impl ReferenceSearchParameter for PlanDefinitionComposedOfSearchReference {
+    type Resource = resources::PlanDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PlanDefinitionSearchParameter {
+        PlanDefinitionSearchParameter::ComposedOf
+    }
+}
+/// The definition reference search parameter of the PlanDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlanDefinitionDefinitionSearchReference;
+impl ReferenceSearchParameter for PlanDefinitionDefinitionSearchReference {
+    type Resource = resources::PlanDefinition;
+    type Target = PlanDefinitionDefinitionSearchTarget;
+    fn parameter(&self) -> PlanDefinitionSearchParameter {
+        PlanDefinitionSearchParameter::Definition
+    }
+}
+/// Target resources of the definition reference search parameter of the PlanDefinition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PlanDefinitionDefinitionSearchTarget {
+    /// Variant for Questionnaire target resources
+    Questionnaire(resources::Questionnaire),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+}
+impl From<resources::Questionnaire> for PlanDefinitionDefinitionSearchTarget {
+    fn from(resource: resources::Questionnaire) -> PlanDefinitionDefinitionSearchTarget {
+        PlanDefinitionDefinitionSearchTarget::Questionnaire(resource)
+    }
+}
+impl From<resources::PlanDefinition> for PlanDefinitionDefinitionSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> PlanDefinitionDefinitionSearchTarget {
+        PlanDefinitionDefinitionSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for PlanDefinitionDefinitionSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> PlanDefinitionDefinitionSearchTarget {
+        PlanDefinitionDefinitionSearchTarget::ActivityDefinition(resource)
+    }
+}
+/// The depends-on reference search parameter of the PlanDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlanDefinitionDependsOnSearchReference;
+impl ReferenceSearchParameter for PlanDefinitionDependsOnSearchReference {
+    type Resource = resources::PlanDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PlanDefinitionSearchParameter {
+        PlanDefinitionSearchParameter::DependsOn
+    }
+}
+/// The derived-from reference search parameter of the PlanDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlanDefinitionDerivedFromSearchReference;
+impl ReferenceSearchParameter for PlanDefinitionDerivedFromSearchReference {
+    type Resource = resources::PlanDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PlanDefinitionSearchParameter {
+        PlanDefinitionSearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the PlanDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlanDefinitionPredecessorSearchReference;
+impl ReferenceSearchParameter for PlanDefinitionPredecessorSearchReference {
+    type Resource = resources::PlanDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PlanDefinitionSearchParameter {
+        PlanDefinitionSearchParameter::Predecessor
+    }
+}
+/// The successor reference search parameter of the PlanDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlanDefinitionSuccessorSearchReference;
+impl ReferenceSearchParameter for PlanDefinitionSuccessorSearchReference {
+    type Resource = resources::PlanDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PlanDefinitionSearchParameter {
+        PlanDefinitionSearchParameter::Successor
+    }
+}
+/// The endpoint reference search parameter of the PractitionerRole resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PractitionerRoleEndpointSearchReference;
+impl ReferenceSearchParameter for PractitionerRoleEndpointSearchReference {
+    type Resource = resources::PractitionerRole;
+    type Target = resources::Endpoint;
+    fn parameter(&self) -> PractitionerRoleSearchParameter {
+        PractitionerRoleSearchParameter::Endpoint
+    }
+}
+/// The location reference search parameter of the PractitionerRole resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PractitionerRoleLocationSearchReference;
+impl ReferenceSearchParameter for PractitionerRoleLocationSearchReference {
+    type Resource = resources::PractitionerRole;
+    type Target = resources::Location;
+    fn parameter(&self) -> PractitionerRoleSearchParameter {
+        PractitionerRoleSearchParameter::Location
+    }
+}
+/// The organization reference search parameter of the PractitionerRole resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PractitionerRoleOrganizationSearchReference;
+impl ReferenceSearchParameter for PractitionerRoleOrganizationSearchReference {
+    type Resource = resources::PractitionerRole;
+    type Target = resources::Organization;
+    fn parameter(&self) -> PractitionerRoleSearchParameter {
+        PractitionerRoleSearchParameter::Organization
+    }
+}
+/// The practitioner reference search parameter of the PractitionerRole resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PractitionerRolePractitionerSearchReference;
+impl ReferenceSearchParameter for PractitionerRolePractitionerSearchReference {
+    type Resource = resources::PractitionerRole;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> PractitionerRoleSearchParameter {
+        PractitionerRoleSearchParameter::Practitioner
+    }
+}
+/// The service reference search parameter of the PractitionerRole resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PractitionerRoleServiceSearchReference;
+impl ReferenceSearchParameter for PractitionerRoleServiceSearchReference {
+    type Resource = resources::PractitionerRole;
+    type Target = resources::HealthcareService;
+    fn parameter(&self) -> PractitionerRoleSearchParameter {
+        PractitionerRoleSearchParameter::Service
+    }
+}
+/// The patient reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedurePatientSearchReference;
+impl ReferenceSearchParameter for ProcedurePatientSearchReference {
+    type Resource = resources::Procedure;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureEncounterSearchReference;
+impl ReferenceSearchParameter for ProcedureEncounterSearchReference {
+    type Resource = resources::Procedure;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::Encounter
+    }
+}
+/// The based-on reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureBasedOnSearchReference;
+impl ReferenceSearchParameter for ProcedureBasedOnSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedureBasedOnSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureBasedOnSearchTarget {
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+}
+impl From<resources::CarePlan> for ProcedureBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> ProcedureBasedOnSearchTarget {
+        ProcedureBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::ServiceRequest> for ProcedureBasedOnSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> ProcedureBasedOnSearchTarget {
+        ProcedureBasedOnSearchTarget::ServiceRequest(resource)
+    }
+}
+/// The instantiates-canonical reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureInstantiatesCanonicalSearchReference;
+impl ReferenceSearchParameter for ProcedureInstantiatesCanonicalSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedureInstantiatesCanonicalSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::InstantiatesCanonical
+    }
+}
+/// Target resources of the instantiates-canonical reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureInstantiatesCanonicalSearchTarget {
+    /// Variant for Questionnaire target resources
+    Questionnaire(resources::Questionnaire),
+    /// Variant for Measure target resources
+    Measure(resources::Measure),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for OperationDefinition target resources
+    OperationDefinition(resources::OperationDefinition),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+}
+impl From<resources::Questionnaire> for ProcedureInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Questionnaire) -> ProcedureInstantiatesCanonicalSearchTarget {
+        ProcedureInstantiatesCanonicalSearchTarget::Questionnaire(resource)
+    }
+}
+impl From<resources::Measure> for ProcedureInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Measure) -> ProcedureInstantiatesCanonicalSearchTarget {
+        ProcedureInstantiatesCanonicalSearchTarget::Measure(resource)
+    }
+}
+impl From<resources::PlanDefinition> for ProcedureInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> ProcedureInstantiatesCanonicalSearchTarget {
+        ProcedureInstantiatesCanonicalSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::OperationDefinition> for ProcedureInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::OperationDefinition) -> ProcedureInstantiatesCanonicalSearchTarget {
+        ProcedureInstantiatesCanonicalSearchTarget::OperationDefinition(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for ProcedureInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> ProcedureInstantiatesCanonicalSearchTarget {
+        ProcedureInstantiatesCanonicalSearchTarget::ActivityDefinition(resource)
+    }
+}
+/// The location reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureLocationSearchReference;
+impl ReferenceSearchParameter for ProcedureLocationSearchReference {
+    type Resource = resources::Procedure;
+    type Target = resources::Location;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::Location
+    }
+}
+/// The part-of reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedurePartOfSearchReference;
+impl ReferenceSearchParameter for ProcedurePartOfSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedurePartOfSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::PartOf
+    }
+}
+/// Target resources of the part-of reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedurePartOfSearchTarget {
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for MedicationAdministration target resources
+    MedicationAdministration(resources::MedicationAdministration),
+}
+impl From<resources::Observation> for ProcedurePartOfSearchTarget {
+    fn from(resource: resources::Observation) -> ProcedurePartOfSearchTarget {
+        ProcedurePartOfSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::Procedure> for ProcedurePartOfSearchTarget {
+    fn from(resource: resources::Procedure) -> ProcedurePartOfSearchTarget {
+        ProcedurePartOfSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::MedicationAdministration> for ProcedurePartOfSearchTarget {
+    fn from(resource: resources::MedicationAdministration) -> ProcedurePartOfSearchTarget {
+        ProcedurePartOfSearchTarget::MedicationAdministration(resource)
+    }
+}
+/// The performer reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedurePerformerSearchReference;
+impl ReferenceSearchParameter for ProcedurePerformerSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedurePerformerSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedurePerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ProcedurePerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ProcedurePerformerSearchTarget {
+        ProcedurePerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ProcedurePerformerSearchTarget {
+    fn from(resource: resources::Organization) -> ProcedurePerformerSearchTarget {
+        ProcedurePerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for ProcedurePerformerSearchTarget {
+    fn from(resource: resources::Device) -> ProcedurePerformerSearchTarget {
+        ProcedurePerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ProcedurePerformerSearchTarget {
+    fn from(resource: resources::Patient) -> ProcedurePerformerSearchTarget {
+        ProcedurePerformerSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ProcedurePerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ProcedurePerformerSearchTarget {
+        ProcedurePerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ProcedurePerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ProcedurePerformerSearchTarget {
+        ProcedurePerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The reason-reference reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureReasonReferenceSearchReference;
+impl ReferenceSearchParameter for ProcedureReasonReferenceSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedureReasonReferenceSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::ReasonReference
+    }
+}
+/// Target resources of the reason-reference reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureReasonReferenceSearchTarget {
+    /// Variant for Condition target resources
+    Condition(resources::Condition),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for DiagnosticReport target resources
+    DiagnosticReport(resources::DiagnosticReport),
+    /// Variant for DocumentReference target resources
+    DocumentReference(resources::DocumentReference),
+}
+impl From<resources::Condition> for ProcedureReasonReferenceSearchTarget {
+    fn from(resource: resources::Condition) -> ProcedureReasonReferenceSearchTarget {
+        ProcedureReasonReferenceSearchTarget::Condition(resource)
+    }
+}
+impl From<resources::Observation> for ProcedureReasonReferenceSearchTarget {
+    fn from(resource: resources::Observation) -> ProcedureReasonReferenceSearchTarget {
+        ProcedureReasonReferenceSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::Procedure> for ProcedureReasonReferenceSearchTarget {
+    fn from(resource: resources::Procedure) -> ProcedureReasonReferenceSearchTarget {
+        ProcedureReasonReferenceSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::DiagnosticReport> for ProcedureReasonReferenceSearchTarget {
+    fn from(resource: resources::DiagnosticReport) -> ProcedureReasonReferenceSearchTarget {
+        ProcedureReasonReferenceSearchTarget::DiagnosticReport(resource)
+    }
+}
+impl From<resources::DocumentReference> for ProcedureReasonReferenceSearchTarget {
+    fn from(resource: resources::DocumentReference) -> ProcedureReasonReferenceSearchTarget {
+        ProcedureReasonReferenceSearchTarget::DocumentReference(resource)
+    }
+}
+/// The subject reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureSubjectSearchReference;
+impl ReferenceSearchParameter for ProcedureSubjectSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedureSubjectSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for ProcedureSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ProcedureSubjectSearchTarget {
+        ProcedureSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for ProcedureSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ProcedureSubjectSearchTarget {
+        ProcedureSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The agent reference search parameter of the Provenance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProvenanceAgentSearchReference;
+impl ReferenceSearchParameter for ProvenanceAgentSearchReference {
+    type Resource = resources::Provenance;
+    type Target = ProvenanceAgentSearchTarget;
+    fn parameter(&self) -> ProvenanceSearchParameter {
+        ProvenanceSearchParameter::Agent
+    }
+}
+/// Target resources of the agent reference search parameter of the Provenance resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProvenanceAgentSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ProvenanceAgentSearchTarget {
+    fn from(resource: resources::Practitioner) -> ProvenanceAgentSearchTarget {
+        ProvenanceAgentSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ProvenanceAgentSearchTarget {
+    fn from(resource: resources::Organization) -> ProvenanceAgentSearchTarget {
+        ProvenanceAgentSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for ProvenanceAgentSearchTarget {
+    fn from(resource: resources::Device) -> ProvenanceAgentSearchTarget {
+        ProvenanceAgentSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ProvenanceAgentSearchTarget {
+    fn from(resource: resources::Patient) -> ProvenanceAgentSearchTarget {
+        ProvenanceAgentSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ProvenanceAgentSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ProvenanceAgentSearchTarget {
+        ProvenanceAgentSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ProvenanceAgentSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ProvenanceAgentSearchTarget {
+        ProvenanceAgentSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The entity reference search parameter of the Provenance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProvenanceEntitySearchReference;
+impl ReferenceSearchParameter for ProvenanceEntitySearchReference {
+    type Resource = resources::Provenance;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ProvenanceSearchParameter {
+        ProvenanceSearchParameter::Entity
+    }
+}
+/// The location reference search parameter of the Provenance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProvenanceLocationSearchReference;
+impl ReferenceSearchParameter for ProvenanceLocationSearchReference {
+    type Resource = resources::Provenance;
+    type Target = resources::Location;
+    fn parameter(&self) -> ProvenanceSearchParameter {
+        ProvenanceSearchParameter::Location
+    }
+}
+/// The patient reference search parameter of the Provenance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProvenancePatientSearchReference;
+impl ReferenceSearchParameter for ProvenancePatientSearchReference {
+    type Resource = resources::Provenance;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ProvenanceSearchParameter {
+        ProvenanceSearchParameter::Patient
+    }
+}
+/// The target reference search parameter of the Provenance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProvenanceTargetSearchReference;
+impl ReferenceSearchParameter for ProvenanceTargetSearchReference {
+    type Resource = resources::Provenance;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ProvenanceSearchParameter {
+        ProvenanceSearchParameter::Target
+    }
+}
+/// The author reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponseAuthorSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponseAuthorSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = QuestionnaireResponseAuthorSearchTarget;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the QuestionnaireResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum QuestionnaireResponseAuthorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for QuestionnaireResponseAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> QuestionnaireResponseAuthorSearchTarget {
+        QuestionnaireResponseAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for QuestionnaireResponseAuthorSearchTarget {
+    fn from(resource: resources::Organization) -> QuestionnaireResponseAuthorSearchTarget {
+        QuestionnaireResponseAuthorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for QuestionnaireResponseAuthorSearchTarget {
+    fn from(resource: resources::Device) -> QuestionnaireResponseAuthorSearchTarget {
+        QuestionnaireResponseAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for QuestionnaireResponseAuthorSearchTarget {
+    fn from(resource: resources::Patient) -> QuestionnaireResponseAuthorSearchTarget {
+        QuestionnaireResponseAuthorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for QuestionnaireResponseAuthorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> QuestionnaireResponseAuthorSearchTarget {
+        QuestionnaireResponseAuthorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for QuestionnaireResponseAuthorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> QuestionnaireResponseAuthorSearchTarget {
+        QuestionnaireResponseAuthorSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The based-on reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponseBasedOnSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponseBasedOnSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = QuestionnaireResponseBasedOnSearchTarget;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the QuestionnaireResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum QuestionnaireResponseBasedOnSearchTarget {
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+}
+impl From<resources::CarePlan> for QuestionnaireResponseBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> QuestionnaireResponseBasedOnSearchTarget {
+        QuestionnaireResponseBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::ServiceRequest> for QuestionnaireResponseBasedOnSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> QuestionnaireResponseBasedOnSearchTarget {
+        QuestionnaireResponseBasedOnSearchTarget::ServiceRequest(resource)
+    }
+}
+/// The encounter reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponseEncounterSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponseEncounterSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::Encounter
+    }
+}
+/// The part-of reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponsePartOfSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponsePartOfSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = QuestionnaireResponsePartOfSearchTarget;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::PartOf
+    }
+}
+/// Target resources of the part-of reference search parameter of the QuestionnaireResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum QuestionnaireResponsePartOfSearchTarget {
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+}
+impl From<resources::Observation> for QuestionnaireResponsePartOfSearchTarget {
+    fn from(resource: resources::Observation) -> QuestionnaireResponsePartOfSearchTarget {
+        QuestionnaireResponsePartOfSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::Procedure> for QuestionnaireResponsePartOfSearchTarget {
+    fn from(resource: resources::Procedure) -> QuestionnaireResponsePartOfSearchTarget {
+        QuestionnaireResponsePartOfSearchTarget::Procedure(resource)
+    }
+}
+/// The patient reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponsePatientSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponsePatientSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = resources::Patient;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::Patient
+    }
+}
+/// The questionnaire reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponseQuestionnaireSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponseQuestionnaireSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = resources::Questionnaire;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::Questionnaire
+    }
+}
+/// The source reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponseSourceSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponseSourceSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = QuestionnaireResponseSourceSearchTarget;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::Source
+    }
+}
+/// Target resources of the source reference search parameter of the QuestionnaireResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum QuestionnaireResponseSourceSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for QuestionnaireResponseSourceSearchTarget {
+    fn from(resource: resources::Practitioner) -> QuestionnaireResponseSourceSearchTarget {
+        QuestionnaireResponseSourceSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for QuestionnaireResponseSourceSearchTarget {
+    fn from(resource: resources::Patient) -> QuestionnaireResponseSourceSearchTarget {
+        QuestionnaireResponseSourceSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for QuestionnaireResponseSourceSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> QuestionnaireResponseSourceSearchTarget {
+        QuestionnaireResponseSourceSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for QuestionnaireResponseSourceSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> QuestionnaireResponseSourceSearchTarget {
+        QuestionnaireResponseSourceSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subject reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponseSubjectSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponseSubjectSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = resources::Resource;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::Subject
+    }
+}
+/// The holder reference search parameter of the RegulatedAuthorization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RegulatedAuthorizationHolderSearchReference;
+impl ReferenceSearchParameter for RegulatedAuthorizationHolderSearchReference {
+    type Resource = resources::RegulatedAuthorization;
+    type Target = resources::Organization;
+    fn parameter(&self) -> RegulatedAuthorizationSearchParameter {
+        RegulatedAuthorizationSearchParameter::Holder
+    }
+}
+/// The subject reference search parameter of the RegulatedAuthorization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RegulatedAuthorizationSubjectSearchReference;
+impl ReferenceSearchParameter for RegulatedAuthorizationSubjectSearchReference {
+    type Resource = resources::RegulatedAuthorization;
+    type Target = RegulatedAuthorizationSubjectSearchTarget;
+    fn parameter(&self) -> RegulatedAuthorizationSearchParameter {
+        RegulatedAuthorizationSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the RegulatedAuthorization resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RegulatedAuthorizationSubjectSearchTarget {
+    /// Variant for SubstanceDefinition target resources
+    SubstanceDefinition(resources::SubstanceDefinition),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for BiologicallyDerivedProduct target resources
+    BiologicallyDerivedProduct(resources::BiologicallyDerivedProduct),
+    /// Variant for PackagedProductDefinition target resources
+    PackagedProductDefinition(resources::PackagedProductDefinition),
+    /// Variant for ResearchStudy target resources
+    ResearchStudy(resources::ResearchStudy),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for MedicinalProductDefinition target resources
+    MedicinalProductDefinition(resources::MedicinalProductDefinition),
+    /// Variant for NutritionProduct target resources
+    NutritionProduct(resources::NutritionProduct),
+    /// Variant for DeviceDefinition target resources
+    DeviceDefinition(resources::DeviceDefinition),
+    /// Variant for ObservationDefinition target resources
+    ObservationDefinition(resources::ObservationDefinition),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::SubstanceDefinition> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::SubstanceDefinition) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::SubstanceDefinition(resource)
+    }
+}
+impl From<resources::Organization> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::BiologicallyDerivedProduct> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::BiologicallyDerivedProduct) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::BiologicallyDerivedProduct(resource)
+    }
+}
+impl From<resources::PackagedProductDefinition> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::PackagedProductDefinition) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::PackagedProductDefinition(resource)
+    }
+}
+impl From<resources::ResearchStudy> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::ResearchStudy) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::ResearchStudy(resource)
+    }
+}
+impl From<resources::Practitioner> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::MedicinalProductDefinition> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::MedicinalProductDefinition) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::MedicinalProductDefinition(resource)
+    }
+}
+impl From<resources::NutritionProduct> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::NutritionProduct) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::NutritionProduct(resource)
+    }
+}
+impl From<resources::DeviceDefinition> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::DeviceDefinition) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::DeviceDefinition(resource)
+    }
+}
+impl From<resources::ObservationDefinition> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::ObservationDefinition) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::ObservationDefinition(resource)
+    }
+}
+impl From<resources::PlanDefinition> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::ActivityDefinition(resource)
+    }
+}
+impl From<resources::Location> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::Location) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::Location(resource)
+    }
+}
+/// The patient reference search parameter of the RelatedPerson resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RelatedPersonPatientSearchReference;
+impl ReferenceSearchParameter for RelatedPersonPatientSearchReference {
+    type Resource = resources::RelatedPerson;
+    type Target = resources::Patient;
+    fn parameter(&self) -> RelatedPersonSearchParameter {
+        RelatedPersonSearchParameter::Patient
+    }
+}
+/// The author reference search parameter of the RequestGroup resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestGroupAuthorSearchReference;
+impl ReferenceSearchParameter for RequestGroupAuthorSearchReference {
+    type Resource = resources::RequestGroup;
+    type Target = RequestGroupAuthorSearchTarget;
+    fn parameter(&self) -> RequestGroupSearchParameter {
+        RequestGroupSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the RequestGroup resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RequestGroupAuthorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for RequestGroupAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> RequestGroupAuthorSearchTarget {
+        RequestGroupAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Device> for RequestGroupAuthorSearchTarget {
+    fn from(resource: resources::Device) -> RequestGroupAuthorSearchTarget {
+        RequestGroupAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::PractitionerRole> for RequestGroupAuthorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> RequestGroupAuthorSearchTarget {
+        RequestGroupAuthorSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The encounter reference search parameter of the RequestGroup resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestGroupEncounterSearchReference;
+impl ReferenceSearchParameter for RequestGroupEncounterSearchReference {
+    type Resource = resources::RequestGroup;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> RequestGroupSearchParameter {
+        RequestGroupSearchParameter::Encounter
+    }
+}
+/// The instantiates-canonical reference search parameter of the RequestGroup resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestGroupInstantiatesCanonicalSearchReference;
+impl ReferenceSearchParameter for RequestGroupInstantiatesCanonicalSearchReference {
+    type Resource = resources::RequestGroup;
+    type Target = resources::Resource;
+    fn parameter(&self) -> RequestGroupSearchParameter {
+        RequestGroupSearchParameter::InstantiatesCanonical
+    }
+}
+/// The participant reference search parameter of the RequestGroup resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestGroupParticipantSearchReference;
+impl ReferenceSearchParameter for RequestGroupParticipantSearchReference {
+    type Resource = resources::RequestGroup;
+    type Target = RequestGroupParticipantSearchTarget;
+    fn parameter(&self) -> RequestGroupSearchParameter {
+        RequestGroupSearchParameter::Participant
+    }
+}
+/// Target resources of the participant reference search parameter of the RequestGroup resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RequestGroupParticipantSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for RequestGroupParticipantSearchTarget {
+    fn from(resource: resources::Practitioner) -> RequestGroupParticipantSearchTarget {
+        RequestGroupParticipantSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Device> for RequestGroupParticipantSearchTarget {
+    fn from(resource: resources::Device) -> RequestGroupParticipantSearchTarget {
+        RequestGroupParticipantSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for RequestGroupParticipantSearchTarget {
+    fn from(resource: resources::Patient) -> RequestGroupParticipantSearchTarget {
+        RequestGroupParticipantSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for RequestGroupParticipantSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> RequestGroupParticipantSearchTarget {
+        RequestGroupParticipantSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for RequestGroupParticipantSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> RequestGroupParticipantSearchTarget {
+        RequestGroupParticipantSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The patient reference search parameter of the RequestGroup resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestGroupPatientSearchReference;
+impl ReferenceSearchParameter for RequestGroupPatientSearchReference {
+    type Resource = resources::RequestGroup;
+    type Target = resources::Patient;
+    fn parameter(&self) -> RequestGroupSearchParameter {
+        RequestGroupSearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the RequestGroup resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestGroupSubjectSearchReference;
+impl ReferenceSearchParameter for RequestGroupSubjectSearchReference {
+    type Resource = resources::RequestGroup;
+    type Target = RequestGroupSubjectSearchTarget;
+    fn parameter(&self) -> RequestGroupSearchParameter {
+        RequestGroupSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the RequestGroup resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RequestGroupSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for RequestGroupSubjectSearchTarget {
+    fn from(resource: resources::Group) -> RequestGroupSubjectSearchTarget {
+        RequestGroupSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for RequestGroupSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> RequestGroupSubjectSearchTarget {
+        RequestGroupSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The composed-of reference search parameter of the ResearchDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchDefinitionComposedOfSearchReference;
+impl ReferenceSearchParameter for ResearchDefinitionComposedOfSearchReference {
+    type Resource = resources::ResearchDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ResearchDefinitionSearchParameter {
+        ResearchDefinitionSearchParameter::ComposedOf
+    }
+}
+/// The depends-on reference search parameter of the ResearchDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchDefinitionDependsOnSearchReference;
+impl ReferenceSearchParameter for ResearchDefinitionDependsOnSearchReference {
+    type Resource = resources::ResearchDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ResearchDefinitionSearchParameter {
+        ResearchDefinitionSearchParameter::DependsOn
+    }
+}
+/// The derived-from reference search parameter of the ResearchDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchDefinitionDerivedFromSearchReference;
+impl ReferenceSearchParameter for ResearchDefinitionDerivedFromSearchReference {
+    type Resource = resources::ResearchDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ResearchDefinitionSearchParameter {
+        ResearchDefinitionSearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the ResearchDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchDefinitionPredecessorSearchReference;
+impl ReferenceSearchParameter for ResearchDefinitionPredecessorSearchReference {
+    type Resource = resources::ResearchDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ResearchDefinitionSearchParameter {
+        ResearchDefinitionSearchParameter::Predecessor
+    }
+}
+/// The successor reference search parameter of the ResearchDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchDefinitionSuccessorSearchReference;
+impl ReferenceSearchParameter for ResearchDefinitionSuccessorSearchReference {
+    type Resource = resources::ResearchDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ResearchDefinitionSearchParameter {
+        ResearchDefinitionSearchParameter::Successor
+    }
+}
+/// The composed-of reference search parameter of the ResearchElementDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchElementDefinitionComposedOfSearchReference;
+impl ReferenceSearchParameter for ResearchElementDefinitionComposedOfSearchReference {
+    type Resource = resources::ResearchElementDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ResearchElementDefinitionSearchParameter {
+        ResearchElementDefinitionSearchParameter::ComposedOf
+    }
+}
+/// The depends-on reference search parameter of the ResearchElementDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchElementDefinitionDependsOnSearchReference;
+impl ReferenceSearchParameter for ResearchElementDefinitionDependsOnSearchReference {
+    type Resource = resources::ResearchElementDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ResearchElementDefinitionSearchParameter {
+        ResearchElementDefinitionSearchParameter::DependsOn
+    }
+}
+/// The derived-from reference search parameter of the ResearchElementDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchElementDefinitionDerivedFromSearchReference;
+impl ReferenceSearchParameter for ResearchElementDefinitionDerivedFromSearchReference {
+    type Resource = resources::ResearchElementDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ResearchElementDefinitionSearchParameter {
+        ResearchElementDefinitionSearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the ResearchElementDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchElementDefinitionPredecessorSearchReference;
+impl ReferenceSearchParameter for ResearchElementDefinitionPredecessorSearchReference {
+    type Resource = resources::ResearchElementDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ResearchElementDefinitionSearchParameter {
+        ResearchElementDefinitionSearchParameter::Predecessor
+    }
+}
+/// The successor reference search parameter of the ResearchElementDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchElementDefinitionSuccessorSearchReference;
+impl ReferenceSearchParameter for ResearchElementDefinitionSuccessorSearchReference {
+    type Resource = resources::ResearchElementDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ResearchElementDefinitionSearchParameter {
+        ResearchElementDefinitionSearchParameter::Successor
+    }
+}
+/// The partof reference search parameter of the ResearchStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchStudyPartofSearchReference;
+impl ReferenceSearchParameter for ResearchStudyPartofSearchReference {
+    type Resource = resources::ResearchStudy;
+    type Target = resources::ResearchStudy;
+    fn parameter(&self) -> ResearchStudySearchParameter {
+        ResearchStudySearchParameter::Partof
+    }
+}
+/// The principalinvestigator reference search parameter of the ResearchStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchStudyPrincipalinvestigatorSearchReference;
+impl ReferenceSearchParameter for ResearchStudyPrincipalinvestigatorSearchReference {
+    type Resource = resources::ResearchStudy;
+    type Target = ResearchStudyPrincipalinvestigatorSearchTarget;
+    fn parameter(&self) -> ResearchStudySearchParameter {
+        ResearchStudySearchParameter::Principalinvestigator
+    }
+}
+/// Target resources of the principalinvestigator reference search parameter of the ResearchStudy resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ResearchStudyPrincipalinvestigatorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for ResearchStudyPrincipalinvestigatorSearchTarget {
+    fn from(resource: resources::Practitioner) -> ResearchStudyPrincipalinvestigatorSearchTarget {
+        ResearchStudyPrincipalinvestigatorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ResearchStudyPrincipalinvestigatorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ResearchStudyPrincipalinvestigatorSearchTarget {
+        ResearchStudyPrincipalinvestigatorSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The protocol reference search parameter of the ResearchStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchStudyProtocolSearchReference;
+impl ReferenceSearchParameter for ResearchStudyProtocolSearchReference {
+    type Resource = resources::ResearchStudy;
+    type Target = resources::PlanDefinition;
+    fn parameter(&self) -> ResearchStudySearchParameter {
+        ResearchStudySearchParameter::Protocol
+    }
+}
+/// The site reference search parameter of the ResearchStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchStudySiteSearchReference;
+impl ReferenceSearchParameter for ResearchStudySiteSearchReference {
+    type Resource = resources::ResearchStudy;
+    type Target = resources::Location;
+    fn parameter(&self) -> ResearchStudySearchParameter {
+        ResearchStudySearchParameter::Site
+    }
+}
+/// The sponsor reference search parameter of the ResearchStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchStudySponsorSearchReference;
+impl ReferenceSearchParameter for ResearchStudySponsorSearchReference {
+    type Resource = resources::ResearchStudy;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ResearchStudySearchParameter {
+        ResearchStudySearchParameter::Sponsor
+    }
+}
+/// The individual reference search parameter of the ResearchSubject resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchSubjectIndividualSearchReference;
+impl ReferenceSearchParameter for ResearchSubjectIndividualSearchReference {
+    type Resource = resources::ResearchSubject;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ResearchSubjectSearchParameter {
+        ResearchSubjectSearchParameter::Individual
+    }
+}
+/// The patient reference search parameter of the ResearchSubject resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchSubjectPatientSearchReference;
+impl ReferenceSearchParameter for ResearchSubjectPatientSearchReference {
+    type Resource = resources::ResearchSubject;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ResearchSubjectSearchParameter {
+        ResearchSubjectSearchParameter::Patient
+    }
+}
+/// The study reference search parameter of the ResearchSubject resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchSubjectStudySearchReference;
+impl ReferenceSearchParameter for ResearchSubjectStudySearchReference {
+    type Resource = resources::ResearchSubject;
+    type Target = resources::ResearchStudy;
+    fn parameter(&self) -> ResearchSubjectSearchParameter {
+        ResearchSubjectSearchParameter::Study
+    }
+}
+/// The patient reference search parameter of the RiskAssessment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RiskAssessmentPatientSearchReference;
+impl ReferenceSearchParameter for RiskAssessmentPatientSearchReference {
+    type Resource = resources::RiskAssessment;
+    type Target = resources::Patient;
+    fn parameter(&self) -> RiskAssessmentSearchParameter {
+        RiskAssessmentSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the RiskAssessment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RiskAssessmentEncounterSearchReference;
+impl ReferenceSearchParameter for RiskAssessmentEncounterSearchReference {
+    type Resource = resources::RiskAssessment;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> RiskAssessmentSearchParameter {
+        RiskAssessmentSearchParameter::Encounter
+    }
+}
+/// The condition reference search parameter of the RiskAssessment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RiskAssessmentConditionSearchReference;
+impl ReferenceSearchParameter for RiskAssessmentConditionSearchReference {
+    type Resource = resources::RiskAssessment;
+    type Target = resources::Condition;
+    fn parameter(&self) -> RiskAssessmentSearchParameter {
+        RiskAssessmentSearchParameter::Condition
+    }
+}
+/// The performer reference search parameter of the RiskAssessment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RiskAssessmentPerformerSearchReference;
+impl ReferenceSearchParameter for RiskAssessmentPerformerSearchReference {
+    type Resource = resources::RiskAssessment;
+    type Target = RiskAssessmentPerformerSearchTarget;
+    fn parameter(&self) -> RiskAssessmentSearchParameter {
+        RiskAssessmentSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the RiskAssessment resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RiskAssessmentPerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for RiskAssessmentPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> RiskAssessmentPerformerSearchTarget {
+        RiskAssessmentPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Device> for RiskAssessmentPerformerSearchTarget {
+    fn from(resource: resources::Device) -> RiskAssessmentPerformerSearchTarget {
+        RiskAssessmentPerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::PractitionerRole> for RiskAssessmentPerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> RiskAssessmentPerformerSearchTarget {
+        RiskAssessmentPerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The subject reference search parameter of the RiskAssessment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RiskAssessmentSubjectSearchReference;
+impl ReferenceSearchParameter for RiskAssessmentSubjectSearchReference {
+    type Resource = resources::RiskAssessment;
+    type Target = RiskAssessmentSubjectSearchTarget;
+    fn parameter(&self) -> RiskAssessmentSearchParameter {
+        RiskAssessmentSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the RiskAssessment resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RiskAssessmentSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for RiskAssessmentSubjectSearchTarget {
+    fn from(resource: resources::Group) -> RiskAssessmentSubjectSearchTarget {
+        RiskAssessmentSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for RiskAssessmentSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> RiskAssessmentSubjectSearchTarget {
+        RiskAssessmentSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The actor reference search parameter of the Schedule resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ScheduleActorSearchReference;
+impl ReferenceSearchParameter for ScheduleActorSearchReference {
+    type Resource = resources::Schedule;
+    type Target = ScheduleActorSearchTarget;
+    fn parameter(&self) -> ScheduleSearchParameter {
+        ScheduleSearchParameter::Actor
+    }
+}
+/// Target resources of the actor reference search parameter of the Schedule resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ScheduleActorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Practitioner> for ScheduleActorSearchTarget {
+    fn from(resource: resources::Practitioner) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Device> for ScheduleActorSearchTarget {
+    fn from(resource: resources::Device) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ScheduleActorSearchTarget {
+    fn from(resource: resources::Patient) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::HealthcareService> for ScheduleActorSearchTarget {
+    fn from(resource: resources::HealthcareService) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ScheduleActorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ScheduleActorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::Location> for ScheduleActorSearchTarget {
+    fn from(resource: resources::Location) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::Location(resource)
+    }
+}
+/// The component reference search parameter of the SearchParameter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SearchParameterComponentSearchReference;
+impl ReferenceSearchParameter for SearchParameterComponentSearchReference {
+    type Resource = resources::SearchParameter;
+    type Target = resources::SearchParameter;
+    fn parameter(&self) -> SearchParameterSearchParameter {
+        SearchParameterSearchParameter::Component
+    }
+}
+/// The derived-from reference search parameter of the SearchParameter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SearchParameterDerivedFromSearchReference;
+impl ReferenceSearchParameter for SearchParameterDerivedFromSearchReference {
+    type Resource = resources::SearchParameter;
+    type Target = resources::SearchParameter;
+    fn parameter(&self) -> SearchParameterSearchParameter {
+        SearchParameterSearchParameter::DerivedFrom
+    }
+}
+/// The patient reference search parameter of the ServiceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRequestPatientSearchReference;
+impl ReferenceSearchParameter for ServiceRequestPatientSearchReference {
+    type Resource = resources::ServiceRequest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ServiceRequestSearchParameter {
+        ServiceRequestSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the ServiceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRequestEncounterSearchReference;
+impl ReferenceSearchParameter for ServiceRequestEncounterSearchReference {
+    type Resource = resources::ServiceRequest;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ServiceRequestSearchParameter {
+        ServiceRequestSearchParameter::Encounter
+    }
+}
+/// The based-on reference search parameter of the ServiceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRequestBasedOnSearchReference;
+impl ReferenceSearchParameter for ServiceRequestBasedOnSearchReference {
+    type Resource = resources::ServiceRequest;
+    type Target = ServiceRequestBasedOnSearchTarget;
+    fn parameter(&self) -> ServiceRequestSearchParameter {
+        ServiceRequestSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the ServiceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ServiceRequestBasedOnSearchTarget {
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for MedicationRequest target resources
+    MedicationRequest(resources::MedicationRequest),
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+}
+impl From<resources::CarePlan> for ServiceRequestBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> ServiceRequestBasedOnSearchTarget {
+        ServiceRequestBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::MedicationRequest> for ServiceRequestBasedOnSearchTarget {
+    fn from(resource: resources::MedicationRequest) -> ServiceRequestBasedOnSearchTarget {
+        ServiceRequestBasedOnSearchTarget::MedicationRequest(resource)
+    }
+}
+impl From<resources::ServiceRequest> for ServiceRequestBasedOnSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> ServiceRequestBasedOnSearchTarget {
+        ServiceRequestBasedOnSearchTarget::ServiceRequest(resource)
+    }
+}
+/// The instantiates-canonical reference search parameter of the ServiceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRequestInstantiatesCanonicalSearchReference;
+impl ReferenceSearchParameter for ServiceRequestInstantiatesCanonicalSearchReference {
+    type Resource = resources::ServiceRequest;
+    type Target = ServiceRequestInstantiatesCanonicalSearchTarget;
+    fn parameter(&self) -> ServiceRequestSearchParameter {
+        ServiceRequestSearchParameter::InstantiatesCanonical
+    }
+}
+/// Target resources of the instantiates-canonical reference search parameter of the ServiceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ServiceRequestInstantiatesCanonicalSearchTarget {
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+}
+impl From<resources::PlanDefinition> for ServiceRequestInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> ServiceRequestInstantiatesCanonicalSearchTarget {
+        ServiceRequestInstantiatesCanonicalSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for ServiceRequestInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> ServiceRequestInstantiatesCanonicalSearchTarget {
+        ServiceRequestInstantiatesCanonicalSearchTarget::ActivityDefinition(resource)
+    }
+}
+/// The performer reference search parameter of the ServiceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRequestPerformerSearchReference;
+impl ReferenceSearchParameter for ServiceRequestPerformerSearchReference {
+    type Resource = resources::ServiceRequest;
+    type Target = ServiceRequestPerformerSearchTarget;
+    fn parameter(&self) -> ServiceRequestSearchParameter {
+        ServiceRequestSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the ServiceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ServiceRequestPerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ServiceRequestPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ServiceRequestPerformerSearchTarget {
+        ServiceRequestPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ServiceRequestPerformerSearchTarget {
+    fn from(resource: resources::Organization) -> ServiceRequestPerformerSearchTarget {
+        ServiceRequestPerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for ServiceRequestPerformerSearchTarget {
+    fn from(resource: resources::CareTeam) -> ServiceRequestPerformerSearchTarget {
+        ServiceRequestPerformerSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for ServiceRequestPerformerSearchTarget {
+    fn from(resource: resources::Device) -> ServiceRequestPerformerSearchTarget {
+        ServiceRequestPerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ServiceRequestPerformerSearchTarget {
+    fn from(resource: resources::Patient) -> ServiceRequestPerformerSearchTarget {
+        ServiceRequestPerformerSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::HealthcareService> for ServiceRequestPerformerSearchTarget {
+    fn from(resource: resources::HealthcareService) -> ServiceRequestPerformerSearchTarget {
+        ServiceRequestPerformerSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ServiceRequestPerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ServiceRequestPerformerSearchTarget {
+        ServiceRequestPerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ServiceRequestPerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ServiceRequestPerformerSearchTarget {
+        ServiceRequestPerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The replaces reference search parameter of the ServiceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRequestReplacesSearchReference;
+impl ReferenceSearchParameter for ServiceRequestReplacesSearchReference {
+    type Resource = resources::ServiceRequest;
+    type Target = resources::ServiceRequest;
+    fn parameter(&self) -> ServiceRequestSearchParameter {
+        ServiceRequestSearchParameter::Replaces
+    }
+}
+/// The requester reference search parameter of the ServiceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRequestRequesterSearchReference;
+impl ReferenceSearchParameter for ServiceRequestRequesterSearchReference {
+    type Resource = resources::ServiceRequest;
+    type Target = ServiceRequestRequesterSearchTarget;
+    fn parameter(&self) -> ServiceRequestSearchParameter {
+        ServiceRequestSearchParameter::Requester
+    }
+}
+/// Target resources of the requester reference search parameter of the ServiceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ServiceRequestRequesterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ServiceRequestRequesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> ServiceRequestRequesterSearchTarget {
+        ServiceRequestRequesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ServiceRequestRequesterSearchTarget {
+    fn from(resource: resources::Organization) -> ServiceRequestRequesterSearchTarget {
+        ServiceRequestRequesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for ServiceRequestRequesterSearchTarget {
+    fn from(resource: resources::Device) -> ServiceRequestRequesterSearchTarget {
+        ServiceRequestRequesterSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ServiceRequestRequesterSearchTarget {
+    fn from(resource: resources::Patient) -> ServiceRequestRequesterSearchTarget {
+        ServiceRequestRequesterSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ServiceRequestRequesterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ServiceRequestRequesterSearchTarget {
+        ServiceRequestRequesterSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ServiceRequestRequesterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ServiceRequestRequesterSearchTarget {
+        ServiceRequestRequesterSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The specimen reference search parameter of the ServiceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRequestSpecimenSearchReference;
+impl ReferenceSearchParameter for ServiceRequestSpecimenSearchReference {
+    type Resource = resources::ServiceRequest;
+    type Target = resources::Specimen;
+    fn parameter(&self) -> ServiceRequestSearchParameter {
+        ServiceRequestSearchParameter::Specimen
+    }
+}
+/// The subject reference search parameter of the ServiceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRequestSubjectSearchReference;
+impl ReferenceSearchParameter for ServiceRequestSubjectSearchReference {
+    type Resource = resources::ServiceRequest;
+    type Target = ServiceRequestSubjectSearchTarget;
+    fn parameter(&self) -> ServiceRequestSearchParameter {
+        ServiceRequestSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the ServiceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ServiceRequestSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Group> for ServiceRequestSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ServiceRequestSubjectSearchTarget {
+        ServiceRequestSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Device> for ServiceRequestSubjectSearchTarget {
+    fn from(resource: resources::Device) -> ServiceRequestSubjectSearchTarget {
+        ServiceRequestSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ServiceRequestSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ServiceRequestSubjectSearchTarget {
+        ServiceRequestSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Location> for ServiceRequestSubjectSearchTarget {
+    fn from(resource: resources::Location) -> ServiceRequestSubjectSearchTarget {
+        ServiceRequestSubjectSearchTarget::Location(resource)
+    }
+}
+/// The schedule reference search parameter of the Slot resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SlotScheduleSearchReference;
+impl ReferenceSearchParameter for SlotScheduleSearchReference {
+    type Resource = resources::Slot;
+    type Target = resources::Schedule;
+    fn parameter(&self) -> SlotSearchParameter {
+        SlotSearchParameter::Schedule
+    }
+}
+/// The collector reference search parameter of the Specimen resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpecimenCollectorSearchReference;
+impl ReferenceSearchParameter for SpecimenCollectorSearchReference {
+    type Resource = resources::Specimen;
+    type Target = SpecimenCollectorSearchTarget;
+    fn parameter(&self) -> SpecimenSearchParameter {
+        SpecimenSearchParameter::Collector
+    }
+}
+/// Target resources of the collector reference search parameter of the Specimen resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SpecimenCollectorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for SpecimenCollectorSearchTarget {
+    fn from(resource: resources::Practitioner) -> SpecimenCollectorSearchTarget {
+        SpecimenCollectorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::PractitionerRole> for SpecimenCollectorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> SpecimenCollectorSearchTarget {
+        SpecimenCollectorSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The parent reference search parameter of the Specimen resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpecimenParentSearchReference;
+impl ReferenceSearchParameter for SpecimenParentSearchReference {
+    type Resource = resources::Specimen;
+    type Target = resources::Specimen;
+    fn parameter(&self) -> SpecimenSearchParameter {
+        SpecimenSearchParameter::Parent
+    }
+}
+/// The patient reference search parameter of the Specimen resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpecimenPatientSearchReference;
+impl ReferenceSearchParameter for SpecimenPatientSearchReference {
+    type Resource = resources::Specimen;
+    type Target = resources::Patient;
+    fn parameter(&self) -> SpecimenSearchParameter {
+        SpecimenSearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the Specimen resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpecimenSubjectSearchReference;
+impl ReferenceSearchParameter for SpecimenSubjectSearchReference {
+    type Resource = resources::Specimen;
+    type Target = SpecimenSubjectSearchTarget;
+    fn parameter(&self) -> SpecimenSearchParameter {
+        SpecimenSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Specimen resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SpecimenSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Group> for SpecimenSubjectSearchTarget {
+    fn from(resource: resources::Group) -> SpecimenSubjectSearchTarget {
+        SpecimenSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Device> for SpecimenSubjectSearchTarget {
+    fn from(resource: resources::Device) -> SpecimenSubjectSearchTarget {
+        SpecimenSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for SpecimenSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> SpecimenSubjectSearchTarget {
+        SpecimenSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Substance> for SpecimenSubjectSearchTarget {
+    fn from(resource: resources::Substance) -> SpecimenSubjectSearchTarget {
+        SpecimenSubjectSearchTarget::Substance(resource)
+    }
+}
+impl From<resources::Location> for SpecimenSubjectSearchTarget {
+    fn from(resource: resources::Location) -> SpecimenSubjectSearchTarget {
+        SpecimenSubjectSearchTarget::Location(resource)
+    }
+}
+/// The base reference search parameter of the StructureDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StructureDefinitionBaseSearchReference;
+impl ReferenceSearchParameter for StructureDefinitionBaseSearchReference {
+    type Resource = resources::StructureDefinition;
+    type Target = resources::StructureDefinition;
+    fn parameter(&self) -> StructureDefinitionSearchParameter {
+        StructureDefinitionSearchParameter::Base
+    }
+}
+/// The valueset reference search parameter of the StructureDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StructureDefinitionValuesetSearchReference;
+impl ReferenceSearchParameter for StructureDefinitionValuesetSearchReference {
+    type Resource = resources::StructureDefinition;
+    type Target = resources::ValueSet;
+    fn parameter(&self) -> StructureDefinitionSearchParameter {
+        StructureDefinitionSearchParameter::Valueset
+    }
+}
+/// The substance-reference reference search parameter of the Substance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SubstanceSubstanceReferenceSearchReference;
+impl ReferenceSearchParameter for SubstanceSubstanceReferenceSearchReference {
+    type Resource = resources::Substance;
+    type Target = resources::Substance;
+    fn parameter(&self) -> SubstanceSearchParameter {
+        SubstanceSearchParameter::SubstanceReference
+    }
+}
+/// The patient reference search parameter of the SupplyDelivery resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SupplyDeliveryPatientSearchReference;
+impl ReferenceSearchParameter for SupplyDeliveryPatientSearchReference {
+    type Resource = resources::SupplyDelivery;
+    type Target = resources::Patient;
+    fn parameter(&self) -> SupplyDeliverySearchParameter {
+        SupplyDeliverySearchParameter::Patient
+    }
+}
+/// The receiver reference search parameter of the SupplyDelivery resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SupplyDeliveryReceiverSearchReference;
+impl ReferenceSearchParameter for SupplyDeliveryReceiverSearchReference {
+    type Resource = resources::SupplyDelivery;
+    type Target = SupplyDeliveryReceiverSearchTarget;
+    fn parameter(&self) -> SupplyDeliverySearchParameter {
+        SupplyDeliverySearchParameter::Receiver
+    }
+}
+/// Target resources of the receiver reference search parameter of the SupplyDelivery resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SupplyDeliveryReceiverSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for SupplyDeliveryReceiverSearchTarget {
+    fn from(resource: resources::Practitioner) -> SupplyDeliveryReceiverSearchTarget {
+        SupplyDeliveryReceiverSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::PractitionerRole> for SupplyDeliveryReceiverSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> SupplyDeliveryReceiverSearchTarget {
+        SupplyDeliveryReceiverSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The supplier reference search parameter of the SupplyDelivery resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SupplyDeliverySupplierSearchReference;
+impl ReferenceSearchParameter for SupplyDeliverySupplierSearchReference {
+    type Resource = resources::SupplyDelivery;
+    type Target = SupplyDeliverySupplierSearchTarget;
+    fn parameter(&self) -> SupplyDeliverySearchParameter {
+        SupplyDeliverySearchParameter::Supplier
+    }
+}
+/// Target resources of the supplier reference search parameter of the SupplyDelivery resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SupplyDeliverySupplierSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for SupplyDeliverySupplierSearchTarget {
+    fn from(resource: resources::Practitioner) -> SupplyDeliverySupplierSearchTarget {
+        SupplyDeliverySupplierSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for SupplyDeliverySupplierSearchTarget {
+    fn from(resource: resources::Organization) -> SupplyDeliverySupplierSearchTarget {
+        SupplyDeliverySupplierSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for SupplyDeliverySupplierSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> SupplyDeliverySupplierSearchTarget {
+        SupplyDeliverySupplierSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The requester reference search parameter of the SupplyRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SupplyRequestRequesterSearchReference;
+impl ReferenceSearchParameter for SupplyRequestRequesterSearchReference {
+    type Resource = resources::SupplyRequest;
+    type Target = SupplyRequestRequesterSearchTarget;
+    fn parameter(&self) -> SupplyRequestSearchParameter {
+        SupplyRequestSearchParameter::Requester
+    }
+}
+/// Target resources of the requester reference search parameter of the SupplyRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SupplyRequestRequesterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for SupplyRequestRequesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> SupplyRequestRequesterSearchTarget {
+        SupplyRequestRequesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for SupplyRequestRequesterSearchTarget {
+    fn from(resource: resources::Organization) -> SupplyRequestRequesterSearchTarget {
+        SupplyRequestRequesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for SupplyRequestRequesterSearchTarget {
+    fn from(resource: resources::Device) -> SupplyRequestRequesterSearchTarget {
+        SupplyRequestRequesterSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for SupplyRequestRequesterSearchTarget {
+    fn from(resource: resources::Patient) -> SupplyRequestRequesterSearchTarget {
+        SupplyRequestRequesterSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for SupplyRequestRequesterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> SupplyRequestRequesterSearchTarget {
+        SupplyRequestRequesterSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for SupplyRequestRequesterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> SupplyRequestRequesterSearchTarget {
+        SupplyRequestRequesterSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subject reference search parameter of the SupplyRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SupplyRequestSubjectSearchReference;
+impl ReferenceSearchParameter for SupplyRequestSubjectSearchReference {
+    type Resource = resources::SupplyRequest;
+    type Target = SupplyRequestSubjectSearchTarget;
+    fn parameter(&self) -> SupplyRequestSearchParameter {
+        SupplyRequestSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the SupplyRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SupplyRequestSubjectSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Organization> for SupplyRequestSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> SupplyRequestSubjectSearchTarget {
+        SupplyRequestSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for SupplyRequestSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> SupplyRequestSubjectSearchTarget {
+        SupplyRequestSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Location> for SupplyRequestSubjectSearchTarget {
+    fn from(resource: resources::Location) -> SupplyRequestSubjectSearchTarget {
+        SupplyRequestSubjectSearchTarget::Location(resource)
+    }
+}
+/// The supplier reference search parameter of the SupplyRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SupplyRequestSupplierSearchReference;
+impl ReferenceSearchParameter for SupplyRequestSupplierSearchReference {
+    type Resource = resources::SupplyRequest;
+    type Target = SupplyRequestSupplierSearchTarget;
+    fn parameter(&self) -> SupplyRequestSearchParameter {
+        SupplyRequestSearchParameter::Supplier
+    }
+}
+/// Target resources of the supplier reference search parameter of the SupplyRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SupplyRequestSupplierSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+}
+impl From<resources::Organization> for SupplyRequestSupplierSearchTarget {
+    fn from(resource: resources::Organization) -> SupplyRequestSupplierSearchTarget {
+        SupplyRequestSupplierSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::HealthcareService> for SupplyRequestSupplierSearchTarget {
+    fn from(resource: resources::HealthcareService) -> SupplyRequestSupplierSearchTarget {
+        SupplyRequestSupplierSearchTarget::HealthcareService(resource)
+    }
+}
+/// The based-on reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskBasedOnSearchReference;
+impl ReferenceSearchParameter for TaskBasedOnSearchReference {
+    type Resource = resources::Task;
+    type Target = resources::Resource;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::BasedOn
+    }
+}
+/// The encounter reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskEncounterSearchReference;
+impl ReferenceSearchParameter for TaskEncounterSearchReference {
+    type Resource = resources::Task;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Encounter
+    }
+}
+/// The focus reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskFocusSearchReference;
+impl ReferenceSearchParameter for TaskFocusSearchReference {
+    type Resource = resources::Task;
+    type Target = resources::Resource;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Focus
+    }
+}
+/// The owner reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskOwnerSearchReference;
+impl ReferenceSearchParameter for TaskOwnerSearchReference {
+    type Resource = resources::Task;
+    type Target = TaskOwnerSearchTarget;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Owner
+    }
+}
+/// Target resources of the owner reference search parameter of the Task resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TaskOwnerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for TaskOwnerSearchTarget {
+    fn from(resource: resources::Practitioner) -> TaskOwnerSearchTarget {
+        TaskOwnerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for TaskOwnerSearchTarget {
+    fn from(resource: resources::Organization) -> TaskOwnerSearchTarget {
+        TaskOwnerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for TaskOwnerSearchTarget {
+    fn from(resource: resources::CareTeam) -> TaskOwnerSearchTarget {
+        TaskOwnerSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for TaskOwnerSearchTarget {
+    fn from(resource: resources::Device) -> TaskOwnerSearchTarget {
+        TaskOwnerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for TaskOwnerSearchTarget {
+    fn from(resource: resources::Patient) -> TaskOwnerSearchTarget {
+        TaskOwnerSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::HealthcareService> for TaskOwnerSearchTarget {
+    fn from(resource: resources::HealthcareService) -> TaskOwnerSearchTarget {
+        TaskOwnerSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::PractitionerRole> for TaskOwnerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> TaskOwnerSearchTarget {
+        TaskOwnerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for TaskOwnerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> TaskOwnerSearchTarget {
+        TaskOwnerSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The part-of reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskPartOfSearchReference;
+impl ReferenceSearchParameter for TaskPartOfSearchReference {
+    type Resource = resources::Task;
+    type Target = resources::Task;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::PartOf
+    }
+}
+/// The patient reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskPatientSearchReference;
+impl ReferenceSearchParameter for TaskPatientSearchReference {
+    type Resource = resources::Task;
+    type Target = resources::Patient;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Patient
+    }
+}
+/// The requester reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskRequesterSearchReference;
+impl ReferenceSearchParameter for TaskRequesterSearchReference {
+    type Resource = resources::Task;
+    type Target = TaskRequesterSearchTarget;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Requester
+    }
+}
+/// Target resources of the requester reference search parameter of the Task resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TaskRequesterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for TaskRequesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> TaskRequesterSearchTarget {
+        TaskRequesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for TaskRequesterSearchTarget {
+    fn from(resource: resources::Organization) -> TaskRequesterSearchTarget {
+        TaskRequesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for TaskRequesterSearchTarget {
+    fn from(resource: resources::Device) -> TaskRequesterSearchTarget {
+        TaskRequesterSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for TaskRequesterSearchTarget {
+    fn from(resource: resources::Patient) -> TaskRequesterSearchTarget {
+        TaskRequesterSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PractitionerRole> for TaskRequesterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> TaskRequesterSearchTarget {
+        TaskRequesterSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for TaskRequesterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> TaskRequesterSearchTarget {
+        TaskRequesterSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subject reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskSubjectSearchReference;
+impl ReferenceSearchParameter for TaskSubjectSearchReference {
+    type Resource = resources::Task;
+    type Target = resources::Resource;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Subject
+    }
+}
+/// The testscript reference search parameter of the TestReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TestReportTestscriptSearchReference;
+impl ReferenceSearchParameter for TestReportTestscriptSearchReference {
+    type Resource = resources::TestReport;
+    type Target = resources::TestScript;
+    fn parameter(&self) -> TestReportSearchParameter {
+        TestReportSearchParameter::Testscript
+    }
+}
+/// The target reference search parameter of the VerificationResult resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VerificationResultTargetSearchReference;
+impl ReferenceSearchParameter for VerificationResultTargetSearchReference {
+    type Resource = resources::VerificationResult;
+    type Target = resources::Resource;
+    fn parameter(&self) -> VerificationResultSearchParameter {
+        VerificationResultSearchParameter::Target
+    }
+}
+/// The patient reference search parameter of the VisionPrescription resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VisionPrescriptionPatientSearchReference;
+impl ReferenceSearchParameter for VisionPrescriptionPatientSearchReference {
+    type Resource = resources::VisionPrescription;
+    type Target = resources::Patient;
+    fn parameter(&self) -> VisionPrescriptionSearchParameter {
+        VisionPrescriptionSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the VisionPrescription resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VisionPrescriptionEncounterSearchReference;
+impl ReferenceSearchParameter for VisionPrescriptionEncounterSearchReference {
+    type Resource = resources::VisionPrescription;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> VisionPrescriptionSearchParameter {
+        VisionPrescriptionSearchParameter::Encounter
+    }
+}
+/// The prescriber reference search parameter of the VisionPrescription resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VisionPrescriptionPrescriberSearchReference;
+impl ReferenceSearchParameter for VisionPrescriptionPrescriberSearchReference {
+    type Resource = resources::VisionPrescription;
+    type Target = VisionPrescriptionPrescriberSearchTarget;
+    fn parameter(&self) -> VisionPrescriptionSearchParameter {
+        VisionPrescriptionSearchParameter::Prescriber
+    }
+}
+/// Target resources of the prescriber reference search parameter of the VisionPrescription resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VisionPrescriptionPrescriberSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Practitioner> for VisionPrescriptionPrescriberSearchTarget {
+    fn from(resource: resources::Practitioner) -> VisionPrescriptionPrescriberSearchTarget {
+        VisionPrescriptionPrescriberSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::PractitionerRole> for VisionPrescriptionPrescriberSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> VisionPrescriptionPrescriberSearchTarget {
+        VisionPrescriptionPrescriberSearchTarget::PractitionerRole(resource)
+    }
+}
 impl SearchableResource for resources::Account {
     /// Parameters that can be used when searching Account resources
     type Params = AccountSearchParameter;
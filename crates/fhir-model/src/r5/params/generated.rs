@@ -7838,6 +7838,14464 @@ impl ResourceSearchParameterDefinition for VisionPrescriptionSearchParameter {
         }
     }
 }
+/// The guarantor reference search parameter of the Account resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AccountGuarantorSearchReference;
+impl ReferenceSearchParameter for AccountGuarantorSearchReference {
+    type Resource = resources::Account;
+    type Target = AccountGuarantorSearchTarget;
+    fn parameter(&self) -> AccountSearchParameter {
+        AccountSearchParameter::Guarantor
+    }
+}
+/// Target resources of the guarantor reference search parameter of the Account resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AccountGuarantorSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for AccountGuarantorSearchTarget {
+    fn from(resource: resources::Organization) -> AccountGuarantorSearchTarget {
+        AccountGuarantorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for AccountGuarantorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AccountGuarantorSearchTarget {
+        AccountGuarantorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::Patient> for AccountGuarantorSearchTarget {
+    fn from(resource: resources::Patient) -> AccountGuarantorSearchTarget {
+        AccountGuarantorSearchTarget::Patient(resource)
+    }
+}
+/// The owner reference search parameter of the Account resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AccountOwnerSearchReference;
+impl ReferenceSearchParameter for AccountOwnerSearchReference {
+    type Resource = resources::Account;
+    type Target = resources::Organization;
+    fn parameter(&self) -> AccountSearchParameter {
+        AccountSearchParameter::Owner
+    }
+}
+/// The patient reference search parameter of the Account resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AccountPatientSearchReference;
+impl ReferenceSearchParameter for AccountPatientSearchReference {
+    type Resource = resources::Account;
+    type Target = resources::Patient;
+    fn parameter(&self) -> AccountSearchParameter {
+        AccountSearchParameter::Patient
+    }
+}
+/// The relatedaccount reference search parameter of the Account resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AccountRelatedaccountSearchReference;
+impl ReferenceSearchParameter for AccountRelatedaccountSearchReference {
+    type Resource = resources::Account;
+    type Target = resources::Account;
+    fn parameter(&self) -> AccountSearchParameter {
+        AccountSearchParameter::Relatedaccount
+    }
+}
+/// The subject reference search parameter of the Account resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AccountSubjectSearchReference;
+impl ReferenceSearchParameter for AccountSubjectSearchReference {
+    type Resource = resources::Account;
+    type Target = AccountSubjectSearchTarget;
+    fn parameter(&self) -> AccountSearchParameter {
+        AccountSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Account resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AccountSubjectSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for AccountSubjectSearchTarget {
+    fn from(resource: resources::HealthcareService) -> AccountSubjectSearchTarget {
+        AccountSubjectSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::Device> for AccountSubjectSearchTarget {
+    fn from(resource: resources::Device) -> AccountSubjectSearchTarget {
+        AccountSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for AccountSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> AccountSubjectSearchTarget {
+        AccountSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for AccountSubjectSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> AccountSubjectSearchTarget {
+        AccountSubjectSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for AccountSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> AccountSubjectSearchTarget {
+        AccountSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Location> for AccountSubjectSearchTarget {
+    fn from(resource: resources::Location) -> AccountSubjectSearchTarget {
+        AccountSubjectSearchTarget::Location(resource)
+    }
+}
+impl From<resources::Patient> for AccountSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> AccountSubjectSearchTarget {
+        AccountSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The composed-of reference search parameter of the ActivityDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ActivityDefinitionComposedOfSearchReference;
+impl ReferenceSearchParameter for ActivityDefinitionComposedOfSearchReference {
+    type Resource = resources::ActivityDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ActivityDefinitionSearchParameter {
+        ActivityDefinitionSearchParameter::ComposedOf
+    }
+}
+/// The depends-on reference search parameter of the ActivityDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ActivityDefinitionDependsOnSearchReference;
+impl ReferenceSearchParameter for ActivityDefinitionDependsOnSearchReference {
+    type Resource = resources::ActivityDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ActivityDefinitionSearchParameter {
+        ActivityDefinitionSearchParameter::DependsOn
+    }
+}
+/// The derived-from reference search parameter of the ActivityDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ActivityDefinitionDerivedFromSearchReference;
+impl ReferenceSearchParameter for ActivityDefinitionDerivedFromSearchReference {
+    type Resource = resources::ActivityDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ActivityDefinitionSearchParameter {
+        ActivityDefinitionSearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the ActivityDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ActivityDefinitionPredecessorSearchReference;
+impl ReferenceSearchParameter for ActivityDefinitionPredecessorSearchReference {
+    type Resource = resources::ActivityDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ActivityDefinitionSearchParameter {
+        ActivityDefinitionSearchParameter::Predecessor
+    }
+}
+/// The successor reference search parameter of the ActivityDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ActivityDefinitionSuccessorSearchReference;
+impl ReferenceSearchParameter for ActivityDefinitionSuccessorSearchReference {
+    type Resource = resources::ActivityDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ActivityDefinitionSearchParameter {
+        ActivityDefinitionSearchParameter::Successor
+    }
+}
+/// The patient reference search parameter of the AdverseEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdverseEventPatientSearchReference;
+impl ReferenceSearchParameter for AdverseEventPatientSearchReference {
+    type Resource = resources::AdverseEvent;
+    type Target = resources::Patient;
+    fn parameter(&self) -> AdverseEventSearchParameter {
+        AdverseEventSearchParameter::Patient
+    }
+}
+/// The location reference search parameter of the AdverseEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdverseEventLocationSearchReference;
+impl ReferenceSearchParameter for AdverseEventLocationSearchReference {
+    type Resource = resources::AdverseEvent;
+    type Target = resources::Location;
+    fn parameter(&self) -> AdverseEventSearchParameter {
+        AdverseEventSearchParameter::Location
+    }
+}
+/// The recorder reference search parameter of the AdverseEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdverseEventRecorderSearchReference;
+impl ReferenceSearchParameter for AdverseEventRecorderSearchReference {
+    type Resource = resources::AdverseEvent;
+    type Target = AdverseEventRecorderSearchTarget;
+    fn parameter(&self) -> AdverseEventSearchParameter {
+        AdverseEventSearchParameter::Recorder
+    }
+}
+/// Target resources of the recorder reference search parameter of the AdverseEvent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AdverseEventRecorderSearchTarget {
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for ResearchSubject target resources
+    ResearchSubject(resources::ResearchSubject),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::RelatedPerson> for AdverseEventRecorderSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AdverseEventRecorderSearchTarget {
+        AdverseEventRecorderSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for AdverseEventRecorderSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> AdverseEventRecorderSearchTarget {
+        AdverseEventRecorderSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::ResearchSubject> for AdverseEventRecorderSearchTarget {
+    fn from(resource: resources::ResearchSubject) -> AdverseEventRecorderSearchTarget {
+        AdverseEventRecorderSearchTarget::ResearchSubject(resource)
+    }
+}
+impl From<resources::Practitioner> for AdverseEventRecorderSearchTarget {
+    fn from(resource: resources::Practitioner) -> AdverseEventRecorderSearchTarget {
+        AdverseEventRecorderSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for AdverseEventRecorderSearchTarget {
+    fn from(resource: resources::Patient) -> AdverseEventRecorderSearchTarget {
+        AdverseEventRecorderSearchTarget::Patient(resource)
+    }
+}
+/// The resultingeffect reference search parameter of the AdverseEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdverseEventResultingeffectSearchReference;
+impl ReferenceSearchParameter for AdverseEventResultingeffectSearchReference {
+    type Resource = resources::AdverseEvent;
+    type Target = AdverseEventResultingeffectSearchTarget;
+    fn parameter(&self) -> AdverseEventSearchParameter {
+        AdverseEventSearchParameter::Resultingeffect
+    }
+}
+/// Target resources of the resultingeffect reference search parameter of the AdverseEvent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AdverseEventResultingeffectSearchTarget {
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for Condition target resources
+    Condition(resources::Condition),
+}
+impl From<resources::Observation> for AdverseEventResultingeffectSearchTarget {
+    fn from(resource: resources::Observation) -> AdverseEventResultingeffectSearchTarget {
+        AdverseEventResultingeffectSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::Condition> for AdverseEventResultingeffectSearchTarget {
+    fn from(resource: resources::Condition) -> AdverseEventResultingeffectSearchTarget {
+        AdverseEventResultingeffectSearchTarget::Condition(resource)
+    }
+}
+/// The study reference search parameter of the AdverseEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdverseEventStudySearchReference;
+impl ReferenceSearchParameter for AdverseEventStudySearchReference {
+    type Resource = resources::AdverseEvent;
+    type Target = resources::ResearchStudy;
+    fn parameter(&self) -> AdverseEventSearchParameter {
+        AdverseEventSearchParameter::Study
+    }
+}
+/// The subject reference search parameter of the AdverseEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdverseEventSubjectSearchReference;
+impl ReferenceSearchParameter for AdverseEventSubjectSearchReference {
+    type Resource = resources::AdverseEvent;
+    type Target = AdverseEventSubjectSearchTarget;
+    fn parameter(&self) -> AdverseEventSearchParameter {
+        AdverseEventSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the AdverseEvent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AdverseEventSubjectSearchTarget {
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for ResearchSubject target resources
+    ResearchSubject(resources::ResearchSubject),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::RelatedPerson> for AdverseEventSubjectSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AdverseEventSubjectSearchTarget {
+        AdverseEventSubjectSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::ResearchSubject> for AdverseEventSubjectSearchTarget {
+    fn from(resource: resources::ResearchSubject) -> AdverseEventSubjectSearchTarget {
+        AdverseEventSubjectSearchTarget::ResearchSubject(resource)
+    }
+}
+impl From<resources::Group> for AdverseEventSubjectSearchTarget {
+    fn from(resource: resources::Group) -> AdverseEventSubjectSearchTarget {
+        AdverseEventSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Practitioner> for AdverseEventSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> AdverseEventSubjectSearchTarget {
+        AdverseEventSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for AdverseEventSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> AdverseEventSubjectSearchTarget {
+        AdverseEventSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The substance reference search parameter of the AdverseEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdverseEventSubstanceSearchReference;
+impl ReferenceSearchParameter for AdverseEventSubstanceSearchReference {
+    type Resource = resources::AdverseEvent;
+    type Target = AdverseEventSubstanceSearchTarget;
+    fn parameter(&self) -> AdverseEventSearchParameter {
+        AdverseEventSearchParameter::Substance
+    }
+}
+/// Target resources of the substance reference search parameter of the AdverseEvent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AdverseEventSubstanceSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for MedicationStatement target resources
+    MedicationStatement(resources::MedicationStatement),
+    /// Variant for ResearchStudy target resources
+    ResearchStudy(resources::ResearchStudy),
+    /// Variant for MedicationAdministration target resources
+    MedicationAdministration(resources::MedicationAdministration),
+    /// Variant for BiologicallyDerivedProduct target resources
+    BiologicallyDerivedProduct(resources::BiologicallyDerivedProduct),
+    /// Variant for Immunization target resources
+    Immunization(resources::Immunization),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+}
+impl From<resources::Device> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::Device) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Procedure> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::Procedure) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::MedicationStatement> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::MedicationStatement) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::MedicationStatement(resource)
+    }
+}
+impl From<resources::ResearchStudy> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::ResearchStudy) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::ResearchStudy(resource)
+    }
+}
+impl From<resources::MedicationAdministration> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::MedicationAdministration) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::MedicationAdministration(resource)
+    }
+}
+impl From<resources::BiologicallyDerivedProduct> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::BiologicallyDerivedProduct) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::BiologicallyDerivedProduct(resource)
+    }
+}
+impl From<resources::Immunization> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::Immunization) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::Immunization(resource)
+    }
+}
+impl From<resources::Substance> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::Substance) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::Substance(resource)
+    }
+}
+impl From<resources::Medication> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::Medication) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::Medication(resource)
+    }
+}
+/// The patient reference search parameter of the AllergyIntolerance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AllergyIntolerancePatientSearchReference;
+impl ReferenceSearchParameter for AllergyIntolerancePatientSearchReference {
+    type Resource = resources::AllergyIntolerance;
+    type Target = resources::Patient;
+    fn parameter(&self) -> AllergyIntoleranceSearchParameter {
+        AllergyIntoleranceSearchParameter::Patient
+    }
+}
+/// The manifestation-reference reference search parameter of the AllergyIntolerance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AllergyIntoleranceManifestationReferenceSearchReference;
+impl ReferenceSearchParameter for AllergyIntoleranceManifestationReferenceSearchReference {
+    type Resource = resources::AllergyIntolerance;
+    type Target = resources::Observation;
+    fn parameter(&self) -> AllergyIntoleranceSearchParameter {
+        AllergyIntoleranceSearchParameter::ManifestationReference
+    }
+}
+/// The participant reference search parameter of the AllergyIntolerance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AllergyIntoleranceParticipantSearchReference;
+impl ReferenceSearchParameter for AllergyIntoleranceParticipantSearchReference {
+    type Resource = resources::AllergyIntolerance;
+    type Target = AllergyIntoleranceParticipantSearchTarget;
+    fn parameter(&self) -> AllergyIntoleranceSearchParameter {
+        AllergyIntoleranceSearchParameter::Participant
+    }
+}
+/// Target resources of the participant reference search parameter of the AllergyIntolerance resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AllergyIntoleranceParticipantSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Device> for AllergyIntoleranceParticipantSearchTarget {
+    fn from(resource: resources::Device) -> AllergyIntoleranceParticipantSearchTarget {
+        AllergyIntoleranceParticipantSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for AllergyIntoleranceParticipantSearchTarget {
+    fn from(resource: resources::Organization) -> AllergyIntoleranceParticipantSearchTarget {
+        AllergyIntoleranceParticipantSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for AllergyIntoleranceParticipantSearchTarget {
+    fn from(resource: resources::CareTeam) -> AllergyIntoleranceParticipantSearchTarget {
+        AllergyIntoleranceParticipantSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::RelatedPerson> for AllergyIntoleranceParticipantSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AllergyIntoleranceParticipantSearchTarget {
+        AllergyIntoleranceParticipantSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for AllergyIntoleranceParticipantSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> AllergyIntoleranceParticipantSearchTarget {
+        AllergyIntoleranceParticipantSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for AllergyIntoleranceParticipantSearchTarget {
+    fn from(resource: resources::Practitioner) -> AllergyIntoleranceParticipantSearchTarget {
+        AllergyIntoleranceParticipantSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for AllergyIntoleranceParticipantSearchTarget {
+    fn from(resource: resources::Patient) -> AllergyIntoleranceParticipantSearchTarget {
+        AllergyIntoleranceParticipantSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentPatientSearchReference;
+impl ReferenceSearchParameter for AppointmentPatientSearchReference {
+    type Resource = resources::Appointment;
+    type Target = resources::Patient;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::Patient
+    }
+}
+/// The actor reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentActorSearchReference;
+impl ReferenceSearchParameter for AppointmentActorSearchReference {
+    type Resource = resources::Appointment;
+    type Target = AppointmentActorSearchTarget;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::Actor
+    }
+}
+/// Target resources of the actor reference search parameter of the Appointment resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AppointmentActorSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for AppointmentActorSearchTarget {
+    fn from(resource: resources::HealthcareService) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::CareTeam> for AppointmentActorSearchTarget {
+    fn from(resource: resources::CareTeam) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for AppointmentActorSearchTarget {
+    fn from(resource: resources::Device) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::RelatedPerson> for AppointmentActorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for AppointmentActorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Group> for AppointmentActorSearchTarget {
+    fn from(resource: resources::Group) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Practitioner> for AppointmentActorSearchTarget {
+    fn from(resource: resources::Practitioner) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Location> for AppointmentActorSearchTarget {
+    fn from(resource: resources::Location) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::Location(resource)
+    }
+}
+impl From<resources::Patient> for AppointmentActorSearchTarget {
+    fn from(resource: resources::Patient) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::Patient(resource)
+    }
+}
+/// The based-on reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentBasedOnSearchReference;
+impl ReferenceSearchParameter for AppointmentBasedOnSearchReference {
+    type Resource = resources::Appointment;
+    type Target = AppointmentBasedOnSearchTarget;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the Appointment resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AppointmentBasedOnSearchTarget {
+    /// Variant for DeviceRequest target resources
+    DeviceRequest(resources::DeviceRequest),
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for MedicationRequest target resources
+    MedicationRequest(resources::MedicationRequest),
+}
+impl From<resources::DeviceRequest> for AppointmentBasedOnSearchTarget {
+    fn from(resource: resources::DeviceRequest) -> AppointmentBasedOnSearchTarget {
+        AppointmentBasedOnSearchTarget::DeviceRequest(resource)
+    }
+}
+impl From<resources::ServiceRequest> for AppointmentBasedOnSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> AppointmentBasedOnSearchTarget {
+        AppointmentBasedOnSearchTarget::ServiceRequest(resource)
+    }
+}
+impl From<resources::CarePlan> for AppointmentBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> AppointmentBasedOnSearchTarget {
+        AppointmentBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::MedicationRequest> for AppointmentBasedOnSearchTarget {
+    fn from(resource: resources::MedicationRequest) -> AppointmentBasedOnSearchTarget {
+        AppointmentBasedOnSearchTarget::MedicationRequest(resource)
+    }
+}
+/// The group reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentGroupSearchReference;
+impl ReferenceSearchParameter for AppointmentGroupSearchReference {
+    type Resource = resources::Appointment;
+    type Target = resources::Group;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::Group
+    }
+}
+/// The location reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentLocationSearchReference;
+impl ReferenceSearchParameter for AppointmentLocationSearchReference {
+    type Resource = resources::Appointment;
+    type Target = resources::Location;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::Location
+    }
+}
+/// The practitioner reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentPractitionerSearchReference;
+impl ReferenceSearchParameter for AppointmentPractitionerSearchReference {
+    type Resource = resources::Appointment;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::Practitioner
+    }
+}
+/// The reason-reference reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentReasonReferenceSearchReference;
+impl ReferenceSearchParameter for AppointmentReasonReferenceSearchReference {
+    type Resource = resources::Appointment;
+    type Target = AppointmentReasonReferenceSearchTarget;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::ReasonReference
+    }
+}
+/// Target resources of the reason-reference reference search parameter of the Appointment resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AppointmentReasonReferenceSearchTarget {
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for ImmunizationRecommendation target resources
+    ImmunizationRecommendation(resources::ImmunizationRecommendation),
+    /// Variant for Condition target resources
+    Condition(resources::Condition),
+}
+impl From<resources::Procedure> for AppointmentReasonReferenceSearchTarget {
+    fn from(resource: resources::Procedure) -> AppointmentReasonReferenceSearchTarget {
+        AppointmentReasonReferenceSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::Observation> for AppointmentReasonReferenceSearchTarget {
+    fn from(resource: resources::Observation) -> AppointmentReasonReferenceSearchTarget {
+        AppointmentReasonReferenceSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::ImmunizationRecommendation> for AppointmentReasonReferenceSearchTarget {
+    fn from(resource: resources::ImmunizationRecommendation) -> AppointmentReasonReferenceSearchTarget {
+        AppointmentReasonReferenceSearchTarget::ImmunizationRecommendation(resource)
+    }
+}
+impl From<resources::Condition> for AppointmentReasonReferenceSearchTarget {
+    fn from(resource: resources::Condition) -> AppointmentReasonReferenceSearchTarget {
+        AppointmentReasonReferenceSearchTarget::Condition(resource)
+    }
+}
+/// The service-type-reference reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentServiceTypeReferenceSearchReference;
+impl ReferenceSearchParameter for AppointmentServiceTypeReferenceSearchReference {
+    type Resource = resources::Appointment;
+    type Target = resources::HealthcareService;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::ServiceTypeReference
+    }
+}
+/// The slot reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentSlotSearchReference;
+impl ReferenceSearchParameter for AppointmentSlotSearchReference {
+    type Resource = resources::Appointment;
+    type Target = resources::Slot;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::Slot
+    }
+}
+/// The subject reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentSubjectSearchReference;
+impl ReferenceSearchParameter for AppointmentSubjectSearchReference {
+    type Resource = resources::Appointment;
+    type Target = AppointmentSubjectSearchTarget;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Appointment resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AppointmentSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for AppointmentSubjectSearchTarget {
+    fn from(resource: resources::Group) -> AppointmentSubjectSearchTarget {
+        AppointmentSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for AppointmentSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> AppointmentSubjectSearchTarget {
+        AppointmentSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The supporting-info reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentSupportingInfoSearchReference;
+impl ReferenceSearchParameter for AppointmentSupportingInfoSearchReference {
+    type Resource = resources::Appointment;
+    type Target = resources::Resource;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::SupportingInfo
+    }
+}
+/// The patient reference search parameter of the AppointmentResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentResponsePatientSearchReference;
+impl ReferenceSearchParameter for AppointmentResponsePatientSearchReference {
+    type Resource = resources::AppointmentResponse;
+    type Target = resources::Patient;
+    fn parameter(&self) -> AppointmentResponseSearchParameter {
+        AppointmentResponseSearchParameter::Patient
+    }
+}
+/// The actor reference search parameter of the AppointmentResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentResponseActorSearchReference;
+impl ReferenceSearchParameter for AppointmentResponseActorSearchReference {
+    type Resource = resources::AppointmentResponse;
+    type Target = AppointmentResponseActorSearchTarget;
+    fn parameter(&self) -> AppointmentResponseSearchParameter {
+        AppointmentResponseSearchParameter::Actor
+    }
+}
+/// Target resources of the actor reference search parameter of the AppointmentResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AppointmentResponseActorSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::HealthcareService) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::Device> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::Device) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::RelatedPerson> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Group> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::Group) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Practitioner> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::Practitioner) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Location> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::Location) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::Location(resource)
+    }
+}
+impl From<resources::Patient> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::Patient) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::Patient(resource)
+    }
+}
+/// The appointment reference search parameter of the AppointmentResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentResponseAppointmentSearchReference;
+impl ReferenceSearchParameter for AppointmentResponseAppointmentSearchReference {
+    type Resource = resources::AppointmentResponse;
+    type Target = resources::Appointment;
+    fn parameter(&self) -> AppointmentResponseSearchParameter {
+        AppointmentResponseSearchParameter::Appointment
+    }
+}
+/// The group reference search parameter of the AppointmentResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentResponseGroupSearchReference;
+impl ReferenceSearchParameter for AppointmentResponseGroupSearchReference {
+    type Resource = resources::AppointmentResponse;
+    type Target = resources::Group;
+    fn parameter(&self) -> AppointmentResponseSearchParameter {
+        AppointmentResponseSearchParameter::Group
+    }
+}
+/// The location reference search parameter of the AppointmentResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentResponseLocationSearchReference;
+impl ReferenceSearchParameter for AppointmentResponseLocationSearchReference {
+    type Resource = resources::AppointmentResponse;
+    type Target = resources::Location;
+    fn parameter(&self) -> AppointmentResponseSearchParameter {
+        AppointmentResponseSearchParameter::Location
+    }
+}
+/// The practitioner reference search parameter of the AppointmentResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentResponsePractitionerSearchReference;
+impl ReferenceSearchParameter for AppointmentResponsePractitionerSearchReference {
+    type Resource = resources::AppointmentResponse;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> AppointmentResponseSearchParameter {
+        AppointmentResponseSearchParameter::Practitioner
+    }
+}
+/// The patient reference search parameter of the AuditEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AuditEventPatientSearchReference;
+impl ReferenceSearchParameter for AuditEventPatientSearchReference {
+    type Resource = resources::AuditEvent;
+    type Target = resources::Patient;
+    fn parameter(&self) -> AuditEventSearchParameter {
+        AuditEventSearchParameter::Patient
+    }
+}
+/// The agent reference search parameter of the AuditEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AuditEventAgentSearchReference;
+impl ReferenceSearchParameter for AuditEventAgentSearchReference {
+    type Resource = resources::AuditEvent;
+    type Target = AuditEventAgentSearchTarget;
+    fn parameter(&self) -> AuditEventSearchParameter {
+        AuditEventSearchParameter::Agent
+    }
+}
+/// Target resources of the agent reference search parameter of the AuditEvent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AuditEventAgentSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for AuditEventAgentSearchTarget {
+    fn from(resource: resources::Organization) -> AuditEventAgentSearchTarget {
+        AuditEventAgentSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for AuditEventAgentSearchTarget {
+    fn from(resource: resources::CareTeam) -> AuditEventAgentSearchTarget {
+        AuditEventAgentSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for AuditEventAgentSearchTarget {
+    fn from(resource: resources::Device) -> AuditEventAgentSearchTarget {
+        AuditEventAgentSearchTarget::Device(resource)
+    }
+}
+impl From<resources::RelatedPerson> for AuditEventAgentSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AuditEventAgentSearchTarget {
+        AuditEventAgentSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for AuditEventAgentSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> AuditEventAgentSearchTarget {
+        AuditEventAgentSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for AuditEventAgentSearchTarget {
+    fn from(resource: resources::Practitioner) -> AuditEventAgentSearchTarget {
+        AuditEventAgentSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for AuditEventAgentSearchTarget {
+    fn from(resource: resources::Patient) -> AuditEventAgentSearchTarget {
+        AuditEventAgentSearchTarget::Patient(resource)
+    }
+}
+/// The based-on reference search parameter of the AuditEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AuditEventBasedOnSearchReference;
+impl ReferenceSearchParameter for AuditEventBasedOnSearchReference {
+    type Resource = resources::AuditEvent;
+    type Target = AuditEventBasedOnSearchTarget;
+    fn parameter(&self) -> AuditEventSearchParameter {
+        AuditEventSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the AuditEvent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AuditEventBasedOnSearchTarget {
+    /// Variant for DeviceRequest target resources
+    DeviceRequest(resources::DeviceRequest),
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for Task target resources
+    Task(resources::Task),
+    /// Variant for MedicationRequest target resources
+    MedicationRequest(resources::MedicationRequest),
+    /// Variant for ImmunizationRecommendation target resources
+    ImmunizationRecommendation(resources::ImmunizationRecommendation),
+    /// Variant for NutritionOrder target resources
+    NutritionOrder(resources::NutritionOrder),
+}
+impl From<resources::DeviceRequest> for AuditEventBasedOnSearchTarget {
+    fn from(resource: resources::DeviceRequest) -> AuditEventBasedOnSearchTarget {
+        AuditEventBasedOnSearchTarget::DeviceRequest(resource)
+    }
+}
+impl From<resources::ServiceRequest> for AuditEventBasedOnSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> AuditEventBasedOnSearchTarget {
+        AuditEventBasedOnSearchTarget::ServiceRequest(resource)
+    }
+}
+impl From<resources::CarePlan> for AuditEventBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> AuditEventBasedOnSearchTarget {
+        AuditEventBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::Task> for AuditEventBasedOnSearchTarget {
+    fn from(resource: resources::Task) -> AuditEventBasedOnSearchTarget {
+        AuditEventBasedOnSearchTarget::Task(resource)
+    }
+}
+impl From<resources::MedicationRequest> for AuditEventBasedOnSearchTarget {
+    fn from(resource: resources::MedicationRequest) -> AuditEventBasedOnSearchTarget {
+        AuditEventBasedOnSearchTarget::MedicationRequest(resource)
+    }
+}
+impl From<resources::ImmunizationRecommendation> for AuditEventBasedOnSearchTarget {
+    fn from(resource: resources::ImmunizationRecommendation) -> AuditEventBasedOnSearchTarget {
+        AuditEventBasedOnSearchTarget::ImmunizationRecommendation(resource)
+    }
+}
+impl From<resources::NutritionOrder> for AuditEventBasedOnSearchTarget {
+    fn from(resource: resources::NutritionOrder) -> AuditEventBasedOnSearchTarget {
+        AuditEventBasedOnSearchTarget::NutritionOrder(resource)
+    }
+}
+/// The encounter reference search parameter of the AuditEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AuditEventEncounterSearchReference;
+impl ReferenceSearchParameter for AuditEventEncounterSearchReference {
+    type Resource = resources::AuditEvent;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> AuditEventSearchParameter {
+        AuditEventSearchParameter::Encounter
+    }
+}
+/// The entity reference search parameter of the AuditEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AuditEventEntitySearchReference;
+impl ReferenceSearchParameter for AuditEventEntitySearchReference {
+    type Resource = resources::AuditEvent;
+    type Target = resources::Resource;
+    fn parameter(&self) -> AuditEventSearchParameter {
+        AuditEventSearchParameter::Entity
+    }
+}
+/// The source reference search parameter of the AuditEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AuditEventSourceSearchReference;
+impl ReferenceSearchParameter for AuditEventSourceSearchReference {
+    type Resource = resources::AuditEvent;
+    type Target = AuditEventSourceSearchTarget;
+    fn parameter(&self) -> AuditEventSearchParameter {
+        AuditEventSearchParameter::Source
+    }
+}
+/// Target resources of the source reference search parameter of the AuditEvent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AuditEventSourceSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for AuditEventSourceSearchTarget {
+    fn from(resource: resources::Organization) -> AuditEventSourceSearchTarget {
+        AuditEventSourceSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for AuditEventSourceSearchTarget {
+    fn from(resource: resources::CareTeam) -> AuditEventSourceSearchTarget {
+        AuditEventSourceSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for AuditEventSourceSearchTarget {
+    fn from(resource: resources::Device) -> AuditEventSourceSearchTarget {
+        AuditEventSourceSearchTarget::Device(resource)
+    }
+}
+impl From<resources::RelatedPerson> for AuditEventSourceSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AuditEventSourceSearchTarget {
+        AuditEventSourceSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for AuditEventSourceSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> AuditEventSourceSearchTarget {
+        AuditEventSourceSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for AuditEventSourceSearchTarget {
+    fn from(resource: resources::Practitioner) -> AuditEventSourceSearchTarget {
+        AuditEventSourceSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for AuditEventSourceSearchTarget {
+    fn from(resource: resources::Patient) -> AuditEventSourceSearchTarget {
+        AuditEventSourceSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the Basic resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BasicPatientSearchReference;
+impl ReferenceSearchParameter for BasicPatientSearchReference {
+    type Resource = resources::Basic;
+    type Target = resources::Patient;
+    fn parameter(&self) -> BasicSearchParameter {
+        BasicSearchParameter::Patient
+    }
+}
+/// The author reference search parameter of the Basic resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BasicAuthorSearchReference;
+impl ReferenceSearchParameter for BasicAuthorSearchReference {
+    type Resource = resources::Basic;
+    type Target = BasicAuthorSearchTarget;
+    fn parameter(&self) -> BasicSearchParameter {
+        BasicSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the Basic resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BasicAuthorSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for BasicAuthorSearchTarget {
+    fn from(resource: resources::Organization) -> BasicAuthorSearchTarget {
+        BasicAuthorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for BasicAuthorSearchTarget {
+    fn from(resource: resources::Device) -> BasicAuthorSearchTarget {
+        BasicAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::CareTeam> for BasicAuthorSearchTarget {
+    fn from(resource: resources::CareTeam) -> BasicAuthorSearchTarget {
+        BasicAuthorSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::RelatedPerson> for BasicAuthorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> BasicAuthorSearchTarget {
+        BasicAuthorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for BasicAuthorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> BasicAuthorSearchTarget {
+        BasicAuthorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for BasicAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> BasicAuthorSearchTarget {
+        BasicAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for BasicAuthorSearchTarget {
+    fn from(resource: resources::Patient) -> BasicAuthorSearchTarget {
+        BasicAuthorSearchTarget::Patient(resource)
+    }
+}
+/// The subject reference search parameter of the Basic resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BasicSubjectSearchReference;
+impl ReferenceSearchParameter for BasicSubjectSearchReference {
+    type Resource = resources::Basic;
+    type Target = resources::Resource;
+    fn parameter(&self) -> BasicSearchParameter {
+        BasicSearchParameter::Subject
+    }
+}
+/// The collector reference search parameter of the BiologicallyDerivedProduct resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BiologicallyDerivedProductCollectorSearchReference;
+impl ReferenceSearchParameter for BiologicallyDerivedProductCollectorSearchReference {
+    type Resource = resources::BiologicallyDerivedProduct;
+    type Target = BiologicallyDerivedProductCollectorSearchTarget;
+    fn parameter(&self) -> BiologicallyDerivedProductSearchParameter {
+        BiologicallyDerivedProductSearchParameter::Collector
+    }
+}
+/// Target resources of the collector reference search parameter of the BiologicallyDerivedProduct resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BiologicallyDerivedProductCollectorSearchTarget {
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::PractitionerRole> for BiologicallyDerivedProductCollectorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> BiologicallyDerivedProductCollectorSearchTarget {
+        BiologicallyDerivedProductCollectorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for BiologicallyDerivedProductCollectorSearchTarget {
+    fn from(resource: resources::Practitioner) -> BiologicallyDerivedProductCollectorSearchTarget {
+        BiologicallyDerivedProductCollectorSearchTarget::Practitioner(resource)
+    }
+}
+/// The request reference search parameter of the BiologicallyDerivedProduct resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BiologicallyDerivedProductRequestSearchReference;
+impl ReferenceSearchParameter for BiologicallyDerivedProductRequestSearchReference {
+    type Resource = resources::BiologicallyDerivedProduct;
+    type Target = resources::ServiceRequest;
+    fn parameter(&self) -> BiologicallyDerivedProductSearchParameter {
+        BiologicallyDerivedProductSearchParameter::Request
+    }
+}
+/// The patient reference search parameter of the BodyStructure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BodyStructurePatientSearchReference;
+impl ReferenceSearchParameter for BodyStructurePatientSearchReference {
+    type Resource = resources::BodyStructure;
+    type Target = resources::Patient;
+    fn parameter(&self) -> BodyStructureSearchParameter {
+        BodyStructureSearchParameter::Patient
+    }
+}
+/// The composition reference search parameter of the Bundle resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BundleCompositionSearchReference;
+impl ReferenceSearchParameter for BundleCompositionSearchReference {
+    type Resource = resources::Bundle;
+    type Target = resources::Composition;
+    fn parameter(&self) -> BundleSearchParameter {
+        BundleSearchParameter::Composition
+    }
+}
+/// The message reference search parameter of the Bundle resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BundleMessageSearchReference;
+impl ReferenceSearchParameter for BundleMessageSearchReference {
+    type Resource = resources::Bundle;
+    type Target = resources::MessageHeader;
+    fn parameter(&self) -> BundleSearchParameter {
+        BundleSearchParameter::Message
+    }
+}
+/// The guide reference search parameter of the CapabilityStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CapabilityStatementGuideSearchReference;
+impl ReferenceSearchParameter for CapabilityStatementGuideSearchReference {
+    type Resource = resources::CapabilityStatement;
+    type Target = resources::ImplementationGuide;
+    fn parameter(&self) -> CapabilityStatementSearchParameter {
+        CapabilityStatementSearchParameter::Guide
+    }
+}
+/// The resource-profile reference search parameter of the CapabilityStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CapabilityStatementResourceProfileSearchReference;
+impl ReferenceSearchParameter for CapabilityStatementResourceProfileSearchReference {
+    type Resource = resources::CapabilityStatement;
+    type Target = resources::StructureDefinition;
+    fn parameter(&self) -> CapabilityStatementSearchParameter {
+        CapabilityStatementSearchParameter::ResourceProfile
+    }
+}
+/// The supported-profile reference search parameter of the CapabilityStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CapabilityStatementSupportedProfileSearchReference;
+impl ReferenceSearchParameter for CapabilityStatementSupportedProfileSearchReference {
+    type Resource = resources::CapabilityStatement;
+    type Target = resources::StructureDefinition;
+    fn parameter(&self) -> CapabilityStatementSearchParameter {
+        CapabilityStatementSearchParameter::SupportedProfile
+    }
+}
+/// The patient reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanPatientSearchReference;
+impl ReferenceSearchParameter for CarePlanPatientSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanEncounterSearchReference;
+impl ReferenceSearchParameter for CarePlanEncounterSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Encounter
+    }
+}
+/// The activity-reference reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanActivityReferenceSearchReference;
+impl ReferenceSearchParameter for CarePlanActivityReferenceSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = CarePlanActivityReferenceSearchTarget;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::ActivityReference
+    }
+}
+/// Target resources of the activity-reference reference search parameter of the CarePlan resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CarePlanActivityReferenceSearchTarget {
+    /// Variant for Appointment target resources
+    Appointment(resources::Appointment),
+    /// Variant for RequestOrchestration target resources
+    RequestOrchestration(resources::RequestOrchestration),
+    /// Variant for DeviceRequest target resources
+    DeviceRequest(resources::DeviceRequest),
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+    /// Variant for CommunicationRequest target resources
+    CommunicationRequest(resources::CommunicationRequest),
+    /// Variant for Task target resources
+    Task(resources::Task),
+    /// Variant for MedicationRequest target resources
+    MedicationRequest(resources::MedicationRequest),
+    /// Variant for SupplyRequest target resources
+    SupplyRequest(resources::SupplyRequest),
+    /// Variant for ImmunizationRecommendation target resources
+    ImmunizationRecommendation(resources::ImmunizationRecommendation),
+    /// Variant for NutritionOrder target resources
+    NutritionOrder(resources::NutritionOrder),
+    /// Variant for VisionPrescription target resources
+    VisionPrescription(resources::VisionPrescription),
+}
+impl From<resources::Appointment> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::Appointment) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::Appointment(resource)
+    }
+}
+impl From<resources::RequestOrchestration> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::RequestOrchestration) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::RequestOrchestration(resource)
+    }
+}
+impl From<resources::DeviceRequest> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::DeviceRequest) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::DeviceRequest(resource)
+    }
+}
+impl From<resources::ServiceRequest> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::ServiceRequest(resource)
+    }
+}
+impl From<resources::CommunicationRequest> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::CommunicationRequest) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::CommunicationRequest(resource)
+    }
+}
+impl From<resources::Task> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::Task) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::Task(resource)
+    }
+}
+impl From<resources::MedicationRequest> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::MedicationRequest) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::MedicationRequest(resource)
+    }
+}
+impl From<resources::SupplyRequest> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::SupplyRequest) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::SupplyRequest(resource)
+    }
+}
+impl From<resources::ImmunizationRecommendation> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::ImmunizationRecommendation) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::ImmunizationRecommendation(resource)
+    }
+}
+impl From<resources::NutritionOrder> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::NutritionOrder) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::NutritionOrder(resource)
+    }
+}
+impl From<resources::VisionPrescription> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::VisionPrescription) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::VisionPrescription(resource)
+    }
+}
+/// The based-on reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanBasedOnSearchReference;
+impl ReferenceSearchParameter for CarePlanBasedOnSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = CarePlanBasedOnSearchTarget;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the CarePlan resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CarePlanBasedOnSearchTarget {
+    /// Variant for RequestOrchestration target resources
+    RequestOrchestration(resources::RequestOrchestration),
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for NutritionOrder target resources
+    NutritionOrder(resources::NutritionOrder),
+}
+impl From<resources::RequestOrchestration> for CarePlanBasedOnSearchTarget {
+    fn from(resource: resources::RequestOrchestration) -> CarePlanBasedOnSearchTarget {
+        CarePlanBasedOnSearchTarget::RequestOrchestration(resource)
+    }
+}
+impl From<resources::ServiceRequest> for CarePlanBasedOnSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> CarePlanBasedOnSearchTarget {
+        CarePlanBasedOnSearchTarget::ServiceRequest(resource)
+    }
+}
+impl From<resources::CarePlan> for CarePlanBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> CarePlanBasedOnSearchTarget {
+        CarePlanBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::NutritionOrder> for CarePlanBasedOnSearchTarget {
+    fn from(resource: resources::NutritionOrder) -> CarePlanBasedOnSearchTarget {
+        CarePlanBasedOnSearchTarget::NutritionOrder(resource)
+    }
+}
+/// The care-team reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanCareTeamSearchReference;
+impl ReferenceSearchParameter for CarePlanCareTeamSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::CareTeam;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::CareTeam
+    }
+}
+/// The condition reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanConditionSearchReference;
+impl ReferenceSearchParameter for CarePlanConditionSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::Condition;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Condition
+    }
+}
+/// The custodian reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanCustodianSearchReference;
+impl ReferenceSearchParameter for CarePlanCustodianSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = CarePlanCustodianSearchTarget;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Custodian
+    }
+}
+/// Target resources of the custodian reference search parameter of the CarePlan resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CarePlanCustodianSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Device> for CarePlanCustodianSearchTarget {
+    fn from(resource: resources::Device) -> CarePlanCustodianSearchTarget {
+        CarePlanCustodianSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for CarePlanCustodianSearchTarget {
+    fn from(resource: resources::Organization) -> CarePlanCustodianSearchTarget {
+        CarePlanCustodianSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for CarePlanCustodianSearchTarget {
+    fn from(resource: resources::CareTeam) -> CarePlanCustodianSearchTarget {
+        CarePlanCustodianSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CarePlanCustodianSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CarePlanCustodianSearchTarget {
+        CarePlanCustodianSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CarePlanCustodianSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CarePlanCustodianSearchTarget {
+        CarePlanCustodianSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for CarePlanCustodianSearchTarget {
+    fn from(resource: resources::Practitioner) -> CarePlanCustodianSearchTarget {
+        CarePlanCustodianSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for CarePlanCustodianSearchTarget {
+    fn from(resource: resources::Patient) -> CarePlanCustodianSearchTarget {
+        CarePlanCustodianSearchTarget::Patient(resource)
+    }
+}
+/// The goal reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanGoalSearchReference;
+impl ReferenceSearchParameter for CarePlanGoalSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::Goal;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Goal
+    }
+}
+/// The instantiates-canonical reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanInstantiatesCanonicalSearchReference;
+impl ReferenceSearchParameter for CarePlanInstantiatesCanonicalSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = CarePlanInstantiatesCanonicalSearchTarget;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::InstantiatesCanonical
+    }
+}
+/// Target resources of the instantiates-canonical reference search parameter of the CarePlan resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CarePlanInstantiatesCanonicalSearchTarget {
+    /// Variant for Measure target resources
+    Measure(resources::Measure),
+    /// Variant for OperationDefinition target resources
+    OperationDefinition(resources::OperationDefinition),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for Questionnaire target resources
+    Questionnaire(resources::Questionnaire),
+}
+impl From<resources::Measure> for CarePlanInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Measure) -> CarePlanInstantiatesCanonicalSearchTarget {
+        CarePlanInstantiatesCanonicalSearchTarget::Measure(resource)
+    }
+}
+impl From<resources::OperationDefinition> for CarePlanInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::OperationDefinition) -> CarePlanInstantiatesCanonicalSearchTarget {
+        CarePlanInstantiatesCanonicalSearchTarget::OperationDefinition(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for CarePlanInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> CarePlanInstantiatesCanonicalSearchTarget {
+        CarePlanInstantiatesCanonicalSearchTarget::ActivityDefinition(resource)
+    }
+}
+impl From<resources::PlanDefinition> for CarePlanInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> CarePlanInstantiatesCanonicalSearchTarget {
+        CarePlanInstantiatesCanonicalSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::Questionnaire> for CarePlanInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Questionnaire) -> CarePlanInstantiatesCanonicalSearchTarget {
+        CarePlanInstantiatesCanonicalSearchTarget::Questionnaire(resource)
+    }
+}
+/// The part-of reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanPartOfSearchReference;
+impl ReferenceSearchParameter for CarePlanPartOfSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::CarePlan;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::PartOf
+    }
+}
+/// The replaces reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanReplacesSearchReference;
+impl ReferenceSearchParameter for CarePlanReplacesSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::CarePlan;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Replaces
+    }
+}
+/// The subject reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanSubjectSearchReference;
+impl ReferenceSearchParameter for CarePlanSubjectSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = CarePlanSubjectSearchTarget;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the CarePlan resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CarePlanSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for CarePlanSubjectSearchTarget {
+    fn from(resource: resources::Group) -> CarePlanSubjectSearchTarget {
+        CarePlanSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for CarePlanSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> CarePlanSubjectSearchTarget {
+        CarePlanSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the CareTeam resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CareTeamPatientSearchReference;
+impl ReferenceSearchParameter for CareTeamPatientSearchReference {
+    type Resource = resources::CareTeam;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CareTeamSearchParameter {
+        CareTeamSearchParameter::Patient
+    }
+}
+/// The participant reference search parameter of the CareTeam resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CareTeamParticipantSearchReference;
+impl ReferenceSearchParameter for CareTeamParticipantSearchReference {
+    type Resource = resources::CareTeam;
+    type Target = CareTeamParticipantSearchTarget;
+    fn parameter(&self) -> CareTeamSearchParameter {
+        CareTeamSearchParameter::Participant
+    }
+}
+/// Target resources of the participant reference search parameter of the CareTeam resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CareTeamParticipantSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for CareTeamParticipantSearchTarget {
+    fn from(resource: resources::Organization) -> CareTeamParticipantSearchTarget {
+        CareTeamParticipantSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for CareTeamParticipantSearchTarget {
+    fn from(resource: resources::CareTeam) -> CareTeamParticipantSearchTarget {
+        CareTeamParticipantSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CareTeamParticipantSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CareTeamParticipantSearchTarget {
+        CareTeamParticipantSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CareTeamParticipantSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CareTeamParticipantSearchTarget {
+        CareTeamParticipantSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for CareTeamParticipantSearchTarget {
+    fn from(resource: resources::Practitioner) -> CareTeamParticipantSearchTarget {
+        CareTeamParticipantSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for CareTeamParticipantSearchTarget {
+    fn from(resource: resources::Patient) -> CareTeamParticipantSearchTarget {
+        CareTeamParticipantSearchTarget::Patient(resource)
+    }
+}
+/// The subject reference search parameter of the CareTeam resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CareTeamSubjectSearchReference;
+impl ReferenceSearchParameter for CareTeamSubjectSearchReference {
+    type Resource = resources::CareTeam;
+    type Target = CareTeamSubjectSearchTarget;
+    fn parameter(&self) -> CareTeamSearchParameter {
+        CareTeamSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the CareTeam resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CareTeamSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for CareTeamSubjectSearchTarget {
+    fn from(resource: resources::Group) -> CareTeamSubjectSearchTarget {
+        CareTeamSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for CareTeamSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> CareTeamSubjectSearchTarget {
+        CareTeamSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemPatientSearchReference;
+impl ReferenceSearchParameter for ChargeItemPatientSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemEncounterSearchReference;
+impl ReferenceSearchParameter for ChargeItemEncounterSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::Encounter
+    }
+}
+/// The account reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemAccountSearchReference;
+impl ReferenceSearchParameter for ChargeItemAccountSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = resources::Account;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::Account
+    }
+}
+/// The enterer reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemEntererSearchReference;
+impl ReferenceSearchParameter for ChargeItemEntererSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = ChargeItemEntererSearchTarget;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::Enterer
+    }
+}
+/// Target resources of the enterer reference search parameter of the ChargeItem resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChargeItemEntererSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for ChargeItemEntererSearchTarget {
+    fn from(resource: resources::Organization) -> ChargeItemEntererSearchTarget {
+        ChargeItemEntererSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for ChargeItemEntererSearchTarget {
+    fn from(resource: resources::Device) -> ChargeItemEntererSearchTarget {
+        ChargeItemEntererSearchTarget::Device(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ChargeItemEntererSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ChargeItemEntererSearchTarget {
+        ChargeItemEntererSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ChargeItemEntererSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ChargeItemEntererSearchTarget {
+        ChargeItemEntererSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ChargeItemEntererSearchTarget {
+    fn from(resource: resources::Practitioner) -> ChargeItemEntererSearchTarget {
+        ChargeItemEntererSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ChargeItemEntererSearchTarget {
+    fn from(resource: resources::Patient) -> ChargeItemEntererSearchTarget {
+        ChargeItemEntererSearchTarget::Patient(resource)
+    }
+}
+/// The performer-actor reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemPerformerActorSearchReference;
+impl ReferenceSearchParameter for ChargeItemPerformerActorSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = ChargeItemPerformerActorSearchTarget;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::PerformerActor
+    }
+}
+/// Target resources of the performer-actor reference search parameter of the ChargeItem resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChargeItemPerformerActorSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for ChargeItemPerformerActorSearchTarget {
+    fn from(resource: resources::HealthcareService) -> ChargeItemPerformerActorSearchTarget {
+        ChargeItemPerformerActorSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::Organization> for ChargeItemPerformerActorSearchTarget {
+    fn from(resource: resources::Organization) -> ChargeItemPerformerActorSearchTarget {
+        ChargeItemPerformerActorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for ChargeItemPerformerActorSearchTarget {
+    fn from(resource: resources::CareTeam) -> ChargeItemPerformerActorSearchTarget {
+        ChargeItemPerformerActorSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for ChargeItemPerformerActorSearchTarget {
+    fn from(resource: resources::Device) -> ChargeItemPerformerActorSearchTarget {
+        ChargeItemPerformerActorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ChargeItemPerformerActorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ChargeItemPerformerActorSearchTarget {
+        ChargeItemPerformerActorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ChargeItemPerformerActorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ChargeItemPerformerActorSearchTarget {
+        ChargeItemPerformerActorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ChargeItemPerformerActorSearchTarget {
+    fn from(resource: resources::Practitioner) -> ChargeItemPerformerActorSearchTarget {
+        ChargeItemPerformerActorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ChargeItemPerformerActorSearchTarget {
+    fn from(resource: resources::Patient) -> ChargeItemPerformerActorSearchTarget {
+        ChargeItemPerformerActorSearchTarget::Patient(resource)
+    }
+}
+/// The performing-organization reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemPerformingOrganizationSearchReference;
+impl ReferenceSearchParameter for ChargeItemPerformingOrganizationSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::PerformingOrganization
+    }
+}
+/// The requesting-organization reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemRequestingOrganizationSearchReference;
+impl ReferenceSearchParameter for ChargeItemRequestingOrganizationSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::RequestingOrganization
+    }
+}
+/// The service reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemServiceSearchReference;
+impl ReferenceSearchParameter for ChargeItemServiceSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = ChargeItemServiceSearchTarget;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::Service
+    }
+}
+/// Target resources of the service reference search parameter of the ChargeItem resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChargeItemServiceSearchTarget {
+    /// Variant for SupplyDelivery target resources
+    SupplyDelivery(resources::SupplyDelivery),
+    /// Variant for ImagingStudy target resources
+    ImagingStudy(resources::ImagingStudy),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+    /// Variant for MedicationAdministration target resources
+    MedicationAdministration(resources::MedicationAdministration),
+    /// Variant for DiagnosticReport target resources
+    DiagnosticReport(resources::DiagnosticReport),
+    /// Variant for Immunization target resources
+    Immunization(resources::Immunization),
+    /// Variant for MedicationRequest target resources
+    MedicationRequest(resources::MedicationRequest),
+    /// Variant for MedicationDispense target resources
+    MedicationDispense(resources::MedicationDispense),
+}
+impl From<resources::SupplyDelivery> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::SupplyDelivery) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::SupplyDelivery(resource)
+    }
+}
+impl From<resources::ImagingStudy> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::ImagingStudy) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::ImagingStudy(resource)
+    }
+}
+impl From<resources::Procedure> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::Procedure) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::Observation> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::Observation) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::ServiceRequest> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::ServiceRequest(resource)
+    }
+}
+impl From<resources::MedicationAdministration> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::MedicationAdministration) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::MedicationAdministration(resource)
+    }
+}
+impl From<resources::DiagnosticReport> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::DiagnosticReport) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::DiagnosticReport(resource)
+    }
+}
+impl From<resources::Immunization> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::Immunization) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::Immunization(resource)
+    }
+}
+impl From<resources::MedicationRequest> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::MedicationRequest) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::MedicationRequest(resource)
+    }
+}
+impl From<resources::MedicationDispense> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::MedicationDispense) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::MedicationDispense(resource)
+    }
+}
+/// The subject reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemSubjectSearchReference;
+impl ReferenceSearchParameter for ChargeItemSubjectSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = ChargeItemSubjectSearchTarget;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the ChargeItem resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChargeItemSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for ChargeItemSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ChargeItemSubjectSearchTarget {
+        ChargeItemSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for ChargeItemSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ChargeItemSubjectSearchTarget {
+        ChargeItemSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimPatientSearchReference;
+impl ReferenceSearchParameter for ClaimPatientSearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimEncounterSearchReference;
+impl ReferenceSearchParameter for ClaimEncounterSearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Encounter
+    }
+}
+/// The care-team reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimCareTeamSearchReference;
+impl ReferenceSearchParameter for ClaimCareTeamSearchReference {
+    type Resource = resources::Claim;
+    type Target = ClaimCareTeamSearchTarget;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::CareTeam
+    }
+}
+/// Target resources of the care-team reference search parameter of the Claim resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClaimCareTeamSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for ClaimCareTeamSearchTarget {
+    fn from(resource: resources::Organization) -> ClaimCareTeamSearchTarget {
+        ClaimCareTeamSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ClaimCareTeamSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ClaimCareTeamSearchTarget {
+        ClaimCareTeamSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ClaimCareTeamSearchTarget {
+    fn from(resource: resources::Practitioner) -> ClaimCareTeamSearchTarget {
+        ClaimCareTeamSearchTarget::Practitioner(resource)
+    }
+}
+/// The detail-udi reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimDetailUdiSearchReference;
+impl ReferenceSearchParameter for ClaimDetailUdiSearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Device;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::DetailUdi
+    }
+}
+/// The enterer reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimEntererSearchReference;
+impl ReferenceSearchParameter for ClaimEntererSearchReference {
+    type Resource = resources::Claim;
+    type Target = ClaimEntererSearchTarget;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Enterer
+    }
+}
+/// Target resources of the enterer reference search parameter of the Claim resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClaimEntererSearchTarget {
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::RelatedPerson> for ClaimEntererSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ClaimEntererSearchTarget {
+        ClaimEntererSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ClaimEntererSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ClaimEntererSearchTarget {
+        ClaimEntererSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ClaimEntererSearchTarget {
+    fn from(resource: resources::Practitioner) -> ClaimEntererSearchTarget {
+        ClaimEntererSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ClaimEntererSearchTarget {
+    fn from(resource: resources::Patient) -> ClaimEntererSearchTarget {
+        ClaimEntererSearchTarget::Patient(resource)
+    }
+}
+/// The facility reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimFacilitySearchReference;
+impl ReferenceSearchParameter for ClaimFacilitySearchReference {
+    type Resource = resources::Claim;
+    type Target = ClaimFacilitySearchTarget;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Facility
+    }
+}
+/// Target resources of the facility reference search parameter of the Claim resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClaimFacilitySearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Organization> for ClaimFacilitySearchTarget {
+    fn from(resource: resources::Organization) -> ClaimFacilitySearchTarget {
+        ClaimFacilitySearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Location> for ClaimFacilitySearchTarget {
+    fn from(resource: resources::Location) -> ClaimFacilitySearchTarget {
+        ClaimFacilitySearchTarget::Location(resource)
+    }
+}
+/// The insurer reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimInsurerSearchReference;
+impl ReferenceSearchParameter for ClaimInsurerSearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Insurer
+    }
+}
+/// The item-udi reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimItemUdiSearchReference;
+impl ReferenceSearchParameter for ClaimItemUdiSearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Device;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::ItemUdi
+    }
+}
+/// The payee reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimPayeeSearchReference;
+impl ReferenceSearchParameter for ClaimPayeeSearchReference {
+    type Resource = resources::Claim;
+    type Target = ClaimPayeeSearchTarget;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Payee
+    }
+}
+/// Target resources of the payee reference search parameter of the Claim resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClaimPayeeSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for ClaimPayeeSearchTarget {
+    fn from(resource: resources::Organization) -> ClaimPayeeSearchTarget {
+        ClaimPayeeSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ClaimPayeeSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ClaimPayeeSearchTarget {
+        ClaimPayeeSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ClaimPayeeSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ClaimPayeeSearchTarget {
+        ClaimPayeeSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ClaimPayeeSearchTarget {
+    fn from(resource: resources::Practitioner) -> ClaimPayeeSearchTarget {
+        ClaimPayeeSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ClaimPayeeSearchTarget {
+    fn from(resource: resources::Patient) -> ClaimPayeeSearchTarget {
+        ClaimPayeeSearchTarget::Patient(resource)
+    }
+}
+/// The procedure-udi reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimProcedureUdiSearchReference;
+impl ReferenceSearchParameter for ClaimProcedureUdiSearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Device;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::ProcedureUdi
+    }
+}
+/// The provider reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimProviderSearchReference;
+impl ReferenceSearchParameter for ClaimProviderSearchReference {
+    type Resource = resources::Claim;
+    type Target = ClaimProviderSearchTarget;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Provider
+    }
+}
+/// Target resources of the provider reference search parameter of the Claim resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClaimProviderSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for ClaimProviderSearchTarget {
+    fn from(resource: resources::Organization) -> ClaimProviderSearchTarget {
+        ClaimProviderSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ClaimProviderSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ClaimProviderSearchTarget {
+        ClaimProviderSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ClaimProviderSearchTarget {
+    fn from(resource: resources::Practitioner) -> ClaimProviderSearchTarget {
+        ClaimProviderSearchTarget::Practitioner(resource)
+    }
+}
+/// The subdetail-udi reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimSubdetailUdiSearchReference;
+impl ReferenceSearchParameter for ClaimSubdetailUdiSearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Device;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::SubdetailUdi
+    }
+}
+/// The patient reference search parameter of the ClaimResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimResponsePatientSearchReference;
+impl ReferenceSearchParameter for ClaimResponsePatientSearchReference {
+    type Resource = resources::ClaimResponse;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ClaimResponseSearchParameter {
+        ClaimResponseSearchParameter::Patient
+    }
+}
+/// The insurer reference search parameter of the ClaimResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimResponseInsurerSearchReference;
+impl ReferenceSearchParameter for ClaimResponseInsurerSearchReference {
+    type Resource = resources::ClaimResponse;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ClaimResponseSearchParameter {
+        ClaimResponseSearchParameter::Insurer
+    }
+}
+/// The request reference search parameter of the ClaimResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimResponseRequestSearchReference;
+impl ReferenceSearchParameter for ClaimResponseRequestSearchReference {
+    type Resource = resources::ClaimResponse;
+    type Target = resources::Claim;
+    fn parameter(&self) -> ClaimResponseSearchParameter {
+        ClaimResponseSearchParameter::Request
+    }
+}
+/// The requestor reference search parameter of the ClaimResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimResponseRequestorSearchReference;
+impl ReferenceSearchParameter for ClaimResponseRequestorSearchReference {
+    type Resource = resources::ClaimResponse;
+    type Target = ClaimResponseRequestorSearchTarget;
+    fn parameter(&self) -> ClaimResponseSearchParameter {
+        ClaimResponseSearchParameter::Requestor
+    }
+}
+/// Target resources of the requestor reference search parameter of the ClaimResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClaimResponseRequestorSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for ClaimResponseRequestorSearchTarget {
+    fn from(resource: resources::Organization) -> ClaimResponseRequestorSearchTarget {
+        ClaimResponseRequestorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ClaimResponseRequestorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ClaimResponseRequestorSearchTarget {
+        ClaimResponseRequestorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ClaimResponseRequestorSearchTarget {
+    fn from(resource: resources::Practitioner) -> ClaimResponseRequestorSearchTarget {
+        ClaimResponseRequestorSearchTarget::Practitioner(resource)
+    }
+}
+/// The patient reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionPatientSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionPatientSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionEncounterSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionEncounterSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Encounter
+    }
+}
+/// The finding-ref reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionFindingRefSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionFindingRefSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = ClinicalImpressionFindingRefSearchTarget;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::FindingRef
+    }
+}
+/// Target resources of the finding-ref reference search parameter of the ClinicalImpression resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClinicalImpressionFindingRefSearchTarget {
+    /// Variant for DocumentReference target resources
+    DocumentReference(resources::DocumentReference),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for Condition target resources
+    Condition(resources::Condition),
+}
+impl From<resources::DocumentReference> for ClinicalImpressionFindingRefSearchTarget {
+    fn from(resource: resources::DocumentReference) -> ClinicalImpressionFindingRefSearchTarget {
+        ClinicalImpressionFindingRefSearchTarget::DocumentReference(resource)
+    }
+}
+impl From<resources::Observation> for ClinicalImpressionFindingRefSearchTarget {
+    fn from(resource: resources::Observation) -> ClinicalImpressionFindingRefSearchTarget {
+        ClinicalImpressionFindingRefSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::Condition> for ClinicalImpressionFindingRefSearchTarget {
+    fn from(resource: resources::Condition) -> ClinicalImpressionFindingRefSearchTarget {
+        ClinicalImpressionFindingRefSearchTarget::Condition(resource)
+    }
+}
+/// The performer reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionPerformerSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionPerformerSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = ClinicalImpressionPerformerSearchTarget;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the ClinicalImpression resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClinicalImpressionPerformerSearchTarget {
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::PractitionerRole> for ClinicalImpressionPerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ClinicalImpressionPerformerSearchTarget {
+        ClinicalImpressionPerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ClinicalImpressionPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ClinicalImpressionPerformerSearchTarget {
+        ClinicalImpressionPerformerSearchTarget::Practitioner(resource)
+    }
+}
+/// The previous reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionPreviousSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionPreviousSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = resources::ClinicalImpression;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Previous
+    }
+}
+/// The problem reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionProblemSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionProblemSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = ClinicalImpressionProblemSearchTarget;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Problem
+    }
+}
+/// Target resources of the problem reference search parameter of the ClinicalImpression resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClinicalImpressionProblemSearchTarget {
+    /// Variant for AllergyIntolerance target resources
+    AllergyIntolerance(resources::AllergyIntolerance),
+    /// Variant for Condition target resources
+    Condition(resources::Condition),
+}
+impl From<resources::AllergyIntolerance> for ClinicalImpressionProblemSearchTarget {
+    fn from(resource: resources::AllergyIntolerance) -> ClinicalImpressionProblemSearchTarget {
+        ClinicalImpressionProblemSearchTarget::AllergyIntolerance(resource)
+    }
+}
+impl From<resources::Condition> for ClinicalImpressionProblemSearchTarget {
+    fn from(resource: resources::Condition) -> ClinicalImpressionProblemSearchTarget {
+        ClinicalImpressionProblemSearchTarget::Condition(resource)
+    }
+}
+/// The subject reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionSubjectSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionSubjectSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = ClinicalImpressionSubjectSearchTarget;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the ClinicalImpression resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClinicalImpressionSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for ClinicalImpressionSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ClinicalImpressionSubjectSearchTarget {
+        ClinicalImpressionSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for ClinicalImpressionSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ClinicalImpressionSubjectSearchTarget {
+        ClinicalImpressionSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The supporting-info reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionSupportingInfoSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionSupportingInfoSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::SupportingInfo
+    }
+}
+/// The contraindication-reference reference search parameter of the ClinicalUseDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalUseDefinitionContraindicationReferenceSearchReference;
+impl ReferenceSearchParameter for ClinicalUseDefinitionContraindicationReferenceSearchReference {
+    type Resource = resources::ClinicalUseDefinition;
+    type Target = resources::ObservationDefinition;
+    fn parameter(&self) -> ClinicalUseDefinitionSearchParameter {
+        ClinicalUseDefinitionSearchParameter::ContraindicationReference
+    }
+}
+/// The effect-reference reference search parameter of the ClinicalUseDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalUseDefinitionEffectReferenceSearchReference;
+impl ReferenceSearchParameter for ClinicalUseDefinitionEffectReferenceSearchReference {
+    type Resource = resources::ClinicalUseDefinition;
+    type Target = resources::ObservationDefinition;
+    fn parameter(&self) -> ClinicalUseDefinitionSearchParameter {
+        ClinicalUseDefinitionSearchParameter::EffectReference
+    }
+}
+/// The indication-reference reference search parameter of the ClinicalUseDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalUseDefinitionIndicationReferenceSearchReference;
+impl ReferenceSearchParameter for ClinicalUseDefinitionIndicationReferenceSearchReference {
+    type Resource = resources::ClinicalUseDefinition;
+    type Target = resources::ObservationDefinition;
+    fn parameter(&self) -> ClinicalUseDefinitionSearchParameter {
+        ClinicalUseDefinitionSearchParameter::IndicationReference
+    }
+}
+/// The product reference search parameter of the ClinicalUseDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalUseDefinitionProductSearchReference;
+impl ReferenceSearchParameter for ClinicalUseDefinitionProductSearchReference {
+    type Resource = resources::ClinicalUseDefinition;
+    type Target = resources::MedicinalProductDefinition;
+    fn parameter(&self) -> ClinicalUseDefinitionSearchParameter {
+        ClinicalUseDefinitionSearchParameter::Product
+    }
+}
+/// The subject reference search parameter of the ClinicalUseDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalUseDefinitionSubjectSearchReference;
+impl ReferenceSearchParameter for ClinicalUseDefinitionSubjectSearchReference {
+    type Resource = resources::ClinicalUseDefinition;
+    type Target = ClinicalUseDefinitionSubjectSearchTarget;
+    fn parameter(&self) -> ClinicalUseDefinitionSearchParameter {
+        ClinicalUseDefinitionSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the ClinicalUseDefinition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClinicalUseDefinitionSubjectSearchTarget {
+    /// Variant for MedicinalProductDefinition target resources
+    MedicinalProductDefinition(resources::MedicinalProductDefinition),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for DeviceDefinition target resources
+    DeviceDefinition(resources::DeviceDefinition),
+    /// Variant for NutritionProduct target resources
+    NutritionProduct(resources::NutritionProduct),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for BiologicallyDerivedProduct target resources
+    BiologicallyDerivedProduct(resources::BiologicallyDerivedProduct),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+}
+impl From<resources::MedicinalProductDefinition> for ClinicalUseDefinitionSubjectSearchTarget {
+    fn from(resource: resources::MedicinalProductDefinition) -> ClinicalUseDefinitionSubjectSearchTarget {
+        ClinicalUseDefinitionSubjectSearchTarget::MedicinalProductDefinition(resource)
+    }
+}
+impl From<resources::Device> for ClinicalUseDefinitionSubjectSearchTarget {
+    fn from(resource: resources::Device) -> ClinicalUseDefinitionSubjectSearchTarget {
+        ClinicalUseDefinitionSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::DeviceDefinition> for ClinicalUseDefinitionSubjectSearchTarget {
+    fn from(resource: resources::DeviceDefinition) -> ClinicalUseDefinitionSubjectSearchTarget {
+        ClinicalUseDefinitionSubjectSearchTarget::DeviceDefinition(resource)
+    }
+}
+impl From<resources::NutritionProduct> for ClinicalUseDefinitionSubjectSearchTarget {
+    fn from(resource: resources::NutritionProduct) -> ClinicalUseDefinitionSubjectSearchTarget {
+        ClinicalUseDefinitionSubjectSearchTarget::NutritionProduct(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for ClinicalUseDefinitionSubjectSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> ClinicalUseDefinitionSubjectSearchTarget {
+        ClinicalUseDefinitionSubjectSearchTarget::ActivityDefinition(resource)
+    }
+}
+impl From<resources::PlanDefinition> for ClinicalUseDefinitionSubjectSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> ClinicalUseDefinitionSubjectSearchTarget {
+        ClinicalUseDefinitionSubjectSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::BiologicallyDerivedProduct> for ClinicalUseDefinitionSubjectSearchTarget {
+    fn from(resource: resources::BiologicallyDerivedProduct) -> ClinicalUseDefinitionSubjectSearchTarget {
+        ClinicalUseDefinitionSubjectSearchTarget::BiologicallyDerivedProduct(resource)
+    }
+}
+impl From<resources::Substance> for ClinicalUseDefinitionSubjectSearchTarget {
+    fn from(resource: resources::Substance) -> ClinicalUseDefinitionSubjectSearchTarget {
+        ClinicalUseDefinitionSubjectSearchTarget::Substance(resource)
+    }
+}
+impl From<resources::Medication> for ClinicalUseDefinitionSubjectSearchTarget {
+    fn from(resource: resources::Medication) -> ClinicalUseDefinitionSubjectSearchTarget {
+        ClinicalUseDefinitionSubjectSearchTarget::Medication(resource)
+    }
+}
+/// The derived-from reference search parameter of the CodeSystem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CodeSystemDerivedFromSearchReference;
+impl ReferenceSearchParameter for CodeSystemDerivedFromSearchReference {
+    type Resource = resources::CodeSystem;
+    type Target = resources::Resource;
+    fn parameter(&self) -> CodeSystemSearchParameter {
+        CodeSystemSearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the CodeSystem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CodeSystemPredecessorSearchReference;
+impl ReferenceSearchParameter for CodeSystemPredecessorSearchReference {
+    type Resource = resources::CodeSystem;
+    type Target = resources::Resource;
+    fn parameter(&self) -> CodeSystemSearchParameter {
+        CodeSystemSearchParameter::Predecessor
+    }
+}
+/// The supplements reference search parameter of the CodeSystem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CodeSystemSupplementsSearchReference;
+impl ReferenceSearchParameter for CodeSystemSupplementsSearchReference {
+    type Resource = resources::CodeSystem;
+    type Target = resources::CodeSystem;
+    fn parameter(&self) -> CodeSystemSearchParameter {
+        CodeSystemSearchParameter::Supplements
+    }
+}
+/// The patient reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationPatientSearchReference;
+impl ReferenceSearchParameter for CommunicationPatientSearchReference {
+    type Resource = resources::Communication;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationEncounterSearchReference;
+impl ReferenceSearchParameter for CommunicationEncounterSearchReference {
+    type Resource = resources::Communication;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::Encounter
+    }
+}
+/// The based-on reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationBasedOnSearchReference;
+impl ReferenceSearchParameter for CommunicationBasedOnSearchReference {
+    type Resource = resources::Communication;
+    type Target = resources::Resource;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::BasedOn
+    }
+}
+/// The instantiates-canonical reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationInstantiatesCanonicalSearchReference;
+impl ReferenceSearchParameter for CommunicationInstantiatesCanonicalSearchReference {
+    type Resource = resources::Communication;
+    type Target = CommunicationInstantiatesCanonicalSearchTarget;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::InstantiatesCanonical
+    }
+}
+/// Target resources of the instantiates-canonical reference search parameter of the Communication resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationInstantiatesCanonicalSearchTarget {
+    /// Variant for Measure target resources
+    Measure(resources::Measure),
+    /// Variant for OperationDefinition target resources
+    OperationDefinition(resources::OperationDefinition),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for Questionnaire target resources
+    Questionnaire(resources::Questionnaire),
+}
+impl From<resources::Measure> for CommunicationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Measure) -> CommunicationInstantiatesCanonicalSearchTarget {
+        CommunicationInstantiatesCanonicalSearchTarget::Measure(resource)
+    }
+}
+impl From<resources::OperationDefinition> for CommunicationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::OperationDefinition) -> CommunicationInstantiatesCanonicalSearchTarget {
+        CommunicationInstantiatesCanonicalSearchTarget::OperationDefinition(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for CommunicationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> CommunicationInstantiatesCanonicalSearchTarget {
+        CommunicationInstantiatesCanonicalSearchTarget::ActivityDefinition(resource)
+    }
+}
+impl From<resources::PlanDefinition> for CommunicationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> CommunicationInstantiatesCanonicalSearchTarget {
+        CommunicationInstantiatesCanonicalSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::Questionnaire> for CommunicationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Questionnaire) -> CommunicationInstantiatesCanonicalSearchTarget {
+        CommunicationInstantiatesCanonicalSearchTarget::Questionnaire(resource)
+    }
+}
+/// The part-of reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationPartOfSearchReference;
+impl ReferenceSearchParameter for CommunicationPartOfSearchReference {
+    type Resource = resources::Communication;
+    type Target = resources::Resource;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::PartOf
+    }
+}
+/// The recipient reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRecipientSearchReference;
+impl ReferenceSearchParameter for CommunicationRecipientSearchReference {
+    type Resource = resources::Communication;
+    type Target = CommunicationRecipientSearchTarget;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::Recipient
+    }
+}
+/// Target resources of the recipient reference search parameter of the Communication resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationRecipientSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Endpoint target resources
+    Endpoint(resources::Endpoint),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::HealthcareService) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::CareTeam> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::CareTeam) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::Device) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::Organization) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Endpoint> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::Endpoint) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::Endpoint(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Group> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::Group) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Practitioner> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::Practitioner) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Location> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::Location) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::Location(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::Patient(resource)
+    }
+}
+/// The sender reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationSenderSearchReference;
+impl ReferenceSearchParameter for CommunicationSenderSearchReference {
+    type Resource = resources::Communication;
+    type Target = CommunicationSenderSearchTarget;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::Sender
+    }
+}
+/// Target resources of the sender reference search parameter of the Communication resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationSenderSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Endpoint target resources
+    Endpoint(resources::Endpoint),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::HealthcareService) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::Device> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::Device) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::Organization) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Endpoint> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::Endpoint) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::Endpoint(resource)
+    }
+}
+impl From<resources::CareTeam> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::CareTeam) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::Practitioner) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::Patient(resource)
+    }
+}
+/// The subject reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationSubjectSearchReference;
+impl ReferenceSearchParameter for CommunicationSubjectSearchReference {
+    type Resource = resources::Communication;
+    type Target = CommunicationSubjectSearchTarget;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Communication resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for CommunicationSubjectSearchTarget {
+    fn from(resource: resources::Group) -> CommunicationSubjectSearchTarget {
+        CommunicationSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationSubjectSearchTarget {
+        CommunicationSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestPatientSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestPatientSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestEncounterSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestEncounterSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Encounter
+    }
+}
+/// The based-on reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestBasedOnSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestBasedOnSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = resources::Resource;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::BasedOn
+    }
+}
+/// The information-provider reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestInformationProviderSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestInformationProviderSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = CommunicationRequestInformationProviderSearchTarget;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::InformationProvider
+    }
+}
+/// Target resources of the information-provider reference search parameter of the CommunicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationRequestInformationProviderSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Endpoint target resources
+    Endpoint(resources::Endpoint),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for CommunicationRequestInformationProviderSearchTarget {
+    fn from(resource: resources::HealthcareService) -> CommunicationRequestInformationProviderSearchTarget {
+        CommunicationRequestInformationProviderSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::Device> for CommunicationRequestInformationProviderSearchTarget {
+    fn from(resource: resources::Device) -> CommunicationRequestInformationProviderSearchTarget {
+        CommunicationRequestInformationProviderSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for CommunicationRequestInformationProviderSearchTarget {
+    fn from(resource: resources::Organization) -> CommunicationRequestInformationProviderSearchTarget {
+        CommunicationRequestInformationProviderSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Endpoint> for CommunicationRequestInformationProviderSearchTarget {
+    fn from(resource: resources::Endpoint) -> CommunicationRequestInformationProviderSearchTarget {
+        CommunicationRequestInformationProviderSearchTarget::Endpoint(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CommunicationRequestInformationProviderSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CommunicationRequestInformationProviderSearchTarget {
+        CommunicationRequestInformationProviderSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CommunicationRequestInformationProviderSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CommunicationRequestInformationProviderSearchTarget {
+        CommunicationRequestInformationProviderSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for CommunicationRequestInformationProviderSearchTarget {
+    fn from(resource: resources::Practitioner) -> CommunicationRequestInformationProviderSearchTarget {
+        CommunicationRequestInformationProviderSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationRequestInformationProviderSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationRequestInformationProviderSearchTarget {
+        CommunicationRequestInformationProviderSearchTarget::Patient(resource)
+    }
+}
+/// The recipient reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestRecipientSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestRecipientSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = CommunicationRequestRecipientSearchTarget;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Recipient
+    }
+}
+/// Target resources of the recipient reference search parameter of the CommunicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationRequestRecipientSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Endpoint target resources
+    Endpoint(resources::Endpoint),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::HealthcareService) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::Device> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::Device) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::Organization) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::CareTeam) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Endpoint> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::Endpoint) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::Endpoint(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::Practitioner) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::Group) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::Patient(resource)
+    }
+}
+/// The replaces reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestReplacesSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestReplacesSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = resources::CommunicationRequest;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Replaces
+    }
+}
+/// The requester reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestRequesterSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestRequesterSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = CommunicationRequestRequesterSearchTarget;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Requester
+    }
+}
+/// Target resources of the requester reference search parameter of the CommunicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationRequestRequesterSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for CommunicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Organization) -> CommunicationRequestRequesterSearchTarget {
+        CommunicationRequestRequesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for CommunicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Device) -> CommunicationRequestRequesterSearchTarget {
+        CommunicationRequestRequesterSearchTarget::Device(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CommunicationRequestRequesterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CommunicationRequestRequesterSearchTarget {
+        CommunicationRequestRequesterSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CommunicationRequestRequesterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CommunicationRequestRequesterSearchTarget {
+        CommunicationRequestRequesterSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for CommunicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> CommunicationRequestRequesterSearchTarget {
+        CommunicationRequestRequesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationRequestRequesterSearchTarget {
+        CommunicationRequestRequesterSearchTarget::Patient(resource)
+    }
+}
+/// The subject reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestSubjectSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestSubjectSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = CommunicationRequestSubjectSearchTarget;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the CommunicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationRequestSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for CommunicationRequestSubjectSearchTarget {
+    fn from(resource: resources::Group) -> CommunicationRequestSubjectSearchTarget {
+        CommunicationRequestSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationRequestSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationRequestSubjectSearchTarget {
+        CommunicationRequestSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionPatientSearchReference;
+impl ReferenceSearchParameter for CompositionPatientSearchReference {
+    type Resource = resources::Composition;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionEncounterSearchReference;
+impl ReferenceSearchParameter for CompositionEncounterSearchReference {
+    type Resource = resources::Composition;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::Encounter
+    }
+}
+/// The attester reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionAttesterSearchReference;
+impl ReferenceSearchParameter for CompositionAttesterSearchReference {
+    type Resource = resources::Composition;
+    type Target = CompositionAttesterSearchTarget;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::Attester
+    }
+}
+/// Target resources of the attester reference search parameter of the Composition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CompositionAttesterSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for CompositionAttesterSearchTarget {
+    fn from(resource: resources::Organization) -> CompositionAttesterSearchTarget {
+        CompositionAttesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CompositionAttesterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CompositionAttesterSearchTarget {
+        CompositionAttesterSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CompositionAttesterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CompositionAttesterSearchTarget {
+        CompositionAttesterSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for CompositionAttesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> CompositionAttesterSearchTarget {
+        CompositionAttesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for CompositionAttesterSearchTarget {
+    fn from(resource: resources::Patient) -> CompositionAttesterSearchTarget {
+        CompositionAttesterSearchTarget::Patient(resource)
+    }
+}
+/// The author reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionAuthorSearchReference;
+impl ReferenceSearchParameter for CompositionAuthorSearchReference {
+    type Resource = resources::Composition;
+    type Target = CompositionAuthorSearchTarget;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the Composition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CompositionAuthorSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Device> for CompositionAuthorSearchTarget {
+    fn from(resource: resources::Device) -> CompositionAuthorSearchTarget {
+        CompositionAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for CompositionAuthorSearchTarget {
+    fn from(resource: resources::Organization) -> CompositionAuthorSearchTarget {
+        CompositionAuthorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CompositionAuthorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CompositionAuthorSearchTarget {
+        CompositionAuthorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CompositionAuthorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CompositionAuthorSearchTarget {
+        CompositionAuthorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for CompositionAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> CompositionAuthorSearchTarget {
+        CompositionAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for CompositionAuthorSearchTarget {
+    fn from(resource: resources::Patient) -> CompositionAuthorSearchTarget {
+        CompositionAuthorSearchTarget::Patient(resource)
+    }
+}
+/// The entry reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionEntrySearchReference;
+impl ReferenceSearchParameter for CompositionEntrySearchReference {
+    type Resource = resources::Composition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::Entry
+    }
+}
+/// The event-reference reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionEventReferenceSearchReference;
+impl ReferenceSearchParameter for CompositionEventReferenceSearchReference {
+    type Resource = resources::Composition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::EventReference
+    }
+}
+/// The related reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionRelatedSearchReference;
+impl ReferenceSearchParameter for CompositionRelatedSearchReference {
+    type Resource = resources::Composition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::Related
+    }
+}
+/// The subject reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionSubjectSearchReference;
+impl ReferenceSearchParameter for CompositionSubjectSearchReference {
+    type Resource = resources::Composition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::Subject
+    }
+}
+/// The derived-from reference search parameter of the ConceptMap resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConceptMapDerivedFromSearchReference;
+impl ReferenceSearchParameter for ConceptMapDerivedFromSearchReference {
+    type Resource = resources::ConceptMap;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ConceptMapSearchParameter {
+        ConceptMapSearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the ConceptMap resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConceptMapPredecessorSearchReference;
+impl ReferenceSearchParameter for ConceptMapPredecessorSearchReference {
+    type Resource = resources::ConceptMap;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ConceptMapSearchParameter {
+        ConceptMapSearchParameter::Predecessor
+    }
+}
+/// The other-map reference search parameter of the ConceptMap resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConceptMapOtherMapSearchReference;
+impl ReferenceSearchParameter for ConceptMapOtherMapSearchReference {
+    type Resource = resources::ConceptMap;
+    type Target = resources::ConceptMap;
+    fn parameter(&self) -> ConceptMapSearchParameter {
+        ConceptMapSearchParameter::OtherMap
+    }
+}
+/// The source-group-system reference search parameter of the ConceptMap resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConceptMapSourceGroupSystemSearchReference;
+impl ReferenceSearchParameter for ConceptMapSourceGroupSystemSearchReference {
+    type Resource = resources::ConceptMap;
+    type Target = resources::CodeSystem;
+    fn parameter(&self) -> ConceptMapSearchParameter {
+        ConceptMapSearchParameter::SourceGroupSystem
+    }
+}
+/// The source-scope reference search parameter of the ConceptMap resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConceptMapSourceScopeSearchReference;
+impl ReferenceSearchParameter for ConceptMapSourceScopeSearchReference {
+    type Resource = resources::ConceptMap;
+    type Target = resources::ValueSet;
+    fn parameter(&self) -> ConceptMapSearchParameter {
+        ConceptMapSearchParameter::SourceScope
+    }
+}
+/// The target-group-system reference search parameter of the ConceptMap resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConceptMapTargetGroupSystemSearchReference;
+impl ReferenceSearchParameter for ConceptMapTargetGroupSystemSearchReference {
+    type Resource = resources::ConceptMap;
+    type Target = resources::CodeSystem;
+    fn parameter(&self) -> ConceptMapSearchParameter {
+        ConceptMapSearchParameter::TargetGroupSystem
+    }
+}
+/// The target-scope reference search parameter of the ConceptMap resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConceptMapTargetScopeSearchReference;
+impl ReferenceSearchParameter for ConceptMapTargetScopeSearchReference {
+    type Resource = resources::ConceptMap;
+    type Target = resources::ValueSet;
+    fn parameter(&self) -> ConceptMapSearchParameter {
+        ConceptMapSearchParameter::TargetScope
+    }
+}
+/// The patient reference search parameter of the Condition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConditionPatientSearchReference;
+impl ReferenceSearchParameter for ConditionPatientSearchReference {
+    type Resource = resources::Condition;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ConditionSearchParameter {
+        ConditionSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the Condition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConditionEncounterSearchReference;
+impl ReferenceSearchParameter for ConditionEncounterSearchReference {
+    type Resource = resources::Condition;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ConditionSearchParameter {
+        ConditionSearchParameter::Encounter
+    }
+}
+/// The evidence-detail reference search parameter of the Condition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConditionEvidenceDetailSearchReference;
+impl ReferenceSearchParameter for ConditionEvidenceDetailSearchReference {
+    type Resource = resources::Condition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ConditionSearchParameter {
+        ConditionSearchParameter::EvidenceDetail
+    }
+}
+/// The participant-actor reference search parameter of the Condition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConditionParticipantActorSearchReference;
+impl ReferenceSearchParameter for ConditionParticipantActorSearchReference {
+    type Resource = resources::Condition;
+    type Target = ConditionParticipantActorSearchTarget;
+    fn parameter(&self) -> ConditionSearchParameter {
+        ConditionSearchParameter::ParticipantActor
+    }
+}
+/// Target resources of the participant-actor reference search parameter of the Condition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConditionParticipantActorSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Device> for ConditionParticipantActorSearchTarget {
+    fn from(resource: resources::Device) -> ConditionParticipantActorSearchTarget {
+        ConditionParticipantActorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for ConditionParticipantActorSearchTarget {
+    fn from(resource: resources::Organization) -> ConditionParticipantActorSearchTarget {
+        ConditionParticipantActorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for ConditionParticipantActorSearchTarget {
+    fn from(resource: resources::CareTeam) -> ConditionParticipantActorSearchTarget {
+        ConditionParticipantActorSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ConditionParticipantActorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ConditionParticipantActorSearchTarget {
+        ConditionParticipantActorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ConditionParticipantActorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ConditionParticipantActorSearchTarget {
+        ConditionParticipantActorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ConditionParticipantActorSearchTarget {
+    fn from(resource: resources::Practitioner) -> ConditionParticipantActorSearchTarget {
+        ConditionParticipantActorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ConditionParticipantActorSearchTarget {
+    fn from(resource: resources::Patient) -> ConditionParticipantActorSearchTarget {
+        ConditionParticipantActorSearchTarget::Patient(resource)
+    }
+}
+/// The subject reference search parameter of the Condition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConditionSubjectSearchReference;
+impl ReferenceSearchParameter for ConditionSubjectSearchReference {
+    type Resource = resources::Condition;
+    type Target = ConditionSubjectSearchTarget;
+    fn parameter(&self) -> ConditionSearchParameter {
+        ConditionSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Condition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConditionSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for ConditionSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ConditionSubjectSearchTarget {
+        ConditionSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for ConditionSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ConditionSubjectSearchTarget {
+        ConditionSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the Consent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsentPatientSearchReference;
+impl ReferenceSearchParameter for ConsentPatientSearchReference {
+    type Resource = resources::Consent;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ConsentSearchParameter {
+        ConsentSearchParameter::Patient
+    }
+}
+/// The actor reference search parameter of the Consent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsentActorSearchReference;
+impl ReferenceSearchParameter for ConsentActorSearchReference {
+    type Resource = resources::Consent;
+    type Target = ConsentActorSearchTarget;
+    fn parameter(&self) -> ConsentSearchParameter {
+        ConsentSearchParameter::Actor
+    }
+}
+/// Target resources of the actor reference search parameter of the Consent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConsentActorSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Device> for ConsentActorSearchTarget {
+    fn from(resource: resources::Device) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::CareTeam> for ConsentActorSearchTarget {
+    fn from(resource: resources::CareTeam) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Organization> for ConsentActorSearchTarget {
+    fn from(resource: resources::Organization) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ConsentActorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ConsentActorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Group> for ConsentActorSearchTarget {
+    fn from(resource: resources::Group) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Practitioner> for ConsentActorSearchTarget {
+    fn from(resource: resources::Practitioner) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ConsentActorSearchTarget {
+    fn from(resource: resources::Patient) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::Patient(resource)
+    }
+}
+/// The controller reference search parameter of the Consent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsentControllerSearchReference;
+impl ReferenceSearchParameter for ConsentControllerSearchReference {
+    type Resource = resources::Consent;
+    type Target = ConsentControllerSearchTarget;
+    fn parameter(&self) -> ConsentSearchParameter {
+        ConsentSearchParameter::Controller
+    }
+}
+/// Target resources of the controller reference search parameter of the Consent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConsentControllerSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for ConsentControllerSearchTarget {
+    fn from(resource: resources::HealthcareService) -> ConsentControllerSearchTarget {
+        ConsentControllerSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::Organization> for ConsentControllerSearchTarget {
+    fn from(resource: resources::Organization) -> ConsentControllerSearchTarget {
+        ConsentControllerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Practitioner> for ConsentControllerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ConsentControllerSearchTarget {
+        ConsentControllerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ConsentControllerSearchTarget {
+    fn from(resource: resources::Patient) -> ConsentControllerSearchTarget {
+        ConsentControllerSearchTarget::Patient(resource)
+    }
+}
+/// The data reference search parameter of the Consent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsentDataSearchReference;
+impl ReferenceSearchParameter for ConsentDataSearchReference {
+    type Resource = resources::Consent;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ConsentSearchParameter {
+        ConsentSearchParameter::Data
+    }
+}
+/// The grantee reference search parameter of the Consent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsentGranteeSearchReference;
+impl ReferenceSearchParameter for ConsentGranteeSearchReference {
+    type Resource = resources::Consent;
+    type Target = ConsentGranteeSearchTarget;
+    fn parameter(&self) -> ConsentSearchParameter {
+        ConsentSearchParameter::Grantee
+    }
+}
+/// Target resources of the grantee reference search parameter of the Consent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConsentGranteeSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for ConsentGranteeSearchTarget {
+    fn from(resource: resources::HealthcareService) -> ConsentGranteeSearchTarget {
+        ConsentGranteeSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::CareTeam> for ConsentGranteeSearchTarget {
+    fn from(resource: resources::CareTeam) -> ConsentGranteeSearchTarget {
+        ConsentGranteeSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Organization> for ConsentGranteeSearchTarget {
+    fn from(resource: resources::Organization) -> ConsentGranteeSearchTarget {
+        ConsentGranteeSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ConsentGranteeSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ConsentGranteeSearchTarget {
+        ConsentGranteeSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ConsentGranteeSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ConsentGranteeSearchTarget {
+        ConsentGranteeSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ConsentGranteeSearchTarget {
+    fn from(resource: resources::Practitioner) -> ConsentGranteeSearchTarget {
+        ConsentGranteeSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ConsentGranteeSearchTarget {
+    fn from(resource: resources::Patient) -> ConsentGranteeSearchTarget {
+        ConsentGranteeSearchTarget::Patient(resource)
+    }
+}
+/// The manager reference search parameter of the Consent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsentManagerSearchReference;
+impl ReferenceSearchParameter for ConsentManagerSearchReference {
+    type Resource = resources::Consent;
+    type Target = ConsentManagerSearchTarget;
+    fn parameter(&self) -> ConsentSearchParameter {
+        ConsentSearchParameter::Manager
+    }
+}
+/// Target resources of the manager reference search parameter of the Consent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConsentManagerSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for ConsentManagerSearchTarget {
+    fn from(resource: resources::HealthcareService) -> ConsentManagerSearchTarget {
+        ConsentManagerSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::Organization> for ConsentManagerSearchTarget {
+    fn from(resource: resources::Organization) -> ConsentManagerSearchTarget {
+        ConsentManagerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Practitioner> for ConsentManagerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ConsentManagerSearchTarget {
+        ConsentManagerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ConsentManagerSearchTarget {
+    fn from(resource: resources::Patient) -> ConsentManagerSearchTarget {
+        ConsentManagerSearchTarget::Patient(resource)
+    }
+}
+/// The source-reference reference search parameter of the Consent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsentSourceReferenceSearchReference;
+impl ReferenceSearchParameter for ConsentSourceReferenceSearchReference {
+    type Resource = resources::Consent;
+    type Target = ConsentSourceReferenceSearchTarget;
+    fn parameter(&self) -> ConsentSearchParameter {
+        ConsentSearchParameter::SourceReference
+    }
+}
+/// Target resources of the source-reference reference search parameter of the Consent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConsentSourceReferenceSearchTarget {
+    /// Variant for Consent target resources
+    Consent(resources::Consent),
+    /// Variant for DocumentReference target resources
+    DocumentReference(resources::DocumentReference),
+    /// Variant for Contract target resources
+    Contract(resources::Contract),
+    /// Variant for QuestionnaireResponse target resources
+    QuestionnaireResponse(resources::QuestionnaireResponse),
+}
+impl From<resources::Consent> for ConsentSourceReferenceSearchTarget {
+    fn from(resource: resources::Consent) -> ConsentSourceReferenceSearchTarget {
+        ConsentSourceReferenceSearchTarget::Consent(resource)
+    }
+}
+impl From<resources::DocumentReference> for ConsentSourceReferenceSearchTarget {
+    fn from(resource: resources::DocumentReference) -> ConsentSourceReferenceSearchTarget {
+        ConsentSourceReferenceSearchTarget::DocumentReference(resource)
+    }
+}
+impl From<resources::Contract> for ConsentSourceReferenceSearchTarget {
+    fn from(resource: resources::Contract) -> ConsentSourceReferenceSearchTarget {
+        ConsentSourceReferenceSearchTarget::Contract(resource)
+    }
+}
+impl From<resources::QuestionnaireResponse> for ConsentSourceReferenceSearchTarget {
+    fn from(resource: resources::QuestionnaireResponse) -> ConsentSourceReferenceSearchTarget {
+        ConsentSourceReferenceSearchTarget::QuestionnaireResponse(resource)
+    }
+}
+/// The subject reference search parameter of the Consent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsentSubjectSearchReference;
+impl ReferenceSearchParameter for ConsentSubjectSearchReference {
+    type Resource = resources::Consent;
+    type Target = ConsentSubjectSearchTarget;
+    fn parameter(&self) -> ConsentSearchParameter {
+        ConsentSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Consent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConsentSubjectSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Practitioner> for ConsentSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> ConsentSubjectSearchTarget {
+        ConsentSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for ConsentSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ConsentSubjectSearchTarget {
+        ConsentSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for ConsentSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ConsentSubjectSearchTarget {
+        ConsentSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the Contract resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContractPatientSearchReference;
+impl ReferenceSearchParameter for ContractPatientSearchReference {
+    type Resource = resources::Contract;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ContractSearchParameter {
+        ContractSearchParameter::Patient
+    }
+}
+/// The authority reference search parameter of the Contract resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContractAuthoritySearchReference;
+impl ReferenceSearchParameter for ContractAuthoritySearchReference {
+    type Resource = resources::Contract;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ContractSearchParameter {
+        ContractSearchParameter::Authority
+    }
+}
+/// The domain reference search parameter of the Contract resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContractDomainSearchReference;
+impl ReferenceSearchParameter for ContractDomainSearchReference {
+    type Resource = resources::Contract;
+    type Target = resources::Location;
+    fn parameter(&self) -> ContractSearchParameter {
+        ContractSearchParameter::Domain
+    }
+}
+/// The signer reference search parameter of the Contract resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContractSignerSearchReference;
+impl ReferenceSearchParameter for ContractSignerSearchReference {
+    type Resource = resources::Contract;
+    type Target = ContractSignerSearchTarget;
+    fn parameter(&self) -> ContractSearchParameter {
+        ContractSearchParameter::Signer
+    }
+}
+/// Target resources of the signer reference search parameter of the Contract resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ContractSignerSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for ContractSignerSearchTarget {
+    fn from(resource: resources::Organization) -> ContractSignerSearchTarget {
+        ContractSignerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ContractSignerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ContractSignerSearchTarget {
+        ContractSignerSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ContractSignerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ContractSignerSearchTarget {
+        ContractSignerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ContractSignerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ContractSignerSearchTarget {
+        ContractSignerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ContractSignerSearchTarget {
+    fn from(resource: resources::Patient) -> ContractSignerSearchTarget {
+        ContractSignerSearchTarget::Patient(resource)
+    }
+}
+/// The subject reference search parameter of the Contract resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContractSubjectSearchReference;
+impl ReferenceSearchParameter for ContractSubjectSearchReference {
+    type Resource = resources::Contract;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ContractSearchParameter {
+        ContractSearchParameter::Subject
+    }
+}
+/// The patient reference search parameter of the Coverage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoveragePatientSearchReference;
+impl ReferenceSearchParameter for CoveragePatientSearchReference {
+    type Resource = resources::Coverage;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CoverageSearchParameter {
+        CoverageSearchParameter::Patient
+    }
+}
+/// The beneficiary reference search parameter of the Coverage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageBeneficiarySearchReference;
+impl ReferenceSearchParameter for CoverageBeneficiarySearchReference {
+    type Resource = resources::Coverage;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CoverageSearchParameter {
+        CoverageSearchParameter::Beneficiary
+    }
+}
+/// The insurer reference search parameter of the Coverage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageInsurerSearchReference;
+impl ReferenceSearchParameter for CoverageInsurerSearchReference {
+    type Resource = resources::Coverage;
+    type Target = resources::Organization;
+    fn parameter(&self) -> CoverageSearchParameter {
+        CoverageSearchParameter::Insurer
+    }
+}
+/// The paymentby-party reference search parameter of the Coverage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoveragePaymentbyPartySearchReference;
+impl ReferenceSearchParameter for CoveragePaymentbyPartySearchReference {
+    type Resource = resources::Coverage;
+    type Target = CoveragePaymentbyPartySearchTarget;
+    fn parameter(&self) -> CoverageSearchParameter {
+        CoverageSearchParameter::PaymentbyParty
+    }
+}
+/// Target resources of the paymentby-party reference search parameter of the Coverage resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CoveragePaymentbyPartySearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for CoveragePaymentbyPartySearchTarget {
+    fn from(resource: resources::Organization) -> CoveragePaymentbyPartySearchTarget {
+        CoveragePaymentbyPartySearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CoveragePaymentbyPartySearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CoveragePaymentbyPartySearchTarget {
+        CoveragePaymentbyPartySearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::Patient> for CoveragePaymentbyPartySearchTarget {
+    fn from(resource: resources::Patient) -> CoveragePaymentbyPartySearchTarget {
+        CoveragePaymentbyPartySearchTarget::Patient(resource)
+    }
+}
+/// The policy-holder reference search parameter of the Coverage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoveragePolicyHolderSearchReference;
+impl ReferenceSearchParameter for CoveragePolicyHolderSearchReference {
+    type Resource = resources::Coverage;
+    type Target = CoveragePolicyHolderSearchTarget;
+    fn parameter(&self) -> CoverageSearchParameter {
+        CoverageSearchParameter::PolicyHolder
+    }
+}
+/// Target resources of the policy-holder reference search parameter of the Coverage resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CoveragePolicyHolderSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for CoveragePolicyHolderSearchTarget {
+    fn from(resource: resources::Organization) -> CoveragePolicyHolderSearchTarget {
+        CoveragePolicyHolderSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CoveragePolicyHolderSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CoveragePolicyHolderSearchTarget {
+        CoveragePolicyHolderSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::Patient> for CoveragePolicyHolderSearchTarget {
+    fn from(resource: resources::Patient) -> CoveragePolicyHolderSearchTarget {
+        CoveragePolicyHolderSearchTarget::Patient(resource)
+    }
+}
+/// The subscriber reference search parameter of the Coverage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageSubscriberSearchReference;
+impl ReferenceSearchParameter for CoverageSubscriberSearchReference {
+    type Resource = resources::Coverage;
+    type Target = CoverageSubscriberSearchTarget;
+    fn parameter(&self) -> CoverageSearchParameter {
+        CoverageSearchParameter::Subscriber
+    }
+}
+/// Target resources of the subscriber reference search parameter of the Coverage resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CoverageSubscriberSearchTarget {
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::RelatedPerson> for CoverageSubscriberSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CoverageSubscriberSearchTarget {
+        CoverageSubscriberSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::Patient> for CoverageSubscriberSearchTarget {
+    fn from(resource: resources::Patient) -> CoverageSubscriberSearchTarget {
+        CoverageSubscriberSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the CoverageEligibilityRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageEligibilityRequestPatientSearchReference;
+impl ReferenceSearchParameter for CoverageEligibilityRequestPatientSearchReference {
+    type Resource = resources::CoverageEligibilityRequest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CoverageEligibilityRequestSearchParameter {
+        CoverageEligibilityRequestSearchParameter::Patient
+    }
+}
+/// The enterer reference search parameter of the CoverageEligibilityRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageEligibilityRequestEntererSearchReference;
+impl ReferenceSearchParameter for CoverageEligibilityRequestEntererSearchReference {
+    type Resource = resources::CoverageEligibilityRequest;
+    type Target = CoverageEligibilityRequestEntererSearchTarget;
+    fn parameter(&self) -> CoverageEligibilityRequestSearchParameter {
+        CoverageEligibilityRequestSearchParameter::Enterer
+    }
+}
+/// Target resources of the enterer reference search parameter of the CoverageEligibilityRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CoverageEligibilityRequestEntererSearchTarget {
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::PractitionerRole> for CoverageEligibilityRequestEntererSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CoverageEligibilityRequestEntererSearchTarget {
+        CoverageEligibilityRequestEntererSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for CoverageEligibilityRequestEntererSearchTarget {
+    fn from(resource: resources::Practitioner) -> CoverageEligibilityRequestEntererSearchTarget {
+        CoverageEligibilityRequestEntererSearchTarget::Practitioner(resource)
+    }
+}
+/// The facility reference search parameter of the CoverageEligibilityRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageEligibilityRequestFacilitySearchReference;
+impl ReferenceSearchParameter for CoverageEligibilityRequestFacilitySearchReference {
+    type Resource = resources::CoverageEligibilityRequest;
+    type Target = resources::Location;
+    fn parameter(&self) -> CoverageEligibilityRequestSearchParameter {
+        CoverageEligibilityRequestSearchParameter::Facility
+    }
+}
+/// The provider reference search parameter of the CoverageEligibilityRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageEligibilityRequestProviderSearchReference;
+impl ReferenceSearchParameter for CoverageEligibilityRequestProviderSearchReference {
+    type Resource = resources::CoverageEligibilityRequest;
+    type Target = CoverageEligibilityRequestProviderSearchTarget;
+    fn parameter(&self) -> CoverageEligibilityRequestSearchParameter {
+        CoverageEligibilityRequestSearchParameter::Provider
+    }
+}
+/// Target resources of the provider reference search parameter of the CoverageEligibilityRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CoverageEligibilityRequestProviderSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for CoverageEligibilityRequestProviderSearchTarget {
+    fn from(resource: resources::Organization) -> CoverageEligibilityRequestProviderSearchTarget {
+        CoverageEligibilityRequestProviderSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CoverageEligibilityRequestProviderSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CoverageEligibilityRequestProviderSearchTarget {
+        CoverageEligibilityRequestProviderSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for CoverageEligibilityRequestProviderSearchTarget {
+    fn from(resource: resources::Practitioner) -> CoverageEligibilityRequestProviderSearchTarget {
+        CoverageEligibilityRequestProviderSearchTarget::Practitioner(resource)
+    }
+}
+/// The patient reference search parameter of the CoverageEligibilityResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageEligibilityResponsePatientSearchReference;
+impl ReferenceSearchParameter for CoverageEligibilityResponsePatientSearchReference {
+    type Resource = resources::CoverageEligibilityResponse;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CoverageEligibilityResponseSearchParameter {
+        CoverageEligibilityResponseSearchParameter::Patient
+    }
+}
+/// The insurer reference search parameter of the CoverageEligibilityResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageEligibilityResponseInsurerSearchReference;
+impl ReferenceSearchParameter for CoverageEligibilityResponseInsurerSearchReference {
+    type Resource = resources::CoverageEligibilityResponse;
+    type Target = resources::Organization;
+    fn parameter(&self) -> CoverageEligibilityResponseSearchParameter {
+        CoverageEligibilityResponseSearchParameter::Insurer
+    }
+}
+/// The request reference search parameter of the CoverageEligibilityResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageEligibilityResponseRequestSearchReference;
+impl ReferenceSearchParameter for CoverageEligibilityResponseRequestSearchReference {
+    type Resource = resources::CoverageEligibilityResponse;
+    type Target = resources::CoverageEligibilityRequest;
+    fn parameter(&self) -> CoverageEligibilityResponseSearchParameter {
+        CoverageEligibilityResponseSearchParameter::Request
+    }
+}
+/// The requestor reference search parameter of the CoverageEligibilityResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageEligibilityResponseRequestorSearchReference;
+impl ReferenceSearchParameter for CoverageEligibilityResponseRequestorSearchReference {
+    type Resource = resources::CoverageEligibilityResponse;
+    type Target = CoverageEligibilityResponseRequestorSearchTarget;
+    fn parameter(&self) -> CoverageEligibilityResponseSearchParameter {
+        CoverageEligibilityResponseSearchParameter::Requestor
+    }
+}
+/// Target resources of the requestor reference search parameter of the CoverageEligibilityResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CoverageEligibilityResponseRequestorSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for CoverageEligibilityResponseRequestorSearchTarget {
+    fn from(resource: resources::Organization) -> CoverageEligibilityResponseRequestorSearchTarget {
+        CoverageEligibilityResponseRequestorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for CoverageEligibilityResponseRequestorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> CoverageEligibilityResponseRequestorSearchTarget {
+        CoverageEligibilityResponseRequestorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for CoverageEligibilityResponseRequestorSearchTarget {
+    fn from(resource: resources::Practitioner) -> CoverageEligibilityResponseRequestorSearchTarget {
+        CoverageEligibilityResponseRequestorSearchTarget::Practitioner(resource)
+    }
+}
+/// The patient reference search parameter of the DetectedIssue resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DetectedIssuePatientSearchReference;
+impl ReferenceSearchParameter for DetectedIssuePatientSearchReference {
+    type Resource = resources::DetectedIssue;
+    type Target = resources::Patient;
+    fn parameter(&self) -> DetectedIssueSearchParameter {
+        DetectedIssueSearchParameter::Patient
+    }
+}
+/// The author reference search parameter of the DetectedIssue resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DetectedIssueAuthorSearchReference;
+impl ReferenceSearchParameter for DetectedIssueAuthorSearchReference {
+    type Resource = resources::DetectedIssue;
+    type Target = DetectedIssueAuthorSearchTarget;
+    fn parameter(&self) -> DetectedIssueSearchParameter {
+        DetectedIssueSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the DetectedIssue resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DetectedIssueAuthorSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Device> for DetectedIssueAuthorSearchTarget {
+    fn from(resource: resources::Device) -> DetectedIssueAuthorSearchTarget {
+        DetectedIssueAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::RelatedPerson> for DetectedIssueAuthorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> DetectedIssueAuthorSearchTarget {
+        DetectedIssueAuthorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for DetectedIssueAuthorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> DetectedIssueAuthorSearchTarget {
+        DetectedIssueAuthorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for DetectedIssueAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> DetectedIssueAuthorSearchTarget {
+        DetectedIssueAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for DetectedIssueAuthorSearchTarget {
+    fn from(resource: resources::Patient) -> DetectedIssueAuthorSearchTarget {
+        DetectedIssueAuthorSearchTarget::Patient(resource)
+    }
+}
+/// The implicated reference search parameter of the DetectedIssue resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DetectedIssueImplicatedSearchReference;
+impl ReferenceSearchParameter for DetectedIssueImplicatedSearchReference {
+    type Resource = resources::DetectedIssue;
+    type Target = resources::Resource;
+    fn parameter(&self) -> DetectedIssueSearchParameter {
+        DetectedIssueSearchParameter::Implicated
+    }
+}
+/// The subject reference search parameter of the DetectedIssue resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DetectedIssueSubjectSearchReference;
+impl ReferenceSearchParameter for DetectedIssueSubjectSearchReference {
+    type Resource = resources::DetectedIssue;
+    type Target = DetectedIssueSubjectSearchTarget;
+    fn parameter(&self) -> DetectedIssueSearchParameter {
+        DetectedIssueSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the DetectedIssue resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DetectedIssueSubjectSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for NutritionProduct target resources
+    NutritionProduct(resources::NutritionProduct),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for BiologicallyDerivedProduct target resources
+    BiologicallyDerivedProduct(resources::BiologicallyDerivedProduct),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+}
+impl From<resources::Device> for DetectedIssueSubjectSearchTarget {
+    fn from(resource: resources::Device) -> DetectedIssueSubjectSearchTarget {
+        DetectedIssueSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for DetectedIssueSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> DetectedIssueSubjectSearchTarget {
+        DetectedIssueSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Procedure> for DetectedIssueSubjectSearchTarget {
+    fn from(resource: resources::Procedure) -> DetectedIssueSubjectSearchTarget {
+        DetectedIssueSubjectSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::NutritionProduct> for DetectedIssueSubjectSearchTarget {
+    fn from(resource: resources::NutritionProduct) -> DetectedIssueSubjectSearchTarget {
+        DetectedIssueSubjectSearchTarget::NutritionProduct(resource)
+    }
+}
+impl From<resources::Group> for DetectedIssueSubjectSearchTarget {
+    fn from(resource: resources::Group) -> DetectedIssueSubjectSearchTarget {
+        DetectedIssueSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Practitioner> for DetectedIssueSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> DetectedIssueSubjectSearchTarget {
+        DetectedIssueSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::BiologicallyDerivedProduct> for DetectedIssueSubjectSearchTarget {
+    fn from(resource: resources::BiologicallyDerivedProduct) -> DetectedIssueSubjectSearchTarget {
+        DetectedIssueSubjectSearchTarget::BiologicallyDerivedProduct(resource)
+    }
+}
+impl From<resources::Substance> for DetectedIssueSubjectSearchTarget {
+    fn from(resource: resources::Substance) -> DetectedIssueSubjectSearchTarget {
+        DetectedIssueSubjectSearchTarget::Substance(resource)
+    }
+}
+impl From<resources::Location> for DetectedIssueSubjectSearchTarget {
+    fn from(resource: resources::Location) -> DetectedIssueSubjectSearchTarget {
+        DetectedIssueSubjectSearchTarget::Location(resource)
+    }
+}
+impl From<resources::Patient> for DetectedIssueSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> DetectedIssueSubjectSearchTarget {
+        DetectedIssueSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Medication> for DetectedIssueSubjectSearchTarget {
+    fn from(resource: resources::Medication) -> DetectedIssueSubjectSearchTarget {
+        DetectedIssueSubjectSearchTarget::Medication(resource)
+    }
+}
+/// The definition reference search parameter of the Device resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceDefinitionSearchReference;
+impl ReferenceSearchParameter for DeviceDefinitionSearchReference {
+    type Resource = resources::Device;
+    type Target = resources::DeviceDefinition;
+    fn parameter(&self) -> DeviceSearchParameter {
+        DeviceSearchParameter::Definition
+    }
+}
+/// The location reference search parameter of the Device resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceLocationSearchReference;
+impl ReferenceSearchParameter for DeviceLocationSearchReference {
+    type Resource = resources::Device;
+    type Target = resources::Location;
+    fn parameter(&self) -> DeviceSearchParameter {
+        DeviceSearchParameter::Location
+    }
+}
+/// The organization reference search parameter of the Device resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceOrganizationSearchReference;
+impl ReferenceSearchParameter for DeviceOrganizationSearchReference {
+    type Resource = resources::Device;
+    type Target = resources::Organization;
+    fn parameter(&self) -> DeviceSearchParameter {
+        DeviceSearchParameter::Organization
+    }
+}
+/// The parent reference search parameter of the Device resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceParentSearchReference;
+impl ReferenceSearchParameter for DeviceParentSearchReference {
+    type Resource = resources::Device;
+    type Target = resources::Device;
+    fn parameter(&self) -> DeviceSearchParameter {
+        DeviceSearchParameter::Parent
+    }
+}
+/// The device reference search parameter of the DeviceAssociation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceAssociationDeviceSearchReference;
+impl ReferenceSearchParameter for DeviceAssociationDeviceSearchReference {
+    type Resource = resources::DeviceAssociation;
+    type Target = resources::Device;
+    fn parameter(&self) -> DeviceAssociationSearchParameter {
+        DeviceAssociationSearchParameter::Device
+    }
+}
+/// The operator reference search parameter of the DeviceAssociation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceAssociationOperatorSearchReference;
+impl ReferenceSearchParameter for DeviceAssociationOperatorSearchReference {
+    type Resource = resources::DeviceAssociation;
+    type Target = DeviceAssociationOperatorSearchTarget;
+    fn parameter(&self) -> DeviceAssociationSearchParameter {
+        DeviceAssociationSearchParameter::Operator
+    }
+}
+/// Target resources of the operator reference search parameter of the DeviceAssociation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceAssociationOperatorSearchTarget {
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::RelatedPerson> for DeviceAssociationOperatorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> DeviceAssociationOperatorSearchTarget {
+        DeviceAssociationOperatorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::Practitioner> for DeviceAssociationOperatorSearchTarget {
+    fn from(resource: resources::Practitioner) -> DeviceAssociationOperatorSearchTarget {
+        DeviceAssociationOperatorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for DeviceAssociationOperatorSearchTarget {
+    fn from(resource: resources::Patient) -> DeviceAssociationOperatorSearchTarget {
+        DeviceAssociationOperatorSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the DeviceAssociation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceAssociationPatientSearchReference;
+impl ReferenceSearchParameter for DeviceAssociationPatientSearchReference {
+    type Resource = resources::DeviceAssociation;
+    type Target = resources::Patient;
+    fn parameter(&self) -> DeviceAssociationSearchParameter {
+        DeviceAssociationSearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the DeviceAssociation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceAssociationSubjectSearchReference;
+impl ReferenceSearchParameter for DeviceAssociationSubjectSearchReference {
+    type Resource = resources::DeviceAssociation;
+    type Target = resources::Patient;
+    fn parameter(&self) -> DeviceAssociationSearchParameter {
+        DeviceAssociationSearchParameter::Subject
+    }
+}
+/// The manufacturer reference search parameter of the DeviceDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceDefinitionManufacturerSearchReference;
+impl ReferenceSearchParameter for DeviceDefinitionManufacturerSearchReference {
+    type Resource = resources::DeviceDefinition;
+    type Target = resources::Organization;
+    fn parameter(&self) -> DeviceDefinitionSearchParameter {
+        DeviceDefinitionSearchParameter::Manufacturer
+    }
+}
+/// The organization reference search parameter of the DeviceDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceDefinitionOrganizationSearchReference;
+impl ReferenceSearchParameter for DeviceDefinitionOrganizationSearchReference {
+    type Resource = resources::DeviceDefinition;
+    type Target = resources::Organization;
+    fn parameter(&self) -> DeviceDefinitionSearchParameter {
+        DeviceDefinitionSearchParameter::Organization
+    }
+}
+/// The patient reference search parameter of the DeviceDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceDispensePatientSearchReference;
+impl ReferenceSearchParameter for DeviceDispensePatientSearchReference {
+    type Resource = resources::DeviceDispense;
+    type Target = resources::Patient;
+    fn parameter(&self) -> DeviceDispenseSearchParameter {
+        DeviceDispenseSearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the DeviceDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceDispenseSubjectSearchReference;
+impl ReferenceSearchParameter for DeviceDispenseSubjectSearchReference {
+    type Resource = resources::DeviceDispense;
+    type Target = DeviceDispenseSubjectSearchTarget;
+    fn parameter(&self) -> DeviceDispenseSearchParameter {
+        DeviceDispenseSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the DeviceDispense resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceDispenseSubjectSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Practitioner> for DeviceDispenseSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> DeviceDispenseSubjectSearchTarget {
+        DeviceDispenseSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for DeviceDispenseSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> DeviceDispenseSubjectSearchTarget {
+        DeviceDispenseSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The device reference search parameter of the DeviceMetric resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceMetricDeviceSearchReference;
+impl ReferenceSearchParameter for DeviceMetricDeviceSearchReference {
+    type Resource = resources::DeviceMetric;
+    type Target = resources::Device;
+    fn parameter(&self) -> DeviceMetricSearchParameter {
+        DeviceMetricSearchParameter::Device
+    }
+}
+/// The patient reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestPatientSearchReference;
+impl ReferenceSearchParameter for DeviceRequestPatientSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestEncounterSearchReference;
+impl ReferenceSearchParameter for DeviceRequestEncounterSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Encounter
+    }
+}
+/// The based-on reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestBasedOnSearchReference;
+impl ReferenceSearchParameter for DeviceRequestBasedOnSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = resources::Resource;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::BasedOn
+    }
+}
+/// The device reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestDeviceSearchReference;
+impl ReferenceSearchParameter for DeviceRequestDeviceSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = DeviceRequestDeviceSearchTarget;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Device
+    }
+}
+/// Target resources of the device reference search parameter of the DeviceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceRequestDeviceSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for DeviceDefinition target resources
+    DeviceDefinition(resources::DeviceDefinition),
+}
+impl From<resources::Device> for DeviceRequestDeviceSearchTarget {
+    fn from(resource: resources::Device) -> DeviceRequestDeviceSearchTarget {
+        DeviceRequestDeviceSearchTarget::Device(resource)
+    }
+}
+impl From<resources::DeviceDefinition> for DeviceRequestDeviceSearchTarget {
+    fn from(resource: resources::DeviceDefinition) -> DeviceRequestDeviceSearchTarget {
+        DeviceRequestDeviceSearchTarget::DeviceDefinition(resource)
+    }
+}
+/// The instantiates-canonical reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestInstantiatesCanonicalSearchReference;
+impl ReferenceSearchParameter for DeviceRequestInstantiatesCanonicalSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = DeviceRequestInstantiatesCanonicalSearchTarget;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::InstantiatesCanonical
+    }
+}
+/// Target resources of the instantiates-canonical reference search parameter of the DeviceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceRequestInstantiatesCanonicalSearchTarget {
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+}
+impl From<resources::ActivityDefinition> for DeviceRequestInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> DeviceRequestInstantiatesCanonicalSearchTarget {
+        DeviceRequestInstantiatesCanonicalSearchTarget::ActivityDefinition(resource)
+    }
+}
+impl From<resources::PlanDefinition> for DeviceRequestInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> DeviceRequestInstantiatesCanonicalSearchTarget {
+        DeviceRequestInstantiatesCanonicalSearchTarget::PlanDefinition(resource)
+    }
+}
+/// The insurance reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestInsuranceSearchReference;
+impl ReferenceSearchParameter for DeviceRequestInsuranceSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = DeviceRequestInsuranceSearchTarget;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Insurance
+    }
+}
+/// Target resources of the insurance reference search parameter of the DeviceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceRequestInsuranceSearchTarget {
+    /// Variant for ClaimResponse target resources
+    ClaimResponse(resources::ClaimResponse),
+    /// Variant for Coverage target resources
+    Coverage(resources::Coverage),
+}
+impl From<resources::ClaimResponse> for DeviceRequestInsuranceSearchTarget {
+    fn from(resource: resources::ClaimResponse) -> DeviceRequestInsuranceSearchTarget {
+        DeviceRequestInsuranceSearchTarget::ClaimResponse(resource)
+    }
+}
+impl From<resources::Coverage> for DeviceRequestInsuranceSearchTarget {
+    fn from(resource: resources::Coverage) -> DeviceRequestInsuranceSearchTarget {
+        DeviceRequestInsuranceSearchTarget::Coverage(resource)
+    }
+}
+/// The performer reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestPerformerSearchReference;
+impl ReferenceSearchParameter for DeviceRequestPerformerSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = DeviceRequestPerformerSearchTarget;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the DeviceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceRequestPerformerSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::HealthcareService) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::Organization> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::Organization) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::CareTeam) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::Device) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::RelatedPerson> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::Patient) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::Patient(resource)
+    }
+}
+/// The prior-request reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestPriorRequestSearchReference;
+impl ReferenceSearchParameter for DeviceRequestPriorRequestSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = resources::DeviceRequest;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::PriorRequest
+    }
+}
+/// The requester reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestRequesterSearchReference;
+impl ReferenceSearchParameter for DeviceRequestRequesterSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = DeviceRequestRequesterSearchTarget;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Requester
+    }
+}
+/// Target resources of the requester reference search parameter of the DeviceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceRequestRequesterSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Device> for DeviceRequestRequesterSearchTarget {
+    fn from(resource: resources::Device) -> DeviceRequestRequesterSearchTarget {
+        DeviceRequestRequesterSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for DeviceRequestRequesterSearchTarget {
+    fn from(resource: resources::Organization) -> DeviceRequestRequesterSearchTarget {
+        DeviceRequestRequesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for DeviceRequestRequesterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> DeviceRequestRequesterSearchTarget {
+        DeviceRequestRequesterSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for DeviceRequestRequesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> DeviceRequestRequesterSearchTarget {
+        DeviceRequestRequesterSearchTarget::Practitioner(resource)
+    }
+}
+/// The subject reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestSubjectSearchReference;
+impl ReferenceSearchParameter for DeviceRequestSubjectSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = DeviceRequestSubjectSearchTarget;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the DeviceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceRequestSubjectSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Device> for DeviceRequestSubjectSearchTarget {
+    fn from(resource: resources::Device) -> DeviceRequestSubjectSearchTarget {
+        DeviceRequestSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Group> for DeviceRequestSubjectSearchTarget {
+    fn from(resource: resources::Group) -> DeviceRequestSubjectSearchTarget {
+        DeviceRequestSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Location> for DeviceRequestSubjectSearchTarget {
+    fn from(resource: resources::Location) -> DeviceRequestSubjectSearchTarget {
+        DeviceRequestSubjectSearchTarget::Location(resource)
+    }
+}
+impl From<resources::Patient> for DeviceRequestSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> DeviceRequestSubjectSearchTarget {
+        DeviceRequestSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the DeviceUsage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceUsagePatientSearchReference;
+impl ReferenceSearchParameter for DeviceUsagePatientSearchReference {
+    type Resource = resources::DeviceUsage;
+    type Target = resources::Patient;
+    fn parameter(&self) -> DeviceUsageSearchParameter {
+        DeviceUsageSearchParameter::Patient
+    }
+}
+/// The patient reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportPatientSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportPatientSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = resources::Patient;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportEncounterSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportEncounterSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Encounter
+    }
+}
+/// The based-on reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportBasedOnSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportBasedOnSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = DiagnosticReportBasedOnSearchTarget;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the DiagnosticReport resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DiagnosticReportBasedOnSearchTarget {
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for MedicationRequest target resources
+    MedicationRequest(resources::MedicationRequest),
+    /// Variant for ImmunizationRecommendation target resources
+    ImmunizationRecommendation(resources::ImmunizationRecommendation),
+    /// Variant for NutritionOrder target resources
+    NutritionOrder(resources::NutritionOrder),
+}
+impl From<resources::ServiceRequest> for DiagnosticReportBasedOnSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> DiagnosticReportBasedOnSearchTarget {
+        DiagnosticReportBasedOnSearchTarget::ServiceRequest(resource)
+    }
+}
+impl From<resources::CarePlan> for DiagnosticReportBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> DiagnosticReportBasedOnSearchTarget {
+        DiagnosticReportBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::MedicationRequest> for DiagnosticReportBasedOnSearchTarget {
+    fn from(resource: resources::MedicationRequest) -> DiagnosticReportBasedOnSearchTarget {
+        DiagnosticReportBasedOnSearchTarget::MedicationRequest(resource)
+    }
+}
+impl From<resources::ImmunizationRecommendation> for DiagnosticReportBasedOnSearchTarget {
+    fn from(resource: resources::ImmunizationRecommendation) -> DiagnosticReportBasedOnSearchTarget {
+        DiagnosticReportBasedOnSearchTarget::ImmunizationRecommendation(resource)
+    }
+}
+impl From<resources::NutritionOrder> for DiagnosticReportBasedOnSearchTarget {
+    fn from(resource: resources::NutritionOrder) -> DiagnosticReportBasedOnSearchTarget {
+        DiagnosticReportBasedOnSearchTarget::NutritionOrder(resource)
+    }
+}
+/// The media reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportMediaSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportMediaSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = resources::DocumentReference;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Media
+    }
+}
+/// The performer reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportPerformerSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportPerformerSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = DiagnosticReportPerformerSearchTarget;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the DiagnosticReport resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DiagnosticReportPerformerSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for DiagnosticReportPerformerSearchTarget {
+    fn from(resource: resources::Organization) -> DiagnosticReportPerformerSearchTarget {
+        DiagnosticReportPerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for DiagnosticReportPerformerSearchTarget {
+    fn from(resource: resources::CareTeam) -> DiagnosticReportPerformerSearchTarget {
+        DiagnosticReportPerformerSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::PractitionerRole> for DiagnosticReportPerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> DiagnosticReportPerformerSearchTarget {
+        DiagnosticReportPerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for DiagnosticReportPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> DiagnosticReportPerformerSearchTarget {
+        DiagnosticReportPerformerSearchTarget::Practitioner(resource)
+    }
+}
+/// The result reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportResultSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportResultSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = resources::Observation;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Result
+    }
+}
+/// The results-interpreter reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportResultsInterpreterSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportResultsInterpreterSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = DiagnosticReportResultsInterpreterSearchTarget;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::ResultsInterpreter
+    }
+}
+/// Target resources of the results-interpreter reference search parameter of the DiagnosticReport resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DiagnosticReportResultsInterpreterSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for DiagnosticReportResultsInterpreterSearchTarget {
+    fn from(resource: resources::Organization) -> DiagnosticReportResultsInterpreterSearchTarget {
+        DiagnosticReportResultsInterpreterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for DiagnosticReportResultsInterpreterSearchTarget {
+    fn from(resource: resources::CareTeam) -> DiagnosticReportResultsInterpreterSearchTarget {
+        DiagnosticReportResultsInterpreterSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::PractitionerRole> for DiagnosticReportResultsInterpreterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> DiagnosticReportResultsInterpreterSearchTarget {
+        DiagnosticReportResultsInterpreterSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for DiagnosticReportResultsInterpreterSearchTarget {
+    fn from(resource: resources::Practitioner) -> DiagnosticReportResultsInterpreterSearchTarget {
+        DiagnosticReportResultsInterpreterSearchTarget::Practitioner(resource)
+    }
+}
+/// The specimen reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportSpecimenSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportSpecimenSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = resources::Specimen;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Specimen
+    }
+}
+/// The study reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportStudySearchReference;
+impl ReferenceSearchParameter for DiagnosticReportStudySearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = DiagnosticReportStudySearchTarget;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Study
+    }
+}
+/// Target resources of the study reference search parameter of the DiagnosticReport resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DiagnosticReportStudySearchTarget {
+    /// Variant for ImagingStudy target resources
+    ImagingStudy(resources::ImagingStudy),
+    /// Variant for GenomicStudy target resources
+    GenomicStudy(resources::GenomicStudy),
+}
+impl From<resources::ImagingStudy> for DiagnosticReportStudySearchTarget {
+    fn from(resource: resources::ImagingStudy) -> DiagnosticReportStudySearchTarget {
+        DiagnosticReportStudySearchTarget::ImagingStudy(resource)
+    }
+}
+impl From<resources::GenomicStudy> for DiagnosticReportStudySearchTarget {
+    fn from(resource: resources::GenomicStudy) -> DiagnosticReportStudySearchTarget {
+        DiagnosticReportStudySearchTarget::GenomicStudy(resource)
+    }
+}
+/// The subject reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportSubjectSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportSubjectSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = DiagnosticReportSubjectSearchTarget;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the DiagnosticReport resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DiagnosticReportSubjectSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for BiologicallyDerivedProduct target resources
+    BiologicallyDerivedProduct(resources::BiologicallyDerivedProduct),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+}
+impl From<resources::Device> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Device) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Group> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Group) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Practitioner> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::BiologicallyDerivedProduct> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::BiologicallyDerivedProduct) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::BiologicallyDerivedProduct(resource)
+    }
+}
+impl From<resources::Substance> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Substance) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Substance(resource)
+    }
+}
+impl From<resources::Location> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Location) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Location(resource)
+    }
+}
+impl From<resources::Patient> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Medication> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Medication) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Medication(resource)
+    }
+}
+/// The patient reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferencePatientSearchReference;
+impl ReferenceSearchParameter for DocumentReferencePatientSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = resources::Patient;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Patient
+    }
+}
+/// The attester reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceAttesterSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceAttesterSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = DocumentReferenceAttesterSearchTarget;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Attester
+    }
+}
+/// Target resources of the attester reference search parameter of the DocumentReference resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentReferenceAttesterSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for DocumentReferenceAttesterSearchTarget {
+    fn from(resource: resources::Organization) -> DocumentReferenceAttesterSearchTarget {
+        DocumentReferenceAttesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for DocumentReferenceAttesterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> DocumentReferenceAttesterSearchTarget {
+        DocumentReferenceAttesterSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for DocumentReferenceAttesterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> DocumentReferenceAttesterSearchTarget {
+        DocumentReferenceAttesterSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for DocumentReferenceAttesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> DocumentReferenceAttesterSearchTarget {
+        DocumentReferenceAttesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for DocumentReferenceAttesterSearchTarget {
+    fn from(resource: resources::Patient) -> DocumentReferenceAttesterSearchTarget {
+        DocumentReferenceAttesterSearchTarget::Patient(resource)
+    }
+}
+/// The author reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceAuthorSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceAuthorSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = DocumentReferenceAuthorSearchTarget;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the DocumentReference resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentReferenceAuthorSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for DocumentReferenceAuthorSearchTarget {
+    fn from(resource: resources::Organization) -> DocumentReferenceAuthorSearchTarget {
+        DocumentReferenceAuthorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for DocumentReferenceAuthorSearchTarget {
+    fn from(resource: resources::Device) -> DocumentReferenceAuthorSearchTarget {
+        DocumentReferenceAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::CareTeam> for DocumentReferenceAuthorSearchTarget {
+    fn from(resource: resources::CareTeam) -> DocumentReferenceAuthorSearchTarget {
+        DocumentReferenceAuthorSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::RelatedPerson> for DocumentReferenceAuthorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> DocumentReferenceAuthorSearchTarget {
+        DocumentReferenceAuthorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for DocumentReferenceAuthorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> DocumentReferenceAuthorSearchTarget {
+        DocumentReferenceAuthorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for DocumentReferenceAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> DocumentReferenceAuthorSearchTarget {
+        DocumentReferenceAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for DocumentReferenceAuthorSearchTarget {
+    fn from(resource: resources::Patient) -> DocumentReferenceAuthorSearchTarget {
+        DocumentReferenceAuthorSearchTarget::Patient(resource)
+    }
+}
+/// The based-on reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceBasedOnSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceBasedOnSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = DocumentReferenceBasedOnSearchTarget;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the DocumentReference resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentReferenceBasedOnSearchTarget {
+    /// Variant for Claim target resources
+    Claim(resources::Claim),
+    /// Variant for RequestOrchestration target resources
+    RequestOrchestration(resources::RequestOrchestration),
+    /// Variant for DeviceRequest target resources
+    DeviceRequest(resources::DeviceRequest),
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+    /// Variant for EnrollmentRequest target resources
+    EnrollmentRequest(resources::EnrollmentRequest),
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for Contract target resources
+    Contract(resources::Contract),
+    /// Variant for MedicationRequest target resources
+    MedicationRequest(resources::MedicationRequest),
+    /// Variant for ImmunizationRecommendation target resources
+    ImmunizationRecommendation(resources::ImmunizationRecommendation),
+    /// Variant for CoverageEligibilityRequest target resources
+    CoverageEligibilityRequest(resources::CoverageEligibilityRequest),
+    /// Variant for VisionPrescription target resources
+    VisionPrescription(resources::VisionPrescription),
+    /// Variant for Appointment target resources
+    Appointment(resources::Appointment),
+    /// Variant for CommunicationRequest target resources
+    CommunicationRequest(resources::CommunicationRequest),
+    /// Variant for AppointmentResponse target resources
+    AppointmentResponse(resources::AppointmentResponse),
+    /// Variant for SupplyRequest target resources
+    SupplyRequest(resources::SupplyRequest),
+    /// Variant for NutritionOrder target resources
+    NutritionOrder(resources::NutritionOrder),
+}
+impl From<resources::Claim> for DocumentReferenceBasedOnSearchTarget {
+    fn from(resource: resources::Claim) -> DocumentReferenceBasedOnSearchTarget {
+        DocumentReferenceBasedOnSearchTarget::Claim(resource)
+    }
+}
+impl From<resources::RequestOrchestration> for DocumentReferenceBasedOnSearchTarget {
+    fn from(resource: resources::RequestOrchestration) -> DocumentReferenceBasedOnSearchTarget {
+        DocumentReferenceBasedOnSearchTarget::RequestOrchestration(resource)
+    }
+}
+impl From<resources::DeviceRequest> for DocumentReferenceBasedOnSearchTarget {
+    fn from(resource: resources::DeviceRequest) -> DocumentReferenceBasedOnSearchTarget {
+        DocumentReferenceBasedOnSearchTarget::DeviceRequest(resource)
+    }
+}
+impl From<resources::ServiceRequest> for DocumentReferenceBasedOnSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> DocumentReferenceBasedOnSearchTarget {
+        DocumentReferenceBasedOnSearchTarget::ServiceRequest(resource)
+    }
+}
+impl From<resources::EnrollmentRequest> for DocumentReferenceBasedOnSearchTarget {
+    fn from(resource: resources::EnrollmentRequest) -> DocumentReferenceBasedOnSearchTarget {
+        DocumentReferenceBasedOnSearchTarget::EnrollmentRequest(resource)
+    }
+}
+impl From<resources::CarePlan> for DocumentReferenceBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> DocumentReferenceBasedOnSearchTarget {
+        DocumentReferenceBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::Contract> for DocumentReferenceBasedOnSearchTarget {
+    fn from(resource: resources::Contract) -> DocumentReferenceBasedOnSearchTarget {
+        DocumentReferenceBasedOnSearchTarget::Contract(resource)
+    }
+}
+impl From<resources::MedicationRequest> for DocumentReferenceBasedOnSearchTarget {
+    fn from(resource: resources::MedicationRequest) -> DocumentReferenceBasedOnSearchTarget {
+        DocumentReferenceBasedOnSearchTarget::MedicationRequest(resource)
+    }
+}
+impl From<resources::ImmunizationRecommendation> for DocumentReferenceBasedOnSearchTarget {
+    fn from(resource: resources::ImmunizationRecommendation) -> DocumentReferenceBasedOnSearchTarget {
+        DocumentReferenceBasedOnSearchTarget::ImmunizationRecommendation(resource)
+    }
+}
+impl From<resources::CoverageEligibilityRequest> for DocumentReferenceBasedOnSearchTarget {
+    fn from(resource: resources::CoverageEligibilityRequest) -> DocumentReferenceBasedOnSearchTarget {
+        DocumentReferenceBasedOnSearchTarget::CoverageEligibilityRequest(resource)
+    }
+}
+impl From<resources::VisionPrescription> for DocumentReferenceBasedOnSearchTarget {
+    fn from(resource: resources::VisionPrescription) -> DocumentReferenceBasedOnSearchTarget {
+        DocumentReferenceBasedOnSearchTarget::VisionPrescription(resource)
+    }
+}
+impl From<resources::Appointment> for DocumentReferenceBasedOnSearchTarget {
+    fn from(resource: resources::Appointment) -> DocumentReferenceBasedOnSearchTarget {
+        DocumentReferenceBasedOnSearchTarget::Appointment(resource)
+    }
+}
+impl From<resources::CommunicationRequest> for DocumentReferenceBasedOnSearchTarget {
+    fn from(resource: resources::CommunicationRequest) -> DocumentReferenceBasedOnSearchTarget {
+        DocumentReferenceBasedOnSearchTarget::CommunicationRequest(resource)
+    }
+}
+impl From<resources::AppointmentResponse> for DocumentReferenceBasedOnSearchTarget {
+    fn from(resource: resources::AppointmentResponse) -> DocumentReferenceBasedOnSearchTarget {
+        DocumentReferenceBasedOnSearchTarget::AppointmentResponse(resource)
+    }
+}
+impl From<resources::SupplyRequest> for DocumentReferenceBasedOnSearchTarget {
+    fn from(resource: resources::SupplyRequest) -> DocumentReferenceBasedOnSearchTarget {
+        DocumentReferenceBasedOnSearchTarget::SupplyRequest(resource)
+    }
+}
+impl From<resources::NutritionOrder> for DocumentReferenceBasedOnSearchTarget {
+    fn from(resource: resources::NutritionOrder) -> DocumentReferenceBasedOnSearchTarget {
+        DocumentReferenceBasedOnSearchTarget::NutritionOrder(resource)
+    }
+}
+/// The bodysite-reference reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceBodysiteReferenceSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceBodysiteReferenceSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = resources::BodyStructure;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::BodysiteReference
+    }
+}
+/// The context reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceContextSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceContextSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = DocumentReferenceContextSearchTarget;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the DocumentReference resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentReferenceContextSearchTarget {
+    /// Variant for Appointment target resources
+    Appointment(resources::Appointment),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+}
+impl From<resources::Appointment> for DocumentReferenceContextSearchTarget {
+    fn from(resource: resources::Appointment) -> DocumentReferenceContextSearchTarget {
+        DocumentReferenceContextSearchTarget::Appointment(resource)
+    }
+}
+impl From<resources::Encounter> for DocumentReferenceContextSearchTarget {
+    fn from(resource: resources::Encounter) -> DocumentReferenceContextSearchTarget {
+        DocumentReferenceContextSearchTarget::Encounter(resource)
+    }
+}
+impl From<resources::EpisodeOfCare> for DocumentReferenceContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> DocumentReferenceContextSearchTarget {
+        DocumentReferenceContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+/// The custodian reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceCustodianSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceCustodianSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = resources::Organization;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Custodian
+    }
+}
+/// The event-reference reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceEventReferenceSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceEventReferenceSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = resources::Resource;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::EventReference
+    }
+}
+/// The format-canonical reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceFormatCanonicalSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceFormatCanonicalSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = DocumentReferenceFormatCanonicalSearchTarget;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::FormatCanonical
+    }
+}
+/// Target resources of the format-canonical reference search parameter of the DocumentReference resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentReferenceFormatCanonicalSearchTarget {
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+    /// Variant for ActorDefinition target resources
+    ActorDefinition(resources::ActorDefinition),
+    /// Variant for CapabilityStatement target resources
+    CapabilityStatement(resources::CapabilityStatement),
+    /// Variant for ChargeItemDefinition target resources
+    ChargeItemDefinition(resources::ChargeItemDefinition),
+    /// Variant for Citation target resources
+    Citation(resources::Citation),
+    /// Variant for CodeSystem target resources
+    CodeSystem(resources::CodeSystem),
+    /// Variant for CompartmentDefinition target resources
+    CompartmentDefinition(resources::CompartmentDefinition),
+    /// Variant for Composition target resources
+    Composition(resources::Composition),
+    /// Variant for ConceptMap target resources
+    ConceptMap(resources::ConceptMap),
+    /// Variant for ConditionDefinition target resources
+    ConditionDefinition(resources::ConditionDefinition),
+    /// Variant for Contract target resources
+    Contract(resources::Contract),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for EventDefinition target resources
+    EventDefinition(resources::EventDefinition),
+    /// Variant for Evidence target resources
+    Evidence(resources::Evidence),
+    /// Variant for EvidenceReport target resources
+    EvidenceReport(resources::EvidenceReport),
+    /// Variant for EvidenceVariable target resources
+    EvidenceVariable(resources::EvidenceVariable),
+    /// Variant for ExampleScenario target resources
+    ExampleScenario(resources::ExampleScenario),
+    /// Variant for GraphDefinition target resources
+    GraphDefinition(resources::GraphDefinition),
+    /// Variant for ImplementationGuide target resources
+    ImplementationGuide(resources::ImplementationGuide),
+    /// Variant for Library target resources
+    Library(resources::Library),
+    /// Variant for Measure target resources
+    Measure(resources::Measure),
+    /// Variant for MessageDefinition target resources
+    MessageDefinition(resources::MessageDefinition),
+    /// Variant for NamingSystem target resources
+    NamingSystem(resources::NamingSystem),
+    /// Variant for ObservationDefinition target resources
+    ObservationDefinition(resources::ObservationDefinition),
+    /// Variant for OperationDefinition target resources
+    OperationDefinition(resources::OperationDefinition),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for Questionnaire target resources
+    Questionnaire(resources::Questionnaire),
+    /// Variant for Requirements target resources
+    Requirements(resources::Requirements),
+    /// Variant for ResearchStudy target resources
+    ResearchStudy(resources::ResearchStudy),
+    /// Variant for SearchParameter target resources
+    SearchParameter(resources::SearchParameter),
+    /// Variant for SpecimenDefinition target resources
+    SpecimenDefinition(resources::SpecimenDefinition),
+    /// Variant for StructureDefinition target resources
+    StructureDefinition(resources::StructureDefinition),
+    /// Variant for StructureMap target resources
+    StructureMap(resources::StructureMap),
+    /// Variant for SubscriptionTopic target resources
+    SubscriptionTopic(resources::SubscriptionTopic),
+    /// Variant for TerminologyCapabilities target resources
+    TerminologyCapabilities(resources::TerminologyCapabilities),
+    /// Variant for TestPlan target resources
+    TestPlan(resources::TestPlan),
+    /// Variant for TestScript target resources
+    TestScript(resources::TestScript),
+    /// Variant for ValueSet target resources
+    ValueSet(resources::ValueSet),
+}
+impl From<resources::ActivityDefinition> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::ActivityDefinition(resource)
+    }
+}
+impl From<resources::ActorDefinition> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::ActorDefinition) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::ActorDefinition(resource)
+    }
+}
+impl From<resources::CapabilityStatement> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::CapabilityStatement) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::CapabilityStatement(resource)
+    }
+}
+impl From<resources::ChargeItemDefinition> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::ChargeItemDefinition) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::ChargeItemDefinition(resource)
+    }
+}
+impl From<resources::Citation> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::Citation) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::Citation(resource)
+    }
+}
+impl From<resources::CodeSystem> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::CodeSystem) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::CodeSystem(resource)
+    }
+}
+impl From<resources::CompartmentDefinition> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::CompartmentDefinition) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::CompartmentDefinition(resource)
+    }
+}
+impl From<resources::Composition> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::Composition) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::Composition(resource)
+    }
+}
+impl From<resources::ConceptMap> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::ConceptMap) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::ConceptMap(resource)
+    }
+}
+impl From<resources::ConditionDefinition> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::ConditionDefinition) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::ConditionDefinition(resource)
+    }
+}
+impl From<resources::Contract> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::Contract) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::Contract(resource)
+    }
+}
+impl From<resources::Device> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::Device) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::Device(resource)
+    }
+}
+impl From<resources::EventDefinition> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::EventDefinition) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::EventDefinition(resource)
+    }
+}
+impl From<resources::Evidence> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::Evidence) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::Evidence(resource)
+    }
+}
+impl From<resources::EvidenceReport> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::EvidenceReport) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::EvidenceReport(resource)
+    }
+}
+impl From<resources::EvidenceVariable> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::EvidenceVariable) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::EvidenceVariable(resource)
+    }
+}
+impl From<resources::ExampleScenario> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::ExampleScenario) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::ExampleScenario(resource)
+    }
+}
+impl From<resources::GraphDefinition> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::GraphDefinition) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::GraphDefinition(resource)
+    }
+}
+impl From<resources::ImplementationGuide> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::ImplementationGuide) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::ImplementationGuide(resource)
+    }
+}
+impl From<resources::Library> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::Library) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::Library(resource)
+    }
+}
+impl From<resources::Measure> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::Measure) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::Measure(resource)
+    }
+}
+impl From<resources::MessageDefinition> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::MessageDefinition) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::MessageDefinition(resource)
+    }
+}
+impl From<resources::NamingSystem> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::NamingSystem) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::NamingSystem(resource)
+    }
+}
+impl From<resources::ObservationDefinition> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::ObservationDefinition) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::ObservationDefinition(resource)
+    }
+}
+impl From<resources::OperationDefinition> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::OperationDefinition) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::OperationDefinition(resource)
+    }
+}
+impl From<resources::PlanDefinition> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::Questionnaire> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::Questionnaire) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::Questionnaire(resource)
+    }
+}
+impl From<resources::Requirements> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::Requirements) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::Requirements(resource)
+    }
+}
+impl From<resources::ResearchStudy> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::ResearchStudy) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::ResearchStudy(resource)
+    }
+}
+impl From<resources::SearchParameter> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::SearchParameter) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::SearchParameter(resource)
+    }
+}
+impl From<resources::SpecimenDefinition> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::SpecimenDefinition) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::SpecimenDefinition(resource)
+    }
+}
+impl From<resources::StructureDefinition> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::StructureDefinition) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::StructureDefinition(resource)
+    }
+}
+impl From<resources::StructureMap> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::StructureMap) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::StructureMap(resource)
+    }
+}
+impl From<resources::SubscriptionTopic> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::SubscriptionTopic) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::SubscriptionTopic(resource)
+    }
+}
+impl From<resources::TerminologyCapabilities> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::TerminologyCapabilities) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::TerminologyCapabilities(resource)
+    }
+}
+impl From<resources::TestPlan> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::TestPlan) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::TestPlan(resource)
+    }
+}
+impl From<resources::TestScript> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::TestScript) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::TestScript(resource)
+    }
+}
+impl From<resources::ValueSet> for DocumentReferenceFormatCanonicalSearchTarget {
+    fn from(resource: resources::ValueSet) -> DocumentReferenceFormatCanonicalSearchTarget {
+        DocumentReferenceFormatCanonicalSearchTarget::ValueSet(resource)
+    }
+}
+/// The relatesto reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceRelatestoSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceRelatestoSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = resources::DocumentReference;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Relatesto
+    }
+}
+/// The subject reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceSubjectSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceSubjectSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = resources::Resource;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Subject
+    }
+}
+/// The patient reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterPatientSearchReference;
+impl ReferenceSearchParameter for EncounterPatientSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::Patient;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Patient
+    }
+}
+/// The account reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterAccountSearchReference;
+impl ReferenceSearchParameter for EncounterAccountSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::Account;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Account
+    }
+}
+/// The appointment reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterAppointmentSearchReference;
+impl ReferenceSearchParameter for EncounterAppointmentSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::Appointment;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Appointment
+    }
+}
+/// The based-on reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterBasedOnSearchReference;
+impl ReferenceSearchParameter for EncounterBasedOnSearchReference {
+    type Resource = resources::Encounter;
+    type Target = EncounterBasedOnSearchTarget;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the Encounter resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EncounterBasedOnSearchTarget {
+    /// Variant for DeviceRequest target resources
+    DeviceRequest(resources::DeviceRequest),
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for MedicationRequest target resources
+    MedicationRequest(resources::MedicationRequest),
+}
+impl From<resources::DeviceRequest> for EncounterBasedOnSearchTarget {
+    fn from(resource: resources::DeviceRequest) -> EncounterBasedOnSearchTarget {
+        EncounterBasedOnSearchTarget::DeviceRequest(resource)
+    }
+}
+impl From<resources::ServiceRequest> for EncounterBasedOnSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> EncounterBasedOnSearchTarget {
+        EncounterBasedOnSearchTarget::ServiceRequest(resource)
+    }
+}
+impl From<resources::CarePlan> for EncounterBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> EncounterBasedOnSearchTarget {
+        EncounterBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::MedicationRequest> for EncounterBasedOnSearchTarget {
+    fn from(resource: resources::MedicationRequest) -> EncounterBasedOnSearchTarget {
+        EncounterBasedOnSearchTarget::MedicationRequest(resource)
+    }
+}
+/// The careteam reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterCareteamSearchReference;
+impl ReferenceSearchParameter for EncounterCareteamSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::CareTeam;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Careteam
+    }
+}
+/// The diagnosis-reference reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterDiagnosisReferenceSearchReference;
+impl ReferenceSearchParameter for EncounterDiagnosisReferenceSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::Condition;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::DiagnosisReference
+    }
+}
+/// The episode-of-care reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterEpisodeOfCareSearchReference;
+impl ReferenceSearchParameter for EncounterEpisodeOfCareSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::EpisodeOfCare;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::EpisodeOfCare
+    }
+}
+/// The location reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterLocationSearchReference;
+impl ReferenceSearchParameter for EncounterLocationSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::Location;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Location
+    }
+}
+/// The part-of reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterPartOfSearchReference;
+impl ReferenceSearchParameter for EncounterPartOfSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::PartOf
+    }
+}
+/// The participant reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterParticipantSearchReference;
+impl ReferenceSearchParameter for EncounterParticipantSearchReference {
+    type Resource = resources::Encounter;
+    type Target = EncounterParticipantSearchTarget;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Participant
+    }
+}
+/// Target resources of the participant reference search parameter of the Encounter resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EncounterParticipantSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for EncounterParticipantSearchTarget {
+    fn from(resource: resources::HealthcareService) -> EncounterParticipantSearchTarget {
+        EncounterParticipantSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::Device> for EncounterParticipantSearchTarget {
+    fn from(resource: resources::Device) -> EncounterParticipantSearchTarget {
+        EncounterParticipantSearchTarget::Device(resource)
+    }
+}
+impl From<resources::RelatedPerson> for EncounterParticipantSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> EncounterParticipantSearchTarget {
+        EncounterParticipantSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for EncounterParticipantSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> EncounterParticipantSearchTarget {
+        EncounterParticipantSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Group> for EncounterParticipantSearchTarget {
+    fn from(resource: resources::Group) -> EncounterParticipantSearchTarget {
+        EncounterParticipantSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Practitioner> for EncounterParticipantSearchTarget {
+    fn from(resource: resources::Practitioner) -> EncounterParticipantSearchTarget {
+        EncounterParticipantSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for EncounterParticipantSearchTarget {
+    fn from(resource: resources::Patient) -> EncounterParticipantSearchTarget {
+        EncounterParticipantSearchTarget::Patient(resource)
+    }
+}
+/// The practitioner reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterPractitionerSearchReference;
+impl ReferenceSearchParameter for EncounterPractitionerSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Practitioner
+    }
+}
+/// The reason-reference reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterReasonReferenceSearchReference;
+impl ReferenceSearchParameter for EncounterReasonReferenceSearchReference {
+    type Resource = resources::Encounter;
+    type Target = EncounterReasonReferenceSearchTarget;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::ReasonReference
+    }
+}
+/// Target resources of the reason-reference reference search parameter of the Encounter resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EncounterReasonReferenceSearchTarget {
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for DiagnosticReport target resources
+    DiagnosticReport(resources::DiagnosticReport),
+    /// Variant for ImmunizationRecommendation target resources
+    ImmunizationRecommendation(resources::ImmunizationRecommendation),
+    /// Variant for Condition target resources
+    Condition(resources::Condition),
+}
+impl From<resources::Procedure> for EncounterReasonReferenceSearchTarget {
+    fn from(resource: resources::Procedure) -> EncounterReasonReferenceSearchTarget {
+        EncounterReasonReferenceSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::Observation> for EncounterReasonReferenceSearchTarget {
+    fn from(resource: resources::Observation) -> EncounterReasonReferenceSearchTarget {
+        EncounterReasonReferenceSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::DiagnosticReport> for EncounterReasonReferenceSearchTarget {
+    fn from(resource: resources::DiagnosticReport) -> EncounterReasonReferenceSearchTarget {
+        EncounterReasonReferenceSearchTarget::DiagnosticReport(resource)
+    }
+}
+impl From<resources::ImmunizationRecommendation> for EncounterReasonReferenceSearchTarget {
+    fn from(resource: resources::ImmunizationRecommendation) -> EncounterReasonReferenceSearchTarget {
+        EncounterReasonReferenceSearchTarget::ImmunizationRecommendation(resource)
+    }
+}
+impl From<resources::Condition> for EncounterReasonReferenceSearchTarget {
+    fn from(resource: resources::Condition) -> EncounterReasonReferenceSearchTarget {
+        EncounterReasonReferenceSearchTarget::Condition(resource)
+    }
+}
+/// The service-provider reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterServiceProviderSearchReference;
+impl ReferenceSearchParameter for EncounterServiceProviderSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::Organization;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::ServiceProvider
+    }
+}
+/// The subject reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterSubjectSearchReference;
+impl ReferenceSearchParameter for EncounterSubjectSearchReference {
+    type Resource = resources::Encounter;
+    type Target = EncounterSubjectSearchTarget;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Encounter resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EncounterSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for EncounterSubjectSearchTarget {
+    fn from(resource: resources::Group) -> EncounterSubjectSearchTarget {
+        EncounterSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for EncounterSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> EncounterSubjectSearchTarget {
+        EncounterSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The encounter reference search parameter of the EncounterHistory resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterHistoryEncounterSearchReference;
+impl ReferenceSearchParameter for EncounterHistoryEncounterSearchReference {
+    type Resource = resources::EncounterHistory;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> EncounterHistorySearchParameter {
+        EncounterHistorySearchParameter::Encounter
+    }
+}
+/// The patient reference search parameter of the EncounterHistory resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterHistoryPatientSearchReference;
+impl ReferenceSearchParameter for EncounterHistoryPatientSearchReference {
+    type Resource = resources::EncounterHistory;
+    type Target = resources::Patient;
+    fn parameter(&self) -> EncounterHistorySearchParameter {
+        EncounterHistorySearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the EncounterHistory resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterHistorySubjectSearchReference;
+impl ReferenceSearchParameter for EncounterHistorySubjectSearchReference {
+    type Resource = resources::EncounterHistory;
+    type Target = EncounterHistorySubjectSearchTarget;
+    fn parameter(&self) -> EncounterHistorySearchParameter {
+        EncounterHistorySearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the EncounterHistory resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EncounterHistorySubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for EncounterHistorySubjectSearchTarget {
+    fn from(resource: resources::Group) -> EncounterHistorySubjectSearchTarget {
+        EncounterHistorySubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for EncounterHistorySubjectSearchTarget {
+    fn from(resource: resources::Patient) -> EncounterHistorySubjectSearchTarget {
+        EncounterHistorySubjectSearchTarget::Patient(resource)
+    }
+}
+/// The organization reference search parameter of the Endpoint resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EndpointOrganizationSearchReference;
+impl ReferenceSearchParameter for EndpointOrganizationSearchReference {
+    type Resource = resources::Endpoint;
+    type Target = resources::Organization;
+    fn parameter(&self) -> EndpointSearchParameter {
+        EndpointSearchParameter::Organization
+    }
+}
+/// The patient reference search parameter of the EnrollmentRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EnrollmentRequestPatientSearchReference;
+impl ReferenceSearchParameter for EnrollmentRequestPatientSearchReference {
+    type Resource = resources::EnrollmentRequest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> EnrollmentRequestSearchParameter {
+        EnrollmentRequestSearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the EnrollmentRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EnrollmentRequestSubjectSearchReference;
+impl ReferenceSearchParameter for EnrollmentRequestSubjectSearchReference {
+    type Resource = resources::EnrollmentRequest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> EnrollmentRequestSearchParameter {
+        EnrollmentRequestSearchParameter::Subject
+    }
+}
+/// The request reference search parameter of the EnrollmentResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EnrollmentResponseRequestSearchReference;
+impl ReferenceSearchParameter for EnrollmentResponseRequestSearchReference {
+    type Resource = resources::EnrollmentResponse;
+    type Target = resources::EnrollmentRequest;
+    fn parameter(&self) -> EnrollmentResponseSearchParameter {
+        EnrollmentResponseSearchParameter::Request
+    }
+}
+/// The patient reference search parameter of the EpisodeOfCare resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EpisodeOfCarePatientSearchReference;
+impl ReferenceSearchParameter for EpisodeOfCarePatientSearchReference {
+    type Resource = resources::EpisodeOfCare;
+    type Target = resources::Patient;
+    fn parameter(&self) -> EpisodeOfCareSearchParameter {
+        EpisodeOfCareSearchParameter::Patient
+    }
+}
+/// The care-manager reference search parameter of the EpisodeOfCare resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EpisodeOfCareCareManagerSearchReference;
+impl ReferenceSearchParameter for EpisodeOfCareCareManagerSearchReference {
+    type Resource = resources::EpisodeOfCare;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> EpisodeOfCareSearchParameter {
+        EpisodeOfCareSearchParameter::CareManager
+    }
+}
+/// The diagnosis-reference reference search parameter of the EpisodeOfCare resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EpisodeOfCareDiagnosisReferenceSearchReference;
+impl ReferenceSearchParameter for EpisodeOfCareDiagnosisReferenceSearchReference {
+    type Resource = resources::EpisodeOfCare;
+    type Target = resources::Condition;
+    fn parameter(&self) -> EpisodeOfCareSearchParameter {
+        EpisodeOfCareSearchParameter::DiagnosisReference
+    }
+}
+/// The incoming-referral reference search parameter of the EpisodeOfCare resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EpisodeOfCareIncomingReferralSearchReference;
+impl ReferenceSearchParameter for EpisodeOfCareIncomingReferralSearchReference {
+    type Resource = resources::EpisodeOfCare;
+    type Target = resources::ServiceRequest;
+    fn parameter(&self) -> EpisodeOfCareSearchParameter {
+        EpisodeOfCareSearchParameter::IncomingReferral
+    }
+}
+/// The organization reference search parameter of the EpisodeOfCare resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EpisodeOfCareOrganizationSearchReference;
+impl ReferenceSearchParameter for EpisodeOfCareOrganizationSearchReference {
+    type Resource = resources::EpisodeOfCare;
+    type Target = resources::Organization;
+    fn parameter(&self) -> EpisodeOfCareSearchParameter {
+        EpisodeOfCareSearchParameter::Organization
+    }
+}
+/// The reason-reference reference search parameter of the EpisodeOfCare resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EpisodeOfCareReasonReferenceSearchReference;
+impl ReferenceSearchParameter for EpisodeOfCareReasonReferenceSearchReference {
+    type Resource = resources::EpisodeOfCare;
+    type Target = EpisodeOfCareReasonReferenceSearchTarget;
+    fn parameter(&self) -> EpisodeOfCareSearchParameter {
+        EpisodeOfCareSearchParameter::ReasonReference
+    }
+}
+/// Target resources of the reason-reference reference search parameter of the EpisodeOfCare resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EpisodeOfCareReasonReferenceSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for Condition target resources
+    Condition(resources::Condition),
+}
+impl From<resources::HealthcareService> for EpisodeOfCareReasonReferenceSearchTarget {
+    fn from(resource: resources::HealthcareService) -> EpisodeOfCareReasonReferenceSearchTarget {
+        EpisodeOfCareReasonReferenceSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::Procedure> for EpisodeOfCareReasonReferenceSearchTarget {
+    fn from(resource: resources::Procedure) -> EpisodeOfCareReasonReferenceSearchTarget {
+        EpisodeOfCareReasonReferenceSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::Observation> for EpisodeOfCareReasonReferenceSearchTarget {
+    fn from(resource: resources::Observation) -> EpisodeOfCareReasonReferenceSearchTarget {
+        EpisodeOfCareReasonReferenceSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::Condition> for EpisodeOfCareReasonReferenceSearchTarget {
+    fn from(resource: resources::Condition) -> EpisodeOfCareReasonReferenceSearchTarget {
+        EpisodeOfCareReasonReferenceSearchTarget::Condition(resource)
+    }
+}
+/// The composed-of reference search parameter of the EventDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EventDefinitionComposedOfSearchReference;
+impl ReferenceSearchParameter for EventDefinitionComposedOfSearchReference {
+    type Resource = resources::EventDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> EventDefinitionSearchParameter {
+        EventDefinitionSearchParameter::ComposedOf
+    }
+}
+/// The depends-on reference search parameter of the EventDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EventDefinitionDependsOnSearchReference;
+impl ReferenceSearchParameter for EventDefinitionDependsOnSearchReference {
+    type Resource = resources::EventDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> EventDefinitionSearchParameter {
+        EventDefinitionSearchParameter::DependsOn
+    }
+}
+/// The derived-from reference search parameter of the EventDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EventDefinitionDerivedFromSearchReference;
+impl ReferenceSearchParameter for EventDefinitionDerivedFromSearchReference {
+    type Resource = resources::EventDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> EventDefinitionSearchParameter {
+        EventDefinitionSearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the EventDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EventDefinitionPredecessorSearchReference;
+impl ReferenceSearchParameter for EventDefinitionPredecessorSearchReference {
+    type Resource = resources::EventDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> EventDefinitionSearchParameter {
+        EventDefinitionSearchParameter::Predecessor
+    }
+}
+/// The successor reference search parameter of the EventDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EventDefinitionSuccessorSearchReference;
+impl ReferenceSearchParameter for EventDefinitionSuccessorSearchReference {
+    type Resource = resources::EventDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> EventDefinitionSearchParameter {
+        EventDefinitionSearchParameter::Successor
+    }
+}
+/// The composed-of reference search parameter of the EvidenceVariable resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EvidenceVariableComposedOfSearchReference;
+impl ReferenceSearchParameter for EvidenceVariableComposedOfSearchReference {
+    type Resource = resources::EvidenceVariable;
+    type Target = resources::Resource;
+    fn parameter(&self) -> EvidenceVariableSearchParameter {
+        EvidenceVariableSearchParameter::ComposedOf
+    }
+}
+/// The depends-on reference search parameter of the EvidenceVariable resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EvidenceVariableDependsOnSearchReference;
+impl ReferenceSearchParameter for EvidenceVariableDependsOnSearchReference {
+    type Resource = resources::EvidenceVariable;
+    type Target = resources::Resource;
+    fn parameter(&self) -> EvidenceVariableSearchParameter {
+        EvidenceVariableSearchParameter::DependsOn
+    }
+}
+/// The derived-from reference search parameter of the EvidenceVariable resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EvidenceVariableDerivedFromSearchReference;
+impl ReferenceSearchParameter for EvidenceVariableDerivedFromSearchReference {
+    type Resource = resources::EvidenceVariable;
+    type Target = resources::Resource;
+    fn parameter(&self) -> EvidenceVariableSearchParameter {
+        EvidenceVariableSearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the EvidenceVariable resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EvidenceVariablePredecessorSearchReference;
+impl ReferenceSearchParameter for EvidenceVariablePredecessorSearchReference {
+    type Resource = resources::EvidenceVariable;
+    type Target = resources::Resource;
+    fn parameter(&self) -> EvidenceVariableSearchParameter {
+        EvidenceVariableSearchParameter::Predecessor
+    }
+}
+/// The successor reference search parameter of the EvidenceVariable resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EvidenceVariableSuccessorSearchReference;
+impl ReferenceSearchParameter for EvidenceVariableSuccessorSearchReference {
+    type Resource = resources::EvidenceVariable;
+    type Target = resources::Resource;
+    fn parameter(&self) -> EvidenceVariableSearchParameter {
+        EvidenceVariableSearchParameter::Successor
+    }
+}
+/// The patient reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitPatientSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitPatientSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitEncounterSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitEncounterSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Encounter
+    }
+}
+/// The care-team reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitCareTeamSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitCareTeamSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = ExplanationOfBenefitCareTeamSearchTarget;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::CareTeam
+    }
+}
+/// Target resources of the care-team reference search parameter of the ExplanationOfBenefit resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExplanationOfBenefitCareTeamSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for ExplanationOfBenefitCareTeamSearchTarget {
+    fn from(resource: resources::Organization) -> ExplanationOfBenefitCareTeamSearchTarget {
+        ExplanationOfBenefitCareTeamSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ExplanationOfBenefitCareTeamSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ExplanationOfBenefitCareTeamSearchTarget {
+        ExplanationOfBenefitCareTeamSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ExplanationOfBenefitCareTeamSearchTarget {
+    fn from(resource: resources::Practitioner) -> ExplanationOfBenefitCareTeamSearchTarget {
+        ExplanationOfBenefitCareTeamSearchTarget::Practitioner(resource)
+    }
+}
+/// The claim reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitClaimSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitClaimSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Claim;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Claim
+    }
+}
+/// The coverage reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitCoverageSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitCoverageSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Coverage;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Coverage
+    }
+}
+/// The detail-udi reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitDetailUdiSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitDetailUdiSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Device;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::DetailUdi
+    }
+}
+/// The enterer reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitEntererSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitEntererSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = ExplanationOfBenefitEntererSearchTarget;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Enterer
+    }
+}
+/// Target resources of the enterer reference search parameter of the ExplanationOfBenefit resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExplanationOfBenefitEntererSearchTarget {
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::RelatedPerson> for ExplanationOfBenefitEntererSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ExplanationOfBenefitEntererSearchTarget {
+        ExplanationOfBenefitEntererSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ExplanationOfBenefitEntererSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ExplanationOfBenefitEntererSearchTarget {
+        ExplanationOfBenefitEntererSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ExplanationOfBenefitEntererSearchTarget {
+    fn from(resource: resources::Practitioner) -> ExplanationOfBenefitEntererSearchTarget {
+        ExplanationOfBenefitEntererSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ExplanationOfBenefitEntererSearchTarget {
+    fn from(resource: resources::Patient) -> ExplanationOfBenefitEntererSearchTarget {
+        ExplanationOfBenefitEntererSearchTarget::Patient(resource)
+    }
+}
+/// The facility reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitFacilitySearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitFacilitySearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = ExplanationOfBenefitFacilitySearchTarget;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Facility
+    }
+}
+/// Target resources of the facility reference search parameter of the ExplanationOfBenefit resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExplanationOfBenefitFacilitySearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Organization> for ExplanationOfBenefitFacilitySearchTarget {
+    fn from(resource: resources::Organization) -> ExplanationOfBenefitFacilitySearchTarget {
+        ExplanationOfBenefitFacilitySearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Location> for ExplanationOfBenefitFacilitySearchTarget {
+    fn from(resource: resources::Location) -> ExplanationOfBenefitFacilitySearchTarget {
+        ExplanationOfBenefitFacilitySearchTarget::Location(resource)
+    }
+}
+/// The item-udi reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitItemUdiSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitItemUdiSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Device;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::ItemUdi
+    }
+}
+/// The payee reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitPayeeSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitPayeeSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = ExplanationOfBenefitPayeeSearchTarget;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Payee
+    }
+}
+/// Target resources of the payee reference search parameter of the ExplanationOfBenefit resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExplanationOfBenefitPayeeSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for ExplanationOfBenefitPayeeSearchTarget {
+    fn from(resource: resources::Organization) -> ExplanationOfBenefitPayeeSearchTarget {
+        ExplanationOfBenefitPayeeSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ExplanationOfBenefitPayeeSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ExplanationOfBenefitPayeeSearchTarget {
+        ExplanationOfBenefitPayeeSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ExplanationOfBenefitPayeeSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ExplanationOfBenefitPayeeSearchTarget {
+        ExplanationOfBenefitPayeeSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ExplanationOfBenefitPayeeSearchTarget {
+    fn from(resource: resources::Practitioner) -> ExplanationOfBenefitPayeeSearchTarget {
+        ExplanationOfBenefitPayeeSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ExplanationOfBenefitPayeeSearchTarget {
+    fn from(resource: resources::Patient) -> ExplanationOfBenefitPayeeSearchTarget {
+        ExplanationOfBenefitPayeeSearchTarget::Patient(resource)
+    }
+}
+/// The procedure-udi reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitProcedureUdiSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitProcedureUdiSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Device;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::ProcedureUdi
+    }
+}
+/// The provider reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitProviderSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitProviderSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = ExplanationOfBenefitProviderSearchTarget;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Provider
+    }
+}
+/// Target resources of the provider reference search parameter of the ExplanationOfBenefit resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExplanationOfBenefitProviderSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for ExplanationOfBenefitProviderSearchTarget {
+    fn from(resource: resources::Organization) -> ExplanationOfBenefitProviderSearchTarget {
+        ExplanationOfBenefitProviderSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ExplanationOfBenefitProviderSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ExplanationOfBenefitProviderSearchTarget {
+        ExplanationOfBenefitProviderSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ExplanationOfBenefitProviderSearchTarget {
+    fn from(resource: resources::Practitioner) -> ExplanationOfBenefitProviderSearchTarget {
+        ExplanationOfBenefitProviderSearchTarget::Practitioner(resource)
+    }
+}
+/// The subdetail-udi reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitSubdetailUdiSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitSubdetailUdiSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Device;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::SubdetailUdi
+    }
+}
+/// The patient reference search parameter of the FamilyMemberHistory resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FamilyMemberHistoryPatientSearchReference;
+impl ReferenceSearchParameter for FamilyMemberHistoryPatientSearchReference {
+    type Resource = resources::FamilyMemberHistory;
+    type Target = resources::Patient;
+    fn parameter(&self) -> FamilyMemberHistorySearchParameter {
+        FamilyMemberHistorySearchParameter::Patient
+    }
+}
+/// The instantiates-canonical reference search parameter of the FamilyMemberHistory resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FamilyMemberHistoryInstantiatesCanonicalSearchReference;
+impl ReferenceSearchParameter for FamilyMemberHistoryInstantiatesCanonicalSearchReference {
+    type Resource = resources::FamilyMemberHistory;
+    type Target = FamilyMemberHistoryInstantiatesCanonicalSearchTarget;
+    fn parameter(&self) -> FamilyMemberHistorySearchParameter {
+        FamilyMemberHistorySearchParameter::InstantiatesCanonical
+    }
+}
+/// Target resources of the instantiates-canonical reference search parameter of the FamilyMemberHistory resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+    /// Variant for Measure target resources
+    Measure(resources::Measure),
+    /// Variant for OperationDefinition target resources
+    OperationDefinition(resources::OperationDefinition),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for Questionnaire target resources
+    Questionnaire(resources::Questionnaire),
+}
+impl From<resources::Measure> for FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Measure) -> FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+        FamilyMemberHistoryInstantiatesCanonicalSearchTarget::Measure(resource)
+    }
+}
+impl From<resources::OperationDefinition> for FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::OperationDefinition) -> FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+        FamilyMemberHistoryInstantiatesCanonicalSearchTarget::OperationDefinition(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+        FamilyMemberHistoryInstantiatesCanonicalSearchTarget::ActivityDefinition(resource)
+    }
+}
+impl From<resources::PlanDefinition> for FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+        FamilyMemberHistoryInstantiatesCanonicalSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::Questionnaire> for FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Questionnaire) -> FamilyMemberHistoryInstantiatesCanonicalSearchTarget {
+        FamilyMemberHistoryInstantiatesCanonicalSearchTarget::Questionnaire(resource)
+    }
+}
+/// The patient reference search parameter of the Flag resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FlagPatientSearchReference;
+impl ReferenceSearchParameter for FlagPatientSearchReference {
+    type Resource = resources::Flag;
+    type Target = resources::Patient;
+    fn parameter(&self) -> FlagSearchParameter {
+        FlagSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the Flag resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FlagEncounterSearchReference;
+impl ReferenceSearchParameter for FlagEncounterSearchReference {
+    type Resource = resources::Flag;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> FlagSearchParameter {
+        FlagSearchParameter::Encounter
+    }
+}
+/// The author reference search parameter of the Flag resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FlagAuthorSearchReference;
+impl ReferenceSearchParameter for FlagAuthorSearchReference {
+    type Resource = resources::Flag;
+    type Target = FlagAuthorSearchTarget;
+    fn parameter(&self) -> FlagSearchParameter {
+        FlagSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the Flag resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FlagAuthorSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Device> for FlagAuthorSearchTarget {
+    fn from(resource: resources::Device) -> FlagAuthorSearchTarget {
+        FlagAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for FlagAuthorSearchTarget {
+    fn from(resource: resources::Organization) -> FlagAuthorSearchTarget {
+        FlagAuthorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for FlagAuthorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> FlagAuthorSearchTarget {
+        FlagAuthorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for FlagAuthorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> FlagAuthorSearchTarget {
+        FlagAuthorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for FlagAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> FlagAuthorSearchTarget {
+        FlagAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for FlagAuthorSearchTarget {
+    fn from(resource: resources::Patient) -> FlagAuthorSearchTarget {
+        FlagAuthorSearchTarget::Patient(resource)
+    }
+}
+/// The subject reference search parameter of the Flag resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FlagSubjectSearchReference;
+impl ReferenceSearchParameter for FlagSubjectSearchReference {
+    type Resource = resources::Flag;
+    type Target = FlagSubjectSearchTarget;
+    fn parameter(&self) -> FlagSearchParameter {
+        FlagSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Flag resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FlagSubjectSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+}
+impl From<resources::Organization> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for FlagSubjectSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for FlagSubjectSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Procedure> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Procedure) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::PlanDefinition> for FlagSubjectSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::Group> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Group) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Practitioner> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Location> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Location) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Location(resource)
+    }
+}
+impl From<resources::Patient> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Medication> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Medication) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Medication(resource)
+    }
+}
+/// The focus reference search parameter of the GenomicStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GenomicStudyFocusSearchReference;
+impl ReferenceSearchParameter for GenomicStudyFocusSearchReference {
+    type Resource = resources::GenomicStudy;
+    type Target = resources::Resource;
+    fn parameter(&self) -> GenomicStudySearchParameter {
+        GenomicStudySearchParameter::Focus
+    }
+}
+/// The patient reference search parameter of the GenomicStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GenomicStudyPatientSearchReference;
+impl ReferenceSearchParameter for GenomicStudyPatientSearchReference {
+    type Resource = resources::GenomicStudy;
+    type Target = resources::Patient;
+    fn parameter(&self) -> GenomicStudySearchParameter {
+        GenomicStudySearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the GenomicStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GenomicStudySubjectSearchReference;
+impl ReferenceSearchParameter for GenomicStudySubjectSearchReference {
+    type Resource = resources::GenomicStudy;
+    type Target = GenomicStudySubjectSearchTarget;
+    fn parameter(&self) -> GenomicStudySearchParameter {
+        GenomicStudySearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the GenomicStudy resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GenomicStudySubjectSearchTarget {
+    /// Variant for NutritionProduct target resources
+    NutritionProduct(resources::NutritionProduct),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for BiologicallyDerivedProduct target resources
+    BiologicallyDerivedProduct(resources::BiologicallyDerivedProduct),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::NutritionProduct> for GenomicStudySubjectSearchTarget {
+    fn from(resource: resources::NutritionProduct) -> GenomicStudySubjectSearchTarget {
+        GenomicStudySubjectSearchTarget::NutritionProduct(resource)
+    }
+}
+impl From<resources::Group> for GenomicStudySubjectSearchTarget {
+    fn from(resource: resources::Group) -> GenomicStudySubjectSearchTarget {
+        GenomicStudySubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::BiologicallyDerivedProduct> for GenomicStudySubjectSearchTarget {
+    fn from(resource: resources::BiologicallyDerivedProduct) -> GenomicStudySubjectSearchTarget {
+        GenomicStudySubjectSearchTarget::BiologicallyDerivedProduct(resource)
+    }
+}
+impl From<resources::Substance> for GenomicStudySubjectSearchTarget {
+    fn from(resource: resources::Substance) -> GenomicStudySubjectSearchTarget {
+        GenomicStudySubjectSearchTarget::Substance(resource)
+    }
+}
+impl From<resources::Patient> for GenomicStudySubjectSearchTarget {
+    fn from(resource: resources::Patient) -> GenomicStudySubjectSearchTarget {
+        GenomicStudySubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the Goal resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GoalPatientSearchReference;
+impl ReferenceSearchParameter for GoalPatientSearchReference {
+    type Resource = resources::Goal;
+    type Target = resources::Patient;
+    fn parameter(&self) -> GoalSearchParameter {
+        GoalSearchParameter::Patient
+    }
+}
+/// The addresses reference search parameter of the Goal resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GoalAddressesSearchReference;
+impl ReferenceSearchParameter for GoalAddressesSearchReference {
+    type Resource = resources::Goal;
+    type Target = GoalAddressesSearchTarget;
+    fn parameter(&self) -> GoalSearchParameter {
+        GoalSearchParameter::Addresses
+    }
+}
+/// Target resources of the addresses reference search parameter of the Goal resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GoalAddressesSearchTarget {
+    /// Variant for RiskAssessment target resources
+    RiskAssessment(resources::RiskAssessment),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for MedicationStatement target resources
+    MedicationStatement(resources::MedicationStatement),
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+    /// Variant for MedicationRequest target resources
+    MedicationRequest(resources::MedicationRequest),
+    /// Variant for Condition target resources
+    Condition(resources::Condition),
+    /// Variant for NutritionOrder target resources
+    NutritionOrder(resources::NutritionOrder),
+}
+impl From<resources::RiskAssessment> for GoalAddressesSearchTarget {
+    fn from(resource: resources::RiskAssessment) -> GoalAddressesSearchTarget {
+        GoalAddressesSearchTarget::RiskAssessment(resource)
+    }
+}
+impl From<resources::Procedure> for GoalAddressesSearchTarget {
+    fn from(resource: resources::Procedure) -> GoalAddressesSearchTarget {
+        GoalAddressesSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::Observation> for GoalAddressesSearchTarget {
+    fn from(resource: resources::Observation) -> GoalAddressesSearchTarget {
+        GoalAddressesSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::MedicationStatement> for GoalAddressesSearchTarget {
+    fn from(resource: resources::MedicationStatement) -> GoalAddressesSearchTarget {
+        GoalAddressesSearchTarget::MedicationStatement(resource)
+    }
+}
+impl From<resources::ServiceRequest> for GoalAddressesSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> GoalAddressesSearchTarget {
+        GoalAddressesSearchTarget::ServiceRequest(resource)
+    }
+}
+impl From<resources::MedicationRequest> for GoalAddressesSearchTarget {
+    fn from(resource: resources::MedicationRequest) -> GoalAddressesSearchTarget {
+        GoalAddressesSearchTarget::MedicationRequest(resource)
+    }
+}
+impl From<resources::Condition> for GoalAddressesSearchTarget {
+    fn from(resource: resources::Condition) -> GoalAddressesSearchTarget {
+        GoalAddressesSearchTarget::Condition(resource)
+    }
+}
+impl From<resources::NutritionOrder> for GoalAddressesSearchTarget {
+    fn from(resource: resources::NutritionOrder) -> GoalAddressesSearchTarget {
+        GoalAddressesSearchTarget::NutritionOrder(resource)
+    }
+}
+/// The subject reference search parameter of the Goal resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GoalSubjectSearchReference;
+impl ReferenceSearchParameter for GoalSubjectSearchReference {
+    type Resource = resources::Goal;
+    type Target = GoalSubjectSearchTarget;
+    fn parameter(&self) -> GoalSearchParameter {
+        GoalSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Goal resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GoalSubjectSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for GoalSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> GoalSubjectSearchTarget {
+        GoalSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Group> for GoalSubjectSearchTarget {
+    fn from(resource: resources::Group) -> GoalSubjectSearchTarget {
+        GoalSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for GoalSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> GoalSubjectSearchTarget {
+        GoalSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The characteristic-reference reference search parameter of the Group resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GroupCharacteristicReferenceSearchReference;
+impl ReferenceSearchParameter for GroupCharacteristicReferenceSearchReference {
+    type Resource = resources::Group;
+    type Target = resources::Resource;
+    fn parameter(&self) -> GroupSearchParameter {
+        GroupSearchParameter::CharacteristicReference
+    }
+}
+/// The managing-entity reference search parameter of the Group resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GroupManagingEntitySearchReference;
+impl ReferenceSearchParameter for GroupManagingEntitySearchReference {
+    type Resource = resources::Group;
+    type Target = GroupManagingEntitySearchTarget;
+    fn parameter(&self) -> GroupSearchParameter {
+        GroupSearchParameter::ManagingEntity
+    }
+}
+/// Target resources of the managing-entity reference search parameter of the Group resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GroupManagingEntitySearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for GroupManagingEntitySearchTarget {
+    fn from(resource: resources::Organization) -> GroupManagingEntitySearchTarget {
+        GroupManagingEntitySearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for GroupManagingEntitySearchTarget {
+    fn from(resource: resources::RelatedPerson) -> GroupManagingEntitySearchTarget {
+        GroupManagingEntitySearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for GroupManagingEntitySearchTarget {
+    fn from(resource: resources::PractitionerRole) -> GroupManagingEntitySearchTarget {
+        GroupManagingEntitySearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for GroupManagingEntitySearchTarget {
+    fn from(resource: resources::Practitioner) -> GroupManagingEntitySearchTarget {
+        GroupManagingEntitySearchTarget::Practitioner(resource)
+    }
+}
+/// The member reference search parameter of the Group resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GroupMemberSearchReference;
+impl ReferenceSearchParameter for GroupMemberSearchReference {
+    type Resource = resources::Group;
+    type Target = GroupMemberSearchTarget;
+    fn parameter(&self) -> GroupSearchParameter {
+        GroupSearchParameter::Member
+    }
+}
+/// Target resources of the member reference search parameter of the Group resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GroupMemberSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Specimen target resources
+    Specimen(resources::Specimen),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for GroupMemberSearchTarget {
+    fn from(resource: resources::HealthcareService) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::CareTeam> for GroupMemberSearchTarget {
+    fn from(resource: resources::CareTeam) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for GroupMemberSearchTarget {
+    fn from(resource: resources::Device) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for GroupMemberSearchTarget {
+    fn from(resource: resources::Organization) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for GroupMemberSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for GroupMemberSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Group> for GroupMemberSearchTarget {
+    fn from(resource: resources::Group) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Practitioner> for GroupMemberSearchTarget {
+    fn from(resource: resources::Practitioner) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Specimen> for GroupMemberSearchTarget {
+    fn from(resource: resources::Specimen) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::Specimen(resource)
+    }
+}
+impl From<resources::Location> for GroupMemberSearchTarget {
+    fn from(resource: resources::Location) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::Location(resource)
+    }
+}
+impl From<resources::Patient> for GroupMemberSearchTarget {
+    fn from(resource: resources::Patient) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the GuidanceResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GuidanceResponsePatientSearchReference;
+impl ReferenceSearchParameter for GuidanceResponsePatientSearchReference {
+    type Resource = resources::GuidanceResponse;
+    type Target = resources::Patient;
+    fn parameter(&self) -> GuidanceResponseSearchParameter {
+        GuidanceResponseSearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the GuidanceResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GuidanceResponseSubjectSearchReference;
+impl ReferenceSearchParameter for GuidanceResponseSubjectSearchReference {
+    type Resource = resources::GuidanceResponse;
+    type Target = GuidanceResponseSubjectSearchTarget;
+    fn parameter(&self) -> GuidanceResponseSearchParameter {
+        GuidanceResponseSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the GuidanceResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GuidanceResponseSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for GuidanceResponseSubjectSearchTarget {
+    fn from(resource: resources::Group) -> GuidanceResponseSubjectSearchTarget {
+        GuidanceResponseSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for GuidanceResponseSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> GuidanceResponseSubjectSearchTarget {
+        GuidanceResponseSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The coverage-area reference search parameter of the HealthcareService resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HealthcareServiceCoverageAreaSearchReference;
+impl ReferenceSearchParameter for HealthcareServiceCoverageAreaSearchReference {
+    type Resource = resources::HealthcareService;
+    type Target = resources::Location;
+    fn parameter(&self) -> HealthcareServiceSearchParameter {
+        HealthcareServiceSearchParameter::CoverageArea
+    }
+}
+/// The endpoint reference search parameter of the HealthcareService resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HealthcareServiceEndpointSearchReference;
+impl ReferenceSearchParameter for HealthcareServiceEndpointSearchReference {
+    type Resource = resources::HealthcareService;
+    type Target = resources::Endpoint;
+    fn parameter(&self) -> HealthcareServiceSearchParameter {
+        HealthcareServiceSearchParameter::Endpoint
+    }
+}
+/// The location reference search parameter of the HealthcareService resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HealthcareServiceLocationSearchReference;
+impl ReferenceSearchParameter for HealthcareServiceLocationSearchReference {
+    type Resource = resources::HealthcareService;
+    type Target = resources::Location;
+    fn parameter(&self) -> HealthcareServiceSearchParameter {
+        HealthcareServiceSearchParameter::Location
+    }
+}
+/// The offered-in reference search parameter of the HealthcareService resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HealthcareServiceOfferedInSearchReference;
+impl ReferenceSearchParameter for HealthcareServiceOfferedInSearchReference {
+    type Resource = resources::HealthcareService;
+    type Target = resources::HealthcareService;
+    fn parameter(&self) -> HealthcareServiceSearchParameter {
+        HealthcareServiceSearchParameter::OfferedIn
+    }
+}
+/// The organization reference search parameter of the HealthcareService resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HealthcareServiceOrganizationSearchReference;
+impl ReferenceSearchParameter for HealthcareServiceOrganizationSearchReference {
+    type Resource = resources::HealthcareService;
+    type Target = resources::Organization;
+    fn parameter(&self) -> HealthcareServiceSearchParameter {
+        HealthcareServiceSearchParameter::Organization
+    }
+}
+/// The patient reference search parameter of the ImagingSelection resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingSelectionPatientSearchReference;
+impl ReferenceSearchParameter for ImagingSelectionPatientSearchReference {
+    type Resource = resources::ImagingSelection;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ImagingSelectionSearchParameter {
+        ImagingSelectionSearchParameter::Patient
+    }
+}
+/// The based-on reference search parameter of the ImagingSelection resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingSelectionBasedOnSearchReference;
+impl ReferenceSearchParameter for ImagingSelectionBasedOnSearchReference {
+    type Resource = resources::ImagingSelection;
+    type Target = ImagingSelectionBasedOnSearchTarget;
+    fn parameter(&self) -> ImagingSelectionSearchParameter {
+        ImagingSelectionSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the ImagingSelection resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImagingSelectionBasedOnSearchTarget {
+    /// Variant for Appointment target resources
+    Appointment(resources::Appointment),
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for Task target resources
+    Task(resources::Task),
+    /// Variant for AppointmentResponse target resources
+    AppointmentResponse(resources::AppointmentResponse),
+}
+impl From<resources::Appointment> for ImagingSelectionBasedOnSearchTarget {
+    fn from(resource: resources::Appointment) -> ImagingSelectionBasedOnSearchTarget {
+        ImagingSelectionBasedOnSearchTarget::Appointment(resource)
+    }
+}
+impl From<resources::ServiceRequest> for ImagingSelectionBasedOnSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> ImagingSelectionBasedOnSearchTarget {
+        ImagingSelectionBasedOnSearchTarget::ServiceRequest(resource)
+    }
+}
+impl From<resources::CarePlan> for ImagingSelectionBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> ImagingSelectionBasedOnSearchTarget {
+        ImagingSelectionBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::Task> for ImagingSelectionBasedOnSearchTarget {
+    fn from(resource: resources::Task) -> ImagingSelectionBasedOnSearchTarget {
+        ImagingSelectionBasedOnSearchTarget::Task(resource)
+    }
+}
+impl From<resources::AppointmentResponse> for ImagingSelectionBasedOnSearchTarget {
+    fn from(resource: resources::AppointmentResponse) -> ImagingSelectionBasedOnSearchTarget {
+        ImagingSelectionBasedOnSearchTarget::AppointmentResponse(resource)
+    }
+}
+/// The body-structure reference search parameter of the ImagingSelection resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingSelectionBodyStructureSearchReference;
+impl ReferenceSearchParameter for ImagingSelectionBodyStructureSearchReference {
+    type Resource = resources::ImagingSelection;
+    type Target = resources::BodyStructure;
+    fn parameter(&self) -> ImagingSelectionSearchParameter {
+        ImagingSelectionSearchParameter::BodyStructure
+    }
+}
+/// The derived-from reference search parameter of the ImagingSelection resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingSelectionDerivedFromSearchReference;
+impl ReferenceSearchParameter for ImagingSelectionDerivedFromSearchReference {
+    type Resource = resources::ImagingSelection;
+    type Target = ImagingSelectionDerivedFromSearchTarget;
+    fn parameter(&self) -> ImagingSelectionSearchParameter {
+        ImagingSelectionSearchParameter::DerivedFrom
+    }
+}
+/// Target resources of the derived-from reference search parameter of the ImagingSelection resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImagingSelectionDerivedFromSearchTarget {
+    /// Variant for ImagingStudy target resources
+    ImagingStudy(resources::ImagingStudy),
+    /// Variant for DocumentReference target resources
+    DocumentReference(resources::DocumentReference),
+}
+impl From<resources::ImagingStudy> for ImagingSelectionDerivedFromSearchTarget {
+    fn from(resource: resources::ImagingStudy) -> ImagingSelectionDerivedFromSearchTarget {
+        ImagingSelectionDerivedFromSearchTarget::ImagingStudy(resource)
+    }
+}
+impl From<resources::DocumentReference> for ImagingSelectionDerivedFromSearchTarget {
+    fn from(resource: resources::DocumentReference) -> ImagingSelectionDerivedFromSearchTarget {
+        ImagingSelectionDerivedFromSearchTarget::DocumentReference(resource)
+    }
+}
+/// The subject reference search parameter of the ImagingSelection resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingSelectionSubjectSearchReference;
+impl ReferenceSearchParameter for ImagingSelectionSubjectSearchReference {
+    type Resource = resources::ImagingSelection;
+    type Target = ImagingSelectionSubjectSearchTarget;
+    fn parameter(&self) -> ImagingSelectionSearchParameter {
+        ImagingSelectionSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the ImagingSelection resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImagingSelectionSubjectSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Specimen target resources
+    Specimen(resources::Specimen),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+}
+impl From<resources::Device> for ImagingSelectionSubjectSearchTarget {
+    fn from(resource: resources::Device) -> ImagingSelectionSubjectSearchTarget {
+        ImagingSelectionSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for ImagingSelectionSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> ImagingSelectionSubjectSearchTarget {
+        ImagingSelectionSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Procedure> for ImagingSelectionSubjectSearchTarget {
+    fn from(resource: resources::Procedure) -> ImagingSelectionSubjectSearchTarget {
+        ImagingSelectionSubjectSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::Group> for ImagingSelectionSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ImagingSelectionSubjectSearchTarget {
+        ImagingSelectionSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Practitioner> for ImagingSelectionSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> ImagingSelectionSubjectSearchTarget {
+        ImagingSelectionSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Specimen> for ImagingSelectionSubjectSearchTarget {
+    fn from(resource: resources::Specimen) -> ImagingSelectionSubjectSearchTarget {
+        ImagingSelectionSubjectSearchTarget::Specimen(resource)
+    }
+}
+impl From<resources::Substance> for ImagingSelectionSubjectSearchTarget {
+    fn from(resource: resources::Substance) -> ImagingSelectionSubjectSearchTarget {
+        ImagingSelectionSubjectSearchTarget::Substance(resource)
+    }
+}
+impl From<resources::Location> for ImagingSelectionSubjectSearchTarget {
+    fn from(resource: resources::Location) -> ImagingSelectionSubjectSearchTarget {
+        ImagingSelectionSubjectSearchTarget::Location(resource)
+    }
+}
+impl From<resources::Patient> for ImagingSelectionSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ImagingSelectionSubjectSearchTarget {
+        ImagingSelectionSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Medication> for ImagingSelectionSubjectSearchTarget {
+    fn from(resource: resources::Medication) -> ImagingSelectionSubjectSearchTarget {
+        ImagingSelectionSubjectSearchTarget::Medication(resource)
+    }
+}
+/// The patient reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudyPatientSearchReference;
+impl ReferenceSearchParameter for ImagingStudyPatientSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudyEncounterSearchReference;
+impl ReferenceSearchParameter for ImagingStudyEncounterSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::Encounter
+    }
+}
+/// The based-on reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudyBasedOnSearchReference;
+impl ReferenceSearchParameter for ImagingStudyBasedOnSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = ImagingStudyBasedOnSearchTarget;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the ImagingStudy resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImagingStudyBasedOnSearchTarget {
+    /// Variant for Appointment target resources
+    Appointment(resources::Appointment),
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for Task target resources
+    Task(resources::Task),
+    /// Variant for AppointmentResponse target resources
+    AppointmentResponse(resources::AppointmentResponse),
+}
+impl From<resources::Appointment> for ImagingStudyBasedOnSearchTarget {
+    fn from(resource: resources::Appointment) -> ImagingStudyBasedOnSearchTarget {
+        ImagingStudyBasedOnSearchTarget::Appointment(resource)
+    }
+}
+impl From<resources::ServiceRequest> for ImagingStudyBasedOnSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> ImagingStudyBasedOnSearchTarget {
+        ImagingStudyBasedOnSearchTarget::ServiceRequest(resource)
+    }
+}
+impl From<resources::CarePlan> for ImagingStudyBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> ImagingStudyBasedOnSearchTarget {
+        ImagingStudyBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::Task> for ImagingStudyBasedOnSearchTarget {
+    fn from(resource: resources::Task) -> ImagingStudyBasedOnSearchTarget {
+        ImagingStudyBasedOnSearchTarget::Task(resource)
+    }
+}
+impl From<resources::AppointmentResponse> for ImagingStudyBasedOnSearchTarget {
+    fn from(resource: resources::AppointmentResponse) -> ImagingStudyBasedOnSearchTarget {
+        ImagingStudyBasedOnSearchTarget::AppointmentResponse(resource)
+    }
+}
+/// The body-structure reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudyBodyStructureSearchReference;
+impl ReferenceSearchParameter for ImagingStudyBodyStructureSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = resources::BodyStructure;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::BodyStructure
+    }
+}
+/// The endpoint reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudyEndpointSearchReference;
+impl ReferenceSearchParameter for ImagingStudyEndpointSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = resources::Endpoint;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::Endpoint
+    }
+}
+/// The performer reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudyPerformerSearchReference;
+impl ReferenceSearchParameter for ImagingStudyPerformerSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = ImagingStudyPerformerSearchTarget;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the ImagingStudy resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImagingStudyPerformerSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for ImagingStudyPerformerSearchTarget {
+    fn from(resource: resources::HealthcareService) -> ImagingStudyPerformerSearchTarget {
+        ImagingStudyPerformerSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::Organization> for ImagingStudyPerformerSearchTarget {
+    fn from(resource: resources::Organization) -> ImagingStudyPerformerSearchTarget {
+        ImagingStudyPerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for ImagingStudyPerformerSearchTarget {
+    fn from(resource: resources::CareTeam) -> ImagingStudyPerformerSearchTarget {
+        ImagingStudyPerformerSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for ImagingStudyPerformerSearchTarget {
+    fn from(resource: resources::Device) -> ImagingStudyPerformerSearchTarget {
+        ImagingStudyPerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ImagingStudyPerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ImagingStudyPerformerSearchTarget {
+        ImagingStudyPerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ImagingStudyPerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ImagingStudyPerformerSearchTarget {
+        ImagingStudyPerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ImagingStudyPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ImagingStudyPerformerSearchTarget {
+        ImagingStudyPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ImagingStudyPerformerSearchTarget {
+    fn from(resource: resources::Patient) -> ImagingStudyPerformerSearchTarget {
+        ImagingStudyPerformerSearchTarget::Patient(resource)
+    }
+}
+/// The referrer reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudyReferrerSearchReference;
+impl ReferenceSearchParameter for ImagingStudyReferrerSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = ImagingStudyReferrerSearchTarget;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::Referrer
+    }
+}
+/// Target resources of the referrer reference search parameter of the ImagingStudy resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImagingStudyReferrerSearchTarget {
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::PractitionerRole> for ImagingStudyReferrerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ImagingStudyReferrerSearchTarget {
+        ImagingStudyReferrerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ImagingStudyReferrerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ImagingStudyReferrerSearchTarget {
+        ImagingStudyReferrerSearchTarget::Practitioner(resource)
+    }
+}
+/// The subject reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudySubjectSearchReference;
+impl ReferenceSearchParameter for ImagingStudySubjectSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = ImagingStudySubjectSearchTarget;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the ImagingStudy resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImagingStudySubjectSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Device> for ImagingStudySubjectSearchTarget {
+    fn from(resource: resources::Device) -> ImagingStudySubjectSearchTarget {
+        ImagingStudySubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Group> for ImagingStudySubjectSearchTarget {
+    fn from(resource: resources::Group) -> ImagingStudySubjectSearchTarget {
+        ImagingStudySubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for ImagingStudySubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ImagingStudySubjectSearchTarget {
+        ImagingStudySubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the Immunization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationPatientSearchReference;
+impl ReferenceSearchParameter for ImmunizationPatientSearchReference {
+    type Resource = resources::Immunization;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ImmunizationSearchParameter {
+        ImmunizationSearchParameter::Patient
+    }
+}
+/// The location reference search parameter of the Immunization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationLocationSearchReference;
+impl ReferenceSearchParameter for ImmunizationLocationSearchReference {
+    type Resource = resources::Immunization;
+    type Target = resources::Location;
+    fn parameter(&self) -> ImmunizationSearchParameter {
+        ImmunizationSearchParameter::Location
+    }
+}
+/// The manufacturer reference search parameter of the Immunization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationManufacturerSearchReference;
+impl ReferenceSearchParameter for ImmunizationManufacturerSearchReference {
+    type Resource = resources::Immunization;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ImmunizationSearchParameter {
+        ImmunizationSearchParameter::Manufacturer
+    }
+}
+/// The performer reference search parameter of the Immunization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationPerformerSearchReference;
+impl ReferenceSearchParameter for ImmunizationPerformerSearchReference {
+    type Resource = resources::Immunization;
+    type Target = ImmunizationPerformerSearchTarget;
+    fn parameter(&self) -> ImmunizationSearchParameter {
+        ImmunizationSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the Immunization resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImmunizationPerformerSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for ImmunizationPerformerSearchTarget {
+    fn from(resource: resources::Organization) -> ImmunizationPerformerSearchTarget {
+        ImmunizationPerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ImmunizationPerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ImmunizationPerformerSearchTarget {
+        ImmunizationPerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ImmunizationPerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ImmunizationPerformerSearchTarget {
+        ImmunizationPerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ImmunizationPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ImmunizationPerformerSearchTarget {
+        ImmunizationPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ImmunizationPerformerSearchTarget {
+    fn from(resource: resources::Patient) -> ImmunizationPerformerSearchTarget {
+        ImmunizationPerformerSearchTarget::Patient(resource)
+    }
+}
+/// The reaction reference search parameter of the Immunization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationReactionSearchReference;
+impl ReferenceSearchParameter for ImmunizationReactionSearchReference {
+    type Resource = resources::Immunization;
+    type Target = resources::Observation;
+    fn parameter(&self) -> ImmunizationSearchParameter {
+        ImmunizationSearchParameter::Reaction
+    }
+}
+/// The reason-reference reference search parameter of the Immunization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationReasonReferenceSearchReference;
+impl ReferenceSearchParameter for ImmunizationReasonReferenceSearchReference {
+    type Resource = resources::Immunization;
+    type Target = ImmunizationReasonReferenceSearchTarget;
+    fn parameter(&self) -> ImmunizationSearchParameter {
+        ImmunizationSearchParameter::ReasonReference
+    }
+}
+/// Target resources of the reason-reference reference search parameter of the Immunization resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImmunizationReasonReferenceSearchTarget {
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for DiagnosticReport target resources
+    DiagnosticReport(resources::DiagnosticReport),
+    /// Variant for Condition target resources
+    Condition(resources::Condition),
+}
+impl From<resources::Observation> for ImmunizationReasonReferenceSearchTarget {
+    fn from(resource: resources::Observation) -> ImmunizationReasonReferenceSearchTarget {
+        ImmunizationReasonReferenceSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::DiagnosticReport> for ImmunizationReasonReferenceSearchTarget {
+    fn from(resource: resources::DiagnosticReport) -> ImmunizationReasonReferenceSearchTarget {
+        ImmunizationReasonReferenceSearchTarget::DiagnosticReport(resource)
+    }
+}
+impl From<resources::Condition> for ImmunizationReasonReferenceSearchTarget {
+    fn from(resource: resources::Condition) -> ImmunizationReasonReferenceSearchTarget {
+        ImmunizationReasonReferenceSearchTarget::Condition(resource)
+    }
+}
+/// The patient reference search parameter of the ImmunizationEvaluation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationEvaluationPatientSearchReference;
+impl ReferenceSearchParameter for ImmunizationEvaluationPatientSearchReference {
+    type Resource = resources::ImmunizationEvaluation;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ImmunizationEvaluationSearchParameter {
+        ImmunizationEvaluationSearchParameter::Patient
+    }
+}
+/// The immunization-event reference search parameter of the ImmunizationEvaluation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationEvaluationImmunizationEventSearchReference;
+impl ReferenceSearchParameter for ImmunizationEvaluationImmunizationEventSearchReference {
+    type Resource = resources::ImmunizationEvaluation;
+    type Target = resources::Immunization;
+    fn parameter(&self) -> ImmunizationEvaluationSearchParameter {
+        ImmunizationEvaluationSearchParameter::ImmunizationEvent
+    }
+}
+/// The patient reference search parameter of the ImmunizationRecommendation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationRecommendationPatientSearchReference;
+impl ReferenceSearchParameter for ImmunizationRecommendationPatientSearchReference {
+    type Resource = resources::ImmunizationRecommendation;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ImmunizationRecommendationSearchParameter {
+        ImmunizationRecommendationSearchParameter::Patient
+    }
+}
+/// The information reference search parameter of the ImmunizationRecommendation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationRecommendationInformationSearchReference;
+impl ReferenceSearchParameter for ImmunizationRecommendationInformationSearchReference {
+    type Resource = resources::ImmunizationRecommendation;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ImmunizationRecommendationSearchParameter {
+        ImmunizationRecommendationSearchParameter::Information
+    }
+}
+/// The support reference search parameter of the ImmunizationRecommendation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationRecommendationSupportSearchReference;
+impl ReferenceSearchParameter for ImmunizationRecommendationSupportSearchReference {
+    type Resource = resources::ImmunizationRecommendation;
+    type Target = ImmunizationRecommendationSupportSearchTarget;
+    fn parameter(&self) -> ImmunizationRecommendationSearchParameter {
+        ImmunizationRecommendationSearchParameter::Support
+    }
+}
+/// Target resources of the support reference search parameter of the ImmunizationRecommendation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImmunizationRecommendationSupportSearchTarget {
+    /// Variant for ImmunizationEvaluation target resources
+    ImmunizationEvaluation(resources::ImmunizationEvaluation),
+    /// Variant for Immunization target resources
+    Immunization(resources::Immunization),
+}
+impl From<resources::ImmunizationEvaluation> for ImmunizationRecommendationSupportSearchTarget {
+    fn from(resource: resources::ImmunizationEvaluation) -> ImmunizationRecommendationSupportSearchTarget {
+        ImmunizationRecommendationSupportSearchTarget::ImmunizationEvaluation(resource)
+    }
+}
+impl From<resources::Immunization> for ImmunizationRecommendationSupportSearchTarget {
+    fn from(resource: resources::Immunization) -> ImmunizationRecommendationSupportSearchTarget {
+        ImmunizationRecommendationSupportSearchTarget::Immunization(resource)
+    }
+}
+/// The depends-on reference search parameter of the ImplementationGuide resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImplementationGuideDependsOnSearchReference;
+impl ReferenceSearchParameter for ImplementationGuideDependsOnSearchReference {
+    type Resource = resources::ImplementationGuide;
+    type Target = resources::ImplementationGuide;
+    fn parameter(&self) -> ImplementationGuideSearchParameter {
+        ImplementationGuideSearchParameter::DependsOn
+    }
+}
+/// The global reference search parameter of the ImplementationGuide resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImplementationGuideGlobalSearchReference;
+impl ReferenceSearchParameter for ImplementationGuideGlobalSearchReference {
+    type Resource = resources::ImplementationGuide;
+    type Target = resources::StructureDefinition;
+    fn parameter(&self) -> ImplementationGuideSearchParameter {
+        ImplementationGuideSearchParameter::Global
+    }
+}
+/// The resource reference search parameter of the ImplementationGuide resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImplementationGuideResourceSearchReference;
+impl ReferenceSearchParameter for ImplementationGuideResourceSearchReference {
+    type Resource = resources::ImplementationGuide;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ImplementationGuideSearchParameter {
+        ImplementationGuideSearchParameter::Resource
+    }
+}
+/// The for reference search parameter of the Ingredient resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IngredientForSearchReference;
+impl ReferenceSearchParameter for IngredientForSearchReference {
+    type Resource = resources::Ingredient;
+    type Target = IngredientForSearchTarget;
+    fn parameter(&self) -> IngredientSearchParameter {
+        IngredientSearchParameter::For
+    }
+}
+/// Target resources of the for reference search parameter of the Ingredient resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum IngredientForSearchTarget {
+    /// Variant for MedicinalProductDefinition target resources
+    MedicinalProductDefinition(resources::MedicinalProductDefinition),
+    /// Variant for AdministrableProductDefinition target resources
+    AdministrableProductDefinition(resources::AdministrableProductDefinition),
+    /// Variant for ManufacturedItemDefinition target resources
+    ManufacturedItemDefinition(resources::ManufacturedItemDefinition),
+}
+impl From<resources::MedicinalProductDefinition> for IngredientForSearchTarget {
+    fn from(resource: resources::MedicinalProductDefinition) -> IngredientForSearchTarget {
+        IngredientForSearchTarget::MedicinalProductDefinition(resource)
+    }
+}
+impl From<resources::AdministrableProductDefinition> for IngredientForSearchTarget {
+    fn from(resource: resources::AdministrableProductDefinition) -> IngredientForSearchTarget {
+        IngredientForSearchTarget::AdministrableProductDefinition(resource)
+    }
+}
+impl From<resources::ManufacturedItemDefinition> for IngredientForSearchTarget {
+    fn from(resource: resources::ManufacturedItemDefinition) -> IngredientForSearchTarget {
+        IngredientForSearchTarget::ManufacturedItemDefinition(resource)
+    }
+}
+/// The manufacturer reference search parameter of the Ingredient resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IngredientManufacturerSearchReference;
+impl ReferenceSearchParameter for IngredientManufacturerSearchReference {
+    type Resource = resources::Ingredient;
+    type Target = resources::Organization;
+    fn parameter(&self) -> IngredientSearchParameter {
+        IngredientSearchParameter::Manufacturer
+    }
+}
+/// The substance reference search parameter of the Ingredient resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IngredientSubstanceSearchReference;
+impl ReferenceSearchParameter for IngredientSubstanceSearchReference {
+    type Resource = resources::Ingredient;
+    type Target = resources::SubstanceDefinition;
+    fn parameter(&self) -> IngredientSearchParameter {
+        IngredientSearchParameter::Substance
+    }
+}
+/// The substance-definition reference search parameter of the Ingredient resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IngredientSubstanceDefinitionSearchReference;
+impl ReferenceSearchParameter for IngredientSubstanceDefinitionSearchReference {
+    type Resource = resources::Ingredient;
+    type Target = resources::SubstanceDefinition;
+    fn parameter(&self) -> IngredientSearchParameter {
+        IngredientSearchParameter::SubstanceDefinition
+    }
+}
+/// The administered-by reference search parameter of the InsurancePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InsurancePlanAdministeredBySearchReference;
+impl ReferenceSearchParameter for InsurancePlanAdministeredBySearchReference {
+    type Resource = resources::InsurancePlan;
+    type Target = resources::Organization;
+    fn parameter(&self) -> InsurancePlanSearchParameter {
+        InsurancePlanSearchParameter::AdministeredBy
+    }
+}
+/// The endpoint reference search parameter of the InsurancePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InsurancePlanEndpointSearchReference;
+impl ReferenceSearchParameter for InsurancePlanEndpointSearchReference {
+    type Resource = resources::InsurancePlan;
+    type Target = resources::Endpoint;
+    fn parameter(&self) -> InsurancePlanSearchParameter {
+        InsurancePlanSearchParameter::Endpoint
+    }
+}
+/// The owned-by reference search parameter of the InsurancePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InsurancePlanOwnedBySearchReference;
+impl ReferenceSearchParameter for InsurancePlanOwnedBySearchReference {
+    type Resource = resources::InsurancePlan;
+    type Target = resources::Organization;
+    fn parameter(&self) -> InsurancePlanSearchParameter {
+        InsurancePlanSearchParameter::OwnedBy
+    }
+}
+/// The subject reference search parameter of the InventoryItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InventoryItemSubjectSearchReference;
+impl ReferenceSearchParameter for InventoryItemSubjectSearchReference {
+    type Resource = resources::InventoryItem;
+    type Target = InventoryItemSubjectSearchTarget;
+    fn parameter(&self) -> InventoryItemSearchParameter {
+        InventoryItemSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the InventoryItem resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InventoryItemSubjectSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for InventoryItemSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> InventoryItemSubjectSearchTarget {
+        InventoryItemSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for InventoryItemSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> InventoryItemSubjectSearchTarget {
+        InventoryItemSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The item-reference reference search parameter of the InventoryReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InventoryReportItemReferenceSearchReference;
+impl ReferenceSearchParameter for InventoryReportItemReferenceSearchReference {
+    type Resource = resources::InventoryReport;
+    type Target = InventoryReportItemReferenceSearchTarget;
+    fn parameter(&self) -> InventoryReportSearchParameter {
+        InventoryReportSearchParameter::ItemReference
+    }
+}
+/// Target resources of the item-reference reference search parameter of the InventoryReport resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InventoryReportItemReferenceSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for InventoryItem target resources
+    InventoryItem(resources::InventoryItem),
+    /// Variant for NutritionProduct target resources
+    NutritionProduct(resources::NutritionProduct),
+    /// Variant for BiologicallyDerivedProduct target resources
+    BiologicallyDerivedProduct(resources::BiologicallyDerivedProduct),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+}
+impl From<resources::Device> for InventoryReportItemReferenceSearchTarget {
+    fn from(resource: resources::Device) -> InventoryReportItemReferenceSearchTarget {
+        InventoryReportItemReferenceSearchTarget::Device(resource)
+    }
+}
+impl From<resources::InventoryItem> for InventoryReportItemReferenceSearchTarget {
+    fn from(resource: resources::InventoryItem) -> InventoryReportItemReferenceSearchTarget {
+        InventoryReportItemReferenceSearchTarget::InventoryItem(resource)
+    }
+}
+impl From<resources::NutritionProduct> for InventoryReportItemReferenceSearchTarget {
+    fn from(resource: resources::NutritionProduct) -> InventoryReportItemReferenceSearchTarget {
+        InventoryReportItemReferenceSearchTarget::NutritionProduct(resource)
+    }
+}
+impl From<resources::BiologicallyDerivedProduct> for InventoryReportItemReferenceSearchTarget {
+    fn from(resource: resources::BiologicallyDerivedProduct) -> InventoryReportItemReferenceSearchTarget {
+        InventoryReportItemReferenceSearchTarget::BiologicallyDerivedProduct(resource)
+    }
+}
+impl From<resources::Medication> for InventoryReportItemReferenceSearchTarget {
+    fn from(resource: resources::Medication) -> InventoryReportItemReferenceSearchTarget {
+        InventoryReportItemReferenceSearchTarget::Medication(resource)
+    }
+}
+/// The patient reference search parameter of the Invoice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InvoicePatientSearchReference;
+impl ReferenceSearchParameter for InvoicePatientSearchReference {
+    type Resource = resources::Invoice;
+    type Target = resources::Patient;
+    fn parameter(&self) -> InvoiceSearchParameter {
+        InvoiceSearchParameter::Patient
+    }
+}
+/// The account reference search parameter of the Invoice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InvoiceAccountSearchReference;
+impl ReferenceSearchParameter for InvoiceAccountSearchReference {
+    type Resource = resources::Invoice;
+    type Target = resources::Account;
+    fn parameter(&self) -> InvoiceSearchParameter {
+        InvoiceSearchParameter::Account
+    }
+}
+/// The issuer reference search parameter of the Invoice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InvoiceIssuerSearchReference;
+impl ReferenceSearchParameter for InvoiceIssuerSearchReference {
+    type Resource = resources::Invoice;
+    type Target = resources::Organization;
+    fn parameter(&self) -> InvoiceSearchParameter {
+        InvoiceSearchParameter::Issuer
+    }
+}
+/// The participant reference search parameter of the Invoice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InvoiceParticipantSearchReference;
+impl ReferenceSearchParameter for InvoiceParticipantSearchReference {
+    type Resource = resources::Invoice;
+    type Target = InvoiceParticipantSearchTarget;
+    fn parameter(&self) -> InvoiceSearchParameter {
+        InvoiceSearchParameter::Participant
+    }
+}
+/// Target resources of the participant reference search parameter of the Invoice resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InvoiceParticipantSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for InvoiceParticipantSearchTarget {
+    fn from(resource: resources::Organization) -> InvoiceParticipantSearchTarget {
+        InvoiceParticipantSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for InvoiceParticipantSearchTarget {
+    fn from(resource: resources::Device) -> InvoiceParticipantSearchTarget {
+        InvoiceParticipantSearchTarget::Device(resource)
+    }
+}
+impl From<resources::RelatedPerson> for InvoiceParticipantSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> InvoiceParticipantSearchTarget {
+        InvoiceParticipantSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for InvoiceParticipantSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> InvoiceParticipantSearchTarget {
+        InvoiceParticipantSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for InvoiceParticipantSearchTarget {
+    fn from(resource: resources::Practitioner) -> InvoiceParticipantSearchTarget {
+        InvoiceParticipantSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for InvoiceParticipantSearchTarget {
+    fn from(resource: resources::Patient) -> InvoiceParticipantSearchTarget {
+        InvoiceParticipantSearchTarget::Patient(resource)
+    }
+}
+/// The recipient reference search parameter of the Invoice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InvoiceRecipientSearchReference;
+impl ReferenceSearchParameter for InvoiceRecipientSearchReference {
+    type Resource = resources::Invoice;
+    type Target = InvoiceRecipientSearchTarget;
+    fn parameter(&self) -> InvoiceSearchParameter {
+        InvoiceSearchParameter::Recipient
+    }
+}
+/// Target resources of the recipient reference search parameter of the Invoice resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InvoiceRecipientSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for InvoiceRecipientSearchTarget {
+    fn from(resource: resources::Organization) -> InvoiceRecipientSearchTarget {
+        InvoiceRecipientSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for InvoiceRecipientSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> InvoiceRecipientSearchTarget {
+        InvoiceRecipientSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::Patient> for InvoiceRecipientSearchTarget {
+    fn from(resource: resources::Patient) -> InvoiceRecipientSearchTarget {
+        InvoiceRecipientSearchTarget::Patient(resource)
+    }
+}
+/// The subject reference search parameter of the Invoice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InvoiceSubjectSearchReference;
+impl ReferenceSearchParameter for InvoiceSubjectSearchReference {
+    type Resource = resources::Invoice;
+    type Target = InvoiceSubjectSearchTarget;
+    fn parameter(&self) -> InvoiceSearchParameter {
+        InvoiceSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Invoice resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InvoiceSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for InvoiceSubjectSearchTarget {
+    fn from(resource: resources::Group) -> InvoiceSubjectSearchTarget {
+        InvoiceSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for InvoiceSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> InvoiceSubjectSearchTarget {
+        InvoiceSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The composed-of reference search parameter of the Library resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LibraryComposedOfSearchReference;
+impl ReferenceSearchParameter for LibraryComposedOfSearchReference {
+    type Resource = resources::Library;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LibrarySearchParameter {
+        LibrarySearchParameter::ComposedOf
+    }
+}
+/// The depends-on reference search parameter of the Library resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LibraryDependsOnSearchReference;
+impl ReferenceSearchParameter for LibraryDependsOnSearchReference {
+    type Resource = resources::Library;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LibrarySearchParameter {
+        LibrarySearchParameter::DependsOn
+    }
+}
+/// The derived-from reference search parameter of the Library resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LibraryDerivedFromSearchReference;
+impl ReferenceSearchParameter for LibraryDerivedFromSearchReference {
+    type Resource = resources::Library;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LibrarySearchParameter {
+        LibrarySearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the Library resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LibraryPredecessorSearchReference;
+impl ReferenceSearchParameter for LibraryPredecessorSearchReference {
+    type Resource = resources::Library;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LibrarySearchParameter {
+        LibrarySearchParameter::Predecessor
+    }
+}
+/// The successor reference search parameter of the Library resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LibrarySuccessorSearchReference;
+impl ReferenceSearchParameter for LibrarySuccessorSearchReference {
+    type Resource = resources::Library;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LibrarySearchParameter {
+        LibrarySearchParameter::Successor
+    }
+}
+/// The author reference search parameter of the Linkage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LinkageAuthorSearchReference;
+impl ReferenceSearchParameter for LinkageAuthorSearchReference {
+    type Resource = resources::Linkage;
+    type Target = LinkageAuthorSearchTarget;
+    fn parameter(&self) -> LinkageSearchParameter {
+        LinkageSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the Linkage resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LinkageAuthorSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for LinkageAuthorSearchTarget {
+    fn from(resource: resources::Organization) -> LinkageAuthorSearchTarget {
+        LinkageAuthorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for LinkageAuthorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> LinkageAuthorSearchTarget {
+        LinkageAuthorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for LinkageAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> LinkageAuthorSearchTarget {
+        LinkageAuthorSearchTarget::Practitioner(resource)
+    }
+}
+/// The item reference search parameter of the Linkage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LinkageItemSearchReference;
+impl ReferenceSearchParameter for LinkageItemSearchReference {
+    type Resource = resources::Linkage;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LinkageSearchParameter {
+        LinkageSearchParameter::Item
+    }
+}
+/// The source reference search parameter of the Linkage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LinkageSourceSearchReference;
+impl ReferenceSearchParameter for LinkageSourceSearchReference {
+    type Resource = resources::Linkage;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LinkageSearchParameter {
+        LinkageSearchParameter::Source
+    }
+}
+/// The patient reference search parameter of the List resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListPatientSearchReference;
+impl ReferenceSearchParameter for ListPatientSearchReference {
+    type Resource = resources::List;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ListSearchParameter {
+        ListSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the List resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListEncounterSearchReference;
+impl ReferenceSearchParameter for ListEncounterSearchReference {
+    type Resource = resources::List;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ListSearchParameter {
+        ListSearchParameter::Encounter
+    }
+}
+/// The item reference search parameter of the List resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListItemSearchReference;
+impl ReferenceSearchParameter for ListItemSearchReference {
+    type Resource = resources::List;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ListSearchParameter {
+        ListSearchParameter::Item
+    }
+}
+/// The source reference search parameter of the List resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListSourceSearchReference;
+impl ReferenceSearchParameter for ListSourceSearchReference {
+    type Resource = resources::List;
+    type Target = ListSourceSearchTarget;
+    fn parameter(&self) -> ListSearchParameter {
+        ListSearchParameter::Source
+    }
+}
+/// Target resources of the source reference search parameter of the List resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ListSourceSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Device> for ListSourceSearchTarget {
+    fn from(resource: resources::Device) -> ListSourceSearchTarget {
+        ListSourceSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for ListSourceSearchTarget {
+    fn from(resource: resources::Organization) -> ListSourceSearchTarget {
+        ListSourceSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for ListSourceSearchTarget {
+    fn from(resource: resources::CareTeam) -> ListSourceSearchTarget {
+        ListSourceSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ListSourceSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ListSourceSearchTarget {
+        ListSourceSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ListSourceSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ListSourceSearchTarget {
+        ListSourceSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ListSourceSearchTarget {
+    fn from(resource: resources::Practitioner) -> ListSourceSearchTarget {
+        ListSourceSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ListSourceSearchTarget {
+    fn from(resource: resources::Patient) -> ListSourceSearchTarget {
+        ListSourceSearchTarget::Patient(resource)
+    }
+}
+/// The subject reference search parameter of the List resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListSubjectSearchReference;
+impl ReferenceSearchParameter for ListSubjectSearchReference {
+    type Resource = resources::List;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ListSearchParameter {
+        ListSearchParameter::Subject
+    }
+}
+/// The endpoint reference search parameter of the Location resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LocationEndpointSearchReference;
+impl ReferenceSearchParameter for LocationEndpointSearchReference {
+    type Resource = resources::Location;
+    type Target = resources::Endpoint;
+    fn parameter(&self) -> LocationSearchParameter {
+        LocationSearchParameter::Endpoint
+    }
+}
+/// The organization reference search parameter of the Location resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LocationOrganizationSearchReference;
+impl ReferenceSearchParameter for LocationOrganizationSearchReference {
+    type Resource = resources::Location;
+    type Target = resources::Organization;
+    fn parameter(&self) -> LocationSearchParameter {
+        LocationSearchParameter::Organization
+    }
+}
+/// The partof reference search parameter of the Location resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LocationPartofSearchReference;
+impl ReferenceSearchParameter for LocationPartofSearchReference {
+    type Resource = resources::Location;
+    type Target = resources::Location;
+    fn parameter(&self) -> LocationSearchParameter {
+        LocationSearchParameter::Partof
+    }
+}
+/// The composed-of reference search parameter of the Measure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureComposedOfSearchReference;
+impl ReferenceSearchParameter for MeasureComposedOfSearchReference {
+    type Resource = resources::Measure;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MeasureSearchParameter {
+        MeasureSearchParameter::ComposedOf
+    }
+}
+/// The depends-on reference search parameter of the Measure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureDependsOnSearchReference;
+impl ReferenceSearchParameter for MeasureDependsOnSearchReference {
+    type Resource = resources::Measure;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MeasureSearchParameter {
+        MeasureSearchParameter::DependsOn
+    }
+}
+/// The derived-from reference search parameter of the Measure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureDerivedFromSearchReference;
+impl ReferenceSearchParameter for MeasureDerivedFromSearchReference {
+    type Resource = resources::Measure;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MeasureSearchParameter {
+        MeasureSearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the Measure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasurePredecessorSearchReference;
+impl ReferenceSearchParameter for MeasurePredecessorSearchReference {
+    type Resource = resources::Measure;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MeasureSearchParameter {
+        MeasureSearchParameter::Predecessor
+    }
+}
+/// The successor reference search parameter of the Measure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureSuccessorSearchReference;
+impl ReferenceSearchParameter for MeasureSuccessorSearchReference {
+    type Resource = resources::Measure;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MeasureSearchParameter {
+        MeasureSearchParameter::Successor
+    }
+}
+/// The patient reference search parameter of the MeasureReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureReportPatientSearchReference;
+impl ReferenceSearchParameter for MeasureReportPatientSearchReference {
+    type Resource = resources::MeasureReport;
+    type Target = resources::Patient;
+    fn parameter(&self) -> MeasureReportSearchParameter {
+        MeasureReportSearchParameter::Patient
+    }
+}
+/// The evaluated-resource reference search parameter of the MeasureReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureReportEvaluatedResourceSearchReference;
+impl ReferenceSearchParameter for MeasureReportEvaluatedResourceSearchReference {
+    type Resource = resources::MeasureReport;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MeasureReportSearchParameter {
+        MeasureReportSearchParameter::EvaluatedResource
+    }
+}
+/// The location reference search parameter of the MeasureReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureReportLocationSearchReference;
+impl ReferenceSearchParameter for MeasureReportLocationSearchReference {
+    type Resource = resources::MeasureReport;
+    type Target = resources::Location;
+    fn parameter(&self) -> MeasureReportSearchParameter {
+        MeasureReportSearchParameter::Location
+    }
+}
+/// The measure reference search parameter of the MeasureReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureReportMeasureSearchReference;
+impl ReferenceSearchParameter for MeasureReportMeasureSearchReference {
+    type Resource = resources::MeasureReport;
+    type Target = resources::Measure;
+    fn parameter(&self) -> MeasureReportSearchParameter {
+        MeasureReportSearchParameter::Measure
+    }
+}
+/// The reporter reference search parameter of the MeasureReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureReportReporterSearchReference;
+impl ReferenceSearchParameter for MeasureReportReporterSearchReference {
+    type Resource = resources::MeasureReport;
+    type Target = MeasureReportReporterSearchTarget;
+    fn parameter(&self) -> MeasureReportSearchParameter {
+        MeasureReportSearchParameter::Reporter
+    }
+}
+/// Target resources of the reporter reference search parameter of the MeasureReport resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MeasureReportReporterSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Organization> for MeasureReportReporterSearchTarget {
+    fn from(resource: resources::Organization) -> MeasureReportReporterSearchTarget {
+        MeasureReportReporterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MeasureReportReporterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MeasureReportReporterSearchTarget {
+        MeasureReportReporterSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for MeasureReportReporterSearchTarget {
+    fn from(resource: resources::Practitioner) -> MeasureReportReporterSearchTarget {
+        MeasureReportReporterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for MeasureReportReporterSearchTarget {
+    fn from(resource: resources::Group) -> MeasureReportReporterSearchTarget {
+        MeasureReportReporterSearchTarget::Group(resource)
+    }
+}
+/// The subject reference search parameter of the MeasureReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureReportSubjectSearchReference;
+impl ReferenceSearchParameter for MeasureReportSubjectSearchReference {
+    type Resource = resources::MeasureReport;
+    type Target = MeasureReportSubjectSearchTarget;
+    fn parameter(&self) -> MeasureReportSearchParameter {
+        MeasureReportSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the MeasureReport resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MeasureReportSubjectSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for MeasureReportSubjectSearchTarget {
+    fn from(resource: resources::HealthcareService) -> MeasureReportSubjectSearchTarget {
+        MeasureReportSubjectSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::CareTeam> for MeasureReportSubjectSearchTarget {
+    fn from(resource: resources::CareTeam) -> MeasureReportSubjectSearchTarget {
+        MeasureReportSubjectSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for MeasureReportSubjectSearchTarget {
+    fn from(resource: resources::Device) -> MeasureReportSubjectSearchTarget {
+        MeasureReportSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for MeasureReportSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> MeasureReportSubjectSearchTarget {
+        MeasureReportSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for MeasureReportSubjectSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> MeasureReportSubjectSearchTarget {
+        MeasureReportSubjectSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MeasureReportSubjectSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MeasureReportSubjectSearchTarget {
+        MeasureReportSubjectSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Group> for MeasureReportSubjectSearchTarget {
+    fn from(resource: resources::Group) -> MeasureReportSubjectSearchTarget {
+        MeasureReportSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Practitioner> for MeasureReportSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> MeasureReportSubjectSearchTarget {
+        MeasureReportSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Location> for MeasureReportSubjectSearchTarget {
+    fn from(resource: resources::Location) -> MeasureReportSubjectSearchTarget {
+        MeasureReportSubjectSearchTarget::Location(resource)
+    }
+}
+impl From<resources::Patient> for MeasureReportSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> MeasureReportSubjectSearchTarget {
+        MeasureReportSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The ingredient reference search parameter of the Medication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationIngredientSearchReference;
+impl ReferenceSearchParameter for MedicationIngredientSearchReference {
+    type Resource = resources::Medication;
+    type Target = MedicationIngredientSearchTarget;
+    fn parameter(&self) -> MedicationSearchParameter {
+        MedicationSearchParameter::Ingredient
+    }
+}
+/// Target resources of the ingredient reference search parameter of the Medication resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationIngredientSearchTarget {
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+}
+impl From<resources::Substance> for MedicationIngredientSearchTarget {
+    fn from(resource: resources::Substance) -> MedicationIngredientSearchTarget {
+        MedicationIngredientSearchTarget::Substance(resource)
+    }
+}
+impl From<resources::Medication> for MedicationIngredientSearchTarget {
+    fn from(resource: resources::Medication) -> MedicationIngredientSearchTarget {
+        MedicationIngredientSearchTarget::Medication(resource)
+    }
+}
+/// The marketingauthorizationholder reference search parameter of the Medication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationMarketingauthorizationholderSearchReference;
+impl ReferenceSearchParameter for MedicationMarketingauthorizationholderSearchReference {
+    type Resource = resources::Medication;
+    type Target = resources::Organization;
+    fn parameter(&self) -> MedicationSearchParameter {
+        MedicationSearchParameter::Marketingauthorizationholder
+    }
+}
+/// The patient reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationPatientSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationPatientSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = resources::Patient;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Patient
+    }
+}
+/// The device reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationDeviceSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationDeviceSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = resources::Device;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Device
+    }
+}
+/// The encounter reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationEncounterSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationEncounterSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Encounter
+    }
+}
+/// The medication reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationMedicationSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationMedicationSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = resources::Medication;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Medication
+    }
+}
+/// The performer reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationPerformerSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationPerformerSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = MedicationAdministrationPerformerSearchTarget;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the MedicationAdministration resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationAdministrationPerformerSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Device> for MedicationAdministrationPerformerSearchTarget {
+    fn from(resource: resources::Device) -> MedicationAdministrationPerformerSearchTarget {
+        MedicationAdministrationPerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::RelatedPerson> for MedicationAdministrationPerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> MedicationAdministrationPerformerSearchTarget {
+        MedicationAdministrationPerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MedicationAdministrationPerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MedicationAdministrationPerformerSearchTarget {
+        MedicationAdministrationPerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for MedicationAdministrationPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> MedicationAdministrationPerformerSearchTarget {
+        MedicationAdministrationPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for MedicationAdministrationPerformerSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationAdministrationPerformerSearchTarget {
+        MedicationAdministrationPerformerSearchTarget::Patient(resource)
+    }
+}
+/// The reason-given reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationReasonGivenSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationReasonGivenSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = MedicationAdministrationReasonGivenSearchTarget;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::ReasonGiven
+    }
+}
+/// Target resources of the reason-given reference search parameter of the MedicationAdministration resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationAdministrationReasonGivenSearchTarget {
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for DiagnosticReport target resources
+    DiagnosticReport(resources::DiagnosticReport),
+    /// Variant for Condition target resources
+    Condition(resources::Condition),
+}
+impl From<resources::Observation> for MedicationAdministrationReasonGivenSearchTarget {
+    fn from(resource: resources::Observation) -> MedicationAdministrationReasonGivenSearchTarget {
+        MedicationAdministrationReasonGivenSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::DiagnosticReport> for MedicationAdministrationReasonGivenSearchTarget {
+    fn from(resource: resources::DiagnosticReport) -> MedicationAdministrationReasonGivenSearchTarget {
+        MedicationAdministrationReasonGivenSearchTarget::DiagnosticReport(resource)
+    }
+}
+impl From<resources::Condition> for MedicationAdministrationReasonGivenSearchTarget {
+    fn from(resource: resources::Condition) -> MedicationAdministrationReasonGivenSearchTarget {
+        MedicationAdministrationReasonGivenSearchTarget::Condition(resource)
+    }
+}
+/// The request reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationRequestSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationRequestSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = resources::MedicationRequest;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Request
+    }
+}
+/// The subject reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationSubjectSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationSubjectSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = MedicationAdministrationSubjectSearchTarget;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the MedicationAdministration resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationAdministrationSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for MedicationAdministrationSubjectSearchTarget {
+    fn from(resource: resources::Group) -> MedicationAdministrationSubjectSearchTarget {
+        MedicationAdministrationSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for MedicationAdministrationSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationAdministrationSubjectSearchTarget {
+        MedicationAdministrationSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispensePatientSearchReference;
+impl ReferenceSearchParameter for MedicationDispensePatientSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = resources::Patient;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispenseEncounterSearchReference;
+impl ReferenceSearchParameter for MedicationDispenseEncounterSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Encounter
+    }
+}
+/// The medication reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispenseMedicationSearchReference;
+impl ReferenceSearchParameter for MedicationDispenseMedicationSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = resources::Medication;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Medication
+    }
+}
+/// The destination reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispenseDestinationSearchReference;
+impl ReferenceSearchParameter for MedicationDispenseDestinationSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = resources::Location;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Destination
+    }
+}
+/// The location reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispenseLocationSearchReference;
+impl ReferenceSearchParameter for MedicationDispenseLocationSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = resources::Location;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Location
+    }
+}
+/// The performer reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispensePerformerSearchReference;
+impl ReferenceSearchParameter for MedicationDispensePerformerSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = MedicationDispensePerformerSearchTarget;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the MedicationDispense resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationDispensePerformerSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for MedicationDispensePerformerSearchTarget {
+    fn from(resource: resources::Organization) -> MedicationDispensePerformerSearchTarget {
+        MedicationDispensePerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for MedicationDispensePerformerSearchTarget {
+    fn from(resource: resources::Device) -> MedicationDispensePerformerSearchTarget {
+        MedicationDispensePerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::CareTeam> for MedicationDispensePerformerSearchTarget {
+    fn from(resource: resources::CareTeam) -> MedicationDispensePerformerSearchTarget {
+        MedicationDispensePerformerSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::RelatedPerson> for MedicationDispensePerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> MedicationDispensePerformerSearchTarget {
+        MedicationDispensePerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MedicationDispensePerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MedicationDispensePerformerSearchTarget {
+        MedicationDispensePerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for MedicationDispensePerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> MedicationDispensePerformerSearchTarget {
+        MedicationDispensePerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for MedicationDispensePerformerSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationDispensePerformerSearchTarget {
+        MedicationDispensePerformerSearchTarget::Patient(resource)
+    }
+}
+/// The prescription reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispensePrescriptionSearchReference;
+impl ReferenceSearchParameter for MedicationDispensePrescriptionSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = resources::MedicationRequest;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Prescription
+    }
+}
+/// The receiver reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispenseReceiverSearchReference;
+impl ReferenceSearchParameter for MedicationDispenseReceiverSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = MedicationDispenseReceiverSearchTarget;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Receiver
+    }
+}
+/// Target resources of the receiver reference search parameter of the MedicationDispense resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationDispenseReceiverSearchTarget {
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::RelatedPerson> for MedicationDispenseReceiverSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> MedicationDispenseReceiverSearchTarget {
+        MedicationDispenseReceiverSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MedicationDispenseReceiverSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MedicationDispenseReceiverSearchTarget {
+        MedicationDispenseReceiverSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for MedicationDispenseReceiverSearchTarget {
+    fn from(resource: resources::Practitioner) -> MedicationDispenseReceiverSearchTarget {
+        MedicationDispenseReceiverSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Location> for MedicationDispenseReceiverSearchTarget {
+    fn from(resource: resources::Location) -> MedicationDispenseReceiverSearchTarget {
+        MedicationDispenseReceiverSearchTarget::Location(resource)
+    }
+}
+impl From<resources::Patient> for MedicationDispenseReceiverSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationDispenseReceiverSearchTarget {
+        MedicationDispenseReceiverSearchTarget::Patient(resource)
+    }
+}
+/// The responsibleparty reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispenseResponsiblepartySearchReference;
+impl ReferenceSearchParameter for MedicationDispenseResponsiblepartySearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = MedicationDispenseResponsiblepartySearchTarget;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Responsibleparty
+    }
+}
+/// Target resources of the responsibleparty reference search parameter of the MedicationDispense resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationDispenseResponsiblepartySearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for MedicationDispenseResponsiblepartySearchTarget {
+    fn from(resource: resources::Organization) -> MedicationDispenseResponsiblepartySearchTarget {
+        MedicationDispenseResponsiblepartySearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MedicationDispenseResponsiblepartySearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MedicationDispenseResponsiblepartySearchTarget {
+        MedicationDispenseResponsiblepartySearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for MedicationDispenseResponsiblepartySearchTarget {
+    fn from(resource: resources::Practitioner) -> MedicationDispenseResponsiblepartySearchTarget {
+        MedicationDispenseResponsiblepartySearchTarget::Practitioner(resource)
+    }
+}
+/// The subject reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispenseSubjectSearchReference;
+impl ReferenceSearchParameter for MedicationDispenseSubjectSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = MedicationDispenseSubjectSearchTarget;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the MedicationDispense resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationDispenseSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for MedicationDispenseSubjectSearchTarget {
+    fn from(resource: resources::Group) -> MedicationDispenseSubjectSearchTarget {
+        MedicationDispenseSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for MedicationDispenseSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationDispenseSubjectSearchTarget {
+        MedicationDispenseSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The ingredient reference search parameter of the MedicationKnowledge resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationKnowledgeIngredientSearchReference;
+impl ReferenceSearchParameter for MedicationKnowledgeIngredientSearchReference {
+    type Resource = resources::MedicationKnowledge;
+    type Target = resources::Substance;
+    fn parameter(&self) -> MedicationKnowledgeSearchParameter {
+        MedicationKnowledgeSearchParameter::Ingredient
+    }
+}
+/// The monograph reference search parameter of the MedicationKnowledge resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationKnowledgeMonographSearchReference;
+impl ReferenceSearchParameter for MedicationKnowledgeMonographSearchReference {
+    type Resource = resources::MedicationKnowledge;
+    type Target = resources::DocumentReference;
+    fn parameter(&self) -> MedicationKnowledgeSearchParameter {
+        MedicationKnowledgeSearchParameter::Monograph
+    }
+}
+/// The patient reference search parameter of the MedicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationRequestPatientSearchReference;
+impl ReferenceSearchParameter for MedicationRequestPatientSearchReference {
+    type Resource = resources::MedicationRequest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> MedicationRequestSearchParameter {
+        MedicationRequestSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the MedicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationRequestEncounterSearchReference;
+impl ReferenceSearchParameter for MedicationRequestEncounterSearchReference {
+    type Resource = resources::MedicationRequest;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> MedicationRequestSearchParameter {
+        MedicationRequestSearchParameter::Encounter
+    }
+}
+/// The medication reference search parameter of the MedicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationRequestMedicationSearchReference;
+impl ReferenceSearchParameter for MedicationRequestMedicationSearchReference {
+    type Resource = resources::MedicationRequest;
+    type Target = resources::Medication;
+    fn parameter(&self) -> MedicationRequestSearchParameter {
+        MedicationRequestSearchParameter::Medication
+    }
+}
+/// The intended-dispenser reference search parameter of the MedicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationRequestIntendedDispenserSearchReference;
+impl ReferenceSearchParameter for MedicationRequestIntendedDispenserSearchReference {
+    type Resource = resources::MedicationRequest;
+    type Target = resources::Organization;
+    fn parameter(&self) -> MedicationRequestSearchParameter {
+        MedicationRequestSearchParameter::IntendedDispenser
+    }
+}
+/// The intended-performer reference search parameter of the MedicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationRequestIntendedPerformerSearchReference;
+impl ReferenceSearchParameter for MedicationRequestIntendedPerformerSearchReference {
+    type Resource = resources::MedicationRequest;
+    type Target = MedicationRequestIntendedPerformerSearchTarget;
+    fn parameter(&self) -> MedicationRequestSearchParameter {
+        MedicationRequestSearchParameter::IntendedPerformer
+    }
+}
+/// Target resources of the intended-performer reference search parameter of the MedicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationRequestIntendedPerformerSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for DeviceDefinition target resources
+    DeviceDefinition(resources::DeviceDefinition),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for MedicationRequestIntendedPerformerSearchTarget {
+    fn from(resource: resources::HealthcareService) -> MedicationRequestIntendedPerformerSearchTarget {
+        MedicationRequestIntendedPerformerSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::Organization> for MedicationRequestIntendedPerformerSearchTarget {
+    fn from(resource: resources::Organization) -> MedicationRequestIntendedPerformerSearchTarget {
+        MedicationRequestIntendedPerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for MedicationRequestIntendedPerformerSearchTarget {
+    fn from(resource: resources::CareTeam) -> MedicationRequestIntendedPerformerSearchTarget {
+        MedicationRequestIntendedPerformerSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::RelatedPerson> for MedicationRequestIntendedPerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> MedicationRequestIntendedPerformerSearchTarget {
+        MedicationRequestIntendedPerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MedicationRequestIntendedPerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MedicationRequestIntendedPerformerSearchTarget {
+        MedicationRequestIntendedPerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::DeviceDefinition> for MedicationRequestIntendedPerformerSearchTarget {
+    fn from(resource: resources::DeviceDefinition) -> MedicationRequestIntendedPerformerSearchTarget {
+        MedicationRequestIntendedPerformerSearchTarget::DeviceDefinition(resource)
+    }
+}
+impl From<resources::Practitioner> for MedicationRequestIntendedPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> MedicationRequestIntendedPerformerSearchTarget {
+        MedicationRequestIntendedPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for MedicationRequestIntendedPerformerSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationRequestIntendedPerformerSearchTarget {
+        MedicationRequestIntendedPerformerSearchTarget::Patient(resource)
+    }
+}
+/// The requester reference search parameter of the MedicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationRequestRequesterSearchReference;
+impl ReferenceSearchParameter for MedicationRequestRequesterSearchReference {
+    type Resource = resources::MedicationRequest;
+    type Target = MedicationRequestRequesterSearchTarget;
+    fn parameter(&self) -> MedicationRequestSearchParameter {
+        MedicationRequestSearchParameter::Requester
+    }
+}
+/// Target resources of the requester reference search parameter of the MedicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationRequestRequesterSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for MedicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Organization) -> MedicationRequestRequesterSearchTarget {
+        MedicationRequestRequesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for MedicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Device) -> MedicationRequestRequesterSearchTarget {
+        MedicationRequestRequesterSearchTarget::Device(resource)
+    }
+}
+impl From<resources::RelatedPerson> for MedicationRequestRequesterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> MedicationRequestRequesterSearchTarget {
+        MedicationRequestRequesterSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MedicationRequestRequesterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MedicationRequestRequesterSearchTarget {
+        MedicationRequestRequesterSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for MedicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> MedicationRequestRequesterSearchTarget {
+        MedicationRequestRequesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for MedicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationRequestRequesterSearchTarget {
+        MedicationRequestRequesterSearchTarget::Patient(resource)
+    }
+}
+/// The subject reference search parameter of the MedicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationRequestSubjectSearchReference;
+impl ReferenceSearchParameter for MedicationRequestSubjectSearchReference {
+    type Resource = resources::MedicationRequest;
+    type Target = MedicationRequestSubjectSearchTarget;
+    fn parameter(&self) -> MedicationRequestSearchParameter {
+        MedicationRequestSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the MedicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationRequestSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for MedicationRequestSubjectSearchTarget {
+    fn from(resource: resources::Group) -> MedicationRequestSubjectSearchTarget {
+        MedicationRequestSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for MedicationRequestSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationRequestSubjectSearchTarget {
+        MedicationRequestSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the MedicationStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationStatementPatientSearchReference;
+impl ReferenceSearchParameter for MedicationStatementPatientSearchReference {
+    type Resource = resources::MedicationStatement;
+    type Target = resources::Patient;
+    fn parameter(&self) -> MedicationStatementSearchParameter {
+        MedicationStatementSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the MedicationStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationStatementEncounterSearchReference;
+impl ReferenceSearchParameter for MedicationStatementEncounterSearchReference {
+    type Resource = resources::MedicationStatement;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> MedicationStatementSearchParameter {
+        MedicationStatementSearchParameter::Encounter
+    }
+}
+/// The medication reference search parameter of the MedicationStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationStatementMedicationSearchReference;
+impl ReferenceSearchParameter for MedicationStatementMedicationSearchReference {
+    type Resource = resources::MedicationStatement;
+    type Target = resources::Medication;
+    fn parameter(&self) -> MedicationStatementSearchParameter {
+        MedicationStatementSearchParameter::Medication
+    }
+}
+/// The source reference search parameter of the MedicationStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationStatementSourceSearchReference;
+impl ReferenceSearchParameter for MedicationStatementSourceSearchReference {
+    type Resource = resources::MedicationStatement;
+    type Target = MedicationStatementSourceSearchTarget;
+    fn parameter(&self) -> MedicationStatementSearchParameter {
+        MedicationStatementSearchParameter::Source
+    }
+}
+/// Target resources of the source reference search parameter of the MedicationStatement resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationStatementSourceSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for MedicationStatementSourceSearchTarget {
+    fn from(resource: resources::Organization) -> MedicationStatementSourceSearchTarget {
+        MedicationStatementSourceSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for MedicationStatementSourceSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> MedicationStatementSourceSearchTarget {
+        MedicationStatementSourceSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MedicationStatementSourceSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MedicationStatementSourceSearchTarget {
+        MedicationStatementSourceSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for MedicationStatementSourceSearchTarget {
+    fn from(resource: resources::Practitioner) -> MedicationStatementSourceSearchTarget {
+        MedicationStatementSourceSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for MedicationStatementSourceSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationStatementSourceSearchTarget {
+        MedicationStatementSourceSearchTarget::Patient(resource)
+    }
+}
+/// The subject reference search parameter of the MedicationStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationStatementSubjectSearchReference;
+impl ReferenceSearchParameter for MedicationStatementSubjectSearchReference {
+    type Resource = resources::MedicationStatement;
+    type Target = MedicationStatementSubjectSearchTarget;
+    fn parameter(&self) -> MedicationStatementSearchParameter {
+        MedicationStatementSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the MedicationStatement resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationStatementSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for MedicationStatementSubjectSearchTarget {
+    fn from(resource: resources::Group) -> MedicationStatementSubjectSearchTarget {
+        MedicationStatementSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for MedicationStatementSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationStatementSubjectSearchTarget {
+        MedicationStatementSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The contact reference search parameter of the MedicinalProductDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicinalProductDefinitionContactSearchReference;
+impl ReferenceSearchParameter for MedicinalProductDefinitionContactSearchReference {
+    type Resource = resources::MedicinalProductDefinition;
+    type Target = MedicinalProductDefinitionContactSearchTarget;
+    fn parameter(&self) -> MedicinalProductDefinitionSearchParameter {
+        MedicinalProductDefinitionSearchParameter::Contact
+    }
+}
+/// Target resources of the contact reference search parameter of the MedicinalProductDefinition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicinalProductDefinitionContactSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+}
+impl From<resources::Organization> for MedicinalProductDefinitionContactSearchTarget {
+    fn from(resource: resources::Organization) -> MedicinalProductDefinitionContactSearchTarget {
+        MedicinalProductDefinitionContactSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MedicinalProductDefinitionContactSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MedicinalProductDefinitionContactSearchTarget {
+        MedicinalProductDefinitionContactSearchTarget::PractitionerRole(resource)
+    }
+}
+/// The master-file reference search parameter of the MedicinalProductDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicinalProductDefinitionMasterFileSearchReference;
+impl ReferenceSearchParameter for MedicinalProductDefinitionMasterFileSearchReference {
+    type Resource = resources::MedicinalProductDefinition;
+    type Target = resources::DocumentReference;
+    fn parameter(&self) -> MedicinalProductDefinitionSearchParameter {
+        MedicinalProductDefinitionSearchParameter::MasterFile
+    }
+}
+/// The parent reference search parameter of the MessageDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageDefinitionParentSearchReference;
+impl ReferenceSearchParameter for MessageDefinitionParentSearchReference {
+    type Resource = resources::MessageDefinition;
+    type Target = MessageDefinitionParentSearchTarget;
+    fn parameter(&self) -> MessageDefinitionSearchParameter {
+        MessageDefinitionSearchParameter::Parent
+    }
+}
+/// Target resources of the parent reference search parameter of the MessageDefinition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MessageDefinitionParentSearchTarget {
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+}
+impl From<resources::ActivityDefinition> for MessageDefinitionParentSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> MessageDefinitionParentSearchTarget {
+        MessageDefinitionParentSearchTarget::ActivityDefinition(resource)
+    }
+}
+impl From<resources::PlanDefinition> for MessageDefinitionParentSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> MessageDefinitionParentSearchTarget {
+        MessageDefinitionParentSearchTarget::PlanDefinition(resource)
+    }
+}
+/// The author reference search parameter of the MessageHeader resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageHeaderAuthorSearchReference;
+impl ReferenceSearchParameter for MessageHeaderAuthorSearchReference {
+    type Resource = resources::MessageHeader;
+    type Target = MessageHeaderAuthorSearchTarget;
+    fn parameter(&self) -> MessageHeaderSearchParameter {
+        MessageHeaderSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the MessageHeader resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MessageHeaderAuthorSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Device> for MessageHeaderAuthorSearchTarget {
+    fn from(resource: resources::Device) -> MessageHeaderAuthorSearchTarget {
+        MessageHeaderAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for MessageHeaderAuthorSearchTarget {
+    fn from(resource: resources::Organization) -> MessageHeaderAuthorSearchTarget {
+        MessageHeaderAuthorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MessageHeaderAuthorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MessageHeaderAuthorSearchTarget {
+        MessageHeaderAuthorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for MessageHeaderAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> MessageHeaderAuthorSearchTarget {
+        MessageHeaderAuthorSearchTarget::Practitioner(resource)
+    }
+}
+/// The focus reference search parameter of the MessageHeader resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageHeaderFocusSearchReference;
+impl ReferenceSearchParameter for MessageHeaderFocusSearchReference {
+    type Resource = resources::MessageHeader;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MessageHeaderSearchParameter {
+        MessageHeaderSearchParameter::Focus
+    }
+}
+/// The receiver reference search parameter of the MessageHeader resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageHeaderReceiverSearchReference;
+impl ReferenceSearchParameter for MessageHeaderReceiverSearchReference {
+    type Resource = resources::MessageHeader;
+    type Target = MessageHeaderReceiverSearchTarget;
+    fn parameter(&self) -> MessageHeaderSearchParameter {
+        MessageHeaderSearchParameter::Receiver
+    }
+}
+/// Target resources of the receiver reference search parameter of the MessageHeader resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MessageHeaderReceiverSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for MessageHeaderReceiverSearchTarget {
+    fn from(resource: resources::Organization) -> MessageHeaderReceiverSearchTarget {
+        MessageHeaderReceiverSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MessageHeaderReceiverSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MessageHeaderReceiverSearchTarget {
+        MessageHeaderReceiverSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for MessageHeaderReceiverSearchTarget {
+    fn from(resource: resources::Practitioner) -> MessageHeaderReceiverSearchTarget {
+        MessageHeaderReceiverSearchTarget::Practitioner(resource)
+    }
+}
+/// The responsible reference search parameter of the MessageHeader resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageHeaderResponsibleSearchReference;
+impl ReferenceSearchParameter for MessageHeaderResponsibleSearchReference {
+    type Resource = resources::MessageHeader;
+    type Target = MessageHeaderResponsibleSearchTarget;
+    fn parameter(&self) -> MessageHeaderSearchParameter {
+        MessageHeaderSearchParameter::Responsible
+    }
+}
+/// Target resources of the responsible reference search parameter of the MessageHeader resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MessageHeaderResponsibleSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for MessageHeaderResponsibleSearchTarget {
+    fn from(resource: resources::Organization) -> MessageHeaderResponsibleSearchTarget {
+        MessageHeaderResponsibleSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MessageHeaderResponsibleSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MessageHeaderResponsibleSearchTarget {
+        MessageHeaderResponsibleSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for MessageHeaderResponsibleSearchTarget {
+    fn from(resource: resources::Practitioner) -> MessageHeaderResponsibleSearchTarget {
+        MessageHeaderResponsibleSearchTarget::Practitioner(resource)
+    }
+}
+/// The sender reference search parameter of the MessageHeader resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageHeaderSenderSearchReference;
+impl ReferenceSearchParameter for MessageHeaderSenderSearchReference {
+    type Resource = resources::MessageHeader;
+    type Target = MessageHeaderSenderSearchTarget;
+    fn parameter(&self) -> MessageHeaderSearchParameter {
+        MessageHeaderSearchParameter::Sender
+    }
+}
+/// Target resources of the sender reference search parameter of the MessageHeader resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MessageHeaderSenderSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Device> for MessageHeaderSenderSearchTarget {
+    fn from(resource: resources::Device) -> MessageHeaderSenderSearchTarget {
+        MessageHeaderSenderSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for MessageHeaderSenderSearchTarget {
+    fn from(resource: resources::Organization) -> MessageHeaderSenderSearchTarget {
+        MessageHeaderSenderSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for MessageHeaderSenderSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> MessageHeaderSenderSearchTarget {
+        MessageHeaderSenderSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for MessageHeaderSenderSearchTarget {
+    fn from(resource: resources::Practitioner) -> MessageHeaderSenderSearchTarget {
+        MessageHeaderSenderSearchTarget::Practitioner(resource)
+    }
+}
+/// The target reference search parameter of the MessageHeader resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageHeaderTargetSearchReference;
+impl ReferenceSearchParameter for MessageHeaderTargetSearchReference {
+    type Resource = resources::MessageHeader;
+    type Target = resources::Device;
+    fn parameter(&self) -> MessageHeaderSearchParameter {
+        MessageHeaderSearchParameter::Target
+    }
+}
+/// The patient reference search parameter of the MolecularSequence resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MolecularSequencePatientSearchReference;
+impl ReferenceSearchParameter for MolecularSequencePatientSearchReference {
+    type Resource = resources::MolecularSequence;
+    type Target = resources::Patient;
+    fn parameter(&self) -> MolecularSequenceSearchParameter {
+        MolecularSequenceSearchParameter::Patient
+    }
+}
+/// The focus reference search parameter of the MolecularSequence resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MolecularSequenceFocusSearchReference;
+impl ReferenceSearchParameter for MolecularSequenceFocusSearchReference {
+    type Resource = resources::MolecularSequence;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MolecularSequenceSearchParameter {
+        MolecularSequenceSearchParameter::Focus
+    }
+}
+/// The subject reference search parameter of the MolecularSequence resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MolecularSequenceSubjectSearchReference;
+impl ReferenceSearchParameter for MolecularSequenceSubjectSearchReference {
+    type Resource = resources::MolecularSequence;
+    type Target = MolecularSequenceSubjectSearchTarget;
+    fn parameter(&self) -> MolecularSequenceSearchParameter {
+        MolecularSequenceSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the MolecularSequence resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MolecularSequenceSubjectSearchTarget {
+    /// Variant for NutritionProduct target resources
+    NutritionProduct(resources::NutritionProduct),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for BiologicallyDerivedProduct target resources
+    BiologicallyDerivedProduct(resources::BiologicallyDerivedProduct),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::NutritionProduct> for MolecularSequenceSubjectSearchTarget {
+    fn from(resource: resources::NutritionProduct) -> MolecularSequenceSubjectSearchTarget {
+        MolecularSequenceSubjectSearchTarget::NutritionProduct(resource)
+    }
+}
+impl From<resources::Group> for MolecularSequenceSubjectSearchTarget {
+    fn from(resource: resources::Group) -> MolecularSequenceSubjectSearchTarget {
+        MolecularSequenceSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::BiologicallyDerivedProduct> for MolecularSequenceSubjectSearchTarget {
+    fn from(resource: resources::BiologicallyDerivedProduct) -> MolecularSequenceSubjectSearchTarget {
+        MolecularSequenceSubjectSearchTarget::BiologicallyDerivedProduct(resource)
+    }
+}
+impl From<resources::Substance> for MolecularSequenceSubjectSearchTarget {
+    fn from(resource: resources::Substance) -> MolecularSequenceSubjectSearchTarget {
+        MolecularSequenceSubjectSearchTarget::Substance(resource)
+    }
+}
+impl From<resources::Patient> for MolecularSequenceSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> MolecularSequenceSubjectSearchTarget {
+        MolecularSequenceSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The derived-from reference search parameter of the NamingSystem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NamingSystemDerivedFromSearchReference;
+impl ReferenceSearchParameter for NamingSystemDerivedFromSearchReference {
+    type Resource = resources::NamingSystem;
+    type Target = resources::Resource;
+    fn parameter(&self) -> NamingSystemSearchParameter {
+        NamingSystemSearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the NamingSystem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NamingSystemPredecessorSearchReference;
+impl ReferenceSearchParameter for NamingSystemPredecessorSearchReference {
+    type Resource = resources::NamingSystem;
+    type Target = resources::Resource;
+    fn parameter(&self) -> NamingSystemSearchParameter {
+        NamingSystemSearchParameter::Predecessor
+    }
+}
+/// The patient reference search parameter of the NutritionIntake resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NutritionIntakePatientSearchReference;
+impl ReferenceSearchParameter for NutritionIntakePatientSearchReference {
+    type Resource = resources::NutritionIntake;
+    type Target = resources::Patient;
+    fn parameter(&self) -> NutritionIntakeSearchParameter {
+        NutritionIntakeSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the NutritionIntake resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NutritionIntakeEncounterSearchReference;
+impl ReferenceSearchParameter for NutritionIntakeEncounterSearchReference {
+    type Resource = resources::NutritionIntake;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> NutritionIntakeSearchParameter {
+        NutritionIntakeSearchParameter::Encounter
+    }
+}
+/// The source reference search parameter of the NutritionIntake resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NutritionIntakeSourceSearchReference;
+impl ReferenceSearchParameter for NutritionIntakeSourceSearchReference {
+    type Resource = resources::NutritionIntake;
+    type Target = NutritionIntakeSourceSearchTarget;
+    fn parameter(&self) -> NutritionIntakeSearchParameter {
+        NutritionIntakeSearchParameter::Source
+    }
+}
+/// Target resources of the source reference search parameter of the NutritionIntake resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NutritionIntakeSourceSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for NutritionIntakeSourceSearchTarget {
+    fn from(resource: resources::Organization) -> NutritionIntakeSourceSearchTarget {
+        NutritionIntakeSourceSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for NutritionIntakeSourceSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> NutritionIntakeSourceSearchTarget {
+        NutritionIntakeSourceSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for NutritionIntakeSourceSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> NutritionIntakeSourceSearchTarget {
+        NutritionIntakeSourceSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for NutritionIntakeSourceSearchTarget {
+    fn from(resource: resources::Practitioner) -> NutritionIntakeSourceSearchTarget {
+        NutritionIntakeSourceSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for NutritionIntakeSourceSearchTarget {
+    fn from(resource: resources::Patient) -> NutritionIntakeSourceSearchTarget {
+        NutritionIntakeSourceSearchTarget::Patient(resource)
+    }
+}
+/// The subject reference search parameter of the NutritionIntake resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NutritionIntakeSubjectSearchReference;
+impl ReferenceSearchParameter for NutritionIntakeSubjectSearchReference {
+    type Resource = resources::NutritionIntake;
+    type Target = NutritionIntakeSubjectSearchTarget;
+    fn parameter(&self) -> NutritionIntakeSearchParameter {
+        NutritionIntakeSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the NutritionIntake resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NutritionIntakeSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for NutritionIntakeSubjectSearchTarget {
+    fn from(resource: resources::Group) -> NutritionIntakeSubjectSearchTarget {
+        NutritionIntakeSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for NutritionIntakeSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> NutritionIntakeSubjectSearchTarget {
+        NutritionIntakeSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the NutritionOrder resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NutritionOrderPatientSearchReference;
+impl ReferenceSearchParameter for NutritionOrderPatientSearchReference {
+    type Resource = resources::NutritionOrder;
+    type Target = resources::Patient;
+    fn parameter(&self) -> NutritionOrderSearchParameter {
+        NutritionOrderSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the NutritionOrder resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NutritionOrderEncounterSearchReference;
+impl ReferenceSearchParameter for NutritionOrderEncounterSearchReference {
+    type Resource = resources::NutritionOrder;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> NutritionOrderSearchParameter {
+        NutritionOrderSearchParameter::Encounter
+    }
+}
+/// The provider reference search parameter of the NutritionOrder resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NutritionOrderProviderSearchReference;
+impl ReferenceSearchParameter for NutritionOrderProviderSearchReference {
+    type Resource = resources::NutritionOrder;
+    type Target = NutritionOrderProviderSearchTarget;
+    fn parameter(&self) -> NutritionOrderSearchParameter {
+        NutritionOrderSearchParameter::Provider
+    }
+}
+/// Target resources of the provider reference search parameter of the NutritionOrder resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NutritionOrderProviderSearchTarget {
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::PractitionerRole> for NutritionOrderProviderSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> NutritionOrderProviderSearchTarget {
+        NutritionOrderProviderSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for NutritionOrderProviderSearchTarget {
+    fn from(resource: resources::Practitioner) -> NutritionOrderProviderSearchTarget {
+        NutritionOrderProviderSearchTarget::Practitioner(resource)
+    }
+}
+/// The subject reference search parameter of the NutritionOrder resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NutritionOrderSubjectSearchReference;
+impl ReferenceSearchParameter for NutritionOrderSubjectSearchReference {
+    type Resource = resources::NutritionOrder;
+    type Target = NutritionOrderSubjectSearchTarget;
+    fn parameter(&self) -> NutritionOrderSearchParameter {
+        NutritionOrderSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the NutritionOrder resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NutritionOrderSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for NutritionOrderSubjectSearchTarget {
+    fn from(resource: resources::Group) -> NutritionOrderSubjectSearchTarget {
+        NutritionOrderSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for NutritionOrderSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> NutritionOrderSubjectSearchTarget {
+        NutritionOrderSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationPatientSearchReference;
+impl ReferenceSearchParameter for ObservationPatientSearchReference {
+    type Resource = resources::Observation;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationEncounterSearchReference;
+impl ReferenceSearchParameter for ObservationEncounterSearchReference {
+    type Resource = resources::Observation;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Encounter
+    }
+}
+/// The based-on reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationBasedOnSearchReference;
+impl ReferenceSearchParameter for ObservationBasedOnSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationBasedOnSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationBasedOnSearchTarget {
+    /// Variant for DeviceRequest target resources
+    DeviceRequest(resources::DeviceRequest),
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for MedicationRequest target resources
+    MedicationRequest(resources::MedicationRequest),
+    /// Variant for ImmunizationRecommendation target resources
+    ImmunizationRecommendation(resources::ImmunizationRecommendation),
+    /// Variant for NutritionOrder target resources
+    NutritionOrder(resources::NutritionOrder),
+}
+impl From<resources::DeviceRequest> for ObservationBasedOnSearchTarget {
+    fn from(resource: resources::DeviceRequest) -> ObservationBasedOnSearchTarget {
+        ObservationBasedOnSearchTarget::DeviceRequest(resource)
+    }
+}
+impl From<resources::ServiceRequest> for ObservationBasedOnSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> ObservationBasedOnSearchTarget {
+        ObservationBasedOnSearchTarget::ServiceRequest(resource)
+    }
+}
+impl From<resources::CarePlan> for ObservationBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> ObservationBasedOnSearchTarget {
+        ObservationBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::MedicationRequest> for ObservationBasedOnSearchTarget {
+    fn from(resource: resources::MedicationRequest) -> ObservationBasedOnSearchTarget {
+        ObservationBasedOnSearchTarget::MedicationRequest(resource)
+    }
+}
+impl From<resources::ImmunizationRecommendation> for ObservationBasedOnSearchTarget {
+    fn from(resource: resources::ImmunizationRecommendation) -> ObservationBasedOnSearchTarget {
+        ObservationBasedOnSearchTarget::ImmunizationRecommendation(resource)
+    }
+}
+impl From<resources::NutritionOrder> for ObservationBasedOnSearchTarget {
+    fn from(resource: resources::NutritionOrder) -> ObservationBasedOnSearchTarget {
+        ObservationBasedOnSearchTarget::NutritionOrder(resource)
+    }
+}
+/// The component-value-reference reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationComponentValueReferenceSearchReference;
+impl ReferenceSearchParameter for ObservationComponentValueReferenceSearchReference {
+    type Resource = resources::Observation;
+    type Target = resources::MolecularSequence;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::ComponentValueReference
+    }
+}
+/// The derived-from reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationDerivedFromSearchReference;
+impl ReferenceSearchParameter for ObservationDerivedFromSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationDerivedFromSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::DerivedFrom
+    }
+}
+/// Target resources of the derived-from reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationDerivedFromSearchTarget {
+    /// Variant for ImagingStudy target resources
+    ImagingStudy(resources::ImagingStudy),
+    /// Variant for DocumentReference target resources
+    DocumentReference(resources::DocumentReference),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for MolecularSequence target resources
+    MolecularSequence(resources::MolecularSequence),
+    /// Variant for GenomicStudy target resources
+    GenomicStudy(resources::GenomicStudy),
+    /// Variant for ImagingSelection target resources
+    ImagingSelection(resources::ImagingSelection),
+    /// Variant for QuestionnaireResponse target resources
+    QuestionnaireResponse(resources::QuestionnaireResponse),
+}
+impl From<resources::ImagingStudy> for ObservationDerivedFromSearchTarget {
+    fn from(resource: resources::ImagingStudy) -> ObservationDerivedFromSearchTarget {
+        ObservationDerivedFromSearchTarget::ImagingStudy(resource)
+    }
+}
+impl From<resources::DocumentReference> for ObservationDerivedFromSearchTarget {
+    fn from(resource: resources::DocumentReference) -> ObservationDerivedFromSearchTarget {
+        ObservationDerivedFromSearchTarget::DocumentReference(resource)
+    }
+}
+impl From<resources::Observation> for ObservationDerivedFromSearchTarget {
+    fn from(resource: resources::Observation) -> ObservationDerivedFromSearchTarget {
+        ObservationDerivedFromSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::MolecularSequence> for ObservationDerivedFromSearchTarget {
+    fn from(resource: resources::MolecularSequence) -> ObservationDerivedFromSearchTarget {
+        ObservationDerivedFromSearchTarget::MolecularSequence(resource)
+    }
+}
+impl From<resources::GenomicStudy> for ObservationDerivedFromSearchTarget {
+    fn from(resource: resources::GenomicStudy) -> ObservationDerivedFromSearchTarget {
+        ObservationDerivedFromSearchTarget::GenomicStudy(resource)
+    }
+}
+impl From<resources::ImagingSelection> for ObservationDerivedFromSearchTarget {
+    fn from(resource: resources::ImagingSelection) -> ObservationDerivedFromSearchTarget {
+        ObservationDerivedFromSearchTarget::ImagingSelection(resource)
+    }
+}
+impl From<resources::QuestionnaireResponse> for ObservationDerivedFromSearchTarget {
+    fn from(resource: resources::QuestionnaireResponse) -> ObservationDerivedFromSearchTarget {
+        ObservationDerivedFromSearchTarget::QuestionnaireResponse(resource)
+    }
+}
+/// The device reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationDeviceSearchReference;
+impl ReferenceSearchParameter for ObservationDeviceSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationDeviceSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Device
+    }
+}
+/// Target resources of the device reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationDeviceSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for DeviceMetric target resources
+    DeviceMetric(resources::DeviceMetric),
+}
+impl From<resources::Device> for ObservationDeviceSearchTarget {
+    fn from(resource: resources::Device) -> ObservationDeviceSearchTarget {
+        ObservationDeviceSearchTarget::Device(resource)
+    }
+}
+impl From<resources::DeviceMetric> for ObservationDeviceSearchTarget {
+    fn from(resource: resources::DeviceMetric) -> ObservationDeviceSearchTarget {
+        ObservationDeviceSearchTarget::DeviceMetric(resource)
+    }
+}
+/// The focus reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationFocusSearchReference;
+impl ReferenceSearchParameter for ObservationFocusSearchReference {
+    type Resource = resources::Observation;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Focus
+    }
+}
+/// The has-member reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationHasMemberSearchReference;
+impl ReferenceSearchParameter for ObservationHasMemberSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationHasMemberSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::HasMember
+    }
+}
+/// Target resources of the has-member reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationHasMemberSearchTarget {
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for MolecularSequence target resources
+    MolecularSequence(resources::MolecularSequence),
+    /// Variant for QuestionnaireResponse target resources
+    QuestionnaireResponse(resources::QuestionnaireResponse),
+}
+impl From<resources::Observation> for ObservationHasMemberSearchTarget {
+    fn from(resource: resources::Observation) -> ObservationHasMemberSearchTarget {
+        ObservationHasMemberSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::MolecularSequence> for ObservationHasMemberSearchTarget {
+    fn from(resource: resources::MolecularSequence) -> ObservationHasMemberSearchTarget {
+        ObservationHasMemberSearchTarget::MolecularSequence(resource)
+    }
+}
+impl From<resources::QuestionnaireResponse> for ObservationHasMemberSearchTarget {
+    fn from(resource: resources::QuestionnaireResponse) -> ObservationHasMemberSearchTarget {
+        ObservationHasMemberSearchTarget::QuestionnaireResponse(resource)
+    }
+}
+/// The part-of reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationPartOfSearchReference;
+impl ReferenceSearchParameter for ObservationPartOfSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationPartOfSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::PartOf
+    }
+}
+/// Target resources of the part-of reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationPartOfSearchTarget {
+    /// Variant for ImagingStudy target resources
+    ImagingStudy(resources::ImagingStudy),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for MedicationStatement target resources
+    MedicationStatement(resources::MedicationStatement),
+    /// Variant for MedicationAdministration target resources
+    MedicationAdministration(resources::MedicationAdministration),
+    /// Variant for GenomicStudy target resources
+    GenomicStudy(resources::GenomicStudy),
+    /// Variant for Immunization target resources
+    Immunization(resources::Immunization),
+    /// Variant for MedicationDispense target resources
+    MedicationDispense(resources::MedicationDispense),
+}
+impl From<resources::ImagingStudy> for ObservationPartOfSearchTarget {
+    fn from(resource: resources::ImagingStudy) -> ObservationPartOfSearchTarget {
+        ObservationPartOfSearchTarget::ImagingStudy(resource)
+    }
+}
+impl From<resources::Procedure> for ObservationPartOfSearchTarget {
+    fn from(resource: resources::Procedure) -> ObservationPartOfSearchTarget {
+        ObservationPartOfSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::MedicationStatement> for ObservationPartOfSearchTarget {
+    fn from(resource: resources::MedicationStatement) -> ObservationPartOfSearchTarget {
+        ObservationPartOfSearchTarget::MedicationStatement(resource)
+    }
+}
+impl From<resources::MedicationAdministration> for ObservationPartOfSearchTarget {
+    fn from(resource: resources::MedicationAdministration) -> ObservationPartOfSearchTarget {
+        ObservationPartOfSearchTarget::MedicationAdministration(resource)
+    }
+}
+impl From<resources::GenomicStudy> for ObservationPartOfSearchTarget {
+    fn from(resource: resources::GenomicStudy) -> ObservationPartOfSearchTarget {
+        ObservationPartOfSearchTarget::GenomicStudy(resource)
+    }
+}
+impl From<resources::Immunization> for ObservationPartOfSearchTarget {
+    fn from(resource: resources::Immunization) -> ObservationPartOfSearchTarget {
+        ObservationPartOfSearchTarget::Immunization(resource)
+    }
+}
+impl From<resources::MedicationDispense> for ObservationPartOfSearchTarget {
+    fn from(resource: resources::MedicationDispense) -> ObservationPartOfSearchTarget {
+        ObservationPartOfSearchTarget::MedicationDispense(resource)
+    }
+}
+/// The performer reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationPerformerSearchReference;
+impl ReferenceSearchParameter for ObservationPerformerSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationPerformerSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationPerformerSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for ObservationPerformerSearchTarget {
+    fn from(resource: resources::Organization) -> ObservationPerformerSearchTarget {
+        ObservationPerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for ObservationPerformerSearchTarget {
+    fn from(resource: resources::CareTeam) -> ObservationPerformerSearchTarget {
+        ObservationPerformerSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ObservationPerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ObservationPerformerSearchTarget {
+        ObservationPerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ObservationPerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ObservationPerformerSearchTarget {
+        ObservationPerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ObservationPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ObservationPerformerSearchTarget {
+        ObservationPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ObservationPerformerSearchTarget {
+    fn from(resource: resources::Patient) -> ObservationPerformerSearchTarget {
+        ObservationPerformerSearchTarget::Patient(resource)
+    }
+}
+/// The specimen reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationSpecimenSearchReference;
+impl ReferenceSearchParameter for ObservationSpecimenSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationSpecimenSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Specimen
+    }
+}
+/// Target resources of the specimen reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationSpecimenSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Specimen target resources
+    Specimen(resources::Specimen),
+}
+impl From<resources::Group> for ObservationSpecimenSearchTarget {
+    fn from(resource: resources::Group) -> ObservationSpecimenSearchTarget {
+        ObservationSpecimenSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Specimen> for ObservationSpecimenSearchTarget {
+    fn from(resource: resources::Specimen) -> ObservationSpecimenSearchTarget {
+        ObservationSpecimenSearchTarget::Specimen(resource)
+    }
+}
+/// The subject reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationSubjectSearchReference;
+impl ReferenceSearchParameter for ObservationSubjectSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationSubjectSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationSubjectSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for NutritionProduct target resources
+    NutritionProduct(resources::NutritionProduct),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for BiologicallyDerivedProduct target resources
+    BiologicallyDerivedProduct(resources::BiologicallyDerivedProduct),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+}
+impl From<resources::Device> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Device) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Procedure> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Procedure) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::NutritionProduct> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::NutritionProduct) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::NutritionProduct(resource)
+    }
+}
+impl From<resources::Group> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Practitioner> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::BiologicallyDerivedProduct> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::BiologicallyDerivedProduct) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::BiologicallyDerivedProduct(resource)
+    }
+}
+impl From<resources::Substance> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Substance) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Substance(resource)
+    }
+}
+impl From<resources::Location> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Location) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Location(resource)
+    }
+}
+impl From<resources::Patient> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Medication> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Medication) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Medication(resource)
+    }
+}
+/// The value-reference reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationValueReferenceSearchReference;
+impl ReferenceSearchParameter for ObservationValueReferenceSearchReference {
+    type Resource = resources::Observation;
+    type Target = resources::MolecularSequence;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::ValueReference
+    }
+}
+/// The base reference search parameter of the OperationDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OperationDefinitionBaseSearchReference;
+impl ReferenceSearchParameter for OperationDefinitionBaseSearchReference {
+    type Resource = resources::OperationDefinition;
+    type Target = resources::OperationDefinition;
+    fn parameter(&self) -> OperationDefinitionSearchParameter {
+        OperationDefinitionSearchParameter::Base
+    }
+}
+/// The input-profile reference search parameter of the OperationDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OperationDefinitionInputProfileSearchReference;
+impl ReferenceSearchParameter for OperationDefinitionInputProfileSearchReference {
+    type Resource = resources::OperationDefinition;
+    type Target = resources::StructureDefinition;
+    fn parameter(&self) -> OperationDefinitionSearchParameter {
+        OperationDefinitionSearchParameter::InputProfile
+    }
+}
+/// The output-profile reference search parameter of the OperationDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OperationDefinitionOutputProfileSearchReference;
+impl ReferenceSearchParameter for OperationDefinitionOutputProfileSearchReference {
+    type Resource = resources::OperationDefinition;
+    type Target = resources::StructureDefinition;
+    fn parameter(&self) -> OperationDefinitionSearchParameter {
+        OperationDefinitionSearchParameter::OutputProfile
+    }
+}
+/// The endpoint reference search parameter of the Organization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrganizationEndpointSearchReference;
+impl ReferenceSearchParameter for OrganizationEndpointSearchReference {
+    type Resource = resources::Organization;
+    type Target = resources::Endpoint;
+    fn parameter(&self) -> OrganizationSearchParameter {
+        OrganizationSearchParameter::Endpoint
+    }
+}
+/// The partof reference search parameter of the Organization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrganizationPartofSearchReference;
+impl ReferenceSearchParameter for OrganizationPartofSearchReference {
+    type Resource = resources::Organization;
+    type Target = resources::Organization;
+    fn parameter(&self) -> OrganizationSearchParameter {
+        OrganizationSearchParameter::Partof
+    }
+}
+/// The endpoint reference search parameter of the OrganizationAffiliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrganizationAffiliationEndpointSearchReference;
+impl ReferenceSearchParameter for OrganizationAffiliationEndpointSearchReference {
+    type Resource = resources::OrganizationAffiliation;
+    type Target = resources::Endpoint;
+    fn parameter(&self) -> OrganizationAffiliationSearchParameter {
+        OrganizationAffiliationSearchParameter::Endpoint
+    }
+}
+/// The location reference search parameter of the OrganizationAffiliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrganizationAffiliationLocationSearchReference;
+impl ReferenceSearchParameter for OrganizationAffiliationLocationSearchReference {
+    type Resource = resources::OrganizationAffiliation;
+    type Target = resources::Location;
+    fn parameter(&self) -> OrganizationAffiliationSearchParameter {
+        OrganizationAffiliationSearchParameter::Location
+    }
+}
+/// The network reference search parameter of the OrganizationAffiliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrganizationAffiliationNetworkSearchReference;
+impl ReferenceSearchParameter for OrganizationAffiliationNetworkSearchReference {
+    type Resource = resources::OrganizationAffiliation;
+    type Target = resources::Organization;
+    fn parameter(&self) -> OrganizationAffiliationSearchParameter {
+        OrganizationAffiliationSearchParameter::Network
+    }
+}
+/// The participating-organization reference search parameter of the OrganizationAffiliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrganizationAffiliationParticipatingOrganizationSearchReference;
+impl ReferenceSearchParameter for OrganizationAffiliationParticipatingOrganizationSearchReference {
+    type Resource = resources::OrganizationAffiliation;
+    type Target = resources::Organization;
+    fn parameter(&self) -> OrganizationAffiliationSearchParameter {
+        OrganizationAffiliationSearchParameter::ParticipatingOrganization
+    }
+}
+/// The primary-organization reference search parameter of the OrganizationAffiliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrganizationAffiliationPrimaryOrganizationSearchReference;
+impl ReferenceSearchParameter for OrganizationAffiliationPrimaryOrganizationSearchReference {
+    type Resource = resources::OrganizationAffiliation;
+    type Target = resources::Organization;
+    fn parameter(&self) -> OrganizationAffiliationSearchParameter {
+        OrganizationAffiliationSearchParameter::PrimaryOrganization
+    }
+}
+/// The service reference search parameter of the OrganizationAffiliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrganizationAffiliationServiceSearchReference;
+impl ReferenceSearchParameter for OrganizationAffiliationServiceSearchReference {
+    type Resource = resources::OrganizationAffiliation;
+    type Target = resources::HealthcareService;
+    fn parameter(&self) -> OrganizationAffiliationSearchParameter {
+        OrganizationAffiliationSearchParameter::Service
+    }
+}
+/// The biological reference search parameter of the PackagedProductDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PackagedProductDefinitionBiologicalSearchReference;
+impl ReferenceSearchParameter for PackagedProductDefinitionBiologicalSearchReference {
+    type Resource = resources::PackagedProductDefinition;
+    type Target = PackagedProductDefinitionBiologicalSearchTarget;
+    fn parameter(&self) -> PackagedProductDefinitionSearchParameter {
+        PackagedProductDefinitionSearchParameter::Biological
+    }
+}
+/// Target resources of the biological reference search parameter of the PackagedProductDefinition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PackagedProductDefinitionBiologicalSearchTarget {
+    /// Variant for DeviceDefinition target resources
+    DeviceDefinition(resources::DeviceDefinition),
+    /// Variant for NutritionProduct target resources
+    NutritionProduct(resources::NutritionProduct),
+    /// Variant for BiologicallyDerivedProduct target resources
+    BiologicallyDerivedProduct(resources::BiologicallyDerivedProduct),
+    /// Variant for PackagedProductDefinition target resources
+    PackagedProductDefinition(resources::PackagedProductDefinition),
+    /// Variant for ManufacturedItemDefinition target resources
+    ManufacturedItemDefinition(resources::ManufacturedItemDefinition),
+}
+impl From<resources::DeviceDefinition> for PackagedProductDefinitionBiologicalSearchTarget {
+    fn from(resource: resources::DeviceDefinition) -> PackagedProductDefinitionBiologicalSearchTarget {
+        PackagedProductDefinitionBiologicalSearchTarget::DeviceDefinition(resource)
+    }
+}
+impl From<resources::NutritionProduct> for PackagedProductDefinitionBiologicalSearchTarget {
+    fn from(resource: resources::NutritionProduct) -> PackagedProductDefinitionBiologicalSearchTarget {
+        PackagedProductDefinitionBiologicalSearchTarget::NutritionProduct(resource)
+    }
+}
+impl From<resources::BiologicallyDerivedProduct> for PackagedProductDefinitionBiologicalSearchTarget {
+    fn from(resource: resources::BiologicallyDerivedProduct) -> PackagedProductDefinitionBiologicalSearchTarget {
+        PackagedProductDefinitionBiologicalSearchTarget::BiologicallyDerivedProduct(resource)
+    }
+}
+impl From<resources::PackagedProductDefinition> for PackagedProductDefinitionBiologicalSearchTarget {
+    fn from(resource: resources::PackagedProductDefinition) -> PackagedProductDefinitionBiologicalSearchTarget {
+        PackagedProductDefinitionBiologicalSearchTarget::PackagedProductDefinition(resource)
+    }
+}
+impl From<resources::ManufacturedItemDefinition> for PackagedProductDefinitionBiologicalSearchTarget {
+    fn from(resource: resources::ManufacturedItemDefinition) -> PackagedProductDefinitionBiologicalSearchTarget {
+        PackagedProductDefinitionBiologicalSearchTarget::ManufacturedItemDefinition(resource)
+    }
+}
+/// The contained-item reference search parameter of the PackagedProductDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PackagedProductDefinitionContainedItemSearchReference;
+impl ReferenceSearchParameter for PackagedProductDefinitionContainedItemSearchReference {
+    type Resource = resources::PackagedProductDefinition;
+    type Target = PackagedProductDefinitionContainedItemSearchTarget;
+    fn parameter(&self) -> PackagedProductDefinitionSearchParameter {
+        PackagedProductDefinitionSearchParameter::ContainedItem
+    }
+}
+/// Target resources of the contained-item reference search parameter of the PackagedProductDefinition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PackagedProductDefinitionContainedItemSearchTarget {
+    /// Variant for DeviceDefinition target resources
+    DeviceDefinition(resources::DeviceDefinition),
+    /// Variant for NutritionProduct target resources
+    NutritionProduct(resources::NutritionProduct),
+    /// Variant for BiologicallyDerivedProduct target resources
+    BiologicallyDerivedProduct(resources::BiologicallyDerivedProduct),
+    /// Variant for PackagedProductDefinition target resources
+    PackagedProductDefinition(resources::PackagedProductDefinition),
+    /// Variant for ManufacturedItemDefinition target resources
+    ManufacturedItemDefinition(resources::ManufacturedItemDefinition),
+}
+impl From<resources::DeviceDefinition> for PackagedProductDefinitionContainedItemSearchTarget {
+    fn from(resource: resources::DeviceDefinition) -> PackagedProductDefinitionContainedItemSearchTarget {
+        PackagedProductDefinitionContainedItemSearchTarget::DeviceDefinition(resource)
+    }
+}
+impl From<resources::NutritionProduct> for PackagedProductDefinitionContainedItemSearchTarget {
+    fn from(resource: resources::NutritionProduct) -> PackagedProductDefinitionContainedItemSearchTarget {
+        PackagedProductDefinitionContainedItemSearchTarget::NutritionProduct(resource)
+    }
+}
+impl From<resources::BiologicallyDerivedProduct> for PackagedProductDefinitionContainedItemSearchTarget {
+    fn from(resource: resources::BiologicallyDerivedProduct) -> PackagedProductDefinitionContainedItemSearchTarget {
+        PackagedProductDefinitionContainedItemSearchTarget::BiologicallyDerivedProduct(resource)
+    }
+}
+impl From<resources::PackagedProductDefinition> for PackagedProductDefinitionContainedItemSearchTarget {
+    fn from(resource: resources::PackagedProductDefinition) -> PackagedProductDefinitionContainedItemSearchTarget {
+        PackagedProductDefinitionContainedItemSearchTarget::PackagedProductDefinition(resource)
+    }
+}
+impl From<resources::ManufacturedItemDefinition> for PackagedProductDefinitionContainedItemSearchTarget {
+    fn from(resource: resources::ManufacturedItemDefinition) -> PackagedProductDefinitionContainedItemSearchTarget {
+        PackagedProductDefinitionContainedItemSearchTarget::ManufacturedItemDefinition(resource)
+    }
+}
+/// The device reference search parameter of the PackagedProductDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PackagedProductDefinitionDeviceSearchReference;
+impl ReferenceSearchParameter for PackagedProductDefinitionDeviceSearchReference {
+    type Resource = resources::PackagedProductDefinition;
+    type Target = PackagedProductDefinitionDeviceSearchTarget;
+    fn parameter(&self) -> PackagedProductDefinitionSearchParameter {
+        PackagedProductDefinitionSearchParameter::Device
+    }
+}
+/// Target resources of the device reference search parameter of the PackagedProductDefinition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PackagedProductDefinitionDeviceSearchTarget {
+    /// Variant for DeviceDefinition target resources
+    DeviceDefinition(resources::DeviceDefinition),
+    /// Variant for NutritionProduct target resources
+    NutritionProduct(resources::NutritionProduct),
+    /// Variant for BiologicallyDerivedProduct target resources
+    BiologicallyDerivedProduct(resources::BiologicallyDerivedProduct),
+    /// Variant for PackagedProductDefinition target resources
+    PackagedProductDefinition(resources::PackagedProductDefinition),
+    /// Variant for ManufacturedItemDefinition target resources
+    ManufacturedItemDefinition(resources::ManufacturedItemDefinition),
+}
+impl From<resources::DeviceDefinition> for PackagedProductDefinitionDeviceSearchTarget {
+    fn from(resource: resources::DeviceDefinition) -> PackagedProductDefinitionDeviceSearchTarget {
+        PackagedProductDefinitionDeviceSearchTarget::DeviceDefinition(resource)
+    }
+}
+impl From<resources::NutritionProduct> for PackagedProductDefinitionDeviceSearchTarget {
+    fn from(resource: resources::NutritionProduct) -> PackagedProductDefinitionDeviceSearchTarget {
+        PackagedProductDefinitionDeviceSearchTarget::NutritionProduct(resource)
+    }
+}
+impl From<resources::BiologicallyDerivedProduct> for PackagedProductDefinitionDeviceSearchTarget {
+    fn from(resource: resources::BiologicallyDerivedProduct) -> PackagedProductDefinitionDeviceSearchTarget {
+        PackagedProductDefinitionDeviceSearchTarget::BiologicallyDerivedProduct(resource)
+    }
+}
+impl From<resources::PackagedProductDefinition> for PackagedProductDefinitionDeviceSearchTarget {
+    fn from(resource: resources::PackagedProductDefinition) -> PackagedProductDefinitionDeviceSearchTarget {
+        PackagedProductDefinitionDeviceSearchTarget::PackagedProductDefinition(resource)
+    }
+}
+impl From<resources::ManufacturedItemDefinition> for PackagedProductDefinitionDeviceSearchTarget {
+    fn from(resource: resources::ManufacturedItemDefinition) -> PackagedProductDefinitionDeviceSearchTarget {
+        PackagedProductDefinitionDeviceSearchTarget::ManufacturedItemDefinition(resource)
+    }
+}
+/// The manufactured-item reference search parameter of the PackagedProductDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PackagedProductDefinitionManufacturedItemSearchReference;
+impl ReferenceSearchParameter for PackagedProductDefinitionManufacturedItemSearchReference {
+    type Resource = resources::PackagedProductDefinition;
+    type Target = PackagedProductDefinitionManufacturedItemSearchTarget;
+    fn parameter(&self) -> PackagedProductDefinitionSearchParameter {
+        PackagedProductDefinitionSearchParameter::ManufacturedItem
+    }
+}
+/// Target resources of the manufactured-item reference search parameter of the PackagedProductDefinition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PackagedProductDefinitionManufacturedItemSearchTarget {
+    /// Variant for DeviceDefinition target resources
+    DeviceDefinition(resources::DeviceDefinition),
+    /// Variant for NutritionProduct target resources
+    NutritionProduct(resources::NutritionProduct),
+    /// Variant for BiologicallyDerivedProduct target resources
+    BiologicallyDerivedProduct(resources::BiologicallyDerivedProduct),
+    /// Variant for PackagedProductDefinition target resources
+    PackagedProductDefinition(resources::PackagedProductDefinition),
+    /// Variant for ManufacturedItemDefinition target resources
+    ManufacturedItemDefinition(resources::ManufacturedItemDefinition),
+}
+impl From<resources::DeviceDefinition> for PackagedProductDefinitionManufacturedItemSearchTarget {
+    fn from(resource: resources::DeviceDefinition) -> PackagedProductDefinitionManufacturedItemSearchTarget {
+        PackagedProductDefinitionManufacturedItemSearchTarget::DeviceDefinition(resource)
+    }
+}
+impl From<resources::NutritionProduct> for PackagedProductDefinitionManufacturedItemSearchTarget {
+    fn from(resource: resources::NutritionProduct) -> PackagedProductDefinitionManufacturedItemSearchTarget {
+        PackagedProductDefinitionManufacturedItemSearchTarget::NutritionProduct(resource)
+    }
+}
+impl From<resources::BiologicallyDerivedProduct> for PackagedProductDefinitionManufacturedItemSearchTarget {
+    fn from(resource: resources::BiologicallyDerivedProduct) -> PackagedProductDefinitionManufacturedItemSearchTarget {
+        PackagedProductDefinitionManufacturedItemSearchTarget::BiologicallyDerivedProduct(resource)
+    }
+}
+impl From<resources::PackagedProductDefinition> for PackagedProductDefinitionManufacturedItemSearchTarget {
+    fn from(resource: resources::PackagedProductDefinition) -> PackagedProductDefinitionManufacturedItemSearchTarget {
+        PackagedProductDefinitionManufacturedItemSearchTarget::PackagedProductDefinition(resource)
+    }
+}
+impl From<resources::ManufacturedItemDefinition> for PackagedProductDefinitionManufacturedItemSearchTarget {
+    fn from(resource: resources::ManufacturedItemDefinition) -> PackagedProductDefinitionManufacturedItemSearchTarget {
+        PackagedProductDefinitionManufacturedItemSearchTarget::ManufacturedItemDefinition(resource)
+    }
+}
+/// The medication reference search parameter of the PackagedProductDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PackagedProductDefinitionMedicationSearchReference;
+impl ReferenceSearchParameter for PackagedProductDefinitionMedicationSearchReference {
+    type Resource = resources::PackagedProductDefinition;
+    type Target = PackagedProductDefinitionMedicationSearchTarget;
+    fn parameter(&self) -> PackagedProductDefinitionSearchParameter {
+        PackagedProductDefinitionSearchParameter::Medication
+    }
+}
+/// Target resources of the medication reference search parameter of the PackagedProductDefinition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PackagedProductDefinitionMedicationSearchTarget {
+    /// Variant for DeviceDefinition target resources
+    DeviceDefinition(resources::DeviceDefinition),
+    /// Variant for NutritionProduct target resources
+    NutritionProduct(resources::NutritionProduct),
+    /// Variant for BiologicallyDerivedProduct target resources
+    BiologicallyDerivedProduct(resources::BiologicallyDerivedProduct),
+    /// Variant for PackagedProductDefinition target resources
+    PackagedProductDefinition(resources::PackagedProductDefinition),
+    /// Variant for ManufacturedItemDefinition target resources
+    ManufacturedItemDefinition(resources::ManufacturedItemDefinition),
+}
+impl From<resources::DeviceDefinition> for PackagedProductDefinitionMedicationSearchTarget {
+    fn from(resource: resources::DeviceDefinition) -> PackagedProductDefinitionMedicationSearchTarget {
+        PackagedProductDefinitionMedicationSearchTarget::DeviceDefinition(resource)
+    }
+}
+impl From<resources::NutritionProduct> for PackagedProductDefinitionMedicationSearchTarget {
+    fn from(resource: resources::NutritionProduct) -> PackagedProductDefinitionMedicationSearchTarget {
+        PackagedProductDefinitionMedicationSearchTarget::NutritionProduct(resource)
+    }
+}
+impl From<resources::BiologicallyDerivedProduct> for PackagedProductDefinitionMedicationSearchTarget {
+    fn from(resource: resources::BiologicallyDerivedProduct) -> PackagedProductDefinitionMedicationSearchTarget {
+        PackagedProductDefinitionMedicationSearchTarget::BiologicallyDerivedProduct(resource)
+    }
+}
+impl From<resources::PackagedProductDefinition> for PackagedProductDefinitionMedicationSearchTarget {
+    fn from(resource: resources::PackagedProductDefinition) -> PackagedProductDefinitionMedicationSearchTarget {
+        PackagedProductDefinitionMedicationSearchTarget::PackagedProductDefinition(resource)
+    }
+}
+impl From<resources::ManufacturedItemDefinition> for PackagedProductDefinitionMedicationSearchTarget {
+    fn from(resource: resources::ManufacturedItemDefinition) -> PackagedProductDefinitionMedicationSearchTarget {
+        PackagedProductDefinitionMedicationSearchTarget::ManufacturedItemDefinition(resource)
+    }
+}
+/// The nutrition reference search parameter of the PackagedProductDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PackagedProductDefinitionNutritionSearchReference;
+impl ReferenceSearchParameter for PackagedProductDefinitionNutritionSearchReference {
+    type Resource = resources::PackagedProductDefinition;
+    type Target = PackagedProductDefinitionNutritionSearchTarget;
+    fn parameter(&self) -> PackagedProductDefinitionSearchParameter {
+        PackagedProductDefinitionSearchParameter::Nutrition
+    }
+}
+/// Target resources of the nutrition reference search parameter of the PackagedProductDefinition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PackagedProductDefinitionNutritionSearchTarget {
+    /// Variant for DeviceDefinition target resources
+    DeviceDefinition(resources::DeviceDefinition),
+    /// Variant for NutritionProduct target resources
+    NutritionProduct(resources::NutritionProduct),
+    /// Variant for BiologicallyDerivedProduct target resources
+    BiologicallyDerivedProduct(resources::BiologicallyDerivedProduct),
+    /// Variant for PackagedProductDefinition target resources
+    PackagedProductDefinition(resources::PackagedProductDefinition),
+    /// Variant for ManufacturedItemDefinition target resources
+    ManufacturedItemDefinition(resources::ManufacturedItemDefinition),
+}
+impl From<resources::DeviceDefinition> for PackagedProductDefinitionNutritionSearchTarget {
+    fn from(resource: resources::DeviceDefinition) -> PackagedProductDefinitionNutritionSearchTarget {
+        PackagedProductDefinitionNutritionSearchTarget::DeviceDefinition(resource)
+    }
+}
+impl From<resources::NutritionProduct> for PackagedProductDefinitionNutritionSearchTarget {
+    fn from(resource: resources::NutritionProduct) -> PackagedProductDefinitionNutritionSearchTarget {
+        PackagedProductDefinitionNutritionSearchTarget::NutritionProduct(resource)
+    }
+}
+impl From<resources::BiologicallyDerivedProduct> for PackagedProductDefinitionNutritionSearchTarget {
+    fn from(resource: resources::BiologicallyDerivedProduct) -> PackagedProductDefinitionNutritionSearchTarget {
+        PackagedProductDefinitionNutritionSearchTarget::BiologicallyDerivedProduct(resource)
+    }
+}
+impl From<resources::PackagedProductDefinition> for PackagedProductDefinitionNutritionSearchTarget {
+    fn from(resource: resources::PackagedProductDefinition) -> PackagedProductDefinitionNutritionSearchTarget {
+        PackagedProductDefinitionNutritionSearchTarget::PackagedProductDefinition(resource)
+    }
+}
+impl From<resources::ManufacturedItemDefinition> for PackagedProductDefinitionNutritionSearchTarget {
+    fn from(resource: resources::ManufacturedItemDefinition) -> PackagedProductDefinitionNutritionSearchTarget {
+        PackagedProductDefinitionNutritionSearchTarget::ManufacturedItemDefinition(resource)
+    }
+}
+/// The package reference search parameter of the PackagedProductDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PackagedProductDefinitionPackageSearchReference;
+impl ReferenceSearchParameter for PackagedProductDefinitionPackageSearchReference {
+    type Resource = resources::PackagedProductDefinition;
+    type Target = PackagedProductDefinitionPackageSearchTarget;
+    fn parameter(&self) -> PackagedProductDefinitionSearchParameter {
+        PackagedProductDefinitionSearchParameter::Package
+    }
+}
+/// Target resources of the package reference search parameter of the PackagedProductDefinition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PackagedProductDefinitionPackageSearchTarget {
+    /// Variant for DeviceDefinition target resources
+    DeviceDefinition(resources::DeviceDefinition),
+    /// Variant for NutritionProduct target resources
+    NutritionProduct(resources::NutritionProduct),
+    /// Variant for BiologicallyDerivedProduct target resources
+    BiologicallyDerivedProduct(resources::BiologicallyDerivedProduct),
+    /// Variant for PackagedProductDefinition target resources
+    PackagedProductDefinition(resources::PackagedProductDefinition),
+    /// Variant for ManufacturedItemDefinition target resources
+    ManufacturedItemDefinition(resources::ManufacturedItemDefinition),
+}
+impl From<resources::DeviceDefinition> for PackagedProductDefinitionPackageSearchTarget {
+    fn from(resource: resources::DeviceDefinition) -> PackagedProductDefinitionPackageSearchTarget {
+        PackagedProductDefinitionPackageSearchTarget::DeviceDefinition(resource)
+    }
+}
+impl From<resources::NutritionProduct> for PackagedProductDefinitionPackageSearchTarget {
+    fn from(resource: resources::NutritionProduct) -> PackagedProductDefinitionPackageSearchTarget {
+        PackagedProductDefinitionPackageSearchTarget::NutritionProduct(resource)
+    }
+}
+impl From<resources::BiologicallyDerivedProduct> for PackagedProductDefinitionPackageSearchTarget {
+    fn from(resource: resources::BiologicallyDerivedProduct) -> PackagedProductDefinitionPackageSearchTarget {
+        PackagedProductDefinitionPackageSearchTarget::BiologicallyDerivedProduct(resource)
+    }
+}
+impl From<resources::PackagedProductDefinition> for PackagedProductDefinitionPackageSearchTarget {
+    fn from(resource: resources::PackagedProductDefinition) -> PackagedProductDefinitionPackageSearchTarget {
+        PackagedProductDefinitionPackageSearchTarget::PackagedProductDefinition(resource)
+    }
+}
+impl From<resources::ManufacturedItemDefinition> for PackagedProductDefinitionPackageSearchTarget {
+    fn from(resource: resources::ManufacturedItemDefinition) -> PackagedProductDefinitionPackageSearchTarget {
+        PackagedProductDefinitionPackageSearchTarget::ManufacturedItemDefinition(resource)
+    }
+}
+/// The package-for reference search parameter of the PackagedProductDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PackagedProductDefinitionPackageForSearchReference;
+impl ReferenceSearchParameter for PackagedProductDefinitionPackageForSearchReference {
+    type Resource = resources::PackagedProductDefinition;
+    type Target = resources::MedicinalProductDefinition;
+    fn parameter(&self) -> PackagedProductDefinitionSearchParameter {
+        PackagedProductDefinitionSearchParameter::PackageFor
+    }
+}
+/// The general-practitioner reference search parameter of the Patient resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PatientGeneralPractitionerSearchReference;
+impl ReferenceSearchParameter for PatientGeneralPractitionerSearchReference {
+    type Resource = resources::Patient;
+    type Target = PatientGeneralPractitionerSearchTarget;
+    fn parameter(&self) -> PatientSearchParameter {
+        PatientSearchParameter::GeneralPractitioner
+    }
+}
+/// Target resources of the general-practitioner reference search parameter of the Patient resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PatientGeneralPractitionerSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for PatientGeneralPractitionerSearchTarget {
+    fn from(resource: resources::Organization) -> PatientGeneralPractitionerSearchTarget {
+        PatientGeneralPractitionerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for PatientGeneralPractitionerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> PatientGeneralPractitionerSearchTarget {
+        PatientGeneralPractitionerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for PatientGeneralPractitionerSearchTarget {
+    fn from(resource: resources::Practitioner) -> PatientGeneralPractitionerSearchTarget {
+        PatientGeneralPractitionerSearchTarget::Practitioner(resource)
+    }
+}
+/// The link reference search parameter of the Patient resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PatientLinkSearchReference;
+impl ReferenceSearchParameter for PatientLinkSearchReference {
+    type Resource = resources::Patient;
+    type Target = PatientLinkSearchTarget;
+    fn parameter(&self) -> PatientSearchParameter {
+        PatientSearchParameter::Link
+    }
+}
+/// Target resources of the link reference search parameter of the Patient resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PatientLinkSearchTarget {
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::RelatedPerson> for PatientLinkSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> PatientLinkSearchTarget {
+        PatientLinkSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::Patient> for PatientLinkSearchTarget {
+    fn from(resource: resources::Patient) -> PatientLinkSearchTarget {
+        PatientLinkSearchTarget::Patient(resource)
+    }
+}
+/// The organization reference search parameter of the Patient resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PatientOrganizationSearchReference;
+impl ReferenceSearchParameter for PatientOrganizationSearchReference {
+    type Resource = resources::Patient;
+    type Target = resources::Organization;
+    fn parameter(&self) -> PatientSearchParameter {
+        PatientSearchParameter::Organization
+    }
+}
+/// The reporter reference search parameter of the PaymentNotice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentNoticeReporterSearchReference;
+impl ReferenceSearchParameter for PaymentNoticeReporterSearchReference {
+    type Resource = resources::PaymentNotice;
+    type Target = PaymentNoticeReporterSearchTarget;
+    fn parameter(&self) -> PaymentNoticeSearchParameter {
+        PaymentNoticeSearchParameter::Reporter
+    }
+}
+/// Target resources of the reporter reference search parameter of the PaymentNotice resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PaymentNoticeReporterSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for PaymentNoticeReporterSearchTarget {
+    fn from(resource: resources::Organization) -> PaymentNoticeReporterSearchTarget {
+        PaymentNoticeReporterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for PaymentNoticeReporterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> PaymentNoticeReporterSearchTarget {
+        PaymentNoticeReporterSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for PaymentNoticeReporterSearchTarget {
+    fn from(resource: resources::Practitioner) -> PaymentNoticeReporterSearchTarget {
+        PaymentNoticeReporterSearchTarget::Practitioner(resource)
+    }
+}
+/// The request reference search parameter of the PaymentNotice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentNoticeRequestSearchReference;
+impl ReferenceSearchParameter for PaymentNoticeRequestSearchReference {
+    type Resource = resources::PaymentNotice;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PaymentNoticeSearchParameter {
+        PaymentNoticeSearchParameter::Request
+    }
+}
+/// The response reference search parameter of the PaymentNotice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentNoticeResponseSearchReference;
+impl ReferenceSearchParameter for PaymentNoticeResponseSearchReference {
+    type Resource = resources::PaymentNotice;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PaymentNoticeSearchParameter {
+        PaymentNoticeSearchParameter::Response
+    }
+}
+/// The allocation-account reference search parameter of the PaymentReconciliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentReconciliationAllocationAccountSearchReference;
+impl ReferenceSearchParameter for PaymentReconciliationAllocationAccountSearchReference {
+    type Resource = resources::PaymentReconciliation;
+    type Target = resources::Account;
+    fn parameter(&self) -> PaymentReconciliationSearchParameter {
+        PaymentReconciliationSearchParameter::AllocationAccount
+    }
+}
+/// The allocation-encounter reference search parameter of the PaymentReconciliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentReconciliationAllocationEncounterSearchReference;
+impl ReferenceSearchParameter for PaymentReconciliationAllocationEncounterSearchReference {
+    type Resource = resources::PaymentReconciliation;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> PaymentReconciliationSearchParameter {
+        PaymentReconciliationSearchParameter::AllocationEncounter
+    }
+}
+/// The payment-issuer reference search parameter of the PaymentReconciliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentReconciliationPaymentIssuerSearchReference;
+impl ReferenceSearchParameter for PaymentReconciliationPaymentIssuerSearchReference {
+    type Resource = resources::PaymentReconciliation;
+    type Target = PaymentReconciliationPaymentIssuerSearchTarget;
+    fn parameter(&self) -> PaymentReconciliationSearchParameter {
+        PaymentReconciliationSearchParameter::PaymentIssuer
+    }
+}
+/// Target resources of the payment-issuer reference search parameter of the PaymentReconciliation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PaymentReconciliationPaymentIssuerSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for PaymentReconciliationPaymentIssuerSearchTarget {
+    fn from(resource: resources::Organization) -> PaymentReconciliationPaymentIssuerSearchTarget {
+        PaymentReconciliationPaymentIssuerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for PaymentReconciliationPaymentIssuerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> PaymentReconciliationPaymentIssuerSearchTarget {
+        PaymentReconciliationPaymentIssuerSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::Patient> for PaymentReconciliationPaymentIssuerSearchTarget {
+    fn from(resource: resources::Patient) -> PaymentReconciliationPaymentIssuerSearchTarget {
+        PaymentReconciliationPaymentIssuerSearchTarget::Patient(resource)
+    }
+}
+/// The request reference search parameter of the PaymentReconciliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentReconciliationRequestSearchReference;
+impl ReferenceSearchParameter for PaymentReconciliationRequestSearchReference {
+    type Resource = resources::PaymentReconciliation;
+    type Target = resources::Task;
+    fn parameter(&self) -> PaymentReconciliationSearchParameter {
+        PaymentReconciliationSearchParameter::Request
+    }
+}
+/// The requestor reference search parameter of the PaymentReconciliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentReconciliationRequestorSearchReference;
+impl ReferenceSearchParameter for PaymentReconciliationRequestorSearchReference {
+    type Resource = resources::PaymentReconciliation;
+    type Target = PaymentReconciliationRequestorSearchTarget;
+    fn parameter(&self) -> PaymentReconciliationSearchParameter {
+        PaymentReconciliationSearchParameter::Requestor
+    }
+}
+/// Target resources of the requestor reference search parameter of the PaymentReconciliation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PaymentReconciliationRequestorSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for PaymentReconciliationRequestorSearchTarget {
+    fn from(resource: resources::Organization) -> PaymentReconciliationRequestorSearchTarget {
+        PaymentReconciliationRequestorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for PaymentReconciliationRequestorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> PaymentReconciliationRequestorSearchTarget {
+        PaymentReconciliationRequestorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for PaymentReconciliationRequestorSearchTarget {
+    fn from(resource: resources::Practitioner) -> PaymentReconciliationRequestorSearchTarget {
+        PaymentReconciliationRequestorSearchTarget::Practitioner(resource)
+    }
+}
+/// The patient reference search parameter of the Person resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PersonPatientSearchReference;
+impl ReferenceSearchParameter for PersonPatientSearchReference {
+    type Resource = resources::Person;
+    type Target = resources::Patient;
+    fn parameter(&self) -> PersonSearchParameter {
+        PersonSearchParameter::Patient
+    }
+}
+/// The link reference search parameter of the Person resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PersonLinkSearchReference;
+impl ReferenceSearchParameter for PersonLinkSearchReference {
+    type Resource = resources::Person;
+    type Target = PersonLinkSearchTarget;
+    fn parameter(&self) -> PersonSearchParameter {
+        PersonSearchParameter::Link
+    }
+}
+/// Target resources of the link reference search parameter of the Person resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PersonLinkSearchTarget {
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Person target resources
+    Person(resources::Person),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::RelatedPerson> for PersonLinkSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> PersonLinkSearchTarget {
+        PersonLinkSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::Practitioner> for PersonLinkSearchTarget {
+    fn from(resource: resources::Practitioner) -> PersonLinkSearchTarget {
+        PersonLinkSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Person> for PersonLinkSearchTarget {
+    fn from(resource: resources::Person) -> PersonLinkSearchTarget {
+        PersonLinkSearchTarget::Person(resource)
+    }
+}
+impl From<resources::Patient> for PersonLinkSearchTarget {
+    fn from(resource: resources::Patient) -> PersonLinkSearchTarget {
+        PersonLinkSearchTarget::Patient(resource)
+    }
+}
+/// The organization reference search parameter of the Person resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PersonOrganizationSearchReference;
+impl ReferenceSearchParameter for PersonOrganizationSearchReference {
+    type Resource = resources::Person;
+    type Target = resources::Organization;
+    fn parameter(&self) -> PersonSearchParameter {
+        PersonSearchParameter::Organization
+    }
+}
+/// The practitioner reference search parameter of the Person resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PersonPractitionerSearchReference;
+impl ReferenceSearchParameter for PersonPractitionerSearchReference {
+    type Resource = resources::Person;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> PersonSearchParameter {
+        PersonSearchParameter::Practitioner
+    }
+}
+/// The relatedperson reference search parameter of the Person resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PersonRelatedpersonSearchReference;
+impl ReferenceSearchParameter for PersonRelatedpersonSearchReference {
+    type Resource = resources::Person;
+    type Target = resources::RelatedPerson;
+    fn parameter(&self) -> PersonSearchParameter {
+        PersonSearchParameter::Relatedperson
+    }
+}
+/// The composed-of reference search parameter of the PlanDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlanDefinitionComposedOfSearchReference;
+impl ReferenceSearchParameter for PlanDefinitionComposedOfSearchReference {
+    type Resource = resources::PlanDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PlanDefinitionSearchParameter {
+        PlanDefinitionSearchParameter::ComposedOf
+    }
+}
+/// The depends-on reference search parameter of the PlanDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlanDefinitionDependsOnSearchReference;
+impl ReferenceSearchParameter for PlanDefinitionDependsOnSearchReference {
+    type Resource = resources::PlanDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PlanDefinitionSearchParameter {
+        PlanDefinitionSearchParameter::DependsOn
+    }
+}
+/// The derived-from reference search parameter of the PlanDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlanDefinitionDerivedFromSearchReference;
+impl ReferenceSearchParameter for PlanDefinitionDerivedFromSearchReference {
+    type Resource = resources::PlanDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PlanDefinitionSearchParameter {
+        PlanDefinitionSearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the PlanDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlanDefinitionPredecessorSearchReference;
+impl ReferenceSearchParameter for PlanDefinitionPredecessorSearchReference {
+    type Resource = resources::PlanDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PlanDefinitionSearchParameter {
+        PlanDefinitionSearchParameter::Predecessor
+    }
+}
+/// The successor reference search parameter of the PlanDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlanDefinitionSuccessorSearchReference;
+impl ReferenceSearchParameter for PlanDefinitionSuccessorSearchReference {
+    type Resource = resources::PlanDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PlanDefinitionSearchParameter {
+        PlanDefinitionSearchParameter::Successor
+    }
+}
+/// The definition reference search parameter of the PlanDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlanDefinitionDefinitionSearchReference;
+impl ReferenceSearchParameter for PlanDefinitionDefinitionSearchReference {
+    type Resource = resources::PlanDefinition;
+    type Target = PlanDefinitionDefinitionSearchTarget;
+    fn parameter(&self) -> PlanDefinitionSearchParameter {
+        PlanDefinitionSearchParameter::Definition
+    }
+}
+/// Target resources of the definition reference search parameter of the PlanDefinition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PlanDefinitionDefinitionSearchTarget {
+    /// Variant for ObservationDefinition target resources
+    ObservationDefinition(resources::ObservationDefinition),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for MessageDefinition target resources
+    MessageDefinition(resources::MessageDefinition),
+    /// Variant for Questionnaire target resources
+    Questionnaire(resources::Questionnaire),
+    /// Variant for SpecimenDefinition target resources
+    SpecimenDefinition(resources::SpecimenDefinition),
+}
+impl From<resources::ObservationDefinition> for PlanDefinitionDefinitionSearchTarget {
+    fn from(resource: resources::ObservationDefinition) -> PlanDefinitionDefinitionSearchTarget {
+        PlanDefinitionDefinitionSearchTarget::ObservationDefinition(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for PlanDefinitionDefinitionSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> PlanDefinitionDefinitionSearchTarget {
+        PlanDefinitionDefinitionSearchTarget::ActivityDefinition(resource)
+    }
+}
+impl From<resources::PlanDefinition> for PlanDefinitionDefinitionSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> PlanDefinitionDefinitionSearchTarget {
+        PlanDefinitionDefinitionSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::MessageDefinition> for PlanDefinitionDefinitionSearchTarget {
+    fn from(resource: resources::MessageDefinition) -> PlanDefinitionDefinitionSearchTarget {
+        PlanDefinitionDefinitionSearchTarget::MessageDefinition(resource)
+    }
+}
+impl From<resources::Questionnaire> for PlanDefinitionDefinitionSearchTarget {
+    fn from(resource: resources::Questionnaire) -> PlanDefinitionDefinitionSearchTarget {
+        PlanDefinitionDefinitionSearchTarget::Questionnaire(resource)
+    }
+}
+impl From<resources::SpecimenDefinition> for PlanDefinitionDefinitionSearchTarget {
+    fn from(resource: resources::SpecimenDefinition) -> PlanDefinitionDefinitionSearchTarget {
+        PlanDefinitionDefinitionSearchTarget::SpecimenDefinition(resource)
+    }
+}
+/// The endpoint reference search parameter of the PractitionerRole resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PractitionerRoleEndpointSearchReference;
+impl ReferenceSearchParameter for PractitionerRoleEndpointSearchReference {
+    type Resource = resources::PractitionerRole;
+    type Target = resources::Endpoint;
+    fn parameter(&self) -> PractitionerRoleSearchParameter {
+        PractitionerRoleSearchParameter::Endpoint
+    }
+}
+/// The location reference search parameter of the PractitionerRole resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PractitionerRoleLocationSearchReference;
+impl ReferenceSearchParameter for PractitionerRoleLocationSearchReference {
+    type Resource = resources::PractitionerRole;
+    type Target = resources::Location;
+    fn parameter(&self) -> PractitionerRoleSearchParameter {
+        PractitionerRoleSearchParameter::Location
+    }
+}
+/// The organization reference search parameter of the PractitionerRole resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PractitionerRoleOrganizationSearchReference;
+impl ReferenceSearchParameter for PractitionerRoleOrganizationSearchReference {
+    type Resource = resources::PractitionerRole;
+    type Target = resources::Organization;
+    fn parameter(&self) -> PractitionerRoleSearchParameter {
+        PractitionerRoleSearchParameter::Organization
+    }
+}
+/// The practitioner reference search parameter of the PractitionerRole resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PractitionerRolePractitionerSearchReference;
+impl ReferenceSearchParameter for PractitionerRolePractitionerSearchReference {
+    type Resource = resources::PractitionerRole;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> PractitionerRoleSearchParameter {
+        PractitionerRoleSearchParameter::Practitioner
+    }
+}
+/// The service reference search parameter of the PractitionerRole resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PractitionerRoleServiceSearchReference;
+impl ReferenceSearchParameter for PractitionerRoleServiceSearchReference {
+    type Resource = resources::PractitionerRole;
+    type Target = resources::HealthcareService;
+    fn parameter(&self) -> PractitionerRoleSearchParameter {
+        PractitionerRoleSearchParameter::Service
+    }
+}
+/// The patient reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedurePatientSearchReference;
+impl ReferenceSearchParameter for ProcedurePatientSearchReference {
+    type Resource = resources::Procedure;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureEncounterSearchReference;
+impl ReferenceSearchParameter for ProcedureEncounterSearchReference {
+    type Resource = resources::Procedure;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::Encounter
+    }
+}
+/// The based-on reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureBasedOnSearchReference;
+impl ReferenceSearchParameter for ProcedureBasedOnSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedureBasedOnSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureBasedOnSearchTarget {
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+}
+impl From<resources::ServiceRequest> for ProcedureBasedOnSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> ProcedureBasedOnSearchTarget {
+        ProcedureBasedOnSearchTarget::ServiceRequest(resource)
+    }
+}
+impl From<resources::CarePlan> for ProcedureBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> ProcedureBasedOnSearchTarget {
+        ProcedureBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+/// The instantiates-canonical reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureInstantiatesCanonicalSearchReference;
+impl ReferenceSearchParameter for ProcedureInstantiatesCanonicalSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedureInstantiatesCanonicalSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::InstantiatesCanonical
+    }
+}
+/// Target resources of the instantiates-canonical reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureInstantiatesCanonicalSearchTarget {
+    /// Variant for Measure target resources
+    Measure(resources::Measure),
+    /// Variant for OperationDefinition target resources
+    OperationDefinition(resources::OperationDefinition),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for Questionnaire target resources
+    Questionnaire(resources::Questionnaire),
+}
+impl From<resources::Measure> for ProcedureInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Measure) -> ProcedureInstantiatesCanonicalSearchTarget {
+        ProcedureInstantiatesCanonicalSearchTarget::Measure(resource)
+    }
+}
+impl From<resources::OperationDefinition> for ProcedureInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::OperationDefinition) -> ProcedureInstantiatesCanonicalSearchTarget {
+        ProcedureInstantiatesCanonicalSearchTarget::OperationDefinition(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for ProcedureInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> ProcedureInstantiatesCanonicalSearchTarget {
+        ProcedureInstantiatesCanonicalSearchTarget::ActivityDefinition(resource)
+    }
+}
+impl From<resources::PlanDefinition> for ProcedureInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> ProcedureInstantiatesCanonicalSearchTarget {
+        ProcedureInstantiatesCanonicalSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::Questionnaire> for ProcedureInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Questionnaire) -> ProcedureInstantiatesCanonicalSearchTarget {
+        ProcedureInstantiatesCanonicalSearchTarget::Questionnaire(resource)
+    }
+}
+/// The location reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureLocationSearchReference;
+impl ReferenceSearchParameter for ProcedureLocationSearchReference {
+    type Resource = resources::Procedure;
+    type Target = resources::Location;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::Location
+    }
+}
+/// The part-of reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedurePartOfSearchReference;
+impl ReferenceSearchParameter for ProcedurePartOfSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedurePartOfSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::PartOf
+    }
+}
+/// Target resources of the part-of reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedurePartOfSearchTarget {
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for MedicationAdministration target resources
+    MedicationAdministration(resources::MedicationAdministration),
+}
+impl From<resources::Procedure> for ProcedurePartOfSearchTarget {
+    fn from(resource: resources::Procedure) -> ProcedurePartOfSearchTarget {
+        ProcedurePartOfSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::Observation> for ProcedurePartOfSearchTarget {
+    fn from(resource: resources::Observation) -> ProcedurePartOfSearchTarget {
+        ProcedurePartOfSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::MedicationAdministration> for ProcedurePartOfSearchTarget {
+    fn from(resource: resources::MedicationAdministration) -> ProcedurePartOfSearchTarget {
+        ProcedurePartOfSearchTarget::MedicationAdministration(resource)
+    }
+}
+/// The performer reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedurePerformerSearchReference;
+impl ReferenceSearchParameter for ProcedurePerformerSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedurePerformerSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedurePerformerSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for ProcedurePerformerSearchTarget {
+    fn from(resource: resources::HealthcareService) -> ProcedurePerformerSearchTarget {
+        ProcedurePerformerSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::Organization> for ProcedurePerformerSearchTarget {
+    fn from(resource: resources::Organization) -> ProcedurePerformerSearchTarget {
+        ProcedurePerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for ProcedurePerformerSearchTarget {
+    fn from(resource: resources::Device) -> ProcedurePerformerSearchTarget {
+        ProcedurePerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::CareTeam> for ProcedurePerformerSearchTarget {
+    fn from(resource: resources::CareTeam) -> ProcedurePerformerSearchTarget {
+        ProcedurePerformerSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ProcedurePerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ProcedurePerformerSearchTarget {
+        ProcedurePerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ProcedurePerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ProcedurePerformerSearchTarget {
+        ProcedurePerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ProcedurePerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ProcedurePerformerSearchTarget {
+        ProcedurePerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ProcedurePerformerSearchTarget {
+    fn from(resource: resources::Patient) -> ProcedurePerformerSearchTarget {
+        ProcedurePerformerSearchTarget::Patient(resource)
+    }
+}
+/// The reason-reference reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureReasonReferenceSearchReference;
+impl ReferenceSearchParameter for ProcedureReasonReferenceSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedureReasonReferenceSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::ReasonReference
+    }
+}
+/// Target resources of the reason-reference reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureReasonReferenceSearchTarget {
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for DocumentReference target resources
+    DocumentReference(resources::DocumentReference),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for DiagnosticReport target resources
+    DiagnosticReport(resources::DiagnosticReport),
+    /// Variant for Condition target resources
+    Condition(resources::Condition),
+}
+impl From<resources::Procedure> for ProcedureReasonReferenceSearchTarget {
+    fn from(resource: resources::Procedure) -> ProcedureReasonReferenceSearchTarget {
+        ProcedureReasonReferenceSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::DocumentReference> for ProcedureReasonReferenceSearchTarget {
+    fn from(resource: resources::DocumentReference) -> ProcedureReasonReferenceSearchTarget {
+        ProcedureReasonReferenceSearchTarget::DocumentReference(resource)
+    }
+}
+impl From<resources::Observation> for ProcedureReasonReferenceSearchTarget {
+    fn from(resource: resources::Observation) -> ProcedureReasonReferenceSearchTarget {
+        ProcedureReasonReferenceSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::DiagnosticReport> for ProcedureReasonReferenceSearchTarget {
+    fn from(resource: resources::DiagnosticReport) -> ProcedureReasonReferenceSearchTarget {
+        ProcedureReasonReferenceSearchTarget::DiagnosticReport(resource)
+    }
+}
+impl From<resources::Condition> for ProcedureReasonReferenceSearchTarget {
+    fn from(resource: resources::Condition) -> ProcedureReasonReferenceSearchTarget {
+        ProcedureReasonReferenceSearchTarget::Condition(resource)
+    }
+}
+/// The report reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureReportSearchReference;
+impl ReferenceSearchParameter for ProcedureReportSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedureReportSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::Report
+    }
+}
+/// Target resources of the report reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureReportSearchTarget {
+    /// Variant for Composition target resources
+    Composition(resources::Composition),
+    /// Variant for DocumentReference target resources
+    DocumentReference(resources::DocumentReference),
+    /// Variant for DiagnosticReport target resources
+    DiagnosticReport(resources::DiagnosticReport),
+}
+impl From<resources::Composition> for ProcedureReportSearchTarget {
+    fn from(resource: resources::Composition) -> ProcedureReportSearchTarget {
+        ProcedureReportSearchTarget::Composition(resource)
+    }
+}
+impl From<resources::DocumentReference> for ProcedureReportSearchTarget {
+    fn from(resource: resources::DocumentReference) -> ProcedureReportSearchTarget {
+        ProcedureReportSearchTarget::DocumentReference(resource)
+    }
+}
+impl From<resources::DiagnosticReport> for ProcedureReportSearchTarget {
+    fn from(resource: resources::DiagnosticReport) -> ProcedureReportSearchTarget {
+        ProcedureReportSearchTarget::DiagnosticReport(resource)
+    }
+}
+/// The subject reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureSubjectSearchReference;
+impl ReferenceSearchParameter for ProcedureSubjectSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedureSubjectSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureSubjectSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Device> for ProcedureSubjectSearchTarget {
+    fn from(resource: resources::Device) -> ProcedureSubjectSearchTarget {
+        ProcedureSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for ProcedureSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> ProcedureSubjectSearchTarget {
+        ProcedureSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Group> for ProcedureSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ProcedureSubjectSearchTarget {
+        ProcedureSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Practitioner> for ProcedureSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> ProcedureSubjectSearchTarget {
+        ProcedureSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Location> for ProcedureSubjectSearchTarget {
+    fn from(resource: resources::Location) -> ProcedureSubjectSearchTarget {
+        ProcedureSubjectSearchTarget::Location(resource)
+    }
+}
+impl From<resources::Patient> for ProcedureSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ProcedureSubjectSearchTarget {
+        ProcedureSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the Provenance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProvenancePatientSearchReference;
+impl ReferenceSearchParameter for ProvenancePatientSearchReference {
+    type Resource = resources::Provenance;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ProvenanceSearchParameter {
+        ProvenanceSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the Provenance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProvenanceEncounterSearchReference;
+impl ReferenceSearchParameter for ProvenanceEncounterSearchReference {
+    type Resource = resources::Provenance;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ProvenanceSearchParameter {
+        ProvenanceSearchParameter::Encounter
+    }
+}
+/// The agent reference search parameter of the Provenance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProvenanceAgentSearchReference;
+impl ReferenceSearchParameter for ProvenanceAgentSearchReference {
+    type Resource = resources::Provenance;
+    type Target = ProvenanceAgentSearchTarget;
+    fn parameter(&self) -> ProvenanceSearchParameter {
+        ProvenanceSearchParameter::Agent
+    }
+}
+/// Target resources of the agent reference search parameter of the Provenance resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProvenanceAgentSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for ProvenanceAgentSearchTarget {
+    fn from(resource: resources::Organization) -> ProvenanceAgentSearchTarget {
+        ProvenanceAgentSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for ProvenanceAgentSearchTarget {
+    fn from(resource: resources::CareTeam) -> ProvenanceAgentSearchTarget {
+        ProvenanceAgentSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for ProvenanceAgentSearchTarget {
+    fn from(resource: resources::Device) -> ProvenanceAgentSearchTarget {
+        ProvenanceAgentSearchTarget::Device(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ProvenanceAgentSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ProvenanceAgentSearchTarget {
+        ProvenanceAgentSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ProvenanceAgentSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ProvenanceAgentSearchTarget {
+        ProvenanceAgentSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ProvenanceAgentSearchTarget {
+    fn from(resource: resources::Practitioner) -> ProvenanceAgentSearchTarget {
+        ProvenanceAgentSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ProvenanceAgentSearchTarget {
+    fn from(resource: resources::Patient) -> ProvenanceAgentSearchTarget {
+        ProvenanceAgentSearchTarget::Patient(resource)
+    }
+}
+/// The based-on reference search parameter of the Provenance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProvenanceBasedOnSearchReference;
+impl ReferenceSearchParameter for ProvenanceBasedOnSearchReference {
+    type Resource = resources::Provenance;
+    type Target = ProvenanceBasedOnSearchTarget;
+    fn parameter(&self) -> ProvenanceSearchParameter {
+        ProvenanceSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the Provenance resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProvenanceBasedOnSearchTarget {
+    /// Variant for DeviceRequest target resources
+    DeviceRequest(resources::DeviceRequest),
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for Task target resources
+    Task(resources::Task),
+    /// Variant for MedicationRequest target resources
+    MedicationRequest(resources::MedicationRequest),
+    /// Variant for ImmunizationRecommendation target resources
+    ImmunizationRecommendation(resources::ImmunizationRecommendation),
+    /// Variant for NutritionOrder target resources
+    NutritionOrder(resources::NutritionOrder),
+}
+impl From<resources::DeviceRequest> for ProvenanceBasedOnSearchTarget {
+    fn from(resource: resources::DeviceRequest) -> ProvenanceBasedOnSearchTarget {
+        ProvenanceBasedOnSearchTarget::DeviceRequest(resource)
+    }
+}
+impl From<resources::ServiceRequest> for ProvenanceBasedOnSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> ProvenanceBasedOnSearchTarget {
+        ProvenanceBasedOnSearchTarget::ServiceRequest(resource)
+    }
+}
+impl From<resources::CarePlan> for ProvenanceBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> ProvenanceBasedOnSearchTarget {
+        ProvenanceBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::Task> for ProvenanceBasedOnSearchTarget {
+    fn from(resource: resources::Task) -> ProvenanceBasedOnSearchTarget {
+        ProvenanceBasedOnSearchTarget::Task(resource)
+    }
+}
+impl From<resources::MedicationRequest> for ProvenanceBasedOnSearchTarget {
+    fn from(resource: resources::MedicationRequest) -> ProvenanceBasedOnSearchTarget {
+        ProvenanceBasedOnSearchTarget::MedicationRequest(resource)
+    }
+}
+impl From<resources::ImmunizationRecommendation> for ProvenanceBasedOnSearchTarget {
+    fn from(resource: resources::ImmunizationRecommendation) -> ProvenanceBasedOnSearchTarget {
+        ProvenanceBasedOnSearchTarget::ImmunizationRecommendation(resource)
+    }
+}
+impl From<resources::NutritionOrder> for ProvenanceBasedOnSearchTarget {
+    fn from(resource: resources::NutritionOrder) -> ProvenanceBasedOnSearchTarget {
+        ProvenanceBasedOnSearchTarget::NutritionOrder(resource)
+    }
+}
+/// The entity reference search parameter of the Provenance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProvenanceEntitySearchReference;
+impl ReferenceSearchParameter for ProvenanceEntitySearchReference {
+    type Resource = resources::Provenance;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ProvenanceSearchParameter {
+        ProvenanceSearchParameter::Entity
+    }
+}
+/// The location reference search parameter of the Provenance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProvenanceLocationSearchReference;
+impl ReferenceSearchParameter for ProvenanceLocationSearchReference {
+    type Resource = resources::Provenance;
+    type Target = resources::Location;
+    fn parameter(&self) -> ProvenanceSearchParameter {
+        ProvenanceSearchParameter::Location
+    }
+}
+/// The target reference search parameter of the Provenance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProvenanceTargetSearchReference;
+impl ReferenceSearchParameter for ProvenanceTargetSearchReference {
+    type Resource = resources::Provenance;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ProvenanceSearchParameter {
+        ProvenanceSearchParameter::Target
+    }
+}
+/// The patient reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponsePatientSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponsePatientSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = resources::Patient;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponseEncounterSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponseEncounterSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::Encounter
+    }
+}
+/// The author reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponseAuthorSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponseAuthorSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = QuestionnaireResponseAuthorSearchTarget;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the QuestionnaireResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum QuestionnaireResponseAuthorSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Device> for QuestionnaireResponseAuthorSearchTarget {
+    fn from(resource: resources::Device) -> QuestionnaireResponseAuthorSearchTarget {
+        QuestionnaireResponseAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for QuestionnaireResponseAuthorSearchTarget {
+    fn from(resource: resources::Organization) -> QuestionnaireResponseAuthorSearchTarget {
+        QuestionnaireResponseAuthorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for QuestionnaireResponseAuthorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> QuestionnaireResponseAuthorSearchTarget {
+        QuestionnaireResponseAuthorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for QuestionnaireResponseAuthorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> QuestionnaireResponseAuthorSearchTarget {
+        QuestionnaireResponseAuthorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for QuestionnaireResponseAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> QuestionnaireResponseAuthorSearchTarget {
+        QuestionnaireResponseAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for QuestionnaireResponseAuthorSearchTarget {
+    fn from(resource: resources::Patient) -> QuestionnaireResponseAuthorSearchTarget {
+        QuestionnaireResponseAuthorSearchTarget::Patient(resource)
+    }
+}
+/// The based-on reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponseBasedOnSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponseBasedOnSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = QuestionnaireResponseBasedOnSearchTarget;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the QuestionnaireResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum QuestionnaireResponseBasedOnSearchTarget {
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+}
+impl From<resources::ServiceRequest> for QuestionnaireResponseBasedOnSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> QuestionnaireResponseBasedOnSearchTarget {
+        QuestionnaireResponseBasedOnSearchTarget::ServiceRequest(resource)
+    }
+}
+impl From<resources::CarePlan> for QuestionnaireResponseBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> QuestionnaireResponseBasedOnSearchTarget {
+        QuestionnaireResponseBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+/// The item-subject reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponseItemSubjectSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponseItemSubjectSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = resources::Resource;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::ItemSubject
+    }
+}
+/// The part-of reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponsePartOfSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponsePartOfSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = QuestionnaireResponsePartOfSearchTarget;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::PartOf
+    }
+}
+/// Target resources of the part-of reference search parameter of the QuestionnaireResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum QuestionnaireResponsePartOfSearchTarget {
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+}
+impl From<resources::Procedure> for QuestionnaireResponsePartOfSearchTarget {
+    fn from(resource: resources::Procedure) -> QuestionnaireResponsePartOfSearchTarget {
+        QuestionnaireResponsePartOfSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::Observation> for QuestionnaireResponsePartOfSearchTarget {
+    fn from(resource: resources::Observation) -> QuestionnaireResponsePartOfSearchTarget {
+        QuestionnaireResponsePartOfSearchTarget::Observation(resource)
+    }
+}
+/// The questionnaire reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponseQuestionnaireSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponseQuestionnaireSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = resources::Questionnaire;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::Questionnaire
+    }
+}
+/// The source reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponseSourceSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponseSourceSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = QuestionnaireResponseSourceSearchTarget;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::Source
+    }
+}
+/// Target resources of the source reference search parameter of the QuestionnaireResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum QuestionnaireResponseSourceSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Device> for QuestionnaireResponseSourceSearchTarget {
+    fn from(resource: resources::Device) -> QuestionnaireResponseSourceSearchTarget {
+        QuestionnaireResponseSourceSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for QuestionnaireResponseSourceSearchTarget {
+    fn from(resource: resources::Organization) -> QuestionnaireResponseSourceSearchTarget {
+        QuestionnaireResponseSourceSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for QuestionnaireResponseSourceSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> QuestionnaireResponseSourceSearchTarget {
+        QuestionnaireResponseSourceSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for QuestionnaireResponseSourceSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> QuestionnaireResponseSourceSearchTarget {
+        QuestionnaireResponseSourceSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for QuestionnaireResponseSourceSearchTarget {
+    fn from(resource: resources::Practitioner) -> QuestionnaireResponseSourceSearchTarget {
+        QuestionnaireResponseSourceSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for QuestionnaireResponseSourceSearchTarget {
+    fn from(resource: resources::Patient) -> QuestionnaireResponseSourceSearchTarget {
+        QuestionnaireResponseSourceSearchTarget::Patient(resource)
+    }
+}
+/// The subject reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponseSubjectSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponseSubjectSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = resources::Resource;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::Subject
+    }
+}
+/// The holder reference search parameter of the RegulatedAuthorization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RegulatedAuthorizationHolderSearchReference;
+impl ReferenceSearchParameter for RegulatedAuthorizationHolderSearchReference {
+    type Resource = resources::RegulatedAuthorization;
+    type Target = resources::Organization;
+    fn parameter(&self) -> RegulatedAuthorizationSearchParameter {
+        RegulatedAuthorizationSearchParameter::Holder
+    }
+}
+/// The subject reference search parameter of the RegulatedAuthorization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RegulatedAuthorizationSubjectSearchReference;
+impl ReferenceSearchParameter for RegulatedAuthorizationSubjectSearchReference {
+    type Resource = resources::RegulatedAuthorization;
+    type Target = RegulatedAuthorizationSubjectSearchTarget;
+    fn parameter(&self) -> RegulatedAuthorizationSearchParameter {
+        RegulatedAuthorizationSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the RegulatedAuthorization resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RegulatedAuthorizationSubjectSearchTarget {
+    /// Variant for Ingredient target resources
+    Ingredient(resources::Ingredient),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for ObservationDefinition target resources
+    ObservationDefinition(resources::ObservationDefinition),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for BiologicallyDerivedProduct target resources
+    BiologicallyDerivedProduct(resources::BiologicallyDerivedProduct),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for PackagedProductDefinition target resources
+    PackagedProductDefinition(resources::PackagedProductDefinition),
+    /// Variant for SubstanceDefinition target resources
+    SubstanceDefinition(resources::SubstanceDefinition),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for MedicinalProductDefinition target resources
+    MedicinalProductDefinition(resources::MedicinalProductDefinition),
+    /// Variant for NutritionProduct target resources
+    NutritionProduct(resources::NutritionProduct),
+    /// Variant for DeviceDefinition target resources
+    DeviceDefinition(resources::DeviceDefinition),
+    /// Variant for ResearchStudy target resources
+    ResearchStudy(resources::ResearchStudy),
+    /// Variant for ManufacturedItemDefinition target resources
+    ManufacturedItemDefinition(resources::ManufacturedItemDefinition),
+}
+impl From<resources::Ingredient> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::Ingredient) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::Ingredient(resource)
+    }
+}
+impl From<resources::Organization> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::ObservationDefinition> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::ObservationDefinition) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::ObservationDefinition(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::ActivityDefinition(resource)
+    }
+}
+impl From<resources::PlanDefinition> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::BiologicallyDerivedProduct> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::BiologicallyDerivedProduct) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::BiologicallyDerivedProduct(resource)
+    }
+}
+impl From<resources::Practitioner> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::PackagedProductDefinition> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::PackagedProductDefinition) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::PackagedProductDefinition(resource)
+    }
+}
+impl From<resources::SubstanceDefinition> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::SubstanceDefinition) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::SubstanceDefinition(resource)
+    }
+}
+impl From<resources::Location> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::Location) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::Location(resource)
+    }
+}
+impl From<resources::MedicinalProductDefinition> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::MedicinalProductDefinition) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::MedicinalProductDefinition(resource)
+    }
+}
+impl From<resources::NutritionProduct> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::NutritionProduct) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::NutritionProduct(resource)
+    }
+}
+impl From<resources::DeviceDefinition> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::DeviceDefinition) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::DeviceDefinition(resource)
+    }
+}
+impl From<resources::ResearchStudy> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::ResearchStudy) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::ResearchStudy(resource)
+    }
+}
+impl From<resources::ManufacturedItemDefinition> for RegulatedAuthorizationSubjectSearchTarget {
+    fn from(resource: resources::ManufacturedItemDefinition) -> RegulatedAuthorizationSubjectSearchTarget {
+        RegulatedAuthorizationSubjectSearchTarget::ManufacturedItemDefinition(resource)
+    }
+}
+/// The patient reference search parameter of the RelatedPerson resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RelatedPersonPatientSearchReference;
+impl ReferenceSearchParameter for RelatedPersonPatientSearchReference {
+    type Resource = resources::RelatedPerson;
+    type Target = resources::Patient;
+    fn parameter(&self) -> RelatedPersonSearchParameter {
+        RelatedPersonSearchParameter::Patient
+    }
+}
+/// The patient reference search parameter of the RequestOrchestration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestOrchestrationPatientSearchReference;
+impl ReferenceSearchParameter for RequestOrchestrationPatientSearchReference {
+    type Resource = resources::RequestOrchestration;
+    type Target = resources::Patient;
+    fn parameter(&self) -> RequestOrchestrationSearchParameter {
+        RequestOrchestrationSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the RequestOrchestration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestOrchestrationEncounterSearchReference;
+impl ReferenceSearchParameter for RequestOrchestrationEncounterSearchReference {
+    type Resource = resources::RequestOrchestration;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> RequestOrchestrationSearchParameter {
+        RequestOrchestrationSearchParameter::Encounter
+    }
+}
+/// The author reference search parameter of the RequestOrchestration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestOrchestrationAuthorSearchReference;
+impl ReferenceSearchParameter for RequestOrchestrationAuthorSearchReference {
+    type Resource = resources::RequestOrchestration;
+    type Target = RequestOrchestrationAuthorSearchTarget;
+    fn parameter(&self) -> RequestOrchestrationSearchParameter {
+        RequestOrchestrationSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the RequestOrchestration resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RequestOrchestrationAuthorSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Device> for RequestOrchestrationAuthorSearchTarget {
+    fn from(resource: resources::Device) -> RequestOrchestrationAuthorSearchTarget {
+        RequestOrchestrationAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::PractitionerRole> for RequestOrchestrationAuthorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> RequestOrchestrationAuthorSearchTarget {
+        RequestOrchestrationAuthorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for RequestOrchestrationAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> RequestOrchestrationAuthorSearchTarget {
+        RequestOrchestrationAuthorSearchTarget::Practitioner(resource)
+    }
+}
+/// The based-on reference search parameter of the RequestOrchestration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestOrchestrationBasedOnSearchReference;
+impl ReferenceSearchParameter for RequestOrchestrationBasedOnSearchReference {
+    type Resource = resources::RequestOrchestration;
+    type Target = resources::Resource;
+    fn parameter(&self) -> RequestOrchestrationSearchParameter {
+        RequestOrchestrationSearchParameter::BasedOn
+    }
+}
+/// The instantiates-canonical reference search parameter of the RequestOrchestration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestOrchestrationInstantiatesCanonicalSearchReference;
+impl ReferenceSearchParameter for RequestOrchestrationInstantiatesCanonicalSearchReference {
+    type Resource = resources::RequestOrchestration;
+    type Target = RequestOrchestrationInstantiatesCanonicalSearchTarget;
+    fn parameter(&self) -> RequestOrchestrationSearchParameter {
+        RequestOrchestrationSearchParameter::InstantiatesCanonical
+    }
+}
+/// Target resources of the instantiates-canonical reference search parameter of the RequestOrchestration resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+    /// Variant for ActorDefinition target resources
+    ActorDefinition(resources::ActorDefinition),
+    /// Variant for CapabilityStatement target resources
+    CapabilityStatement(resources::CapabilityStatement),
+    /// Variant for ChargeItemDefinition target resources
+    ChargeItemDefinition(resources::ChargeItemDefinition),
+    /// Variant for Citation target resources
+    Citation(resources::Citation),
+    /// Variant for CodeSystem target resources
+    CodeSystem(resources::CodeSystem),
+    /// Variant for CompartmentDefinition target resources
+    CompartmentDefinition(resources::CompartmentDefinition),
+    /// Variant for Composition target resources
+    Composition(resources::Composition),
+    /// Variant for ConceptMap target resources
+    ConceptMap(resources::ConceptMap),
+    /// Variant for ConditionDefinition target resources
+    ConditionDefinition(resources::ConditionDefinition),
+    /// Variant for Contract target resources
+    Contract(resources::Contract),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for EventDefinition target resources
+    EventDefinition(resources::EventDefinition),
+    /// Variant for Evidence target resources
+    Evidence(resources::Evidence),
+    /// Variant for EvidenceReport target resources
+    EvidenceReport(resources::EvidenceReport),
+    /// Variant for EvidenceVariable target resources
+    EvidenceVariable(resources::EvidenceVariable),
+    /// Variant for ExampleScenario target resources
+    ExampleScenario(resources::ExampleScenario),
+    /// Variant for GraphDefinition target resources
+    GraphDefinition(resources::GraphDefinition),
+    /// Variant for ImplementationGuide target resources
+    ImplementationGuide(resources::ImplementationGuide),
+    /// Variant for Library target resources
+    Library(resources::Library),
+    /// Variant for Measure target resources
+    Measure(resources::Measure),
+    /// Variant for MessageDefinition target resources
+    MessageDefinition(resources::MessageDefinition),
+    /// Variant for NamingSystem target resources
+    NamingSystem(resources::NamingSystem),
+    /// Variant for ObservationDefinition target resources
+    ObservationDefinition(resources::ObservationDefinition),
+    /// Variant for OperationDefinition target resources
+    OperationDefinition(resources::OperationDefinition),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for Questionnaire target resources
+    Questionnaire(resources::Questionnaire),
+    /// Variant for Requirements target resources
+    Requirements(resources::Requirements),
+    /// Variant for ResearchStudy target resources
+    ResearchStudy(resources::ResearchStudy),
+    /// Variant for SearchParameter target resources
+    SearchParameter(resources::SearchParameter),
+    /// Variant for SpecimenDefinition target resources
+    SpecimenDefinition(resources::SpecimenDefinition),
+    /// Variant for StructureDefinition target resources
+    StructureDefinition(resources::StructureDefinition),
+    /// Variant for StructureMap target resources
+    StructureMap(resources::StructureMap),
+    /// Variant for SubscriptionTopic target resources
+    SubscriptionTopic(resources::SubscriptionTopic),
+    /// Variant for TerminologyCapabilities target resources
+    TerminologyCapabilities(resources::TerminologyCapabilities),
+    /// Variant for TestPlan target resources
+    TestPlan(resources::TestPlan),
+    /// Variant for TestScript target resources
+    TestScript(resources::TestScript),
+    /// Variant for ValueSet target resources
+    ValueSet(resources::ValueSet),
+}
+impl From<resources::ActivityDefinition> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::ActivityDefinition(resource)
+    }
+}
+impl From<resources::ActorDefinition> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ActorDefinition) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::ActorDefinition(resource)
+    }
+}
+impl From<resources::CapabilityStatement> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::CapabilityStatement) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::CapabilityStatement(resource)
+    }
+}
+impl From<resources::ChargeItemDefinition> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ChargeItemDefinition) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::ChargeItemDefinition(resource)
+    }
+}
+impl From<resources::Citation> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Citation) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::Citation(resource)
+    }
+}
+impl From<resources::CodeSystem> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::CodeSystem) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::CodeSystem(resource)
+    }
+}
+impl From<resources::CompartmentDefinition> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::CompartmentDefinition) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::CompartmentDefinition(resource)
+    }
+}
+impl From<resources::Composition> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Composition) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::Composition(resource)
+    }
+}
+impl From<resources::ConceptMap> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ConceptMap) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::ConceptMap(resource)
+    }
+}
+impl From<resources::ConditionDefinition> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ConditionDefinition) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::ConditionDefinition(resource)
+    }
+}
+impl From<resources::Contract> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Contract) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::Contract(resource)
+    }
+}
+impl From<resources::Device> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Device) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::Device(resource)
+    }
+}
+impl From<resources::EventDefinition> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::EventDefinition) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::EventDefinition(resource)
+    }
+}
+impl From<resources::Evidence> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Evidence) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::Evidence(resource)
+    }
+}
+impl From<resources::EvidenceReport> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::EvidenceReport) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::EvidenceReport(resource)
+    }
+}
+impl From<resources::EvidenceVariable> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::EvidenceVariable) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::EvidenceVariable(resource)
+    }
+}
+impl From<resources::ExampleScenario> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ExampleScenario) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::ExampleScenario(resource)
+    }
+}
+impl From<resources::GraphDefinition> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::GraphDefinition) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::GraphDefinition(resource)
+    }
+}
+impl From<resources::ImplementationGuide> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ImplementationGuide) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::ImplementationGuide(resource)
+    }
+}
+impl From<resources::Library> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Library) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::Library(resource)
+    }
+}
+impl From<resources::Measure> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Measure) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::Measure(resource)
+    }
+}
+impl From<resources::MessageDefinition> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::MessageDefinition) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::MessageDefinition(resource)
+    }
+}
+impl From<resources::NamingSystem> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::NamingSystem) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::NamingSystem(resource)
+    }
+}
+impl From<resources::ObservationDefinition> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ObservationDefinition) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::ObservationDefinition(resource)
+    }
+}
+impl From<resources::OperationDefinition> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::OperationDefinition) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::OperationDefinition(resource)
+    }
+}
+impl From<resources::PlanDefinition> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::Questionnaire> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Questionnaire) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::Questionnaire(resource)
+    }
+}
+impl From<resources::Requirements> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::Requirements) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::Requirements(resource)
+    }
+}
+impl From<resources::ResearchStudy> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ResearchStudy) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::ResearchStudy(resource)
+    }
+}
+impl From<resources::SearchParameter> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::SearchParameter) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::SearchParameter(resource)
+    }
+}
+impl From<resources::SpecimenDefinition> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::SpecimenDefinition) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::SpecimenDefinition(resource)
+    }
+}
+impl From<resources::StructureDefinition> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::StructureDefinition) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::StructureDefinition(resource)
+    }
+}
+impl From<resources::StructureMap> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::StructureMap) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::StructureMap(resource)
+    }
+}
+impl From<resources::SubscriptionTopic> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::SubscriptionTopic) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::SubscriptionTopic(resource)
+    }
+}
+impl From<resources::TerminologyCapabilities> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::TerminologyCapabilities) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::TerminologyCapabilities(resource)
+    }
+}
+impl From<resources::TestPlan> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::TestPlan) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::TestPlan(resource)
+    }
+}
+impl From<resources::TestScript> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::TestScript) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::TestScript(resource)
+    }
+}
+impl From<resources::ValueSet> for RequestOrchestrationInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ValueSet) -> RequestOrchestrationInstantiatesCanonicalSearchTarget {
+        RequestOrchestrationInstantiatesCanonicalSearchTarget::ValueSet(resource)
+    }
+}
+/// The participant reference search parameter of the RequestOrchestration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestOrchestrationParticipantSearchReference;
+impl ReferenceSearchParameter for RequestOrchestrationParticipantSearchReference {
+    type Resource = resources::RequestOrchestration;
+    type Target = RequestOrchestrationParticipantSearchTarget;
+    fn parameter(&self) -> RequestOrchestrationSearchParameter {
+        RequestOrchestrationSearchParameter::Participant
+    }
+}
+/// Target resources of the participant reference search parameter of the RequestOrchestration resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RequestOrchestrationParticipantSearchTarget {
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for CapabilityStatement target resources
+    CapabilityStatement(resources::CapabilityStatement),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for Endpoint target resources
+    Endpoint(resources::Endpoint),
+    /// Variant for DeviceDefinition target resources
+    DeviceDefinition(resources::DeviceDefinition),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::CareTeam> for RequestOrchestrationParticipantSearchTarget {
+    fn from(resource: resources::CareTeam) -> RequestOrchestrationParticipantSearchTarget {
+        RequestOrchestrationParticipantSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for RequestOrchestrationParticipantSearchTarget {
+    fn from(resource: resources::Device) -> RequestOrchestrationParticipantSearchTarget {
+        RequestOrchestrationParticipantSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for RequestOrchestrationParticipantSearchTarget {
+    fn from(resource: resources::Organization) -> RequestOrchestrationParticipantSearchTarget {
+        RequestOrchestrationParticipantSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for RequestOrchestrationParticipantSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> RequestOrchestrationParticipantSearchTarget {
+        RequestOrchestrationParticipantSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::Group> for RequestOrchestrationParticipantSearchTarget {
+    fn from(resource: resources::Group) -> RequestOrchestrationParticipantSearchTarget {
+        RequestOrchestrationParticipantSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Practitioner> for RequestOrchestrationParticipantSearchTarget {
+    fn from(resource: resources::Practitioner) -> RequestOrchestrationParticipantSearchTarget {
+        RequestOrchestrationParticipantSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::CapabilityStatement> for RequestOrchestrationParticipantSearchTarget {
+    fn from(resource: resources::CapabilityStatement) -> RequestOrchestrationParticipantSearchTarget {
+        RequestOrchestrationParticipantSearchTarget::CapabilityStatement(resource)
+    }
+}
+impl From<resources::Location> for RequestOrchestrationParticipantSearchTarget {
+    fn from(resource: resources::Location) -> RequestOrchestrationParticipantSearchTarget {
+        RequestOrchestrationParticipantSearchTarget::Location(resource)
+    }
+}
+impl From<resources::HealthcareService> for RequestOrchestrationParticipantSearchTarget {
+    fn from(resource: resources::HealthcareService) -> RequestOrchestrationParticipantSearchTarget {
+        RequestOrchestrationParticipantSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::Endpoint> for RequestOrchestrationParticipantSearchTarget {
+    fn from(resource: resources::Endpoint) -> RequestOrchestrationParticipantSearchTarget {
+        RequestOrchestrationParticipantSearchTarget::Endpoint(resource)
+    }
+}
+impl From<resources::DeviceDefinition> for RequestOrchestrationParticipantSearchTarget {
+    fn from(resource: resources::DeviceDefinition) -> RequestOrchestrationParticipantSearchTarget {
+        RequestOrchestrationParticipantSearchTarget::DeviceDefinition(resource)
+    }
+}
+impl From<resources::PractitionerRole> for RequestOrchestrationParticipantSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> RequestOrchestrationParticipantSearchTarget {
+        RequestOrchestrationParticipantSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Patient> for RequestOrchestrationParticipantSearchTarget {
+    fn from(resource: resources::Patient) -> RequestOrchestrationParticipantSearchTarget {
+        RequestOrchestrationParticipantSearchTarget::Patient(resource)
+    }
+}
+/// The subject reference search parameter of the RequestOrchestration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestOrchestrationSubjectSearchReference;
+impl ReferenceSearchParameter for RequestOrchestrationSubjectSearchReference {
+    type Resource = resources::RequestOrchestration;
+    type Target = RequestOrchestrationSubjectSearchTarget;
+    fn parameter(&self) -> RequestOrchestrationSearchParameter {
+        RequestOrchestrationSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the RequestOrchestration resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RequestOrchestrationSubjectSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for RequestOrchestrationSubjectSearchTarget {
+    fn from(resource: resources::HealthcareService) -> RequestOrchestrationSubjectSearchTarget {
+        RequestOrchestrationSubjectSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::CareTeam> for RequestOrchestrationSubjectSearchTarget {
+    fn from(resource: resources::CareTeam) -> RequestOrchestrationSubjectSearchTarget {
+        RequestOrchestrationSubjectSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for RequestOrchestrationSubjectSearchTarget {
+    fn from(resource: resources::Device) -> RequestOrchestrationSubjectSearchTarget {
+        RequestOrchestrationSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for RequestOrchestrationSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> RequestOrchestrationSubjectSearchTarget {
+        RequestOrchestrationSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for RequestOrchestrationSubjectSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> RequestOrchestrationSubjectSearchTarget {
+        RequestOrchestrationSubjectSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for RequestOrchestrationSubjectSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> RequestOrchestrationSubjectSearchTarget {
+        RequestOrchestrationSubjectSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Group> for RequestOrchestrationSubjectSearchTarget {
+    fn from(resource: resources::Group) -> RequestOrchestrationSubjectSearchTarget {
+        RequestOrchestrationSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Practitioner> for RequestOrchestrationSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> RequestOrchestrationSubjectSearchTarget {
+        RequestOrchestrationSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Location> for RequestOrchestrationSubjectSearchTarget {
+    fn from(resource: resources::Location) -> RequestOrchestrationSubjectSearchTarget {
+        RequestOrchestrationSubjectSearchTarget::Location(resource)
+    }
+}
+impl From<resources::Patient> for RequestOrchestrationSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> RequestOrchestrationSubjectSearchTarget {
+        RequestOrchestrationSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The actor reference search parameter of the Requirements resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequirementsActorSearchReference;
+impl ReferenceSearchParameter for RequirementsActorSearchReference {
+    type Resource = resources::Requirements;
+    type Target = resources::ActorDefinition;
+    fn parameter(&self) -> RequirementsSearchParameter {
+        RequirementsSearchParameter::Actor
+    }
+}
+/// The derived-from reference search parameter of the Requirements resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequirementsDerivedFromSearchReference;
+impl ReferenceSearchParameter for RequirementsDerivedFromSearchReference {
+    type Resource = resources::Requirements;
+    type Target = resources::Requirements;
+    fn parameter(&self) -> RequirementsSearchParameter {
+        RequirementsSearchParameter::DerivedFrom
+    }
+}
+/// The eligibility reference search parameter of the ResearchStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchStudyEligibilitySearchReference;
+impl ReferenceSearchParameter for ResearchStudyEligibilitySearchReference {
+    type Resource = resources::ResearchStudy;
+    type Target = ResearchStudyEligibilitySearchTarget;
+    fn parameter(&self) -> ResearchStudySearchParameter {
+        ResearchStudySearchParameter::Eligibility
+    }
+}
+/// Target resources of the eligibility reference search parameter of the ResearchStudy resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ResearchStudyEligibilitySearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for EvidenceVariable target resources
+    EvidenceVariable(resources::EvidenceVariable),
+}
+impl From<resources::Group> for ResearchStudyEligibilitySearchTarget {
+    fn from(resource: resources::Group) -> ResearchStudyEligibilitySearchTarget {
+        ResearchStudyEligibilitySearchTarget::Group(resource)
+    }
+}
+impl From<resources::EvidenceVariable> for ResearchStudyEligibilitySearchTarget {
+    fn from(resource: resources::EvidenceVariable) -> ResearchStudyEligibilitySearchTarget {
+        ResearchStudyEligibilitySearchTarget::EvidenceVariable(resource)
+    }
+}
+/// The focus-reference reference search parameter of the ResearchStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchStudyFocusReferenceSearchReference;
+impl ReferenceSearchParameter for ResearchStudyFocusReferenceSearchReference {
+    type Resource = resources::ResearchStudy;
+    type Target = ResearchStudyFocusReferenceSearchTarget;
+    fn parameter(&self) -> ResearchStudySearchParameter {
+        ResearchStudySearchParameter::FocusReference
+    }
+}
+/// Target resources of the focus-reference reference search parameter of the ResearchStudy resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ResearchStudyFocusReferenceSearchTarget {
+    /// Variant for MedicinalProductDefinition target resources
+    MedicinalProductDefinition(resources::MedicinalProductDefinition),
+    /// Variant for EvidenceVariable target resources
+    EvidenceVariable(resources::EvidenceVariable),
+    /// Variant for SubstanceDefinition target resources
+    SubstanceDefinition(resources::SubstanceDefinition),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+}
+impl From<resources::MedicinalProductDefinition> for ResearchStudyFocusReferenceSearchTarget {
+    fn from(resource: resources::MedicinalProductDefinition) -> ResearchStudyFocusReferenceSearchTarget {
+        ResearchStudyFocusReferenceSearchTarget::MedicinalProductDefinition(resource)
+    }
+}
+impl From<resources::EvidenceVariable> for ResearchStudyFocusReferenceSearchTarget {
+    fn from(resource: resources::EvidenceVariable) -> ResearchStudyFocusReferenceSearchTarget {
+        ResearchStudyFocusReferenceSearchTarget::EvidenceVariable(resource)
+    }
+}
+impl From<resources::SubstanceDefinition> for ResearchStudyFocusReferenceSearchTarget {
+    fn from(resource: resources::SubstanceDefinition) -> ResearchStudyFocusReferenceSearchTarget {
+        ResearchStudyFocusReferenceSearchTarget::SubstanceDefinition(resource)
+    }
+}
+impl From<resources::Medication> for ResearchStudyFocusReferenceSearchTarget {
+    fn from(resource: resources::Medication) -> ResearchStudyFocusReferenceSearchTarget {
+        ResearchStudyFocusReferenceSearchTarget::Medication(resource)
+    }
+}
+/// The part-of reference search parameter of the ResearchStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchStudyPartOfSearchReference;
+impl ReferenceSearchParameter for ResearchStudyPartOfSearchReference {
+    type Resource = resources::ResearchStudy;
+    type Target = resources::ResearchStudy;
+    fn parameter(&self) -> ResearchStudySearchParameter {
+        ResearchStudySearchParameter::PartOf
+    }
+}
+/// The protocol reference search parameter of the ResearchStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchStudyProtocolSearchReference;
+impl ReferenceSearchParameter for ResearchStudyProtocolSearchReference {
+    type Resource = resources::ResearchStudy;
+    type Target = resources::PlanDefinition;
+    fn parameter(&self) -> ResearchStudySearchParameter {
+        ResearchStudySearchParameter::Protocol
+    }
+}
+/// The site reference search parameter of the ResearchStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchStudySiteSearchReference;
+impl ReferenceSearchParameter for ResearchStudySiteSearchReference {
+    type Resource = resources::ResearchStudy;
+    type Target = ResearchStudySiteSearchTarget;
+    fn parameter(&self) -> ResearchStudySearchParameter {
+        ResearchStudySearchParameter::Site
+    }
+}
+/// Target resources of the site reference search parameter of the ResearchStudy resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ResearchStudySiteSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for ResearchStudy target resources
+    ResearchStudy(resources::ResearchStudy),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Organization> for ResearchStudySiteSearchTarget {
+    fn from(resource: resources::Organization) -> ResearchStudySiteSearchTarget {
+        ResearchStudySiteSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::ResearchStudy> for ResearchStudySiteSearchTarget {
+    fn from(resource: resources::ResearchStudy) -> ResearchStudySiteSearchTarget {
+        ResearchStudySiteSearchTarget::ResearchStudy(resource)
+    }
+}
+impl From<resources::Location> for ResearchStudySiteSearchTarget {
+    fn from(resource: resources::Location) -> ResearchStudySiteSearchTarget {
+        ResearchStudySiteSearchTarget::Location(resource)
+    }
+}
+/// The patient reference search parameter of the ResearchSubject resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchSubjectPatientSearchReference;
+impl ReferenceSearchParameter for ResearchSubjectPatientSearchReference {
+    type Resource = resources::ResearchSubject;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ResearchSubjectSearchParameter {
+        ResearchSubjectSearchParameter::Patient
+    }
+}
+/// The study reference search parameter of the ResearchSubject resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchSubjectStudySearchReference;
+impl ReferenceSearchParameter for ResearchSubjectStudySearchReference {
+    type Resource = resources::ResearchSubject;
+    type Target = resources::ResearchStudy;
+    fn parameter(&self) -> ResearchSubjectSearchParameter {
+        ResearchSubjectSearchParameter::Study
+    }
+}
+/// The subject reference search parameter of the ResearchSubject resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchSubjectSubjectSearchReference;
+impl ReferenceSearchParameter for ResearchSubjectSubjectSearchReference {
+    type Resource = resources::ResearchSubject;
+    type Target = ResearchSubjectSubjectSearchTarget;
+    fn parameter(&self) -> ResearchSubjectSearchParameter {
+        ResearchSubjectSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the ResearchSubject resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ResearchSubjectSubjectSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for BiologicallyDerivedProduct target resources
+    BiologicallyDerivedProduct(resources::BiologicallyDerivedProduct),
+    /// Variant for Specimen target resources
+    Specimen(resources::Specimen),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+}
+impl From<resources::Device> for ResearchSubjectSubjectSearchTarget {
+    fn from(resource: resources::Device) -> ResearchSubjectSubjectSearchTarget {
+        ResearchSubjectSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Group> for ResearchSubjectSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ResearchSubjectSubjectSearchTarget {
+        ResearchSubjectSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::BiologicallyDerivedProduct> for ResearchSubjectSubjectSearchTarget {
+    fn from(resource: resources::BiologicallyDerivedProduct) -> ResearchSubjectSubjectSearchTarget {
+        ResearchSubjectSubjectSearchTarget::BiologicallyDerivedProduct(resource)
+    }
+}
+impl From<resources::Specimen> for ResearchSubjectSubjectSearchTarget {
+    fn from(resource: resources::Specimen) -> ResearchSubjectSubjectSearchTarget {
+        ResearchSubjectSubjectSearchTarget::Specimen(resource)
+    }
+}
+impl From<resources::Substance> for ResearchSubjectSubjectSearchTarget {
+    fn from(resource: resources::Substance) -> ResearchSubjectSubjectSearchTarget {
+        ResearchSubjectSubjectSearchTarget::Substance(resource)
+    }
+}
+impl From<resources::Patient> for ResearchSubjectSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ResearchSubjectSubjectSearchTarget {
+        ResearchSubjectSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Medication> for ResearchSubjectSubjectSearchTarget {
+    fn from(resource: resources::Medication) -> ResearchSubjectSubjectSearchTarget {
+        ResearchSubjectSubjectSearchTarget::Medication(resource)
+    }
+}
+/// The patient reference search parameter of the RiskAssessment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RiskAssessmentPatientSearchReference;
+impl ReferenceSearchParameter for RiskAssessmentPatientSearchReference {
+    type Resource = resources::RiskAssessment;
+    type Target = resources::Patient;
+    fn parameter(&self) -> RiskAssessmentSearchParameter {
+        RiskAssessmentSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the RiskAssessment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RiskAssessmentEncounterSearchReference;
+impl ReferenceSearchParameter for RiskAssessmentEncounterSearchReference {
+    type Resource = resources::RiskAssessment;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> RiskAssessmentSearchParameter {
+        RiskAssessmentSearchParameter::Encounter
+    }
+}
+/// The condition reference search parameter of the RiskAssessment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RiskAssessmentConditionSearchReference;
+impl ReferenceSearchParameter for RiskAssessmentConditionSearchReference {
+    type Resource = resources::RiskAssessment;
+    type Target = resources::Condition;
+    fn parameter(&self) -> RiskAssessmentSearchParameter {
+        RiskAssessmentSearchParameter::Condition
+    }
+}
+/// The performer reference search parameter of the RiskAssessment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RiskAssessmentPerformerSearchReference;
+impl ReferenceSearchParameter for RiskAssessmentPerformerSearchReference {
+    type Resource = resources::RiskAssessment;
+    type Target = RiskAssessmentPerformerSearchTarget;
+    fn parameter(&self) -> RiskAssessmentSearchParameter {
+        RiskAssessmentSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the RiskAssessment resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RiskAssessmentPerformerSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Device> for RiskAssessmentPerformerSearchTarget {
+    fn from(resource: resources::Device) -> RiskAssessmentPerformerSearchTarget {
+        RiskAssessmentPerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::RelatedPerson> for RiskAssessmentPerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> RiskAssessmentPerformerSearchTarget {
+        RiskAssessmentPerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for RiskAssessmentPerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> RiskAssessmentPerformerSearchTarget {
+        RiskAssessmentPerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for RiskAssessmentPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> RiskAssessmentPerformerSearchTarget {
+        RiskAssessmentPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for RiskAssessmentPerformerSearchTarget {
+    fn from(resource: resources::Patient) -> RiskAssessmentPerformerSearchTarget {
+        RiskAssessmentPerformerSearchTarget::Patient(resource)
+    }
+}
+/// The subject reference search parameter of the RiskAssessment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RiskAssessmentSubjectSearchReference;
+impl ReferenceSearchParameter for RiskAssessmentSubjectSearchReference {
+    type Resource = resources::RiskAssessment;
+    type Target = RiskAssessmentSubjectSearchTarget;
+    fn parameter(&self) -> RiskAssessmentSearchParameter {
+        RiskAssessmentSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the RiskAssessment resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RiskAssessmentSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for RiskAssessmentSubjectSearchTarget {
+    fn from(resource: resources::Group) -> RiskAssessmentSubjectSearchTarget {
+        RiskAssessmentSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for RiskAssessmentSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> RiskAssessmentSubjectSearchTarget {
+        RiskAssessmentSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The actor reference search parameter of the Schedule resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ScheduleActorSearchReference;
+impl ReferenceSearchParameter for ScheduleActorSearchReference {
+    type Resource = resources::Schedule;
+    type Target = ScheduleActorSearchTarget;
+    fn parameter(&self) -> ScheduleSearchParameter {
+        ScheduleSearchParameter::Actor
+    }
+}
+/// Target resources of the actor reference search parameter of the Schedule resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ScheduleActorSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for ScheduleActorSearchTarget {
+    fn from(resource: resources::HealthcareService) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::CareTeam> for ScheduleActorSearchTarget {
+    fn from(resource: resources::CareTeam) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for ScheduleActorSearchTarget {
+    fn from(resource: resources::Device) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ScheduleActorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ScheduleActorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ScheduleActorSearchTarget {
+    fn from(resource: resources::Practitioner) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Location> for ScheduleActorSearchTarget {
+    fn from(resource: resources::Location) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::Location(resource)
+    }
+}
+impl From<resources::Patient> for ScheduleActorSearchTarget {
+    fn from(resource: resources::Patient) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::Patient(resource)
+    }
+}
+/// The service-type-reference reference search parameter of the Schedule resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ScheduleServiceTypeReferenceSearchReference;
+impl ReferenceSearchParameter for ScheduleServiceTypeReferenceSearchReference {
+    type Resource = resources::Schedule;
+    type Target = resources::HealthcareService;
+    fn parameter(&self) -> ScheduleSearchParameter {
+        ScheduleSearchParameter::ServiceTypeReference
+    }
+}
+/// The component reference search parameter of the SearchParameter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SearchParameterComponentSearchReference;
+impl ReferenceSearchParameter for SearchParameterComponentSearchReference {
+    type Resource = resources::SearchParameter;
+    type Target = resources::SearchParameter;
+    fn parameter(&self) -> SearchParameterSearchParameter {
+        SearchParameterSearchParameter::Component
+    }
+}
+/// The derived-from reference search parameter of the SearchParameter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SearchParameterDerivedFromSearchReference;
+impl ReferenceSearchParameter for SearchParameterDerivedFromSearchReference {
+    type Resource = resources::SearchParameter;
+    type Target = resources::SearchParameter;
+    fn parameter(&self) -> SearchParameterSearchParameter {
+        SearchParameterSearchParameter::DerivedFrom
+    }
+}
+/// The patient reference search parameter of the ServiceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRequestPatientSearchReference;
+impl ReferenceSearchParameter for ServiceRequestPatientSearchReference {
+    type Resource = resources::ServiceRequest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ServiceRequestSearchParameter {
+        ServiceRequestSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the ServiceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRequestEncounterSearchReference;
+impl ReferenceSearchParameter for ServiceRequestEncounterSearchReference {
+    type Resource = resources::ServiceRequest;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ServiceRequestSearchParameter {
+        ServiceRequestSearchParameter::Encounter
+    }
+}
+/// The based-on reference search parameter of the ServiceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRequestBasedOnSearchReference;
+impl ReferenceSearchParameter for ServiceRequestBasedOnSearchReference {
+    type Resource = resources::ServiceRequest;
+    type Target = ServiceRequestBasedOnSearchTarget;
+    fn parameter(&self) -> ServiceRequestSearchParameter {
+        ServiceRequestSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the ServiceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ServiceRequestBasedOnSearchTarget {
+    /// Variant for ServiceRequest target resources
+    ServiceRequest(resources::ServiceRequest),
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for MedicationRequest target resources
+    MedicationRequest(resources::MedicationRequest),
+}
+impl From<resources::ServiceRequest> for ServiceRequestBasedOnSearchTarget {
+    fn from(resource: resources::ServiceRequest) -> ServiceRequestBasedOnSearchTarget {
+        ServiceRequestBasedOnSearchTarget::ServiceRequest(resource)
+    }
+}
+impl From<resources::CarePlan> for ServiceRequestBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> ServiceRequestBasedOnSearchTarget {
+        ServiceRequestBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::MedicationRequest> for ServiceRequestBasedOnSearchTarget {
+    fn from(resource: resources::MedicationRequest) -> ServiceRequestBasedOnSearchTarget {
+        ServiceRequestBasedOnSearchTarget::MedicationRequest(resource)
+    }
+}
+/// The body-structure reference search parameter of the ServiceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRequestBodyStructureSearchReference;
+impl ReferenceSearchParameter for ServiceRequestBodyStructureSearchReference {
+    type Resource = resources::ServiceRequest;
+    type Target = resources::BodyStructure;
+    fn parameter(&self) -> ServiceRequestSearchParameter {
+        ServiceRequestSearchParameter::BodyStructure
+    }
+}
+/// The code-reference reference search parameter of the ServiceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRequestCodeReferenceSearchReference;
+impl ReferenceSearchParameter for ServiceRequestCodeReferenceSearchReference {
+    type Resource = resources::ServiceRequest;
+    type Target = ServiceRequestCodeReferenceSearchTarget;
+    fn parameter(&self) -> ServiceRequestSearchParameter {
+        ServiceRequestSearchParameter::CodeReference
+    }
+}
+/// Target resources of the code-reference reference search parameter of the ServiceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ServiceRequestCodeReferenceSearchTarget {
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+}
+impl From<resources::ActivityDefinition> for ServiceRequestCodeReferenceSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> ServiceRequestCodeReferenceSearchTarget {
+        ServiceRequestCodeReferenceSearchTarget::ActivityDefinition(resource)
+    }
+}
+impl From<resources::PlanDefinition> for ServiceRequestCodeReferenceSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> ServiceRequestCodeReferenceSearchTarget {
+        ServiceRequestCodeReferenceSearchTarget::PlanDefinition(resource)
+    }
+}
+/// The instantiates-canonical reference search parameter of the ServiceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRequestInstantiatesCanonicalSearchReference;
+impl ReferenceSearchParameter for ServiceRequestInstantiatesCanonicalSearchReference {
+    type Resource = resources::ServiceRequest;
+    type Target = ServiceRequestInstantiatesCanonicalSearchTarget;
+    fn parameter(&self) -> ServiceRequestSearchParameter {
+        ServiceRequestSearchParameter::InstantiatesCanonical
+    }
+}
+/// Target resources of the instantiates-canonical reference search parameter of the ServiceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ServiceRequestInstantiatesCanonicalSearchTarget {
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+}
+impl From<resources::ActivityDefinition> for ServiceRequestInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> ServiceRequestInstantiatesCanonicalSearchTarget {
+        ServiceRequestInstantiatesCanonicalSearchTarget::ActivityDefinition(resource)
+    }
+}
+impl From<resources::PlanDefinition> for ServiceRequestInstantiatesCanonicalSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> ServiceRequestInstantiatesCanonicalSearchTarget {
+        ServiceRequestInstantiatesCanonicalSearchTarget::PlanDefinition(resource)
+    }
+}
+/// The performer reference search parameter of the ServiceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRequestPerformerSearchReference;
+impl ReferenceSearchParameter for ServiceRequestPerformerSearchReference {
+    type Resource = resources::ServiceRequest;
+    type Target = ServiceRequestPerformerSearchTarget;
+    fn parameter(&self) -> ServiceRequestSearchParameter {
+        ServiceRequestSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the ServiceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ServiceRequestPerformerSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for ServiceRequestPerformerSearchTarget {
+    fn from(resource: resources::HealthcareService) -> ServiceRequestPerformerSearchTarget {
+        ServiceRequestPerformerSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::Organization> for ServiceRequestPerformerSearchTarget {
+    fn from(resource: resources::Organization) -> ServiceRequestPerformerSearchTarget {
+        ServiceRequestPerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for ServiceRequestPerformerSearchTarget {
+    fn from(resource: resources::CareTeam) -> ServiceRequestPerformerSearchTarget {
+        ServiceRequestPerformerSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for ServiceRequestPerformerSearchTarget {
+    fn from(resource: resources::Device) -> ServiceRequestPerformerSearchTarget {
+        ServiceRequestPerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ServiceRequestPerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ServiceRequestPerformerSearchTarget {
+        ServiceRequestPerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ServiceRequestPerformerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ServiceRequestPerformerSearchTarget {
+        ServiceRequestPerformerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ServiceRequestPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ServiceRequestPerformerSearchTarget {
+        ServiceRequestPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ServiceRequestPerformerSearchTarget {
+    fn from(resource: resources::Patient) -> ServiceRequestPerformerSearchTarget {
+        ServiceRequestPerformerSearchTarget::Patient(resource)
+    }
+}
+/// The replaces reference search parameter of the ServiceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRequestReplacesSearchReference;
+impl ReferenceSearchParameter for ServiceRequestReplacesSearchReference {
+    type Resource = resources::ServiceRequest;
+    type Target = resources::ServiceRequest;
+    fn parameter(&self) -> ServiceRequestSearchParameter {
+        ServiceRequestSearchParameter::Replaces
+    }
+}
+/// The requester reference search parameter of the ServiceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRequestRequesterSearchReference;
+impl ReferenceSearchParameter for ServiceRequestRequesterSearchReference {
+    type Resource = resources::ServiceRequest;
+    type Target = ServiceRequestRequesterSearchTarget;
+    fn parameter(&self) -> ServiceRequestSearchParameter {
+        ServiceRequestSearchParameter::Requester
+    }
+}
+/// Target resources of the requester reference search parameter of the ServiceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ServiceRequestRequesterSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for ServiceRequestRequesterSearchTarget {
+    fn from(resource: resources::Organization) -> ServiceRequestRequesterSearchTarget {
+        ServiceRequestRequesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for ServiceRequestRequesterSearchTarget {
+    fn from(resource: resources::Device) -> ServiceRequestRequesterSearchTarget {
+        ServiceRequestRequesterSearchTarget::Device(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ServiceRequestRequesterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ServiceRequestRequesterSearchTarget {
+        ServiceRequestRequesterSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ServiceRequestRequesterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ServiceRequestRequesterSearchTarget {
+        ServiceRequestRequesterSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for ServiceRequestRequesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> ServiceRequestRequesterSearchTarget {
+        ServiceRequestRequesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ServiceRequestRequesterSearchTarget {
+    fn from(resource: resources::Patient) -> ServiceRequestRequesterSearchTarget {
+        ServiceRequestRequesterSearchTarget::Patient(resource)
+    }
+}
+/// The specimen reference search parameter of the ServiceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRequestSpecimenSearchReference;
+impl ReferenceSearchParameter for ServiceRequestSpecimenSearchReference {
+    type Resource = resources::ServiceRequest;
+    type Target = resources::Specimen;
+    fn parameter(&self) -> ServiceRequestSearchParameter {
+        ServiceRequestSearchParameter::Specimen
+    }
+}
+/// The subject reference search parameter of the ServiceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceRequestSubjectSearchReference;
+impl ReferenceSearchParameter for ServiceRequestSubjectSearchReference {
+    type Resource = resources::ServiceRequest;
+    type Target = ServiceRequestSubjectSearchTarget;
+    fn parameter(&self) -> ServiceRequestSearchParameter {
+        ServiceRequestSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the ServiceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ServiceRequestSubjectSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Device> for ServiceRequestSubjectSearchTarget {
+    fn from(resource: resources::Device) -> ServiceRequestSubjectSearchTarget {
+        ServiceRequestSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Group> for ServiceRequestSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ServiceRequestSubjectSearchTarget {
+        ServiceRequestSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Location> for ServiceRequestSubjectSearchTarget {
+    fn from(resource: resources::Location) -> ServiceRequestSubjectSearchTarget {
+        ServiceRequestSubjectSearchTarget::Location(resource)
+    }
+}
+impl From<resources::Patient> for ServiceRequestSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ServiceRequestSubjectSearchTarget {
+        ServiceRequestSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The schedule reference search parameter of the Slot resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SlotScheduleSearchReference;
+impl ReferenceSearchParameter for SlotScheduleSearchReference {
+    type Resource = resources::Slot;
+    type Target = resources::Schedule;
+    fn parameter(&self) -> SlotSearchParameter {
+        SlotSearchParameter::Schedule
+    }
+}
+/// The service-type-reference reference search parameter of the Slot resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SlotServiceTypeReferenceSearchReference;
+impl ReferenceSearchParameter for SlotServiceTypeReferenceSearchReference {
+    type Resource = resources::Slot;
+    type Target = resources::HealthcareService;
+    fn parameter(&self) -> SlotSearchParameter {
+        SlotSearchParameter::ServiceTypeReference
+    }
+}
+/// The patient reference search parameter of the Specimen resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpecimenPatientSearchReference;
+impl ReferenceSearchParameter for SpecimenPatientSearchReference {
+    type Resource = resources::Specimen;
+    type Target = resources::Patient;
+    fn parameter(&self) -> SpecimenSearchParameter {
+        SpecimenSearchParameter::Patient
+    }
+}
+/// The bodysite reference search parameter of the Specimen resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpecimenBodysiteSearchReference;
+impl ReferenceSearchParameter for SpecimenBodysiteSearchReference {
+    type Resource = resources::Specimen;
+    type Target = resources::BodyStructure;
+    fn parameter(&self) -> SpecimenSearchParameter {
+        SpecimenSearchParameter::Bodysite
+    }
+}
+/// The collector reference search parameter of the Specimen resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpecimenCollectorSearchReference;
+impl ReferenceSearchParameter for SpecimenCollectorSearchReference {
+    type Resource = resources::Specimen;
+    type Target = SpecimenCollectorSearchTarget;
+    fn parameter(&self) -> SpecimenSearchParameter {
+        SpecimenSearchParameter::Collector
+    }
+}
+/// Target resources of the collector reference search parameter of the Specimen resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SpecimenCollectorSearchTarget {
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::RelatedPerson> for SpecimenCollectorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> SpecimenCollectorSearchTarget {
+        SpecimenCollectorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for SpecimenCollectorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> SpecimenCollectorSearchTarget {
+        SpecimenCollectorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for SpecimenCollectorSearchTarget {
+    fn from(resource: resources::Practitioner) -> SpecimenCollectorSearchTarget {
+        SpecimenCollectorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for SpecimenCollectorSearchTarget {
+    fn from(resource: resources::Patient) -> SpecimenCollectorSearchTarget {
+        SpecimenCollectorSearchTarget::Patient(resource)
+    }
+}
+/// The container-device reference search parameter of the Specimen resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpecimenContainerDeviceSearchReference;
+impl ReferenceSearchParameter for SpecimenContainerDeviceSearchReference {
+    type Resource = resources::Specimen;
+    type Target = resources::Device;
+    fn parameter(&self) -> SpecimenSearchParameter {
+        SpecimenSearchParameter::ContainerDevice
+    }
+}
+/// The parent reference search parameter of the Specimen resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpecimenParentSearchReference;
+impl ReferenceSearchParameter for SpecimenParentSearchReference {
+    type Resource = resources::Specimen;
+    type Target = resources::Specimen;
+    fn parameter(&self) -> SpecimenSearchParameter {
+        SpecimenSearchParameter::Parent
+    }
+}
+/// The procedure reference search parameter of the Specimen resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpecimenProcedureSearchReference;
+impl ReferenceSearchParameter for SpecimenProcedureSearchReference {
+    type Resource = resources::Specimen;
+    type Target = resources::Procedure;
+    fn parameter(&self) -> SpecimenSearchParameter {
+        SpecimenSearchParameter::Procedure
+    }
+}
+/// The subject reference search parameter of the Specimen resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpecimenSubjectSearchReference;
+impl ReferenceSearchParameter for SpecimenSubjectSearchReference {
+    type Resource = resources::Specimen;
+    type Target = SpecimenSubjectSearchTarget;
+    fn parameter(&self) -> SpecimenSearchParameter {
+        SpecimenSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Specimen resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SpecimenSubjectSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for BiologicallyDerivedProduct target resources
+    BiologicallyDerivedProduct(resources::BiologicallyDerivedProduct),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Device> for SpecimenSubjectSearchTarget {
+    fn from(resource: resources::Device) -> SpecimenSubjectSearchTarget {
+        SpecimenSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Group> for SpecimenSubjectSearchTarget {
+    fn from(resource: resources::Group) -> SpecimenSubjectSearchTarget {
+        SpecimenSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::BiologicallyDerivedProduct> for SpecimenSubjectSearchTarget {
+    fn from(resource: resources::BiologicallyDerivedProduct) -> SpecimenSubjectSearchTarget {
+        SpecimenSubjectSearchTarget::BiologicallyDerivedProduct(resource)
+    }
+}
+impl From<resources::Substance> for SpecimenSubjectSearchTarget {
+    fn from(resource: resources::Substance) -> SpecimenSubjectSearchTarget {
+        SpecimenSubjectSearchTarget::Substance(resource)
+    }
+}
+impl From<resources::Location> for SpecimenSubjectSearchTarget {
+    fn from(resource: resources::Location) -> SpecimenSubjectSearchTarget {
+        SpecimenSubjectSearchTarget::Location(resource)
+    }
+}
+impl From<resources::Patient> for SpecimenSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> SpecimenSubjectSearchTarget {
+        SpecimenSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The base reference search parameter of the StructureDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StructureDefinitionBaseSearchReference;
+impl ReferenceSearchParameter for StructureDefinitionBaseSearchReference {
+    type Resource = resources::StructureDefinition;
+    type Target = resources::StructureDefinition;
+    fn parameter(&self) -> StructureDefinitionSearchParameter {
+        StructureDefinitionSearchParameter::Base
+    }
+}
+/// The valueset reference search parameter of the StructureDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StructureDefinitionValuesetSearchReference;
+impl ReferenceSearchParameter for StructureDefinitionValuesetSearchReference {
+    type Resource = resources::StructureDefinition;
+    type Target = resources::ValueSet;
+    fn parameter(&self) -> StructureDefinitionSearchParameter {
+        StructureDefinitionSearchParameter::Valueset
+    }
+}
+/// The owner reference search parameter of the Subscription resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SubscriptionOwnerSearchReference;
+impl ReferenceSearchParameter for SubscriptionOwnerSearchReference {
+    type Resource = resources::Subscription;
+    type Target = SubscriptionOwnerSearchTarget;
+    fn parameter(&self) -> SubscriptionSearchParameter {
+        SubscriptionSearchParameter::Owner
+    }
+}
+/// Target resources of the owner reference search parameter of the Subscription resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SubscriptionOwnerSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for SubscriptionOwnerSearchTarget {
+    fn from(resource: resources::HealthcareService) -> SubscriptionOwnerSearchTarget {
+        SubscriptionOwnerSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::CareTeam> for SubscriptionOwnerSearchTarget {
+    fn from(resource: resources::CareTeam) -> SubscriptionOwnerSearchTarget {
+        SubscriptionOwnerSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Organization> for SubscriptionOwnerSearchTarget {
+    fn from(resource: resources::Organization) -> SubscriptionOwnerSearchTarget {
+        SubscriptionOwnerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for SubscriptionOwnerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> SubscriptionOwnerSearchTarget {
+        SubscriptionOwnerSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for SubscriptionOwnerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> SubscriptionOwnerSearchTarget {
+        SubscriptionOwnerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for SubscriptionOwnerSearchTarget {
+    fn from(resource: resources::Practitioner) -> SubscriptionOwnerSearchTarget {
+        SubscriptionOwnerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for SubscriptionOwnerSearchTarget {
+    fn from(resource: resources::Patient) -> SubscriptionOwnerSearchTarget {
+        SubscriptionOwnerSearchTarget::Patient(resource)
+    }
+}
+/// The code-reference reference search parameter of the Substance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SubstanceCodeReferenceSearchReference;
+impl ReferenceSearchParameter for SubstanceCodeReferenceSearchReference {
+    type Resource = resources::Substance;
+    type Target = resources::SubstanceDefinition;
+    fn parameter(&self) -> SubstanceSearchParameter {
+        SubstanceSearchParameter::CodeReference
+    }
+}
+/// The substance-reference reference search parameter of the Substance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SubstanceSubstanceReferenceSearchReference;
+impl ReferenceSearchParameter for SubstanceSubstanceReferenceSearchReference {
+    type Resource = resources::Substance;
+    type Target = resources::Substance;
+    fn parameter(&self) -> SubstanceSearchParameter {
+        SubstanceSearchParameter::SubstanceReference
+    }
+}
+/// The patient reference search parameter of the SupplyDelivery resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SupplyDeliveryPatientSearchReference;
+impl ReferenceSearchParameter for SupplyDeliveryPatientSearchReference {
+    type Resource = resources::SupplyDelivery;
+    type Target = resources::Patient;
+    fn parameter(&self) -> SupplyDeliverySearchParameter {
+        SupplyDeliverySearchParameter::Patient
+    }
+}
+/// The receiver reference search parameter of the SupplyDelivery resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SupplyDeliveryReceiverSearchReference;
+impl ReferenceSearchParameter for SupplyDeliveryReceiverSearchReference {
+    type Resource = resources::SupplyDelivery;
+    type Target = SupplyDeliveryReceiverSearchTarget;
+    fn parameter(&self) -> SupplyDeliverySearchParameter {
+        SupplyDeliverySearchParameter::Receiver
+    }
+}
+/// Target resources of the receiver reference search parameter of the SupplyDelivery resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SupplyDeliveryReceiverSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for SupplyDeliveryReceiverSearchTarget {
+    fn from(resource: resources::Organization) -> SupplyDeliveryReceiverSearchTarget {
+        SupplyDeliveryReceiverSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for SupplyDeliveryReceiverSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> SupplyDeliveryReceiverSearchTarget {
+        SupplyDeliveryReceiverSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for SupplyDeliveryReceiverSearchTarget {
+    fn from(resource: resources::Practitioner) -> SupplyDeliveryReceiverSearchTarget {
+        SupplyDeliveryReceiverSearchTarget::Practitioner(resource)
+    }
+}
+/// The supplier reference search parameter of the SupplyDelivery resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SupplyDeliverySupplierSearchReference;
+impl ReferenceSearchParameter for SupplyDeliverySupplierSearchReference {
+    type Resource = resources::SupplyDelivery;
+    type Target = SupplyDeliverySupplierSearchTarget;
+    fn parameter(&self) -> SupplyDeliverySearchParameter {
+        SupplyDeliverySearchParameter::Supplier
+    }
+}
+/// Target resources of the supplier reference search parameter of the SupplyDelivery resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SupplyDeliverySupplierSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for SupplyDeliverySupplierSearchTarget {
+    fn from(resource: resources::Organization) -> SupplyDeliverySupplierSearchTarget {
+        SupplyDeliverySupplierSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for SupplyDeliverySupplierSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> SupplyDeliverySupplierSearchTarget {
+        SupplyDeliverySupplierSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for SupplyDeliverySupplierSearchTarget {
+    fn from(resource: resources::Practitioner) -> SupplyDeliverySupplierSearchTarget {
+        SupplyDeliverySupplierSearchTarget::Practitioner(resource)
+    }
+}
+/// The patient reference search parameter of the SupplyRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SupplyRequestPatientSearchReference;
+impl ReferenceSearchParameter for SupplyRequestPatientSearchReference {
+    type Resource = resources::SupplyRequest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> SupplyRequestSearchParameter {
+        SupplyRequestSearchParameter::Patient
+    }
+}
+/// The requester reference search parameter of the SupplyRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SupplyRequestRequesterSearchReference;
+impl ReferenceSearchParameter for SupplyRequestRequesterSearchReference {
+    type Resource = resources::SupplyRequest;
+    type Target = SupplyRequestRequesterSearchTarget;
+    fn parameter(&self) -> SupplyRequestSearchParameter {
+        SupplyRequestSearchParameter::Requester
+    }
+}
+/// Target resources of the requester reference search parameter of the SupplyRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SupplyRequestRequesterSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for SupplyRequestRequesterSearchTarget {
+    fn from(resource: resources::Organization) -> SupplyRequestRequesterSearchTarget {
+        SupplyRequestRequesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for SupplyRequestRequesterSearchTarget {
+    fn from(resource: resources::Device) -> SupplyRequestRequesterSearchTarget {
+        SupplyRequestRequesterSearchTarget::Device(resource)
+    }
+}
+impl From<resources::CareTeam> for SupplyRequestRequesterSearchTarget {
+    fn from(resource: resources::CareTeam) -> SupplyRequestRequesterSearchTarget {
+        SupplyRequestRequesterSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::RelatedPerson> for SupplyRequestRequesterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> SupplyRequestRequesterSearchTarget {
+        SupplyRequestRequesterSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for SupplyRequestRequesterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> SupplyRequestRequesterSearchTarget {
+        SupplyRequestRequesterSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for SupplyRequestRequesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> SupplyRequestRequesterSearchTarget {
+        SupplyRequestRequesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for SupplyRequestRequesterSearchTarget {
+    fn from(resource: resources::Patient) -> SupplyRequestRequesterSearchTarget {
+        SupplyRequestRequesterSearchTarget::Patient(resource)
+    }
+}
+/// The subject reference search parameter of the SupplyRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SupplyRequestSubjectSearchReference;
+impl ReferenceSearchParameter for SupplyRequestSubjectSearchReference {
+    type Resource = resources::SupplyRequest;
+    type Target = SupplyRequestSubjectSearchTarget;
+    fn parameter(&self) -> SupplyRequestSearchParameter {
+        SupplyRequestSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the SupplyRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SupplyRequestSubjectSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for Location target resources
+    Location(resources::Location),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for SupplyRequestSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> SupplyRequestSubjectSearchTarget {
+        SupplyRequestSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for SupplyRequestSubjectSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> SupplyRequestSubjectSearchTarget {
+        SupplyRequestSubjectSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::Location> for SupplyRequestSubjectSearchTarget {
+    fn from(resource: resources::Location) -> SupplyRequestSubjectSearchTarget {
+        SupplyRequestSubjectSearchTarget::Location(resource)
+    }
+}
+impl From<resources::Patient> for SupplyRequestSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> SupplyRequestSubjectSearchTarget {
+        SupplyRequestSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The supplier reference search parameter of the SupplyRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SupplyRequestSupplierSearchReference;
+impl ReferenceSearchParameter for SupplyRequestSupplierSearchReference {
+    type Resource = resources::SupplyRequest;
+    type Target = SupplyRequestSupplierSearchTarget;
+    fn parameter(&self) -> SupplyRequestSearchParameter {
+        SupplyRequestSearchParameter::Supplier
+    }
+}
+/// Target resources of the supplier reference search parameter of the SupplyRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SupplyRequestSupplierSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+}
+impl From<resources::HealthcareService> for SupplyRequestSupplierSearchTarget {
+    fn from(resource: resources::HealthcareService) -> SupplyRequestSupplierSearchTarget {
+        SupplyRequestSupplierSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::Organization> for SupplyRequestSupplierSearchTarget {
+    fn from(resource: resources::Organization) -> SupplyRequestSupplierSearchTarget {
+        SupplyRequestSupplierSearchTarget::Organization(resource)
+    }
+}
+/// The patient reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskPatientSearchReference;
+impl ReferenceSearchParameter for TaskPatientSearchReference {
+    type Resource = resources::Task;
+    type Target = resources::Patient;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskEncounterSearchReference;
+impl ReferenceSearchParameter for TaskEncounterSearchReference {
+    type Resource = resources::Task;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Encounter
+    }
+}
+/// The actor reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskActorSearchReference;
+impl ReferenceSearchParameter for TaskActorSearchReference {
+    type Resource = resources::Task;
+    type Target = TaskActorSearchTarget;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Actor
+    }
+}
+/// Target resources of the actor reference search parameter of the Task resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TaskActorSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for TaskActorSearchTarget {
+    fn from(resource: resources::Organization) -> TaskActorSearchTarget {
+        TaskActorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for TaskActorSearchTarget {
+    fn from(resource: resources::CareTeam) -> TaskActorSearchTarget {
+        TaskActorSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::RelatedPerson> for TaskActorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> TaskActorSearchTarget {
+        TaskActorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for TaskActorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> TaskActorSearchTarget {
+        TaskActorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for TaskActorSearchTarget {
+    fn from(resource: resources::Practitioner) -> TaskActorSearchTarget {
+        TaskActorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for TaskActorSearchTarget {
+    fn from(resource: resources::Patient) -> TaskActorSearchTarget {
+        TaskActorSearchTarget::Patient(resource)
+    }
+}
+/// The based-on reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskBasedOnSearchReference;
+impl ReferenceSearchParameter for TaskBasedOnSearchReference {
+    type Resource = resources::Task;
+    type Target = resources::Resource;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::BasedOn
+    }
+}
+/// The focus reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskFocusSearchReference;
+impl ReferenceSearchParameter for TaskFocusSearchReference {
+    type Resource = resources::Task;
+    type Target = resources::Resource;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Focus
+    }
+}
+/// The output reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskOutputSearchReference;
+impl ReferenceSearchParameter for TaskOutputSearchReference {
+    type Resource = resources::Task;
+    type Target = resources::Resource;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Output
+    }
+}
+/// The owner reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskOwnerSearchReference;
+impl ReferenceSearchParameter for TaskOwnerSearchReference {
+    type Resource = resources::Task;
+    type Target = TaskOwnerSearchTarget;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Owner
+    }
+}
+/// Target resources of the owner reference search parameter of the Task resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TaskOwnerSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Organization> for TaskOwnerSearchTarget {
+    fn from(resource: resources::Organization) -> TaskOwnerSearchTarget {
+        TaskOwnerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for TaskOwnerSearchTarget {
+    fn from(resource: resources::CareTeam) -> TaskOwnerSearchTarget {
+        TaskOwnerSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::RelatedPerson> for TaskOwnerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> TaskOwnerSearchTarget {
+        TaskOwnerSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for TaskOwnerSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> TaskOwnerSearchTarget {
+        TaskOwnerSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for TaskOwnerSearchTarget {
+    fn from(resource: resources::Practitioner) -> TaskOwnerSearchTarget {
+        TaskOwnerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for TaskOwnerSearchTarget {
+    fn from(resource: resources::Patient) -> TaskOwnerSearchTarget {
+        TaskOwnerSearchTarget::Patient(resource)
+    }
+}
+/// The part-of reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskPartOfSearchReference;
+impl ReferenceSearchParameter for TaskPartOfSearchReference {
+    type Resource = resources::Task;
+    type Target = resources::Task;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::PartOf
+    }
+}
+/// The requestedperformer-reference reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskRequestedperformerReferenceSearchReference;
+impl ReferenceSearchParameter for TaskRequestedperformerReferenceSearchReference {
+    type Resource = resources::Task;
+    type Target = TaskRequestedperformerReferenceSearchTarget;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::RequestedperformerReference
+    }
+}
+/// Target resources of the requestedperformer-reference reference search parameter of the Task resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TaskRequestedperformerReferenceSearchTarget {
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::HealthcareService> for TaskRequestedperformerReferenceSearchTarget {
+    fn from(resource: resources::HealthcareService) -> TaskRequestedperformerReferenceSearchTarget {
+        TaskRequestedperformerReferenceSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::Organization> for TaskRequestedperformerReferenceSearchTarget {
+    fn from(resource: resources::Organization) -> TaskRequestedperformerReferenceSearchTarget {
+        TaskRequestedperformerReferenceSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for TaskRequestedperformerReferenceSearchTarget {
+    fn from(resource: resources::CareTeam) -> TaskRequestedperformerReferenceSearchTarget {
+        TaskRequestedperformerReferenceSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for TaskRequestedperformerReferenceSearchTarget {
+    fn from(resource: resources::Device) -> TaskRequestedperformerReferenceSearchTarget {
+        TaskRequestedperformerReferenceSearchTarget::Device(resource)
+    }
+}
+impl From<resources::RelatedPerson> for TaskRequestedperformerReferenceSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> TaskRequestedperformerReferenceSearchTarget {
+        TaskRequestedperformerReferenceSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for TaskRequestedperformerReferenceSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> TaskRequestedperformerReferenceSearchTarget {
+        TaskRequestedperformerReferenceSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for TaskRequestedperformerReferenceSearchTarget {
+    fn from(resource: resources::Practitioner) -> TaskRequestedperformerReferenceSearchTarget {
+        TaskRequestedperformerReferenceSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for TaskRequestedperformerReferenceSearchTarget {
+    fn from(resource: resources::Patient) -> TaskRequestedperformerReferenceSearchTarget {
+        TaskRequestedperformerReferenceSearchTarget::Patient(resource)
+    }
+}
+/// The requester reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskRequesterSearchReference;
+impl ReferenceSearchParameter for TaskRequesterSearchReference {
+    type Resource = resources::Task;
+    type Target = TaskRequesterSearchTarget;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Requester
+    }
+}
+/// Target resources of the requester reference search parameter of the Task resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TaskRequesterSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Device> for TaskRequesterSearchTarget {
+    fn from(resource: resources::Device) -> TaskRequesterSearchTarget {
+        TaskRequesterSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Organization> for TaskRequesterSearchTarget {
+    fn from(resource: resources::Organization) -> TaskRequesterSearchTarget {
+        TaskRequesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::RelatedPerson> for TaskRequesterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> TaskRequesterSearchTarget {
+        TaskRequesterSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::PractitionerRole> for TaskRequesterSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> TaskRequesterSearchTarget {
+        TaskRequesterSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for TaskRequesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> TaskRequesterSearchTarget {
+        TaskRequesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for TaskRequesterSearchTarget {
+    fn from(resource: resources::Patient) -> TaskRequesterSearchTarget {
+        TaskRequesterSearchTarget::Patient(resource)
+    }
+}
+/// The subject reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskSubjectSearchReference;
+impl ReferenceSearchParameter for TaskSubjectSearchReference {
+    type Resource = resources::Task;
+    type Target = resources::Resource;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Subject
+    }
+}
+/// The scope reference search parameter of the TestPlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TestPlanScopeSearchReference;
+impl ReferenceSearchParameter for TestPlanScopeSearchReference {
+    type Resource = resources::TestPlan;
+    type Target = resources::Resource;
+    fn parameter(&self) -> TestPlanSearchParameter {
+        TestPlanSearchParameter::Scope
+    }
+}
+/// The testscript reference search parameter of the TestReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TestReportTestscriptSearchReference;
+impl ReferenceSearchParameter for TestReportTestscriptSearchReference {
+    type Resource = resources::TestReport;
+    type Target = resources::TestScript;
+    fn parameter(&self) -> TestReportSearchParameter {
+        TestReportSearchParameter::Testscript
+    }
+}
+/// The scope-artifact reference search parameter of the TestScript resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TestScriptScopeArtifactSearchReference;
+impl ReferenceSearchParameter for TestScriptScopeArtifactSearchReference {
+    type Resource = resources::TestScript;
+    type Target = resources::Resource;
+    fn parameter(&self) -> TestScriptSearchParameter {
+        TestScriptSearchParameter::ScopeArtifact
+    }
+}
+/// The derived-from reference search parameter of the ValueSet resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ValueSetDerivedFromSearchReference;
+impl ReferenceSearchParameter for ValueSetDerivedFromSearchReference {
+    type Resource = resources::ValueSet;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ValueSetSearchParameter {
+        ValueSetSearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the ValueSet resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ValueSetPredecessorSearchReference;
+impl ReferenceSearchParameter for ValueSetPredecessorSearchReference {
+    type Resource = resources::ValueSet;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ValueSetSearchParameter {
+        ValueSetSearchParameter::Predecessor
+    }
+}
+/// The attestation-onbehalfof reference search parameter of the VerificationResult resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VerificationResultAttestationOnbehalfofSearchReference;
+impl ReferenceSearchParameter for VerificationResultAttestationOnbehalfofSearchReference {
+    type Resource = resources::VerificationResult;
+    type Target = VerificationResultAttestationOnbehalfofSearchTarget;
+    fn parameter(&self) -> VerificationResultSearchParameter {
+        VerificationResultSearchParameter::AttestationOnbehalfof
+    }
+}
+/// Target resources of the attestation-onbehalfof reference search parameter of the VerificationResult resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VerificationResultAttestationOnbehalfofSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for VerificationResultAttestationOnbehalfofSearchTarget {
+    fn from(resource: resources::Organization) -> VerificationResultAttestationOnbehalfofSearchTarget {
+        VerificationResultAttestationOnbehalfofSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for VerificationResultAttestationOnbehalfofSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> VerificationResultAttestationOnbehalfofSearchTarget {
+        VerificationResultAttestationOnbehalfofSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for VerificationResultAttestationOnbehalfofSearchTarget {
+    fn from(resource: resources::Practitioner) -> VerificationResultAttestationOnbehalfofSearchTarget {
+        VerificationResultAttestationOnbehalfofSearchTarget::Practitioner(resource)
+    }
+}
+/// The attestation-who reference search parameter of the VerificationResult resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VerificationResultAttestationWhoSearchReference;
+impl ReferenceSearchParameter for VerificationResultAttestationWhoSearchReference {
+    type Resource = resources::VerificationResult;
+    type Target = VerificationResultAttestationWhoSearchTarget;
+    fn parameter(&self) -> VerificationResultSearchParameter {
+        VerificationResultSearchParameter::AttestationWho
+    }
+}
+/// Target resources of the attestation-who reference search parameter of the VerificationResult resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VerificationResultAttestationWhoSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for VerificationResultAttestationWhoSearchTarget {
+    fn from(resource: resources::Organization) -> VerificationResultAttestationWhoSearchTarget {
+        VerificationResultAttestationWhoSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for VerificationResultAttestationWhoSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> VerificationResultAttestationWhoSearchTarget {
+        VerificationResultAttestationWhoSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for VerificationResultAttestationWhoSearchTarget {
+    fn from(resource: resources::Practitioner) -> VerificationResultAttestationWhoSearchTarget {
+        VerificationResultAttestationWhoSearchTarget::Practitioner(resource)
+    }
+}
+/// The primarysource-who reference search parameter of the VerificationResult resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VerificationResultPrimarysourceWhoSearchReference;
+impl ReferenceSearchParameter for VerificationResultPrimarysourceWhoSearchReference {
+    type Resource = resources::VerificationResult;
+    type Target = VerificationResultPrimarysourceWhoSearchTarget;
+    fn parameter(&self) -> VerificationResultSearchParameter {
+        VerificationResultSearchParameter::PrimarysourceWho
+    }
+}
+/// Target resources of the primarysource-who reference search parameter of the VerificationResult resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VerificationResultPrimarysourceWhoSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::Organization> for VerificationResultPrimarysourceWhoSearchTarget {
+    fn from(resource: resources::Organization) -> VerificationResultPrimarysourceWhoSearchTarget {
+        VerificationResultPrimarysourceWhoSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::PractitionerRole> for VerificationResultPrimarysourceWhoSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> VerificationResultPrimarysourceWhoSearchTarget {
+        VerificationResultPrimarysourceWhoSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for VerificationResultPrimarysourceWhoSearchTarget {
+    fn from(resource: resources::Practitioner) -> VerificationResultPrimarysourceWhoSearchTarget {
+        VerificationResultPrimarysourceWhoSearchTarget::Practitioner(resource)
+    }
+}
+/// The target reference search parameter of the VerificationResult resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VerificationResultTargetSearchReference;
+impl ReferenceSearchParameter for VerificationResultTargetSearchReference {
+    type Resource = resources::VerificationResult;
+    type Target = resources::Resource;
+    fn parameter(&self) -> VerificationResultSearchParameter {
+        VerificationResultSearchParameter::Target
+    }
+}
+/// The validator-organization reference search parameter of the VerificationResult resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VerificationResultValidatorOrganizationSearchReference;
+impl ReferenceSearchParameter for VerificationResultValidatorOrganizationSearchReference {
+    type Resource = resources::VerificationResult;
+    type Target = resources::Organization;
+    fn parameter(&self) -> VerificationResultSearchParameter {
+        VerificationResultSearchParameter::ValidatorOrganization
+    }
+}
+/// The patient reference search parameter of the VisionPrescription resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VisionPrescriptionPatientSearchReference;
+impl ReferenceSearchParameter for VisionPrescriptionPatientSearchReference {
+    type Resource = resources::VisionPrescription;
+    type Target = resources::Patient;
+    fn parameter(&self) -> VisionPrescriptionSearchParameter {
+        VisionPrescriptionSearchParameter::Patient
+    }
+}
+/// The encounter reference search parameter of the VisionPrescription resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VisionPrescriptionEncounterSearchReference;
+impl ReferenceSearchParameter for VisionPrescriptionEncounterSearchReference {
+    type Resource = resources::VisionPrescription;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> VisionPrescriptionSearchParameter {
+        VisionPrescriptionSearchParameter::Encounter
+    }
+}
+/// The prescriber reference search parameter of the VisionPrescription resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VisionPrescriptionPrescriberSearchReference;
+impl ReferenceSearchParameter for VisionPrescriptionPrescriberSearchReference {
+    type Resource = resources::VisionPrescription;
+    type Target = VisionPrescriptionPrescriberSearchTarget;
+    fn parameter(&self) -> VisionPrescriptionSearchParameter {
+        VisionPrescriptionSearchParameter::Prescriber
+    }
+}
+/// Target resources of the prescriber reference search parameter of the VisionPrescription resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VisionPrescriptionPrescriberSearchTarget {
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+}
+impl From<resources::PractitionerRole> for VisionPrescriptionPrescriberSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> VisionPrescriptionPrescriberSearchTarget {
+        VisionPrescriptionPrescriberSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::Practitioner> for VisionPrescriptionPrescriberSearchTarget {
+    fn from(resource: resources::Practitioner) -> VisionPrescriptionPrescriberSearchTarget {
+        VisionPrescriptionPrescriberSearchTarget::Practitioner(resource)
+    }
+}
 impl SearchableResource for resources::Account {
     /// Parameters that can be used when searching Account resources
     type Params = AccountSearchParameter;
@@ -0,0 +1,367 @@
+//! Version-agnostic traits over the FHIR versions' models.
+//!
+//! The models of the different FHIR versions are unrelated types. The traits
+//! in this module give access to the parts that are shared between the
+//! versions, so that code working with IDs, meta data, identifiers, references
+//! or codings only needs to be written once:
+//!
+//! ```ignore
+//! use fhir_model::{FhirRelease, GenericIdentifiable, GenericResource};
+//!
+//! fn mrn<V: FhirRelease, R: GenericResource<V> + GenericIdentifiable<V>>(resource: &R) -> Option<&str> {
+//! 	resource.identifier_with_system("http://hospital.example.org/mrn")
+//! }
+//! ```
+
+use std::{
+	fmt::{Debug, Display},
+	hash::Hash,
+	str::FromStr,
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{error::UnknownResourceType, Instant, ParsedReference};
+
+/// A FHIR version, giving access to the version's resource and type families.
+/// Implemented by the marker types of the versions, e.g. `r5::R5`.
+pub trait FhirRelease: Debug + Clone + Copy + Send + Sync + 'static {
+	/// The FHIR version number, e.g. `5.0`.
+	const VERSION: &'static str;
+
+	/// The `Resource` enum of all resources.
+	type Resource: GenericResource<Self>
+		+ Serialize
+		+ DeserializeOwned
+		+ Debug
+		+ Clone
+		+ PartialEq
+		+ Send
+		+ Sync;
+	/// The `ResourceType` enum of all resource types.
+	type ResourceType: Debug
+		+ Display
+		+ Copy
+		+ PartialEq
+		+ Eq
+		+ Hash
+		+ FromStr<Err = UnknownResourceType>
+		+ Send
+		+ Sync;
+	/// The `Meta` data type.
+	type Meta: GenericMeta<Self>;
+	/// The `Identifier` data type.
+	type Identifier: GenericIdentifier<Self>;
+	/// The `Reference` data type.
+	type Reference: GenericReference<Self>;
+	/// The `Coding` data type.
+	type Coding: GenericCoding;
+	/// The `CodeableConcept` data type.
+	type CodeableConcept: GenericCodeableConcept<Self>;
+}
+
+/// Access to the data all resources of a FHIR version share. Implemented for
+/// all resources and the `Resource` enum.
+pub trait GenericResource<V: FhirRelease> {
+	/// Get the resource's type.
+	fn resource_type(&self) -> V::ResourceType;
+
+	/// Get the resource's ID.
+	fn resource_id(&self) -> Option<&str>;
+
+	/// Set the resource's ID.
+	fn set_resource_id(&mut self, id: Option<String>);
+
+	/// Get the resource's meta data.
+	fn resource_meta(&self) -> Option<&V::Meta>;
+
+	/// Get the resource's version ID.
+	fn resource_version_id(&self) -> Option<&str> {
+		self.resource_meta().and_then(GenericMeta::version_id)
+	}
+}
+
+/// Access to the `identifier` field of the resources having multiple
+/// identifiers.
+pub trait GenericIdentifiable<V: FhirRelease> {
+	/// Get the resource's identifiers.
+	fn identifiers(&self) -> &[Option<V::Identifier>];
+
+	/// Return the first identifier value for a given system.
+	fn identifier_with_system(&self, system: &str) -> Option<&str> {
+		self.identifiers_with_system(system).into_iter().find_map(GenericIdentifier::value)
+	}
+
+	/// Return a list of identifiers for a given system.
+	fn identifiers_with_system(&self, system: &str) -> Vec<&V::Identifier> {
+		self.identifiers().iter().flatten().filter(|ident| ident.system() == Some(system)).collect()
+	}
+
+	/// Return the first identifier value for a given type.
+	fn identifier_with_type(&self, type_system: &str, type_code: &str) -> Option<&str> {
+		self.identifiers_with_type(type_system, type_code)
+			.into_iter()
+			.find_map(GenericIdentifier::value)
+	}
+
+	/// Return a list of identifiers for a given type.
+	fn identifiers_with_type(&self, type_system: &str, type_code: &str) -> Vec<&V::Identifier> {
+		self.identifiers()
+			.iter()
+			.flatten()
+			.filter(|ident| {
+				ident.identifier_type().map_or(false, |ty| ty.has_coding(type_system, type_code))
+			})
+			.collect()
+	}
+}
+
+/// Access to the `Meta` data type.
+pub trait GenericMeta<V: FhirRelease> {
+	/// Get the version ID.
+	fn version_id(&self) -> Option<&str>;
+
+	/// Get the time of the last update.
+	fn last_updated(&self) -> Option<&Instant>;
+
+	/// Get the profiles the resource claims to conform to.
+	fn profiles(&self) -> impl Iterator<Item = &str>;
+
+	/// Get the security labels.
+	fn security(&self) -> impl Iterator<Item = &V::Coding>;
+
+	/// Get the tags.
+	fn tags(&self) -> impl Iterator<Item = &V::Coding>;
+}
+
+/// Access to the `Identifier` data type.
+pub trait GenericIdentifier<V: FhirRelease> {
+	/// Get the identifier's system.
+	fn system(&self) -> Option<&str>;
+
+	/// Get the identifier's value.
+	fn value(&self) -> Option<&str>;
+
+	/// Get the identifier's type.
+	fn identifier_type(&self) -> Option<&V::CodeableConcept>;
+}
+
+/// Access to the `Reference` data type.
+pub trait GenericReference<V: FhirRelease> {
+	/// Get the literal reference.
+	fn reference(&self) -> Option<&str>;
+
+	/// Get the expected type of the target resource. Always `None` in STU3,
+	/// which does not have `Reference.type`.
+	fn target_type(&self) -> Option<&str>;
+
+	/// Get the logical reference via an identifier.
+	fn identifier(&self) -> Option<&V::Identifier>;
+
+	/// Get the text alternative for the resource.
+	fn display(&self) -> Option<&str>;
+
+	/// Parse the literal reference into a [`ParsedReference`]. Returns `None`
+	/// if the `reference` field is empty.
+	fn parse(&self) -> Option<ParsedReference<'_>> {
+		Some(ParsedReference::new::<V::ResourceType>(self.reference()?))
+	}
+}
+
+/// Access to the `Coding` data type.
+pub trait GenericCoding {
+	/// Get the code system.
+	fn system(&self) -> Option<&str>;
+
+	/// Get the version of the code system.
+	fn version(&self) -> Option<&str>;
+
+	/// Get the code.
+	fn code(&self) -> Option<&str>;
+
+	/// Get the display text of the code.
+	fn display(&self) -> Option<&str>;
+}
+
+/// Access to the `CodeableConcept` data type.
+pub trait GenericCodeableConcept<V: FhirRelease> {
+	/// Get the codings.
+	fn codings(&self) -> impl Iterator<Item = &V::Coding>;
+
+	/// Get the plain text representation.
+	fn text(&self) -> Option<&str>;
+
+	/// Whether the concept contains a coding with the given system and code.
+	fn has_coding(&self, system: &str, code: &str) -> bool {
+		self.codings().any(|coding| coding.system() == Some(system) && coding.code() == Some(code))
+	}
+
+	/// Return the first code with the given system.
+	fn code_with_system(&self, system: &str) -> Option<&str> {
+		self.codings()
+			.filter(|coding| coding.system() == Some(system))
+			.find_map(GenericCoding::code)
+	}
+}
+
+/// Implement the generic traits for a FHIR version.
+macro_rules! impl_release {
+	(@target_type stu3, $reference:expr) => {
+		None
+	};
+	(@target_type $version:ident, $reference:expr) => {
+		$reference.r#type.as_deref()
+	};
+	($version:ident: $release:ident, $number:literal) => {
+		impl FhirRelease for crate::$version::$release {
+			const VERSION: &'static str = $number;
+
+			type Resource = crate::$version::resources::Resource;
+			type ResourceType = crate::$version::resources::ResourceType;
+			type Meta = crate::$version::types::Meta;
+			type Identifier = crate::$version::types::Identifier;
+			type Reference = crate::$version::types::Reference;
+			type Coding = crate::$version::types::Coding;
+			type CodeableConcept = crate::$version::types::CodeableConcept;
+		}
+
+		impl<R> GenericResource<crate::$version::$release> for R
+		where
+			R: crate::$version::resources::NamedResource + crate::$version::resources::BaseResource,
+		{
+			fn resource_type(&self) -> crate::$version::resources::ResourceType {
+				R::TYPE
+			}
+
+			fn resource_id(&self) -> Option<&str> {
+				self.id().as_deref()
+			}
+
+			fn set_resource_id(&mut self, id: Option<String>) {
+				self.set_id(id);
+			}
+
+			fn resource_meta(&self) -> Option<&crate::$version::types::Meta> {
+				self.meta().as_ref()
+			}
+		}
+
+		impl GenericResource<crate::$version::$release> for crate::$version::resources::Resource {
+			fn resource_type(&self) -> crate::$version::resources::ResourceType {
+				crate::$version::resources::Resource::resource_type(self)
+			}
+
+			fn resource_id(&self) -> Option<&str> {
+				self.as_base_resource().id().as_deref()
+			}
+
+			fn set_resource_id(&mut self, id: Option<String>) {
+				self.as_base_resource_mut().set_id(id);
+			}
+
+			fn resource_meta(&self) -> Option<&crate::$version::types::Meta> {
+				self.as_base_resource().meta().as_ref()
+			}
+		}
+
+		impl<R> GenericIdentifiable<crate::$version::$release> for R
+		where
+			R: crate::$version::resources::IdentifiableResource,
+		{
+			fn identifiers(&self) -> &[Option<crate::$version::types::Identifier>] {
+				self.identifier()
+			}
+		}
+
+		impl GenericMeta<crate::$version::$release> for crate::$version::types::Meta {
+			fn version_id(&self) -> Option<&str> {
+				self.version_id.as_deref()
+			}
+
+			fn last_updated(&self) -> Option<&Instant> {
+				self.last_updated.as_ref()
+			}
+
+			fn profiles(&self) -> impl Iterator<Item = &str> {
+				self.profile.iter().flatten().map(String::as_str)
+			}
+
+			fn security(&self) -> impl Iterator<Item = &crate::$version::types::Coding> {
+				self.security.iter().flatten()
+			}
+
+			fn tags(&self) -> impl Iterator<Item = &crate::$version::types::Coding> {
+				self.tag.iter().flatten()
+			}
+		}
+
+		impl GenericIdentifier<crate::$version::$release> for crate::$version::types::Identifier {
+			fn system(&self) -> Option<&str> {
+				self.system.as_deref()
+			}
+
+			fn value(&self) -> Option<&str> {
+				self.value.as_deref()
+			}
+
+			fn identifier_type(&self) -> Option<&crate::$version::types::CodeableConcept> {
+				self.r#type.as_ref()
+			}
+		}
+
+		impl GenericReference<crate::$version::$release> for crate::$version::types::Reference {
+			fn reference(&self) -> Option<&str> {
+				self.reference.as_deref()
+			}
+
+			fn target_type(&self) -> Option<&str> {
+				impl_release!(@target_type $version, self)
+			}
+
+			fn identifier(&self) -> Option<&crate::$version::types::Identifier> {
+				self.identifier.as_ref()
+			}
+
+			fn display(&self) -> Option<&str> {
+				self.display.as_deref()
+			}
+		}
+
+		impl GenericCoding for crate::$version::types::Coding {
+			fn system(&self) -> Option<&str> {
+				self.system.as_deref()
+			}
+
+			fn version(&self) -> Option<&str> {
+				self.version.as_deref()
+			}
+
+			fn code(&self) -> Option<&str> {
+				self.code.as_deref()
+			}
+
+			fn display(&self) -> Option<&str> {
+				self.display.as_deref()
+			}
+		}
+
+		impl GenericCodeableConcept<crate::$version::$release>
+			for crate::$version::types::CodeableConcept
+		{
+			fn codings(&self) -> impl Iterator<Item = &crate::$version::types::Coding> {
+				self.coding.iter().flatten()
+			}
+
+			fn text(&self) -> Option<&str> {
+				self.text.as_deref()
+			}
+		}
+	};
+}
+
+#[cfg(feature = "stu3")]
+impl_release!(stu3: Stu3, "3.0");
+#[cfg(feature = "r4b")]
+impl_release!(r4b: R4B, "4.3");
+#[cfg(feature = "r5")]
+impl_release!(r5: R5, "5.0");
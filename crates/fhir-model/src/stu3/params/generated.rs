@@ -5582,6 +5582,9713 @@ impl ResourceSearchParameterDefinition for VisionPrescriptionSearchParameter {
         }
     }
 }
+/// The owner reference search parameter of the Account resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AccountOwnerSearchReference;
+impl ReferenceSearchParameter for AccountOwnerSearchReference {
+    type Resource = resources::Account;
+    type Target = resources::Organization;
+    fn parameter(&self) -> AccountSearchParameter {
+        AccountSearchParameter::Owner
+    }
+}
+/// The patient reference search parameter of the Account resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AccountPatientSearchReference;
+impl ReferenceSearchParameter for AccountPatientSearchReference {
+    type Resource = resources::Account;
+    type Target = resources::Patient;
+    fn parameter(&self) -> AccountSearchParameter {
+        AccountSearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the Account resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AccountSubjectSearchReference;
+impl ReferenceSearchParameter for AccountSubjectSearchReference {
+    type Resource = resources::Account;
+    type Target = AccountSubjectSearchTarget;
+    fn parameter(&self) -> AccountSearchParameter {
+        AccountSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Account resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AccountSubjectSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Practitioner> for AccountSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> AccountSubjectSearchTarget {
+        AccountSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for AccountSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> AccountSubjectSearchTarget {
+        AccountSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for AccountSubjectSearchTarget {
+    fn from(resource: resources::Device) -> AccountSubjectSearchTarget {
+        AccountSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for AccountSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> AccountSubjectSearchTarget {
+        AccountSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::HealthcareService> for AccountSubjectSearchTarget {
+    fn from(resource: resources::HealthcareService) -> AccountSubjectSearchTarget {
+        AccountSubjectSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::Location> for AccountSubjectSearchTarget {
+    fn from(resource: resources::Location) -> AccountSubjectSearchTarget {
+        AccountSubjectSearchTarget::Location(resource)
+    }
+}
+/// The composed-of reference search parameter of the ActivityDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ActivityDefinitionComposedOfSearchReference;
+impl ReferenceSearchParameter for ActivityDefinitionComposedOfSearchReference {
+    type Resource = resources::ActivityDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ActivityDefinitionSearchParameter {
+        ActivityDefinitionSearchParameter::ComposedOf
+    }
+}
+/// The depends-on reference search parameter of the ActivityDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ActivityDefinitionDependsOnSearchReference;
+impl ReferenceSearchParameter for ActivityDefinitionDependsOnSearchReference {
+    type Resource = resources::ActivityDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ActivityDefinitionSearchParameter {
+        ActivityDefinitionSearchParameter::DependsOn
+    }
+}
+/// The derived-from reference search parameter of the ActivityDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ActivityDefinitionDerivedFromSearchReference;
+impl ReferenceSearchParameter for ActivityDefinitionDerivedFromSearchReference {
+    type Resource = resources::ActivityDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ActivityDefinitionSearchParameter {
+        ActivityDefinitionSearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the ActivityDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ActivityDefinitionPredecessorSearchReference;
+impl ReferenceSearchParameter for ActivityDefinitionPredecessorSearchReference {
+    type Resource = resources::ActivityDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ActivityDefinitionSearchParameter {
+        ActivityDefinitionSearchParameter::Predecessor
+    }
+}
+/// The successor reference search parameter of the ActivityDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ActivityDefinitionSuccessorSearchReference;
+impl ReferenceSearchParameter for ActivityDefinitionSuccessorSearchReference {
+    type Resource = resources::ActivityDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ActivityDefinitionSearchParameter {
+        ActivityDefinitionSearchParameter::Successor
+    }
+}
+/// The location reference search parameter of the AdverseEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdverseEventLocationSearchReference;
+impl ReferenceSearchParameter for AdverseEventLocationSearchReference {
+    type Resource = resources::AdverseEvent;
+    type Target = resources::Location;
+    fn parameter(&self) -> AdverseEventSearchParameter {
+        AdverseEventSearchParameter::Location
+    }
+}
+/// The reaction reference search parameter of the AdverseEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdverseEventReactionSearchReference;
+impl ReferenceSearchParameter for AdverseEventReactionSearchReference {
+    type Resource = resources::AdverseEvent;
+    type Target = resources::Condition;
+    fn parameter(&self) -> AdverseEventSearchParameter {
+        AdverseEventSearchParameter::Reaction
+    }
+}
+/// The recorder reference search parameter of the AdverseEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdverseEventRecorderSearchReference;
+impl ReferenceSearchParameter for AdverseEventRecorderSearchReference {
+    type Resource = resources::AdverseEvent;
+    type Target = AdverseEventRecorderSearchTarget;
+    fn parameter(&self) -> AdverseEventSearchParameter {
+        AdverseEventSearchParameter::Recorder
+    }
+}
+/// Target resources of the recorder reference search parameter of the AdverseEvent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AdverseEventRecorderSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for AdverseEventRecorderSearchTarget {
+    fn from(resource: resources::Practitioner) -> AdverseEventRecorderSearchTarget {
+        AdverseEventRecorderSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for AdverseEventRecorderSearchTarget {
+    fn from(resource: resources::Patient) -> AdverseEventRecorderSearchTarget {
+        AdverseEventRecorderSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for AdverseEventRecorderSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AdverseEventRecorderSearchTarget {
+        AdverseEventRecorderSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The study reference search parameter of the AdverseEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdverseEventStudySearchReference;
+impl ReferenceSearchParameter for AdverseEventStudySearchReference {
+    type Resource = resources::AdverseEvent;
+    type Target = resources::ResearchStudy;
+    fn parameter(&self) -> AdverseEventSearchParameter {
+        AdverseEventSearchParameter::Study
+    }
+}
+/// The subject reference search parameter of the AdverseEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdverseEventSubjectSearchReference;
+impl ReferenceSearchParameter for AdverseEventSubjectSearchReference {
+    type Resource = resources::AdverseEvent;
+    type Target = AdverseEventSubjectSearchTarget;
+    fn parameter(&self) -> AdverseEventSearchParameter {
+        AdverseEventSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the AdverseEvent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AdverseEventSubjectSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for ResearchSubject target resources
+    ResearchSubject(resources::ResearchSubject),
+}
+impl From<resources::Device> for AdverseEventSubjectSearchTarget {
+    fn from(resource: resources::Device) -> AdverseEventSubjectSearchTarget {
+        AdverseEventSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Medication> for AdverseEventSubjectSearchTarget {
+    fn from(resource: resources::Medication) -> AdverseEventSubjectSearchTarget {
+        AdverseEventSubjectSearchTarget::Medication(resource)
+    }
+}
+impl From<resources::Patient> for AdverseEventSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> AdverseEventSubjectSearchTarget {
+        AdverseEventSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::ResearchSubject> for AdverseEventSubjectSearchTarget {
+    fn from(resource: resources::ResearchSubject) -> AdverseEventSubjectSearchTarget {
+        AdverseEventSubjectSearchTarget::ResearchSubject(resource)
+    }
+}
+/// The substance reference search parameter of the AdverseEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AdverseEventSubstanceSearchReference;
+impl ReferenceSearchParameter for AdverseEventSubstanceSearchReference {
+    type Resource = resources::AdverseEvent;
+    type Target = AdverseEventSubstanceSearchTarget;
+    fn parameter(&self) -> AdverseEventSearchParameter {
+        AdverseEventSearchParameter::Substance
+    }
+}
+/// Target resources of the substance reference search parameter of the AdverseEvent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AdverseEventSubstanceSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+    /// Variant for MedicationAdministration target resources
+    MedicationAdministration(resources::MedicationAdministration),
+    /// Variant for MedicationStatement target resources
+    MedicationStatement(resources::MedicationStatement),
+}
+impl From<resources::Device> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::Device) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Medication> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::Medication) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::Medication(resource)
+    }
+}
+impl From<resources::Substance> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::Substance) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::Substance(resource)
+    }
+}
+impl From<resources::MedicationAdministration> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::MedicationAdministration) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::MedicationAdministration(resource)
+    }
+}
+impl From<resources::MedicationStatement> for AdverseEventSubstanceSearchTarget {
+    fn from(resource: resources::MedicationStatement) -> AdverseEventSubstanceSearchTarget {
+        AdverseEventSubstanceSearchTarget::MedicationStatement(resource)
+    }
+}
+/// The asserter reference search parameter of the AllergyIntolerance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AllergyIntoleranceAsserterSearchReference;
+impl ReferenceSearchParameter for AllergyIntoleranceAsserterSearchReference {
+    type Resource = resources::AllergyIntolerance;
+    type Target = AllergyIntoleranceAsserterSearchTarget;
+    fn parameter(&self) -> AllergyIntoleranceSearchParameter {
+        AllergyIntoleranceSearchParameter::Asserter
+    }
+}
+/// Target resources of the asserter reference search parameter of the AllergyIntolerance resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AllergyIntoleranceAsserterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for AllergyIntoleranceAsserterSearchTarget {
+    fn from(resource: resources::Practitioner) -> AllergyIntoleranceAsserterSearchTarget {
+        AllergyIntoleranceAsserterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for AllergyIntoleranceAsserterSearchTarget {
+    fn from(resource: resources::Patient) -> AllergyIntoleranceAsserterSearchTarget {
+        AllergyIntoleranceAsserterSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for AllergyIntoleranceAsserterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AllergyIntoleranceAsserterSearchTarget {
+        AllergyIntoleranceAsserterSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The patient reference search parameter of the AllergyIntolerance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AllergyIntolerancePatientSearchReference;
+impl ReferenceSearchParameter for AllergyIntolerancePatientSearchReference {
+    type Resource = resources::AllergyIntolerance;
+    type Target = AllergyIntolerancePatientSearchTarget;
+    fn parameter(&self) -> AllergyIntoleranceSearchParameter {
+        AllergyIntoleranceSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the AllergyIntolerance resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AllergyIntolerancePatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for AllergyIntolerancePatientSearchTarget {
+    fn from(resource: resources::Patient) -> AllergyIntolerancePatientSearchTarget {
+        AllergyIntolerancePatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for AllergyIntolerancePatientSearchTarget {
+    fn from(resource: resources::Group) -> AllergyIntolerancePatientSearchTarget {
+        AllergyIntolerancePatientSearchTarget::Group(resource)
+    }
+}
+/// The recorder reference search parameter of the AllergyIntolerance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AllergyIntoleranceRecorderSearchReference;
+impl ReferenceSearchParameter for AllergyIntoleranceRecorderSearchReference {
+    type Resource = resources::AllergyIntolerance;
+    type Target = AllergyIntoleranceRecorderSearchTarget;
+    fn parameter(&self) -> AllergyIntoleranceSearchParameter {
+        AllergyIntoleranceSearchParameter::Recorder
+    }
+}
+/// Target resources of the recorder reference search parameter of the AllergyIntolerance resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AllergyIntoleranceRecorderSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Practitioner> for AllergyIntoleranceRecorderSearchTarget {
+    fn from(resource: resources::Practitioner) -> AllergyIntoleranceRecorderSearchTarget {
+        AllergyIntoleranceRecorderSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for AllergyIntoleranceRecorderSearchTarget {
+    fn from(resource: resources::Patient) -> AllergyIntoleranceRecorderSearchTarget {
+        AllergyIntoleranceRecorderSearchTarget::Patient(resource)
+    }
+}
+/// The actor reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentActorSearchReference;
+impl ReferenceSearchParameter for AppointmentActorSearchReference {
+    type Resource = resources::Appointment;
+    type Target = AppointmentActorSearchTarget;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::Actor
+    }
+}
+/// Target resources of the actor reference search parameter of the Appointment resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AppointmentActorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Practitioner> for AppointmentActorSearchTarget {
+    fn from(resource: resources::Practitioner) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Device> for AppointmentActorSearchTarget {
+    fn from(resource: resources::Device) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for AppointmentActorSearchTarget {
+    fn from(resource: resources::Patient) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::HealthcareService> for AppointmentActorSearchTarget {
+    fn from(resource: resources::HealthcareService) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::RelatedPerson> for AppointmentActorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::Location> for AppointmentActorSearchTarget {
+    fn from(resource: resources::Location) -> AppointmentActorSearchTarget {
+        AppointmentActorSearchTarget::Location(resource)
+    }
+}
+/// The incomingreferral reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentIncomingreferralSearchReference;
+impl ReferenceSearchParameter for AppointmentIncomingreferralSearchReference {
+    type Resource = resources::Appointment;
+    type Target = resources::ReferralRequest;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::Incomingreferral
+    }
+}
+/// The location reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentLocationSearchReference;
+impl ReferenceSearchParameter for AppointmentLocationSearchReference {
+    type Resource = resources::Appointment;
+    type Target = resources::Location;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::Location
+    }
+}
+/// The patient reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentPatientSearchReference;
+impl ReferenceSearchParameter for AppointmentPatientSearchReference {
+    type Resource = resources::Appointment;
+    type Target = resources::Patient;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::Patient
+    }
+}
+/// The practitioner reference search parameter of the Appointment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentPractitionerSearchReference;
+impl ReferenceSearchParameter for AppointmentPractitionerSearchReference {
+    type Resource = resources::Appointment;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> AppointmentSearchParameter {
+        AppointmentSearchParameter::Practitioner
+    }
+}
+/// The actor reference search parameter of the AppointmentResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentResponseActorSearchReference;
+impl ReferenceSearchParameter for AppointmentResponseActorSearchReference {
+    type Resource = resources::AppointmentResponse;
+    type Target = AppointmentResponseActorSearchTarget;
+    fn parameter(&self) -> AppointmentResponseSearchParameter {
+        AppointmentResponseSearchParameter::Actor
+    }
+}
+/// Target resources of the actor reference search parameter of the AppointmentResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AppointmentResponseActorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Practitioner> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::Practitioner) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Device> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::Device) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::Patient) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::HealthcareService> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::HealthcareService) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::RelatedPerson> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::Location> for AppointmentResponseActorSearchTarget {
+    fn from(resource: resources::Location) -> AppointmentResponseActorSearchTarget {
+        AppointmentResponseActorSearchTarget::Location(resource)
+    }
+}
+/// The appointment reference search parameter of the AppointmentResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentResponseAppointmentSearchReference;
+impl ReferenceSearchParameter for AppointmentResponseAppointmentSearchReference {
+    type Resource = resources::AppointmentResponse;
+    type Target = resources::Appointment;
+    fn parameter(&self) -> AppointmentResponseSearchParameter {
+        AppointmentResponseSearchParameter::Appointment
+    }
+}
+/// The location reference search parameter of the AppointmentResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentResponseLocationSearchReference;
+impl ReferenceSearchParameter for AppointmentResponseLocationSearchReference {
+    type Resource = resources::AppointmentResponse;
+    type Target = resources::Location;
+    fn parameter(&self) -> AppointmentResponseSearchParameter {
+        AppointmentResponseSearchParameter::Location
+    }
+}
+/// The patient reference search parameter of the AppointmentResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentResponsePatientSearchReference;
+impl ReferenceSearchParameter for AppointmentResponsePatientSearchReference {
+    type Resource = resources::AppointmentResponse;
+    type Target = resources::Patient;
+    fn parameter(&self) -> AppointmentResponseSearchParameter {
+        AppointmentResponseSearchParameter::Patient
+    }
+}
+/// The practitioner reference search parameter of the AppointmentResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AppointmentResponsePractitionerSearchReference;
+impl ReferenceSearchParameter for AppointmentResponsePractitionerSearchReference {
+    type Resource = resources::AppointmentResponse;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> AppointmentResponseSearchParameter {
+        AppointmentResponseSearchParameter::Practitioner
+    }
+}
+/// The agent reference search parameter of the AuditEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AuditEventAgentSearchReference;
+impl ReferenceSearchParameter for AuditEventAgentSearchReference {
+    type Resource = resources::AuditEvent;
+    type Target = AuditEventAgentSearchTarget;
+    fn parameter(&self) -> AuditEventSearchParameter {
+        AuditEventSearchParameter::Agent
+    }
+}
+/// Target resources of the agent reference search parameter of the AuditEvent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AuditEventAgentSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for AuditEventAgentSearchTarget {
+    fn from(resource: resources::Practitioner) -> AuditEventAgentSearchTarget {
+        AuditEventAgentSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for AuditEventAgentSearchTarget {
+    fn from(resource: resources::Organization) -> AuditEventAgentSearchTarget {
+        AuditEventAgentSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for AuditEventAgentSearchTarget {
+    fn from(resource: resources::Device) -> AuditEventAgentSearchTarget {
+        AuditEventAgentSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for AuditEventAgentSearchTarget {
+    fn from(resource: resources::Patient) -> AuditEventAgentSearchTarget {
+        AuditEventAgentSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for AuditEventAgentSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> AuditEventAgentSearchTarget {
+        AuditEventAgentSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The entity reference search parameter of the AuditEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AuditEventEntitySearchReference;
+impl ReferenceSearchParameter for AuditEventEntitySearchReference {
+    type Resource = resources::AuditEvent;
+    type Target = resources::Resource;
+    fn parameter(&self) -> AuditEventSearchParameter {
+        AuditEventSearchParameter::Entity
+    }
+}
+/// The patient reference search parameter of the AuditEvent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AuditEventPatientSearchReference;
+impl ReferenceSearchParameter for AuditEventPatientSearchReference {
+    type Resource = resources::AuditEvent;
+    type Target = resources::Patient;
+    fn parameter(&self) -> AuditEventSearchParameter {
+        AuditEventSearchParameter::Patient
+    }
+}
+/// The author reference search parameter of the Basic resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BasicAuthorSearchReference;
+impl ReferenceSearchParameter for BasicAuthorSearchReference {
+    type Resource = resources::Basic;
+    type Target = BasicAuthorSearchTarget;
+    fn parameter(&self) -> BasicSearchParameter {
+        BasicSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the Basic resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum BasicAuthorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for BasicAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> BasicAuthorSearchTarget {
+        BasicAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for BasicAuthorSearchTarget {
+    fn from(resource: resources::Patient) -> BasicAuthorSearchTarget {
+        BasicAuthorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for BasicAuthorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> BasicAuthorSearchTarget {
+        BasicAuthorSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The patient reference search parameter of the Basic resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BasicPatientSearchReference;
+impl ReferenceSearchParameter for BasicPatientSearchReference {
+    type Resource = resources::Basic;
+    type Target = resources::Patient;
+    fn parameter(&self) -> BasicSearchParameter {
+        BasicSearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the Basic resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BasicSubjectSearchReference;
+impl ReferenceSearchParameter for BasicSubjectSearchReference {
+    type Resource = resources::Basic;
+    type Target = resources::Resource;
+    fn parameter(&self) -> BasicSearchParameter {
+        BasicSearchParameter::Subject
+    }
+}
+/// The patient reference search parameter of the BodySite resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BodySitePatientSearchReference;
+impl ReferenceSearchParameter for BodySitePatientSearchReference {
+    type Resource = resources::BodySite;
+    type Target = resources::Patient;
+    fn parameter(&self) -> BodySiteSearchParameter {
+        BodySiteSearchParameter::Patient
+    }
+}
+/// The composition reference search parameter of the Bundle resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BundleCompositionSearchReference;
+impl ReferenceSearchParameter for BundleCompositionSearchReference {
+    type Resource = resources::Bundle;
+    type Target = resources::Composition;
+    fn parameter(&self) -> BundleSearchParameter {
+        BundleSearchParameter::Composition
+    }
+}
+/// The message reference search parameter of the Bundle resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BundleMessageSearchReference;
+impl ReferenceSearchParameter for BundleMessageSearchReference {
+    type Resource = resources::Bundle;
+    type Target = resources::MessageHeader;
+    fn parameter(&self) -> BundleSearchParameter {
+        BundleSearchParameter::Message
+    }
+}
+/// The resource-profile reference search parameter of the CapabilityStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CapabilityStatementResourceProfileSearchReference;
+impl ReferenceSearchParameter for CapabilityStatementResourceProfileSearchReference {
+    type Resource = resources::CapabilityStatement;
+    type Target = resources::StructureDefinition;
+    fn parameter(&self) -> CapabilityStatementSearchParameter {
+        CapabilityStatementSearchParameter::ResourceProfile
+    }
+}
+/// The supported-profile reference search parameter of the CapabilityStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CapabilityStatementSupportedProfileSearchReference;
+impl ReferenceSearchParameter for CapabilityStatementSupportedProfileSearchReference {
+    type Resource = resources::CapabilityStatement;
+    type Target = resources::StructureDefinition;
+    fn parameter(&self) -> CapabilityStatementSearchParameter {
+        CapabilityStatementSearchParameter::SupportedProfile
+    }
+}
+/// The patient reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanPatientSearchReference;
+impl ReferenceSearchParameter for CarePlanPatientSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = CarePlanPatientSearchTarget;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the CarePlan resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CarePlanPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for CarePlanPatientSearchTarget {
+    fn from(resource: resources::Patient) -> CarePlanPatientSearchTarget {
+        CarePlanPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for CarePlanPatientSearchTarget {
+    fn from(resource: resources::Group) -> CarePlanPatientSearchTarget {
+        CarePlanPatientSearchTarget::Group(resource)
+    }
+}
+/// The activity-reference reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanActivityReferenceSearchReference;
+impl ReferenceSearchParameter for CarePlanActivityReferenceSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = CarePlanActivityReferenceSearchTarget;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::ActivityReference
+    }
+}
+/// Target resources of the activity-reference reference search parameter of the CarePlan resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CarePlanActivityReferenceSearchTarget {
+    /// Variant for Appointment target resources
+    Appointment(resources::Appointment),
+    /// Variant for ReferralRequest target resources
+    ReferralRequest(resources::ReferralRequest),
+    /// Variant for MedicationRequest target resources
+    MedicationRequest(resources::MedicationRequest),
+    /// Variant for Task target resources
+    Task(resources::Task),
+    /// Variant for NutritionOrder target resources
+    NutritionOrder(resources::NutritionOrder),
+    /// Variant for RequestGroup target resources
+    RequestGroup(resources::RequestGroup),
+    /// Variant for VisionPrescription target resources
+    VisionPrescription(resources::VisionPrescription),
+    /// Variant for ProcedureRequest target resources
+    ProcedureRequest(resources::ProcedureRequest),
+    /// Variant for DeviceRequest target resources
+    DeviceRequest(resources::DeviceRequest),
+    /// Variant for CommunicationRequest target resources
+    CommunicationRequest(resources::CommunicationRequest),
+}
+impl From<resources::Appointment> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::Appointment) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::Appointment(resource)
+    }
+}
+impl From<resources::ReferralRequest> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::ReferralRequest) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::ReferralRequest(resource)
+    }
+}
+impl From<resources::MedicationRequest> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::MedicationRequest) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::MedicationRequest(resource)
+    }
+}
+impl From<resources::Task> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::Task) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::Task(resource)
+    }
+}
+impl From<resources::NutritionOrder> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::NutritionOrder) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::NutritionOrder(resource)
+    }
+}
+impl From<resources::RequestGroup> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::RequestGroup) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::RequestGroup(resource)
+    }
+}
+impl From<resources::VisionPrescription> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::VisionPrescription) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::VisionPrescription(resource)
+    }
+}
+impl From<resources::ProcedureRequest> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::ProcedureRequest) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::ProcedureRequest(resource)
+    }
+}
+impl From<resources::DeviceRequest> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::DeviceRequest) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::DeviceRequest(resource)
+    }
+}
+impl From<resources::CommunicationRequest> for CarePlanActivityReferenceSearchTarget {
+    fn from(resource: resources::CommunicationRequest) -> CarePlanActivityReferenceSearchTarget {
+        CarePlanActivityReferenceSearchTarget::CommunicationRequest(resource)
+    }
+}
+/// The based-on reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanBasedOnSearchReference;
+impl ReferenceSearchParameter for CarePlanBasedOnSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::CarePlan;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::BasedOn
+    }
+}
+/// The care-team reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanCareTeamSearchReference;
+impl ReferenceSearchParameter for CarePlanCareTeamSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::CareTeam;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::CareTeam
+    }
+}
+/// The condition reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanConditionSearchReference;
+impl ReferenceSearchParameter for CarePlanConditionSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::Condition;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Condition
+    }
+}
+/// The context reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanContextSearchReference;
+impl ReferenceSearchParameter for CarePlanContextSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = CarePlanContextSearchTarget;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the CarePlan resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CarePlanContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for CarePlanContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> CarePlanContextSearchTarget {
+        CarePlanContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for CarePlanContextSearchTarget {
+    fn from(resource: resources::Encounter) -> CarePlanContextSearchTarget {
+        CarePlanContextSearchTarget::Encounter(resource)
+    }
+}
+/// The definition reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanDefinitionSearchReference;
+impl ReferenceSearchParameter for CarePlanDefinitionSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = CarePlanDefinitionSearchTarget;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Definition
+    }
+}
+/// Target resources of the definition reference search parameter of the CarePlan resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CarePlanDefinitionSearchTarget {
+    /// Variant for Questionnaire target resources
+    Questionnaire(resources::Questionnaire),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+}
+impl From<resources::Questionnaire> for CarePlanDefinitionSearchTarget {
+    fn from(resource: resources::Questionnaire) -> CarePlanDefinitionSearchTarget {
+        CarePlanDefinitionSearchTarget::Questionnaire(resource)
+    }
+}
+impl From<resources::PlanDefinition> for CarePlanDefinitionSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> CarePlanDefinitionSearchTarget {
+        CarePlanDefinitionSearchTarget::PlanDefinition(resource)
+    }
+}
+/// The encounter reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanEncounterSearchReference;
+impl ReferenceSearchParameter for CarePlanEncounterSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Encounter
+    }
+}
+/// The goal reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanGoalSearchReference;
+impl ReferenceSearchParameter for CarePlanGoalSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::Goal;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Goal
+    }
+}
+/// The part-of reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanPartOfSearchReference;
+impl ReferenceSearchParameter for CarePlanPartOfSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::CarePlan;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::PartOf
+    }
+}
+/// The performer reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanPerformerSearchReference;
+impl ReferenceSearchParameter for CarePlanPerformerSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = CarePlanPerformerSearchTarget;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the CarePlan resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CarePlanPerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for CarePlanPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> CarePlanPerformerSearchTarget {
+        CarePlanPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for CarePlanPerformerSearchTarget {
+    fn from(resource: resources::Organization) -> CarePlanPerformerSearchTarget {
+        CarePlanPerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for CarePlanPerformerSearchTarget {
+    fn from(resource: resources::CareTeam) -> CarePlanPerformerSearchTarget {
+        CarePlanPerformerSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Patient> for CarePlanPerformerSearchTarget {
+    fn from(resource: resources::Patient) -> CarePlanPerformerSearchTarget {
+        CarePlanPerformerSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CarePlanPerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CarePlanPerformerSearchTarget {
+        CarePlanPerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The replaces reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanReplacesSearchReference;
+impl ReferenceSearchParameter for CarePlanReplacesSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = resources::CarePlan;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Replaces
+    }
+}
+/// The subject reference search parameter of the CarePlan resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CarePlanSubjectSearchReference;
+impl ReferenceSearchParameter for CarePlanSubjectSearchReference {
+    type Resource = resources::CarePlan;
+    type Target = CarePlanSubjectSearchTarget;
+    fn parameter(&self) -> CarePlanSearchParameter {
+        CarePlanSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the CarePlan resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CarePlanSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for CarePlanSubjectSearchTarget {
+    fn from(resource: resources::Group) -> CarePlanSubjectSearchTarget {
+        CarePlanSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for CarePlanSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> CarePlanSubjectSearchTarget {
+        CarePlanSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the CareTeam resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CareTeamPatientSearchReference;
+impl ReferenceSearchParameter for CareTeamPatientSearchReference {
+    type Resource = resources::CareTeam;
+    type Target = CareTeamPatientSearchTarget;
+    fn parameter(&self) -> CareTeamSearchParameter {
+        CareTeamSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the CareTeam resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CareTeamPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for CareTeamPatientSearchTarget {
+    fn from(resource: resources::Patient) -> CareTeamPatientSearchTarget {
+        CareTeamPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for CareTeamPatientSearchTarget {
+    fn from(resource: resources::Group) -> CareTeamPatientSearchTarget {
+        CareTeamPatientSearchTarget::Group(resource)
+    }
+}
+/// The context reference search parameter of the CareTeam resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CareTeamContextSearchReference;
+impl ReferenceSearchParameter for CareTeamContextSearchReference {
+    type Resource = resources::CareTeam;
+    type Target = CareTeamContextSearchTarget;
+    fn parameter(&self) -> CareTeamSearchParameter {
+        CareTeamSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the CareTeam resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CareTeamContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for CareTeamContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> CareTeamContextSearchTarget {
+        CareTeamContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for CareTeamContextSearchTarget {
+    fn from(resource: resources::Encounter) -> CareTeamContextSearchTarget {
+        CareTeamContextSearchTarget::Encounter(resource)
+    }
+}
+/// The encounter reference search parameter of the CareTeam resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CareTeamEncounterSearchReference;
+impl ReferenceSearchParameter for CareTeamEncounterSearchReference {
+    type Resource = resources::CareTeam;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> CareTeamSearchParameter {
+        CareTeamSearchParameter::Encounter
+    }
+}
+/// The participant reference search parameter of the CareTeam resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CareTeamParticipantSearchReference;
+impl ReferenceSearchParameter for CareTeamParticipantSearchReference {
+    type Resource = resources::CareTeam;
+    type Target = CareTeamParticipantSearchTarget;
+    fn parameter(&self) -> CareTeamSearchParameter {
+        CareTeamSearchParameter::Participant
+    }
+}
+/// Target resources of the participant reference search parameter of the CareTeam resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CareTeamParticipantSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for CareTeamParticipantSearchTarget {
+    fn from(resource: resources::Practitioner) -> CareTeamParticipantSearchTarget {
+        CareTeamParticipantSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for CareTeamParticipantSearchTarget {
+    fn from(resource: resources::Organization) -> CareTeamParticipantSearchTarget {
+        CareTeamParticipantSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for CareTeamParticipantSearchTarget {
+    fn from(resource: resources::CareTeam) -> CareTeamParticipantSearchTarget {
+        CareTeamParticipantSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Patient> for CareTeamParticipantSearchTarget {
+    fn from(resource: resources::Patient) -> CareTeamParticipantSearchTarget {
+        CareTeamParticipantSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CareTeamParticipantSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CareTeamParticipantSearchTarget {
+        CareTeamParticipantSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subject reference search parameter of the CareTeam resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CareTeamSubjectSearchReference;
+impl ReferenceSearchParameter for CareTeamSubjectSearchReference {
+    type Resource = resources::CareTeam;
+    type Target = CareTeamSubjectSearchTarget;
+    fn parameter(&self) -> CareTeamSearchParameter {
+        CareTeamSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the CareTeam resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CareTeamSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for CareTeamSubjectSearchTarget {
+    fn from(resource: resources::Group) -> CareTeamSubjectSearchTarget {
+        CareTeamSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for CareTeamSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> CareTeamSubjectSearchTarget {
+        CareTeamSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The account reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemAccountSearchReference;
+impl ReferenceSearchParameter for ChargeItemAccountSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = resources::Account;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::Account
+    }
+}
+/// The context reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemContextSearchReference;
+impl ReferenceSearchParameter for ChargeItemContextSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = ChargeItemContextSearchTarget;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the ChargeItem resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChargeItemContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for ChargeItemContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> ChargeItemContextSearchTarget {
+        ChargeItemContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for ChargeItemContextSearchTarget {
+    fn from(resource: resources::Encounter) -> ChargeItemContextSearchTarget {
+        ChargeItemContextSearchTarget::Encounter(resource)
+    }
+}
+/// The enterer reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemEntererSearchReference;
+impl ReferenceSearchParameter for ChargeItemEntererSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = ChargeItemEntererSearchTarget;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::Enterer
+    }
+}
+/// Target resources of the enterer reference search parameter of the ChargeItem resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChargeItemEntererSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ChargeItemEntererSearchTarget {
+    fn from(resource: resources::Practitioner) -> ChargeItemEntererSearchTarget {
+        ChargeItemEntererSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ChargeItemEntererSearchTarget {
+    fn from(resource: resources::Organization) -> ChargeItemEntererSearchTarget {
+        ChargeItemEntererSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for ChargeItemEntererSearchTarget {
+    fn from(resource: resources::Device) -> ChargeItemEntererSearchTarget {
+        ChargeItemEntererSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ChargeItemEntererSearchTarget {
+    fn from(resource: resources::Patient) -> ChargeItemEntererSearchTarget {
+        ChargeItemEntererSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ChargeItemEntererSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ChargeItemEntererSearchTarget {
+        ChargeItemEntererSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The participant-actor reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemParticipantActorSearchReference;
+impl ReferenceSearchParameter for ChargeItemParticipantActorSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = ChargeItemParticipantActorSearchTarget;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::ParticipantActor
+    }
+}
+/// Target resources of the participant-actor reference search parameter of the ChargeItem resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChargeItemParticipantActorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ChargeItemParticipantActorSearchTarget {
+    fn from(resource: resources::Practitioner) -> ChargeItemParticipantActorSearchTarget {
+        ChargeItemParticipantActorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ChargeItemParticipantActorSearchTarget {
+    fn from(resource: resources::Organization) -> ChargeItemParticipantActorSearchTarget {
+        ChargeItemParticipantActorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for ChargeItemParticipantActorSearchTarget {
+    fn from(resource: resources::Device) -> ChargeItemParticipantActorSearchTarget {
+        ChargeItemParticipantActorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ChargeItemParticipantActorSearchTarget {
+    fn from(resource: resources::Patient) -> ChargeItemParticipantActorSearchTarget {
+        ChargeItemParticipantActorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ChargeItemParticipantActorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ChargeItemParticipantActorSearchTarget {
+        ChargeItemParticipantActorSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The patient reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemPatientSearchReference;
+impl ReferenceSearchParameter for ChargeItemPatientSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::Patient
+    }
+}
+/// The performing-organization reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemPerformingOrganizationSearchReference;
+impl ReferenceSearchParameter for ChargeItemPerformingOrganizationSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::PerformingOrganization
+    }
+}
+/// The requesting-organization reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemRequestingOrganizationSearchReference;
+impl ReferenceSearchParameter for ChargeItemRequestingOrganizationSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::RequestingOrganization
+    }
+}
+/// The service reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemServiceSearchReference;
+impl ReferenceSearchParameter for ChargeItemServiceSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = ChargeItemServiceSearchTarget;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::Service
+    }
+}
+/// Target resources of the service reference search parameter of the ChargeItem resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChargeItemServiceSearchTarget {
+    /// Variant for Immunization target resources
+    Immunization(resources::Immunization),
+    /// Variant for MedicationDispense target resources
+    MedicationDispense(resources::MedicationDispense),
+    /// Variant for SupplyDelivery target resources
+    SupplyDelivery(resources::SupplyDelivery),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for DiagnosticReport target resources
+    DiagnosticReport(resources::DiagnosticReport),
+    /// Variant for ImagingStudy target resources
+    ImagingStudy(resources::ImagingStudy),
+    /// Variant for MedicationAdministration target resources
+    MedicationAdministration(resources::MedicationAdministration),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+}
+impl From<resources::Immunization> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::Immunization) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::Immunization(resource)
+    }
+}
+impl From<resources::MedicationDispense> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::MedicationDispense) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::MedicationDispense(resource)
+    }
+}
+impl From<resources::SupplyDelivery> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::SupplyDelivery) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::SupplyDelivery(resource)
+    }
+}
+impl From<resources::Observation> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::Observation) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::DiagnosticReport> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::DiagnosticReport) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::DiagnosticReport(resource)
+    }
+}
+impl From<resources::ImagingStudy> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::ImagingStudy) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::ImagingStudy(resource)
+    }
+}
+impl From<resources::MedicationAdministration> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::MedicationAdministration) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::MedicationAdministration(resource)
+    }
+}
+impl From<resources::Procedure> for ChargeItemServiceSearchTarget {
+    fn from(resource: resources::Procedure) -> ChargeItemServiceSearchTarget {
+        ChargeItemServiceSearchTarget::Procedure(resource)
+    }
+}
+/// The subject reference search parameter of the ChargeItem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChargeItemSubjectSearchReference;
+impl ReferenceSearchParameter for ChargeItemSubjectSearchReference {
+    type Resource = resources::ChargeItem;
+    type Target = ChargeItemSubjectSearchTarget;
+    fn parameter(&self) -> ChargeItemSearchParameter {
+        ChargeItemSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the ChargeItem resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ChargeItemSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for ChargeItemSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ChargeItemSubjectSearchTarget {
+        ChargeItemSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for ChargeItemSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ChargeItemSubjectSearchTarget {
+        ChargeItemSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The care-team reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimCareTeamSearchReference;
+impl ReferenceSearchParameter for ClaimCareTeamSearchReference {
+    type Resource = resources::Claim;
+    type Target = ClaimCareTeamSearchTarget;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::CareTeam
+    }
+}
+/// Target resources of the care-team reference search parameter of the Claim resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClaimCareTeamSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+}
+impl From<resources::Practitioner> for ClaimCareTeamSearchTarget {
+    fn from(resource: resources::Practitioner) -> ClaimCareTeamSearchTarget {
+        ClaimCareTeamSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ClaimCareTeamSearchTarget {
+    fn from(resource: resources::Organization) -> ClaimCareTeamSearchTarget {
+        ClaimCareTeamSearchTarget::Organization(resource)
+    }
+}
+/// The encounter reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimEncounterSearchReference;
+impl ReferenceSearchParameter for ClaimEncounterSearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Encounter
+    }
+}
+/// The enterer reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimEntererSearchReference;
+impl ReferenceSearchParameter for ClaimEntererSearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Enterer
+    }
+}
+/// The facility reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimFacilitySearchReference;
+impl ReferenceSearchParameter for ClaimFacilitySearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Location;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Facility
+    }
+}
+/// The insurer reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimInsurerSearchReference;
+impl ReferenceSearchParameter for ClaimInsurerSearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Insurer
+    }
+}
+/// The organization reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimOrganizationSearchReference;
+impl ReferenceSearchParameter for ClaimOrganizationSearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Organization
+    }
+}
+/// The patient reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimPatientSearchReference;
+impl ReferenceSearchParameter for ClaimPatientSearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Patient
+    }
+}
+/// The payee reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimPayeeSearchReference;
+impl ReferenceSearchParameter for ClaimPayeeSearchReference {
+    type Resource = resources::Claim;
+    type Target = ClaimPayeeSearchTarget;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Payee
+    }
+}
+/// Target resources of the payee reference search parameter of the Claim resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClaimPayeeSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ClaimPayeeSearchTarget {
+    fn from(resource: resources::Practitioner) -> ClaimPayeeSearchTarget {
+        ClaimPayeeSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ClaimPayeeSearchTarget {
+    fn from(resource: resources::Organization) -> ClaimPayeeSearchTarget {
+        ClaimPayeeSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for ClaimPayeeSearchTarget {
+    fn from(resource: resources::Patient) -> ClaimPayeeSearchTarget {
+        ClaimPayeeSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ClaimPayeeSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ClaimPayeeSearchTarget {
+        ClaimPayeeSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The provider reference search parameter of the Claim resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimProviderSearchReference;
+impl ReferenceSearchParameter for ClaimProviderSearchReference {
+    type Resource = resources::Claim;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> ClaimSearchParameter {
+        ClaimSearchParameter::Provider
+    }
+}
+/// The insurer reference search parameter of the ClaimResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimResponseInsurerSearchReference;
+impl ReferenceSearchParameter for ClaimResponseInsurerSearchReference {
+    type Resource = resources::ClaimResponse;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ClaimResponseSearchParameter {
+        ClaimResponseSearchParameter::Insurer
+    }
+}
+/// The patient reference search parameter of the ClaimResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimResponsePatientSearchReference;
+impl ReferenceSearchParameter for ClaimResponsePatientSearchReference {
+    type Resource = resources::ClaimResponse;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ClaimResponseSearchParameter {
+        ClaimResponseSearchParameter::Patient
+    }
+}
+/// The request reference search parameter of the ClaimResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimResponseRequestSearchReference;
+impl ReferenceSearchParameter for ClaimResponseRequestSearchReference {
+    type Resource = resources::ClaimResponse;
+    type Target = resources::Claim;
+    fn parameter(&self) -> ClaimResponseSearchParameter {
+        ClaimResponseSearchParameter::Request
+    }
+}
+/// The request-provider reference search parameter of the ClaimResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClaimResponseRequestProviderSearchReference;
+impl ReferenceSearchParameter for ClaimResponseRequestProviderSearchReference {
+    type Resource = resources::ClaimResponse;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> ClaimResponseSearchParameter {
+        ClaimResponseSearchParameter::RequestProvider
+    }
+}
+/// The patient reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionPatientSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionPatientSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = ClinicalImpressionPatientSearchTarget;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the ClinicalImpression resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClinicalImpressionPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for ClinicalImpressionPatientSearchTarget {
+    fn from(resource: resources::Patient) -> ClinicalImpressionPatientSearchTarget {
+        ClinicalImpressionPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for ClinicalImpressionPatientSearchTarget {
+    fn from(resource: resources::Group) -> ClinicalImpressionPatientSearchTarget {
+        ClinicalImpressionPatientSearchTarget::Group(resource)
+    }
+}
+/// The action reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionActionSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionActionSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = ClinicalImpressionActionSearchTarget;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Action
+    }
+}
+/// Target resources of the action reference search parameter of the ClinicalImpression resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClinicalImpressionActionSearchTarget {
+    /// Variant for Appointment target resources
+    Appointment(resources::Appointment),
+    /// Variant for ReferralRequest target resources
+    ReferralRequest(resources::ReferralRequest),
+    /// Variant for MedicationRequest target resources
+    MedicationRequest(resources::MedicationRequest),
+    /// Variant for ProcedureRequest target resources
+    ProcedureRequest(resources::ProcedureRequest),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+}
+impl From<resources::Appointment> for ClinicalImpressionActionSearchTarget {
+    fn from(resource: resources::Appointment) -> ClinicalImpressionActionSearchTarget {
+        ClinicalImpressionActionSearchTarget::Appointment(resource)
+    }
+}
+impl From<resources::ReferralRequest> for ClinicalImpressionActionSearchTarget {
+    fn from(resource: resources::ReferralRequest) -> ClinicalImpressionActionSearchTarget {
+        ClinicalImpressionActionSearchTarget::ReferralRequest(resource)
+    }
+}
+impl From<resources::MedicationRequest> for ClinicalImpressionActionSearchTarget {
+    fn from(resource: resources::MedicationRequest) -> ClinicalImpressionActionSearchTarget {
+        ClinicalImpressionActionSearchTarget::MedicationRequest(resource)
+    }
+}
+impl From<resources::ProcedureRequest> for ClinicalImpressionActionSearchTarget {
+    fn from(resource: resources::ProcedureRequest) -> ClinicalImpressionActionSearchTarget {
+        ClinicalImpressionActionSearchTarget::ProcedureRequest(resource)
+    }
+}
+impl From<resources::Procedure> for ClinicalImpressionActionSearchTarget {
+    fn from(resource: resources::Procedure) -> ClinicalImpressionActionSearchTarget {
+        ClinicalImpressionActionSearchTarget::Procedure(resource)
+    }
+}
+/// The assessor reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionAssessorSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionAssessorSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Assessor
+    }
+}
+/// The context reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionContextSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionContextSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = ClinicalImpressionContextSearchTarget;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the ClinicalImpression resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClinicalImpressionContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for ClinicalImpressionContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> ClinicalImpressionContextSearchTarget {
+        ClinicalImpressionContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for ClinicalImpressionContextSearchTarget {
+    fn from(resource: resources::Encounter) -> ClinicalImpressionContextSearchTarget {
+        ClinicalImpressionContextSearchTarget::Encounter(resource)
+    }
+}
+/// The finding-ref reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionFindingRefSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionFindingRefSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = ClinicalImpressionFindingRefSearchTarget;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::FindingRef
+    }
+}
+/// Target resources of the finding-ref reference search parameter of the ClinicalImpression resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClinicalImpressionFindingRefSearchTarget {
+    /// Variant for Condition target resources
+    Condition(resources::Condition),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+}
+impl From<resources::Condition> for ClinicalImpressionFindingRefSearchTarget {
+    fn from(resource: resources::Condition) -> ClinicalImpressionFindingRefSearchTarget {
+        ClinicalImpressionFindingRefSearchTarget::Condition(resource)
+    }
+}
+impl From<resources::Observation> for ClinicalImpressionFindingRefSearchTarget {
+    fn from(resource: resources::Observation) -> ClinicalImpressionFindingRefSearchTarget {
+        ClinicalImpressionFindingRefSearchTarget::Observation(resource)
+    }
+}
+/// The investigation reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionInvestigationSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionInvestigationSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = ClinicalImpressionInvestigationSearchTarget;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Investigation
+    }
+}
+/// Target resources of the investigation reference search parameter of the ClinicalImpression resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClinicalImpressionInvestigationSearchTarget {
+    /// Variant for RiskAssessment target resources
+    RiskAssessment(resources::RiskAssessment),
+    /// Variant for FamilyMemberHistory target resources
+    FamilyMemberHistory(resources::FamilyMemberHistory),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for DiagnosticReport target resources
+    DiagnosticReport(resources::DiagnosticReport),
+    /// Variant for ImagingStudy target resources
+    ImagingStudy(resources::ImagingStudy),
+    /// Variant for QuestionnaireResponse target resources
+    QuestionnaireResponse(resources::QuestionnaireResponse),
+}
+impl From<resources::RiskAssessment> for ClinicalImpressionInvestigationSearchTarget {
+    fn from(resource: resources::RiskAssessment) -> ClinicalImpressionInvestigationSearchTarget {
+        ClinicalImpressionInvestigationSearchTarget::RiskAssessment(resource)
+    }
+}
+impl From<resources::FamilyMemberHistory> for ClinicalImpressionInvestigationSearchTarget {
+    fn from(resource: resources::FamilyMemberHistory) -> ClinicalImpressionInvestigationSearchTarget {
+        ClinicalImpressionInvestigationSearchTarget::FamilyMemberHistory(resource)
+    }
+}
+impl From<resources::Observation> for ClinicalImpressionInvestigationSearchTarget {
+    fn from(resource: resources::Observation) -> ClinicalImpressionInvestigationSearchTarget {
+        ClinicalImpressionInvestigationSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::DiagnosticReport> for ClinicalImpressionInvestigationSearchTarget {
+    fn from(resource: resources::DiagnosticReport) -> ClinicalImpressionInvestigationSearchTarget {
+        ClinicalImpressionInvestigationSearchTarget::DiagnosticReport(resource)
+    }
+}
+impl From<resources::ImagingStudy> for ClinicalImpressionInvestigationSearchTarget {
+    fn from(resource: resources::ImagingStudy) -> ClinicalImpressionInvestigationSearchTarget {
+        ClinicalImpressionInvestigationSearchTarget::ImagingStudy(resource)
+    }
+}
+impl From<resources::QuestionnaireResponse> for ClinicalImpressionInvestigationSearchTarget {
+    fn from(resource: resources::QuestionnaireResponse) -> ClinicalImpressionInvestigationSearchTarget {
+        ClinicalImpressionInvestigationSearchTarget::QuestionnaireResponse(resource)
+    }
+}
+/// The previous reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionPreviousSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionPreviousSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = resources::ClinicalImpression;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Previous
+    }
+}
+/// The problem reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionProblemSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionProblemSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = ClinicalImpressionProblemSearchTarget;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Problem
+    }
+}
+/// Target resources of the problem reference search parameter of the ClinicalImpression resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClinicalImpressionProblemSearchTarget {
+    /// Variant for Condition target resources
+    Condition(resources::Condition),
+    /// Variant for AllergyIntolerance target resources
+    AllergyIntolerance(resources::AllergyIntolerance),
+}
+impl From<resources::Condition> for ClinicalImpressionProblemSearchTarget {
+    fn from(resource: resources::Condition) -> ClinicalImpressionProblemSearchTarget {
+        ClinicalImpressionProblemSearchTarget::Condition(resource)
+    }
+}
+impl From<resources::AllergyIntolerance> for ClinicalImpressionProblemSearchTarget {
+    fn from(resource: resources::AllergyIntolerance) -> ClinicalImpressionProblemSearchTarget {
+        ClinicalImpressionProblemSearchTarget::AllergyIntolerance(resource)
+    }
+}
+/// The subject reference search parameter of the ClinicalImpression resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ClinicalImpressionSubjectSearchReference;
+impl ReferenceSearchParameter for ClinicalImpressionSubjectSearchReference {
+    type Resource = resources::ClinicalImpression;
+    type Target = ClinicalImpressionSubjectSearchTarget;
+    fn parameter(&self) -> ClinicalImpressionSearchParameter {
+        ClinicalImpressionSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the ClinicalImpression resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ClinicalImpressionSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for ClinicalImpressionSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ClinicalImpressionSubjectSearchTarget {
+        ClinicalImpressionSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for ClinicalImpressionSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ClinicalImpressionSubjectSearchTarget {
+        ClinicalImpressionSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The based-on reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationBasedOnSearchReference;
+impl ReferenceSearchParameter for CommunicationBasedOnSearchReference {
+    type Resource = resources::Communication;
+    type Target = resources::Resource;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::BasedOn
+    }
+}
+/// The context reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationContextSearchReference;
+impl ReferenceSearchParameter for CommunicationContextSearchReference {
+    type Resource = resources::Communication;
+    type Target = CommunicationContextSearchTarget;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the Communication resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for CommunicationContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> CommunicationContextSearchTarget {
+        CommunicationContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for CommunicationContextSearchTarget {
+    fn from(resource: resources::Encounter) -> CommunicationContextSearchTarget {
+        CommunicationContextSearchTarget::Encounter(resource)
+    }
+}
+/// The definition reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationDefinitionSearchReference;
+impl ReferenceSearchParameter for CommunicationDefinitionSearchReference {
+    type Resource = resources::Communication;
+    type Target = CommunicationDefinitionSearchTarget;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::Definition
+    }
+}
+/// Target resources of the definition reference search parameter of the Communication resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationDefinitionSearchTarget {
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+}
+impl From<resources::PlanDefinition> for CommunicationDefinitionSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> CommunicationDefinitionSearchTarget {
+        CommunicationDefinitionSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for CommunicationDefinitionSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> CommunicationDefinitionSearchTarget {
+        CommunicationDefinitionSearchTarget::ActivityDefinition(resource)
+    }
+}
+/// The encounter reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationEncounterSearchReference;
+impl ReferenceSearchParameter for CommunicationEncounterSearchReference {
+    type Resource = resources::Communication;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::Encounter
+    }
+}
+/// The part-of reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationPartOfSearchReference;
+impl ReferenceSearchParameter for CommunicationPartOfSearchReference {
+    type Resource = resources::Communication;
+    type Target = resources::Resource;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::PartOf
+    }
+}
+/// The patient reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationPatientSearchReference;
+impl ReferenceSearchParameter for CommunicationPatientSearchReference {
+    type Resource = resources::Communication;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::Patient
+    }
+}
+/// The recipient reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRecipientSearchReference;
+impl ReferenceSearchParameter for CommunicationRecipientSearchReference {
+    type Resource = resources::Communication;
+    type Target = CommunicationRecipientSearchTarget;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::Recipient
+    }
+}
+/// Target resources of the recipient reference search parameter of the Communication resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationRecipientSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::Practitioner) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::Group) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Organization> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::Organization) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::Device) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CommunicationRecipientSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CommunicationRecipientSearchTarget {
+        CommunicationRecipientSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The sender reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationSenderSearchReference;
+impl ReferenceSearchParameter for CommunicationSenderSearchReference {
+    type Resource = resources::Communication;
+    type Target = CommunicationSenderSearchTarget;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::Sender
+    }
+}
+/// Target resources of the sender reference search parameter of the Communication resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationSenderSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::Practitioner) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::Organization) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::Device) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CommunicationSenderSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CommunicationSenderSearchTarget {
+        CommunicationSenderSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subject reference search parameter of the Communication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationSubjectSearchReference;
+impl ReferenceSearchParameter for CommunicationSubjectSearchReference {
+    type Resource = resources::Communication;
+    type Target = CommunicationSubjectSearchTarget;
+    fn parameter(&self) -> CommunicationSearchParameter {
+        CommunicationSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Communication resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for CommunicationSubjectSearchTarget {
+    fn from(resource: resources::Group) -> CommunicationSubjectSearchTarget {
+        CommunicationSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationSubjectSearchTarget {
+        CommunicationSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The based-on reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestBasedOnSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestBasedOnSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = resources::Resource;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::BasedOn
+    }
+}
+/// The context reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestContextSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestContextSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = CommunicationRequestContextSearchTarget;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the CommunicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationRequestContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for CommunicationRequestContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> CommunicationRequestContextSearchTarget {
+        CommunicationRequestContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for CommunicationRequestContextSearchTarget {
+    fn from(resource: resources::Encounter) -> CommunicationRequestContextSearchTarget {
+        CommunicationRequestContextSearchTarget::Encounter(resource)
+    }
+}
+/// The encounter reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestEncounterSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestEncounterSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Encounter
+    }
+}
+/// The patient reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestPatientSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestPatientSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Patient
+    }
+}
+/// The recipient reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestRecipientSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestRecipientSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = CommunicationRequestRecipientSearchTarget;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Recipient
+    }
+}
+/// Target resources of the recipient reference search parameter of the CommunicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationRequestRecipientSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::Practitioner) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::Group) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Organization> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::Organization) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::CareTeam) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::Device) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CommunicationRequestRecipientSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CommunicationRequestRecipientSearchTarget {
+        CommunicationRequestRecipientSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The replaces reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestReplacesSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestReplacesSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = resources::CommunicationRequest;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Replaces
+    }
+}
+/// The requester reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestRequesterSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestRequesterSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = CommunicationRequestRequesterSearchTarget;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Requester
+    }
+}
+/// Target resources of the requester reference search parameter of the CommunicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationRequestRequesterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for CommunicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> CommunicationRequestRequesterSearchTarget {
+        CommunicationRequestRequesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for CommunicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Organization) -> CommunicationRequestRequesterSearchTarget {
+        CommunicationRequestRequesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for CommunicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Device) -> CommunicationRequestRequesterSearchTarget {
+        CommunicationRequestRequesterSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationRequestRequesterSearchTarget {
+        CommunicationRequestRequesterSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CommunicationRequestRequesterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CommunicationRequestRequesterSearchTarget {
+        CommunicationRequestRequesterSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The sender reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestSenderSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestSenderSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = CommunicationRequestSenderSearchTarget;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Sender
+    }
+}
+/// Target resources of the sender reference search parameter of the CommunicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationRequestSenderSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for CommunicationRequestSenderSearchTarget {
+    fn from(resource: resources::Practitioner) -> CommunicationRequestSenderSearchTarget {
+        CommunicationRequestSenderSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for CommunicationRequestSenderSearchTarget {
+    fn from(resource: resources::Organization) -> CommunicationRequestSenderSearchTarget {
+        CommunicationRequestSenderSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for CommunicationRequestSenderSearchTarget {
+    fn from(resource: resources::Device) -> CommunicationRequestSenderSearchTarget {
+        CommunicationRequestSenderSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationRequestSenderSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationRequestSenderSearchTarget {
+        CommunicationRequestSenderSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CommunicationRequestSenderSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CommunicationRequestSenderSearchTarget {
+        CommunicationRequestSenderSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subject reference search parameter of the CommunicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CommunicationRequestSubjectSearchReference;
+impl ReferenceSearchParameter for CommunicationRequestSubjectSearchReference {
+    type Resource = resources::CommunicationRequest;
+    type Target = CommunicationRequestSubjectSearchTarget;
+    fn parameter(&self) -> CommunicationRequestSearchParameter {
+        CommunicationRequestSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the CommunicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CommunicationRequestSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for CommunicationRequestSubjectSearchTarget {
+    fn from(resource: resources::Group) -> CommunicationRequestSubjectSearchTarget {
+        CommunicationRequestSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for CommunicationRequestSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> CommunicationRequestSubjectSearchTarget {
+        CommunicationRequestSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionPatientSearchReference;
+impl ReferenceSearchParameter for CompositionPatientSearchReference {
+    type Resource = resources::Composition;
+    type Target = CompositionPatientSearchTarget;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the Composition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CompositionPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for CompositionPatientSearchTarget {
+    fn from(resource: resources::Patient) -> CompositionPatientSearchTarget {
+        CompositionPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for CompositionPatientSearchTarget {
+    fn from(resource: resources::Group) -> CompositionPatientSearchTarget {
+        CompositionPatientSearchTarget::Group(resource)
+    }
+}
+/// The attester reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionAttesterSearchReference;
+impl ReferenceSearchParameter for CompositionAttesterSearchReference {
+    type Resource = resources::Composition;
+    type Target = CompositionAttesterSearchTarget;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::Attester
+    }
+}
+/// Target resources of the attester reference search parameter of the Composition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CompositionAttesterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Practitioner> for CompositionAttesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> CompositionAttesterSearchTarget {
+        CompositionAttesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for CompositionAttesterSearchTarget {
+    fn from(resource: resources::Organization) -> CompositionAttesterSearchTarget {
+        CompositionAttesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for CompositionAttesterSearchTarget {
+    fn from(resource: resources::Patient) -> CompositionAttesterSearchTarget {
+        CompositionAttesterSearchTarget::Patient(resource)
+    }
+}
+/// The author reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionAuthorSearchReference;
+impl ReferenceSearchParameter for CompositionAuthorSearchReference {
+    type Resource = resources::Composition;
+    type Target = CompositionAuthorSearchTarget;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the Composition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CompositionAuthorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for CompositionAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> CompositionAuthorSearchTarget {
+        CompositionAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Device> for CompositionAuthorSearchTarget {
+    fn from(resource: resources::Device) -> CompositionAuthorSearchTarget {
+        CompositionAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for CompositionAuthorSearchTarget {
+    fn from(resource: resources::Patient) -> CompositionAuthorSearchTarget {
+        CompositionAuthorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CompositionAuthorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CompositionAuthorSearchTarget {
+        CompositionAuthorSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The encounter reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionEncounterSearchReference;
+impl ReferenceSearchParameter for CompositionEncounterSearchReference {
+    type Resource = resources::Composition;
+    type Target = CompositionEncounterSearchTarget;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::Encounter
+    }
+}
+/// Target resources of the encounter reference search parameter of the Composition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CompositionEncounterSearchTarget {
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+}
+impl From<resources::Encounter> for CompositionEncounterSearchTarget {
+    fn from(resource: resources::Encounter) -> CompositionEncounterSearchTarget {
+        CompositionEncounterSearchTarget::Encounter(resource)
+    }
+}
+impl From<resources::EpisodeOfCare> for CompositionEncounterSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> CompositionEncounterSearchTarget {
+        CompositionEncounterSearchTarget::EpisodeOfCare(resource)
+    }
+}
+/// The entry reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionEntrySearchReference;
+impl ReferenceSearchParameter for CompositionEntrySearchReference {
+    type Resource = resources::Composition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::Entry
+    }
+}
+/// The related-ref reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionRelatedRefSearchReference;
+impl ReferenceSearchParameter for CompositionRelatedRefSearchReference {
+    type Resource = resources::Composition;
+    type Target = resources::Composition;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::RelatedRef
+    }
+}
+/// The subject reference search parameter of the Composition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CompositionSubjectSearchReference;
+impl ReferenceSearchParameter for CompositionSubjectSearchReference {
+    type Resource = resources::Composition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> CompositionSearchParameter {
+        CompositionSearchParameter::Subject
+    }
+}
+/// The source reference search parameter of the ConceptMap resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConceptMapSourceSearchReference;
+impl ReferenceSearchParameter for ConceptMapSourceSearchReference {
+    type Resource = resources::ConceptMap;
+    type Target = resources::ValueSet;
+    fn parameter(&self) -> ConceptMapSearchParameter {
+        ConceptMapSearchParameter::Source
+    }
+}
+/// The source-uri reference search parameter of the ConceptMap resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConceptMapSourceUriSearchReference;
+impl ReferenceSearchParameter for ConceptMapSourceUriSearchReference {
+    type Resource = resources::ConceptMap;
+    type Target = resources::ValueSet;
+    fn parameter(&self) -> ConceptMapSearchParameter {
+        ConceptMapSearchParameter::SourceUri
+    }
+}
+/// The target reference search parameter of the ConceptMap resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConceptMapTargetSearchReference;
+impl ReferenceSearchParameter for ConceptMapTargetSearchReference {
+    type Resource = resources::ConceptMap;
+    type Target = resources::ValueSet;
+    fn parameter(&self) -> ConceptMapSearchParameter {
+        ConceptMapSearchParameter::Target
+    }
+}
+/// The target-uri reference search parameter of the ConceptMap resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConceptMapTargetUriSearchReference;
+impl ReferenceSearchParameter for ConceptMapTargetUriSearchReference {
+    type Resource = resources::ConceptMap;
+    type Target = resources::ValueSet;
+    fn parameter(&self) -> ConceptMapSearchParameter {
+        ConceptMapSearchParameter::TargetUri
+    }
+}
+/// The patient reference search parameter of the Condition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConditionPatientSearchReference;
+impl ReferenceSearchParameter for ConditionPatientSearchReference {
+    type Resource = resources::Condition;
+    type Target = ConditionPatientSearchTarget;
+    fn parameter(&self) -> ConditionSearchParameter {
+        ConditionSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the Condition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConditionPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for ConditionPatientSearchTarget {
+    fn from(resource: resources::Patient) -> ConditionPatientSearchTarget {
+        ConditionPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for ConditionPatientSearchTarget {
+    fn from(resource: resources::Group) -> ConditionPatientSearchTarget {
+        ConditionPatientSearchTarget::Group(resource)
+    }
+}
+/// The asserter reference search parameter of the Condition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConditionAsserterSearchReference;
+impl ReferenceSearchParameter for ConditionAsserterSearchReference {
+    type Resource = resources::Condition;
+    type Target = ConditionAsserterSearchTarget;
+    fn parameter(&self) -> ConditionSearchParameter {
+        ConditionSearchParameter::Asserter
+    }
+}
+/// Target resources of the asserter reference search parameter of the Condition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConditionAsserterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ConditionAsserterSearchTarget {
+    fn from(resource: resources::Practitioner) -> ConditionAsserterSearchTarget {
+        ConditionAsserterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for ConditionAsserterSearchTarget {
+    fn from(resource: resources::Patient) -> ConditionAsserterSearchTarget {
+        ConditionAsserterSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ConditionAsserterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ConditionAsserterSearchTarget {
+        ConditionAsserterSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The context reference search parameter of the Condition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConditionContextSearchReference;
+impl ReferenceSearchParameter for ConditionContextSearchReference {
+    type Resource = resources::Condition;
+    type Target = ConditionContextSearchTarget;
+    fn parameter(&self) -> ConditionSearchParameter {
+        ConditionSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the Condition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConditionContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for ConditionContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> ConditionContextSearchTarget {
+        ConditionContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for ConditionContextSearchTarget {
+    fn from(resource: resources::Encounter) -> ConditionContextSearchTarget {
+        ConditionContextSearchTarget::Encounter(resource)
+    }
+}
+/// The encounter reference search parameter of the Condition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConditionEncounterSearchReference;
+impl ReferenceSearchParameter for ConditionEncounterSearchReference {
+    type Resource = resources::Condition;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ConditionSearchParameter {
+        ConditionSearchParameter::Encounter
+    }
+}
+/// The evidence-detail reference search parameter of the Condition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConditionEvidenceDetailSearchReference;
+impl ReferenceSearchParameter for ConditionEvidenceDetailSearchReference {
+    type Resource = resources::Condition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ConditionSearchParameter {
+        ConditionSearchParameter::EvidenceDetail
+    }
+}
+/// The subject reference search parameter of the Condition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConditionSubjectSearchReference;
+impl ReferenceSearchParameter for ConditionSubjectSearchReference {
+    type Resource = resources::Condition;
+    type Target = ConditionSubjectSearchTarget;
+    fn parameter(&self) -> ConditionSearchParameter {
+        ConditionSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Condition resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConditionSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for ConditionSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ConditionSubjectSearchTarget {
+        ConditionSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for ConditionSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ConditionSubjectSearchTarget {
+        ConditionSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the Consent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsentPatientSearchReference;
+impl ReferenceSearchParameter for ConsentPatientSearchReference {
+    type Resource = resources::Consent;
+    type Target = ConsentPatientSearchTarget;
+    fn parameter(&self) -> ConsentSearchParameter {
+        ConsentSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the Consent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConsentPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for ConsentPatientSearchTarget {
+    fn from(resource: resources::Patient) -> ConsentPatientSearchTarget {
+        ConsentPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for ConsentPatientSearchTarget {
+    fn from(resource: resources::Group) -> ConsentPatientSearchTarget {
+        ConsentPatientSearchTarget::Group(resource)
+    }
+}
+/// The actor reference search parameter of the Consent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsentActorSearchReference;
+impl ReferenceSearchParameter for ConsentActorSearchReference {
+    type Resource = resources::Consent;
+    type Target = ConsentActorSearchTarget;
+    fn parameter(&self) -> ConsentSearchParameter {
+        ConsentSearchParameter::Actor
+    }
+}
+/// Target resources of the actor reference search parameter of the Consent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConsentActorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for CareTeam target resources
+    CareTeam(resources::CareTeam),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ConsentActorSearchTarget {
+    fn from(resource: resources::Practitioner) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for ConsentActorSearchTarget {
+    fn from(resource: resources::Group) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Organization> for ConsentActorSearchTarget {
+    fn from(resource: resources::Organization) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::CareTeam> for ConsentActorSearchTarget {
+    fn from(resource: resources::CareTeam) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::CareTeam(resource)
+    }
+}
+impl From<resources::Device> for ConsentActorSearchTarget {
+    fn from(resource: resources::Device) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ConsentActorSearchTarget {
+    fn from(resource: resources::Patient) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ConsentActorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ConsentActorSearchTarget {
+        ConsentActorSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The consentor reference search parameter of the Consent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsentConsentorSearchReference;
+impl ReferenceSearchParameter for ConsentConsentorSearchReference {
+    type Resource = resources::Consent;
+    type Target = ConsentConsentorSearchTarget;
+    fn parameter(&self) -> ConsentSearchParameter {
+        ConsentSearchParameter::Consentor
+    }
+}
+/// Target resources of the consentor reference search parameter of the Consent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConsentConsentorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ConsentConsentorSearchTarget {
+    fn from(resource: resources::Practitioner) -> ConsentConsentorSearchTarget {
+        ConsentConsentorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ConsentConsentorSearchTarget {
+    fn from(resource: resources::Organization) -> ConsentConsentorSearchTarget {
+        ConsentConsentorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for ConsentConsentorSearchTarget {
+    fn from(resource: resources::Patient) -> ConsentConsentorSearchTarget {
+        ConsentConsentorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ConsentConsentorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ConsentConsentorSearchTarget {
+        ConsentConsentorSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The data reference search parameter of the Consent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsentDataSearchReference;
+impl ReferenceSearchParameter for ConsentDataSearchReference {
+    type Resource = resources::Consent;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ConsentSearchParameter {
+        ConsentSearchParameter::Data
+    }
+}
+/// The organization reference search parameter of the Consent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsentOrganizationSearchReference;
+impl ReferenceSearchParameter for ConsentOrganizationSearchReference {
+    type Resource = resources::Consent;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ConsentSearchParameter {
+        ConsentSearchParameter::Organization
+    }
+}
+/// The source reference search parameter of the Consent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ConsentSourceSearchReference;
+impl ReferenceSearchParameter for ConsentSourceSearchReference {
+    type Resource = resources::Consent;
+    type Target = ConsentSourceSearchTarget;
+    fn parameter(&self) -> ConsentSearchParameter {
+        ConsentSearchParameter::Source
+    }
+}
+/// Target resources of the source reference search parameter of the Consent resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConsentSourceSearchTarget {
+    /// Variant for Consent target resources
+    Consent(resources::Consent),
+    /// Variant for Contract target resources
+    Contract(resources::Contract),
+    /// Variant for QuestionnaireResponse target resources
+    QuestionnaireResponse(resources::QuestionnaireResponse),
+    /// Variant for DocumentReference target resources
+    DocumentReference(resources::DocumentReference),
+}
+impl From<resources::Consent> for ConsentSourceSearchTarget {
+    fn from(resource: resources::Consent) -> ConsentSourceSearchTarget {
+        ConsentSourceSearchTarget::Consent(resource)
+    }
+}
+impl From<resources::Contract> for ConsentSourceSearchTarget {
+    fn from(resource: resources::Contract) -> ConsentSourceSearchTarget {
+        ConsentSourceSearchTarget::Contract(resource)
+    }
+}
+impl From<resources::QuestionnaireResponse> for ConsentSourceSearchTarget {
+    fn from(resource: resources::QuestionnaireResponse) -> ConsentSourceSearchTarget {
+        ConsentSourceSearchTarget::QuestionnaireResponse(resource)
+    }
+}
+impl From<resources::DocumentReference> for ConsentSourceSearchTarget {
+    fn from(resource: resources::DocumentReference) -> ConsentSourceSearchTarget {
+        ConsentSourceSearchTarget::DocumentReference(resource)
+    }
+}
+/// The agent reference search parameter of the Contract resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContractAgentSearchReference;
+impl ReferenceSearchParameter for ContractAgentSearchReference {
+    type Resource = resources::Contract;
+    type Target = ContractAgentSearchTarget;
+    fn parameter(&self) -> ContractSearchParameter {
+        ContractSearchParameter::Agent
+    }
+}
+/// Target resources of the agent reference search parameter of the Contract resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ContractAgentSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+    /// Variant for Contract target resources
+    Contract(resources::Contract),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Practitioner> for ContractAgentSearchTarget {
+    fn from(resource: resources::Practitioner) -> ContractAgentSearchTarget {
+        ContractAgentSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for ContractAgentSearchTarget {
+    fn from(resource: resources::Group) -> ContractAgentSearchTarget {
+        ContractAgentSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Organization> for ContractAgentSearchTarget {
+    fn from(resource: resources::Organization) -> ContractAgentSearchTarget {
+        ContractAgentSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for ContractAgentSearchTarget {
+    fn from(resource: resources::Device) -> ContractAgentSearchTarget {
+        ContractAgentSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ContractAgentSearchTarget {
+    fn from(resource: resources::Patient) -> ContractAgentSearchTarget {
+        ContractAgentSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Substance> for ContractAgentSearchTarget {
+    fn from(resource: resources::Substance) -> ContractAgentSearchTarget {
+        ContractAgentSearchTarget::Substance(resource)
+    }
+}
+impl From<resources::Contract> for ContractAgentSearchTarget {
+    fn from(resource: resources::Contract) -> ContractAgentSearchTarget {
+        ContractAgentSearchTarget::Contract(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ContractAgentSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ContractAgentSearchTarget {
+        ContractAgentSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::Location> for ContractAgentSearchTarget {
+    fn from(resource: resources::Location) -> ContractAgentSearchTarget {
+        ContractAgentSearchTarget::Location(resource)
+    }
+}
+/// The authority reference search parameter of the Contract resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContractAuthoritySearchReference;
+impl ReferenceSearchParameter for ContractAuthoritySearchReference {
+    type Resource = resources::Contract;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ContractSearchParameter {
+        ContractSearchParameter::Authority
+    }
+}
+/// The domain reference search parameter of the Contract resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContractDomainSearchReference;
+impl ReferenceSearchParameter for ContractDomainSearchReference {
+    type Resource = resources::Contract;
+    type Target = resources::Location;
+    fn parameter(&self) -> ContractSearchParameter {
+        ContractSearchParameter::Domain
+    }
+}
+/// The patient reference search parameter of the Contract resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContractPatientSearchReference;
+impl ReferenceSearchParameter for ContractPatientSearchReference {
+    type Resource = resources::Contract;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ContractSearchParameter {
+        ContractSearchParameter::Patient
+    }
+}
+/// The signer reference search parameter of the Contract resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContractSignerSearchReference;
+impl ReferenceSearchParameter for ContractSignerSearchReference {
+    type Resource = resources::Contract;
+    type Target = ContractSignerSearchTarget;
+    fn parameter(&self) -> ContractSearchParameter {
+        ContractSearchParameter::Signer
+    }
+}
+/// Target resources of the signer reference search parameter of the Contract resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ContractSignerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ContractSignerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ContractSignerSearchTarget {
+        ContractSignerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ContractSignerSearchTarget {
+    fn from(resource: resources::Organization) -> ContractSignerSearchTarget {
+        ContractSignerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for ContractSignerSearchTarget {
+    fn from(resource: resources::Patient) -> ContractSignerSearchTarget {
+        ContractSignerSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ContractSignerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ContractSignerSearchTarget {
+        ContractSignerSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subject reference search parameter of the Contract resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContractSubjectSearchReference;
+impl ReferenceSearchParameter for ContractSubjectSearchReference {
+    type Resource = resources::Contract;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ContractSearchParameter {
+        ContractSearchParameter::Subject
+    }
+}
+/// The term-topic reference search parameter of the Contract resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ContractTermTopicSearchReference;
+impl ReferenceSearchParameter for ContractTermTopicSearchReference {
+    type Resource = resources::Contract;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ContractSearchParameter {
+        ContractSearchParameter::TermTopic
+    }
+}
+/// The beneficiary reference search parameter of the Coverage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageBeneficiarySearchReference;
+impl ReferenceSearchParameter for CoverageBeneficiarySearchReference {
+    type Resource = resources::Coverage;
+    type Target = resources::Patient;
+    fn parameter(&self) -> CoverageSearchParameter {
+        CoverageSearchParameter::Beneficiary
+    }
+}
+/// The payor reference search parameter of the Coverage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoveragePayorSearchReference;
+impl ReferenceSearchParameter for CoveragePayorSearchReference {
+    type Resource = resources::Coverage;
+    type Target = CoveragePayorSearchTarget;
+    fn parameter(&self) -> CoverageSearchParameter {
+        CoverageSearchParameter::Payor
+    }
+}
+/// Target resources of the payor reference search parameter of the Coverage resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CoveragePayorSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Organization> for CoveragePayorSearchTarget {
+    fn from(resource: resources::Organization) -> CoveragePayorSearchTarget {
+        CoveragePayorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for CoveragePayorSearchTarget {
+    fn from(resource: resources::Patient) -> CoveragePayorSearchTarget {
+        CoveragePayorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CoveragePayorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CoveragePayorSearchTarget {
+        CoveragePayorSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The policy-holder reference search parameter of the Coverage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoveragePolicyHolderSearchReference;
+impl ReferenceSearchParameter for CoveragePolicyHolderSearchReference {
+    type Resource = resources::Coverage;
+    type Target = CoveragePolicyHolderSearchTarget;
+    fn parameter(&self) -> CoverageSearchParameter {
+        CoverageSearchParameter::PolicyHolder
+    }
+}
+/// Target resources of the policy-holder reference search parameter of the Coverage resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CoveragePolicyHolderSearchTarget {
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Organization> for CoveragePolicyHolderSearchTarget {
+    fn from(resource: resources::Organization) -> CoveragePolicyHolderSearchTarget {
+        CoveragePolicyHolderSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for CoveragePolicyHolderSearchTarget {
+    fn from(resource: resources::Patient) -> CoveragePolicyHolderSearchTarget {
+        CoveragePolicyHolderSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CoveragePolicyHolderSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CoveragePolicyHolderSearchTarget {
+        CoveragePolicyHolderSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subscriber reference search parameter of the Coverage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CoverageSubscriberSearchReference;
+impl ReferenceSearchParameter for CoverageSubscriberSearchReference {
+    type Resource = resources::Coverage;
+    type Target = CoverageSubscriberSearchTarget;
+    fn parameter(&self) -> CoverageSearchParameter {
+        CoverageSearchParameter::Subscriber
+    }
+}
+/// Target resources of the subscriber reference search parameter of the Coverage resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CoverageSubscriberSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Patient> for CoverageSubscriberSearchTarget {
+    fn from(resource: resources::Patient) -> CoverageSubscriberSearchTarget {
+        CoverageSubscriberSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for CoverageSubscriberSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> CoverageSubscriberSearchTarget {
+        CoverageSubscriberSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The patient reference search parameter of the DetectedIssue resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DetectedIssuePatientSearchReference;
+impl ReferenceSearchParameter for DetectedIssuePatientSearchReference {
+    type Resource = resources::DetectedIssue;
+    type Target = DetectedIssuePatientSearchTarget;
+    fn parameter(&self) -> DetectedIssueSearchParameter {
+        DetectedIssueSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the DetectedIssue resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DetectedIssuePatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for DetectedIssuePatientSearchTarget {
+    fn from(resource: resources::Patient) -> DetectedIssuePatientSearchTarget {
+        DetectedIssuePatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for DetectedIssuePatientSearchTarget {
+    fn from(resource: resources::Group) -> DetectedIssuePatientSearchTarget {
+        DetectedIssuePatientSearchTarget::Group(resource)
+    }
+}
+/// The author reference search parameter of the DetectedIssue resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DetectedIssueAuthorSearchReference;
+impl ReferenceSearchParameter for DetectedIssueAuthorSearchReference {
+    type Resource = resources::DetectedIssue;
+    type Target = DetectedIssueAuthorSearchTarget;
+    fn parameter(&self) -> DetectedIssueSearchParameter {
+        DetectedIssueSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the DetectedIssue resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DetectedIssueAuthorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Device target resources
+    Device(resources::Device),
+}
+impl From<resources::Practitioner> for DetectedIssueAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> DetectedIssueAuthorSearchTarget {
+        DetectedIssueAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Device> for DetectedIssueAuthorSearchTarget {
+    fn from(resource: resources::Device) -> DetectedIssueAuthorSearchTarget {
+        DetectedIssueAuthorSearchTarget::Device(resource)
+    }
+}
+/// The implicated reference search parameter of the DetectedIssue resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DetectedIssueImplicatedSearchReference;
+impl ReferenceSearchParameter for DetectedIssueImplicatedSearchReference {
+    type Resource = resources::DetectedIssue;
+    type Target = resources::Resource;
+    fn parameter(&self) -> DetectedIssueSearchParameter {
+        DetectedIssueSearchParameter::Implicated
+    }
+}
+/// The location reference search parameter of the Device resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceLocationSearchReference;
+impl ReferenceSearchParameter for DeviceLocationSearchReference {
+    type Resource = resources::Device;
+    type Target = resources::Location;
+    fn parameter(&self) -> DeviceSearchParameter {
+        DeviceSearchParameter::Location
+    }
+}
+/// The organization reference search parameter of the Device resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceOrganizationSearchReference;
+impl ReferenceSearchParameter for DeviceOrganizationSearchReference {
+    type Resource = resources::Device;
+    type Target = resources::Organization;
+    fn parameter(&self) -> DeviceSearchParameter {
+        DeviceSearchParameter::Organization
+    }
+}
+/// The patient reference search parameter of the Device resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DevicePatientSearchReference;
+impl ReferenceSearchParameter for DevicePatientSearchReference {
+    type Resource = resources::Device;
+    type Target = resources::Patient;
+    fn parameter(&self) -> DeviceSearchParameter {
+        DeviceSearchParameter::Patient
+    }
+}
+/// The parent reference search parameter of the DeviceComponent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceComponentParentSearchReference;
+impl ReferenceSearchParameter for DeviceComponentParentSearchReference {
+    type Resource = resources::DeviceComponent;
+    type Target = resources::DeviceComponent;
+    fn parameter(&self) -> DeviceComponentSearchParameter {
+        DeviceComponentSearchParameter::Parent
+    }
+}
+/// The source reference search parameter of the DeviceComponent resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceComponentSourceSearchReference;
+impl ReferenceSearchParameter for DeviceComponentSourceSearchReference {
+    type Resource = resources::DeviceComponent;
+    type Target = resources::Device;
+    fn parameter(&self) -> DeviceComponentSearchParameter {
+        DeviceComponentSearchParameter::Source
+    }
+}
+/// The parent reference search parameter of the DeviceMetric resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceMetricParentSearchReference;
+impl ReferenceSearchParameter for DeviceMetricParentSearchReference {
+    type Resource = resources::DeviceMetric;
+    type Target = resources::DeviceComponent;
+    fn parameter(&self) -> DeviceMetricSearchParameter {
+        DeviceMetricSearchParameter::Parent
+    }
+}
+/// The source reference search parameter of the DeviceMetric resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceMetricSourceSearchReference;
+impl ReferenceSearchParameter for DeviceMetricSourceSearchReference {
+    type Resource = resources::DeviceMetric;
+    type Target = resources::Device;
+    fn parameter(&self) -> DeviceMetricSearchParameter {
+        DeviceMetricSearchParameter::Source
+    }
+}
+/// The patient reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestPatientSearchReference;
+impl ReferenceSearchParameter for DeviceRequestPatientSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = DeviceRequestPatientSearchTarget;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the DeviceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceRequestPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for DeviceRequestPatientSearchTarget {
+    fn from(resource: resources::Patient) -> DeviceRequestPatientSearchTarget {
+        DeviceRequestPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for DeviceRequestPatientSearchTarget {
+    fn from(resource: resources::Group) -> DeviceRequestPatientSearchTarget {
+        DeviceRequestPatientSearchTarget::Group(resource)
+    }
+}
+/// The encounter reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestEncounterSearchReference;
+impl ReferenceSearchParameter for DeviceRequestEncounterSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = DeviceRequestEncounterSearchTarget;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Encounter
+    }
+}
+/// Target resources of the encounter reference search parameter of the DeviceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceRequestEncounterSearchTarget {
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+}
+impl From<resources::Encounter> for DeviceRequestEncounterSearchTarget {
+    fn from(resource: resources::Encounter) -> DeviceRequestEncounterSearchTarget {
+        DeviceRequestEncounterSearchTarget::Encounter(resource)
+    }
+}
+impl From<resources::EpisodeOfCare> for DeviceRequestEncounterSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> DeviceRequestEncounterSearchTarget {
+        DeviceRequestEncounterSearchTarget::EpisodeOfCare(resource)
+    }
+}
+/// The based-on reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestBasedOnSearchReference;
+impl ReferenceSearchParameter for DeviceRequestBasedOnSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = resources::Resource;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::BasedOn
+    }
+}
+/// The definition reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestDefinitionSearchReference;
+impl ReferenceSearchParameter for DeviceRequestDefinitionSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = DeviceRequestDefinitionSearchTarget;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Definition
+    }
+}
+/// Target resources of the definition reference search parameter of the DeviceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceRequestDefinitionSearchTarget {
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+}
+impl From<resources::PlanDefinition> for DeviceRequestDefinitionSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> DeviceRequestDefinitionSearchTarget {
+        DeviceRequestDefinitionSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for DeviceRequestDefinitionSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> DeviceRequestDefinitionSearchTarget {
+        DeviceRequestDefinitionSearchTarget::ActivityDefinition(resource)
+    }
+}
+/// The device reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestDeviceSearchReference;
+impl ReferenceSearchParameter for DeviceRequestDeviceSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = resources::Device;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Device
+    }
+}
+/// The performer reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestPerformerSearchReference;
+impl ReferenceSearchParameter for DeviceRequestPerformerSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = DeviceRequestPerformerSearchTarget;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the DeviceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceRequestPerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::Organization) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::Device) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::Patient) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::HealthcareService> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::HealthcareService) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::RelatedPerson> for DeviceRequestPerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> DeviceRequestPerformerSearchTarget {
+        DeviceRequestPerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The priorrequest reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestPriorrequestSearchReference;
+impl ReferenceSearchParameter for DeviceRequestPriorrequestSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = resources::Resource;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Priorrequest
+    }
+}
+/// The requester reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestRequesterSearchReference;
+impl ReferenceSearchParameter for DeviceRequestRequesterSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = DeviceRequestRequesterSearchTarget;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Requester
+    }
+}
+/// Target resources of the requester reference search parameter of the DeviceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceRequestRequesterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+}
+impl From<resources::Practitioner> for DeviceRequestRequesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> DeviceRequestRequesterSearchTarget {
+        DeviceRequestRequesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for DeviceRequestRequesterSearchTarget {
+    fn from(resource: resources::Organization) -> DeviceRequestRequesterSearchTarget {
+        DeviceRequestRequesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for DeviceRequestRequesterSearchTarget {
+    fn from(resource: resources::Device) -> DeviceRequestRequesterSearchTarget {
+        DeviceRequestRequesterSearchTarget::Device(resource)
+    }
+}
+/// The subject reference search parameter of the DeviceRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceRequestSubjectSearchReference;
+impl ReferenceSearchParameter for DeviceRequestSubjectSearchReference {
+    type Resource = resources::DeviceRequest;
+    type Target = DeviceRequestSubjectSearchTarget;
+    fn parameter(&self) -> DeviceRequestSearchParameter {
+        DeviceRequestSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the DeviceRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceRequestSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Group> for DeviceRequestSubjectSearchTarget {
+    fn from(resource: resources::Group) -> DeviceRequestSubjectSearchTarget {
+        DeviceRequestSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Device> for DeviceRequestSubjectSearchTarget {
+    fn from(resource: resources::Device) -> DeviceRequestSubjectSearchTarget {
+        DeviceRequestSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for DeviceRequestSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> DeviceRequestSubjectSearchTarget {
+        DeviceRequestSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Location> for DeviceRequestSubjectSearchTarget {
+    fn from(resource: resources::Location) -> DeviceRequestSubjectSearchTarget {
+        DeviceRequestSubjectSearchTarget::Location(resource)
+    }
+}
+/// The patient reference search parameter of the DeviceUseStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceUseStatementPatientSearchReference;
+impl ReferenceSearchParameter for DeviceUseStatementPatientSearchReference {
+    type Resource = resources::DeviceUseStatement;
+    type Target = DeviceUseStatementPatientSearchTarget;
+    fn parameter(&self) -> DeviceUseStatementSearchParameter {
+        DeviceUseStatementSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the DeviceUseStatement resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceUseStatementPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for DeviceUseStatementPatientSearchTarget {
+    fn from(resource: resources::Patient) -> DeviceUseStatementPatientSearchTarget {
+        DeviceUseStatementPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for DeviceUseStatementPatientSearchTarget {
+    fn from(resource: resources::Group) -> DeviceUseStatementPatientSearchTarget {
+        DeviceUseStatementPatientSearchTarget::Group(resource)
+    }
+}
+/// The device reference search parameter of the DeviceUseStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceUseStatementDeviceSearchReference;
+impl ReferenceSearchParameter for DeviceUseStatementDeviceSearchReference {
+    type Resource = resources::DeviceUseStatement;
+    type Target = resources::Device;
+    fn parameter(&self) -> DeviceUseStatementSearchParameter {
+        DeviceUseStatementSearchParameter::Device
+    }
+}
+/// The subject reference search parameter of the DeviceUseStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeviceUseStatementSubjectSearchReference;
+impl ReferenceSearchParameter for DeviceUseStatementSubjectSearchReference {
+    type Resource = resources::DeviceUseStatement;
+    type Target = DeviceUseStatementSubjectSearchTarget;
+    fn parameter(&self) -> DeviceUseStatementSearchParameter {
+        DeviceUseStatementSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the DeviceUseStatement resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeviceUseStatementSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for DeviceUseStatementSubjectSearchTarget {
+    fn from(resource: resources::Group) -> DeviceUseStatementSubjectSearchTarget {
+        DeviceUseStatementSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for DeviceUseStatementSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> DeviceUseStatementSubjectSearchTarget {
+        DeviceUseStatementSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportPatientSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportPatientSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = DiagnosticReportPatientSearchTarget;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the DiagnosticReport resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DiagnosticReportPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for DiagnosticReportPatientSearchTarget {
+    fn from(resource: resources::Patient) -> DiagnosticReportPatientSearchTarget {
+        DiagnosticReportPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for DiagnosticReportPatientSearchTarget {
+    fn from(resource: resources::Group) -> DiagnosticReportPatientSearchTarget {
+        DiagnosticReportPatientSearchTarget::Group(resource)
+    }
+}
+/// The encounter reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportEncounterSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportEncounterSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = DiagnosticReportEncounterSearchTarget;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Encounter
+    }
+}
+/// Target resources of the encounter reference search parameter of the DiagnosticReport resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DiagnosticReportEncounterSearchTarget {
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+}
+impl From<resources::Encounter> for DiagnosticReportEncounterSearchTarget {
+    fn from(resource: resources::Encounter) -> DiagnosticReportEncounterSearchTarget {
+        DiagnosticReportEncounterSearchTarget::Encounter(resource)
+    }
+}
+impl From<resources::EpisodeOfCare> for DiagnosticReportEncounterSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> DiagnosticReportEncounterSearchTarget {
+        DiagnosticReportEncounterSearchTarget::EpisodeOfCare(resource)
+    }
+}
+/// The based-on reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportBasedOnSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportBasedOnSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = DiagnosticReportBasedOnSearchTarget;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the DiagnosticReport resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DiagnosticReportBasedOnSearchTarget {
+    /// Variant for ReferralRequest target resources
+    ReferralRequest(resources::ReferralRequest),
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for MedicationRequest target resources
+    MedicationRequest(resources::MedicationRequest),
+    /// Variant for NutritionOrder target resources
+    NutritionOrder(resources::NutritionOrder),
+    /// Variant for ProcedureRequest target resources
+    ProcedureRequest(resources::ProcedureRequest),
+    /// Variant for ImmunizationRecommendation target resources
+    ImmunizationRecommendation(resources::ImmunizationRecommendation),
+}
+impl From<resources::ReferralRequest> for DiagnosticReportBasedOnSearchTarget {
+    fn from(resource: resources::ReferralRequest) -> DiagnosticReportBasedOnSearchTarget {
+        DiagnosticReportBasedOnSearchTarget::ReferralRequest(resource)
+    }
+}
+impl From<resources::CarePlan> for DiagnosticReportBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> DiagnosticReportBasedOnSearchTarget {
+        DiagnosticReportBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::MedicationRequest> for DiagnosticReportBasedOnSearchTarget {
+    fn from(resource: resources::MedicationRequest) -> DiagnosticReportBasedOnSearchTarget {
+        DiagnosticReportBasedOnSearchTarget::MedicationRequest(resource)
+    }
+}
+impl From<resources::NutritionOrder> for DiagnosticReportBasedOnSearchTarget {
+    fn from(resource: resources::NutritionOrder) -> DiagnosticReportBasedOnSearchTarget {
+        DiagnosticReportBasedOnSearchTarget::NutritionOrder(resource)
+    }
+}
+impl From<resources::ProcedureRequest> for DiagnosticReportBasedOnSearchTarget {
+    fn from(resource: resources::ProcedureRequest) -> DiagnosticReportBasedOnSearchTarget {
+        DiagnosticReportBasedOnSearchTarget::ProcedureRequest(resource)
+    }
+}
+impl From<resources::ImmunizationRecommendation> for DiagnosticReportBasedOnSearchTarget {
+    fn from(resource: resources::ImmunizationRecommendation) -> DiagnosticReportBasedOnSearchTarget {
+        DiagnosticReportBasedOnSearchTarget::ImmunizationRecommendation(resource)
+    }
+}
+/// The context reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportContextSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportContextSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = DiagnosticReportContextSearchTarget;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the DiagnosticReport resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DiagnosticReportContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for DiagnosticReportContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> DiagnosticReportContextSearchTarget {
+        DiagnosticReportContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for DiagnosticReportContextSearchTarget {
+    fn from(resource: resources::Encounter) -> DiagnosticReportContextSearchTarget {
+        DiagnosticReportContextSearchTarget::Encounter(resource)
+    }
+}
+/// The image reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportImageSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportImageSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = resources::Media;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Image
+    }
+}
+/// The performer reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportPerformerSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportPerformerSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = DiagnosticReportPerformerSearchTarget;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the DiagnosticReport resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DiagnosticReportPerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+}
+impl From<resources::Practitioner> for DiagnosticReportPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> DiagnosticReportPerformerSearchTarget {
+        DiagnosticReportPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for DiagnosticReportPerformerSearchTarget {
+    fn from(resource: resources::Organization) -> DiagnosticReportPerformerSearchTarget {
+        DiagnosticReportPerformerSearchTarget::Organization(resource)
+    }
+}
+/// The result reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportResultSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportResultSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = resources::Observation;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Result
+    }
+}
+/// The specimen reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportSpecimenSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportSpecimenSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = resources::Specimen;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Specimen
+    }
+}
+/// The subject reference search parameter of the DiagnosticReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiagnosticReportSubjectSearchReference;
+impl ReferenceSearchParameter for DiagnosticReportSubjectSearchReference {
+    type Resource = resources::DiagnosticReport;
+    type Target = DiagnosticReportSubjectSearchTarget;
+    fn parameter(&self) -> DiagnosticReportSearchParameter {
+        DiagnosticReportSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the DiagnosticReport resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DiagnosticReportSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Group> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Group) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Device> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Device) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Location> for DiagnosticReportSubjectSearchTarget {
+    fn from(resource: resources::Location) -> DiagnosticReportSubjectSearchTarget {
+        DiagnosticReportSubjectSearchTarget::Location(resource)
+    }
+}
+/// The patient reference search parameter of the DocumentManifest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentManifestPatientSearchReference;
+impl ReferenceSearchParameter for DocumentManifestPatientSearchReference {
+    type Resource = resources::DocumentManifest;
+    type Target = DocumentManifestPatientSearchTarget;
+    fn parameter(&self) -> DocumentManifestSearchParameter {
+        DocumentManifestSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the DocumentManifest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentManifestPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for DocumentManifestPatientSearchTarget {
+    fn from(resource: resources::Patient) -> DocumentManifestPatientSearchTarget {
+        DocumentManifestPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for DocumentManifestPatientSearchTarget {
+    fn from(resource: resources::Group) -> DocumentManifestPatientSearchTarget {
+        DocumentManifestPatientSearchTarget::Group(resource)
+    }
+}
+/// The author reference search parameter of the DocumentManifest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentManifestAuthorSearchReference;
+impl ReferenceSearchParameter for DocumentManifestAuthorSearchReference {
+    type Resource = resources::DocumentManifest;
+    type Target = DocumentManifestAuthorSearchTarget;
+    fn parameter(&self) -> DocumentManifestSearchParameter {
+        DocumentManifestSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the DocumentManifest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentManifestAuthorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for DocumentManifestAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> DocumentManifestAuthorSearchTarget {
+        DocumentManifestAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for DocumentManifestAuthorSearchTarget {
+    fn from(resource: resources::Organization) -> DocumentManifestAuthorSearchTarget {
+        DocumentManifestAuthorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for DocumentManifestAuthorSearchTarget {
+    fn from(resource: resources::Device) -> DocumentManifestAuthorSearchTarget {
+        DocumentManifestAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for DocumentManifestAuthorSearchTarget {
+    fn from(resource: resources::Patient) -> DocumentManifestAuthorSearchTarget {
+        DocumentManifestAuthorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for DocumentManifestAuthorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> DocumentManifestAuthorSearchTarget {
+        DocumentManifestAuthorSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The content-ref reference search parameter of the DocumentManifest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentManifestContentRefSearchReference;
+impl ReferenceSearchParameter for DocumentManifestContentRefSearchReference {
+    type Resource = resources::DocumentManifest;
+    type Target = resources::Resource;
+    fn parameter(&self) -> DocumentManifestSearchParameter {
+        DocumentManifestSearchParameter::ContentRef
+    }
+}
+/// The recipient reference search parameter of the DocumentManifest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentManifestRecipientSearchReference;
+impl ReferenceSearchParameter for DocumentManifestRecipientSearchReference {
+    type Resource = resources::DocumentManifest;
+    type Target = DocumentManifestRecipientSearchTarget;
+    fn parameter(&self) -> DocumentManifestSearchParameter {
+        DocumentManifestSearchParameter::Recipient
+    }
+}
+/// Target resources of the recipient reference search parameter of the DocumentManifest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentManifestRecipientSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for DocumentManifestRecipientSearchTarget {
+    fn from(resource: resources::Practitioner) -> DocumentManifestRecipientSearchTarget {
+        DocumentManifestRecipientSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for DocumentManifestRecipientSearchTarget {
+    fn from(resource: resources::Organization) -> DocumentManifestRecipientSearchTarget {
+        DocumentManifestRecipientSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for DocumentManifestRecipientSearchTarget {
+    fn from(resource: resources::Patient) -> DocumentManifestRecipientSearchTarget {
+        DocumentManifestRecipientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for DocumentManifestRecipientSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> DocumentManifestRecipientSearchTarget {
+        DocumentManifestRecipientSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The related-ref reference search parameter of the DocumentManifest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentManifestRelatedRefSearchReference;
+impl ReferenceSearchParameter for DocumentManifestRelatedRefSearchReference {
+    type Resource = resources::DocumentManifest;
+    type Target = resources::Resource;
+    fn parameter(&self) -> DocumentManifestSearchParameter {
+        DocumentManifestSearchParameter::RelatedRef
+    }
+}
+/// The subject reference search parameter of the DocumentManifest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentManifestSubjectSearchReference;
+impl ReferenceSearchParameter for DocumentManifestSubjectSearchReference {
+    type Resource = resources::DocumentManifest;
+    type Target = DocumentManifestSubjectSearchTarget;
+    fn parameter(&self) -> DocumentManifestSearchParameter {
+        DocumentManifestSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the DocumentManifest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentManifestSubjectSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Practitioner> for DocumentManifestSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> DocumentManifestSubjectSearchTarget {
+        DocumentManifestSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for DocumentManifestSubjectSearchTarget {
+    fn from(resource: resources::Group) -> DocumentManifestSubjectSearchTarget {
+        DocumentManifestSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Device> for DocumentManifestSubjectSearchTarget {
+    fn from(resource: resources::Device) -> DocumentManifestSubjectSearchTarget {
+        DocumentManifestSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for DocumentManifestSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> DocumentManifestSubjectSearchTarget {
+        DocumentManifestSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferencePatientSearchReference;
+impl ReferenceSearchParameter for DocumentReferencePatientSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = DocumentReferencePatientSearchTarget;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the DocumentReference resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentReferencePatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for DocumentReferencePatientSearchTarget {
+    fn from(resource: resources::Patient) -> DocumentReferencePatientSearchTarget {
+        DocumentReferencePatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for DocumentReferencePatientSearchTarget {
+    fn from(resource: resources::Group) -> DocumentReferencePatientSearchTarget {
+        DocumentReferencePatientSearchTarget::Group(resource)
+    }
+}
+/// The encounter reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceEncounterSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceEncounterSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = DocumentReferenceEncounterSearchTarget;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Encounter
+    }
+}
+/// Target resources of the encounter reference search parameter of the DocumentReference resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentReferenceEncounterSearchTarget {
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+}
+impl From<resources::Encounter> for DocumentReferenceEncounterSearchTarget {
+    fn from(resource: resources::Encounter) -> DocumentReferenceEncounterSearchTarget {
+        DocumentReferenceEncounterSearchTarget::Encounter(resource)
+    }
+}
+impl From<resources::EpisodeOfCare> for DocumentReferenceEncounterSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> DocumentReferenceEncounterSearchTarget {
+        DocumentReferenceEncounterSearchTarget::EpisodeOfCare(resource)
+    }
+}
+/// The authenticator reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceAuthenticatorSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceAuthenticatorSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = DocumentReferenceAuthenticatorSearchTarget;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Authenticator
+    }
+}
+/// Target resources of the authenticator reference search parameter of the DocumentReference resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentReferenceAuthenticatorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+}
+impl From<resources::Practitioner> for DocumentReferenceAuthenticatorSearchTarget {
+    fn from(resource: resources::Practitioner) -> DocumentReferenceAuthenticatorSearchTarget {
+        DocumentReferenceAuthenticatorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for DocumentReferenceAuthenticatorSearchTarget {
+    fn from(resource: resources::Organization) -> DocumentReferenceAuthenticatorSearchTarget {
+        DocumentReferenceAuthenticatorSearchTarget::Organization(resource)
+    }
+}
+/// The author reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceAuthorSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceAuthorSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = DocumentReferenceAuthorSearchTarget;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the DocumentReference resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentReferenceAuthorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for DocumentReferenceAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> DocumentReferenceAuthorSearchTarget {
+        DocumentReferenceAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for DocumentReferenceAuthorSearchTarget {
+    fn from(resource: resources::Organization) -> DocumentReferenceAuthorSearchTarget {
+        DocumentReferenceAuthorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for DocumentReferenceAuthorSearchTarget {
+    fn from(resource: resources::Device) -> DocumentReferenceAuthorSearchTarget {
+        DocumentReferenceAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for DocumentReferenceAuthorSearchTarget {
+    fn from(resource: resources::Patient) -> DocumentReferenceAuthorSearchTarget {
+        DocumentReferenceAuthorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for DocumentReferenceAuthorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> DocumentReferenceAuthorSearchTarget {
+        DocumentReferenceAuthorSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The custodian reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceCustodianSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceCustodianSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = resources::Organization;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Custodian
+    }
+}
+/// The related-ref reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceRelatedRefSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceRelatedRefSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = resources::Resource;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::RelatedRef
+    }
+}
+/// The relatesto reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceRelatestoSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceRelatestoSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = resources::DocumentReference;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Relatesto
+    }
+}
+/// The subject reference search parameter of the DocumentReference resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocumentReferenceSubjectSearchReference;
+impl ReferenceSearchParameter for DocumentReferenceSubjectSearchReference {
+    type Resource = resources::DocumentReference;
+    type Target = DocumentReferenceSubjectSearchTarget;
+    fn parameter(&self) -> DocumentReferenceSearchParameter {
+        DocumentReferenceSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the DocumentReference resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DocumentReferenceSubjectSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Practitioner> for DocumentReferenceSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> DocumentReferenceSubjectSearchTarget {
+        DocumentReferenceSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for DocumentReferenceSubjectSearchTarget {
+    fn from(resource: resources::Group) -> DocumentReferenceSubjectSearchTarget {
+        DocumentReferenceSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Device> for DocumentReferenceSubjectSearchTarget {
+    fn from(resource: resources::Device) -> DocumentReferenceSubjectSearchTarget {
+        DocumentReferenceSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for DocumentReferenceSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> DocumentReferenceSubjectSearchTarget {
+        DocumentReferenceSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The enterer reference search parameter of the EligibilityRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EligibilityRequestEntererSearchReference;
+impl ReferenceSearchParameter for EligibilityRequestEntererSearchReference {
+    type Resource = resources::EligibilityRequest;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> EligibilityRequestSearchParameter {
+        EligibilityRequestSearchParameter::Enterer
+    }
+}
+/// The facility reference search parameter of the EligibilityRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EligibilityRequestFacilitySearchReference;
+impl ReferenceSearchParameter for EligibilityRequestFacilitySearchReference {
+    type Resource = resources::EligibilityRequest;
+    type Target = resources::Location;
+    fn parameter(&self) -> EligibilityRequestSearchParameter {
+        EligibilityRequestSearchParameter::Facility
+    }
+}
+/// The organization reference search parameter of the EligibilityRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EligibilityRequestOrganizationSearchReference;
+impl ReferenceSearchParameter for EligibilityRequestOrganizationSearchReference {
+    type Resource = resources::EligibilityRequest;
+    type Target = resources::Organization;
+    fn parameter(&self) -> EligibilityRequestSearchParameter {
+        EligibilityRequestSearchParameter::Organization
+    }
+}
+/// The patient reference search parameter of the EligibilityRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EligibilityRequestPatientSearchReference;
+impl ReferenceSearchParameter for EligibilityRequestPatientSearchReference {
+    type Resource = resources::EligibilityRequest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> EligibilityRequestSearchParameter {
+        EligibilityRequestSearchParameter::Patient
+    }
+}
+/// The provider reference search parameter of the EligibilityRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EligibilityRequestProviderSearchReference;
+impl ReferenceSearchParameter for EligibilityRequestProviderSearchReference {
+    type Resource = resources::EligibilityRequest;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> EligibilityRequestSearchParameter {
+        EligibilityRequestSearchParameter::Provider
+    }
+}
+/// The insurer reference search parameter of the EligibilityResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EligibilityResponseInsurerSearchReference;
+impl ReferenceSearchParameter for EligibilityResponseInsurerSearchReference {
+    type Resource = resources::EligibilityResponse;
+    type Target = resources::Organization;
+    fn parameter(&self) -> EligibilityResponseSearchParameter {
+        EligibilityResponseSearchParameter::Insurer
+    }
+}
+/// The request reference search parameter of the EligibilityResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EligibilityResponseRequestSearchReference;
+impl ReferenceSearchParameter for EligibilityResponseRequestSearchReference {
+    type Resource = resources::EligibilityResponse;
+    type Target = resources::EligibilityRequest;
+    fn parameter(&self) -> EligibilityResponseSearchParameter {
+        EligibilityResponseSearchParameter::Request
+    }
+}
+/// The request-organization reference search parameter of the EligibilityResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EligibilityResponseRequestOrganizationSearchReference;
+impl ReferenceSearchParameter for EligibilityResponseRequestOrganizationSearchReference {
+    type Resource = resources::EligibilityResponse;
+    type Target = resources::Organization;
+    fn parameter(&self) -> EligibilityResponseSearchParameter {
+        EligibilityResponseSearchParameter::RequestOrganization
+    }
+}
+/// The request-provider reference search parameter of the EligibilityResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EligibilityResponseRequestProviderSearchReference;
+impl ReferenceSearchParameter for EligibilityResponseRequestProviderSearchReference {
+    type Resource = resources::EligibilityResponse;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> EligibilityResponseSearchParameter {
+        EligibilityResponseSearchParameter::RequestProvider
+    }
+}
+/// The patient reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterPatientSearchReference;
+impl ReferenceSearchParameter for EncounterPatientSearchReference {
+    type Resource = resources::Encounter;
+    type Target = EncounterPatientSearchTarget;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the Encounter resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EncounterPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for EncounterPatientSearchTarget {
+    fn from(resource: resources::Patient) -> EncounterPatientSearchTarget {
+        EncounterPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for EncounterPatientSearchTarget {
+    fn from(resource: resources::Group) -> EncounterPatientSearchTarget {
+        EncounterPatientSearchTarget::Group(resource)
+    }
+}
+/// The appointment reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterAppointmentSearchReference;
+impl ReferenceSearchParameter for EncounterAppointmentSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::Appointment;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Appointment
+    }
+}
+/// The diagnosis reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterDiagnosisSearchReference;
+impl ReferenceSearchParameter for EncounterDiagnosisSearchReference {
+    type Resource = resources::Encounter;
+    type Target = EncounterDiagnosisSearchTarget;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Diagnosis
+    }
+}
+/// Target resources of the diagnosis reference search parameter of the Encounter resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EncounterDiagnosisSearchTarget {
+    /// Variant for Condition target resources
+    Condition(resources::Condition),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+}
+impl From<resources::Condition> for EncounterDiagnosisSearchTarget {
+    fn from(resource: resources::Condition) -> EncounterDiagnosisSearchTarget {
+        EncounterDiagnosisSearchTarget::Condition(resource)
+    }
+}
+impl From<resources::Procedure> for EncounterDiagnosisSearchTarget {
+    fn from(resource: resources::Procedure) -> EncounterDiagnosisSearchTarget {
+        EncounterDiagnosisSearchTarget::Procedure(resource)
+    }
+}
+/// The episodeofcare reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterEpisodeofcareSearchReference;
+impl ReferenceSearchParameter for EncounterEpisodeofcareSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::EpisodeOfCare;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Episodeofcare
+    }
+}
+/// The incomingreferral reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterIncomingreferralSearchReference;
+impl ReferenceSearchParameter for EncounterIncomingreferralSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::ReferralRequest;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Incomingreferral
+    }
+}
+/// The location reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterLocationSearchReference;
+impl ReferenceSearchParameter for EncounterLocationSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::Location;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Location
+    }
+}
+/// The part-of reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterPartOfSearchReference;
+impl ReferenceSearchParameter for EncounterPartOfSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::PartOf
+    }
+}
+/// The participant reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterParticipantSearchReference;
+impl ReferenceSearchParameter for EncounterParticipantSearchReference {
+    type Resource = resources::Encounter;
+    type Target = EncounterParticipantSearchTarget;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Participant
+    }
+}
+/// Target resources of the participant reference search parameter of the Encounter resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EncounterParticipantSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for EncounterParticipantSearchTarget {
+    fn from(resource: resources::Practitioner) -> EncounterParticipantSearchTarget {
+        EncounterParticipantSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::RelatedPerson> for EncounterParticipantSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> EncounterParticipantSearchTarget {
+        EncounterParticipantSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The practitioner reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterPractitionerSearchReference;
+impl ReferenceSearchParameter for EncounterPractitionerSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Practitioner
+    }
+}
+/// The service-provider reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterServiceProviderSearchReference;
+impl ReferenceSearchParameter for EncounterServiceProviderSearchReference {
+    type Resource = resources::Encounter;
+    type Target = resources::Organization;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::ServiceProvider
+    }
+}
+/// The subject reference search parameter of the Encounter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EncounterSubjectSearchReference;
+impl ReferenceSearchParameter for EncounterSubjectSearchReference {
+    type Resource = resources::Encounter;
+    type Target = EncounterSubjectSearchTarget;
+    fn parameter(&self) -> EncounterSearchParameter {
+        EncounterSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Encounter resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EncounterSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for EncounterSubjectSearchTarget {
+    fn from(resource: resources::Group) -> EncounterSubjectSearchTarget {
+        EncounterSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for EncounterSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> EncounterSubjectSearchTarget {
+        EncounterSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The organization reference search parameter of the Endpoint resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EndpointOrganizationSearchReference;
+impl ReferenceSearchParameter for EndpointOrganizationSearchReference {
+    type Resource = resources::Endpoint;
+    type Target = resources::Organization;
+    fn parameter(&self) -> EndpointSearchParameter {
+        EndpointSearchParameter::Organization
+    }
+}
+/// The organization reference search parameter of the EnrollmentRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EnrollmentRequestOrganizationSearchReference;
+impl ReferenceSearchParameter for EnrollmentRequestOrganizationSearchReference {
+    type Resource = resources::EnrollmentRequest;
+    type Target = resources::Organization;
+    fn parameter(&self) -> EnrollmentRequestSearchParameter {
+        EnrollmentRequestSearchParameter::Organization
+    }
+}
+/// The patient reference search parameter of the EnrollmentRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EnrollmentRequestPatientSearchReference;
+impl ReferenceSearchParameter for EnrollmentRequestPatientSearchReference {
+    type Resource = resources::EnrollmentRequest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> EnrollmentRequestSearchParameter {
+        EnrollmentRequestSearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the EnrollmentRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EnrollmentRequestSubjectSearchReference;
+impl ReferenceSearchParameter for EnrollmentRequestSubjectSearchReference {
+    type Resource = resources::EnrollmentRequest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> EnrollmentRequestSearchParameter {
+        EnrollmentRequestSearchParameter::Subject
+    }
+}
+/// The organization reference search parameter of the EnrollmentResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EnrollmentResponseOrganizationSearchReference;
+impl ReferenceSearchParameter for EnrollmentResponseOrganizationSearchReference {
+    type Resource = resources::EnrollmentResponse;
+    type Target = resources::Organization;
+    fn parameter(&self) -> EnrollmentResponseSearchParameter {
+        EnrollmentResponseSearchParameter::Organization
+    }
+}
+/// The request reference search parameter of the EnrollmentResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EnrollmentResponseRequestSearchReference;
+impl ReferenceSearchParameter for EnrollmentResponseRequestSearchReference {
+    type Resource = resources::EnrollmentResponse;
+    type Target = resources::EnrollmentRequest;
+    fn parameter(&self) -> EnrollmentResponseSearchParameter {
+        EnrollmentResponseSearchParameter::Request
+    }
+}
+/// The patient reference search parameter of the EpisodeOfCare resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EpisodeOfCarePatientSearchReference;
+impl ReferenceSearchParameter for EpisodeOfCarePatientSearchReference {
+    type Resource = resources::EpisodeOfCare;
+    type Target = EpisodeOfCarePatientSearchTarget;
+    fn parameter(&self) -> EpisodeOfCareSearchParameter {
+        EpisodeOfCareSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the EpisodeOfCare resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EpisodeOfCarePatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for EpisodeOfCarePatientSearchTarget {
+    fn from(resource: resources::Patient) -> EpisodeOfCarePatientSearchTarget {
+        EpisodeOfCarePatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for EpisodeOfCarePatientSearchTarget {
+    fn from(resource: resources::Group) -> EpisodeOfCarePatientSearchTarget {
+        EpisodeOfCarePatientSearchTarget::Group(resource)
+    }
+}
+/// The care-manager reference search parameter of the EpisodeOfCare resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EpisodeOfCareCareManagerSearchReference;
+impl ReferenceSearchParameter for EpisodeOfCareCareManagerSearchReference {
+    type Resource = resources::EpisodeOfCare;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> EpisodeOfCareSearchParameter {
+        EpisodeOfCareSearchParameter::CareManager
+    }
+}
+/// The condition reference search parameter of the EpisodeOfCare resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EpisodeOfCareConditionSearchReference;
+impl ReferenceSearchParameter for EpisodeOfCareConditionSearchReference {
+    type Resource = resources::EpisodeOfCare;
+    type Target = resources::Condition;
+    fn parameter(&self) -> EpisodeOfCareSearchParameter {
+        EpisodeOfCareSearchParameter::Condition
+    }
+}
+/// The incomingreferral reference search parameter of the EpisodeOfCare resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EpisodeOfCareIncomingreferralSearchReference;
+impl ReferenceSearchParameter for EpisodeOfCareIncomingreferralSearchReference {
+    type Resource = resources::EpisodeOfCare;
+    type Target = resources::ReferralRequest;
+    fn parameter(&self) -> EpisodeOfCareSearchParameter {
+        EpisodeOfCareSearchParameter::Incomingreferral
+    }
+}
+/// The organization reference search parameter of the EpisodeOfCare resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EpisodeOfCareOrganizationSearchReference;
+impl ReferenceSearchParameter for EpisodeOfCareOrganizationSearchReference {
+    type Resource = resources::EpisodeOfCare;
+    type Target = resources::Organization;
+    fn parameter(&self) -> EpisodeOfCareSearchParameter {
+        EpisodeOfCareSearchParameter::Organization
+    }
+}
+/// The care-team reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitCareTeamSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitCareTeamSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = ExplanationOfBenefitCareTeamSearchTarget;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::CareTeam
+    }
+}
+/// Target resources of the care-team reference search parameter of the ExplanationOfBenefit resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExplanationOfBenefitCareTeamSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+}
+impl From<resources::Practitioner> for ExplanationOfBenefitCareTeamSearchTarget {
+    fn from(resource: resources::Practitioner) -> ExplanationOfBenefitCareTeamSearchTarget {
+        ExplanationOfBenefitCareTeamSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ExplanationOfBenefitCareTeamSearchTarget {
+    fn from(resource: resources::Organization) -> ExplanationOfBenefitCareTeamSearchTarget {
+        ExplanationOfBenefitCareTeamSearchTarget::Organization(resource)
+    }
+}
+/// The claim reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitClaimSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitClaimSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Claim;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Claim
+    }
+}
+/// The coverage reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitCoverageSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitCoverageSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Coverage;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Coverage
+    }
+}
+/// The encounter reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitEncounterSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitEncounterSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Encounter
+    }
+}
+/// The enterer reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitEntererSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitEntererSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Enterer
+    }
+}
+/// The facility reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitFacilitySearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitFacilitySearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Location;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Facility
+    }
+}
+/// The organization reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitOrganizationSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitOrganizationSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Organization
+    }
+}
+/// The patient reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitPatientSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitPatientSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Patient
+    }
+}
+/// The payee reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitPayeeSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitPayeeSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = ExplanationOfBenefitPayeeSearchTarget;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Payee
+    }
+}
+/// Target resources of the payee reference search parameter of the ExplanationOfBenefit resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExplanationOfBenefitPayeeSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ExplanationOfBenefitPayeeSearchTarget {
+    fn from(resource: resources::Practitioner) -> ExplanationOfBenefitPayeeSearchTarget {
+        ExplanationOfBenefitPayeeSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ExplanationOfBenefitPayeeSearchTarget {
+    fn from(resource: resources::Organization) -> ExplanationOfBenefitPayeeSearchTarget {
+        ExplanationOfBenefitPayeeSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for ExplanationOfBenefitPayeeSearchTarget {
+    fn from(resource: resources::Patient) -> ExplanationOfBenefitPayeeSearchTarget {
+        ExplanationOfBenefitPayeeSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ExplanationOfBenefitPayeeSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ExplanationOfBenefitPayeeSearchTarget {
+        ExplanationOfBenefitPayeeSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The provider reference search parameter of the ExplanationOfBenefit resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExplanationOfBenefitProviderSearchReference;
+impl ReferenceSearchParameter for ExplanationOfBenefitProviderSearchReference {
+    type Resource = resources::ExplanationOfBenefit;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> ExplanationOfBenefitSearchParameter {
+        ExplanationOfBenefitSearchParameter::Provider
+    }
+}
+/// The patient reference search parameter of the FamilyMemberHistory resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FamilyMemberHistoryPatientSearchReference;
+impl ReferenceSearchParameter for FamilyMemberHistoryPatientSearchReference {
+    type Resource = resources::FamilyMemberHistory;
+    type Target = FamilyMemberHistoryPatientSearchTarget;
+    fn parameter(&self) -> FamilyMemberHistorySearchParameter {
+        FamilyMemberHistorySearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the FamilyMemberHistory resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FamilyMemberHistoryPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for FamilyMemberHistoryPatientSearchTarget {
+    fn from(resource: resources::Patient) -> FamilyMemberHistoryPatientSearchTarget {
+        FamilyMemberHistoryPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for FamilyMemberHistoryPatientSearchTarget {
+    fn from(resource: resources::Group) -> FamilyMemberHistoryPatientSearchTarget {
+        FamilyMemberHistoryPatientSearchTarget::Group(resource)
+    }
+}
+/// The definition reference search parameter of the FamilyMemberHistory resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FamilyMemberHistoryDefinitionSearchReference;
+impl ReferenceSearchParameter for FamilyMemberHistoryDefinitionSearchReference {
+    type Resource = resources::FamilyMemberHistory;
+    type Target = FamilyMemberHistoryDefinitionSearchTarget;
+    fn parameter(&self) -> FamilyMemberHistorySearchParameter {
+        FamilyMemberHistorySearchParameter::Definition
+    }
+}
+/// Target resources of the definition reference search parameter of the FamilyMemberHistory resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FamilyMemberHistoryDefinitionSearchTarget {
+    /// Variant for Questionnaire target resources
+    Questionnaire(resources::Questionnaire),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+}
+impl From<resources::Questionnaire> for FamilyMemberHistoryDefinitionSearchTarget {
+    fn from(resource: resources::Questionnaire) -> FamilyMemberHistoryDefinitionSearchTarget {
+        FamilyMemberHistoryDefinitionSearchTarget::Questionnaire(resource)
+    }
+}
+impl From<resources::PlanDefinition> for FamilyMemberHistoryDefinitionSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> FamilyMemberHistoryDefinitionSearchTarget {
+        FamilyMemberHistoryDefinitionSearchTarget::PlanDefinition(resource)
+    }
+}
+/// The patient reference search parameter of the Flag resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FlagPatientSearchReference;
+impl ReferenceSearchParameter for FlagPatientSearchReference {
+    type Resource = resources::Flag;
+    type Target = FlagPatientSearchTarget;
+    fn parameter(&self) -> FlagSearchParameter {
+        FlagSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the Flag resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FlagPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for FlagPatientSearchTarget {
+    fn from(resource: resources::Patient) -> FlagPatientSearchTarget {
+        FlagPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for FlagPatientSearchTarget {
+    fn from(resource: resources::Group) -> FlagPatientSearchTarget {
+        FlagPatientSearchTarget::Group(resource)
+    }
+}
+/// The encounter reference search parameter of the Flag resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FlagEncounterSearchReference;
+impl ReferenceSearchParameter for FlagEncounterSearchReference {
+    type Resource = resources::Flag;
+    type Target = FlagEncounterSearchTarget;
+    fn parameter(&self) -> FlagSearchParameter {
+        FlagSearchParameter::Encounter
+    }
+}
+/// Target resources of the encounter reference search parameter of the Flag resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FlagEncounterSearchTarget {
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+}
+impl From<resources::Encounter> for FlagEncounterSearchTarget {
+    fn from(resource: resources::Encounter) -> FlagEncounterSearchTarget {
+        FlagEncounterSearchTarget::Encounter(resource)
+    }
+}
+impl From<resources::EpisodeOfCare> for FlagEncounterSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> FlagEncounterSearchTarget {
+        FlagEncounterSearchTarget::EpisodeOfCare(resource)
+    }
+}
+/// The author reference search parameter of the Flag resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FlagAuthorSearchReference;
+impl ReferenceSearchParameter for FlagAuthorSearchReference {
+    type Resource = resources::Flag;
+    type Target = FlagAuthorSearchTarget;
+    fn parameter(&self) -> FlagSearchParameter {
+        FlagSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the Flag resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FlagAuthorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Practitioner> for FlagAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> FlagAuthorSearchTarget {
+        FlagAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for FlagAuthorSearchTarget {
+    fn from(resource: resources::Organization) -> FlagAuthorSearchTarget {
+        FlagAuthorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for FlagAuthorSearchTarget {
+    fn from(resource: resources::Device) -> FlagAuthorSearchTarget {
+        FlagAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for FlagAuthorSearchTarget {
+    fn from(resource: resources::Patient) -> FlagAuthorSearchTarget {
+        FlagAuthorSearchTarget::Patient(resource)
+    }
+}
+/// The subject reference search parameter of the Flag resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FlagSubjectSearchReference;
+impl ReferenceSearchParameter for FlagSubjectSearchReference {
+    type Resource = resources::Flag;
+    type Target = FlagSubjectSearchTarget;
+    fn parameter(&self) -> FlagSearchParameter {
+        FlagSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Flag resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FlagSubjectSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Practitioner> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Group) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Organization> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Medication> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Medication) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Medication(resource)
+    }
+}
+impl From<resources::Patient> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::PlanDefinition> for FlagSubjectSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::Procedure> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Procedure) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::Location> for FlagSubjectSearchTarget {
+    fn from(resource: resources::Location) -> FlagSubjectSearchTarget {
+        FlagSubjectSearchTarget::Location(resource)
+    }
+}
+/// The patient reference search parameter of the Goal resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GoalPatientSearchReference;
+impl ReferenceSearchParameter for GoalPatientSearchReference {
+    type Resource = resources::Goal;
+    type Target = GoalPatientSearchTarget;
+    fn parameter(&self) -> GoalSearchParameter {
+        GoalSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the Goal resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GoalPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for GoalPatientSearchTarget {
+    fn from(resource: resources::Patient) -> GoalPatientSearchTarget {
+        GoalPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for GoalPatientSearchTarget {
+    fn from(resource: resources::Group) -> GoalPatientSearchTarget {
+        GoalPatientSearchTarget::Group(resource)
+    }
+}
+/// The subject reference search parameter of the Goal resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GoalSubjectSearchReference;
+impl ReferenceSearchParameter for GoalSubjectSearchReference {
+    type Resource = resources::Goal;
+    type Target = GoalSubjectSearchTarget;
+    fn parameter(&self) -> GoalSearchParameter {
+        GoalSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Goal resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GoalSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for GoalSubjectSearchTarget {
+    fn from(resource: resources::Group) -> GoalSubjectSearchTarget {
+        GoalSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Organization> for GoalSubjectSearchTarget {
+    fn from(resource: resources::Organization) -> GoalSubjectSearchTarget {
+        GoalSubjectSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for GoalSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> GoalSubjectSearchTarget {
+        GoalSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The member reference search parameter of the Group resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GroupMemberSearchReference;
+impl ReferenceSearchParameter for GroupMemberSearchReference {
+    type Resource = resources::Group;
+    type Target = GroupMemberSearchTarget;
+    fn parameter(&self) -> GroupSearchParameter {
+        GroupSearchParameter::Member
+    }
+}
+/// Target resources of the member reference search parameter of the Group resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GroupMemberSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+}
+impl From<resources::Practitioner> for GroupMemberSearchTarget {
+    fn from(resource: resources::Practitioner) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Device> for GroupMemberSearchTarget {
+    fn from(resource: resources::Device) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Medication> for GroupMemberSearchTarget {
+    fn from(resource: resources::Medication) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::Medication(resource)
+    }
+}
+impl From<resources::Patient> for GroupMemberSearchTarget {
+    fn from(resource: resources::Patient) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Substance> for GroupMemberSearchTarget {
+    fn from(resource: resources::Substance) -> GroupMemberSearchTarget {
+        GroupMemberSearchTarget::Substance(resource)
+    }
+}
+/// The patient reference search parameter of the GuidanceResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GuidanceResponsePatientSearchReference;
+impl ReferenceSearchParameter for GuidanceResponsePatientSearchReference {
+    type Resource = resources::GuidanceResponse;
+    type Target = resources::Patient;
+    fn parameter(&self) -> GuidanceResponseSearchParameter {
+        GuidanceResponseSearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the GuidanceResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GuidanceResponseSubjectSearchReference;
+impl ReferenceSearchParameter for GuidanceResponseSubjectSearchReference {
+    type Resource = resources::GuidanceResponse;
+    type Target = GuidanceResponseSubjectSearchTarget;
+    fn parameter(&self) -> GuidanceResponseSearchParameter {
+        GuidanceResponseSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the GuidanceResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GuidanceResponseSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for GuidanceResponseSubjectSearchTarget {
+    fn from(resource: resources::Group) -> GuidanceResponseSubjectSearchTarget {
+        GuidanceResponseSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for GuidanceResponseSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> GuidanceResponseSubjectSearchTarget {
+        GuidanceResponseSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The endpoint reference search parameter of the HealthcareService resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HealthcareServiceEndpointSearchReference;
+impl ReferenceSearchParameter for HealthcareServiceEndpointSearchReference {
+    type Resource = resources::HealthcareService;
+    type Target = resources::Endpoint;
+    fn parameter(&self) -> HealthcareServiceSearchParameter {
+        HealthcareServiceSearchParameter::Endpoint
+    }
+}
+/// The location reference search parameter of the HealthcareService resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HealthcareServiceLocationSearchReference;
+impl ReferenceSearchParameter for HealthcareServiceLocationSearchReference {
+    type Resource = resources::HealthcareService;
+    type Target = resources::Location;
+    fn parameter(&self) -> HealthcareServiceSearchParameter {
+        HealthcareServiceSearchParameter::Location
+    }
+}
+/// The organization reference search parameter of the HealthcareService resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct HealthcareServiceOrganizationSearchReference;
+impl ReferenceSearchParameter for HealthcareServiceOrganizationSearchReference {
+    type Resource = resources::HealthcareService;
+    type Target = resources::Organization;
+    fn parameter(&self) -> HealthcareServiceSearchParameter {
+        HealthcareServiceSearchParameter::Organization
+    }
+}
+/// The patient reference search parameter of the ImagingManifest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingManifestPatientSearchReference;
+impl ReferenceSearchParameter for ImagingManifestPatientSearchReference {
+    type Resource = resources::ImagingManifest;
+    type Target = ImagingManifestPatientSearchTarget;
+    fn parameter(&self) -> ImagingManifestSearchParameter {
+        ImagingManifestSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the ImagingManifest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImagingManifestPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for ImagingManifestPatientSearchTarget {
+    fn from(resource: resources::Patient) -> ImagingManifestPatientSearchTarget {
+        ImagingManifestPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for ImagingManifestPatientSearchTarget {
+    fn from(resource: resources::Group) -> ImagingManifestPatientSearchTarget {
+        ImagingManifestPatientSearchTarget::Group(resource)
+    }
+}
+/// The author reference search parameter of the ImagingManifest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingManifestAuthorSearchReference;
+impl ReferenceSearchParameter for ImagingManifestAuthorSearchReference {
+    type Resource = resources::ImagingManifest;
+    type Target = ImagingManifestAuthorSearchTarget;
+    fn parameter(&self) -> ImagingManifestSearchParameter {
+        ImagingManifestSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the ImagingManifest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImagingManifestAuthorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ImagingManifestAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> ImagingManifestAuthorSearchTarget {
+        ImagingManifestAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ImagingManifestAuthorSearchTarget {
+    fn from(resource: resources::Organization) -> ImagingManifestAuthorSearchTarget {
+        ImagingManifestAuthorSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for ImagingManifestAuthorSearchTarget {
+    fn from(resource: resources::Device) -> ImagingManifestAuthorSearchTarget {
+        ImagingManifestAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ImagingManifestAuthorSearchTarget {
+    fn from(resource: resources::Patient) -> ImagingManifestAuthorSearchTarget {
+        ImagingManifestAuthorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ImagingManifestAuthorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ImagingManifestAuthorSearchTarget {
+        ImagingManifestAuthorSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The endpoint reference search parameter of the ImagingManifest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingManifestEndpointSearchReference;
+impl ReferenceSearchParameter for ImagingManifestEndpointSearchReference {
+    type Resource = resources::ImagingManifest;
+    type Target = resources::Endpoint;
+    fn parameter(&self) -> ImagingManifestSearchParameter {
+        ImagingManifestSearchParameter::Endpoint
+    }
+}
+/// The imaging-study reference search parameter of the ImagingManifest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingManifestImagingStudySearchReference;
+impl ReferenceSearchParameter for ImagingManifestImagingStudySearchReference {
+    type Resource = resources::ImagingManifest;
+    type Target = resources::ImagingStudy;
+    fn parameter(&self) -> ImagingManifestSearchParameter {
+        ImagingManifestSearchParameter::ImagingStudy
+    }
+}
+/// The patient reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudyPatientSearchReference;
+impl ReferenceSearchParameter for ImagingStudyPatientSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = ImagingStudyPatientSearchTarget;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the ImagingStudy resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImagingStudyPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for ImagingStudyPatientSearchTarget {
+    fn from(resource: resources::Patient) -> ImagingStudyPatientSearchTarget {
+        ImagingStudyPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for ImagingStudyPatientSearchTarget {
+    fn from(resource: resources::Group) -> ImagingStudyPatientSearchTarget {
+        ImagingStudyPatientSearchTarget::Group(resource)
+    }
+}
+/// The basedon reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudyBasedonSearchReference;
+impl ReferenceSearchParameter for ImagingStudyBasedonSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = ImagingStudyBasedonSearchTarget;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::Basedon
+    }
+}
+/// Target resources of the basedon reference search parameter of the ImagingStudy resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImagingStudyBasedonSearchTarget {
+    /// Variant for ReferralRequest target resources
+    ReferralRequest(resources::ReferralRequest),
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for ProcedureRequest target resources
+    ProcedureRequest(resources::ProcedureRequest),
+}
+impl From<resources::ReferralRequest> for ImagingStudyBasedonSearchTarget {
+    fn from(resource: resources::ReferralRequest) -> ImagingStudyBasedonSearchTarget {
+        ImagingStudyBasedonSearchTarget::ReferralRequest(resource)
+    }
+}
+impl From<resources::CarePlan> for ImagingStudyBasedonSearchTarget {
+    fn from(resource: resources::CarePlan) -> ImagingStudyBasedonSearchTarget {
+        ImagingStudyBasedonSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::ProcedureRequest> for ImagingStudyBasedonSearchTarget {
+    fn from(resource: resources::ProcedureRequest) -> ImagingStudyBasedonSearchTarget {
+        ImagingStudyBasedonSearchTarget::ProcedureRequest(resource)
+    }
+}
+/// The context reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudyContextSearchReference;
+impl ReferenceSearchParameter for ImagingStudyContextSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = ImagingStudyContextSearchTarget;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the ImagingStudy resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImagingStudyContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for ImagingStudyContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> ImagingStudyContextSearchTarget {
+        ImagingStudyContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for ImagingStudyContextSearchTarget {
+    fn from(resource: resources::Encounter) -> ImagingStudyContextSearchTarget {
+        ImagingStudyContextSearchTarget::Encounter(resource)
+    }
+}
+/// The endpoint reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudyEndpointSearchReference;
+impl ReferenceSearchParameter for ImagingStudyEndpointSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = resources::Endpoint;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::Endpoint
+    }
+}
+/// The performer reference search parameter of the ImagingStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImagingStudyPerformerSearchReference;
+impl ReferenceSearchParameter for ImagingStudyPerformerSearchReference {
+    type Resource = resources::ImagingStudy;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> ImagingStudySearchParameter {
+        ImagingStudySearchParameter::Performer
+    }
+}
+/// The patient reference search parameter of the Immunization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationPatientSearchReference;
+impl ReferenceSearchParameter for ImmunizationPatientSearchReference {
+    type Resource = resources::Immunization;
+    type Target = ImmunizationPatientSearchTarget;
+    fn parameter(&self) -> ImmunizationSearchParameter {
+        ImmunizationSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the Immunization resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImmunizationPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for ImmunizationPatientSearchTarget {
+    fn from(resource: resources::Patient) -> ImmunizationPatientSearchTarget {
+        ImmunizationPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for ImmunizationPatientSearchTarget {
+    fn from(resource: resources::Group) -> ImmunizationPatientSearchTarget {
+        ImmunizationPatientSearchTarget::Group(resource)
+    }
+}
+/// The location reference search parameter of the Immunization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationLocationSearchReference;
+impl ReferenceSearchParameter for ImmunizationLocationSearchReference {
+    type Resource = resources::Immunization;
+    type Target = resources::Location;
+    fn parameter(&self) -> ImmunizationSearchParameter {
+        ImmunizationSearchParameter::Location
+    }
+}
+/// The manufacturer reference search parameter of the Immunization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationManufacturerSearchReference;
+impl ReferenceSearchParameter for ImmunizationManufacturerSearchReference {
+    type Resource = resources::Immunization;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ImmunizationSearchParameter {
+        ImmunizationSearchParameter::Manufacturer
+    }
+}
+/// The practitioner reference search parameter of the Immunization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationPractitionerSearchReference;
+impl ReferenceSearchParameter for ImmunizationPractitionerSearchReference {
+    type Resource = resources::Immunization;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> ImmunizationSearchParameter {
+        ImmunizationSearchParameter::Practitioner
+    }
+}
+/// The reaction reference search parameter of the Immunization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationReactionSearchReference;
+impl ReferenceSearchParameter for ImmunizationReactionSearchReference {
+    type Resource = resources::Immunization;
+    type Target = resources::Observation;
+    fn parameter(&self) -> ImmunizationSearchParameter {
+        ImmunizationSearchParameter::Reaction
+    }
+}
+/// The information reference search parameter of the ImmunizationRecommendation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationRecommendationInformationSearchReference;
+impl ReferenceSearchParameter for ImmunizationRecommendationInformationSearchReference {
+    type Resource = resources::ImmunizationRecommendation;
+    type Target = ImmunizationRecommendationInformationSearchTarget;
+    fn parameter(&self) -> ImmunizationRecommendationSearchParameter {
+        ImmunizationRecommendationSearchParameter::Information
+    }
+}
+/// Target resources of the information reference search parameter of the ImmunizationRecommendation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ImmunizationRecommendationInformationSearchTarget {
+    /// Variant for AllergyIntolerance target resources
+    AllergyIntolerance(resources::AllergyIntolerance),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+}
+impl From<resources::AllergyIntolerance> for ImmunizationRecommendationInformationSearchTarget {
+    fn from(resource: resources::AllergyIntolerance) -> ImmunizationRecommendationInformationSearchTarget {
+        ImmunizationRecommendationInformationSearchTarget::AllergyIntolerance(resource)
+    }
+}
+impl From<resources::Observation> for ImmunizationRecommendationInformationSearchTarget {
+    fn from(resource: resources::Observation) -> ImmunizationRecommendationInformationSearchTarget {
+        ImmunizationRecommendationInformationSearchTarget::Observation(resource)
+    }
+}
+/// The patient reference search parameter of the ImmunizationRecommendation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationRecommendationPatientSearchReference;
+impl ReferenceSearchParameter for ImmunizationRecommendationPatientSearchReference {
+    type Resource = resources::ImmunizationRecommendation;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ImmunizationRecommendationSearchParameter {
+        ImmunizationRecommendationSearchParameter::Patient
+    }
+}
+/// The support reference search parameter of the ImmunizationRecommendation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImmunizationRecommendationSupportSearchReference;
+impl ReferenceSearchParameter for ImmunizationRecommendationSupportSearchReference {
+    type Resource = resources::ImmunizationRecommendation;
+    type Target = resources::Immunization;
+    fn parameter(&self) -> ImmunizationRecommendationSearchParameter {
+        ImmunizationRecommendationSearchParameter::Support
+    }
+}
+/// The resource reference search parameter of the ImplementationGuide resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ImplementationGuideResourceSearchReference;
+impl ReferenceSearchParameter for ImplementationGuideResourceSearchReference {
+    type Resource = resources::ImplementationGuide;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ImplementationGuideSearchParameter {
+        ImplementationGuideSearchParameter::Resource
+    }
+}
+/// The composed-of reference search parameter of the Library resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LibraryComposedOfSearchReference;
+impl ReferenceSearchParameter for LibraryComposedOfSearchReference {
+    type Resource = resources::Library;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LibrarySearchParameter {
+        LibrarySearchParameter::ComposedOf
+    }
+}
+/// The depends-on reference search parameter of the Library resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LibraryDependsOnSearchReference;
+impl ReferenceSearchParameter for LibraryDependsOnSearchReference {
+    type Resource = resources::Library;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LibrarySearchParameter {
+        LibrarySearchParameter::DependsOn
+    }
+}
+/// The derived-from reference search parameter of the Library resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LibraryDerivedFromSearchReference;
+impl ReferenceSearchParameter for LibraryDerivedFromSearchReference {
+    type Resource = resources::Library;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LibrarySearchParameter {
+        LibrarySearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the Library resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LibraryPredecessorSearchReference;
+impl ReferenceSearchParameter for LibraryPredecessorSearchReference {
+    type Resource = resources::Library;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LibrarySearchParameter {
+        LibrarySearchParameter::Predecessor
+    }
+}
+/// The successor reference search parameter of the Library resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LibrarySuccessorSearchReference;
+impl ReferenceSearchParameter for LibrarySuccessorSearchReference {
+    type Resource = resources::Library;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LibrarySearchParameter {
+        LibrarySearchParameter::Successor
+    }
+}
+/// The author reference search parameter of the Linkage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LinkageAuthorSearchReference;
+impl ReferenceSearchParameter for LinkageAuthorSearchReference {
+    type Resource = resources::Linkage;
+    type Target = LinkageAuthorSearchTarget;
+    fn parameter(&self) -> LinkageSearchParameter {
+        LinkageSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the Linkage resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LinkageAuthorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+}
+impl From<resources::Practitioner> for LinkageAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> LinkageAuthorSearchTarget {
+        LinkageAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for LinkageAuthorSearchTarget {
+    fn from(resource: resources::Organization) -> LinkageAuthorSearchTarget {
+        LinkageAuthorSearchTarget::Organization(resource)
+    }
+}
+/// The item reference search parameter of the Linkage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LinkageItemSearchReference;
+impl ReferenceSearchParameter for LinkageItemSearchReference {
+    type Resource = resources::Linkage;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LinkageSearchParameter {
+        LinkageSearchParameter::Item
+    }
+}
+/// The source reference search parameter of the Linkage resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LinkageSourceSearchReference;
+impl ReferenceSearchParameter for LinkageSourceSearchReference {
+    type Resource = resources::Linkage;
+    type Target = resources::Resource;
+    fn parameter(&self) -> LinkageSearchParameter {
+        LinkageSearchParameter::Source
+    }
+}
+/// The patient reference search parameter of the List resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListPatientSearchReference;
+impl ReferenceSearchParameter for ListPatientSearchReference {
+    type Resource = resources::List;
+    type Target = ListPatientSearchTarget;
+    fn parameter(&self) -> ListSearchParameter {
+        ListSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the List resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ListPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for ListPatientSearchTarget {
+    fn from(resource: resources::Patient) -> ListPatientSearchTarget {
+        ListPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for ListPatientSearchTarget {
+    fn from(resource: resources::Group) -> ListPatientSearchTarget {
+        ListPatientSearchTarget::Group(resource)
+    }
+}
+/// The encounter reference search parameter of the List resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListEncounterSearchReference;
+impl ReferenceSearchParameter for ListEncounterSearchReference {
+    type Resource = resources::List;
+    type Target = ListEncounterSearchTarget;
+    fn parameter(&self) -> ListSearchParameter {
+        ListSearchParameter::Encounter
+    }
+}
+/// Target resources of the encounter reference search parameter of the List resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ListEncounterSearchTarget {
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+}
+impl From<resources::Encounter> for ListEncounterSearchTarget {
+    fn from(resource: resources::Encounter) -> ListEncounterSearchTarget {
+        ListEncounterSearchTarget::Encounter(resource)
+    }
+}
+impl From<resources::EpisodeOfCare> for ListEncounterSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> ListEncounterSearchTarget {
+        ListEncounterSearchTarget::EpisodeOfCare(resource)
+    }
+}
+/// The item reference search parameter of the List resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListItemSearchReference;
+impl ReferenceSearchParameter for ListItemSearchReference {
+    type Resource = resources::List;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ListSearchParameter {
+        ListSearchParameter::Item
+    }
+}
+/// The source reference search parameter of the List resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListSourceSearchReference;
+impl ReferenceSearchParameter for ListSourceSearchReference {
+    type Resource = resources::List;
+    type Target = ListSourceSearchTarget;
+    fn parameter(&self) -> ListSearchParameter {
+        ListSearchParameter::Source
+    }
+}
+/// Target resources of the source reference search parameter of the List resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ListSourceSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Practitioner> for ListSourceSearchTarget {
+    fn from(resource: resources::Practitioner) -> ListSourceSearchTarget {
+        ListSourceSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Device> for ListSourceSearchTarget {
+    fn from(resource: resources::Device) -> ListSourceSearchTarget {
+        ListSourceSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ListSourceSearchTarget {
+    fn from(resource: resources::Patient) -> ListSourceSearchTarget {
+        ListSourceSearchTarget::Patient(resource)
+    }
+}
+/// The subject reference search parameter of the List resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListSubjectSearchReference;
+impl ReferenceSearchParameter for ListSubjectSearchReference {
+    type Resource = resources::List;
+    type Target = ListSubjectSearchTarget;
+    fn parameter(&self) -> ListSearchParameter {
+        ListSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the List resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ListSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Group> for ListSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ListSubjectSearchTarget {
+        ListSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Device> for ListSubjectSearchTarget {
+    fn from(resource: resources::Device) -> ListSubjectSearchTarget {
+        ListSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ListSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ListSubjectSearchTarget {
+        ListSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Location> for ListSubjectSearchTarget {
+    fn from(resource: resources::Location) -> ListSubjectSearchTarget {
+        ListSubjectSearchTarget::Location(resource)
+    }
+}
+/// The endpoint reference search parameter of the Location resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LocationEndpointSearchReference;
+impl ReferenceSearchParameter for LocationEndpointSearchReference {
+    type Resource = resources::Location;
+    type Target = resources::Endpoint;
+    fn parameter(&self) -> LocationSearchParameter {
+        LocationSearchParameter::Endpoint
+    }
+}
+/// The organization reference search parameter of the Location resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LocationOrganizationSearchReference;
+impl ReferenceSearchParameter for LocationOrganizationSearchReference {
+    type Resource = resources::Location;
+    type Target = resources::Organization;
+    fn parameter(&self) -> LocationSearchParameter {
+        LocationSearchParameter::Organization
+    }
+}
+/// The partof reference search parameter of the Location resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LocationPartofSearchReference;
+impl ReferenceSearchParameter for LocationPartofSearchReference {
+    type Resource = resources::Location;
+    type Target = resources::Location;
+    fn parameter(&self) -> LocationSearchParameter {
+        LocationSearchParameter::Partof
+    }
+}
+/// The composed-of reference search parameter of the Measure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureComposedOfSearchReference;
+impl ReferenceSearchParameter for MeasureComposedOfSearchReference {
+    type Resource = resources::Measure;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MeasureSearchParameter {
+        MeasureSearchParameter::ComposedOf
+    }
+}
+/// The depends-on reference search parameter of the Measure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureDependsOnSearchReference;
+impl ReferenceSearchParameter for MeasureDependsOnSearchReference {
+    type Resource = resources::Measure;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MeasureSearchParameter {
+        MeasureSearchParameter::DependsOn
+    }
+}
+/// The derived-from reference search parameter of the Measure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureDerivedFromSearchReference;
+impl ReferenceSearchParameter for MeasureDerivedFromSearchReference {
+    type Resource = resources::Measure;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MeasureSearchParameter {
+        MeasureSearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the Measure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasurePredecessorSearchReference;
+impl ReferenceSearchParameter for MeasurePredecessorSearchReference {
+    type Resource = resources::Measure;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MeasureSearchParameter {
+        MeasureSearchParameter::Predecessor
+    }
+}
+/// The successor reference search parameter of the Measure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureSuccessorSearchReference;
+impl ReferenceSearchParameter for MeasureSuccessorSearchReference {
+    type Resource = resources::Measure;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MeasureSearchParameter {
+        MeasureSearchParameter::Successor
+    }
+}
+/// The patient reference search parameter of the MeasureReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MeasureReportPatientSearchReference;
+impl ReferenceSearchParameter for MeasureReportPatientSearchReference {
+    type Resource = resources::MeasureReport;
+    type Target = resources::Patient;
+    fn parameter(&self) -> MeasureReportSearchParameter {
+        MeasureReportSearchParameter::Patient
+    }
+}
+/// The based-on reference search parameter of the Media resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MediaBasedOnSearchReference;
+impl ReferenceSearchParameter for MediaBasedOnSearchReference {
+    type Resource = resources::Media;
+    type Target = resources::ProcedureRequest;
+    fn parameter(&self) -> MediaSearchParameter {
+        MediaSearchParameter::BasedOn
+    }
+}
+/// The context reference search parameter of the Media resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MediaContextSearchReference;
+impl ReferenceSearchParameter for MediaContextSearchReference {
+    type Resource = resources::Media;
+    type Target = MediaContextSearchTarget;
+    fn parameter(&self) -> MediaSearchParameter {
+        MediaSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the Media resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MediaContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for MediaContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> MediaContextSearchTarget {
+        MediaContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for MediaContextSearchTarget {
+    fn from(resource: resources::Encounter) -> MediaContextSearchTarget {
+        MediaContextSearchTarget::Encounter(resource)
+    }
+}
+/// The device reference search parameter of the Media resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MediaDeviceSearchReference;
+impl ReferenceSearchParameter for MediaDeviceSearchReference {
+    type Resource = resources::Media;
+    type Target = MediaDeviceSearchTarget;
+    fn parameter(&self) -> MediaSearchParameter {
+        MediaSearchParameter::Device
+    }
+}
+/// Target resources of the device reference search parameter of the Media resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MediaDeviceSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for DeviceMetric target resources
+    DeviceMetric(resources::DeviceMetric),
+}
+impl From<resources::Device> for MediaDeviceSearchTarget {
+    fn from(resource: resources::Device) -> MediaDeviceSearchTarget {
+        MediaDeviceSearchTarget::Device(resource)
+    }
+}
+impl From<resources::DeviceMetric> for MediaDeviceSearchTarget {
+    fn from(resource: resources::DeviceMetric) -> MediaDeviceSearchTarget {
+        MediaDeviceSearchTarget::DeviceMetric(resource)
+    }
+}
+/// The operator reference search parameter of the Media resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MediaOperatorSearchReference;
+impl ReferenceSearchParameter for MediaOperatorSearchReference {
+    type Resource = resources::Media;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> MediaSearchParameter {
+        MediaSearchParameter::Operator
+    }
+}
+/// The patient reference search parameter of the Media resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MediaPatientSearchReference;
+impl ReferenceSearchParameter for MediaPatientSearchReference {
+    type Resource = resources::Media;
+    type Target = resources::Patient;
+    fn parameter(&self) -> MediaSearchParameter {
+        MediaSearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the Media resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MediaSubjectSearchReference;
+impl ReferenceSearchParameter for MediaSubjectSearchReference {
+    type Resource = resources::Media;
+    type Target = MediaSubjectSearchTarget;
+    fn parameter(&self) -> MediaSearchParameter {
+        MediaSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Media resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MediaSubjectSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Specimen target resources
+    Specimen(resources::Specimen),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Practitioner> for MediaSubjectSearchTarget {
+    fn from(resource: resources::Practitioner) -> MediaSubjectSearchTarget {
+        MediaSubjectSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Group> for MediaSubjectSearchTarget {
+    fn from(resource: resources::Group) -> MediaSubjectSearchTarget {
+        MediaSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Specimen> for MediaSubjectSearchTarget {
+    fn from(resource: resources::Specimen) -> MediaSubjectSearchTarget {
+        MediaSubjectSearchTarget::Specimen(resource)
+    }
+}
+impl From<resources::Device> for MediaSubjectSearchTarget {
+    fn from(resource: resources::Device) -> MediaSubjectSearchTarget {
+        MediaSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for MediaSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> MediaSubjectSearchTarget {
+        MediaSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The ingredient reference search parameter of the Medication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationIngredientSearchReference;
+impl ReferenceSearchParameter for MedicationIngredientSearchReference {
+    type Resource = resources::Medication;
+    type Target = MedicationIngredientSearchTarget;
+    fn parameter(&self) -> MedicationSearchParameter {
+        MedicationSearchParameter::Ingredient
+    }
+}
+/// Target resources of the ingredient reference search parameter of the Medication resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationIngredientSearchTarget {
+    /// Variant for Medication target resources
+    Medication(resources::Medication),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+}
+impl From<resources::Medication> for MedicationIngredientSearchTarget {
+    fn from(resource: resources::Medication) -> MedicationIngredientSearchTarget {
+        MedicationIngredientSearchTarget::Medication(resource)
+    }
+}
+impl From<resources::Substance> for MedicationIngredientSearchTarget {
+    fn from(resource: resources::Substance) -> MedicationIngredientSearchTarget {
+        MedicationIngredientSearchTarget::Substance(resource)
+    }
+}
+/// The manufacturer reference search parameter of the Medication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationManufacturerSearchReference;
+impl ReferenceSearchParameter for MedicationManufacturerSearchReference {
+    type Resource = resources::Medication;
+    type Target = resources::Organization;
+    fn parameter(&self) -> MedicationSearchParameter {
+        MedicationSearchParameter::Manufacturer
+    }
+}
+/// The package-item reference search parameter of the Medication resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationPackageItemSearchReference;
+impl ReferenceSearchParameter for MedicationPackageItemSearchReference {
+    type Resource = resources::Medication;
+    type Target = resources::Medication;
+    fn parameter(&self) -> MedicationSearchParameter {
+        MedicationSearchParameter::PackageItem
+    }
+}
+/// The context reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationContextSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationContextSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = MedicationAdministrationContextSearchTarget;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the MedicationAdministration resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationAdministrationContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for MedicationAdministrationContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> MedicationAdministrationContextSearchTarget {
+        MedicationAdministrationContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for MedicationAdministrationContextSearchTarget {
+    fn from(resource: resources::Encounter) -> MedicationAdministrationContextSearchTarget {
+        MedicationAdministrationContextSearchTarget::Encounter(resource)
+    }
+}
+/// The device reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationDeviceSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationDeviceSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = resources::Device;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Device
+    }
+}
+/// The medication reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationMedicationSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationMedicationSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = resources::Medication;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Medication
+    }
+}
+/// The patient reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationPatientSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationPatientSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = resources::Patient;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Patient
+    }
+}
+/// The performer reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationPerformerSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationPerformerSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = MedicationAdministrationPerformerSearchTarget;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the MedicationAdministration resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationAdministrationPerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for MedicationAdministrationPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> MedicationAdministrationPerformerSearchTarget {
+        MedicationAdministrationPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Device> for MedicationAdministrationPerformerSearchTarget {
+    fn from(resource: resources::Device) -> MedicationAdministrationPerformerSearchTarget {
+        MedicationAdministrationPerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for MedicationAdministrationPerformerSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationAdministrationPerformerSearchTarget {
+        MedicationAdministrationPerformerSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for MedicationAdministrationPerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> MedicationAdministrationPerformerSearchTarget {
+        MedicationAdministrationPerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The prescription reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationPrescriptionSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationPrescriptionSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = resources::MedicationRequest;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Prescription
+    }
+}
+/// The subject reference search parameter of the MedicationAdministration resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationAdministrationSubjectSearchReference;
+impl ReferenceSearchParameter for MedicationAdministrationSubjectSearchReference {
+    type Resource = resources::MedicationAdministration;
+    type Target = MedicationAdministrationSubjectSearchTarget;
+    fn parameter(&self) -> MedicationAdministrationSearchParameter {
+        MedicationAdministrationSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the MedicationAdministration resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationAdministrationSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for MedicationAdministrationSubjectSearchTarget {
+    fn from(resource: resources::Group) -> MedicationAdministrationSubjectSearchTarget {
+        MedicationAdministrationSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for MedicationAdministrationSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationAdministrationSubjectSearchTarget {
+        MedicationAdministrationSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The medication reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispenseMedicationSearchReference;
+impl ReferenceSearchParameter for MedicationDispenseMedicationSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = resources::Medication;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Medication
+    }
+}
+/// The patient reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispensePatientSearchReference;
+impl ReferenceSearchParameter for MedicationDispensePatientSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = resources::Patient;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Patient
+    }
+}
+/// The prescription reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispensePrescriptionSearchReference;
+impl ReferenceSearchParameter for MedicationDispensePrescriptionSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = resources::MedicationRequest;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Prescription
+    }
+}
+/// The context reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispenseContextSearchReference;
+impl ReferenceSearchParameter for MedicationDispenseContextSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = MedicationDispenseContextSearchTarget;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the MedicationDispense resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationDispenseContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for MedicationDispenseContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> MedicationDispenseContextSearchTarget {
+        MedicationDispenseContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for MedicationDispenseContextSearchTarget {
+    fn from(resource: resources::Encounter) -> MedicationDispenseContextSearchTarget {
+        MedicationDispenseContextSearchTarget::Encounter(resource)
+    }
+}
+/// The destination reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispenseDestinationSearchReference;
+impl ReferenceSearchParameter for MedicationDispenseDestinationSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = resources::Location;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Destination
+    }
+}
+/// The performer reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispensePerformerSearchReference;
+impl ReferenceSearchParameter for MedicationDispensePerformerSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = MedicationDispensePerformerSearchTarget;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the MedicationDispense resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationDispensePerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for MedicationDispensePerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> MedicationDispensePerformerSearchTarget {
+        MedicationDispensePerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for MedicationDispensePerformerSearchTarget {
+    fn from(resource: resources::Organization) -> MedicationDispensePerformerSearchTarget {
+        MedicationDispensePerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for MedicationDispensePerformerSearchTarget {
+    fn from(resource: resources::Device) -> MedicationDispensePerformerSearchTarget {
+        MedicationDispensePerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for MedicationDispensePerformerSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationDispensePerformerSearchTarget {
+        MedicationDispensePerformerSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for MedicationDispensePerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> MedicationDispensePerformerSearchTarget {
+        MedicationDispensePerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The receiver reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispenseReceiverSearchReference;
+impl ReferenceSearchParameter for MedicationDispenseReceiverSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = MedicationDispenseReceiverSearchTarget;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Receiver
+    }
+}
+/// Target resources of the receiver reference search parameter of the MedicationDispense resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationDispenseReceiverSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Practitioner> for MedicationDispenseReceiverSearchTarget {
+    fn from(resource: resources::Practitioner) -> MedicationDispenseReceiverSearchTarget {
+        MedicationDispenseReceiverSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for MedicationDispenseReceiverSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationDispenseReceiverSearchTarget {
+        MedicationDispenseReceiverSearchTarget::Patient(resource)
+    }
+}
+/// The responsibleparty reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispenseResponsiblepartySearchReference;
+impl ReferenceSearchParameter for MedicationDispenseResponsiblepartySearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Responsibleparty
+    }
+}
+/// The subject reference search parameter of the MedicationDispense resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationDispenseSubjectSearchReference;
+impl ReferenceSearchParameter for MedicationDispenseSubjectSearchReference {
+    type Resource = resources::MedicationDispense;
+    type Target = MedicationDispenseSubjectSearchTarget;
+    fn parameter(&self) -> MedicationDispenseSearchParameter {
+        MedicationDispenseSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the MedicationDispense resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationDispenseSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for MedicationDispenseSubjectSearchTarget {
+    fn from(resource: resources::Group) -> MedicationDispenseSubjectSearchTarget {
+        MedicationDispenseSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for MedicationDispenseSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationDispenseSubjectSearchTarget {
+        MedicationDispenseSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The medication reference search parameter of the MedicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationRequestMedicationSearchReference;
+impl ReferenceSearchParameter for MedicationRequestMedicationSearchReference {
+    type Resource = resources::MedicationRequest;
+    type Target = resources::Medication;
+    fn parameter(&self) -> MedicationRequestSearchParameter {
+        MedicationRequestSearchParameter::Medication
+    }
+}
+/// The patient reference search parameter of the MedicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationRequestPatientSearchReference;
+impl ReferenceSearchParameter for MedicationRequestPatientSearchReference {
+    type Resource = resources::MedicationRequest;
+    type Target = resources::Patient;
+    fn parameter(&self) -> MedicationRequestSearchParameter {
+        MedicationRequestSearchParameter::Patient
+    }
+}
+/// The context reference search parameter of the MedicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationRequestContextSearchReference;
+impl ReferenceSearchParameter for MedicationRequestContextSearchReference {
+    type Resource = resources::MedicationRequest;
+    type Target = MedicationRequestContextSearchTarget;
+    fn parameter(&self) -> MedicationRequestSearchParameter {
+        MedicationRequestSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the MedicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationRequestContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for MedicationRequestContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> MedicationRequestContextSearchTarget {
+        MedicationRequestContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for MedicationRequestContextSearchTarget {
+    fn from(resource: resources::Encounter) -> MedicationRequestContextSearchTarget {
+        MedicationRequestContextSearchTarget::Encounter(resource)
+    }
+}
+/// The intended-dispenser reference search parameter of the MedicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationRequestIntendedDispenserSearchReference;
+impl ReferenceSearchParameter for MedicationRequestIntendedDispenserSearchReference {
+    type Resource = resources::MedicationRequest;
+    type Target = resources::Organization;
+    fn parameter(&self) -> MedicationRequestSearchParameter {
+        MedicationRequestSearchParameter::IntendedDispenser
+    }
+}
+/// The requester reference search parameter of the MedicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationRequestRequesterSearchReference;
+impl ReferenceSearchParameter for MedicationRequestRequesterSearchReference {
+    type Resource = resources::MedicationRequest;
+    type Target = MedicationRequestRequesterSearchTarget;
+    fn parameter(&self) -> MedicationRequestSearchParameter {
+        MedicationRequestSearchParameter::Requester
+    }
+}
+/// Target resources of the requester reference search parameter of the MedicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationRequestRequesterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for MedicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> MedicationRequestRequesterSearchTarget {
+        MedicationRequestRequesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for MedicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Organization) -> MedicationRequestRequesterSearchTarget {
+        MedicationRequestRequesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for MedicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Device) -> MedicationRequestRequesterSearchTarget {
+        MedicationRequestRequesterSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for MedicationRequestRequesterSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationRequestRequesterSearchTarget {
+        MedicationRequestRequesterSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for MedicationRequestRequesterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> MedicationRequestRequesterSearchTarget {
+        MedicationRequestRequesterSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subject reference search parameter of the MedicationRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationRequestSubjectSearchReference;
+impl ReferenceSearchParameter for MedicationRequestSubjectSearchReference {
+    type Resource = resources::MedicationRequest;
+    type Target = MedicationRequestSubjectSearchTarget;
+    fn parameter(&self) -> MedicationRequestSearchParameter {
+        MedicationRequestSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the MedicationRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationRequestSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for MedicationRequestSubjectSearchTarget {
+    fn from(resource: resources::Group) -> MedicationRequestSubjectSearchTarget {
+        MedicationRequestSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for MedicationRequestSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationRequestSubjectSearchTarget {
+        MedicationRequestSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The medication reference search parameter of the MedicationStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationStatementMedicationSearchReference;
+impl ReferenceSearchParameter for MedicationStatementMedicationSearchReference {
+    type Resource = resources::MedicationStatement;
+    type Target = resources::Medication;
+    fn parameter(&self) -> MedicationStatementSearchParameter {
+        MedicationStatementSearchParameter::Medication
+    }
+}
+/// The patient reference search parameter of the MedicationStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationStatementPatientSearchReference;
+impl ReferenceSearchParameter for MedicationStatementPatientSearchReference {
+    type Resource = resources::MedicationStatement;
+    type Target = resources::Patient;
+    fn parameter(&self) -> MedicationStatementSearchParameter {
+        MedicationStatementSearchParameter::Patient
+    }
+}
+/// The context reference search parameter of the MedicationStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationStatementContextSearchReference;
+impl ReferenceSearchParameter for MedicationStatementContextSearchReference {
+    type Resource = resources::MedicationStatement;
+    type Target = MedicationStatementContextSearchTarget;
+    fn parameter(&self) -> MedicationStatementSearchParameter {
+        MedicationStatementSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the MedicationStatement resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationStatementContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for MedicationStatementContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> MedicationStatementContextSearchTarget {
+        MedicationStatementContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for MedicationStatementContextSearchTarget {
+    fn from(resource: resources::Encounter) -> MedicationStatementContextSearchTarget {
+        MedicationStatementContextSearchTarget::Encounter(resource)
+    }
+}
+/// The part-of reference search parameter of the MedicationStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationStatementPartOfSearchReference;
+impl ReferenceSearchParameter for MedicationStatementPartOfSearchReference {
+    type Resource = resources::MedicationStatement;
+    type Target = MedicationStatementPartOfSearchTarget;
+    fn parameter(&self) -> MedicationStatementSearchParameter {
+        MedicationStatementSearchParameter::PartOf
+    }
+}
+/// Target resources of the part-of reference search parameter of the MedicationStatement resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationStatementPartOfSearchTarget {
+    /// Variant for MedicationDispense target resources
+    MedicationDispense(resources::MedicationDispense),
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for MedicationAdministration target resources
+    MedicationAdministration(resources::MedicationAdministration),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for MedicationStatement target resources
+    MedicationStatement(resources::MedicationStatement),
+}
+impl From<resources::MedicationDispense> for MedicationStatementPartOfSearchTarget {
+    fn from(resource: resources::MedicationDispense) -> MedicationStatementPartOfSearchTarget {
+        MedicationStatementPartOfSearchTarget::MedicationDispense(resource)
+    }
+}
+impl From<resources::Observation> for MedicationStatementPartOfSearchTarget {
+    fn from(resource: resources::Observation) -> MedicationStatementPartOfSearchTarget {
+        MedicationStatementPartOfSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::MedicationAdministration> for MedicationStatementPartOfSearchTarget {
+    fn from(resource: resources::MedicationAdministration) -> MedicationStatementPartOfSearchTarget {
+        MedicationStatementPartOfSearchTarget::MedicationAdministration(resource)
+    }
+}
+impl From<resources::Procedure> for MedicationStatementPartOfSearchTarget {
+    fn from(resource: resources::Procedure) -> MedicationStatementPartOfSearchTarget {
+        MedicationStatementPartOfSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::MedicationStatement> for MedicationStatementPartOfSearchTarget {
+    fn from(resource: resources::MedicationStatement) -> MedicationStatementPartOfSearchTarget {
+        MedicationStatementPartOfSearchTarget::MedicationStatement(resource)
+    }
+}
+/// The source reference search parameter of the MedicationStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationStatementSourceSearchReference;
+impl ReferenceSearchParameter for MedicationStatementSourceSearchReference {
+    type Resource = resources::MedicationStatement;
+    type Target = MedicationStatementSourceSearchTarget;
+    fn parameter(&self) -> MedicationStatementSearchParameter {
+        MedicationStatementSearchParameter::Source
+    }
+}
+/// Target resources of the source reference search parameter of the MedicationStatement resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationStatementSourceSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for MedicationStatementSourceSearchTarget {
+    fn from(resource: resources::Practitioner) -> MedicationStatementSourceSearchTarget {
+        MedicationStatementSourceSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for MedicationStatementSourceSearchTarget {
+    fn from(resource: resources::Organization) -> MedicationStatementSourceSearchTarget {
+        MedicationStatementSourceSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for MedicationStatementSourceSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationStatementSourceSearchTarget {
+        MedicationStatementSourceSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for MedicationStatementSourceSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> MedicationStatementSourceSearchTarget {
+        MedicationStatementSourceSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subject reference search parameter of the MedicationStatement resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MedicationStatementSubjectSearchReference;
+impl ReferenceSearchParameter for MedicationStatementSubjectSearchReference {
+    type Resource = resources::MedicationStatement;
+    type Target = MedicationStatementSubjectSearchTarget;
+    fn parameter(&self) -> MedicationStatementSearchParameter {
+        MedicationStatementSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the MedicationStatement resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MedicationStatementSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for MedicationStatementSubjectSearchTarget {
+    fn from(resource: resources::Group) -> MedicationStatementSubjectSearchTarget {
+        MedicationStatementSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for MedicationStatementSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> MedicationStatementSubjectSearchTarget {
+        MedicationStatementSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The author reference search parameter of the MessageHeader resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageHeaderAuthorSearchReference;
+impl ReferenceSearchParameter for MessageHeaderAuthorSearchReference {
+    type Resource = resources::MessageHeader;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> MessageHeaderSearchParameter {
+        MessageHeaderSearchParameter::Author
+    }
+}
+/// The enterer reference search parameter of the MessageHeader resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageHeaderEntererSearchReference;
+impl ReferenceSearchParameter for MessageHeaderEntererSearchReference {
+    type Resource = resources::MessageHeader;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> MessageHeaderSearchParameter {
+        MessageHeaderSearchParameter::Enterer
+    }
+}
+/// The focus reference search parameter of the MessageHeader resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageHeaderFocusSearchReference;
+impl ReferenceSearchParameter for MessageHeaderFocusSearchReference {
+    type Resource = resources::MessageHeader;
+    type Target = resources::Resource;
+    fn parameter(&self) -> MessageHeaderSearchParameter {
+        MessageHeaderSearchParameter::Focus
+    }
+}
+/// The receiver reference search parameter of the MessageHeader resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageHeaderReceiverSearchReference;
+impl ReferenceSearchParameter for MessageHeaderReceiverSearchReference {
+    type Resource = resources::MessageHeader;
+    type Target = MessageHeaderReceiverSearchTarget;
+    fn parameter(&self) -> MessageHeaderSearchParameter {
+        MessageHeaderSearchParameter::Receiver
+    }
+}
+/// Target resources of the receiver reference search parameter of the MessageHeader resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MessageHeaderReceiverSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+}
+impl From<resources::Practitioner> for MessageHeaderReceiverSearchTarget {
+    fn from(resource: resources::Practitioner) -> MessageHeaderReceiverSearchTarget {
+        MessageHeaderReceiverSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for MessageHeaderReceiverSearchTarget {
+    fn from(resource: resources::Organization) -> MessageHeaderReceiverSearchTarget {
+        MessageHeaderReceiverSearchTarget::Organization(resource)
+    }
+}
+/// The responsible reference search parameter of the MessageHeader resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageHeaderResponsibleSearchReference;
+impl ReferenceSearchParameter for MessageHeaderResponsibleSearchReference {
+    type Resource = resources::MessageHeader;
+    type Target = MessageHeaderResponsibleSearchTarget;
+    fn parameter(&self) -> MessageHeaderSearchParameter {
+        MessageHeaderSearchParameter::Responsible
+    }
+}
+/// Target resources of the responsible reference search parameter of the MessageHeader resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MessageHeaderResponsibleSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+}
+impl From<resources::Practitioner> for MessageHeaderResponsibleSearchTarget {
+    fn from(resource: resources::Practitioner) -> MessageHeaderResponsibleSearchTarget {
+        MessageHeaderResponsibleSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for MessageHeaderResponsibleSearchTarget {
+    fn from(resource: resources::Organization) -> MessageHeaderResponsibleSearchTarget {
+        MessageHeaderResponsibleSearchTarget::Organization(resource)
+    }
+}
+/// The sender reference search parameter of the MessageHeader resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageHeaderSenderSearchReference;
+impl ReferenceSearchParameter for MessageHeaderSenderSearchReference {
+    type Resource = resources::MessageHeader;
+    type Target = MessageHeaderSenderSearchTarget;
+    fn parameter(&self) -> MessageHeaderSearchParameter {
+        MessageHeaderSearchParameter::Sender
+    }
+}
+/// Target resources of the sender reference search parameter of the MessageHeader resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MessageHeaderSenderSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+}
+impl From<resources::Practitioner> for MessageHeaderSenderSearchTarget {
+    fn from(resource: resources::Practitioner) -> MessageHeaderSenderSearchTarget {
+        MessageHeaderSenderSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for MessageHeaderSenderSearchTarget {
+    fn from(resource: resources::Organization) -> MessageHeaderSenderSearchTarget {
+        MessageHeaderSenderSearchTarget::Organization(resource)
+    }
+}
+/// The target reference search parameter of the MessageHeader resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MessageHeaderTargetSearchReference;
+impl ReferenceSearchParameter for MessageHeaderTargetSearchReference {
+    type Resource = resources::MessageHeader;
+    type Target = resources::Device;
+    fn parameter(&self) -> MessageHeaderSearchParameter {
+        MessageHeaderSearchParameter::Target
+    }
+}
+/// The replaced-by reference search parameter of the NamingSystem resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NamingSystemReplacedBySearchReference;
+impl ReferenceSearchParameter for NamingSystemReplacedBySearchReference {
+    type Resource = resources::NamingSystem;
+    type Target = resources::NamingSystem;
+    fn parameter(&self) -> NamingSystemSearchParameter {
+        NamingSystemSearchParameter::ReplacedBy
+    }
+}
+/// The patient reference search parameter of the NutritionOrder resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NutritionOrderPatientSearchReference;
+impl ReferenceSearchParameter for NutritionOrderPatientSearchReference {
+    type Resource = resources::NutritionOrder;
+    type Target = NutritionOrderPatientSearchTarget;
+    fn parameter(&self) -> NutritionOrderSearchParameter {
+        NutritionOrderSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the NutritionOrder resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NutritionOrderPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for NutritionOrderPatientSearchTarget {
+    fn from(resource: resources::Patient) -> NutritionOrderPatientSearchTarget {
+        NutritionOrderPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for NutritionOrderPatientSearchTarget {
+    fn from(resource: resources::Group) -> NutritionOrderPatientSearchTarget {
+        NutritionOrderPatientSearchTarget::Group(resource)
+    }
+}
+/// The encounter reference search parameter of the NutritionOrder resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NutritionOrderEncounterSearchReference;
+impl ReferenceSearchParameter for NutritionOrderEncounterSearchReference {
+    type Resource = resources::NutritionOrder;
+    type Target = NutritionOrderEncounterSearchTarget;
+    fn parameter(&self) -> NutritionOrderSearchParameter {
+        NutritionOrderSearchParameter::Encounter
+    }
+}
+/// Target resources of the encounter reference search parameter of the NutritionOrder resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NutritionOrderEncounterSearchTarget {
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+}
+impl From<resources::Encounter> for NutritionOrderEncounterSearchTarget {
+    fn from(resource: resources::Encounter) -> NutritionOrderEncounterSearchTarget {
+        NutritionOrderEncounterSearchTarget::Encounter(resource)
+    }
+}
+impl From<resources::EpisodeOfCare> for NutritionOrderEncounterSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> NutritionOrderEncounterSearchTarget {
+        NutritionOrderEncounterSearchTarget::EpisodeOfCare(resource)
+    }
+}
+/// The provider reference search parameter of the NutritionOrder resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NutritionOrderProviderSearchReference;
+impl ReferenceSearchParameter for NutritionOrderProviderSearchReference {
+    type Resource = resources::NutritionOrder;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> NutritionOrderSearchParameter {
+        NutritionOrderSearchParameter::Provider
+    }
+}
+/// The patient reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationPatientSearchReference;
+impl ReferenceSearchParameter for ObservationPatientSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationPatientSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for ObservationPatientSearchTarget {
+    fn from(resource: resources::Patient) -> ObservationPatientSearchTarget {
+        ObservationPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for ObservationPatientSearchTarget {
+    fn from(resource: resources::Group) -> ObservationPatientSearchTarget {
+        ObservationPatientSearchTarget::Group(resource)
+    }
+}
+/// The encounter reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationEncounterSearchReference;
+impl ReferenceSearchParameter for ObservationEncounterSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationEncounterSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Encounter
+    }
+}
+/// Target resources of the encounter reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationEncounterSearchTarget {
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+}
+impl From<resources::Encounter> for ObservationEncounterSearchTarget {
+    fn from(resource: resources::Encounter) -> ObservationEncounterSearchTarget {
+        ObservationEncounterSearchTarget::Encounter(resource)
+    }
+}
+impl From<resources::EpisodeOfCare> for ObservationEncounterSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> ObservationEncounterSearchTarget {
+        ObservationEncounterSearchTarget::EpisodeOfCare(resource)
+    }
+}
+/// The based-on reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationBasedOnSearchReference;
+impl ReferenceSearchParameter for ObservationBasedOnSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationBasedOnSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationBasedOnSearchTarget {
+    /// Variant for ReferralRequest target resources
+    ReferralRequest(resources::ReferralRequest),
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for MedicationRequest target resources
+    MedicationRequest(resources::MedicationRequest),
+    /// Variant for NutritionOrder target resources
+    NutritionOrder(resources::NutritionOrder),
+    /// Variant for ProcedureRequest target resources
+    ProcedureRequest(resources::ProcedureRequest),
+    /// Variant for DeviceRequest target resources
+    DeviceRequest(resources::DeviceRequest),
+    /// Variant for ImmunizationRecommendation target resources
+    ImmunizationRecommendation(resources::ImmunizationRecommendation),
+}
+impl From<resources::ReferralRequest> for ObservationBasedOnSearchTarget {
+    fn from(resource: resources::ReferralRequest) -> ObservationBasedOnSearchTarget {
+        ObservationBasedOnSearchTarget::ReferralRequest(resource)
+    }
+}
+impl From<resources::CarePlan> for ObservationBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> ObservationBasedOnSearchTarget {
+        ObservationBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::MedicationRequest> for ObservationBasedOnSearchTarget {
+    fn from(resource: resources::MedicationRequest) -> ObservationBasedOnSearchTarget {
+        ObservationBasedOnSearchTarget::MedicationRequest(resource)
+    }
+}
+impl From<resources::NutritionOrder> for ObservationBasedOnSearchTarget {
+    fn from(resource: resources::NutritionOrder) -> ObservationBasedOnSearchTarget {
+        ObservationBasedOnSearchTarget::NutritionOrder(resource)
+    }
+}
+impl From<resources::ProcedureRequest> for ObservationBasedOnSearchTarget {
+    fn from(resource: resources::ProcedureRequest) -> ObservationBasedOnSearchTarget {
+        ObservationBasedOnSearchTarget::ProcedureRequest(resource)
+    }
+}
+impl From<resources::DeviceRequest> for ObservationBasedOnSearchTarget {
+    fn from(resource: resources::DeviceRequest) -> ObservationBasedOnSearchTarget {
+        ObservationBasedOnSearchTarget::DeviceRequest(resource)
+    }
+}
+impl From<resources::ImmunizationRecommendation> for ObservationBasedOnSearchTarget {
+    fn from(resource: resources::ImmunizationRecommendation) -> ObservationBasedOnSearchTarget {
+        ObservationBasedOnSearchTarget::ImmunizationRecommendation(resource)
+    }
+}
+/// The context reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationContextSearchReference;
+impl ReferenceSearchParameter for ObservationContextSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationContextSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for ObservationContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> ObservationContextSearchTarget {
+        ObservationContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for ObservationContextSearchTarget {
+    fn from(resource: resources::Encounter) -> ObservationContextSearchTarget {
+        ObservationContextSearchTarget::Encounter(resource)
+    }
+}
+/// The device reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationDeviceSearchReference;
+impl ReferenceSearchParameter for ObservationDeviceSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationDeviceSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Device
+    }
+}
+/// Target resources of the device reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationDeviceSearchTarget {
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for DeviceMetric target resources
+    DeviceMetric(resources::DeviceMetric),
+}
+impl From<resources::Device> for ObservationDeviceSearchTarget {
+    fn from(resource: resources::Device) -> ObservationDeviceSearchTarget {
+        ObservationDeviceSearchTarget::Device(resource)
+    }
+}
+impl From<resources::DeviceMetric> for ObservationDeviceSearchTarget {
+    fn from(resource: resources::DeviceMetric) -> ObservationDeviceSearchTarget {
+        ObservationDeviceSearchTarget::DeviceMetric(resource)
+    }
+}
+/// The performer reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationPerformerSearchReference;
+impl ReferenceSearchParameter for ObservationPerformerSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationPerformerSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationPerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ObservationPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ObservationPerformerSearchTarget {
+        ObservationPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ObservationPerformerSearchTarget {
+    fn from(resource: resources::Organization) -> ObservationPerformerSearchTarget {
+        ObservationPerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Patient> for ObservationPerformerSearchTarget {
+    fn from(resource: resources::Patient) -> ObservationPerformerSearchTarget {
+        ObservationPerformerSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ObservationPerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ObservationPerformerSearchTarget {
+        ObservationPerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The related-target reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationRelatedTargetSearchReference;
+impl ReferenceSearchParameter for ObservationRelatedTargetSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationRelatedTargetSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::RelatedTarget
+    }
+}
+/// Target resources of the related-target reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationRelatedTargetSearchTarget {
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for Sequence target resources
+    Sequence(resources::Sequence),
+    /// Variant for QuestionnaireResponse target resources
+    QuestionnaireResponse(resources::QuestionnaireResponse),
+}
+impl From<resources::Observation> for ObservationRelatedTargetSearchTarget {
+    fn from(resource: resources::Observation) -> ObservationRelatedTargetSearchTarget {
+        ObservationRelatedTargetSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::Sequence> for ObservationRelatedTargetSearchTarget {
+    fn from(resource: resources::Sequence) -> ObservationRelatedTargetSearchTarget {
+        ObservationRelatedTargetSearchTarget::Sequence(resource)
+    }
+}
+impl From<resources::QuestionnaireResponse> for ObservationRelatedTargetSearchTarget {
+    fn from(resource: resources::QuestionnaireResponse) -> ObservationRelatedTargetSearchTarget {
+        ObservationRelatedTargetSearchTarget::QuestionnaireResponse(resource)
+    }
+}
+/// The specimen reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationSpecimenSearchReference;
+impl ReferenceSearchParameter for ObservationSpecimenSearchReference {
+    type Resource = resources::Observation;
+    type Target = resources::Specimen;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Specimen
+    }
+}
+/// The subject reference search parameter of the Observation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObservationSubjectSearchReference;
+impl ReferenceSearchParameter for ObservationSubjectSearchReference {
+    type Resource = resources::Observation;
+    type Target = ObservationSubjectSearchTarget;
+    fn parameter(&self) -> ObservationSearchParameter {
+        ObservationSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Observation resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ObservationSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Group> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Device> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Device) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Location> for ObservationSubjectSearchTarget {
+    fn from(resource: resources::Location) -> ObservationSubjectSearchTarget {
+        ObservationSubjectSearchTarget::Location(resource)
+    }
+}
+/// The base reference search parameter of the OperationDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OperationDefinitionBaseSearchReference;
+impl ReferenceSearchParameter for OperationDefinitionBaseSearchReference {
+    type Resource = resources::OperationDefinition;
+    type Target = resources::OperationDefinition;
+    fn parameter(&self) -> OperationDefinitionSearchParameter {
+        OperationDefinitionSearchParameter::Base
+    }
+}
+/// The param-profile reference search parameter of the OperationDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OperationDefinitionParamProfileSearchReference;
+impl ReferenceSearchParameter for OperationDefinitionParamProfileSearchReference {
+    type Resource = resources::OperationDefinition;
+    type Target = resources::StructureDefinition;
+    fn parameter(&self) -> OperationDefinitionSearchParameter {
+        OperationDefinitionSearchParameter::ParamProfile
+    }
+}
+/// The endpoint reference search parameter of the Organization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrganizationEndpointSearchReference;
+impl ReferenceSearchParameter for OrganizationEndpointSearchReference {
+    type Resource = resources::Organization;
+    type Target = resources::Endpoint;
+    fn parameter(&self) -> OrganizationSearchParameter {
+        OrganizationSearchParameter::Endpoint
+    }
+}
+/// The partof reference search parameter of the Organization resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct OrganizationPartofSearchReference;
+impl ReferenceSearchParameter for OrganizationPartofSearchReference {
+    type Resource = resources::Organization;
+    type Target = resources::Organization;
+    fn parameter(&self) -> OrganizationSearchParameter {
+        OrganizationSearchParameter::Partof
+    }
+}
+/// The general-practitioner reference search parameter of the Patient resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PatientGeneralPractitionerSearchReference;
+impl ReferenceSearchParameter for PatientGeneralPractitionerSearchReference {
+    type Resource = resources::Patient;
+    type Target = PatientGeneralPractitionerSearchTarget;
+    fn parameter(&self) -> PatientSearchParameter {
+        PatientSearchParameter::GeneralPractitioner
+    }
+}
+/// Target resources of the general-practitioner reference search parameter of the Patient resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PatientGeneralPractitionerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+}
+impl From<resources::Practitioner> for PatientGeneralPractitionerSearchTarget {
+    fn from(resource: resources::Practitioner) -> PatientGeneralPractitionerSearchTarget {
+        PatientGeneralPractitionerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for PatientGeneralPractitionerSearchTarget {
+    fn from(resource: resources::Organization) -> PatientGeneralPractitionerSearchTarget {
+        PatientGeneralPractitionerSearchTarget::Organization(resource)
+    }
+}
+/// The link reference search parameter of the Patient resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PatientLinkSearchReference;
+impl ReferenceSearchParameter for PatientLinkSearchReference {
+    type Resource = resources::Patient;
+    type Target = PatientLinkSearchTarget;
+    fn parameter(&self) -> PatientSearchParameter {
+        PatientSearchParameter::Link
+    }
+}
+/// Target resources of the link reference search parameter of the Patient resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PatientLinkSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Patient> for PatientLinkSearchTarget {
+    fn from(resource: resources::Patient) -> PatientLinkSearchTarget {
+        PatientLinkSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for PatientLinkSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> PatientLinkSearchTarget {
+        PatientLinkSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The organization reference search parameter of the Patient resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PatientOrganizationSearchReference;
+impl ReferenceSearchParameter for PatientOrganizationSearchReference {
+    type Resource = resources::Patient;
+    type Target = resources::Organization;
+    fn parameter(&self) -> PatientSearchParameter {
+        PatientSearchParameter::Organization
+    }
+}
+/// The organization reference search parameter of the PaymentNotice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentNoticeOrganizationSearchReference;
+impl ReferenceSearchParameter for PaymentNoticeOrganizationSearchReference {
+    type Resource = resources::PaymentNotice;
+    type Target = resources::Organization;
+    fn parameter(&self) -> PaymentNoticeSearchParameter {
+        PaymentNoticeSearchParameter::Organization
+    }
+}
+/// The provider reference search parameter of the PaymentNotice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentNoticeProviderSearchReference;
+impl ReferenceSearchParameter for PaymentNoticeProviderSearchReference {
+    type Resource = resources::PaymentNotice;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> PaymentNoticeSearchParameter {
+        PaymentNoticeSearchParameter::Provider
+    }
+}
+/// The request reference search parameter of the PaymentNotice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentNoticeRequestSearchReference;
+impl ReferenceSearchParameter for PaymentNoticeRequestSearchReference {
+    type Resource = resources::PaymentNotice;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PaymentNoticeSearchParameter {
+        PaymentNoticeSearchParameter::Request
+    }
+}
+/// The response reference search parameter of the PaymentNotice resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentNoticeResponseSearchReference;
+impl ReferenceSearchParameter for PaymentNoticeResponseSearchReference {
+    type Resource = resources::PaymentNotice;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PaymentNoticeSearchParameter {
+        PaymentNoticeSearchParameter::Response
+    }
+}
+/// The organization reference search parameter of the PaymentReconciliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentReconciliationOrganizationSearchReference;
+impl ReferenceSearchParameter for PaymentReconciliationOrganizationSearchReference {
+    type Resource = resources::PaymentReconciliation;
+    type Target = resources::Organization;
+    fn parameter(&self) -> PaymentReconciliationSearchParameter {
+        PaymentReconciliationSearchParameter::Organization
+    }
+}
+/// The request reference search parameter of the PaymentReconciliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentReconciliationRequestSearchReference;
+impl ReferenceSearchParameter for PaymentReconciliationRequestSearchReference {
+    type Resource = resources::PaymentReconciliation;
+    type Target = resources::ProcessRequest;
+    fn parameter(&self) -> PaymentReconciliationSearchParameter {
+        PaymentReconciliationSearchParameter::Request
+    }
+}
+/// The request-organization reference search parameter of the PaymentReconciliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentReconciliationRequestOrganizationSearchReference;
+impl ReferenceSearchParameter for PaymentReconciliationRequestOrganizationSearchReference {
+    type Resource = resources::PaymentReconciliation;
+    type Target = resources::Organization;
+    fn parameter(&self) -> PaymentReconciliationSearchParameter {
+        PaymentReconciliationSearchParameter::RequestOrganization
+    }
+}
+/// The request-provider reference search parameter of the PaymentReconciliation resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PaymentReconciliationRequestProviderSearchReference;
+impl ReferenceSearchParameter for PaymentReconciliationRequestProviderSearchReference {
+    type Resource = resources::PaymentReconciliation;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> PaymentReconciliationSearchParameter {
+        PaymentReconciliationSearchParameter::RequestProvider
+    }
+}
+/// The link reference search parameter of the Person resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PersonLinkSearchReference;
+impl ReferenceSearchParameter for PersonLinkSearchReference {
+    type Resource = resources::Person;
+    type Target = PersonLinkSearchTarget;
+    fn parameter(&self) -> PersonSearchParameter {
+        PersonSearchParameter::Link
+    }
+}
+/// Target resources of the link reference search parameter of the Person resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PersonLinkSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Person target resources
+    Person(resources::Person),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for PersonLinkSearchTarget {
+    fn from(resource: resources::Practitioner) -> PersonLinkSearchTarget {
+        PersonLinkSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for PersonLinkSearchTarget {
+    fn from(resource: resources::Patient) -> PersonLinkSearchTarget {
+        PersonLinkSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Person> for PersonLinkSearchTarget {
+    fn from(resource: resources::Person) -> PersonLinkSearchTarget {
+        PersonLinkSearchTarget::Person(resource)
+    }
+}
+impl From<resources::RelatedPerson> for PersonLinkSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> PersonLinkSearchTarget {
+        PersonLinkSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The organization reference search parameter of the Person resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PersonOrganizationSearchReference;
+impl ReferenceSearchParameter for PersonOrganizationSearchReference {
+    type Resource = resources::Person;
+    type Target = resources::Organization;
+    fn parameter(&self) -> PersonSearchParameter {
+        PersonSearchParameter::Organization
+    }
+}
+/// The patient reference search parameter of the Person resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PersonPatientSearchReference;
+impl ReferenceSearchParameter for PersonPatientSearchReference {
+    type Resource = resources::Person;
+    type Target = resources::Patient;
+    fn parameter(&self) -> PersonSearchParameter {
+        PersonSearchParameter::Patient
+    }
+}
+/// The practitioner reference search parameter of the Person resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PersonPractitionerSearchReference;
+impl ReferenceSearchParameter for PersonPractitionerSearchReference {
+    type Resource = resources::Person;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> PersonSearchParameter {
+        PersonSearchParameter::Practitioner
+    }
+}
+/// The relatedperson reference search parameter of the Person resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PersonRelatedpersonSearchReference;
+impl ReferenceSearchParameter for PersonRelatedpersonSearchReference {
+    type Resource = resources::Person;
+    type Target = resources::RelatedPerson;
+    fn parameter(&self) -> PersonSearchParameter {
+        PersonSearchParameter::Relatedperson
+    }
+}
+/// The composed-of reference search parameter of the PlanDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlanDefinitionComposedOfSearchReference;
+impl ReferenceSearchParameter for PlanDefinitionComposedOfSearchReference {
+    type Resource = resources::PlanDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PlanDefinitionSearchParameter {
+        PlanDefinitionSearchParameter::ComposedOf
+    }
+}
+/// The depends-on reference search parameter of the PlanDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlanDefinitionDependsOnSearchReference;
+impl ReferenceSearchParameter for PlanDefinitionDependsOnSearchReference {
+    type Resource = resources::PlanDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PlanDefinitionSearchParameter {
+        PlanDefinitionSearchParameter::DependsOn
+    }
+}
+/// The derived-from reference search parameter of the PlanDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlanDefinitionDerivedFromSearchReference;
+impl ReferenceSearchParameter for PlanDefinitionDerivedFromSearchReference {
+    type Resource = resources::PlanDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PlanDefinitionSearchParameter {
+        PlanDefinitionSearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the PlanDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlanDefinitionPredecessorSearchReference;
+impl ReferenceSearchParameter for PlanDefinitionPredecessorSearchReference {
+    type Resource = resources::PlanDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PlanDefinitionSearchParameter {
+        PlanDefinitionSearchParameter::Predecessor
+    }
+}
+/// The successor reference search parameter of the PlanDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PlanDefinitionSuccessorSearchReference;
+impl ReferenceSearchParameter for PlanDefinitionSuccessorSearchReference {
+    type Resource = resources::PlanDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> PlanDefinitionSearchParameter {
+        PlanDefinitionSearchParameter::Successor
+    }
+}
+/// The endpoint reference search parameter of the PractitionerRole resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PractitionerRoleEndpointSearchReference;
+impl ReferenceSearchParameter for PractitionerRoleEndpointSearchReference {
+    type Resource = resources::PractitionerRole;
+    type Target = resources::Endpoint;
+    fn parameter(&self) -> PractitionerRoleSearchParameter {
+        PractitionerRoleSearchParameter::Endpoint
+    }
+}
+/// The location reference search parameter of the PractitionerRole resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PractitionerRoleLocationSearchReference;
+impl ReferenceSearchParameter for PractitionerRoleLocationSearchReference {
+    type Resource = resources::PractitionerRole;
+    type Target = resources::Location;
+    fn parameter(&self) -> PractitionerRoleSearchParameter {
+        PractitionerRoleSearchParameter::Location
+    }
+}
+/// The organization reference search parameter of the PractitionerRole resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PractitionerRoleOrganizationSearchReference;
+impl ReferenceSearchParameter for PractitionerRoleOrganizationSearchReference {
+    type Resource = resources::PractitionerRole;
+    type Target = resources::Organization;
+    fn parameter(&self) -> PractitionerRoleSearchParameter {
+        PractitionerRoleSearchParameter::Organization
+    }
+}
+/// The practitioner reference search parameter of the PractitionerRole resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PractitionerRolePractitionerSearchReference;
+impl ReferenceSearchParameter for PractitionerRolePractitionerSearchReference {
+    type Resource = resources::PractitionerRole;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> PractitionerRoleSearchParameter {
+        PractitionerRoleSearchParameter::Practitioner
+    }
+}
+/// The service reference search parameter of the PractitionerRole resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PractitionerRoleServiceSearchReference;
+impl ReferenceSearchParameter for PractitionerRoleServiceSearchReference {
+    type Resource = resources::PractitionerRole;
+    type Target = resources::HealthcareService;
+    fn parameter(&self) -> PractitionerRoleSearchParameter {
+        PractitionerRoleSearchParameter::Service
+    }
+}
+/// The patient reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedurePatientSearchReference;
+impl ReferenceSearchParameter for ProcedurePatientSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedurePatientSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedurePatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for ProcedurePatientSearchTarget {
+    fn from(resource: resources::Patient) -> ProcedurePatientSearchTarget {
+        ProcedurePatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for ProcedurePatientSearchTarget {
+    fn from(resource: resources::Group) -> ProcedurePatientSearchTarget {
+        ProcedurePatientSearchTarget::Group(resource)
+    }
+}
+/// The encounter reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureEncounterSearchReference;
+impl ReferenceSearchParameter for ProcedureEncounterSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedureEncounterSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::Encounter
+    }
+}
+/// Target resources of the encounter reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureEncounterSearchTarget {
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+}
+impl From<resources::Encounter> for ProcedureEncounterSearchTarget {
+    fn from(resource: resources::Encounter) -> ProcedureEncounterSearchTarget {
+        ProcedureEncounterSearchTarget::Encounter(resource)
+    }
+}
+impl From<resources::EpisodeOfCare> for ProcedureEncounterSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> ProcedureEncounterSearchTarget {
+        ProcedureEncounterSearchTarget::EpisodeOfCare(resource)
+    }
+}
+/// The based-on reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureBasedOnSearchReference;
+impl ReferenceSearchParameter for ProcedureBasedOnSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedureBasedOnSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureBasedOnSearchTarget {
+    /// Variant for ReferralRequest target resources
+    ReferralRequest(resources::ReferralRequest),
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for ProcedureRequest target resources
+    ProcedureRequest(resources::ProcedureRequest),
+}
+impl From<resources::ReferralRequest> for ProcedureBasedOnSearchTarget {
+    fn from(resource: resources::ReferralRequest) -> ProcedureBasedOnSearchTarget {
+        ProcedureBasedOnSearchTarget::ReferralRequest(resource)
+    }
+}
+impl From<resources::CarePlan> for ProcedureBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> ProcedureBasedOnSearchTarget {
+        ProcedureBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::ProcedureRequest> for ProcedureBasedOnSearchTarget {
+    fn from(resource: resources::ProcedureRequest) -> ProcedureBasedOnSearchTarget {
+        ProcedureBasedOnSearchTarget::ProcedureRequest(resource)
+    }
+}
+/// The context reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureContextSearchReference;
+impl ReferenceSearchParameter for ProcedureContextSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedureContextSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for ProcedureContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> ProcedureContextSearchTarget {
+        ProcedureContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for ProcedureContextSearchTarget {
+    fn from(resource: resources::Encounter) -> ProcedureContextSearchTarget {
+        ProcedureContextSearchTarget::Encounter(resource)
+    }
+}
+/// The definition reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureDefinitionSearchReference;
+impl ReferenceSearchParameter for ProcedureDefinitionSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedureDefinitionSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::Definition
+    }
+}
+/// Target resources of the definition reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureDefinitionSearchTarget {
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+}
+impl From<resources::PlanDefinition> for ProcedureDefinitionSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> ProcedureDefinitionSearchTarget {
+        ProcedureDefinitionSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::HealthcareService> for ProcedureDefinitionSearchTarget {
+    fn from(resource: resources::HealthcareService) -> ProcedureDefinitionSearchTarget {
+        ProcedureDefinitionSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for ProcedureDefinitionSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> ProcedureDefinitionSearchTarget {
+        ProcedureDefinitionSearchTarget::ActivityDefinition(resource)
+    }
+}
+/// The location reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureLocationSearchReference;
+impl ReferenceSearchParameter for ProcedureLocationSearchReference {
+    type Resource = resources::Procedure;
+    type Target = resources::Location;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::Location
+    }
+}
+/// The part-of reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedurePartOfSearchReference;
+impl ReferenceSearchParameter for ProcedurePartOfSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedurePartOfSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::PartOf
+    }
+}
+/// Target resources of the part-of reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedurePartOfSearchTarget {
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+    /// Variant for MedicationAdministration target resources
+    MedicationAdministration(resources::MedicationAdministration),
+}
+impl From<resources::Observation> for ProcedurePartOfSearchTarget {
+    fn from(resource: resources::Observation) -> ProcedurePartOfSearchTarget {
+        ProcedurePartOfSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::Procedure> for ProcedurePartOfSearchTarget {
+    fn from(resource: resources::Procedure) -> ProcedurePartOfSearchTarget {
+        ProcedurePartOfSearchTarget::Procedure(resource)
+    }
+}
+impl From<resources::MedicationAdministration> for ProcedurePartOfSearchTarget {
+    fn from(resource: resources::MedicationAdministration) -> ProcedurePartOfSearchTarget {
+        ProcedurePartOfSearchTarget::MedicationAdministration(resource)
+    }
+}
+/// The performer reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedurePerformerSearchReference;
+impl ReferenceSearchParameter for ProcedurePerformerSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedurePerformerSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedurePerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ProcedurePerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ProcedurePerformerSearchTarget {
+        ProcedurePerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ProcedurePerformerSearchTarget {
+    fn from(resource: resources::Organization) -> ProcedurePerformerSearchTarget {
+        ProcedurePerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for ProcedurePerformerSearchTarget {
+    fn from(resource: resources::Device) -> ProcedurePerformerSearchTarget {
+        ProcedurePerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ProcedurePerformerSearchTarget {
+    fn from(resource: resources::Patient) -> ProcedurePerformerSearchTarget {
+        ProcedurePerformerSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ProcedurePerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ProcedurePerformerSearchTarget {
+        ProcedurePerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subject reference search parameter of the Procedure resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureSubjectSearchReference;
+impl ReferenceSearchParameter for ProcedureSubjectSearchReference {
+    type Resource = resources::Procedure;
+    type Target = ProcedureSubjectSearchTarget;
+    fn parameter(&self) -> ProcedureSearchParameter {
+        ProcedureSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Procedure resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for ProcedureSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ProcedureSubjectSearchTarget {
+        ProcedureSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for ProcedureSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ProcedureSubjectSearchTarget {
+        ProcedureSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the ProcedureRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureRequestPatientSearchReference;
+impl ReferenceSearchParameter for ProcedureRequestPatientSearchReference {
+    type Resource = resources::ProcedureRequest;
+    type Target = ProcedureRequestPatientSearchTarget;
+    fn parameter(&self) -> ProcedureRequestSearchParameter {
+        ProcedureRequestSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the ProcedureRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureRequestPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for ProcedureRequestPatientSearchTarget {
+    fn from(resource: resources::Patient) -> ProcedureRequestPatientSearchTarget {
+        ProcedureRequestPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for ProcedureRequestPatientSearchTarget {
+    fn from(resource: resources::Group) -> ProcedureRequestPatientSearchTarget {
+        ProcedureRequestPatientSearchTarget::Group(resource)
+    }
+}
+/// The encounter reference search parameter of the ProcedureRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureRequestEncounterSearchReference;
+impl ReferenceSearchParameter for ProcedureRequestEncounterSearchReference {
+    type Resource = resources::ProcedureRequest;
+    type Target = ProcedureRequestEncounterSearchTarget;
+    fn parameter(&self) -> ProcedureRequestSearchParameter {
+        ProcedureRequestSearchParameter::Encounter
+    }
+}
+/// Target resources of the encounter reference search parameter of the ProcedureRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureRequestEncounterSearchTarget {
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+}
+impl From<resources::Encounter> for ProcedureRequestEncounterSearchTarget {
+    fn from(resource: resources::Encounter) -> ProcedureRequestEncounterSearchTarget {
+        ProcedureRequestEncounterSearchTarget::Encounter(resource)
+    }
+}
+impl From<resources::EpisodeOfCare> for ProcedureRequestEncounterSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> ProcedureRequestEncounterSearchTarget {
+        ProcedureRequestEncounterSearchTarget::EpisodeOfCare(resource)
+    }
+}
+/// The based-on reference search parameter of the ProcedureRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureRequestBasedOnSearchReference;
+impl ReferenceSearchParameter for ProcedureRequestBasedOnSearchReference {
+    type Resource = resources::ProcedureRequest;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ProcedureRequestSearchParameter {
+        ProcedureRequestSearchParameter::BasedOn
+    }
+}
+/// The context reference search parameter of the ProcedureRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureRequestContextSearchReference;
+impl ReferenceSearchParameter for ProcedureRequestContextSearchReference {
+    type Resource = resources::ProcedureRequest;
+    type Target = ProcedureRequestContextSearchTarget;
+    fn parameter(&self) -> ProcedureRequestSearchParameter {
+        ProcedureRequestSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the ProcedureRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureRequestContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for ProcedureRequestContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> ProcedureRequestContextSearchTarget {
+        ProcedureRequestContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for ProcedureRequestContextSearchTarget {
+    fn from(resource: resources::Encounter) -> ProcedureRequestContextSearchTarget {
+        ProcedureRequestContextSearchTarget::Encounter(resource)
+    }
+}
+/// The definition reference search parameter of the ProcedureRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureRequestDefinitionSearchReference;
+impl ReferenceSearchParameter for ProcedureRequestDefinitionSearchReference {
+    type Resource = resources::ProcedureRequest;
+    type Target = ProcedureRequestDefinitionSearchTarget;
+    fn parameter(&self) -> ProcedureRequestSearchParameter {
+        ProcedureRequestSearchParameter::Definition
+    }
+}
+/// Target resources of the definition reference search parameter of the ProcedureRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureRequestDefinitionSearchTarget {
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+}
+impl From<resources::PlanDefinition> for ProcedureRequestDefinitionSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> ProcedureRequestDefinitionSearchTarget {
+        ProcedureRequestDefinitionSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for ProcedureRequestDefinitionSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> ProcedureRequestDefinitionSearchTarget {
+        ProcedureRequestDefinitionSearchTarget::ActivityDefinition(resource)
+    }
+}
+/// The performer reference search parameter of the ProcedureRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureRequestPerformerSearchReference;
+impl ReferenceSearchParameter for ProcedureRequestPerformerSearchReference {
+    type Resource = resources::ProcedureRequest;
+    type Target = ProcedureRequestPerformerSearchTarget;
+    fn parameter(&self) -> ProcedureRequestSearchParameter {
+        ProcedureRequestSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the ProcedureRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureRequestPerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ProcedureRequestPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> ProcedureRequestPerformerSearchTarget {
+        ProcedureRequestPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ProcedureRequestPerformerSearchTarget {
+    fn from(resource: resources::Organization) -> ProcedureRequestPerformerSearchTarget {
+        ProcedureRequestPerformerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for ProcedureRequestPerformerSearchTarget {
+    fn from(resource: resources::Device) -> ProcedureRequestPerformerSearchTarget {
+        ProcedureRequestPerformerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ProcedureRequestPerformerSearchTarget {
+    fn from(resource: resources::Patient) -> ProcedureRequestPerformerSearchTarget {
+        ProcedureRequestPerformerSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::HealthcareService> for ProcedureRequestPerformerSearchTarget {
+    fn from(resource: resources::HealthcareService) -> ProcedureRequestPerformerSearchTarget {
+        ProcedureRequestPerformerSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ProcedureRequestPerformerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ProcedureRequestPerformerSearchTarget {
+        ProcedureRequestPerformerSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The replaces reference search parameter of the ProcedureRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureRequestReplacesSearchReference;
+impl ReferenceSearchParameter for ProcedureRequestReplacesSearchReference {
+    type Resource = resources::ProcedureRequest;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ProcedureRequestSearchParameter {
+        ProcedureRequestSearchParameter::Replaces
+    }
+}
+/// The requester reference search parameter of the ProcedureRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureRequestRequesterSearchReference;
+impl ReferenceSearchParameter for ProcedureRequestRequesterSearchReference {
+    type Resource = resources::ProcedureRequest;
+    type Target = ProcedureRequestRequesterSearchTarget;
+    fn parameter(&self) -> ProcedureRequestSearchParameter {
+        ProcedureRequestSearchParameter::Requester
+    }
+}
+/// Target resources of the requester reference search parameter of the ProcedureRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureRequestRequesterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+}
+impl From<resources::Practitioner> for ProcedureRequestRequesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> ProcedureRequestRequesterSearchTarget {
+        ProcedureRequestRequesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ProcedureRequestRequesterSearchTarget {
+    fn from(resource: resources::Organization) -> ProcedureRequestRequesterSearchTarget {
+        ProcedureRequestRequesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for ProcedureRequestRequesterSearchTarget {
+    fn from(resource: resources::Device) -> ProcedureRequestRequesterSearchTarget {
+        ProcedureRequestRequesterSearchTarget::Device(resource)
+    }
+}
+/// The specimen reference search parameter of the ProcedureRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureRequestSpecimenSearchReference;
+impl ReferenceSearchParameter for ProcedureRequestSpecimenSearchReference {
+    type Resource = resources::ProcedureRequest;
+    type Target = resources::Specimen;
+    fn parameter(&self) -> ProcedureRequestSearchParameter {
+        ProcedureRequestSearchParameter::Specimen
+    }
+}
+/// The subject reference search parameter of the ProcedureRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcedureRequestSubjectSearchReference;
+impl ReferenceSearchParameter for ProcedureRequestSubjectSearchReference {
+    type Resource = resources::ProcedureRequest;
+    type Target = ProcedureRequestSubjectSearchTarget;
+    fn parameter(&self) -> ProcedureRequestSearchParameter {
+        ProcedureRequestSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the ProcedureRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProcedureRequestSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Group> for ProcedureRequestSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ProcedureRequestSubjectSearchTarget {
+        ProcedureRequestSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Device> for ProcedureRequestSubjectSearchTarget {
+    fn from(resource: resources::Device) -> ProcedureRequestSubjectSearchTarget {
+        ProcedureRequestSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ProcedureRequestSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ProcedureRequestSubjectSearchTarget {
+        ProcedureRequestSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Location> for ProcedureRequestSubjectSearchTarget {
+    fn from(resource: resources::Location) -> ProcedureRequestSubjectSearchTarget {
+        ProcedureRequestSubjectSearchTarget::Location(resource)
+    }
+}
+/// The organization reference search parameter of the ProcessRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcessRequestOrganizationSearchReference;
+impl ReferenceSearchParameter for ProcessRequestOrganizationSearchReference {
+    type Resource = resources::ProcessRequest;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ProcessRequestSearchParameter {
+        ProcessRequestSearchParameter::Organization
+    }
+}
+/// The provider reference search parameter of the ProcessRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcessRequestProviderSearchReference;
+impl ReferenceSearchParameter for ProcessRequestProviderSearchReference {
+    type Resource = resources::ProcessRequest;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> ProcessRequestSearchParameter {
+        ProcessRequestSearchParameter::Provider
+    }
+}
+/// The organization reference search parameter of the ProcessResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcessResponseOrganizationSearchReference;
+impl ReferenceSearchParameter for ProcessResponseOrganizationSearchReference {
+    type Resource = resources::ProcessResponse;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ProcessResponseSearchParameter {
+        ProcessResponseSearchParameter::Organization
+    }
+}
+/// The request reference search parameter of the ProcessResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcessResponseRequestSearchReference;
+impl ReferenceSearchParameter for ProcessResponseRequestSearchReference {
+    type Resource = resources::ProcessResponse;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ProcessResponseSearchParameter {
+        ProcessResponseSearchParameter::Request
+    }
+}
+/// The request-organization reference search parameter of the ProcessResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcessResponseRequestOrganizationSearchReference;
+impl ReferenceSearchParameter for ProcessResponseRequestOrganizationSearchReference {
+    type Resource = resources::ProcessResponse;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ProcessResponseSearchParameter {
+        ProcessResponseSearchParameter::RequestOrganization
+    }
+}
+/// The request-provider reference search parameter of the ProcessResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcessResponseRequestProviderSearchReference;
+impl ReferenceSearchParameter for ProcessResponseRequestProviderSearchReference {
+    type Resource = resources::ProcessResponse;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> ProcessResponseSearchParameter {
+        ProcessResponseSearchParameter::RequestProvider
+    }
+}
+/// The agent reference search parameter of the Provenance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProvenanceAgentSearchReference;
+impl ReferenceSearchParameter for ProvenanceAgentSearchReference {
+    type Resource = resources::Provenance;
+    type Target = ProvenanceAgentSearchTarget;
+    fn parameter(&self) -> ProvenanceSearchParameter {
+        ProvenanceSearchParameter::Agent
+    }
+}
+/// Target resources of the agent reference search parameter of the Provenance resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ProvenanceAgentSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ProvenanceAgentSearchTarget {
+    fn from(resource: resources::Practitioner) -> ProvenanceAgentSearchTarget {
+        ProvenanceAgentSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ProvenanceAgentSearchTarget {
+    fn from(resource: resources::Organization) -> ProvenanceAgentSearchTarget {
+        ProvenanceAgentSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for ProvenanceAgentSearchTarget {
+    fn from(resource: resources::Device) -> ProvenanceAgentSearchTarget {
+        ProvenanceAgentSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ProvenanceAgentSearchTarget {
+    fn from(resource: resources::Patient) -> ProvenanceAgentSearchTarget {
+        ProvenanceAgentSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ProvenanceAgentSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ProvenanceAgentSearchTarget {
+        ProvenanceAgentSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The entity-ref reference search parameter of the Provenance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProvenanceEntityRefSearchReference;
+impl ReferenceSearchParameter for ProvenanceEntityRefSearchReference {
+    type Resource = resources::Provenance;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ProvenanceSearchParameter {
+        ProvenanceSearchParameter::EntityRef
+    }
+}
+/// The location reference search parameter of the Provenance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProvenanceLocationSearchReference;
+impl ReferenceSearchParameter for ProvenanceLocationSearchReference {
+    type Resource = resources::Provenance;
+    type Target = resources::Location;
+    fn parameter(&self) -> ProvenanceSearchParameter {
+        ProvenanceSearchParameter::Location
+    }
+}
+/// The patient reference search parameter of the Provenance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProvenancePatientSearchReference;
+impl ReferenceSearchParameter for ProvenancePatientSearchReference {
+    type Resource = resources::Provenance;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ProvenanceSearchParameter {
+        ProvenanceSearchParameter::Patient
+    }
+}
+/// The target reference search parameter of the Provenance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProvenanceTargetSearchReference;
+impl ReferenceSearchParameter for ProvenanceTargetSearchReference {
+    type Resource = resources::Provenance;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ProvenanceSearchParameter {
+        ProvenanceSearchParameter::Target
+    }
+}
+/// The author reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponseAuthorSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponseAuthorSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = QuestionnaireResponseAuthorSearchTarget;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the QuestionnaireResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum QuestionnaireResponseAuthorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for QuestionnaireResponseAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> QuestionnaireResponseAuthorSearchTarget {
+        QuestionnaireResponseAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Device> for QuestionnaireResponseAuthorSearchTarget {
+    fn from(resource: resources::Device) -> QuestionnaireResponseAuthorSearchTarget {
+        QuestionnaireResponseAuthorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for QuestionnaireResponseAuthorSearchTarget {
+    fn from(resource: resources::Patient) -> QuestionnaireResponseAuthorSearchTarget {
+        QuestionnaireResponseAuthorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for QuestionnaireResponseAuthorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> QuestionnaireResponseAuthorSearchTarget {
+        QuestionnaireResponseAuthorSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The based-on reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponseBasedOnSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponseBasedOnSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = QuestionnaireResponseBasedOnSearchTarget;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the QuestionnaireResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum QuestionnaireResponseBasedOnSearchTarget {
+    /// Variant for ReferralRequest target resources
+    ReferralRequest(resources::ReferralRequest),
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for ProcedureRequest target resources
+    ProcedureRequest(resources::ProcedureRequest),
+}
+impl From<resources::ReferralRequest> for QuestionnaireResponseBasedOnSearchTarget {
+    fn from(resource: resources::ReferralRequest) -> QuestionnaireResponseBasedOnSearchTarget {
+        QuestionnaireResponseBasedOnSearchTarget::ReferralRequest(resource)
+    }
+}
+impl From<resources::CarePlan> for QuestionnaireResponseBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> QuestionnaireResponseBasedOnSearchTarget {
+        QuestionnaireResponseBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::ProcedureRequest> for QuestionnaireResponseBasedOnSearchTarget {
+    fn from(resource: resources::ProcedureRequest) -> QuestionnaireResponseBasedOnSearchTarget {
+        QuestionnaireResponseBasedOnSearchTarget::ProcedureRequest(resource)
+    }
+}
+/// The context reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponseContextSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponseContextSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = QuestionnaireResponseContextSearchTarget;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the QuestionnaireResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum QuestionnaireResponseContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for QuestionnaireResponseContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> QuestionnaireResponseContextSearchTarget {
+        QuestionnaireResponseContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for QuestionnaireResponseContextSearchTarget {
+    fn from(resource: resources::Encounter) -> QuestionnaireResponseContextSearchTarget {
+        QuestionnaireResponseContextSearchTarget::Encounter(resource)
+    }
+}
+/// The parent reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponseParentSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponseParentSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = QuestionnaireResponseParentSearchTarget;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::Parent
+    }
+}
+/// Target resources of the parent reference search parameter of the QuestionnaireResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum QuestionnaireResponseParentSearchTarget {
+    /// Variant for Observation target resources
+    Observation(resources::Observation),
+    /// Variant for Procedure target resources
+    Procedure(resources::Procedure),
+}
+impl From<resources::Observation> for QuestionnaireResponseParentSearchTarget {
+    fn from(resource: resources::Observation) -> QuestionnaireResponseParentSearchTarget {
+        QuestionnaireResponseParentSearchTarget::Observation(resource)
+    }
+}
+impl From<resources::Procedure> for QuestionnaireResponseParentSearchTarget {
+    fn from(resource: resources::Procedure) -> QuestionnaireResponseParentSearchTarget {
+        QuestionnaireResponseParentSearchTarget::Procedure(resource)
+    }
+}
+/// The patient reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponsePatientSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponsePatientSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = resources::Patient;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::Patient
+    }
+}
+/// The questionnaire reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponseQuestionnaireSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponseQuestionnaireSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = resources::Questionnaire;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::Questionnaire
+    }
+}
+/// The source reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponseSourceSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponseSourceSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = QuestionnaireResponseSourceSearchTarget;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::Source
+    }
+}
+/// Target resources of the source reference search parameter of the QuestionnaireResponse resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum QuestionnaireResponseSourceSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for QuestionnaireResponseSourceSearchTarget {
+    fn from(resource: resources::Practitioner) -> QuestionnaireResponseSourceSearchTarget {
+        QuestionnaireResponseSourceSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for QuestionnaireResponseSourceSearchTarget {
+    fn from(resource: resources::Patient) -> QuestionnaireResponseSourceSearchTarget {
+        QuestionnaireResponseSourceSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for QuestionnaireResponseSourceSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> QuestionnaireResponseSourceSearchTarget {
+        QuestionnaireResponseSourceSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subject reference search parameter of the QuestionnaireResponse resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct QuestionnaireResponseSubjectSearchReference;
+impl ReferenceSearchParameter for QuestionnaireResponseSubjectSearchReference {
+    type Resource = resources::QuestionnaireResponse;
+    type Target = resources::Resource;
+    fn parameter(&self) -> QuestionnaireResponseSearchParameter {
+        QuestionnaireResponseSearchParameter::Subject
+    }
+}
+/// The patient reference search parameter of the ReferralRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ReferralRequestPatientSearchReference;
+impl ReferenceSearchParameter for ReferralRequestPatientSearchReference {
+    type Resource = resources::ReferralRequest;
+    type Target = ReferralRequestPatientSearchTarget;
+    fn parameter(&self) -> ReferralRequestSearchParameter {
+        ReferralRequestSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the ReferralRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ReferralRequestPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for ReferralRequestPatientSearchTarget {
+    fn from(resource: resources::Patient) -> ReferralRequestPatientSearchTarget {
+        ReferralRequestPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for ReferralRequestPatientSearchTarget {
+    fn from(resource: resources::Group) -> ReferralRequestPatientSearchTarget {
+        ReferralRequestPatientSearchTarget::Group(resource)
+    }
+}
+/// The based-on reference search parameter of the ReferralRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ReferralRequestBasedOnSearchReference;
+impl ReferenceSearchParameter for ReferralRequestBasedOnSearchReference {
+    type Resource = resources::ReferralRequest;
+    type Target = ReferralRequestBasedOnSearchTarget;
+    fn parameter(&self) -> ReferralRequestSearchParameter {
+        ReferralRequestSearchParameter::BasedOn
+    }
+}
+/// Target resources of the based-on reference search parameter of the ReferralRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ReferralRequestBasedOnSearchTarget {
+    /// Variant for ReferralRequest target resources
+    ReferralRequest(resources::ReferralRequest),
+    /// Variant for CarePlan target resources
+    CarePlan(resources::CarePlan),
+    /// Variant for ProcedureRequest target resources
+    ProcedureRequest(resources::ProcedureRequest),
+}
+impl From<resources::ReferralRequest> for ReferralRequestBasedOnSearchTarget {
+    fn from(resource: resources::ReferralRequest) -> ReferralRequestBasedOnSearchTarget {
+        ReferralRequestBasedOnSearchTarget::ReferralRequest(resource)
+    }
+}
+impl From<resources::CarePlan> for ReferralRequestBasedOnSearchTarget {
+    fn from(resource: resources::CarePlan) -> ReferralRequestBasedOnSearchTarget {
+        ReferralRequestBasedOnSearchTarget::CarePlan(resource)
+    }
+}
+impl From<resources::ProcedureRequest> for ReferralRequestBasedOnSearchTarget {
+    fn from(resource: resources::ProcedureRequest) -> ReferralRequestBasedOnSearchTarget {
+        ReferralRequestBasedOnSearchTarget::ProcedureRequest(resource)
+    }
+}
+/// The context reference search parameter of the ReferralRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ReferralRequestContextSearchReference;
+impl ReferenceSearchParameter for ReferralRequestContextSearchReference {
+    type Resource = resources::ReferralRequest;
+    type Target = ReferralRequestContextSearchTarget;
+    fn parameter(&self) -> ReferralRequestSearchParameter {
+        ReferralRequestSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the ReferralRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ReferralRequestContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for ReferralRequestContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> ReferralRequestContextSearchTarget {
+        ReferralRequestContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for ReferralRequestContextSearchTarget {
+    fn from(resource: resources::Encounter) -> ReferralRequestContextSearchTarget {
+        ReferralRequestContextSearchTarget::Encounter(resource)
+    }
+}
+/// The definition reference search parameter of the ReferralRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ReferralRequestDefinitionSearchReference;
+impl ReferenceSearchParameter for ReferralRequestDefinitionSearchReference {
+    type Resource = resources::ReferralRequest;
+    type Target = ReferralRequestDefinitionSearchTarget;
+    fn parameter(&self) -> ReferralRequestSearchParameter {
+        ReferralRequestSearchParameter::Definition
+    }
+}
+/// Target resources of the definition reference search parameter of the ReferralRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ReferralRequestDefinitionSearchTarget {
+    /// Variant for PlanDefinition target resources
+    PlanDefinition(resources::PlanDefinition),
+    /// Variant for ActivityDefinition target resources
+    ActivityDefinition(resources::ActivityDefinition),
+}
+impl From<resources::PlanDefinition> for ReferralRequestDefinitionSearchTarget {
+    fn from(resource: resources::PlanDefinition) -> ReferralRequestDefinitionSearchTarget {
+        ReferralRequestDefinitionSearchTarget::PlanDefinition(resource)
+    }
+}
+impl From<resources::ActivityDefinition> for ReferralRequestDefinitionSearchTarget {
+    fn from(resource: resources::ActivityDefinition) -> ReferralRequestDefinitionSearchTarget {
+        ReferralRequestDefinitionSearchTarget::ActivityDefinition(resource)
+    }
+}
+/// The encounter reference search parameter of the ReferralRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ReferralRequestEncounterSearchReference;
+impl ReferenceSearchParameter for ReferralRequestEncounterSearchReference {
+    type Resource = resources::ReferralRequest;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> ReferralRequestSearchParameter {
+        ReferralRequestSearchParameter::Encounter
+    }
+}
+/// The recipient reference search parameter of the ReferralRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ReferralRequestRecipientSearchReference;
+impl ReferenceSearchParameter for ReferralRequestRecipientSearchReference {
+    type Resource = resources::ReferralRequest;
+    type Target = ReferralRequestRecipientSearchTarget;
+    fn parameter(&self) -> ReferralRequestSearchParameter {
+        ReferralRequestSearchParameter::Recipient
+    }
+}
+/// Target resources of the recipient reference search parameter of the ReferralRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ReferralRequestRecipientSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+}
+impl From<resources::Practitioner> for ReferralRequestRecipientSearchTarget {
+    fn from(resource: resources::Practitioner) -> ReferralRequestRecipientSearchTarget {
+        ReferralRequestRecipientSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ReferralRequestRecipientSearchTarget {
+    fn from(resource: resources::Organization) -> ReferralRequestRecipientSearchTarget {
+        ReferralRequestRecipientSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::HealthcareService> for ReferralRequestRecipientSearchTarget {
+    fn from(resource: resources::HealthcareService) -> ReferralRequestRecipientSearchTarget {
+        ReferralRequestRecipientSearchTarget::HealthcareService(resource)
+    }
+}
+/// The replaces reference search parameter of the ReferralRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ReferralRequestReplacesSearchReference;
+impl ReferenceSearchParameter for ReferralRequestReplacesSearchReference {
+    type Resource = resources::ReferralRequest;
+    type Target = resources::ReferralRequest;
+    fn parameter(&self) -> ReferralRequestSearchParameter {
+        ReferralRequestSearchParameter::Replaces
+    }
+}
+/// The requester reference search parameter of the ReferralRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ReferralRequestRequesterSearchReference;
+impl ReferenceSearchParameter for ReferralRequestRequesterSearchReference {
+    type Resource = resources::ReferralRequest;
+    type Target = ReferralRequestRequesterSearchTarget;
+    fn parameter(&self) -> ReferralRequestSearchParameter {
+        ReferralRequestSearchParameter::Requester
+    }
+}
+/// Target resources of the requester reference search parameter of the ReferralRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ReferralRequestRequesterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for ReferralRequestRequesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> ReferralRequestRequesterSearchTarget {
+        ReferralRequestRequesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for ReferralRequestRequesterSearchTarget {
+    fn from(resource: resources::Organization) -> ReferralRequestRequesterSearchTarget {
+        ReferralRequestRequesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for ReferralRequestRequesterSearchTarget {
+    fn from(resource: resources::Device) -> ReferralRequestRequesterSearchTarget {
+        ReferralRequestRequesterSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ReferralRequestRequesterSearchTarget {
+    fn from(resource: resources::Patient) -> ReferralRequestRequesterSearchTarget {
+        ReferralRequestRequesterSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ReferralRequestRequesterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ReferralRequestRequesterSearchTarget {
+        ReferralRequestRequesterSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subject reference search parameter of the ReferralRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ReferralRequestSubjectSearchReference;
+impl ReferenceSearchParameter for ReferralRequestSubjectSearchReference {
+    type Resource = resources::ReferralRequest;
+    type Target = ReferralRequestSubjectSearchTarget;
+    fn parameter(&self) -> ReferralRequestSearchParameter {
+        ReferralRequestSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the ReferralRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ReferralRequestSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for ReferralRequestSubjectSearchTarget {
+    fn from(resource: resources::Group) -> ReferralRequestSubjectSearchTarget {
+        ReferralRequestSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for ReferralRequestSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> ReferralRequestSubjectSearchTarget {
+        ReferralRequestSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The patient reference search parameter of the RelatedPerson resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RelatedPersonPatientSearchReference;
+impl ReferenceSearchParameter for RelatedPersonPatientSearchReference {
+    type Resource = resources::RelatedPerson;
+    type Target = resources::Patient;
+    fn parameter(&self) -> RelatedPersonSearchParameter {
+        RelatedPersonSearchParameter::Patient
+    }
+}
+/// The author reference search parameter of the RequestGroup resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestGroupAuthorSearchReference;
+impl ReferenceSearchParameter for RequestGroupAuthorSearchReference {
+    type Resource = resources::RequestGroup;
+    type Target = RequestGroupAuthorSearchTarget;
+    fn parameter(&self) -> RequestGroupSearchParameter {
+        RequestGroupSearchParameter::Author
+    }
+}
+/// Target resources of the author reference search parameter of the RequestGroup resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RequestGroupAuthorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Device target resources
+    Device(resources::Device),
+}
+impl From<resources::Practitioner> for RequestGroupAuthorSearchTarget {
+    fn from(resource: resources::Practitioner) -> RequestGroupAuthorSearchTarget {
+        RequestGroupAuthorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Device> for RequestGroupAuthorSearchTarget {
+    fn from(resource: resources::Device) -> RequestGroupAuthorSearchTarget {
+        RequestGroupAuthorSearchTarget::Device(resource)
+    }
+}
+/// The context reference search parameter of the RequestGroup resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestGroupContextSearchReference;
+impl ReferenceSearchParameter for RequestGroupContextSearchReference {
+    type Resource = resources::RequestGroup;
+    type Target = RequestGroupContextSearchTarget;
+    fn parameter(&self) -> RequestGroupSearchParameter {
+        RequestGroupSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the RequestGroup resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RequestGroupContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for RequestGroupContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> RequestGroupContextSearchTarget {
+        RequestGroupContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for RequestGroupContextSearchTarget {
+    fn from(resource: resources::Encounter) -> RequestGroupContextSearchTarget {
+        RequestGroupContextSearchTarget::Encounter(resource)
+    }
+}
+/// The definition reference search parameter of the RequestGroup resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestGroupDefinitionSearchReference;
+impl ReferenceSearchParameter for RequestGroupDefinitionSearchReference {
+    type Resource = resources::RequestGroup;
+    type Target = resources::Resource;
+    fn parameter(&self) -> RequestGroupSearchParameter {
+        RequestGroupSearchParameter::Definition
+    }
+}
+/// The encounter reference search parameter of the RequestGroup resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestGroupEncounterSearchReference;
+impl ReferenceSearchParameter for RequestGroupEncounterSearchReference {
+    type Resource = resources::RequestGroup;
+    type Target = resources::Encounter;
+    fn parameter(&self) -> RequestGroupSearchParameter {
+        RequestGroupSearchParameter::Encounter
+    }
+}
+/// The participant reference search parameter of the RequestGroup resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestGroupParticipantSearchReference;
+impl ReferenceSearchParameter for RequestGroupParticipantSearchReference {
+    type Resource = resources::RequestGroup;
+    type Target = RequestGroupParticipantSearchTarget;
+    fn parameter(&self) -> RequestGroupSearchParameter {
+        RequestGroupSearchParameter::Participant
+    }
+}
+/// Target resources of the participant reference search parameter of the RequestGroup resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RequestGroupParticipantSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Person target resources
+    Person(resources::Person),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for RequestGroupParticipantSearchTarget {
+    fn from(resource: resources::Practitioner) -> RequestGroupParticipantSearchTarget {
+        RequestGroupParticipantSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Patient> for RequestGroupParticipantSearchTarget {
+    fn from(resource: resources::Patient) -> RequestGroupParticipantSearchTarget {
+        RequestGroupParticipantSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Person> for RequestGroupParticipantSearchTarget {
+    fn from(resource: resources::Person) -> RequestGroupParticipantSearchTarget {
+        RequestGroupParticipantSearchTarget::Person(resource)
+    }
+}
+impl From<resources::RelatedPerson> for RequestGroupParticipantSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> RequestGroupParticipantSearchTarget {
+        RequestGroupParticipantSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The patient reference search parameter of the RequestGroup resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestGroupPatientSearchReference;
+impl ReferenceSearchParameter for RequestGroupPatientSearchReference {
+    type Resource = resources::RequestGroup;
+    type Target = resources::Patient;
+    fn parameter(&self) -> RequestGroupSearchParameter {
+        RequestGroupSearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the RequestGroup resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RequestGroupSubjectSearchReference;
+impl ReferenceSearchParameter for RequestGroupSubjectSearchReference {
+    type Resource = resources::RequestGroup;
+    type Target = RequestGroupSubjectSearchTarget;
+    fn parameter(&self) -> RequestGroupSearchParameter {
+        RequestGroupSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the RequestGroup resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RequestGroupSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for RequestGroupSubjectSearchTarget {
+    fn from(resource: resources::Group) -> RequestGroupSubjectSearchTarget {
+        RequestGroupSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for RequestGroupSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> RequestGroupSubjectSearchTarget {
+        RequestGroupSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The partof reference search parameter of the ResearchStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchStudyPartofSearchReference;
+impl ReferenceSearchParameter for ResearchStudyPartofSearchReference {
+    type Resource = resources::ResearchStudy;
+    type Target = resources::ResearchStudy;
+    fn parameter(&self) -> ResearchStudySearchParameter {
+        ResearchStudySearchParameter::Partof
+    }
+}
+/// The principalinvestigator reference search parameter of the ResearchStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchStudyPrincipalinvestigatorSearchReference;
+impl ReferenceSearchParameter for ResearchStudyPrincipalinvestigatorSearchReference {
+    type Resource = resources::ResearchStudy;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> ResearchStudySearchParameter {
+        ResearchStudySearchParameter::Principalinvestigator
+    }
+}
+/// The protocol reference search parameter of the ResearchStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchStudyProtocolSearchReference;
+impl ReferenceSearchParameter for ResearchStudyProtocolSearchReference {
+    type Resource = resources::ResearchStudy;
+    type Target = resources::PlanDefinition;
+    fn parameter(&self) -> ResearchStudySearchParameter {
+        ResearchStudySearchParameter::Protocol
+    }
+}
+/// The site reference search parameter of the ResearchStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchStudySiteSearchReference;
+impl ReferenceSearchParameter for ResearchStudySiteSearchReference {
+    type Resource = resources::ResearchStudy;
+    type Target = resources::Location;
+    fn parameter(&self) -> ResearchStudySearchParameter {
+        ResearchStudySearchParameter::Site
+    }
+}
+/// The sponsor reference search parameter of the ResearchStudy resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchStudySponsorSearchReference;
+impl ReferenceSearchParameter for ResearchStudySponsorSearchReference {
+    type Resource = resources::ResearchStudy;
+    type Target = resources::Organization;
+    fn parameter(&self) -> ResearchStudySearchParameter {
+        ResearchStudySearchParameter::Sponsor
+    }
+}
+/// The individual reference search parameter of the ResearchSubject resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchSubjectIndividualSearchReference;
+impl ReferenceSearchParameter for ResearchSubjectIndividualSearchReference {
+    type Resource = resources::ResearchSubject;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ResearchSubjectSearchParameter {
+        ResearchSubjectSearchParameter::Individual
+    }
+}
+/// The patient reference search parameter of the ResearchSubject resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ResearchSubjectPatientSearchReference;
+impl ReferenceSearchParameter for ResearchSubjectPatientSearchReference {
+    type Resource = resources::ResearchSubject;
+    type Target = resources::Patient;
+    fn parameter(&self) -> ResearchSubjectSearchParameter {
+        ResearchSubjectSearchParameter::Patient
+    }
+}
+/// The patient reference search parameter of the RiskAssessment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RiskAssessmentPatientSearchReference;
+impl ReferenceSearchParameter for RiskAssessmentPatientSearchReference {
+    type Resource = resources::RiskAssessment;
+    type Target = RiskAssessmentPatientSearchTarget;
+    fn parameter(&self) -> RiskAssessmentSearchParameter {
+        RiskAssessmentSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the RiskAssessment resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RiskAssessmentPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for RiskAssessmentPatientSearchTarget {
+    fn from(resource: resources::Patient) -> RiskAssessmentPatientSearchTarget {
+        RiskAssessmentPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for RiskAssessmentPatientSearchTarget {
+    fn from(resource: resources::Group) -> RiskAssessmentPatientSearchTarget {
+        RiskAssessmentPatientSearchTarget::Group(resource)
+    }
+}
+/// The encounter reference search parameter of the RiskAssessment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RiskAssessmentEncounterSearchReference;
+impl ReferenceSearchParameter for RiskAssessmentEncounterSearchReference {
+    type Resource = resources::RiskAssessment;
+    type Target = RiskAssessmentEncounterSearchTarget;
+    fn parameter(&self) -> RiskAssessmentSearchParameter {
+        RiskAssessmentSearchParameter::Encounter
+    }
+}
+/// Target resources of the encounter reference search parameter of the RiskAssessment resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RiskAssessmentEncounterSearchTarget {
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+}
+impl From<resources::Encounter> for RiskAssessmentEncounterSearchTarget {
+    fn from(resource: resources::Encounter) -> RiskAssessmentEncounterSearchTarget {
+        RiskAssessmentEncounterSearchTarget::Encounter(resource)
+    }
+}
+impl From<resources::EpisodeOfCare> for RiskAssessmentEncounterSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> RiskAssessmentEncounterSearchTarget {
+        RiskAssessmentEncounterSearchTarget::EpisodeOfCare(resource)
+    }
+}
+/// The condition reference search parameter of the RiskAssessment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RiskAssessmentConditionSearchReference;
+impl ReferenceSearchParameter for RiskAssessmentConditionSearchReference {
+    type Resource = resources::RiskAssessment;
+    type Target = resources::Condition;
+    fn parameter(&self) -> RiskAssessmentSearchParameter {
+        RiskAssessmentSearchParameter::Condition
+    }
+}
+/// The performer reference search parameter of the RiskAssessment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RiskAssessmentPerformerSearchReference;
+impl ReferenceSearchParameter for RiskAssessmentPerformerSearchReference {
+    type Resource = resources::RiskAssessment;
+    type Target = RiskAssessmentPerformerSearchTarget;
+    fn parameter(&self) -> RiskAssessmentSearchParameter {
+        RiskAssessmentSearchParameter::Performer
+    }
+}
+/// Target resources of the performer reference search parameter of the RiskAssessment resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RiskAssessmentPerformerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Device target resources
+    Device(resources::Device),
+}
+impl From<resources::Practitioner> for RiskAssessmentPerformerSearchTarget {
+    fn from(resource: resources::Practitioner) -> RiskAssessmentPerformerSearchTarget {
+        RiskAssessmentPerformerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Device> for RiskAssessmentPerformerSearchTarget {
+    fn from(resource: resources::Device) -> RiskAssessmentPerformerSearchTarget {
+        RiskAssessmentPerformerSearchTarget::Device(resource)
+    }
+}
+/// The subject reference search parameter of the RiskAssessment resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RiskAssessmentSubjectSearchReference;
+impl ReferenceSearchParameter for RiskAssessmentSubjectSearchReference {
+    type Resource = resources::RiskAssessment;
+    type Target = RiskAssessmentSubjectSearchTarget;
+    fn parameter(&self) -> RiskAssessmentSearchParameter {
+        RiskAssessmentSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the RiskAssessment resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RiskAssessmentSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+}
+impl From<resources::Group> for RiskAssessmentSubjectSearchTarget {
+    fn from(resource: resources::Group) -> RiskAssessmentSubjectSearchTarget {
+        RiskAssessmentSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Patient> for RiskAssessmentSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> RiskAssessmentSubjectSearchTarget {
+        RiskAssessmentSubjectSearchTarget::Patient(resource)
+    }
+}
+/// The actor reference search parameter of the Schedule resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ScheduleActorSearchReference;
+impl ReferenceSearchParameter for ScheduleActorSearchReference {
+    type Resource = resources::Schedule;
+    type Target = ScheduleActorSearchTarget;
+    fn parameter(&self) -> ScheduleSearchParameter {
+        ScheduleSearchParameter::Actor
+    }
+}
+/// Target resources of the actor reference search parameter of the Schedule resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ScheduleActorSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for HealthcareService target resources
+    HealthcareService(resources::HealthcareService),
+    /// Variant for PractitionerRole target resources
+    PractitionerRole(resources::PractitionerRole),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+    /// Variant for Location target resources
+    Location(resources::Location),
+}
+impl From<resources::Practitioner> for ScheduleActorSearchTarget {
+    fn from(resource: resources::Practitioner) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Device> for ScheduleActorSearchTarget {
+    fn from(resource: resources::Device) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for ScheduleActorSearchTarget {
+    fn from(resource: resources::Patient) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::HealthcareService> for ScheduleActorSearchTarget {
+    fn from(resource: resources::HealthcareService) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::HealthcareService(resource)
+    }
+}
+impl From<resources::PractitionerRole> for ScheduleActorSearchTarget {
+    fn from(resource: resources::PractitionerRole) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::PractitionerRole(resource)
+    }
+}
+impl From<resources::RelatedPerson> for ScheduleActorSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::RelatedPerson(resource)
+    }
+}
+impl From<resources::Location> for ScheduleActorSearchTarget {
+    fn from(resource: resources::Location) -> ScheduleActorSearchTarget {
+        ScheduleActorSearchTarget::Location(resource)
+    }
+}
+/// The component reference search parameter of the SearchParameter resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SearchParameterComponentSearchReference;
+impl ReferenceSearchParameter for SearchParameterComponentSearchReference {
+    type Resource = resources::SearchParameter;
+    type Target = resources::SearchParameter;
+    fn parameter(&self) -> SearchParameterSearchParameter {
+        SearchParameterSearchParameter::Component
+    }
+}
+/// The patient reference search parameter of the Sequence resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SequencePatientSearchReference;
+impl ReferenceSearchParameter for SequencePatientSearchReference {
+    type Resource = resources::Sequence;
+    type Target = resources::Patient;
+    fn parameter(&self) -> SequenceSearchParameter {
+        SequenceSearchParameter::Patient
+    }
+}
+/// The composed-of reference search parameter of the ServiceDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceDefinitionComposedOfSearchReference;
+impl ReferenceSearchParameter for ServiceDefinitionComposedOfSearchReference {
+    type Resource = resources::ServiceDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ServiceDefinitionSearchParameter {
+        ServiceDefinitionSearchParameter::ComposedOf
+    }
+}
+/// The depends-on reference search parameter of the ServiceDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceDefinitionDependsOnSearchReference;
+impl ReferenceSearchParameter for ServiceDefinitionDependsOnSearchReference {
+    type Resource = resources::ServiceDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ServiceDefinitionSearchParameter {
+        ServiceDefinitionSearchParameter::DependsOn
+    }
+}
+/// The derived-from reference search parameter of the ServiceDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceDefinitionDerivedFromSearchReference;
+impl ReferenceSearchParameter for ServiceDefinitionDerivedFromSearchReference {
+    type Resource = resources::ServiceDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ServiceDefinitionSearchParameter {
+        ServiceDefinitionSearchParameter::DerivedFrom
+    }
+}
+/// The predecessor reference search parameter of the ServiceDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceDefinitionPredecessorSearchReference;
+impl ReferenceSearchParameter for ServiceDefinitionPredecessorSearchReference {
+    type Resource = resources::ServiceDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ServiceDefinitionSearchParameter {
+        ServiceDefinitionSearchParameter::Predecessor
+    }
+}
+/// The successor reference search parameter of the ServiceDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ServiceDefinitionSuccessorSearchReference;
+impl ReferenceSearchParameter for ServiceDefinitionSuccessorSearchReference {
+    type Resource = resources::ServiceDefinition;
+    type Target = resources::Resource;
+    fn parameter(&self) -> ServiceDefinitionSearchParameter {
+        ServiceDefinitionSearchParameter::Successor
+    }
+}
+/// The schedule reference search parameter of the Slot resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SlotScheduleSearchReference;
+impl ReferenceSearchParameter for SlotScheduleSearchReference {
+    type Resource = resources::Slot;
+    type Target = resources::Schedule;
+    fn parameter(&self) -> SlotSearchParameter {
+        SlotSearchParameter::Schedule
+    }
+}
+/// The collector reference search parameter of the Specimen resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpecimenCollectorSearchReference;
+impl ReferenceSearchParameter for SpecimenCollectorSearchReference {
+    type Resource = resources::Specimen;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> SpecimenSearchParameter {
+        SpecimenSearchParameter::Collector
+    }
+}
+/// The parent reference search parameter of the Specimen resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpecimenParentSearchReference;
+impl ReferenceSearchParameter for SpecimenParentSearchReference {
+    type Resource = resources::Specimen;
+    type Target = resources::Specimen;
+    fn parameter(&self) -> SpecimenSearchParameter {
+        SpecimenSearchParameter::Parent
+    }
+}
+/// The patient reference search parameter of the Specimen resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpecimenPatientSearchReference;
+impl ReferenceSearchParameter for SpecimenPatientSearchReference {
+    type Resource = resources::Specimen;
+    type Target = resources::Patient;
+    fn parameter(&self) -> SpecimenSearchParameter {
+        SpecimenSearchParameter::Patient
+    }
+}
+/// The subject reference search parameter of the Specimen resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SpecimenSubjectSearchReference;
+impl ReferenceSearchParameter for SpecimenSubjectSearchReference {
+    type Resource = resources::Specimen;
+    type Target = SpecimenSubjectSearchTarget;
+    fn parameter(&self) -> SpecimenSearchParameter {
+        SpecimenSearchParameter::Subject
+    }
+}
+/// Target resources of the subject reference search parameter of the Specimen resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SpecimenSubjectSearchTarget {
+    /// Variant for Group target resources
+    Group(resources::Group),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Substance target resources
+    Substance(resources::Substance),
+}
+impl From<resources::Group> for SpecimenSubjectSearchTarget {
+    fn from(resource: resources::Group) -> SpecimenSubjectSearchTarget {
+        SpecimenSubjectSearchTarget::Group(resource)
+    }
+}
+impl From<resources::Device> for SpecimenSubjectSearchTarget {
+    fn from(resource: resources::Device) -> SpecimenSubjectSearchTarget {
+        SpecimenSubjectSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for SpecimenSubjectSearchTarget {
+    fn from(resource: resources::Patient) -> SpecimenSubjectSearchTarget {
+        SpecimenSubjectSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Substance> for SpecimenSubjectSearchTarget {
+    fn from(resource: resources::Substance) -> SpecimenSubjectSearchTarget {
+        SpecimenSubjectSearchTarget::Substance(resource)
+    }
+}
+/// The valueset reference search parameter of the StructureDefinition resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StructureDefinitionValuesetSearchReference;
+impl ReferenceSearchParameter for StructureDefinitionValuesetSearchReference {
+    type Resource = resources::StructureDefinition;
+    type Target = resources::ValueSet;
+    fn parameter(&self) -> StructureDefinitionSearchParameter {
+        StructureDefinitionSearchParameter::Valueset
+    }
+}
+/// The substance-reference reference search parameter of the Substance resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SubstanceSubstanceReferenceSearchReference;
+impl ReferenceSearchParameter for SubstanceSubstanceReferenceSearchReference {
+    type Resource = resources::Substance;
+    type Target = resources::Substance;
+    fn parameter(&self) -> SubstanceSearchParameter {
+        SubstanceSearchParameter::SubstanceReference
+    }
+}
+/// The patient reference search parameter of the SupplyDelivery resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SupplyDeliveryPatientSearchReference;
+impl ReferenceSearchParameter for SupplyDeliveryPatientSearchReference {
+    type Resource = resources::SupplyDelivery;
+    type Target = SupplyDeliveryPatientSearchTarget;
+    fn parameter(&self) -> SupplyDeliverySearchParameter {
+        SupplyDeliverySearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the SupplyDelivery resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SupplyDeliveryPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for SupplyDeliveryPatientSearchTarget {
+    fn from(resource: resources::Patient) -> SupplyDeliveryPatientSearchTarget {
+        SupplyDeliveryPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for SupplyDeliveryPatientSearchTarget {
+    fn from(resource: resources::Group) -> SupplyDeliveryPatientSearchTarget {
+        SupplyDeliveryPatientSearchTarget::Group(resource)
+    }
+}
+/// The receiver reference search parameter of the SupplyDelivery resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SupplyDeliveryReceiverSearchReference;
+impl ReferenceSearchParameter for SupplyDeliveryReceiverSearchReference {
+    type Resource = resources::SupplyDelivery;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> SupplyDeliverySearchParameter {
+        SupplyDeliverySearchParameter::Receiver
+    }
+}
+/// The supplier reference search parameter of the SupplyDelivery resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SupplyDeliverySupplierSearchReference;
+impl ReferenceSearchParameter for SupplyDeliverySupplierSearchReference {
+    type Resource = resources::SupplyDelivery;
+    type Target = SupplyDeliverySupplierSearchTarget;
+    fn parameter(&self) -> SupplyDeliverySearchParameter {
+        SupplyDeliverySearchParameter::Supplier
+    }
+}
+/// Target resources of the supplier reference search parameter of the SupplyDelivery resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SupplyDeliverySupplierSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+}
+impl From<resources::Practitioner> for SupplyDeliverySupplierSearchTarget {
+    fn from(resource: resources::Practitioner) -> SupplyDeliverySupplierSearchTarget {
+        SupplyDeliverySupplierSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for SupplyDeliverySupplierSearchTarget {
+    fn from(resource: resources::Organization) -> SupplyDeliverySupplierSearchTarget {
+        SupplyDeliverySupplierSearchTarget::Organization(resource)
+    }
+}
+/// The requester reference search parameter of the SupplyRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SupplyRequestRequesterSearchReference;
+impl ReferenceSearchParameter for SupplyRequestRequesterSearchReference {
+    type Resource = resources::SupplyRequest;
+    type Target = SupplyRequestRequesterSearchTarget;
+    fn parameter(&self) -> SupplyRequestSearchParameter {
+        SupplyRequestSearchParameter::Requester
+    }
+}
+/// Target resources of the requester reference search parameter of the SupplyRequest resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SupplyRequestRequesterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for SupplyRequestRequesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> SupplyRequestRequesterSearchTarget {
+        SupplyRequestRequesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for SupplyRequestRequesterSearchTarget {
+    fn from(resource: resources::Organization) -> SupplyRequestRequesterSearchTarget {
+        SupplyRequestRequesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for SupplyRequestRequesterSearchTarget {
+    fn from(resource: resources::Device) -> SupplyRequestRequesterSearchTarget {
+        SupplyRequestRequesterSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for SupplyRequestRequesterSearchTarget {
+    fn from(resource: resources::Patient) -> SupplyRequestRequesterSearchTarget {
+        SupplyRequestRequesterSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for SupplyRequestRequesterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> SupplyRequestRequesterSearchTarget {
+        SupplyRequestRequesterSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The supplier reference search parameter of the SupplyRequest resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SupplyRequestSupplierSearchReference;
+impl ReferenceSearchParameter for SupplyRequestSupplierSearchReference {
+    type Resource = resources::SupplyRequest;
+    type Target = resources::Organization;
+    fn parameter(&self) -> SupplyRequestSearchParameter {
+        SupplyRequestSearchParameter::Supplier
+    }
+}
+/// The based-on reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskBasedOnSearchReference;
+impl ReferenceSearchParameter for TaskBasedOnSearchReference {
+    type Resource = resources::Task;
+    type Target = resources::Resource;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::BasedOn
+    }
+}
+/// The context reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskContextSearchReference;
+impl ReferenceSearchParameter for TaskContextSearchReference {
+    type Resource = resources::Task;
+    type Target = TaskContextSearchTarget;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Context
+    }
+}
+/// Target resources of the context reference search parameter of the Task resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TaskContextSearchTarget {
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+}
+impl From<resources::EpisodeOfCare> for TaskContextSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> TaskContextSearchTarget {
+        TaskContextSearchTarget::EpisodeOfCare(resource)
+    }
+}
+impl From<resources::Encounter> for TaskContextSearchTarget {
+    fn from(resource: resources::Encounter) -> TaskContextSearchTarget {
+        TaskContextSearchTarget::Encounter(resource)
+    }
+}
+/// The focus reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskFocusSearchReference;
+impl ReferenceSearchParameter for TaskFocusSearchReference {
+    type Resource = resources::Task;
+    type Target = resources::Resource;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Focus
+    }
+}
+/// The organization reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskOrganizationSearchReference;
+impl ReferenceSearchParameter for TaskOrganizationSearchReference {
+    type Resource = resources::Task;
+    type Target = resources::Organization;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Organization
+    }
+}
+/// The owner reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskOwnerSearchReference;
+impl ReferenceSearchParameter for TaskOwnerSearchReference {
+    type Resource = resources::Task;
+    type Target = TaskOwnerSearchTarget;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Owner
+    }
+}
+/// Target resources of the owner reference search parameter of the Task resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TaskOwnerSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for TaskOwnerSearchTarget {
+    fn from(resource: resources::Practitioner) -> TaskOwnerSearchTarget {
+        TaskOwnerSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for TaskOwnerSearchTarget {
+    fn from(resource: resources::Organization) -> TaskOwnerSearchTarget {
+        TaskOwnerSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for TaskOwnerSearchTarget {
+    fn from(resource: resources::Device) -> TaskOwnerSearchTarget {
+        TaskOwnerSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for TaskOwnerSearchTarget {
+    fn from(resource: resources::Patient) -> TaskOwnerSearchTarget {
+        TaskOwnerSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for TaskOwnerSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> TaskOwnerSearchTarget {
+        TaskOwnerSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The part-of reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskPartOfSearchReference;
+impl ReferenceSearchParameter for TaskPartOfSearchReference {
+    type Resource = resources::Task;
+    type Target = resources::Task;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::PartOf
+    }
+}
+/// The patient reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskPatientSearchReference;
+impl ReferenceSearchParameter for TaskPatientSearchReference {
+    type Resource = resources::Task;
+    type Target = resources::Patient;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Patient
+    }
+}
+/// The requester reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskRequesterSearchReference;
+impl ReferenceSearchParameter for TaskRequesterSearchReference {
+    type Resource = resources::Task;
+    type Target = TaskRequesterSearchTarget;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Requester
+    }
+}
+/// Target resources of the requester reference search parameter of the Task resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TaskRequesterSearchTarget {
+    /// Variant for Practitioner target resources
+    Practitioner(resources::Practitioner),
+    /// Variant for Organization target resources
+    Organization(resources::Organization),
+    /// Variant for Device target resources
+    Device(resources::Device),
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for RelatedPerson target resources
+    RelatedPerson(resources::RelatedPerson),
+}
+impl From<resources::Practitioner> for TaskRequesterSearchTarget {
+    fn from(resource: resources::Practitioner) -> TaskRequesterSearchTarget {
+        TaskRequesterSearchTarget::Practitioner(resource)
+    }
+}
+impl From<resources::Organization> for TaskRequesterSearchTarget {
+    fn from(resource: resources::Organization) -> TaskRequesterSearchTarget {
+        TaskRequesterSearchTarget::Organization(resource)
+    }
+}
+impl From<resources::Device> for TaskRequesterSearchTarget {
+    fn from(resource: resources::Device) -> TaskRequesterSearchTarget {
+        TaskRequesterSearchTarget::Device(resource)
+    }
+}
+impl From<resources::Patient> for TaskRequesterSearchTarget {
+    fn from(resource: resources::Patient) -> TaskRequesterSearchTarget {
+        TaskRequesterSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::RelatedPerson> for TaskRequesterSearchTarget {
+    fn from(resource: resources::RelatedPerson) -> TaskRequesterSearchTarget {
+        TaskRequesterSearchTarget::RelatedPerson(resource)
+    }
+}
+/// The subject reference search parameter of the Task resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskSubjectSearchReference;
+impl ReferenceSearchParameter for TaskSubjectSearchReference {
+    type Resource = resources::Task;
+    type Target = resources::Resource;
+    fn parameter(&self) -> TaskSearchParameter {
+        TaskSearchParameter::Subject
+    }
+}
+/// The testscript reference search parameter of the TestReport resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TestReportTestscriptSearchReference;
+impl ReferenceSearchParameter for TestReportTestscriptSearchReference {
+    type Resource = resources::TestReport;
+    type Target = resources::TestScript;
+    fn parameter(&self) -> TestReportSearchParameter {
+        TestReportSearchParameter::Testscript
+    }
+}
+/// The patient reference search parameter of the VisionPrescription resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VisionPrescriptionPatientSearchReference;
+impl ReferenceSearchParameter for VisionPrescriptionPatientSearchReference {
+    type Resource = resources::VisionPrescription;
+    type Target = VisionPrescriptionPatientSearchTarget;
+    fn parameter(&self) -> VisionPrescriptionSearchParameter {
+        VisionPrescriptionSearchParameter::Patient
+    }
+}
+/// Target resources of the patient reference search parameter of the VisionPrescription resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VisionPrescriptionPatientSearchTarget {
+    /// Variant for Patient target resources
+    Patient(resources::Patient),
+    /// Variant for Group target resources
+    Group(resources::Group),
+}
+impl From<resources::Patient> for VisionPrescriptionPatientSearchTarget {
+    fn from(resource: resources::Patient) -> VisionPrescriptionPatientSearchTarget {
+        VisionPrescriptionPatientSearchTarget::Patient(resource)
+    }
+}
+impl From<resources::Group> for VisionPrescriptionPatientSearchTarget {
+    fn from(resource: resources::Group) -> VisionPrescriptionPatientSearchTarget {
+        VisionPrescriptionPatientSearchTarget::Group(resource)
+    }
+}
+/// The encounter reference search parameter of the VisionPrescription resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VisionPrescriptionEncounterSearchReference;
+impl ReferenceSearchParameter for VisionPrescriptionEncounterSearchReference {
+    type Resource = resources::VisionPrescription;
+    type Target = VisionPrescriptionEncounterSearchTarget;
+    fn parameter(&self) -> VisionPrescriptionSearchParameter {
+        VisionPrescriptionSearchParameter::Encounter
+    }
+}
+/// Target resources of the encounter reference search parameter of the VisionPrescription resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VisionPrescriptionEncounterSearchTarget {
+    /// Variant for Encounter target resources
+    Encounter(resources::Encounter),
+    /// Variant for EpisodeOfCare target resources
+    EpisodeOfCare(resources::EpisodeOfCare),
+}
+impl From<resources::Encounter> for VisionPrescriptionEncounterSearchTarget {
+    fn from(resource: resources::Encounter) -> VisionPrescriptionEncounterSearchTarget {
+        VisionPrescriptionEncounterSearchTarget::Encounter(resource)
+    }
+}
+impl From<resources::EpisodeOfCare> for VisionPrescriptionEncounterSearchTarget {
+    fn from(resource: resources::EpisodeOfCare) -> VisionPrescriptionEncounterSearchTarget {
+        VisionPrescriptionEncounterSearchTarget::EpisodeOfCare(resource)
+    }
+}
+/// The prescriber reference search parameter of the VisionPrescription resource
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct VisionPrescriptionPrescriberSearchReference;
+impl ReferenceSearchParameter for VisionPrescriptionPrescriberSearchReference {
+    type Resource = resources::VisionPrescription;
+    type Target = resources::Practitioner;
+    fn parameter(&self) -> VisionPrescriptionSearchParameter {
+        VisionPrescriptionSearchParameter::Prescriber
+    }
+}
 impl SearchableResource for resources::Account {
     /// Parameters that can be used when searching Account resources
     type Params = AccountSearchParameter;
@@ -0,0 +1,140 @@
+#![cfg(all(feature = "stu3", feature = "r4b", feature = "r5", feature = "builders"))]
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+#![recursion_limit = "1024"]
+
+use fhir_model::{
+	convert::{ConversionError, ConvertFrom, ConvertInto, Converted},
+	r4b, r5, stu3,
+};
+use serde_json::json;
+
+#[test]
+fn patient_round_trip_keeps_animal() {
+	let json = json!({
+		"resourceType": "Patient",
+		"id": "dog",
+		"name": [{ "family": "Doe", "given": ["Rex"] }],
+		"animal": {
+			"species": { "coding": [{ "system": "http://hl7.org/fhir/animal-species", "code": "canislf" }] }
+		}
+	});
+	let patient: stu3::resources::Patient = serde_json::from_value(json.clone()).unwrap();
+
+	let converted = r5::resources::Patient::convert_from(patient).unwrap();
+	assert!(converted.report.is_lossless());
+	assert_eq!(converted.report.extensions, vec!["Patient.animal".to_owned()]);
+	let r5_json = serde_json::to_value(&converted.resource).unwrap();
+	assert!(r5_json.get("animal").is_none());
+	assert_eq!(
+		r5_json["extension"][0]["url"],
+		"http://hl7.org/fhir/3.0/StructureDefinition/extension-Patient.animal"
+	);
+
+	let converted: stu3::resources::Patient =
+		converted.resource.try_into().expect("converting back");
+	let stu3_json = serde_json::to_value(&converted).unwrap();
+	assert_eq!(stu3_json, json);
+}
+
+#[test]
+fn condition_status_mapping() {
+	let json = json!({
+		"resourceType": "Condition",
+		"clinicalStatus": "active",
+		"verificationStatus": "unknown",
+		"subject": { "reference": "Patient/1" },
+		"assertedDate": "2020-01-01"
+	});
+	let condition: stu3::resources::Condition = serde_json::from_value(json.clone()).unwrap();
+
+	let converted = r4b::resources::Condition::convert_from(condition).unwrap();
+	assert!(converted.report.is_lossless());
+	assert_eq!(converted.report.extensions, vec!["Condition.verificationStatus".to_owned()]);
+	let r4b_json = serde_json::to_value(&converted.resource).unwrap();
+	assert_eq!(
+		r4b_json["clinicalStatus"]["coding"][0],
+		json!({ "system": "http://terminology.hl7.org/CodeSystem/condition-clinical", "code": "active" })
+	);
+	assert!(r4b_json.get("verificationStatus").is_none());
+	assert_eq!(r4b_json["recordedDate"], "2020-01-01");
+
+	let converted: stu3::resources::Condition = converted.resource.try_into().unwrap();
+	assert_eq!(serde_json::to_value(&converted).unwrap(), json);
+}
+
+#[test]
+fn encounter_reports_dropped_data() {
+	let json = json!({
+		"resourceType": "Encounter",
+		"status": "completed",
+		"class": [{ "coding": [{ "code": "AMB" }] }],
+		"reason": [{
+			"use": [{ "text": "Chief complaint" }],
+			"value": [{ "concept": { "text": "Headache" } }]
+		}]
+	});
+	let encounter: r5::resources::Encounter = serde_json::from_value(json).unwrap();
+
+	let converted: Converted<r4b::resources::Encounter> = encounter.convert_into().unwrap();
+	let r4b_json = serde_json::to_value(&converted.resource).unwrap();
+	assert_eq!(r4b_json["status"], "finished");
+	assert_eq!(r4b_json["class"], json!({ "code": "AMB" }));
+	assert_eq!(r4b_json["reasonCode"], json!([{ "text": "Headache" }]));
+
+	assert!(!converted.report.is_lossless());
+	assert_eq!(converted.report.dropped.len(), 1);
+	assert_eq!(converted.report.dropped[0].path, "Encounter.reason.use");
+	assert_eq!(converted.report.dropped[0].value, json!([{ "text": "Chief complaint" }]));
+}
+
+#[test]
+fn unsupported_resource_type() {
+	let json = json!({
+		"resourceType": "BodySite",
+		"patient": { "reference": "Patient/1" }
+	});
+	let resource: stu3::resources::Resource = serde_json::from_value(json).unwrap();
+
+	let result = r5::resources::Resource::convert_from(resource);
+	assert!(matches!(
+		result,
+		Err(ConversionError::UnsupportedResourceType(resource_type)) if resource_type == "BodySite"
+	));
+}
+
+#[test]
+fn unmapped_resource_type() {
+	let json = json!({ "resourceType": "Location", "name": "Ward 1" });
+	let resource: stu3::resources::Resource = serde_json::from_value(json.clone()).unwrap();
+
+	let converted = r4b::resources::Resource::convert_from(resource).unwrap();
+	assert!(converted.report.dropped.is_empty());
+	assert_eq!(converted.report.unmapped, vec!["Location".to_owned()]);
+	assert!(!converted.report.is_lossless());
+	assert_eq!(serde_json::to_value(&converted.resource).unwrap(), json);
+}
+
+#[test]
+fn bundle_entries_are_converted() {
+	let json = json!({
+		"resourceType": "Bundle",
+		"type": "collection",
+		"entry": [{
+			"resource": {
+				"resourceType": "Observation",
+				"status": "final",
+				"code": { "text": "Note" },
+				"context": { "reference": "Encounter/1" },
+				"comment": "Some comment"
+			}
+		}]
+	});
+	let bundle: stu3::resources::Resource = serde_json::from_value(json).unwrap();
+
+	let converted = r4b::resources::Resource::convert_from(bundle).unwrap();
+	assert!(converted.report.is_lossless());
+	let bundle = serde_json::to_value(&converted.resource).unwrap();
+	let observation = &bundle["entry"][0]["resource"];
+	assert_eq!(observation["encounter"], json!({ "reference": "Encounter/1" }));
+	assert_eq!(observation["note"], json!([{ "text": "Some comment" }]));
+}
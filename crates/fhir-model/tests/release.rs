@@ -0,0 +1,94 @@
+#![cfg(all(feature = "stu3", feature = "r5"))]
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+#![recursion_limit = "1024"]
+
+use fhir_model::{
+	r5, stu3, FhirRelease, GenericCodeableConcept, GenericCoding, GenericIdentifiable, GenericMeta,
+	GenericReference, GenericResource, ParsedReference,
+};
+use serde_json::json;
+
+/// Patient JSON that is valid in STU3 and R5.
+fn patient_json() -> serde_json::Value {
+	json!({
+		"resourceType": "Patient",
+		"id": "1",
+		"meta": { "versionId": "2", "tag": [{ "system": "tags", "code": "test" }] },
+		"identifier": [
+			{ "system": "mrn", "value": "123" },
+			{
+				"type": { "coding": [{ "system": "types", "code": "SSN" }] },
+				"value": "456"
+			}
+		],
+		"generalPractitioner": [{ "reference": "Practitioner/2/_history/3" }]
+	})
+}
+
+/// Summary of a patient, written once for all FHIR versions.
+fn summary<V, R>(patient: &R) -> (Option<&str>, Option<&str>, Option<&str>, Option<&str>)
+where
+	V: FhirRelease,
+	R: GenericResource<V> + GenericIdentifiable<V>,
+{
+	(
+		patient.resource_id(),
+		patient.resource_version_id(),
+		patient.identifier_with_system("mrn"),
+		patient.identifier_with_type("types", "SSN"),
+	)
+}
+
+/// Whether the resource has the given tag, written once for all FHIR versions.
+fn has_tag<V: FhirRelease>(resource: &V::Resource, system: &str, code: &str) -> bool {
+	resource.resource_meta().map_or(false, |meta| {
+		meta.tags().any(|tag| tag.system() == Some(system) && tag.code() == Some(code))
+	})
+}
+
+#[test]
+fn generic_resource_access() {
+	let stu3_patient: stu3::resources::Patient = serde_json::from_value(patient_json()).unwrap();
+	let r5_patient: r5::resources::Patient = serde_json::from_value(patient_json()).unwrap();
+
+	let expected = (Some("1"), Some("2"), Some("123"), Some("456"));
+	assert_eq!(summary::<stu3::Stu3, _>(&stu3_patient), expected);
+	assert_eq!(summary::<r5::R5, _>(&r5_patient), expected);
+	assert_eq!(
+		GenericResource::<r5::R5>::resource_type(&r5_patient),
+		r5::resources::ResourceType::Patient
+	);
+
+	let stu3_resource: stu3::resources::Resource = stu3_patient.into();
+	let mut r5_resource: r5::resources::Resource = r5_patient.into();
+	assert!(has_tag::<stu3::Stu3>(&stu3_resource, "tags", "test"));
+	assert!(has_tag::<r5::R5>(&r5_resource, "tags", "test"));
+	assert!(!has_tag::<r5::R5>(&r5_resource, "tags", "other"));
+
+	GenericResource::<r5::R5>::set_resource_id(&mut r5_resource, None);
+	assert_eq!(GenericResource::<r5::R5>::resource_id(&r5_resource), None);
+}
+
+#[test]
+fn generic_reference_parsing() {
+	let reference: stu3::types::Reference =
+		serde_json::from_value(json!({ "reference": "Practitioner/2/_history/3" })).unwrap();
+	assert_eq!(
+		GenericReference::<stu3::Stu3>::parse(&reference),
+		Some(ParsedReference::Relative {
+			resource_type: "Practitioner",
+			id: "2",
+			version_id: Some("3")
+		})
+	);
+	assert_eq!(GenericReference::<stu3::Stu3>::target_type(&reference), None);
+
+	let reference: r5::types::Reference =
+		serde_json::from_value(json!({ "reference": "Patient/1", "type": "Patient" })).unwrap();
+	assert_eq!(GenericReference::<r5::R5>::target_type(&reference), Some("Patient"));
+
+	let concept: r5::types::CodeableConcept =
+		serde_json::from_value(json!({ "coding": [{ "system": "a", "code": "b" }] })).unwrap();
+	assert_eq!(GenericCodeableConcept::<r5::R5>::code_with_system(&concept, "a"), Some("b"));
+	assert_eq!(<r5::R5 as FhirRelease>::VERSION, "5.0");
+}
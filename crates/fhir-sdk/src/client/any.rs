@@ -0,0 +1,426 @@
+//! Client with the FHIR version detected at runtime.
+
+#[cfg(feature = "r4b")]
+use fhir_model::r4b;
+#[cfg(feature = "r5")]
+use fhir_model::r5;
+#[cfg(feature = "stu3")]
+use fhir_model::stu3;
+use fhir_model::{FhirRelease, GenericResource};
+use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+use reqwest::{
+	header::{self, HeaderMap, HeaderValue},
+	Method, StatusCode, Url,
+};
+
+#[cfg(feature = "r4b")]
+use super::FhirR4B;
+#[cfg(feature = "r5")]
+use super::FhirR5;
+#[cfg(feature = "stu3")]
+use super::FhirStu3;
+use super::{misc, Client, DefaultVersion, Error, FhirVersion, HttpRequest};
+
+/// MIME type used to request the `CapabilityStatement` without knowing the
+/// FHIR version of the server.
+const GENERIC_JSON_MIME_TYPE: &str = "application/fhir+json";
+
+/// Dispatch to the inner value of an [`AnyClient`] or [`VersionedResource`]
+/// for every enabled FHIR version. The body is evaluated with the inner value
+/// bound to the given name and the given type alias set to the respective
+/// [`FhirVersion`]. A client and a resource are dispatched together, falling
+/// back to the given expression if their FHIR versions differ.
+macro_rules! dispatch {
+	($enum:ident, $value:expr, |$inner:ident, $version:ident| $body:expr) => {
+		dispatch!(@single $enum, $value, $inner, $version, $body;
+			"stu3" Stu3 FhirStu3, "r4b" R4B FhirR4B, "r5" R5 FhirR5)
+	};
+	(
+		($client:expr, $resource:expr),
+		|$inner_client:ident, $inner_resource:ident, $version:ident| $body:expr,
+		$otherwise:expr
+	) => {
+		dispatch!(@pair $client, $resource, $inner_client, $inner_resource, $version, $body, $otherwise;
+			"stu3" Stu3 FhirStu3, "r4b" R4B FhirR4B, "r5" R5 FhirR5)
+	};
+	(@single $enum:ident, $value:expr, $inner:ident, $version:ident, $body:expr;
+		$($feature:literal $variant:ident $fhir:ident),*) => {
+		match $value {
+			$(
+				#[cfg(feature = $feature)]
+				$enum::$variant($inner) => {
+					#[allow(dead_code)]
+					type $version = $fhir;
+					$body
+				}
+			)*
+		}
+	};
+	(@pair $client:expr, $resource:expr, $inner_client:ident, $inner_resource:ident,
+		$version:ident, $body:expr, $otherwise:expr;
+		$($feature:literal $variant:ident $fhir:ident),*) => {
+		match ($client, $resource) {
+			$(
+				#[cfg(feature = $feature)]
+				(AnyClient::$variant($inner_client), VersionedResource::$variant($inner_resource)) => {
+					#[allow(dead_code)]
+					type $version = $fhir;
+					$body
+				}
+			)*
+			_ => $otherwise,
+		}
+	};
+}
+
+/// FHIR REST client for servers whose FHIR version is only known at runtime.
+///
+/// The version is detected from `CapabilityStatement.fhirVersion` and every
+/// variant wraps the respective versioned [`Client`]. Match on the variants to
+/// access the full typed API of a version.
+#[derive(Debug, Clone)]
+pub enum AnyClient {
+	#[cfg(feature = "stu3")]
+	/// Client for a FHIR STU3 server.
+	Stu3(Client<FhirStu3>),
+	#[cfg(feature = "r4b")]
+	/// Client for a FHIR R4B server.
+	R4B(Client<FhirR4B>),
+	#[cfg(feature = "r5")]
+	/// Client for a FHIR R5 server.
+	R5(Client<FhirR5>),
+}
+
+/// A FHIR resource tagged with its FHIR version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VersionedResource {
+	#[cfg(feature = "stu3")]
+	/// FHIR STU3 resource.
+	Stu3(stu3::resources::Resource),
+	#[cfg(feature = "r4b")]
+	/// FHIR R4B resource.
+	R4B(r4b::resources::Resource),
+	#[cfg(feature = "r5")]
+	/// FHIR R5 resource.
+	R5(r5::resources::Resource),
+}
+
+impl AnyClient {
+	/// Create a new client with default settings for the server at the given
+	/// base URL, detecting its FHIR version.
+	pub async fn new(base_url: Url) -> Result<Self, Error> {
+		let client = Client::<DefaultVersion>::builder().base_url(base_url).build()?;
+		Self::detect(client).await
+	}
+
+	/// Detect the FHIR version of the server behind the given client and
+	/// switch the client to it. Use this to detect the version with custom
+	/// client settings.
+	pub async fn detect<V: FhirVersion + Send + Sync>(client: Client<V>) -> Result<Self, Error> {
+		let url = client.url(&["metadata"]);
+		let request = HttpRequest::get(url)
+			.header(header::ACCEPT, HeaderValue::from_static(GENERIC_JSON_MIME_TYPE));
+		let response = client.run_request(request).await?;
+
+		let status = response.status();
+		let body = response.response.text();
+		if !status.is_success() {
+			return Err(Error::Response(status, body));
+		}
+
+		let capabilities: serde_json::Value = serde_json::from_str(&body)?;
+		let fhir_version = capabilities
+			.get("fhirVersion")
+			.and_then(serde_json::Value::as_str)
+			.ok_or_else(|| Error::UnsupportedFhirVersion("None".to_owned()))?;
+		tracing::debug!("Detected FHIR version {fhir_version}");
+
+		Self::from_fhir_version(client, fhir_version)
+	}
+
+	/// Switch the client to the given FHIR version, e.g. `4.3.0`.
+	#[allow(unreachable_patterns)] // Depends on the enabled FHIR versions.
+	fn from_fhir_version<V: FhirVersion + Send + Sync>(
+		client: Client<V>,
+		fhir_version: &str,
+	) -> Result<Self, Error> {
+		let mut parts = fhir_version.split('.');
+		match (parts.next(), parts.next()) {
+			#[cfg(feature = "stu3")]
+			(Some("3"), Some("0")) => Ok(Self::Stu3(client.stu3())),
+			#[cfg(feature = "r4b")]
+			(Some("4"), Some("3")) => Ok(Self::R4B(client.r4b())),
+			#[cfg(feature = "r5")]
+			(Some("5"), Some("0")) => Ok(Self::R5(client.r5())),
+			_ => Err(Error::UnsupportedFhirVersion(fhir_version.to_owned())),
+		}
+	}
+
+	/// The FHIR version of this client as used in the `fhirVersion` MIME type
+	/// parameter, e.g. `4.3`.
+	#[must_use]
+	pub fn fhir_version(&self) -> &'static str {
+		dispatch!(AnyClient, self, |_client, V| fhir_version::<V>())
+	}
+
+	/// Get the server's capabilities.
+	pub async fn capabilities(&self) -> Result<VersionedResource, Error> {
+		dispatch!(AnyClient, self, |client, V| {
+			let capabilities = client.capabilities().await?;
+			Ok(<V as FhirVersion>::Resource::from(capabilities).into())
+		})
+	}
+
+	/// Read the current version of a FHIR resource of the given resource type.
+	pub async fn read(
+		&self,
+		resource_type: &str,
+		id: &str,
+	) -> Result<Option<VersionedResource>, Error> {
+		self.read_path(resource_type, &[id]).await
+	}
+
+	/// Read a specific version of a FHIR resource of the given resource type.
+	pub async fn read_version(
+		&self,
+		resource_type: &str,
+		id: &str,
+		version_id: &str,
+	) -> Result<Option<VersionedResource>, Error> {
+		self.read_path(resource_type, &[id, "_history", version_id]).await
+	}
+
+	/// Read a resource from the path below the resource type's path.
+	async fn read_path(
+		&self,
+		resource_type: &str,
+		segments: &[&str],
+	) -> Result<Option<VersionedResource>, Error> {
+		dispatch!(AnyClient, self, |client, V| {
+			let resource = read_existing(client, resource_type, segments).await?;
+			Ok(resource.map(VersionedResource::from))
+		})
+	}
+
+	/// Search for FHIR resources of the given resource type with the given
+	/// query parameters, e.g. `[("name", "peter")]`. Only returns matches,
+	/// fetching all pages.
+	pub async fn search(
+		&self,
+		resource_type: &str,
+		queries: &[(&str, &str)],
+	) -> Result<BoxStream<'static, Result<VersionedResource, Error>>, Error> {
+		dispatch!(AnyClient, self, |client, V| {
+			let resource_type = parse_resource_type::<V>(resource_type)?;
+			let matches = client.search_raw(resource_type, queries).await?;
+			Ok(matches.map_ok(VersionedResource::from).boxed())
+		})
+	}
+
+	/// Create a new FHIR resource on the FHIR server. Returns the resource ID
+	/// and version ID. Fails if the resource does not match the server's FHIR
+	/// version.
+	#[allow(unreachable_patterns)] // Depends on the enabled FHIR versions.
+	pub async fn create(
+		&self,
+		resource: &VersionedResource,
+	) -> Result<(String, Option<String>), Error> {
+		let (_, headers) = dispatch!(
+			(self, resource),
+			|client, resource, V| send_resource(client, Method::POST, resource, None).await?,
+			return Err(Error::FhirVersionMismatch(self.fhir_version(), resource.fhir_version()))
+		);
+
+		let (id, version_id) = misc::parse_location(&headers)?;
+		let version_id = version_id.or(misc::parse_etag(&headers).ok());
+		Ok((id, version_id))
+	}
+
+	/// Update a FHIR resource (or create it if it did not exist). If
+	/// conditional update is selected, the resource is only updated if the
+	/// version ID matches the expectations. Returns whether the resource was
+	/// created and the new version ID.
+	#[allow(unreachable_patterns)] // Depends on the enabled FHIR versions.
+	pub async fn update(
+		&self,
+		resource: &VersionedResource,
+		conditional: bool,
+	) -> Result<(bool, String), Error> {
+		let if_match = if conditional {
+			let version_id = resource.version_id().ok_or(Error::MissingVersionId)?;
+			let if_match = HeaderValue::from_str(&format!("W/\"{version_id}\""))
+				.map_err(|_| Error::MissingVersionId)?;
+			Some(if_match)
+		} else {
+			None
+		};
+		let id = resource.id().ok_or(Error::MissingId)?;
+
+		let (status, headers) = dispatch!(
+			(self, resource),
+			|client, resource, V| {
+				send_resource(client, Method::PUT, resource, Some((id, if_match))).await?
+			},
+			return Err(Error::FhirVersionMismatch(self.fhir_version(), resource.fhir_version()))
+		);
+
+		let created = status == StatusCode::CREATED;
+		let version_id = misc::parse_etag(&headers)?;
+		Ok((created, version_id))
+	}
+
+	/// Delete a FHIR resource of the given resource type on the server.
+	pub async fn delete(&self, resource_type: &str, id: &str) -> Result<(), Error> {
+		dispatch!(AnyClient, self, |client, V| {
+			client.delete(parse_resource_type::<V>(resource_type)?, id).await
+		})
+	}
+}
+
+impl VersionedResource {
+	/// The FHIR version of this resource as used in the `fhirVersion` MIME type
+	/// parameter, e.g. `4.3`.
+	#[must_use]
+	pub fn fhir_version(&self) -> &'static str {
+		dispatch!(VersionedResource, self, |_resource, V| fhir_version::<V>())
+	}
+
+	/// Get the resource type as str.
+	#[must_use]
+	pub fn resource_type(&self) -> &'static str {
+		dispatch!(VersionedResource, self, |resource, V| resource.resource_type().as_str())
+	}
+
+	/// Get the resource ID.
+	#[must_use]
+	pub fn id(&self) -> Option<&str> {
+		dispatch!(VersionedResource, self, |resource, V| resource.resource_id())
+	}
+
+	/// Get the resource's version ID.
+	#[must_use]
+	pub fn version_id(&self) -> Option<&str> {
+		dispatch!(VersionedResource, self, |resource, V| resource.resource_version_id())
+	}
+}
+
+#[cfg(feature = "stu3")]
+impl From<stu3::resources::Resource> for VersionedResource {
+	fn from(resource: stu3::resources::Resource) -> Self {
+		Self::Stu3(resource)
+	}
+}
+
+#[cfg(feature = "r4b")]
+impl From<r4b::resources::Resource> for VersionedResource {
+	fn from(resource: r4b::resources::Resource) -> Self {
+		Self::R4B(resource)
+	}
+}
+
+#[cfg(feature = "r5")]
+impl From<r5::resources::Resource> for VersionedResource {
+	fn from(resource: r5::resources::Resource) -> Self {
+		Self::R5(resource)
+	}
+}
+
+/// The FHIR version as used in the `fhirVersion` MIME type parameter.
+fn fhir_version<V: FhirVersion>() -> &'static str {
+	<V::Release as FhirRelease>::VERSION
+}
+
+/// Parse the resource type of the FHIR version.
+fn parse_resource_type<V: FhirVersion>(resource_type: &str) -> Result<V::ResourceType, Error> {
+	resource_type.parse().map_err(|_| Error::UnexpectedResourceType(resource_type.to_owned()))
+}
+
+/// Read a resource of the given type from the path below the resource type's
+/// path. Returns `None` if the resource does not exist (anymore).
+async fn read_existing<V: FhirVersion>(
+	client: &Client<V>,
+	resource_type: &str,
+	segments: &[&str],
+) -> Result<Option<V::Resource>, Error> {
+	parse_resource_type::<V>(resource_type)?;
+	let mut url = client.url(&[resource_type]);
+	#[allow(clippy::expect_used)] // We made sure of it in the constructor.
+	url.path_segments_mut().expect("Base URL cannot be base").extend(segments);
+	let response = client.fetch_url(url).await?;
+
+	if [StatusCode::NOT_FOUND, StatusCode::GONE].contains(&response.status()) {
+		return Ok(None);
+	}
+
+	response.body().await.map(Some)
+}
+
+/// Send a resource to the server using the given method, to the resource
+/// type's path or, given an ID and optional `If-Match` header, to the
+/// resource's path. Returns the status and headers of the successful
+/// response.
+async fn send_resource<V: FhirVersion>(
+	client: &Client<V>,
+	method: Method,
+	resource: &V::Resource,
+	target: Option<(&str, Option<HeaderValue>)>,
+) -> Result<(StatusCode, HeaderMap), Error> {
+	let resource_type = resource.resource_type().to_string();
+	let (url, if_match) = match target {
+		Some((id, if_match)) => (client.url(&[resource_type.as_str(), id]), if_match),
+		None => (client.url(&[resource_type.as_str()]), None),
+	};
+	let mut request = HttpRequest::new(method, url)
+		.header(header::ACCEPT, HeaderValue::from_static(V::JSON_MIME_TYPE))
+		.header(header::CONTENT_TYPE, HeaderValue::from_static(V::JSON_MIME_TYPE))
+		.json(resource)?;
+	if let Some(if_match) = if_match {
+		request = request.header(header::IF_MATCH, if_match);
+	}
+
+	let response = client.run_request(request).await?;
+	if !response.status().is_success() {
+		return Err(response.into_error());
+	}
+	Ok((response.status(), response.response.headers))
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::expect_used)] // Allowed for tests
+
+	use super::*;
+
+	#[test]
+	fn version_detection() {
+		let client = Client::<DefaultVersion>::builder()
+			.base_url("http://localhost:8100/fhir/".parse().expect("parsing URL"))
+			.build()
+			.expect("building client");
+
+		#[cfg(feature = "stu3")]
+		assert!(matches!(
+			AnyClient::from_fhir_version(client.clone(), "3.0.2"),
+			Ok(AnyClient::Stu3(_))
+		));
+		#[cfg(feature = "r4b")]
+		assert!(matches!(
+			AnyClient::from_fhir_version(client.clone(), "4.3.0"),
+			Ok(AnyClient::R4B(_))
+		));
+		#[cfg(feature = "r5")]
+		assert!(matches!(
+			AnyClient::from_fhir_version(client.clone(), "5.0.0"),
+			Ok(AnyClient::R5(_))
+		));
+
+		assert!(matches!(
+			AnyClient::from_fhir_version(client.clone(), "1.0.2"),
+			Err(Error::UnsupportedFhirVersion(_))
+		));
+		assert!(matches!(
+			AnyClient::from_fhir_version(client, "garbage"),
+			Err(Error::UnsupportedFhirVersion(_))
+		));
+	}
+}
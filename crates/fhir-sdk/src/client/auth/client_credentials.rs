@@ -0,0 +1,96 @@
+//! OAuth2 client credentials grant.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures::lock::Mutex;
+use reqwest::{header::HeaderValue, Url};
+
+use super::{basic_auth, request_token, AuthProvider, CachedToken, DEFAULT_REFRESH_MARGIN};
+use crate::client::{Error, Next};
+
+/// [`AuthProvider`] using the OAuth2 client credentials grant, authenticating
+/// the client via HTTP Basic authentication.
+#[derive(Debug)]
+pub struct ClientCredentials {
+	/// URL of the token endpoint.
+	token_url: Url,
+	/// The client ID.
+	client_id: String,
+	/// The client secret.
+	client_secret: String,
+	/// Scopes to request.
+	scopes: Vec<String>,
+	/// Time before the expiry of the token to already refresh it.
+	refresh_margin: Duration,
+	/// The cached token. The lock is held while requesting a new token, so
+	/// that concurrent requests wait for the same token.
+	token: Mutex<Option<CachedToken>>,
+}
+
+impl ClientCredentials {
+	/// Create a new provider requesting tokens at the token endpoint with the
+	/// client's credentials.
+	#[must_use]
+	pub fn new(
+		token_url: Url,
+		client_id: impl Into<String>,
+		client_secret: impl Into<String>,
+	) -> Self {
+		Self {
+			token_url,
+			client_id: client_id.into(),
+			client_secret: client_secret.into(),
+			scopes: Vec::new(),
+			refresh_margin: DEFAULT_REFRESH_MARGIN,
+			token: Mutex::new(None),
+		}
+	}
+
+	/// Add a scope to request, e.g. `system/*.rs`.
+	#[must_use]
+	pub fn scope(mut self, scope: impl Into<String>) -> Self {
+		self.scopes.push(scope.into());
+		self
+	}
+
+	/// Set the time before the expiry of a token to already refresh it.
+	/// Defaults to 60 seconds, but tokens are refreshed after half their
+	/// lifetime at latest.
+	#[must_use]
+	pub fn refresh_margin(mut self, margin: Duration) -> Self {
+		self.refresh_margin = margin;
+		self
+	}
+}
+
+#[async_trait]
+impl AuthProvider for ClientCredentials {
+	async fn authorization(&self, next: Next<'_>) -> Result<HeaderValue, Error> {
+		let mut token = self.token.lock().await;
+		if let Some(token) = token.as_ref().filter(|token| token.is_fresh()) {
+			return Ok(token.authorization.clone());
+		}
+
+		let scope = self.scopes.join(" ");
+		let mut form = vec![("grant_type", "client_credentials")];
+		if !scope.is_empty() {
+			form.push(("scope", scope.as_str()));
+		}
+		let authorization = basic_auth(&self.client_id, &self.client_secret)?;
+		let (response, requested_at) =
+			request_token(next, &self.token_url, Some(authorization), &form).await?;
+
+		let new_token = CachedToken::new(&response, requested_at, self.refresh_margin)?;
+		let authorization = new_token.authorization.clone();
+		*token = Some(new_token);
+		Ok(authorization)
+	}
+
+	async fn invalidate(&self, authorization: &HeaderValue) {
+		let mut token = self.token.lock().await;
+		if token.as_ref().is_some_and(|token| &token.authorization == authorization) {
+			*token = None;
+		}
+	}
+}
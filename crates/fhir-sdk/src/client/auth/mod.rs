@@ -0,0 +1,220 @@
+//! Authentication providers for the client.
+//!
+//! An [`AuthProvider`] is set via
+//! [`ClientBuilder::auth_provider`](super::ClientBuilder::auth_provider) and
+//! supplies the `Authorization` header for all requests. Tokens are cached
+//! and refreshed when they are about to expire. If the server still rejects a
+//! token with `401 Unauthorized`, it is dropped and the request is retried
+//! once with a new token.
+
+mod client_credentials;
+#[cfg(feature = "smart")]
+mod smart_backend;
+#[cfg(feature = "smart")]
+mod smart_launch;
+
+use std::{
+	fmt::Debug,
+	sync::Arc,
+	time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use reqwest::{
+	header::{self, HeaderValue},
+	StatusCode, Url,
+};
+use serde::{de::DeserializeOwned, Deserialize};
+
+pub use self::client_credentials::ClientCredentials;
+#[cfg(feature = "smart")]
+pub use self::smart_backend::{SigningKey, SmartBackend};
+#[cfg(feature = "smart")]
+pub use self::smart_launch::{LaunchContext, SmartLaunch};
+use super::{Error, HttpRequest, HttpResponse, Middleware, Next};
+
+/// Default time before the expiry of a token to already refresh it.
+const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// Provider of the `Authorization` header for the client's requests.
+#[async_trait]
+pub trait AuthProvider: Debug + Send + Sync + 'static {
+	/// Get the `Authorization` header value to use, fetching a new token if
+	/// there is no valid one. Requests to the authorization server should be
+	/// sent via `next`, which passes them on to the client's transport.
+	/// Concurrent calls should share a single token request.
+	async fn authorization(&self, next: Next<'_>) -> Result<HeaderValue, Error>;
+
+	/// Drop the token after the server rejected the given `Authorization`
+	/// header value, so that the next call to
+	/// [`authorization`](Self::authorization) fetches a new one.
+	async fn invalidate(&self, authorization: &HeaderValue);
+}
+
+#[async_trait]
+impl<P: AuthProvider + ?Sized> AuthProvider for Arc<P> {
+	async fn authorization(&self, next: Next<'_>) -> Result<HeaderValue, Error> {
+		(**self).authorization(next).await
+	}
+
+	async fn invalidate(&self, authorization: &HeaderValue) {
+		(**self).invalidate(authorization).await;
+	}
+}
+
+/// Middleware layer setting the `Authorization` header from an
+/// [`AuthProvider`], retrying once with a new token on `401 Unauthorized`.
+#[derive(Debug)]
+pub(crate) struct AuthLayer<P>(pub(crate) P);
+
+#[async_trait]
+impl<P: AuthProvider> Middleware for AuthLayer<P> {
+	async fn handle(&self, request: HttpRequest, next: Next<'_>) -> Result<HttpResponse, Error> {
+		let authorization = self.0.authorization(next).await?;
+		let response =
+			next.run(request.clone().header(header::AUTHORIZATION, authorization.clone())).await?;
+		if response.status != StatusCode::UNAUTHORIZED {
+			return Ok(response);
+		}
+
+		tracing::info!("Hit unauthorized response, retrying with a new token");
+		self.0.invalidate(&authorization).await;
+		let authorization = self.0.authorization(next).await?;
+		next.run(request.header(header::AUTHORIZATION, authorization)).await
+	}
+}
+
+/// Successful response of an OAuth2 token endpoint.
+#[derive(Debug, Clone, Deserialize)]
+struct TokenResponse {
+	/// The access token.
+	access_token: String,
+	/// The token type, usually `Bearer`.
+	token_type: String,
+	/// Lifetime of the access token in seconds.
+	expires_in: Option<u64>,
+}
+
+/// Access token cached by a provider.
+#[derive(Debug, Clone)]
+struct CachedToken {
+	/// The `Authorization` header value.
+	authorization: HeaderValue,
+	/// Time from which on the token should be refreshed.
+	refresh_at: Option<Instant>,
+}
+
+impl CachedToken {
+	/// Create the cached token from the token response to the request at the
+	/// given time. The token is refreshed the margin before it expires, but at
+	/// latest after half its lifetime.
+	fn new(
+		response: &TokenResponse,
+		requested_at: Instant,
+		refresh_margin: Duration,
+	) -> Result<Self, Error> {
+		// Token types are case-insensitive, but many servers only accept `Bearer`.
+		let token_type = if response.token_type.eq_ignore_ascii_case("bearer") {
+			"Bearer"
+		} else {
+			response.token_type.as_str()
+		};
+		let mut authorization =
+			HeaderValue::from_str(&format!("{token_type} {}", response.access_token))
+				.map_err(|_| Error::Auth("Invalid characters in access token".to_owned()))?;
+		authorization.set_sensitive(true);
+		let refresh_at = response.expires_in.map(|secs| {
+			let lifetime = Duration::from_secs(secs);
+			requested_at + lifetime - refresh_margin.min(lifetime / 2)
+		});
+		Ok(Self { authorization, refresh_at })
+	}
+
+	/// Whether the token is still fresh, i.e. does not need to be refreshed.
+	fn is_fresh(&self) -> bool {
+		self.refresh_at.map_or(true, |refresh_at| Instant::now() < refresh_at)
+	}
+}
+
+/// Build the HTTP Basic authentication header for the client credentials.
+fn basic_auth(client_id: &str, client_secret: &str) -> Result<HeaderValue, Error> {
+	// RFC 6749 requires form-encoding the credentials before.
+	let encode =
+		|value: &str| -> String { form_urlencoded::byte_serialize(value.as_bytes()).collect() };
+	let credentials = STANDARD.encode(format!("{}:{}", encode(client_id), encode(client_secret)));
+	let mut header = HeaderValue::from_str(&format!("Basic {credentials}"))
+		.map_err(|_| Error::Auth("Invalid client credentials".to_owned()))?;
+	header.set_sensitive(true);
+	Ok(header)
+}
+
+/// Send a form-encoded request to the token endpoint and parse the response.
+async fn request_token<T: DeserializeOwned>(
+	next: Next<'_>,
+	token_url: &Url,
+	authorization: Option<HeaderValue>,
+	form: &[(&str, &str)],
+) -> Result<(T, Instant), Error> {
+	let body = form_urlencoded::Serializer::new(String::new()).extend_pairs(form).finish();
+	let mut request = HttpRequest::post(token_url.clone())
+		.header(header::ACCEPT, HeaderValue::from_static("application/json"))
+		.header(header::CONTENT_TYPE, HeaderValue::from_static("application/x-www-form-urlencoded"))
+		.body(body);
+	if let Some(authorization) = authorization {
+		request = request.header(header::AUTHORIZATION, authorization);
+	}
+
+	tracing::debug!("Requesting new access token from {token_url}");
+	let requested_at = Instant::now();
+	let response = next.run(request).await?;
+	if !response.status.is_success() {
+		return Err(Error::Auth(format!(
+			"Token request failed ({}): {}",
+			response.status,
+			response.text()
+		)));
+	}
+
+	let token = serde_json::from_slice(&response.body)?;
+	Ok((token, requested_at))
+}
+
+/// The relevant parts of the SMART configuration.
+#[cfg(feature = "smart")]
+#[derive(Debug, Clone, Deserialize)]
+struct SmartConfiguration {
+	/// URL of the authorization endpoint, not present for servers only
+	/// supporting backend services.
+	authorization_endpoint: Option<Url>,
+	/// URL of the token endpoint.
+	token_endpoint: Url,
+}
+
+/// Discover the SMART configuration of the FHIR server at the base URL via its
+/// `.well-known/smart-configuration`.
+#[cfg(feature = "smart")]
+async fn discover_smart_configuration(
+	next: Next<'_>,
+	base_url: &Url,
+) -> Result<SmartConfiguration, Error> {
+	let mut url = base_url.clone();
+	url.path_segments_mut()
+		.map_err(|()| Error::UrlCannotBeBase)?
+		.pop_if_empty()
+		.extend([".well-known", "smart-configuration"]);
+
+	tracing::debug!("Discovering SMART configuration at {url}");
+	let request =
+		HttpRequest::get(url).header(header::ACCEPT, HeaderValue::from_static("application/json"));
+	let response = next.run(request).await?;
+	if !response.status.is_success() {
+		return Err(Error::Auth(format!(
+			"SMART configuration discovery failed ({}): {}",
+			response.status,
+			response.text()
+		)));
+	}
+
+	Ok(serde_json::from_slice(&response.body)?)
+}
@@ -0,0 +1,288 @@
+//! SMART Backend Services authorization, using the OAuth2 client credentials
+//! grant with an asymmetrically signed JWT client assertion.
+
+use std::{
+	fmt::Debug,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use futures::lock::Mutex;
+use p384::ecdsa::signature::Signer as _;
+use reqwest::{header::HeaderValue, Url};
+use rsa::{
+	pkcs1::DecodeRsaPrivateKey, pkcs8::DecodePrivateKey, sha2::Sha384, signature::SignatureEncoding,
+};
+use serde::Deserialize;
+use serde_json::json;
+use uuid::Uuid;
+
+use super::{
+	discover_smart_configuration, request_token, AuthProvider, CachedToken, DEFAULT_REFRESH_MARGIN,
+};
+use crate::client::{Error, Next};
+
+/// Client assertion type of JWT assertions.
+const CLIENT_ASSERTION_TYPE: &str = "urn:ietf:params:oauth:client-assertion-type:jwt-bearer";
+
+/// Lifetime of the client assertions, the maximum allowed by SMART.
+const ASSERTION_LIFETIME: Duration = Duration::from_secs(300);
+
+/// Private key to sign the client assertions with.
+pub struct SigningKey {
+	/// The key ID, sent as `kid` in the JWT header.
+	key_id: String,
+	/// The key itself.
+	key: Key,
+}
+
+/// The supported key types.
+enum Key {
+	/// RSA key, signing with `RS384`.
+	Rs384(rsa::pkcs1v15::SigningKey<Sha384>),
+	/// EC key on curve P-384, signing with `ES384`.
+	Es384(p384::ecdsa::SigningKey),
+}
+
+/// The parts of a JWK needed to load private keys.
+#[derive(Deserialize)]
+struct Jwk {
+	/// Key type, `RSA` or `EC`.
+	kty: String,
+	/// Key ID.
+	kid: Option<String>,
+	/// EC curve.
+	crv: Option<String>,
+	/// RSA modulus.
+	n: Option<String>,
+	/// RSA public exponent.
+	e: Option<String>,
+	/// RSA private exponent or EC private key.
+	d: Option<String>,
+	/// RSA first prime factor.
+	p: Option<String>,
+	/// RSA second prime factor.
+	q: Option<String>,
+}
+
+impl SigningKey {
+	/// Load an RSA private key in PKCS#8 or PKCS#1 PEM format, signing with
+	/// `RS384`.
+	pub fn rs384_from_pem(pem: &str, key_id: impl Into<String>) -> Result<Self, Error> {
+		let key = rsa::RsaPrivateKey::from_pkcs8_pem(pem)
+			.or_else(|_| rsa::RsaPrivateKey::from_pkcs1_pem(pem))
+			.map_err(|err| Error::Auth(format!("Invalid RSA key: {err}")))?;
+		Ok(Self { key_id: key_id.into(), key: Key::Rs384(rsa::pkcs1v15::SigningKey::new(key)) })
+	}
+
+	/// Load an EC private key on curve P-384 in PKCS#8 or SEC1 PEM format,
+	/// signing with `ES384`.
+	pub fn es384_from_pem(pem: &str, key_id: impl Into<String>) -> Result<Self, Error> {
+		let key = p384::SecretKey::from_pkcs8_pem(pem)
+			.or_else(|_| p384::SecretKey::from_sec1_pem(pem))
+			.map_err(|err| Error::Auth(format!("Invalid EC key: {err}")))?;
+		Ok(Self { key_id: key_id.into(), key: Key::Es384(key.into()) })
+	}
+
+	/// Load an RSA or P-384 EC private key from a JWK, taking the key ID from
+	/// its `kid`.
+	pub fn from_jwk(jwk: &str) -> Result<Self, Error> {
+		let parsed: Jwk = serde_json::from_str(jwk)?;
+		let key_id = parsed.kid.ok_or_else(|| Error::Auth("JWK is missing `kid`".to_owned()))?;
+
+		let decode = |value: Option<String>, name: &str| {
+			let value = value.ok_or_else(|| Error::Auth(format!("JWK is missing `{name}`")))?;
+			URL_SAFE_NO_PAD
+				.decode(value)
+				.map_err(|err| Error::Auth(format!("Invalid `{name}` in JWK: {err}")))
+		};
+		let key = match parsed.kty.as_str() {
+			"RSA" => {
+				let decode_uint = |value, name| {
+					decode(value, name).map(|bytes| rsa::BigUint::from_bytes_be(&bytes))
+				};
+				let key = rsa::RsaPrivateKey::from_components(
+					decode_uint(parsed.n, "n")?,
+					decode_uint(parsed.e, "e")?,
+					decode_uint(parsed.d, "d")?,
+					vec![decode_uint(parsed.p, "p")?, decode_uint(parsed.q, "q")?],
+				)
+				.map_err(|err| Error::Auth(format!("Invalid RSA key: {err}")))?;
+				Key::Rs384(rsa::pkcs1v15::SigningKey::new(key))
+			}
+			"EC" if parsed.crv.as_deref() == Some("P-384") => {
+				let key = p384::SecretKey::from_slice(&decode(parsed.d, "d")?)
+					.map_err(|err| Error::Auth(format!("Invalid EC key: {err}")))?;
+				Key::Es384(key.into())
+			}
+			"EC" => {
+				return Err(Error::Auth(
+					"Unsupported JWK curve, only P-384 is supported".to_owned(),
+				))
+			}
+			kty => return Err(Error::Auth(format!("Unsupported JWK key type `{kty}`"))),
+		};
+		Ok(Self { key_id, key })
+	}
+
+	/// The JWS algorithm of the key.
+	fn algorithm(&self) -> &'static str {
+		match self.key {
+			Key::Rs384(_) => "RS384",
+			Key::Es384(_) => "ES384",
+		}
+	}
+
+	/// Create a signed JWT with the claims.
+	fn sign_jwt(&self, claims: &serde_json::Value) -> Result<String, Error> {
+		let header = json!({ "alg": self.algorithm(), "typ": "JWT", "kid": self.key_id });
+		let message = format!(
+			"{}.{}",
+			URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header)?),
+			URL_SAFE_NO_PAD.encode(serde_json::to_vec(claims)?)
+		);
+
+		let signature = match &self.key {
+			Key::Rs384(key) => rsa::signature::Signer::sign(key, message.as_bytes()).to_vec(),
+			Key::Es384(key) => {
+				let signature: p384::ecdsa::Signature = key.sign(message.as_bytes());
+				signature.to_bytes().to_vec()
+			}
+		};
+		Ok(format!("{message}.{}", URL_SAFE_NO_PAD.encode(signature)))
+	}
+}
+
+impl Debug for SigningKey {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("SigningKey")
+			.field("key_id", &self.key_id)
+			.field("algorithm", &self.algorithm())
+			.finish_non_exhaustive()
+	}
+}
+
+/// State of the provider, guarded by a lock that is held while requesting new
+/// tokens.
+#[derive(Debug, Default)]
+struct State {
+	/// The token endpoint, once discovered.
+	token_url: Option<Url>,
+	/// The cached token.
+	token: Option<CachedToken>,
+}
+
+/// [`AuthProvider`] for
+/// [SMART Backend Services](https://hl7.org/fhir/smart-app-launch/backend-services.html),
+/// authenticating with a JWT signed by the client's private key. The token
+/// endpoint is discovered via the FHIR server's
+/// `.well-known/smart-configuration`.
+#[derive(Debug)]
+pub struct SmartBackend {
+	/// The FHIR server's base URL.
+	base_url: Url,
+	/// The client ID.
+	client_id: String,
+	/// The key to sign the client assertions with.
+	key: SigningKey,
+	/// Scopes to request.
+	scopes: Vec<String>,
+	/// Time before the expiry of the token to already refresh it.
+	refresh_margin: Duration,
+	/// Discovered token endpoint and cached token.
+	state: Mutex<State>,
+}
+
+impl SmartBackend {
+	/// Create a new provider for the FHIR server at the base URL, using the
+	/// client ID registered at the server and the registered key.
+	#[must_use]
+	pub fn new(base_url: Url, client_id: impl Into<String>, key: SigningKey) -> Self {
+		Self {
+			base_url,
+			client_id: client_id.into(),
+			key,
+			scopes: Vec::new(),
+			refresh_margin: DEFAULT_REFRESH_MARGIN,
+			state: Mutex::new(State::default()),
+		}
+	}
+
+	/// Add a scope to request, e.g. `system/Patient.rs`.
+	#[must_use]
+	pub fn scope(mut self, scope: impl Into<String>) -> Self {
+		self.scopes.push(scope.into());
+		self
+	}
+
+	/// Set the token endpoint instead of discovering it.
+	#[must_use]
+	pub fn token_url(self, token_url: Url) -> Self {
+		Self { state: Mutex::new(State { token_url: Some(token_url), token: None }), ..self }
+	}
+
+	/// Set the time before the expiry of a token to already refresh it.
+	/// Defaults to 60 seconds, but tokens are refreshed after half their
+	/// lifetime at latest.
+	#[must_use]
+	pub fn refresh_margin(mut self, margin: Duration) -> Self {
+		self.refresh_margin = margin;
+		self
+	}
+
+	/// Create a new signed client assertion for the token endpoint.
+	fn client_assertion(&self, token_url: &Url) -> Result<String, Error> {
+		let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+		let claims = json!({
+			"iss": self.client_id,
+			"sub": self.client_id,
+			"aud": token_url.as_str(),
+			"exp": (now + ASSERTION_LIFETIME).as_secs(),
+			"jti": Uuid::new_v4().to_string(),
+		});
+		self.key.sign_jwt(&claims)
+	}
+}
+
+#[async_trait]
+impl AuthProvider for SmartBackend {
+	async fn authorization(&self, next: Next<'_>) -> Result<HeaderValue, Error> {
+		let mut state = self.state.lock().await;
+		if let Some(token) = state.token.as_ref().filter(|token| token.is_fresh()) {
+			return Ok(token.authorization.clone());
+		}
+
+		let token_url = match &state.token_url {
+			Some(token_url) => token_url.clone(),
+			None => {
+				let token_url =
+					discover_smart_configuration(next, &self.base_url).await?.token_endpoint;
+				state.token_url = Some(token_url.clone());
+				token_url
+			}
+		};
+
+		let scope = self.scopes.join(" ");
+		let assertion = self.client_assertion(&token_url)?;
+		let form = [
+			("grant_type", "client_credentials"),
+			("scope", scope.as_str()),
+			("client_assertion_type", CLIENT_ASSERTION_TYPE),
+			("client_assertion", assertion.as_str()),
+		];
+		let (response, requested_at) = request_token(next, &token_url, None, &form).await?;
+
+		let token = CachedToken::new(&response, requested_at, self.refresh_margin)?;
+		let authorization = token.authorization.clone();
+		state.token = Some(token);
+		Ok(authorization)
+	}
+
+	async fn invalidate(&self, authorization: &HeaderValue) {
+		let mut state = self.state.lock().await;
+		if state.token.as_ref().is_some_and(|token| &token.authorization == authorization) {
+			state.token = None;
+		}
+	}
+}
@@ -0,0 +1,383 @@
+//! SMART App Launch, using the OAuth2 authorization code grant with PKCE and
+//! refresh tokens.
+
+use std::{
+	sync::Arc,
+	time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use futures::lock::Mutex;
+use reqwest::{header::HeaderValue, Url};
+use rsa::sha2::{Digest, Sha256};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use super::{
+	basic_auth, discover_smart_configuration, request_token, AuthProvider, CachedToken,
+	TokenResponse, DEFAULT_REFRESH_MARGIN,
+};
+use crate::client::{Error, FhirVersion, Next, ReqwestTransport, Transport};
+
+/// Launch context granted along with the access token.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[non_exhaustive]
+pub struct LaunchContext {
+	/// ID of the patient in context.
+	pub patient: Option<String>,
+	/// ID of the encounter in context.
+	pub encounter: Option<String>,
+	/// Reference to the resource representing the current user, e.g.
+	/// `Practitioner/123`, as an absolute or relative URL.
+	#[serde(rename = "fhirUser")]
+	pub fhir_user: Option<String>,
+	/// The granted scopes, separated by spaces.
+	pub scope: Option<String>,
+}
+
+impl LaunchContext {
+	/// Reference to the patient in context, to be used with
+	/// [`Client::read_referenced`](crate::client::Client::read_referenced).
+	#[must_use]
+	pub fn patient_reference<V: FhirVersion>(&self) -> Option<V::Reference> {
+		self.patient.as_ref().map(|id| V::reference(&format!("Patient/{id}")))
+	}
+
+	/// Reference to the encounter in context, to be used with
+	/// [`Client::read_referenced`](crate::client::Client::read_referenced).
+	#[must_use]
+	pub fn encounter_reference<V: FhirVersion>(&self) -> Option<V::Reference> {
+		self.encounter.as_ref().map(|id| V::reference(&format!("Encounter/{id}")))
+	}
+
+	/// Reference to the current user, to be used with
+	/// [`Client::read_referenced`](crate::client::Client::read_referenced).
+	#[must_use]
+	pub fn fhir_user_reference<V: FhirVersion>(&self) -> Option<V::Reference> {
+		self.fhir_user.as_deref().map(V::reference)
+	}
+}
+
+/// Response of the token endpoint in the SMART App Launch.
+#[derive(Debug, Deserialize)]
+struct LaunchTokenResponse {
+	/// The access token.
+	#[serde(flatten)]
+	token: TokenResponse,
+	/// Refresh token to request new access tokens with.
+	refresh_token: Option<String>,
+	/// OpenID Connect ID token, containing the `fhirUser` claim.
+	id_token: Option<String>,
+	/// The launch context.
+	#[serde(flatten)]
+	context: LaunchContext,
+}
+
+impl LaunchTokenResponse {
+	/// Get the launch context, taking `fhirUser` from the ID token if it is
+	/// not part of the response itself. The ID token's signature is not
+	/// verified, as it was received directly from the token endpoint.
+	fn context(&self) -> LaunchContext {
+		/// The relevant claims of the ID token.
+		#[derive(Deserialize)]
+		struct IdTokenClaims {
+			/// Reference to the resource representing the user.
+			#[serde(rename = "fhirUser")]
+			fhir_user: Option<String>,
+		}
+
+		let mut context = self.context.clone();
+		if context.fhir_user.is_none() {
+			context.fhir_user = self
+				.id_token
+				.as_deref()
+				.and_then(|id_token| id_token.split('.').nth(1))
+				.and_then(|claims| URL_SAFE_NO_PAD.decode(claims).ok())
+				.and_then(|claims| serde_json::from_slice::<IdTokenClaims>(&claims).ok())
+				.and_then(|claims| claims.fhir_user);
+		}
+		context
+	}
+}
+
+/// Authorization request waiting for the redirect back to the app.
+#[derive(Debug)]
+struct PendingAuthorization {
+	/// The `state` parameter sent to the authorization endpoint.
+	state: String,
+	/// The PKCE code verifier.
+	code_verifier: String,
+}
+
+/// State of the provider, guarded by a lock that is held while requesting new
+/// tokens.
+#[derive(Debug, Default)]
+struct State {
+	/// The authorization and token endpoints, once discovered.
+	endpoints: Option<(Url, Url)>,
+	/// The authorization request waiting for the redirect.
+	pending: Option<PendingAuthorization>,
+	/// The cached token.
+	token: Option<CachedToken>,
+	/// The refresh token.
+	refresh_token: Option<String>,
+	/// The launch context of the last code exchange.
+	context: Option<LaunchContext>,
+}
+
+/// [`AuthProvider`] for the
+/// [SMART App Launch](https://hl7.org/fhir/smart-app-launch/app-launch.html),
+/// authorizing a user via the authorization code grant with PKCE. The
+/// authorization and token endpoints are discovered via the FHIR server's
+/// `.well-known/smart-configuration`.
+///
+/// Send the user to the [`authorize_url`](Self::authorize_url) and pass the
+/// redirect back to the app to [`handle_redirect`](Self::handle_redirect).
+/// Keep an [`Arc`] of the provider to do so while the client uses it. Access
+/// tokens are refreshed with the refresh token automatically, so request the
+/// `offline_access` or `online_access` scope.
+#[derive(Debug)]
+pub struct SmartLaunch {
+	/// The FHIR server's base URL.
+	base_url: Url,
+	/// The client ID.
+	client_id: String,
+	/// The client secret of confidential clients.
+	client_secret: Option<String>,
+	/// The registered redirect URI.
+	redirect_uri: Url,
+	/// Scopes to request.
+	scopes: Vec<String>,
+	/// Time before the expiry of the token to already refresh it.
+	refresh_margin: Duration,
+	/// Transport for requests outside of the client's requests, i.e. the
+	/// discovery and the code exchange.
+	transport: Arc<dyn Transport>,
+	/// Discovered endpoints, pending authorization and tokens.
+	state: Mutex<State>,
+}
+
+impl SmartLaunch {
+	/// Create a new provider for the FHIR server at the base URL, using the
+	/// client ID and redirect URI registered at the server.
+	#[must_use]
+	pub fn new(base_url: Url, client_id: impl Into<String>, redirect_uri: Url) -> Self {
+		Self {
+			base_url,
+			client_id: client_id.into(),
+			client_secret: None,
+			redirect_uri,
+			scopes: Vec::new(),
+			refresh_margin: DEFAULT_REFRESH_MARGIN,
+			transport: Arc::new(ReqwestTransport::default()),
+			state: Mutex::new(State::default()),
+		}
+	}
+
+	/// Add a scope to request, e.g. `launch`, `openid`, `fhirUser` or
+	/// `patient/*.rs`.
+	#[must_use]
+	pub fn scope(mut self, scope: impl Into<String>) -> Self {
+		self.scopes.push(scope.into());
+		self
+	}
+
+	/// Set the client secret, authenticating as confidential client.
+	#[must_use]
+	pub fn client_secret(mut self, client_secret: impl Into<String>) -> Self {
+		self.client_secret = Some(client_secret.into());
+		self
+	}
+
+	/// Set the authorization and token endpoints instead of discovering them.
+	#[must_use]
+	pub fn endpoints(self, authorize_url: Url, token_url: Url) -> Self {
+		let state = State { endpoints: Some((authorize_url, token_url)), ..State::default() };
+		Self { state: Mutex::new(state), ..self }
+	}
+
+	/// Set the time before the expiry of a token to already refresh it.
+	/// Defaults to 60 seconds, but tokens are refreshed after half their
+	/// lifetime at latest.
+	#[must_use]
+	pub fn refresh_margin(mut self, margin: Duration) -> Self {
+		self.refresh_margin = margin;
+		self
+	}
+
+	/// Set the transport for the discovery and the code exchange, which are
+	/// not sent via the client. Refreshes are sent via the client's transport.
+	#[must_use]
+	pub fn transport(mut self, transport: impl Transport) -> Self {
+		self.transport = Arc::new(transport);
+		self
+	}
+
+	/// Get the authorization and token endpoints, discovering them if needed.
+	async fn endpoints_of(&self, state: &mut State) -> Result<(Url, Url), Error> {
+		if let Some(endpoints) = &state.endpoints {
+			return Ok(endpoints.clone());
+		}
+
+		let configuration =
+			discover_smart_configuration(Next::new(self.transport.as_ref(), &[]), &self.base_url)
+				.await?;
+		let authorize_url = configuration.authorization_endpoint.ok_or_else(|| {
+			Error::Auth("SMART configuration is missing `authorization_endpoint`".to_owned())
+		})?;
+		let endpoints = (authorize_url, configuration.token_endpoint);
+		state.endpoints = Some(endpoints.clone());
+		Ok(endpoints)
+	}
+
+	/// Start a new authorization and get the URL to send the user to. Pass the
+	/// `launch` parameter received from the EHR for EHR launches and `None`
+	/// for standalone launches. Replaces any pending authorization.
+	pub async fn authorize_url(&self, launch: Option<&str>) -> Result<Url, Error> {
+		let mut state = self.state.lock().await;
+		let (mut url, _) = self.endpoints_of(&mut state).await?;
+
+		let pending = PendingAuthorization {
+			state: Uuid::new_v4().simple().to_string(),
+			code_verifier: URL_SAFE_NO_PAD
+				.encode([Uuid::new_v4().into_bytes(), Uuid::new_v4().into_bytes()].concat()),
+		};
+		let code_challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(&pending.code_verifier));
+
+		{
+			let mut query = url.query_pairs_mut();
+			query
+				.append_pair("response_type", "code")
+				.append_pair("client_id", &self.client_id)
+				.append_pair("redirect_uri", self.redirect_uri.as_str())
+				.append_pair("scope", &self.scopes.join(" "))
+				.append_pair("state", &pending.state)
+				.append_pair("aud", self.base_url.as_str().trim_end_matches('/'))
+				.append_pair("code_challenge", &code_challenge)
+				.append_pair("code_challenge_method", "S256");
+			if let Some(launch) = launch {
+				query.append_pair("launch", launch);
+			}
+		}
+
+		state.pending = Some(pending);
+		Ok(url)
+	}
+
+	/// Handle the redirect back to the app after the authorization, exchanging
+	/// the code for tokens. Returns the launch context.
+	pub async fn handle_redirect(&self, redirect: &Url) -> Result<LaunchContext, Error> {
+		let param = |name: &str| {
+			redirect.query_pairs().find(|(key, _)| key == name).map(|(_, value)| value.into_owned())
+		};
+		if let Some(error) = param("error") {
+			let description = param("error_description").unwrap_or_default();
+			return Err(Error::Auth(format!("Authorization failed ({error}): {description}")));
+		}
+
+		let code = param("code")
+			.ok_or_else(|| Error::Auth("Redirect is missing the `code` parameter".to_owned()))?;
+		let state = param("state")
+			.ok_or_else(|| Error::Auth("Redirect is missing the `state` parameter".to_owned()))?;
+		self.exchange_code(&code, &state).await
+	}
+
+	/// Exchange the authorization code for tokens, checking the `state`
+	/// against the pending authorization. Returns the launch context.
+	pub async fn exchange_code(&self, code: &str, state: &str) -> Result<LaunchContext, Error> {
+		let mut locked = self.state.lock().await;
+		// The pending authorization is only cleared once the exchange succeeded,
+		// so that failed exchanges can be retried.
+		let code_verifier = locked
+			.pending
+			.as_ref()
+			.filter(|pending| pending.state == state)
+			.map(|pending| pending.code_verifier.clone())
+			.ok_or_else(|| Error::Auth("No pending authorization with this state".to_owned()))?;
+		let (_, token_url) = self.endpoints_of(&mut locked).await?;
+
+		let form = [
+			("grant_type", "authorization_code"),
+			("code", code),
+			("redirect_uri", self.redirect_uri.as_str()),
+			("code_verifier", code_verifier.as_str()),
+		];
+		let response =
+			self.request_token(Next::new(self.transport.as_ref(), &[]), &token_url, &form).await?;
+		let (_, context) = self.store(&mut locked, response)?;
+		locked.pending = None;
+		locked.context = Some(context.clone());
+		Ok(context)
+	}
+
+	/// Get the launch context of the last code exchange.
+	pub async fn context(&self) -> Option<LaunchContext> {
+		self.state.lock().await.context.clone()
+	}
+
+	/// Send the token request, authenticating the client.
+	async fn request_token(
+		&self,
+		next: Next<'_>,
+		token_url: &Url,
+		form: &[(&str, &str)],
+	) -> Result<(LaunchTokenResponse, Instant), Error> {
+		match &self.client_secret {
+			Some(client_secret) => {
+				let authorization = basic_auth(&self.client_id, client_secret)?;
+				request_token(next, token_url, Some(authorization), form).await
+			}
+			None => {
+				let mut form = form.to_vec();
+				form.push(("client_id", self.client_id.as_str()));
+				request_token(next, token_url, None, &form).await
+			}
+		}
+	}
+
+	/// Store the tokens of the token response. Returns the `Authorization`
+	/// header value and the launch context.
+	fn store(
+		&self,
+		state: &mut State,
+		(response, requested_at): (LaunchTokenResponse, Instant),
+	) -> Result<(HeaderValue, LaunchContext), Error> {
+		let token = CachedToken::new(&response.token, requested_at, self.refresh_margin)?;
+		let authorization = token.authorization.clone();
+		state.token = Some(token);
+		// Servers only return a new refresh token if they rotate it.
+		if let Some(refresh_token) = &response.refresh_token {
+			state.refresh_token = Some(refresh_token.clone());
+		}
+		Ok((authorization, response.context()))
+	}
+}
+
+#[async_trait]
+impl AuthProvider for SmartLaunch {
+	async fn authorization(&self, next: Next<'_>) -> Result<HeaderValue, Error> {
+		let mut state = self.state.lock().await;
+		if let Some(token) = state.token.as_ref().filter(|token| token.is_fresh()) {
+			return Ok(token.authorization.clone());
+		}
+
+		let Some(refresh_token) = state.refresh_token.clone() else {
+			return Err(Error::Auth(
+				"No valid access token or refresh token, the app needs to be launched".to_owned(),
+			));
+		};
+		let (_, token_url) = self.endpoints_of(&mut state).await?;
+		let form = [("grant_type", "refresh_token"), ("refresh_token", refresh_token.as_str())];
+		let response = self.request_token(next, &token_url, &form).await?;
+		let (authorization, _) = self.store(&mut state, response)?;
+		Ok(authorization)
+	}
+
+	async fn invalidate(&self, authorization: &HeaderValue) {
+		let mut state = self.state.lock().await;
+		if state.token.as_ref().is_some_and(|token| &token.authorization == authorization) {
+			state.token = None;
+		}
+	}
+}
@@ -8,7 +8,14 @@ use std::{
 
 use reqwest::{header::HeaderValue, Url};
 
-use super::{AuthCallback, Client, Error, RequestSettings};
+use super::{
+	auth::{AuthLayer, AuthProvider},
+	cache::ResponseCache,
+	limit::Limited,
+	middleware::MiddlewareStack,
+	AuthCallback, CacheSettings, Client, Error, Middleware, RequestSettings, ReqwestTransport,
+	Transport,
+};
 
 /// Default user agent of this client.
 const DEFAULT_USER_AGENT: HeaderValue =
@@ -22,8 +29,18 @@ pub struct ClientBuilder<Version = super::DefaultVersion> {
 	/// If a client is set using [`ClientBuilder::client`], this value will
 	/// override the user agent value in the [reqwest::Client]
 	user_agent: Option<HeaderValue>,
-	/// [reqwest::Client] to use for all requests
-	client: Option<reqwest::Client>,
+	/// [Transport] to send all requests with.
+	transport: Option<Arc<dyn Transport>>,
+	/// Middleware layers, outermost first.
+	middleware: Vec<Arc<dyn Middleware>>,
+	/// Rate limit in requests per second and burst size.
+	rate_limit: Option<(f64, u32)>,
+	/// Maximum number of requests in flight.
+	max_in_flight_requests: Option<usize>,
+	/// Response cache settings, if enabled.
+	cache: Option<CacheSettings>,
+	/// URL length above which searches are sent via `POST`.
+	post_search_threshold: Option<usize>,
 	/// Request settings.
 	request_settings: Option<RequestSettings>,
 	/// Auth callback.
@@ -37,7 +54,12 @@ impl<V> Default for ClientBuilder<V> {
 		Self {
 			base_url: None,
 			user_agent: None,
-			client: None,
+			transport: None,
+			middleware: Vec::new(),
+			rate_limit: None,
+			max_in_flight_requests: None,
+			cache: None,
+			post_search_threshold: None,
 			request_settings: None,
 			auth_callback: None,
 			version: PhantomData,
@@ -60,10 +82,74 @@ impl<V> ClientBuilder<V> {
 		self
 	}
 
-	/// [reqwest::Client] to use
+	/// [reqwest::Client] to use. Shorthand for setting a [ReqwestTransport]
+	/// via [`ClientBuilder::transport`].
 	#[must_use]
-	pub fn client(mut self, client: reqwest::Client) -> Self {
-		self.client = Some(client);
+	pub fn client(self, client: reqwest::Client) -> Self {
+		self.transport(ReqwestTransport::new(client))
+	}
+
+	/// [Transport] to send all requests with. Defaults to a
+	/// [ReqwestTransport].
+	#[must_use]
+	pub fn transport(mut self, transport: impl Transport) -> Self {
+		self.transport = Some(Arc::new(transport));
+		self
+	}
+
+	/// Add a middleware layer that all HTTP requests pass through. Layers
+	/// added first are called first for requests and last for responses.
+	#[must_use]
+	pub fn middleware(mut self, middleware: impl Middleware) -> Self {
+		self.middleware.push(Arc::new(middleware));
+		self
+	}
+
+	/// Set an authentication provider supplying the `Authorization` header for
+	/// all requests, e.g. [`ClientCredentials`](super::auth::ClientCredentials).
+	/// It is added as middleware layer, so layers added before see the requests
+	/// without the header.
+	#[must_use]
+	pub fn auth_provider(self, provider: impl AuthProvider) -> Self {
+		self.middleware(AuthLayer(provider))
+	}
+
+	/// Limit the rate of requests to `per_second` on average, allowing bursts
+	/// of up to `burst` requests. Requests exceeding the limit wait. The limit
+	/// is shared by all clones of the client and applies to every request,
+	/// including retries and page fetches.
+	#[must_use]
+	pub fn rate_limit(mut self, per_second: f64, burst: u32) -> Self {
+		self.rate_limit = Some((per_second, burst));
+		self
+	}
+
+	/// Limit the number of requests in flight at the same time. Further
+	/// requests wait. The limit is shared by all clones of the client.
+	#[must_use]
+	pub fn max_in_flight_requests(mut self, max: usize) -> Self {
+		self.max_in_flight_requests = Some(max);
+		self
+	}
+
+	/// Enable caching of `GET` responses with `ETag` or `Last-Modified`
+	/// headers. Cached responses are revalidated with conditional requests,
+	/// answering `304 Not Modified` responses from the cache. Responses are
+	/// cached by URL and request headers, the cache is shared by all clones
+	/// of the client.
+	#[must_use]
+	pub fn cache(mut self, settings: CacheSettings) -> Self {
+		self.cache = Some(settings);
+		self
+	}
+
+	/// Send searches via `POST [type]/_search` with a form-encoded body if
+	/// the search URL would be longer than `max_url_length`, e.g. to stay
+	/// below URL length limits of servers and proxies. Individual searches can
+	/// be sent via `POST` using [`Search::via_post`](super::Search::via_post).
+	#[must_use]
+	pub fn post_search_threshold(mut self, max_url_length: usize) -> Self {
+		self.post_search_threshold = Some(max_url_length);
 		self
 	}
 
@@ -98,9 +184,28 @@ impl<V> ClientBuilder<V> {
 			return Err(Error::UrlCannotBeBase);
 		}
 
+		if self.rate_limit.is_some_and(|(per_second, burst)| {
+			per_second <= 0.0 || !per_second.is_finite() || burst == 0
+		}) {
+			return Err(Error::BuilderInvalidField("rate_limit"));
+		}
+		if self.max_in_flight_requests == Some(0) {
+			return Err(Error::BuilderInvalidField("max_in_flight_requests"));
+		}
+
 		let user_agent = self.user_agent.unwrap_or(DEFAULT_USER_AGENT);
 
-		let client = self.client.unwrap_or_default();
+		let mut transport = self.transport.unwrap_or_else(|| Arc::new(ReqwestTransport::default()));
+		if !self.middleware.is_empty() {
+			transport = Arc::new(MiddlewareStack::new(transport, self.middleware));
+		}
+		if let Some(cache) = self.cache {
+			transport = Arc::new(ResponseCache::new(transport, cache));
+		}
+		if self.rate_limit.is_some() || self.max_in_flight_requests.is_some() {
+			transport =
+				Arc::new(Limited::new(transport, self.rate_limit, self.max_in_flight_requests));
+		}
 
 		let request_settings = self
 			.request_settings
@@ -109,9 +214,10 @@ impl<V> ClientBuilder<V> {
 
 		let data = super::ClientData {
 			base_url,
-			client,
+			transport,
 			request_settings: Mutex::new(request_settings),
 			auth_callback: Mutex::new(self.auth_callback),
+			post_search_threshold: self.post_search_threshold,
 		};
 		Ok(Client::from(data))
 	}
@@ -122,7 +228,12 @@ impl<V> Clone for ClientBuilder<V> {
 		Self {
 			base_url: self.base_url.clone(),
 			user_agent: self.user_agent.clone(),
-			client: self.client.clone(),
+			transport: self.transport.clone(),
+			middleware: self.middleware.clone(),
+			rate_limit: self.rate_limit,
+			max_in_flight_requests: self.max_in_flight_requests,
+			cache: self.cache.clone(),
+			post_search_threshold: self.post_search_threshold,
 			request_settings: self.request_settings.clone(),
 			auth_callback: self.auth_callback.clone(),
 			version: self.version,
@@ -135,7 +246,12 @@ impl<V> std::fmt::Debug for ClientBuilder<V> {
 		f.debug_struct("ClientBuilder")
 			.field("base_url", &self.base_url)
 			.field("user_agent", &self.user_agent)
-			.field("client", &self.client)
+			.field("transport", &self.transport)
+			.field("middleware", &self.middleware)
+			.field("rate_limit", &self.rate_limit)
+			.field("max_in_flight_requests", &self.max_in_flight_requests)
+			.field("cache", &self.cache)
+			.field("post_search_threshold", &self.post_search_threshold)
 			.field("request_settings", &self.request_settings)
 			.field("auth_callback", &self.auth_callback.as_ref().map(|_| "<fn>"))
 			.finish()
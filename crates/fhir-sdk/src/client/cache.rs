@@ -0,0 +1,217 @@
+//! HTTP response cache of the client.
+//!
+//! The cache is enabled via
+//! [`ClientBuilder::cache`](super::ClientBuilder::cache) and stores the
+//! responses of `GET` requests that have an `ETag` or `Last-Modified` header.
+//! Cached responses are always revalidated with the server using
+//! `If-None-Match` and `If-Modified-Since`, and `304 Not Modified` responses
+//! are answered from the cache.
+
+use std::{
+	collections::HashMap,
+	sync::Mutex,
+	time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use reqwest::{header, Method, StatusCode};
+
+use super::{Error, HttpRequest, HttpResponse, Transport};
+
+/// Settings of the response cache.
+///
+/// By default, up to 1000 responses are cached for at most one hour.
+#[derive(Debug, Clone)]
+pub struct CacheSettings {
+	/// Maximum number of cached responses.
+	max_entries: usize,
+	/// Maximum time to keep responses in the cache.
+	ttl: Option<Duration>,
+}
+
+impl Default for CacheSettings {
+	fn default() -> Self {
+		Self { max_entries: 1000, ttl: Some(Duration::from_secs(3600)) }
+	}
+}
+
+impl CacheSettings {
+	/// Set the maximum number of cached responses. The least recently used
+	/// responses are dropped first.
+	#[must_use]
+	pub fn max_entries(mut self, max_entries: usize) -> Self {
+		self.max_entries = max_entries;
+		self
+	}
+
+	/// Set the maximum time to keep responses in the cache. The time starts
+	/// anew whenever a response is revalidated.
+	#[must_use]
+	pub fn ttl(mut self, ttl: Option<Duration>) -> Self {
+		self.ttl = ttl;
+		self
+	}
+}
+
+/// Cached response.
+#[derive(Debug)]
+struct Entry {
+	/// The cached response.
+	response: HttpResponse,
+	/// Time the response was stored or last revalidated.
+	validated_at: Instant,
+	/// Sequence number of the last use, to find the least recently used entry.
+	last_used: u64,
+}
+
+/// The cached responses.
+#[derive(Debug, Default)]
+struct Entries {
+	/// Cached responses by cache key.
+	entries: HashMap<String, Entry>,
+	/// Sequence number of the last use of any entry.
+	uses: u64,
+}
+
+/// Transport caching the responses of the inner transport.
+#[derive(Debug)]
+pub(crate) struct ResponseCache<T> {
+	/// The transport to send the requests with.
+	transport: T,
+	/// The cache settings.
+	settings: CacheSettings,
+	/// The cached responses.
+	entries: Mutex<Entries>,
+}
+
+impl<T> ResponseCache<T> {
+	/// Wrap the transport in a cache.
+	pub(crate) fn new(transport: T, settings: CacheSettings) -> Self {
+		Self { transport, settings, entries: Mutex::default() }
+	}
+
+	/// Get the cached response for the key if it has not expired.
+	fn get(&self, key: &str) -> Option<HttpResponse> {
+		let mut entries = self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+		let expired = entries.entries.get(key).is_some_and(|entry| {
+			self.settings.ttl.is_some_and(|ttl| entry.validated_at.elapsed() > ttl)
+		});
+		if expired {
+			entries.entries.remove(key);
+			return None;
+		}
+
+		entries.uses += 1;
+		let uses = entries.uses;
+		let entry = entries.entries.get_mut(key)?;
+		entry.last_used = uses;
+		Some(entry.response.clone())
+	}
+
+	/// Store the response, dropping the least recently used response if the
+	/// cache is full.
+	fn store(&self, key: String, response: HttpResponse) {
+		if self.settings.max_entries == 0 {
+			return;
+		}
+
+		let mut entries = self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+		if !entries.entries.contains_key(&key) && entries.entries.len() >= self.settings.max_entries
+		{
+			let least_recently_used = entries
+				.entries
+				.iter()
+				.min_by_key(|(_, entry)| entry.last_used)
+				.map(|(key, _)| key.clone());
+			if let Some(least_recently_used) = least_recently_used {
+				entries.entries.remove(&least_recently_used);
+			}
+		}
+
+		entries.uses += 1;
+		let entry = Entry { response, validated_at: Instant::now(), last_used: entries.uses };
+		entries.entries.insert(key, entry);
+	}
+
+	/// Drop the cached response.
+	fn remove(&self, key: &str) {
+		let mut entries = self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+		entries.entries.remove(key);
+	}
+}
+
+/// Get the cache key of the request, consisting of the URL and all headers.
+/// Returns `None` for requests that are not to be cached, i.e. non-`GET`
+/// requests and requests that are conditional already.
+fn cache_key(request: &HttpRequest) -> Option<String> {
+	if request.method != Method::GET
+		|| request.headers.contains_key(header::IF_NONE_MATCH)
+		|| request.headers.contains_key(header::IF_MODIFIED_SINCE)
+	{
+		return None;
+	}
+
+	let mut headers: Vec<String> = request
+		.headers
+		.iter()
+		.map(|(name, value)| format!("{name}: {}", String::from_utf8_lossy(value.as_bytes())))
+		.collect();
+	headers.sort_unstable();
+	Some(format!("{}\n{}", request.url, headers.join("\n")))
+}
+
+/// Whether the response can be cached, i.e. is successful, has validators and
+/// does not forbid storing it.
+fn is_cacheable(response: &HttpResponse) -> bool {
+	let no_store = response
+		.headers
+		.get_all(header::CACHE_CONTROL)
+		.iter()
+		.filter_map(|value| value.to_str().ok())
+		.any(|value| value.to_ascii_lowercase().contains("no-store"));
+	response.status == StatusCode::OK
+		&& !no_store
+		&& (response.headers.contains_key(header::ETAG)
+			|| response.headers.contains_key(header::LAST_MODIFIED))
+}
+
+#[async_trait]
+impl<T: Transport> Transport for ResponseCache<T> {
+	async fn send(&self, mut request: HttpRequest) -> Result<HttpResponse, Error> {
+		let Some(key) = cache_key(&request) else {
+			return self.transport.send(request).await;
+		};
+
+		let cached = self.get(&key);
+		if let Some(cached) = &cached {
+			if let Some(etag) = cached.headers.get(header::ETAG) {
+				request.headers.insert(header::IF_NONE_MATCH, etag.clone());
+			}
+			if let Some(last_modified) = cached.headers.get(header::LAST_MODIFIED) {
+				request.headers.insert(header::IF_MODIFIED_SINCE, last_modified.clone());
+			}
+		}
+
+		let response = self.transport.send(request).await?;
+		match cached {
+			Some(cached) if response.status == StatusCode::NOT_MODIFIED => {
+				tracing::debug!("Response was not modified, using cached response");
+				self.store(key, cached.clone());
+				Ok(cached)
+			}
+			_ if is_cacheable(&response) => {
+				self.store(key, response.clone());
+				Ok(response)
+			}
+			_ => {
+				// Keep the cached response on temporary failures.
+				if [StatusCode::OK, StatusCode::NOT_FOUND, StatusCode::GONE]
+					.contains(&response.status)
+				{
+					self.remove(&key);
+				}
+				Ok(response)
+			}
+		}
+	}
+}
@@ -18,6 +18,10 @@ pub enum Error {
 	#[error("Builder is missing field `{0}` to construct the client")]
 	BuilderMissingField(&'static str),
 
+	/// Builder field has an invalid value.
+	#[error("Builder field `{0}` has an invalid value")]
+	BuilderInvalidField(&'static str),
+
 	/// URL cannot be a base URL.
 	#[error("Given base URL cannot be a base URL")]
 	UrlCannotBeBase,
@@ -54,6 +58,11 @@ pub enum Error {
 	#[error("Authorization callback error: {0}")]
 	AuthCallback(String),
 
+	/// Error of an [`AuthProvider`](super::auth::AuthProvider), e.g. a failed
+	/// token request.
+	#[error("Authentication error: {0}")]
+	Auth(String),
+
 	/// Serialization/Deserialization error.
 	#[error("JSON error: {0}")]
 	Json(Arc<serde_json::Error>),
@@ -62,6 +71,12 @@ pub enum Error {
 	#[error("Request error: {0}")]
 	Request(Arc<reqwest::Error>),
 
+	/// Error of a custom [`Transport`](super::Transport) in sending a request.
+	/// The flag states whether the request should be retried, e.g. on
+	/// connection failures or timeouts.
+	#[error("Transport error: {0}")]
+	Transport(Arc<dyn std::error::Error + Send + Sync>, bool),
+
 	/// HTTP error response.
 	#[error("Got error response ({0}): {1}")]
 	Response(StatusCode, String),
@@ -81,6 +96,12 @@ pub enum Error {
 	#[error("OperationOutcome({0}): {1:?}")]
 	OperationOutcomeStu3(StatusCode, stu3::resources::OperationOutcome),
 
+	/// A precondition of the request failed (`412 Precondition Failed`), e.g.
+	/// the version did not match on conditional update or multiple resources
+	/// matched on conditional interactions. Contains the server's error.
+	#[error("Precondition failed: {0}")]
+	PreconditionFailed(Box<Error>),
+
 	/// Resource was not found.
 	#[error("Resource `{0}` was not found")]
 	ResourceNotFound(String),
@@ -97,9 +118,21 @@ pub enum Error {
 	#[error("Resource type {0} is not the requested type {1}")]
 	WrongResourceType(String, String),
 
+	/// Wrong `Bundle` type was delivered.
+	#[error("Bundle type {0} is not the expected type {1}")]
+	WrongBundleType(String, String),
+
 	/// Unexpected resource type.
 	#[error("Unexpected resource type {0}")]
 	UnexpectedResourceType(String),
+
+	/// The server's FHIR version is not supported or not enabled.
+	#[error("Unsupported FHIR version: {0}")]
+	UnsupportedFhirVersion(String),
+
+	/// Resource of a different FHIR version than the client's.
+	#[error("Client for FHIR version {0} cannot be used with resources of FHIR version {1}")]
+	FhirVersionMismatch(&'static str, &'static str),
 }
 
 impl From<serde_json::Error> for Error {
@@ -115,12 +148,21 @@ impl From<reqwest::Error> for Error {
 }
 
 impl Error {
+	/// Create a [`Error::Transport`] error for custom transports.
+	pub fn transport(
+		error: impl Into<Box<dyn std::error::Error + Send + Sync>>,
+		should_retry: bool,
+	) -> Self {
+		Self::Transport(Arc::from(error.into()), should_retry)
+	}
+
 	/// Whether the error should likely be retried.
 	#[must_use]
 	pub fn should_retry(&self) -> bool {
 		tracing::debug!("Checking if error `{self}` should be retried");
 		match self {
 			Self::Request(err) => err.is_connect() || err.is_request() || err.is_timeout(),
+			Self::Transport(_, should_retry) => *should_retry,
 			_ => false,
 		}
 	}
@@ -0,0 +1,578 @@
+//! Implementation of the FHIR REST interactions, common to all FHIR versions.
+
+use fhir_model::{GenericReference, GenericResource, ParsedReference};
+use reqwest::{
+	header::{self, HeaderName, HeaderValue},
+	StatusCode, Url,
+};
+use serde_json::json;
+
+use super::{
+	misc,
+	modify::Modify,
+	patch::{PatchViaFhir, PatchViaJson},
+	response::{FhirResponse, Response},
+	transaction::BatchTransaction,
+	Client, Error, FhirResource, FhirVersion, HttpRequest, SearchParameters,
+};
+
+/// The `If-None-Exist` header of conditional creates.
+const IF_NONE_EXIST: HeaderName = HeaderName::from_static("if-none-exist");
+
+impl<V: FhirVersion> Client<V> {
+	/// Get the server's capabilities. Fails if the respective FHIR version is
+	/// not supported at all.
+	pub async fn capabilities(&self) -> Result<V::CapabilityStatement, Error> {
+		let url = self.url(&["metadata"]);
+
+		self.fetch_resource(url).await
+	}
+
+	/// Fetch a resource from the URL, failing if it does not exist.
+	pub(super) async fn fetch_resource<R: TryFrom<V::Resource>>(
+		&self,
+		url: Url,
+	) -> Result<R, Error> {
+		let response = self.fetch_url(url).await?;
+
+		response.body().await
+	}
+
+	/// Read any resource from any URL.
+	pub(super) async fn read_generic<R: TryFrom<V::Resource>>(
+		&self,
+		url: Url,
+	) -> Result<Option<R>, Error> {
+		self.read_generic_response(url).await.map(Response::into_value)
+	}
+
+	/// Read any resource from any URL, keeping the response metadata.
+	async fn read_generic_response<R: TryFrom<V::Resource>>(
+		&self,
+		url: Url,
+	) -> Result<Response<V, Option<R>>, Error> {
+		let response = self.fetch_url(url).await?;
+
+		if [StatusCode::NOT_FOUND, StatusCode::GONE].contains(&response.status()) {
+			return Ok(response.into_response(None));
+		}
+
+		response.body_response().await.map(|response| response.map(Some))
+	}
+
+	/// Read the current version of a specific FHIR resource.
+	pub async fn read<R: FhirResource<V>>(&self, id: &str) -> Result<Option<R>, Error> {
+		self.read_with_response(id).await.map(Response::into_value)
+	}
+
+	/// Read the current version of a specific FHIR resource, returning the
+	/// response metadata along with it.
+	pub async fn read_with_response<R: FhirResource<V>>(
+		&self,
+		id: &str,
+	) -> Result<Response<V, Option<R>>, Error> {
+		let resource_type = R::TYPE.to_string();
+		let url = self.url(&[&resource_type, id]);
+		self.read_generic_response(url).await
+	}
+
+	/// Read a specific version of a specific FHIR resource.
+	pub async fn read_version<R: FhirResource<V>>(
+		&self,
+		id: &str,
+		version_id: &str,
+	) -> Result<Option<R>, Error> {
+		self.read_version_with_response(id, version_id).await.map(Response::into_value)
+	}
+
+	/// Read a specific version of a specific FHIR resource, returning the
+	/// response metadata along with it.
+	pub async fn read_version_with_response<R: FhirResource<V>>(
+		&self,
+		id: &str,
+		version_id: &str,
+	) -> Result<Response<V, Option<R>>, Error> {
+		let resource_type = R::TYPE.to_string();
+		let url = self.url(&[&resource_type, id, "_history", version_id]);
+		self.read_generic_response(url).await
+	}
+
+	/// Read the current version of a specific FHIR resource if it was modified
+	/// since the given version, using a conditional read with
+	/// `If-None-Match`.
+	pub async fn read_if_modified<R: FhirResource<V>>(
+		&self,
+		id: &str,
+		version_id: &str,
+	) -> Result<ReadIfModified<R>, Error> {
+		let resource_type = R::TYPE.to_string();
+		let url = self.url(&[&resource_type, id]);
+		let if_none_match = HeaderValue::from_str(&format!("W/\"{version_id}\""))
+			.map_err(|_| Error::MissingVersionId)?;
+		let request = HttpRequest::get(url)
+			.header(header::ACCEPT, HeaderValue::from_static(V::JSON_MIME_TYPE))
+			.header(header::IF_NONE_MATCH, if_none_match);
+
+		let response = self.run_request(request).await?;
+		match response.status() {
+			StatusCode::NOT_MODIFIED => Ok(ReadIfModified::NotModified),
+			StatusCode::NOT_FOUND | StatusCode::GONE => Ok(ReadIfModified::NotFound),
+			_ => response.body().await.map(ReadIfModified::Modified),
+		}
+	}
+
+	/// Read the resource that is targeted in the reference.
+	pub async fn read_referenced(&self, reference: &V::Reference) -> Result<V::Resource, Error> {
+		let parsed_reference = reference.parse().ok_or(Error::MissingReference)?;
+
+		let absolute: String = match parsed_reference {
+			ParsedReference::Local { .. } => return Err(Error::LocalReference),
+			ParsedReference::Relative { .. } => {
+				parsed_reference.with_base_url(self.0.base_url.as_str()).to_string()
+			}
+			absolute => absolute.to_string(),
+		};
+
+		let url: Url = absolute.parse().map_err(|_| Error::UrlParse(absolute))?;
+
+		let resource: V::Resource = self
+			.read_generic(url.clone())
+			.await?
+			.ok_or_else(|| Error::ResourceNotFound(url.to_string()))?;
+		if let Some(expected_type) = reference.target_type() {
+			let resource_type = resource.resource_type().to_string();
+			if resource_type != expected_type {
+				return Err(Error::WrongResourceType(resource_type, expected_type.to_owned()));
+			}
+		}
+
+		Ok(resource)
+	}
+
+	/// Create a new FHIR resource on the FHIR server. Returns the resource ID
+	/// and version ID.
+	pub async fn create<R: FhirResource<V>>(
+		&self,
+		resource: &R,
+	) -> Result<(String, Option<String>), Error> {
+		let response = self.create_with_response(resource).await?;
+		let version_id = response.version_id();
+		Ok((response.into_value(), version_id))
+	}
+
+	/// Create a new FHIR resource on the FHIR server. Returns the resource ID
+	/// along with the response metadata.
+	pub async fn create_with_response<R: FhirResource<V>>(
+		&self,
+		resource: &R,
+	) -> Result<Response<V, String>, Error> {
+		let response = self.send_create(resource, None).await?.successful_response().await?;
+		let (id, _) = misc::parse_location(response.headers())?;
+		Ok(response.map(|()| id))
+	}
+
+	/// Create a new FHIR resource on the FHIR server, asking the server to
+	/// return the given content via the `Prefer` header. Returns the stored
+	/// resource if the server returned it, along with the response metadata.
+	/// With [`PreferReturn::OperationOutcome`], the outcome is available via
+	/// [`Response::operation_outcome`].
+	pub async fn create_returning<R: FhirResource<V>>(
+		&self,
+		resource: &R,
+		prefer: PreferReturn,
+	) -> Result<Response<V, Option<R>>, Error> {
+		self.send_create(resource, Some(prefer)).await?.returned_resource().await
+	}
+
+	/// Create a new FHIR resource on the FHIR server only if no resource
+	/// matches the search parameters, using a conditional create with
+	/// `If-None-Exist`. Returns whether the resource was created or already
+	/// existed, with the ID of the created or matching resource. Fails if
+	/// multiple resources match.
+	pub async fn create_if_none_exist<R: FhirResource<V>>(
+		&self,
+		resource: &R,
+		params: SearchParameters<R>,
+	) -> Result<CreateIfNoneExist, Error> {
+		let query = form_urlencoded::Serializer::new(String::new())
+			.extend_pairs(params.into_queries())
+			.finish();
+		let if_none_exist =
+			HeaderValue::from_str(&query).map_err(|_| Error::UrlParse(query.clone()))?;
+		let request = self.create_request(resource)?.header(IF_NONE_EXIST, if_none_exist);
+
+		let response = self.run_request(request).await?.returned_resource::<R>().await?;
+		let id = match misc::parse_location(response.headers()) {
+			Ok((id, _)) => id,
+			// The matching resource might only be returned in the body.
+			Err(err) => response
+				.value()
+				.as_ref()
+				.and_then(|resource| resource.resource_id())
+				.map(ToOwned::to_owned)
+				.ok_or(err)?,
+		};
+
+		if response.status() == StatusCode::CREATED {
+			Ok(CreateIfNoneExist::Created(id))
+		} else {
+			Ok(CreateIfNoneExist::Exists(id))
+		}
+	}
+
+	/// Send the create request for the resource.
+	async fn send_create<R: FhirResource<V>>(
+		&self,
+		resource: &R,
+		prefer: Option<PreferReturn>,
+	) -> Result<FhirResponse<V>, Error> {
+		let request = self.create_request(resource)?;
+		self.run_request(PreferReturn::apply(prefer, request)).await
+	}
+
+	/// Build the create request for the resource.
+	fn create_request<R: FhirResource<V>>(&self, resource: &R) -> Result<HttpRequest, Error> {
+		let resource_type = R::TYPE.to_string();
+		let url = self.url(&[&resource_type]);
+		HttpRequest::post(url)
+			.header(header::ACCEPT, HeaderValue::from_static(V::JSON_MIME_TYPE))
+			.header(header::CONTENT_TYPE, HeaderValue::from_static(V::JSON_MIME_TYPE))
+			.json(resource)
+	}
+
+	/// Update a FHIR resource (or create it if it did not
+	/// exist). If conditional update is selected, the resource is only updated
+	/// if the version ID matches the expectations.
+	pub async fn update<R: FhirResource<V>>(
+		&self,
+		resource: &R,
+		conditional: bool,
+	) -> Result<(bool, String), Error> {
+		let response = self.update_with_response(resource, conditional).await?;
+		let version_id = misc::parse_etag(response.headers())?;
+		Ok((response.into_value(), version_id))
+	}
+
+	/// Update a FHIR resource (or create it if it did not exist), returning
+	/// whether the resource was created along with the response metadata.
+	/// If conditional update is selected, the resource is only updated if the
+	/// version ID matches the expectations.
+	pub async fn update_with_response<R: FhirResource<V>>(
+		&self,
+		resource: &R,
+		conditional: bool,
+	) -> Result<Response<V, bool>, Error> {
+		let response =
+			self.send_update(resource, conditional, None).await?.successful_response().await?;
+		let created = response.status() == StatusCode::CREATED;
+		Ok(response.map(|()| created))
+	}
+
+	/// Update a FHIR resource (or create it if it did not exist), asking the
+	/// server to return the given content via the `Prefer` header. Returns the
+	/// stored resource if the server returned it, along with the response
+	/// metadata. If conditional update is selected, the resource is only
+	/// updated if the version ID matches the expectations.
+	pub async fn update_returning<R: FhirResource<V>>(
+		&self,
+		resource: &R,
+		conditional: bool,
+		prefer: PreferReturn,
+	) -> Result<Response<V, Option<R>>, Error> {
+		self.send_update(resource, conditional, Some(prefer)).await?.returned_resource().await
+	}
+
+	/// Update a FHIR resource (or create it if nothing matches) selected by
+	/// the search parameters instead of its ID, using a conditional update.
+	/// Returns whether the resource was created or updated, with its ID.
+	/// Fails with [`Error::PreconditionFailed`] if multiple resources match.
+	pub async fn update_where<R: FhirResource<V>>(
+		&self,
+		resource: &R,
+		params: SearchParameters<R>,
+	) -> Result<ConditionalUpdate, Error> {
+		let resource_type = R::TYPE.to_string();
+		let mut url = self.url(&[&resource_type]);
+		url.query_pairs_mut().extend_pairs(params.into_queries()).finish();
+		let request = HttpRequest::put(url)
+			.header(header::ACCEPT, HeaderValue::from_static(V::JSON_MIME_TYPE))
+			.header(header::CONTENT_TYPE, HeaderValue::from_static(V::JSON_MIME_TYPE))
+			.json(resource)?;
+
+		let response = self.run_request(request).await?.returned_resource::<R>().await?;
+		let id = match misc::parse_location(response.headers()) {
+			Ok((id, _)) => id,
+			Err(err) => response
+				.value()
+				.as_ref()
+				.and_then(|resource| resource.resource_id())
+				.or(resource.resource_id())
+				.map(ToOwned::to_owned)
+				.ok_or(err)?,
+		};
+
+		if response.status() == StatusCode::CREATED {
+			Ok(ConditionalUpdate::Created(id))
+		} else {
+			Ok(ConditionalUpdate::Updated(id))
+		}
+	}
+
+	/// Send the update request for the resource.
+	async fn send_update<R: FhirResource<V>>(
+		&self,
+		resource: &R,
+		conditional: bool,
+		prefer: Option<PreferReturn>,
+	) -> Result<FhirResponse<V>, Error> {
+		let id = resource.resource_id().ok_or(Error::MissingId)?;
+
+		let resource_type = R::TYPE.to_string();
+		let url = self.url(&[&resource_type, id]);
+		let mut request = HttpRequest::put(url)
+			.header(header::ACCEPT, HeaderValue::from_static(V::JSON_MIME_TYPE))
+			.header(header::CONTENT_TYPE, HeaderValue::from_static(V::JSON_MIME_TYPE))
+			.json(resource)?;
+		if conditional {
+			let version_id = resource.resource_version_id().ok_or(Error::MissingVersionId)?;
+			let if_match = HeaderValue::from_str(&format!("W/\"{version_id}\""))
+				.map_err(|_| Error::MissingVersionId)?;
+			request = request.header(header::IF_MATCH, if_match);
+		}
+
+		self.run_request(PreferReturn::apply(prefer, request)).await
+	}
+
+	/// Begin building a modification of a FHIR resource on the server using
+	/// optimistic concurrency: The current version is read, the modification
+	/// is applied and the resource is updated conditionally on its version ID,
+	/// retrying on version conflicts.
+	pub fn modify<'a, R, F>(&self, id: &'a str, modification: F) -> Modify<'a, V, R, F>
+	where
+		R: FhirResource<V>,
+		F: FnMut(&mut R) + Send,
+	{
+		Modify::new(self.clone(), id, modification)
+	}
+
+	/// Begin building a patch request for a FHIR resource on the server via the
+	/// `FHIRPath Patch` method.
+	pub fn patch_via_fhir<'a>(
+		&self,
+		resource_type: V::ResourceType,
+		id: &'a str,
+	) -> PatchViaFhir<'a, V> {
+		PatchViaFhir::new(self.clone(), resource_type, id)
+	}
+
+	/// Begin building a patch request for a FHIR resource on the server via the
+	/// [`JSON Patch`](https://datatracker.ietf.org/doc/html/rfc6902) method.
+	pub fn patch_via_json<'a>(
+		&self,
+		resource_type: V::ResourceType,
+		id: &'a str,
+	) -> PatchViaJson<'a, V> {
+		PatchViaJson::new(self.clone(), resource_type, id)
+	}
+
+	/// Delete a FHIR resource on the server.
+	pub async fn delete(&self, resource_type: V::ResourceType, id: &str) -> Result<(), Error> {
+		self.delete_with_response(resource_type, id).await.map(Response::into_value)
+	}
+
+	/// Delete a FHIR resource on the server, returning the response metadata.
+	pub async fn delete_with_response(
+		&self,
+		resource_type: V::ResourceType,
+		id: &str,
+	) -> Result<Response<V, ()>, Error> {
+		let resource_type = resource_type.to_string();
+		let url = self.url(&[&resource_type, id]);
+		let request = HttpRequest::delete(url)
+			.header(header::ACCEPT, HeaderValue::from_static(V::JSON_MIME_TYPE));
+
+		let response = self.run_request(request).await?;
+
+		response.successful_response().await
+	}
+
+	/// Delete the FHIR resources matching the search parameters, using a
+	/// conditional delete. Depending on the server, all matching resources
+	/// are deleted or the request fails with [`Error::PreconditionFailed`] if
+	/// multiple resources match.
+	pub async fn delete_where<R: FhirResource<V>>(
+		&self,
+		params: SearchParameters<R>,
+	) -> Result<ConditionalDelete, Error> {
+		let resource_type = R::TYPE.to_string();
+		let mut url = self.url(&[&resource_type]);
+		url.query_pairs_mut().extend_pairs(params.into_queries()).finish();
+		let request = HttpRequest::delete(url)
+			.header(header::ACCEPT, HeaderValue::from_static(V::JSON_MIME_TYPE));
+
+		let response = self.run_request(request).await?;
+		if response.status() == StatusCode::NOT_FOUND {
+			return Ok(ConditionalDelete::NotFound);
+		}
+
+		let response = response.successful_response().await?;
+		let deleted = response.operation_outcome().and_then(|outcome| {
+			V::operation_outcome_diagnostics(outcome).into_iter().find_map(misc::parse_count)
+		});
+		Ok(ConditionalDelete::Deleted(deleted))
+	}
+
+	/// Start building a new batch request.
+	pub fn batch(&self) -> BatchTransaction<V> {
+		BatchTransaction::new(self.clone(), false)
+	}
+
+	/// Start building a new transaction request.
+	pub fn transaction(&self) -> BatchTransaction<V> {
+		BatchTransaction::new(self.clone(), true)
+	}
+
+	/// Operation `$everything` on `Encounter`, returning a Bundle with all
+	/// resources for an `Encounter` record.
+	pub async fn operation_encounter_everything(&self, id: &str) -> Result<V::Bundle, Error> {
+		let url = self.url(&["Encounter", id, "$everything"]);
+
+		self.fetch_resource(url).await
+	}
+
+	/// Operation `$everything` on `Patient`, returning a Bundle with all
+	/// resources for an `Patient` record.
+	pub async fn operation_patient_everything(&self, id: &str) -> Result<V::Bundle, Error> {
+		let url = self.url(&["Patient", id, "$everything"]);
+
+		self.fetch_resource(url).await
+	}
+
+	/// Operation `$match` on `Patient`, returning matches for Patient records
+	/// based on a given incomplete Patient resource.
+	pub async fn operation_patient_match(
+		&self,
+		patient: V::Patient,
+		only_certain: bool,
+		count: i32,
+	) -> Result<V::Bundle, Error> {
+		let parameters = json!({
+			"resourceType": "Parameters",
+			"parameter": [
+				{ "name": "resource", "resource": serde_json::to_value(patient)? },
+				{ "name": "onlyCertainMatches", "valueBoolean": only_certain },
+				{ "name": "count", "valueInteger": count },
+			],
+		});
+
+		let url = self.url(&["Patient", "$match"]);
+		let request = HttpRequest::post(url)
+			.header(header::ACCEPT, HeaderValue::from_static(V::JSON_MIME_TYPE))
+			.header(header::CONTENT_TYPE, HeaderValue::from_static(V::JSON_MIME_TYPE))
+			.json(&parameters)?;
+
+		let response = self.run_request(request).await?;
+
+		response.body().await
+	}
+}
+
+/// Content the server is asked to return on writes, via the
+/// `Prefer: return=` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreferReturn {
+	/// Return no body (`return=minimal`).
+	Minimal,
+	/// Return the stored resource (`return=representation`).
+	Representation,
+	/// Return an `OperationOutcome` (`return=OperationOutcome`).
+	OperationOutcome,
+}
+
+impl PreferReturn {
+	/// The `Prefer` header.
+	const HEADER: HeaderName = HeaderName::from_static("prefer");
+
+	/// The value of the `Prefer` header.
+	fn header_value(self) -> HeaderValue {
+		HeaderValue::from_static(match self {
+			Self::Minimal => "return=minimal",
+			Self::Representation => "return=representation",
+			Self::OperationOutcome => "return=OperationOutcome",
+		})
+	}
+
+	/// Set the `Prefer` header on the request, if any preference is given.
+	pub(super) fn apply(prefer: Option<Self>, request: HttpRequest) -> HttpRequest {
+		match prefer {
+			Some(prefer) => request.header(Self::HEADER, prefer.header_value()),
+			None => request,
+		}
+	}
+}
+
+/// Result of [`Client::create_if_none_exist`], containing the resource ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CreateIfNoneExist {
+	/// The resource was created.
+	Created(String),
+	/// A matching resource existed already, so nothing was created.
+	Exists(String),
+}
+
+impl CreateIfNoneExist {
+	/// The ID of the created or matching resource.
+	#[must_use]
+	pub fn id(&self) -> &str {
+		match self {
+			Self::Created(id) | Self::Exists(id) => id,
+		}
+	}
+
+	/// Whether the resource was created.
+	#[must_use]
+	pub fn created(&self) -> bool {
+		matches!(self, Self::Created(_))
+	}
+}
+
+/// Result of [`Client::update_where`], containing the resource ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConditionalUpdate {
+	/// No resource matched, so the resource was created.
+	Created(String),
+	/// The matching resource was updated.
+	Updated(String),
+}
+
+impl ConditionalUpdate {
+	/// The ID of the created or updated resource.
+	#[must_use]
+	pub fn id(&self) -> &str {
+		match self {
+			Self::Created(id) | Self::Updated(id) => id,
+		}
+	}
+}
+
+/// Result of [`Client::delete_where`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConditionalDelete {
+	/// No resource matched. Not all servers report this, others return
+	/// [`ConditionalDelete::Deleted`] without count instead.
+	NotFound,
+	/// The matching resources were deleted. Contains the number of deleted
+	/// resources if the server reported it in a returned `OperationOutcome`.
+	Deleted(Option<usize>),
+}
+
+/// Result of [`Client::read_if_modified`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReadIfModified<R> {
+	/// The resource was not modified since the given version.
+	NotModified,
+	/// The resource was modified, containing its current version.
+	Modified(R),
+	/// The resource does not exist (anymore).
+	NotFound,
+}
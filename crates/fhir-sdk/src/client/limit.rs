@@ -0,0 +1,100 @@
+//! Client-side rate and concurrency limiting.
+//!
+//! Limits are set via
+//! [`ClientBuilder::rate_limit`](super::ClientBuilder::rate_limit) and
+//! [`ClientBuilder::max_in_flight_requests`](super::ClientBuilder::max_in_flight_requests).
+//! They wrap the transport, including all middleware, so they are shared by
+//! all clones of the client and apply to every request attempt, including
+//! retries and page fetches.
+
+use std::{
+	sync::Mutex,
+	time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use tokio::sync::Semaphore;
+
+use super::{Error, HttpRequest, HttpResponse, Transport};
+
+/// Token bucket, refilling at a constant rate up to the burst size.
+#[derive(Debug)]
+struct TokenBucket {
+	/// Tokens added per second.
+	per_second: f64,
+	/// Maximum number of tokens.
+	burst: f64,
+	/// Available tokens and the time of their last update. Negative when
+	/// tokens are reserved by waiting requests.
+	tokens: Mutex<(f64, Instant)>,
+}
+
+impl TokenBucket {
+	/// Create a new full token bucket.
+	fn new(per_second: f64, burst: u32) -> Self {
+		let burst = f64::from(burst);
+		Self { per_second, burst, tokens: Mutex::new((burst, Instant::now())) }
+	}
+
+	/// Take a token, waiting until one is available. Tokens are reserved
+	/// immediately, so waiting requests are served in order.
+	async fn acquire(&self) {
+		let wait = {
+			let mut tokens = self.tokens.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+			let (available, updated_at) = *tokens;
+			let now = Instant::now();
+			let refilled = now.duration_since(updated_at).as_secs_f64() * self.per_second;
+			let available = (available + refilled).min(self.burst) - 1.0;
+			*tokens = (available, now);
+			(available < 0.0).then(|| Duration::from_secs_f64(-available / self.per_second))
+		};
+
+		if let Some(wait) = wait {
+			tracing::debug!("Rate limit reached, waiting {wait:?}");
+			tokio::time::sleep(wait).await;
+		}
+	}
+}
+
+/// Transport applying the rate and concurrency limits to the inner transport.
+#[derive(Debug)]
+pub(crate) struct Limited<T> {
+	/// The transport to send the requests with.
+	transport: T,
+	/// The rate limit.
+	rate: Option<TokenBucket>,
+	/// The concurrency limit.
+	in_flight: Option<Semaphore>,
+}
+
+impl<T> Limited<T> {
+	/// Wrap the transport in the limits. The rate limit is given as requests
+	/// per second and burst size.
+	pub(crate) fn new(
+		transport: T,
+		rate: Option<(f64, u32)>,
+		max_in_flight: Option<usize>,
+	) -> Self {
+		Self {
+			transport,
+			rate: rate.map(|(per_second, burst)| TokenBucket::new(per_second, burst)),
+			in_flight: max_in_flight.map(Semaphore::new),
+		}
+	}
+}
+
+#[async_trait]
+impl<T: Transport> Transport for Limited<T> {
+	async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+		// The semaphore is never closed, so acquiring cannot fail.
+		let _permit = match &self.in_flight {
+			Some(semaphore) => semaphore.acquire().await.ok(),
+			None => None,
+		};
+		if let Some(rate) = &self.rate {
+			rate.acquire().await;
+		}
+
+		self.transport.send(request).await
+	}
+}
@@ -0,0 +1,80 @@
+//! Request/response middleware of the client.
+//!
+//! Middleware layers are set via
+//! [`ClientBuilder::middleware`](super::ClientBuilder::middleware) and wrap
+//! the [`Transport`], so every request the client sends passes through them,
+//! including page fetches and batches/transactions. Each layer receives the
+//! request with all headers set and can modify it, pass it on to the [`Next`]
+//! layer (even multiple times to retry) or answer it directly.
+//!
+//! Middleware operates at the HTTP level: it sees the raw [`HttpRequest`] and
+//! [`HttpResponse`], before the client parses the response body or maps error
+//! statuses to [`Error`]. Status and headers are available as
+//! [`HttpResponse::status`] and [`HttpResponse::headers`], the FHIR resource
+//! only as serialized bytes in [`HttpResponse::body`].
+
+use std::{fmt::Debug, sync::Arc};
+
+use async_trait::async_trait;
+
+use super::{Error, HttpRequest, HttpResponse, Transport};
+
+/// Middleware layer to inspect or modify HTTP requests and responses, before
+/// the client parses them.
+#[async_trait]
+pub trait Middleware: Debug + Send + Sync + 'static {
+	/// Handle the request. Call [`Next::run`] to pass it on to the next layer
+	/// and eventually the transport, or return a response directly to
+	/// short-circuit.
+	async fn handle(&self, request: HttpRequest, next: Next<'_>) -> Result<HttpResponse, Error>;
+}
+
+/// The remaining middleware layers and the transport to pass a request on to.
+#[derive(Debug, Clone, Copy)]
+pub struct Next<'a> {
+	/// The transport to send the request with in the end.
+	transport: &'a dyn Transport,
+	/// The remaining middleware layers.
+	middleware: &'a [Arc<dyn Middleware>],
+}
+
+impl<'a> Next<'a> {
+	/// Create the chain of the remaining middleware layers and the transport.
+	pub(crate) fn new(transport: &'a dyn Transport, middleware: &'a [Arc<dyn Middleware>]) -> Self {
+		Self { transport, middleware }
+	}
+
+	/// Pass the request on to the next layer and return its response. Can be
+	/// called multiple times, e.g. to retry requests.
+	pub async fn run(self, request: HttpRequest) -> Result<HttpResponse, Error> {
+		if let Some((layer, rest)) = self.middleware.split_first() {
+			let next = Next { transport: self.transport, middleware: rest };
+			layer.handle(request, next).await
+		} else {
+			self.transport.send(request).await
+		}
+	}
+}
+
+/// Transport sending requests through the middleware layers.
+#[derive(Debug)]
+pub(crate) struct MiddlewareStack {
+	/// The transport to send the requests with.
+	transport: Arc<dyn Transport>,
+	/// The middleware layers, outermost first.
+	middleware: Vec<Arc<dyn Middleware>>,
+}
+
+impl MiddlewareStack {
+	/// Wrap the transport in the middleware layers, outermost first.
+	pub(crate) fn new(transport: Arc<dyn Transport>, middleware: Vec<Arc<dyn Middleware>>) -> Self {
+		Self { transport, middleware }
+	}
+}
+
+#[async_trait]
+impl Transport for MiddlewareStack {
+	async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+		Next::new(self.transport.as_ref(), &self.middleware).run(request).await
+	}
+}
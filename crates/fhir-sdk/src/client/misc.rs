@@ -47,6 +47,12 @@ pub fn parse_location(headers: &HeaderMap) -> Result<(String, Option<String>), E
 	}
 }
 
+/// Parse the first number in a message, e.g. the number of deleted resources
+/// in `Successfully deleted 2 resource(s)`.
+pub fn parse_count(message: &str) -> Option<usize> {
+	message.split(|c: char| !c.is_ascii_digit()).find(|number| !number.is_empty())?.parse().ok()
+}
+
 #[cfg(test)]
 mod tests {
 	#![allow(clippy::expect_used)] // Allowed for tests
@@ -94,4 +100,10 @@ mod tests {
 		assert_eq!(id, "123");
 		assert_eq!(version_id.as_deref(), Some("1"));
 	}
+
+	#[test]
+	fn count_parsing() {
+		assert_eq!(parse_count("Successfully deleted 2 resource(s) in 25ms"), Some(2));
+		assert_eq!(parse_count("Nothing deleted"), None);
+	}
 }
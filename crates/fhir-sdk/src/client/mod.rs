@@ -1,19 +1,32 @@
 //! REST Client Implementation.
 //!
-//! Does only work with one FHIR version at a time!
+//! Does only work with one FHIR version at a time! Use [`AnyClient`] if the
+//! server's FHIR version is only known at runtime.
 
+mod any;
+pub mod auth;
 mod builder;
+mod cache;
 mod error;
+mod interactions;
+mod limit;
+mod middleware;
 mod misc;
+mod modify;
+mod patch;
 #[cfg(feature = "r4b")]
 pub mod r4b;
 #[cfg(feature = "r5")]
 pub mod r5;
+mod references;
 mod request;
 mod response;
 mod search;
 #[cfg(feature = "stu3")]
 pub mod stu3;
+mod transaction;
+mod transport;
+mod version;
 mod write;
 
 use std::{
@@ -25,52 +38,44 @@ use std::{
 
 pub use reqwest::{
 	header::{self, HeaderMap, HeaderValue},
-	StatusCode, Url,
+	Method, StatusCode, Url,
 };
 
 use self::response::FhirResponse;
 pub use self::{
+	any::{AnyClient, VersionedResource},
 	builder::ClientBuilder,
+	cache::CacheSettings,
 	error::Error,
+	interactions::{
+		ConditionalDelete, ConditionalUpdate, CreateIfNoneExist, PreferReturn, ReadIfModified,
+	},
+	middleware::{Middleware, Next},
+	modify::Modify,
 	request::RequestSettings,
+	response::Response,
 	search::{
-		ExecutableSearch, OrderedSearch, Paged, Search, SearchExecutor, SearchParameter,
-		SearchParameterOrList, SearchParameters,
+		Compartment, ContainedMode, ContainedType, ExecutableSearch, OrderedSearch, Paged, Search,
+		SearchExecutor, SearchParameter, SearchParameterOrList, SearchParameters, SummaryMode,
+		SystemSearch, TotalMode,
 	},
+	transport::{HttpRequest, HttpResponse, ReqwestTransport, Transport},
+	version::{FhirResource, FhirVersion, SearchMatch},
 	write::ResourceWrite,
 };
 
-/// Trait implemented by the FHIR version markers to provide some version specific
-/// constants
-pub trait FhirVersion {
-	/// MIME type used in Accept and Content-Type headers
-	const JSON_MIME_TYPE: &'static str;
-}
-
 /// FHIR client version to use: FHIR STU3.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct FhirStu3;
 
-impl FhirVersion for FhirStu3 {
-	const JSON_MIME_TYPE: &'static str = "application/fhir+json; fhirVersion=3.0";
-}
-
 /// FHIR client version to use: FHIR R4B.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct FhirR4B;
 
-impl FhirVersion for FhirR4B {
-	const JSON_MIME_TYPE: &'static str = "application/fhir+json; fhirVersion=4.3";
-}
-
 /// FHIR client version to use: FHIR R5.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct FhirR5;
 
-impl FhirVersion for FhirR5 {
-	const JSON_MIME_TYPE: &'static str = "application/fhir+json; fhirVersion=5.0";
-}
-
 #[cfg(feature = "r5")]
 /// Default client version.
 type DefaultVersion = FhirR5;
@@ -83,7 +88,21 @@ type DefaultVersion = FhirStu3;
 
 /// FHIR REST Client.
 #[derive(Debug)]
-pub struct Client<Version = DefaultVersion>(Arc<ClientData>, PhantomData<Version>);
+pub struct Client<Version = DefaultVersion>(
+	Arc<ClientData>,
+	Option<SettingsOverride>,
+	PhantomData<Version>,
+);
+
+/// Override of the request settings for the requests of a client handle.
+#[derive(Clone)]
+struct SettingsOverride(Arc<dyn Fn(RequestSettings) -> RequestSettings + Send + Sync>);
+
+impl std::fmt::Debug for SettingsOverride {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str("<fn>")
+	}
+}
 
 /// Return type of the auth callback.
 type AuthCallbackReturn = Result<HeaderValue, Box<dyn std::error::Error + Send + Sync>>;
@@ -95,17 +114,19 @@ type AuthCallback =
 struct ClientData {
 	/// The FHIR server's base URL.
 	base_url: Url,
-	/// HTTP request client.
-	client: reqwest::Client,
+	/// HTTP transport to send the requests with.
+	transport: Arc<dyn Transport>,
 	/// Request settings.
 	request_settings: Mutex<RequestSettings>,
 	/// Authorization callback method, returning the authorization header value.
 	auth_callback: Mutex<Option<AuthCallback>>,
+	/// URL length above which searches are sent via `POST`.
+	post_search_threshold: Option<usize>,
 }
 
 impl<V> From<ClientData> for Client<V> {
 	fn from(data: ClientData) -> Self {
-		Self(Arc::new(data), PhantomData)
+		Self(Arc::new(data), None, PhantomData)
 	}
 }
 
@@ -137,6 +158,34 @@ impl<V: FhirVersion + Send + Sync> Client<V> {
 		*request_settings = settings;
 	}
 
+	/// Get a handle to this client that overrides the request settings for
+	/// its requests, e.g. to set a longer timeout, different retries or
+	/// additional headers for a single call. The override is applied on top
+	/// of the client's request settings for every request made via the
+	/// handle, including searches, patches and transactions started from it.
+	/// The shared settings are not changed.
+	#[must_use]
+	pub fn with_settings<F>(&self, settings_override: F) -> Self
+	where
+		F: Fn(RequestSettings) -> RequestSettings + Send + Sync + 'static,
+	{
+		let settings_override = match self.1.clone() {
+			Some(previous) => SettingsOverride(Arc::new(move |settings| {
+				settings_override((previous.0)(settings))
+			})),
+			None => SettingsOverride(Arc::new(settings_override)),
+		};
+		Self(self.0.clone(), Some(settings_override), PhantomData)
+	}
+
+	/// Get the request settings to use for this handle's requests.
+	fn effective_settings(&self, settings: RequestSettings) -> RequestSettings {
+		match &self.1 {
+			Some(settings_override) => (settings_override.0)(settings),
+			None => settings,
+		}
+	}
+
 	/// Get the auth callback configured in this client.
 	#[must_use]
 	pub fn auth_callback(&self) -> Option<AuthCallback> {
@@ -146,7 +195,7 @@ impl<V: FhirVersion + Send + Sync> Client<V> {
 
 	/// Convert to a different version.
 	fn convert_version<Version>(self) -> Client<Version> {
-		Client(self.0, PhantomData)
+		Client(self.0, self.1, PhantomData)
 	}
 
 	/// Switch the client to STU3 mode.
@@ -170,14 +219,13 @@ impl<V: FhirVersion + Send + Sync> Client<V> {
 	/// Run a request using the internal request settings, calling the auth
 	/// callback to retrieve a new Authorization header on `unauthtorized`
 	/// responses.
-	async fn run_request(
-		&self,
-		request: reqwest::RequestBuilder,
-	) -> Result<FhirResponse<V>, Error> {
+	async fn run_request(&self, request: HttpRequest) -> Result<FhirResponse<V>, Error> {
 		// Try running the request
 		let mut request_settings = self.request_settings();
-		let response = request_settings
-			.make_request(request.try_clone().ok_or(Error::RequestNotClone)?)
+		let transport = self.0.transport.as_ref();
+		let response = self
+			.effective_settings(request_settings.clone())
+			.make_request(transport, request.clone())
 			.await?;
 
 		let base_url = self.0.base_url.clone();
@@ -191,7 +239,10 @@ impl<V: FhirVersion + Send + Sync> Client<V> {
 					.map_err(|err| Error::AuthCallback(format!("{err:#}")))?;
 				request_settings = request_settings.header(header::AUTHORIZATION, auth_value);
 				self.set_request_settings(request_settings.clone());
-				let response = request_settings.make_request(request).await?;
+				let response = self
+					.effective_settings(request_settings)
+					.make_request(transport, request)
+					.await?;
 
 				return Ok(FhirResponse::new(base_url, response));
 			}
@@ -208,7 +259,8 @@ impl<V: FhirVersion + Send + Sync> Client<V> {
 			return Err(Error::DifferentOrigin(url.to_string()));
 		}
 
-		let request = self.0.client.get(url).header(header::ACCEPT, V::JSON_MIME_TYPE);
+		let request = HttpRequest::get(url)
+			.header(header::ACCEPT, HeaderValue::from_static(V::JSON_MIME_TYPE));
 
 		self.run_request(request).await
 	}
@@ -224,7 +276,7 @@ impl<V: FhirVersion + Send + Sync> Client<V> {
 
 impl<V> Clone for Client<V> {
 	fn clone(&self) -> Self {
-		Self(self.0.clone(), self.1)
+		Self(self.0.clone(), self.1.clone(), PhantomData)
 	}
 }
 
@@ -243,9 +295,10 @@ impl std::fmt::Debug for ClientData {
 
 		f.debug_struct("ClientData")
 			.field("base_url", &self.base_url)
-			.field("client", &self.client)
+			.field("transport", &self.transport)
 			.field("request_settings", &self.request_settings)
 			.field("auth_callback", &auth_callback)
+			.field("post_search_threshold", &self.post_search_threshold)
 			.finish()
 	}
 }
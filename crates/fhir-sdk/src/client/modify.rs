@@ -0,0 +1,82 @@
+//! Optimistic-concurrency modification of resources.
+
+use std::marker::PhantomData;
+
+use super::{Client, Error, FhirResource, FhirVersion, ResourceWrite};
+
+/// Builder for modifying a resource on the FHIR server using optimistic
+/// concurrency: The current version of the resource is read, the modification
+/// is applied and the resource is updated conditionally on its version ID.
+/// On version conflicts, i.e. [`Error::PreconditionFailed`], this is retried
+/// with the then current version.
+#[must_use = "You probably want to send the modification"]
+pub struct Modify<'a, V: FhirVersion, R, F> {
+	/// FHIR client.
+	client: Client<V>,
+	/// Resource ID of the resource to modify.
+	id: &'a str,
+	/// The modification to apply to the resource.
+	modification: F,
+	/// Maximum number of retries on version conflicts.
+	max_retries: u32,
+	/// The resource type to modify.
+	resource_type: PhantomData<R>,
+}
+
+impl<'a, V, R, F> Modify<'a, V, R, F>
+where
+	V: FhirVersion,
+	R: FhirResource<V>,
+	F: FnMut(&mut R) + Send,
+{
+	/// Start building a new modification, retrying up to 3 times on version
+	/// conflicts by default.
+	pub fn new(client: Client<V>, id: &'a str, modification: F) -> Self {
+		Self { client, id, modification, max_retries: 3, resource_type: PhantomData }
+	}
+
+	/// Set the maximum number of retries on version conflicts.
+	pub fn max_retries(mut self, max_retries: u32) -> Self {
+		self.max_retries = max_retries;
+		self
+	}
+
+	/// Read, modify and update the resource, retrying on version conflicts.
+	/// Returns the updated resource. Fails with
+	/// [`Error::PreconditionFailed`] if the retries are exhausted.
+	pub async fn send(mut self) -> Result<R, Error> {
+		let mut retries = 0;
+		loop {
+			let mut resource = self
+				.client
+				.read::<R>(self.id)
+				.await?
+				.ok_or_else(|| Error::ResourceNotFound(format!("{}/{}", R::TYPE, self.id)))?;
+			(self.modification)(&mut resource);
+
+			match resource.update(true, &self.client).await {
+				Ok(_) => return Ok(resource),
+				Err(Error::PreconditionFailed(_)) if retries < self.max_retries => {
+					retries += 1;
+					tracing::debug!(
+						"Version conflict modifying {}/{}, retrying ({retries}/{})",
+						R::TYPE,
+						self.id,
+						self.max_retries
+					);
+				}
+				Err(err) => return Err(err),
+			}
+		}
+	}
+}
+
+impl<'a, V: FhirVersion, R, F> std::fmt::Debug for Modify<'a, V, R, F> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("Modify")
+			.field("client", &self.client)
+			.field("id", &self.id)
+			.field("max_retries", &self.max_retries)
+			.finish_non_exhaustive()
+	}
+}
@@ -1,33 +1,81 @@
 //! Patch request building.
 
-use fhir_model::r4b::resources::{
-	Parameters, ParametersParameter, ParametersParameterValue, ResourceType,
-};
 use reqwest::header::{self, HeaderValue};
 use serde::Serialize;
+use serde_json::{json, Value};
 
-use crate::client::FhirVersion;
+use super::{response::Response, Client, Error, FhirVersion, HttpRequest, PreferReturn};
 
-use super::{Client, Error, FhirR4B};
+/// Operation of a FHIRPath patch, i.e. a `Parameters.parameter` with the
+/// name `operation`.
+#[derive(Debug, Clone)]
+struct FhirPatchOperation<P> {
+	/// The parts describing the operation.
+	parts: Vec<Value>,
+	/// The `value` part of the operation, if any.
+	value: Option<P>,
+}
+
+impl<P: Serialize> FhirPatchOperation<P> {
+	/// Start a new operation of the given type with its path.
+	fn new(r#type: &str, path: String) -> Self {
+		Self {
+			parts: vec![
+				json!({ "name": "type", "valueCode": r#type }),
+				json!({ "name": "path", "valueString": path }),
+			],
+			value: None,
+		}
+	}
+
+	/// Add a part with a string value.
+	fn string(mut self, name: &str, value: String) -> Self {
+		self.parts.push(json!({ "name": name, "valueString": value }));
+		self
+	}
+
+	/// Add a part with an integer value.
+	fn integer(mut self, name: &str, value: i32) -> Self {
+		self.parts.push(json!({ "name": name, "valueInteger": value }));
+		self
+	}
+
+	/// Set the `value` part.
+	fn value(mut self, value: P) -> Self {
+		self.value = Some(value);
+		self
+	}
+
+	/// Convert into the `Parameters.parameter` JSON.
+	fn into_parameter(self) -> Result<Value, Error> {
+		let mut parts = self.parts;
+		if let Some(value) = self.value {
+			parts.push(serde_json::to_value(value)?);
+		}
+		Ok(json!({ "name": "operation", "part": parts }))
+	}
+}
 
 /// Builder for a PATCH request via FHIRPath for a FHIR resource.
 #[derive(Debug, Clone)]
 #[must_use = "You probably want to send the PATCH request"]
-pub struct PatchViaFhir<'a> {
+pub struct PatchViaFhir<'a, V: FhirVersion> {
 	/// FHIR client.
-	client: Client<FhirR4B>,
+	client: Client<V>,
 	/// Resource type to apply the patch to.
-	resource_type: ResourceType,
+	resource_type: V::ResourceType,
 	/// Resource ID to apply the path to.
 	id: &'a str,
 	/// Operations to apply.
-	operations: Vec<Option<ParametersParameter>>,
+	operations: Vec<FhirPatchOperation<V::ParametersParameter>>,
+	/// Content the server is asked to return.
+	prefer: Option<PreferReturn>,
 }
 
-impl<'a> PatchViaFhir<'a> {
+impl<'a, V: FhirVersion> PatchViaFhir<'a, V> {
 	/// Start building a new Patch request.
-	pub fn new(client: Client<FhirR4B>, resource_type: ResourceType, id: &'a str) -> Self {
-		Self { client, resource_type, id, operations: Vec::new() }
+	pub fn new(client: Client<V>, resource_type: V::ResourceType, id: &'a str) -> Self {
+		Self { client, resource_type, id, operations: Vec::new(), prefer: None }
 	}
 
 	/// Add an `add` operation to the list of operations. Note that the `path`
@@ -38,39 +86,12 @@ impl<'a> PatchViaFhir<'a> {
 		mut self,
 		path: impl Into<String>,
 		name: impl Into<String>,
-		value: ParametersParameter,
+		value: V::ParametersParameter,
 	) -> Self {
-		#[allow(clippy::unwrap_used)] // Will always succeed.
-		let parameter = ParametersParameter::builder()
-			.name("operation".to_owned())
-			.part(vec![
-				Some(
-					ParametersParameter::builder()
-						.name("type".to_owned())
-						.value(ParametersParameterValue::Code("add".to_owned()))
-						.build()
-						.unwrap(),
-				),
-				Some(
-					ParametersParameter::builder()
-						.name("path".to_owned())
-						.value(ParametersParameterValue::String(path.into()))
-						.build()
-						.unwrap(),
-				),
-				Some(
-					ParametersParameter::builder()
-						.name("name".to_owned())
-						.value(ParametersParameterValue::String(name.into()))
-						.build()
-						.unwrap(),
-				),
-				Some(value),
-			])
-			.build()
-			.unwrap();
-
-		self.operations.push(Some(parameter));
+		let operation =
+			FhirPatchOperation::new("add", path.into()).string("name", name.into()).value(value);
+
+		self.operations.push(operation);
 		self
 	}
 
@@ -81,40 +102,13 @@ impl<'a> PatchViaFhir<'a> {
 	pub fn insert(
 		mut self,
 		path: impl Into<String>,
-		value: ParametersParameter,
+		value: V::ParametersParameter,
 		index: i32,
 	) -> Self {
-		#[allow(clippy::unwrap_used)] // Will always succeed.
-		let parameter = ParametersParameter::builder()
-			.name("operation".to_owned())
-			.part(vec![
-				Some(
-					ParametersParameter::builder()
-						.name("type".to_owned())
-						.value(ParametersParameterValue::Code("insert".to_owned()))
-						.build()
-						.unwrap(),
-				),
-				Some(
-					ParametersParameter::builder()
-						.name("path".to_owned())
-						.value(ParametersParameterValue::String(path.into()))
-						.build()
-						.unwrap(),
-				),
-				Some(
-					ParametersParameter::builder()
-						.name("index".to_owned())
-						.value(ParametersParameterValue::Integer(index))
-						.build()
-						.unwrap(),
-				),
-				Some(value),
-			])
-			.build()
-			.unwrap();
-
-		self.operations.push(Some(parameter));
+		let operation =
+			FhirPatchOperation::new("insert", path.into()).integer("index", index).value(value);
+
+		self.operations.push(operation);
 		self
 	}
 
@@ -122,29 +116,7 @@ impl<'a> PatchViaFhir<'a> {
 	/// `path` needs to be set according the FHIR defititions, e.g.
 	/// `Patient.active` to delete the `active` field on a `Patient` resource.
 	pub fn delete(mut self, path: impl Into<String>) -> Self {
-		#[allow(clippy::unwrap_used)] // Will always succeed.
-		let parameter = ParametersParameter::builder()
-			.name("operation".to_owned())
-			.part(vec![
-				Some(
-					ParametersParameter::builder()
-						.name("type".to_owned())
-						.value(ParametersParameterValue::Code("delete".to_owned()))
-						.build()
-						.unwrap(),
-				),
-				Some(
-					ParametersParameter::builder()
-						.name("path".to_owned())
-						.value(ParametersParameterValue::String(path.into()))
-						.build()
-						.unwrap(),
-				),
-			])
-			.build()
-			.unwrap();
-
-		self.operations.push(Some(parameter));
+		self.operations.push(FhirPatchOperation::new("delete", path.into()));
 		self
 	}
 
@@ -152,31 +124,8 @@ impl<'a> PatchViaFhir<'a> {
 	/// `path` needs to be set according the FHIR defititions, e.g.
 	/// `Patient.name`. The value must have the `name` field set to `value` and
 	/// then either set a `value[x]` or `part`.
-	pub fn replace(mut self, path: impl Into<String>, value: ParametersParameter) -> Self {
-		#[allow(clippy::unwrap_used)] // Will always succeed.
-		let parameter = ParametersParameter::builder()
-			.name("operation".to_owned())
-			.part(vec![
-				Some(
-					ParametersParameter::builder()
-						.name("type".to_owned())
-						.value(ParametersParameterValue::Code("replace".to_owned()))
-						.build()
-						.unwrap(),
-				),
-				Some(
-					ParametersParameter::builder()
-						.name("path".to_owned())
-						.value(ParametersParameterValue::String(path.into()))
-						.build()
-						.unwrap(),
-				),
-				Some(value),
-			])
-			.build()
-			.unwrap();
-
-		self.operations.push(Some(parameter));
+	pub fn replace(mut self, path: impl Into<String>, value: V::ParametersParameter) -> Self {
+		self.operations.push(FhirPatchOperation::new("replace", path.into()).value(value));
 		self
 	}
 
@@ -185,85 +134,74 @@ impl<'a> PatchViaFhir<'a> {
 	/// `Patient.name`. The value must have the `name` field set to `value` and
 	/// then either set a `value[x]` or `part`.
 	pub fn r#move(mut self, path: impl Into<String>, source: i32, destination: i32) -> Self {
-		#[allow(clippy::unwrap_used)] // Will always succeed.
-		let parameter = ParametersParameter::builder()
-			.name("operation".to_owned())
-			.part(vec![
-				Some(
-					ParametersParameter::builder()
-						.name("type".to_owned())
-						.value(ParametersParameterValue::Code("move".to_owned()))
-						.build()
-						.unwrap(),
-				),
-				Some(
-					ParametersParameter::builder()
-						.name("path".to_owned())
-						.value(ParametersParameterValue::String(path.into()))
-						.build()
-						.unwrap(),
-				),
-				Some(
-					ParametersParameter::builder()
-						.name("source".to_owned())
-						.value(ParametersParameterValue::Integer(source))
-						.build()
-						.unwrap(),
-				),
-				Some(
-					ParametersParameter::builder()
-						.name("destination".to_owned())
-						.value(ParametersParameterValue::Integer(destination))
-						.build()
-						.unwrap(),
-				),
-			])
-			.build()
-			.unwrap();
-
-		self.operations.push(Some(parameter));
+		let operation = FhirPatchOperation::new("move", path.into())
+			.integer("source", source)
+			.integer("destination", destination);
+
+		self.operations.push(operation);
+		self
+	}
+
+	/// Ask the server to return the given content via the `Prefer` header.
+	pub fn prefer(mut self, prefer: PreferReturn) -> Self {
+		self.prefer = Some(prefer);
 		self
 	}
 
 	/// Patch the resource on the FHIR server.
 	pub async fn send(self) -> Result<(), Error> {
-		#[allow(clippy::unwrap_used)] // Will always succeed.
-		let parameters = Parameters::builder().parameter(self.operations).build().unwrap();
-
-		let url = self.client.url(&[self.resource_type.as_str(), self.id]);
-		let request = self
-			.client
-			.0
-			.client
-			.patch(url)
-			.header(header::ACCEPT, FhirR4B::JSON_MIME_TYPE)
-			.header(header::CONTENT_TYPE, HeaderValue::from_static(FhirR4B::JSON_MIME_TYPE))
-			.json(&parameters);
-
-		let response = self.client.run_request(request).await?;
-
-		response.successful().await
+		self.send_with_response().await.map(Response::into_value)
+	}
+
+	/// Patch the resource on the FHIR server, returning the response
+	/// metadata.
+	pub async fn send_with_response(self) -> Result<Response<V, ()>, Error> {
+		self.send_returning().await.map(|response| response.map(|_| ()))
+	}
+
+	/// Patch the resource on the FHIR server. Returns the patched resource if
+	/// the server returned it, along with the response metadata.
+	pub async fn send_returning(self) -> Result<Response<V, Option<V::Resource>>, Error> {
+		let parameter = self
+			.operations
+			.into_iter()
+			.map(FhirPatchOperation::into_parameter)
+			.collect::<Result<Vec<_>, _>>()?;
+		let parameters = json!({ "resourceType": "Parameters", "parameter": parameter });
+
+		let resource_type = self.resource_type.to_string();
+		let url = self.client.url(&[&resource_type, self.id]);
+		let request = HttpRequest::patch(url)
+			.header(header::ACCEPT, HeaderValue::from_static(V::JSON_MIME_TYPE))
+			.header(header::CONTENT_TYPE, HeaderValue::from_static(V::JSON_MIME_TYPE))
+			.json(&parameters)?;
+
+		let response = self.client.run_request(PreferReturn::apply(self.prefer, request)).await?;
+
+		response.returned_resource().await
 	}
 }
 
 /// Builder for a PATCH request via JSONPatch for a FHIR resource.
 #[derive(Debug, Clone)]
 #[must_use = "You probably want to send the PATCH request"]
-pub struct PatchViaJson<'a> {
+pub struct PatchViaJson<'a, V: FhirVersion> {
 	/// FHIR client.
-	client: Client<FhirR4B>,
+	client: Client<V>,
 	/// Resource type to apply the patch to.
-	resource_type: ResourceType,
+	resource_type: V::ResourceType,
 	/// Resource ID to apply the path to.
 	id: &'a str,
 	/// Operations to apply.
 	operations: Vec<serde_json::Map<String, serde_json::Value>>,
+	/// Content the server is asked to return.
+	prefer: Option<PreferReturn>,
 }
 
-impl<'a> PatchViaJson<'a> {
+impl<'a, V: FhirVersion> PatchViaJson<'a, V> {
 	/// Start building a new Patch request.
-	pub fn new(client: Client<FhirR4B>, resource_type: ResourceType, id: &'a str) -> Self {
-		Self { client, resource_type, id, operations: Vec::new() }
+	pub fn new(client: Client<V>, resource_type: V::ResourceType, id: &'a str) -> Self {
+		Self { client, resource_type, id, operations: Vec::new(), prefer: None }
 	}
 
 	/// Add an `add` operation to the list of operations. The `path` needs to be
@@ -353,20 +291,35 @@ impl<'a> PatchViaJson<'a> {
 		self
 	}
 
+	/// Ask the server to return the given content via the `Prefer` header.
+	pub fn prefer(mut self, prefer: PreferReturn) -> Self {
+		self.prefer = Some(prefer);
+		self
+	}
+
 	/// Patch the resource on the FHIR server.
 	pub async fn send(self) -> Result<(), Error> {
-		let url = self.client.url(&[self.resource_type.as_str(), self.id]);
-		let request = self
-			.client
-			.0
-			.client
-			.patch(url)
-			.header(header::ACCEPT, FhirR4B::JSON_MIME_TYPE)
+		self.send_with_response().await.map(Response::into_value)
+	}
+
+	/// Patch the resource on the FHIR server, returning the response
+	/// metadata.
+	pub async fn send_with_response(self) -> Result<Response<V, ()>, Error> {
+		self.send_returning().await.map(|response| response.map(|_| ()))
+	}
+
+	/// Patch the resource on the FHIR server. Returns the patched resource if
+	/// the server returned it, along with the response metadata.
+	pub async fn send_returning(self) -> Result<Response<V, Option<V::Resource>>, Error> {
+		let resource_type = self.resource_type.to_string();
+		let url = self.client.url(&[&resource_type, self.id]);
+		let request = HttpRequest::patch(url)
+			.header(header::ACCEPT, HeaderValue::from_static(V::JSON_MIME_TYPE))
 			.header(header::CONTENT_TYPE, HeaderValue::from_static("application/json-patch+json"))
-			.json(&self.operations);
+			.json(&self.operations)?;
 
-		let response = self.client.run_request(request).await?;
+		let response = self.client.run_request(PreferReturn::apply(self.prefer, request)).await?;
 
-		response.successful().await
+		response.returned_resource().await
 	}
 }
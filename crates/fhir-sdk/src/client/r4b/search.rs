@@ -0,0 +1,334 @@
+//! Typed search parameters for FHIR R4B.
+
+pub use crate::client::search::{
+	CompositeParam, Filter, FilterOperator, FilterPath, FilterValue,
+	LocalReferenceUnusableAsParameter, MissingParam, NearParam, StringParam, UriParam,
+};
+use crate::client::{
+	search::{self, escape_value, IntoQuery, SearchParameter},
+	FhirR4B,
+};
+use fhir_model::r4b::{codes::SearchModifierCode, resources::ResourceType};
+
+/// Number search.
+pub type NumberParam<'a> = search::NumberParam<'a, FhirR4B>;
+
+/// Date search.
+pub type DateParam<'a> = search::DateParam<'a, FhirR4B>;
+
+/// Token search, e.g. in `CodeableConcept`s or `identifier`s.
+///
+/// Only implements most common functionality. Refer to adding raw queries when
+/// this does not suffice.
+#[derive(Debug, Clone, Copy)]
+pub enum TokenParam<'a> {
+	/// Match a specific code in a specific system
+	CodeInSystem {
+		/// The system
+		system: &'a str,
+		/// The code
+		code: &'a str,
+		/// Whether to negate the search
+		not: bool,
+	},
+	/// Match a specific code in any system
+	CodeInAnySystem {
+		/// The code
+		code: &'a str,
+		/// Whether to negate the match
+		not: bool,
+	},
+	/// Match a code that does not have a system
+	CodeWithoutSystem {
+		/// The code
+		code: &'a str,
+		/// Whether to negate the search
+		not: bool,
+	},
+	/// Match any code in a specific system
+	InSystem {
+		/// The system
+		system: &'a str,
+		/// Whether to negate the search
+		not: bool,
+	},
+	/// Token search with the `of-type` modifier. Only possible on
+	/// `identifier`s.
+	OfType {
+		/// System of type to search on.
+		type_system: Option<&'a str>,
+		/// Code of the type to search on.
+		type_code: Option<&'a str>,
+		/// Value to search on with the given type.
+		value: Option<&'a str>,
+	},
+	/// Token search whether the value is `in` or `not-in` a given `ValueSet`.
+	In {
+		/// `ValueSet` reference URI.
+		value_set: &'a str,
+		/// Whether to switch to `not-in` search.
+		not: bool,
+	},
+	/// Tests the `text` or `display` values.
+	Text {
+		/// Text to search for (is a starts-with search).
+		text: &'a str,
+	},
+}
+
+impl<'a> TokenParam<'a> {
+	/// Token param for a code within a specific system
+	pub fn code_in_system(system: &'a str, code: &'a str) -> Self {
+		Self::CodeInSystem { system, code, not: false }
+	}
+
+	/// Token param for any code within the given system
+	pub fn in_system(system: &'a str) -> Self {
+		Self::InSystem { system, not: false }
+	}
+
+	/// Token param for a specific code without specifying system
+	pub fn code(code: &'a str) -> Self {
+		Self::CodeInAnySystem { code, not: false }
+	}
+
+	/// Match a specific code that does not have a system
+	pub fn code_without_system(code: &'a str) -> Self {
+		Self::CodeWithoutSystem { code, not: false }
+	}
+}
+
+impl<'a> SearchParameter for TokenParam<'a> {
+	fn modifier(&self) -> Option<&str> {
+		match self {
+			TokenParam::CodeInSystem { not, .. }
+			| TokenParam::CodeWithoutSystem { not, .. }
+			| TokenParam::CodeInAnySystem { not, .. }
+			| TokenParam::CodeInSystem { not, .. }
+				if *not =>
+			{
+				Some(SearchModifierCode::Not.as_ref())
+			}
+			TokenParam::OfType { .. } => Some(SearchModifierCode::OfType.as_ref()),
+			TokenParam::In { not, .. } if *not => Some(SearchModifierCode::NotIn.as_ref()),
+			TokenParam::In { .. } => Some(SearchModifierCode::In.as_ref()),
+			TokenParam::Text { .. } => Some(SearchModifierCode::Text.as_ref()),
+			_ => None,
+		}
+	}
+
+	fn query_value(&self) -> String {
+		match self {
+			TokenParam::CodeInSystem { system, code, .. } => format!("{system}|{code}"),
+			TokenParam::CodeInAnySystem { code, .. } => format!("{code}"),
+			TokenParam::CodeWithoutSystem { code, .. } => format!("|{code}"),
+			TokenParam::InSystem { system, .. } => format!("{system}|"),
+			TokenParam::OfType { type_system, type_code, value } => format!(
+				"{}|{}|{}",
+				escape_value(type_system.unwrap_or_default()),
+				escape_value(type_code.unwrap_or_default()),
+				escape_value(value.unwrap_or_default())
+			),
+			TokenParam::In { value_set, .. } => escape_value(value_set),
+			TokenParam::Text { text } => escape_value(text),
+		}
+	}
+}
+
+/// Search in references, by relative reference, absolute URL or identifier.
+pub type ReferenceParam<'a> = search::ReferenceParam<'a, FhirR4B, TokenParam<'a>>;
+
+/// Search on a quantity.
+pub type QuantityParam<'a> = search::QuantityParam<'a, FhirR4B>;
+
+/// Include referred to resources in the search response
+#[derive(Debug, Clone, Copy)]
+pub struct IncludeParam<'a> {
+	/// Resource type from which the join comes
+	pub source_type: ResourceType,
+
+	/// Field name to join on. Must be a search parameter of type reference for the [IncludeParam::source] resource type.
+	pub field: &'a str,
+
+	/// Type of the target resource in the case the reference field can have multiple target resource types.
+	pub target_type: Option<ResourceType>,
+
+	/// Whether to recursively include.
+	pub iterate: bool,
+
+	/// Whether this is a reverse include.
+	pub reverse: bool,
+}
+
+impl<'a> IncludeParam<'a> {
+	/// Create a new `_include` parameter
+	pub fn new(source_type: ResourceType, field: &'a str) -> Self {
+		Self { source_type, field, target_type: None, iterate: false, reverse: false }
+	}
+
+	/// Create a new `_revInclude` parameter
+	pub fn reverse(source_type: ResourceType, field: &'a str) -> Self {
+		Self { source_type, field, target_type: None, iterate: false, reverse: true }
+	}
+
+	/// Add the `:iterate` modifier
+	pub fn iterate(mut self) -> Self {
+		self.iterate = true;
+		self
+	}
+
+	/// Set a target type
+	pub fn with_target_type(mut self, target_type: ResourceType) -> Self {
+		self.target_type = Some(target_type);
+		self
+	}
+}
+
+impl<'a> IntoQuery for IncludeParam<'a> {
+	fn into_query(self) -> (String, String) {
+		let mut name: String = if self.reverse { "_revInclude" } else { "_include" }.to_string();
+
+		if self.iterate {
+			name += ":iterate";
+		}
+
+		let mut value = format!("{}:{}", self.source_type.as_str(), self.field);
+
+		if let Some(target_type) = self.target_type {
+			value = format!("{}:{}", value, target_type.as_str())
+		}
+
+		(name, value)
+	}
+}
+
+/// Chain of reference search parameters to resources of type `T`.
+pub type Chain<T> = search::Chain<FhirR4B, T>;
+
+/// Chained search parameter, created via [Chain::with].
+pub type ChainedParam<P> = search::ChainedParam<FhirR4B, P>;
+
+/// Reverse chain (`_has`) of resources of type `T` referring to the searched
+/// resources of type `R`.
+pub type ReverseChain<R, T> = search::ReverseChain<FhirR4B, R, T>;
+
+/// Reverse chained search parameter, created via [ReverseChain::with].
+pub type HasParam<P> = search::HasParam<FhirR4B, P>;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn token() {
+		let token = TokenParam::CodeInSystem { system: "system", code: "code", not: false };
+		assert_eq!(token.query_value(), "system|code".to_owned());
+
+		let token = TokenParam::CodeInAnySystem { code: "code", not: false };
+		assert_eq!(token.query_value(), "code".to_owned());
+
+		let token = TokenParam::CodeWithoutSystem { code: "code", not: false };
+		assert_eq!(token.query_value(), "|code".to_owned());
+
+		let token = TokenParam::InSystem { system: "system", not: false };
+		assert_eq!(token.query_value(), "system|".to_owned());
+
+		let token = TokenParam::OfType { type_system: None, type_code: None, value: Some("value") };
+		assert_eq!(token.query_value(), "||value".to_owned());
+	}
+
+	#[test]
+	fn include() {
+		let include = IncludeParam {
+			source_type: ResourceType::MedicationRequest,
+			field: "encounter",
+			target_type: None,
+			iterate: false,
+			reverse: false,
+		};
+		assert_eq!(
+			include.into_query(),
+			("_include".to_owned(), "MedicationRequest:encounter".to_owned())
+		);
+
+		let include = IncludeParam {
+			source_type: ResourceType::Observation,
+			field: "subject",
+			target_type: Some(ResourceType::Patient),
+			iterate: false,
+			reverse: false,
+		};
+		assert_eq!(
+			include.into_query(),
+			("_include".to_owned(), "Observation:subject:Patient".to_owned())
+		);
+
+		let include = IncludeParam {
+			source_type: ResourceType::Patient,
+			field: "link",
+			target_type: None,
+			iterate: true,
+			reverse: false,
+		};
+		assert_eq!(
+			include.into_query(),
+			("_include:iterate".to_owned(), "Patient:link".to_owned())
+		);
+
+		let include = IncludeParam {
+			source_type: ResourceType::Encounter,
+			field: "episode-of-care",
+			target_type: None,
+			iterate: false,
+			reverse: true,
+		};
+		assert_eq!(
+			include.into_query(),
+			("_revInclude".to_owned(), "Encounter:episode-of-care".to_owned())
+		);
+	}
+
+	#[test]
+	fn chained() {
+		use fhir_model::r4b::{
+			params::{
+				ObservationSubjectSearchReference, OrganizationSearchParameter,
+				PatientOrganizationSearchReference, PatientSearchParameter,
+			},
+			resources::{Organization, Patient},
+		};
+
+		let chained = Chain::<Patient>::new(ObservationSubjectSearchReference)
+			.with(PatientSearchParameter::Name, StringParam::Standard("peter"));
+		assert_eq!(chained.into_query(), ("subject:Patient.name".to_owned(), "peter".to_owned()));
+
+		let chained = Chain::<Patient>::new(ObservationSubjectSearchReference)
+			.then::<_, Organization>(PatientOrganizationSearchReference)
+			.with(OrganizationSearchParameter::Name, StringParam::Exact("Smith, Jones"));
+		assert_eq!(
+			chained.into_query(),
+			(
+				"subject:Patient.organization:Organization.name:exact".to_owned(),
+				"Smith\\, Jones".to_owned()
+			)
+		);
+	}
+
+	#[test]
+	fn reverse_chained() {
+		use fhir_model::r4b::{
+			params::{ObservationPatientSearchReference, ObservationSearchParameter},
+			resources::{Observation, Patient},
+		};
+
+		let has = ReverseChain::<Patient, Observation>::new(ObservationPatientSearchReference)
+			.with(
+				ObservationSearchParameter::Code,
+				TokenParam::CodeInSystem { system: "http://loinc.org", code: "1234-5", not: false },
+			);
+		assert_eq!(
+			has.into_query(),
+			("_has:Observation:patient:code".to_owned(), "http://loinc.org|1234-5".to_owned())
+		);
+	}
+}
@@ -1,272 +1,160 @@
 //! FHIR R5 client implementation.
 
-mod patch;
-mod references;
-mod response;
 pub mod search;
-mod transaction;
 
 use fhir_model::{
 	r5::{
-		codes::SubscriptionPayloadContent,
+		codes::{
+			BundleType, CompartmentType, LinkRelationTypes, SearchComparator, SearchEntryMode,
+			SubscriptionPayloadContent,
+		},
 		resources::{
-			BaseResource, Bundle, CapabilityStatement, NamedResource, Parameters,
-			ParametersParameter, ParametersParameterValue, Patient, Resource, ResourceType,
-			SubscriptionStatus, 
+			BaseResource, Bundle, BundleEntry, CapabilityStatement, NamedResource,
+			OperationOutcome, ParametersParameter, Patient, Resource, ResourceType,
+			SubscriptionStatus,
 		},
-		types::Reference,
+		types::{Meta, Reference},
+		R5,
 	},
 	ParsedReference,
 };
-use reqwest::{
-	header::{self, HeaderValue},
-	StatusCode, Url,
-};
+use reqwest::StatusCode;
 use serde::Serialize;
 
-use self::{
-	patch::{PatchViaFhir, PatchViaJson},
-	transaction::BatchTransaction,
-};
-use super::{misc, Client, Error, FhirR5, FhirVersion};
+use super::{Client, Error, FhirR5, FhirResource, FhirVersion, SearchMatch};
 
-impl Client<FhirR5> {
-	/// Get the server's capabilities. Fails if the respective FHIR version is
-	/// not supported at all.
-	pub async fn capabilities(&self) -> Result<CapabilityStatement, Error> {
-		let url = self.url(&["metadata"]);
+impl FhirVersion for FhirR5 {
+	const JSON_MIME_TYPE: &'static str = "application/fhir+json; fhirVersion=5.0";
 
-		self.fetch_resource(url).await
-	}
-
-	async fn fetch_resource<R: TryFrom<Resource>>(&self, url: Url) -> Result<R, Error> {
-		let response = self.fetch_url(url).await?;
+	type Release = R5;
+	type Resource = Resource;
+	type ResourceType = ResourceType;
+	type Bundle = Bundle;
+	type BundleEntry = BundleEntry;
+	type OperationOutcome = OperationOutcome;
+	type CompartmentType = CompartmentType;
+	type CapabilityStatement = CapabilityStatement;
+	type Patient = Patient;
+	type ParametersParameter = ParametersParameter;
+	type Reference = Reference;
+	type SearchComparator = SearchComparator;
 
-		response.body().await
+	fn reference(reference: &str) -> Reference {
+		ParsedReference::new::<ResourceType>(reference).into()
 	}
 
-	/// Read any resource from any URL.
-	async fn read_generic<R: TryFrom<Resource>>(&self, url: Url) -> Result<Option<R>, Error> {
-		let response = self.fetch_url(url).await?;
-
-		if [StatusCode::NOT_FOUND, StatusCode::GONE].contains(&response.status()) {
-			return Ok(None);
-		}
-
-		response.body().await.map(Some)
+	fn operation_outcome_error(status: StatusCode, outcome: OperationOutcome) -> Error {
+		Error::OperationOutcomeR5(status, outcome)
 	}
 
-	/// Read the current version of a specific FHIR resource.
-	pub async fn read<R: NamedResource + TryFrom<Resource>>(
-		&self,
-		id: &str,
-	) -> Result<Option<R>, Error> {
-		let url = self.url(&[R::TYPE.as_str(), id]);
-		self.read_generic(url).await
+	fn operation_outcome_diagnostics(outcome: &OperationOutcome) -> Vec<&str> {
+		outcome.issue.iter().flatten().filter_map(|issue| issue.diagnostics.as_deref()).collect()
 	}
 
-	/// Read a specific version of a specific FHIR resource.
-	pub async fn read_version<R: NamedResource + TryFrom<Resource>>(
-		&self,
-		id: &str,
-		version_id: &str,
-	) -> Result<Option<R>, Error> {
-		let url = self.url(&[R::TYPE.as_str(), id, "_history", version_id]);
-		self.read_generic(url).await
+	fn contained(resource: &Resource) -> Option<&Vec<Resource>> {
+		resource.as_domain_resource().map(|domain_resource| domain_resource.contained())
 	}
 
-	/// Read the resource that is targeted in the reference.
-	pub async fn read_referenced(&self, reference: &Reference) -> Result<Resource, Error> {
-		let parsed_reference = reference.parse().ok_or(Error::MissingReference)?;
-
-		let absolute: String = match parsed_reference {
-			ParsedReference::Local { .. } => return Err(Error::LocalReference),
-			ParsedReference::Relative { .. } => {
-				parsed_reference.with_base_url(self.0.base_url.as_str()).to_string()
-			}
-			absolute => absolute.to_string(),
-		};
+	fn contained_mut(resource: &mut Resource) -> Option<&mut Vec<Resource>> {
+		resource.as_domain_resource_mut().map(|domain_resource| domain_resource.contained_mut())
+	}
 
-		let url: Url = absolute.parse().map_err(|_| Error::UrlParse(absolute))?;
+	fn set_reference_targets(
+		resource: &mut Resource,
+		mut resolve: impl FnMut(&ParsedReference<'_>) -> Option<Resource>,
+	) -> Vec<(String, ResourceType)> {
+		let mut unsupported = Vec::new();
 
-		let resource: Resource = self
-			.read_generic(url.clone())
-			.await?
-			.ok_or_else(|| Error::ResourceNotFound(url.to_string()))?;
-		if let Some(resource_type) = reference.r#type.as_ref() {
-			if resource.resource_type().as_str() != resource_type {
-				return Err(Error::WrongResourceType(
-					resource.resource_type().to_string(),
-					resource_type.clone(),
-				));
+		if let Some(domain_resource) = resource.as_domain_resource_mut() {
+			for field in domain_resource.lookup_references() {
+				if let Some(reference) = field.reference().clone().parse() {
+					if let Some(target) = resolve(&reference) {
+						let resource_type = target.resource_type();
+						if field.set_target(target).is_err() {
+							unsupported.push((reference.to_string(), resource_type));
+						}
+					}
+				}
 			}
 		}
 
-		Ok(resource)
+		unsupported
 	}
 
-	/// Create a new FHIR resource on the FHIR server. Returns the resource ID
-	/// and version ID.
-	pub async fn create<R: NamedResource + Serialize + Send + Sync>(
-		&self,
-		resource: &R,
-	) -> Result<(String, Option<String>), Error> {
-		let url = self.url(&[R::TYPE.as_str()]);
-		let request = self
-			.0
-			.client
-			.post(url)
-			.header(header::ACCEPT, FhirR5::JSON_MIME_TYPE)
-			.header(header::CONTENT_TYPE, FhirR5::JSON_MIME_TYPE)
-			.json(resource);
-
-		let response = self.run_request(request).await?;
-		if response.status().is_success() {
-			let (id, version_id) = misc::parse_location(response.headers())?;
-			let version_id = version_id.or(misc::parse_etag(response.headers()).ok());
-			Ok((id, version_id))
-		} else {
-			Err(response.successful().await.unwrap_err())
+	fn as_bundle_mut(resource: &mut Resource) -> Option<&mut Bundle> {
+		match resource {
+			Resource::Bundle(bundle) => Some(bundle),
+			_ => None,
 		}
 	}
 
-	/// Update a FHIR resource (or create it if it did not
-	/// exist). If conditional update is selected, the resource is only updated
-	/// if the version ID matches the expectations.
-	pub async fn update<R: NamedResource + BaseResource + Serialize + Send + Sync>(
-		&self,
-		resource: &R,
-		conditional: bool,
-	) -> Result<(bool, String), Error> {
-		let id = resource.id().as_ref().ok_or(Error::MissingId)?;
-
-		let url = self.url(&[R::TYPE.as_str(), id]);
-		let mut request = self
-			.0
-			.client
-			.put(url)
-			.header(header::ACCEPT, FhirR5::JSON_MIME_TYPE)
-			.header(header::CONTENT_TYPE, FhirR5::JSON_MIME_TYPE)
-			.json(resource);
-		if conditional {
-			let version_id = resource
-				.meta()
-				.as_ref()
-				.and_then(|meta| meta.version_id.as_ref())
-				.ok_or(Error::MissingVersionId)?;
-			let if_match = HeaderValue::from_str(&format!("W/\"{version_id}\""))
-				.map_err(|_| Error::MissingVersionId)?;
-			request = request.header(header::IF_MATCH, if_match);
-		}
-
-		let response = self.run_request(request).await?;
-		if response.status().is_success() {
-			let created = response.status() == StatusCode::CREATED;
-			let version_id = misc::parse_etag(response.headers())?;
-			Ok((created, version_id))
-		} else {
-			Err(response.successful().await.unwrap_err())
-		}
+	fn bundle_resources_mut(bundle: &mut Bundle) -> Vec<&mut Resource> {
+		bundle.entry.iter_mut().flatten().filter_map(|entry| entry.resource.as_mut()).collect()
 	}
 
-	/// Begin building a patch request for a FHIR resource on the server via the
-	/// `FHIRPath Patch` method.
-	pub fn patch_via_fhir<'a>(&self, resource_type: ResourceType, id: &'a str) -> PatchViaFhir<'a> {
-		PatchViaFhir::new(self.clone(), resource_type, id)
+	fn resolve_bundle_reference<'a>(
+		bundle: &'a Bundle,
+		base_url: &str,
+		reference: &ParsedReference<'_>,
+	) -> Option<&'a Resource> {
+		bundle.resolve_reference(base_url, reference)
 	}
 
-	/// Begin building a patch request for a FHIR resource on the server via the
-	/// [`JSON Patch`](https://datatracker.ietf.org/doc/html/rfc6902) method.
-	pub fn patch_via_json<'a>(&self, resource_type: ResourceType, id: &'a str) -> PatchViaJson<'a> {
-		PatchViaJson::new(self.clone(), resource_type, id)
-	}
-
-	/// Delete a FHIR resource on the server.
-	pub async fn delete(&self, resource_type: ResourceType, id: &str) -> Result<(), Error> {
-		let url = self.url(&[resource_type.as_str(), id]);
-		let request = self.0.client.delete(url).header(header::ACCEPT, FhirR5::JSON_MIME_TYPE);
-
-		let response = self.run_request(request).await?;
-
-		response.successful().await
-	}
-
-	/// Start building a new batch request.
-	pub fn batch(&self) -> BatchTransaction {
-		BatchTransaction::new(self.clone(), false)
-	}
-
-	/// Start building a new transaction request.
-	pub fn transaction(&self) -> BatchTransaction {
-		BatchTransaction::new(self.clone(), true)
-	}
-
-	/// Operation `$everything` on `Encounter`, returning a Bundle with all
-	/// resources for an `Encounter` record.
-	pub async fn operation_encounter_everything(&self, id: &str) -> Result<Bundle, Error> {
-		let url = self.url(&["Encounter", id, "$everything"]);
-
-		self.fetch_resource(url).await
+	fn next_page_url(bundle: &Bundle) -> Option<&str> {
+		bundle
+			.link
+			.iter()
+			.flatten()
+			.find(|link| link.relation == LinkRelationTypes::Next)
+			.map(|link| link.url.as_str())
 	}
 
-	/// Operation `$everything` on `Patient`, returning a Bundle with all
-	/// resources for an `Patient` record.
-	pub async fn operation_patient_everything(&self, id: &str) -> Result<Bundle, Error> {
-		let url = self.url(&["Patient", id, "$everything"]);
+	fn search_matches(bundle: &Bundle) -> Result<Vec<SearchMatch<Resource>>, Error> {
+		if bundle.r#type != BundleType::Searchset {
+			return Err(Error::WrongBundleType(
+				bundle.r#type.to_string(),
+				BundleType::Searchset.to_string(),
+			));
+		}
 
-		self.fetch_resource(url).await
+		let matches = bundle
+			.entry
+			.iter()
+			.flatten()
+			.filter(|entry| {
+				entry
+					.search
+					.as_ref()
+					.and_then(|search| search.mode.as_ref())
+					.map_or(true, |mode| *mode == SearchEntryMode::Match)
+			})
+			.map(|entry| SearchMatch {
+				resource: entry.resource.clone(),
+				full_url: entry.full_url.clone(),
+			})
+			.collect();
+		Ok(matches)
 	}
+}
 
-	/// Operation `$match` on `Patient`, returning matches for Patient records
-	/// based on a given incomplete Patient resource.
-	pub async fn operation_patient_match(
-		&self,
-		patient: Patient,
-		only_certain: bool,
-		count: i32,
-	) -> Result<Bundle, Error> {
-		#[allow(clippy::unwrap_used)] // Will always succeed.
-		let parameters = Parameters::builder()
-			.parameter(vec![
-				Some(
-					ParametersParameter::builder()
-						.name("resource".to_owned())
-						.resource(Resource::from(patient))
-						.build()
-						.unwrap(),
-				),
-				Some(
-					ParametersParameter::builder()
-						.name("onlyCertainMatches".to_owned())
-						.value(ParametersParameterValue::Boolean(only_certain))
-						.build()
-						.unwrap(),
-				),
-				Some(
-					ParametersParameter::builder()
-						.name("count".to_owned())
-						.value(ParametersParameterValue::Integer(count))
-						.build()
-						.unwrap(),
-				),
-			])
-			.build()
-			.unwrap();
-
-		let url = self.url(&["Patient", "$match"]);
-		let request = self
-			.0
-			.client
-			.post(url)
-			.header(header::ACCEPT, FhirR5::JSON_MIME_TYPE)
-			.header(header::CONTENT_TYPE, FhirR5::JSON_MIME_TYPE)
-			.json(&parameters);
-
-		let response = self.run_request(request).await?;
-
-		response.body().await
+impl<R> FhirResource<FhirR5> for R
+where
+	R: NamedResource + BaseResource + Serialize + TryFrom<Resource> + Send + Sync,
+{
+	const TYPE: ResourceType = <R as NamedResource>::TYPE;
+
+	fn set_resource_version_id(&mut self, version_id: Option<String>) {
+		if let Some(meta) = self.meta_mut() {
+			meta.version_id = version_id;
+		} else if let Some(version_id) = version_id {
+			// Meta does not require any field and will succeed building.
+			#[allow(clippy::unwrap_used)]
+			self.set_meta(Some(Meta::builder().version_id(version_id).build().unwrap()));
+		}
 	}
+}
 
+impl Client<FhirR5> {
 	/// Operation `$status` on `Subscription`, returning the
 	/// `SubcriptionStatus`.
 	pub async fn operation_subscription_status(
@@ -1,65 +1,75 @@
-use fhir_model::r5::resources::{Bundle, DomainResource, Resource, };
-use fhir_model::ParsedReference;
+//! Population of the reference target fields, shared by all FHIR versions.
+
+use fhir_model::{GenericResource, ParsedReference};
 use reqwest::Url;
 
-pub(super) fn populate_reference_targets(base_url: &Url, resource: &mut Resource) {
-	if let Resource::Bundle(bundle) = resource {
+use super::FhirVersion;
+
+/// Fill the reference target fields of the resource with the referenced
+/// resources, looked up in the contained resources and in the given `Bundle`.
+/// If no `Bundle` is given and the resource is a `Bundle` itself, the
+/// references of its entries are populated.
+pub(super) fn populate_reference_targets<V: FhirVersion>(
+	base_url: &Url,
+	resource: &mut V::Resource,
+	bundle: Option<&V::Bundle>,
+) {
+	if bundle.is_some() {
+		populate_reference_targets_internal::<V>(base_url, resource, bundle, None);
+	} else if let Some(bundle) = V::as_bundle_mut(resource) {
 		let lookup = bundle.clone();
 
-		for entry in bundle.entry.iter_mut().flatten() {
-			if let Some(resource) = entry.resource.as_mut() {
-				if let Some(domain_resource) = resource.as_domain_resource_mut() {
-					populate_reference_targets_internal(
-						base_url,
-						domain_resource,
-						Some(&lookup),
-						None,
-					);
-				}
-			}
+		for resource in V::bundle_resources_mut(bundle) {
+			populate_reference_targets_internal::<V>(base_url, resource, Some(&lookup), None);
 		}
-	} else if let Some(domain_resource) = resource.as_domain_resource_mut() {
-		populate_reference_targets_internal(base_url, domain_resource, None, None)
+	} else {
+		populate_reference_targets_internal::<V>(base_url, resource, None, None);
 	}
 }
 
-pub(super) fn populate_reference_targets_internal(
+/// Populate the references of a domain resource and its contained resources.
+fn populate_reference_targets_internal<V: FhirVersion>(
 	base_url: &Url,
-	resource: &mut dyn DomainResource,
-	bundle: Option<&Bundle>,
-	sibling_contained: Option<&Vec<Resource>>,
+	resource: &mut V::Resource,
+	bundle: Option<&V::Bundle>,
+	sibling_contained: Option<&Vec<V::Resource>>,
 ) {
+	// Only domain resources have references to populate.
+	let Some(contained) = V::contained(resource) else {
+		return;
+	};
+
 	// If this resource is a contained resource, sibling_contained will be set so we use
 	// that for lookup. Otherwise, this resource is not contained and we use its contained
 	// resources for lookup
-	let contained_lookup = sibling_contained.unwrap_or_else(|| resource.contained()).clone();
-
-	for field in resource.lookup_references() {
-		if let Some(reference) = field.reference().clone().parse() {
-			let target = match reference {
-				ParsedReference::Local { id } => contained_lookup
-					.iter()
-					.find(|c| c.as_base_resource().id() == &Some(id.to_string())),
-				other if bundle.is_some() => {
-					bundle.unwrap().resolve_reference(base_url.as_str(), &other)
-				}
-				_ => None,
-			};
-
-			if let Some(target) = target {
-				if field.set_target(target.clone()).is_err() {
-					tracing::warn!("Reference {} in Bundled resource refers to resource of unsupported type {}", reference.to_string(), target.resource_type());
-				}
-			} else {
-				tracing::debug!(
-					"Unable to resolve reference {} in Bundled resource",
-					reference.to_string(),
-				);
+	let contained_lookup = sibling_contained.unwrap_or(contained).clone();
+
+	let unsupported = V::set_reference_targets(resource, |reference| {
+		let target = match reference {
+			ParsedReference::Local { id } => {
+				contained_lookup.iter().find(|c| c.resource_id() == Some(*id))
 			}
+			other => bundle
+				.and_then(|bundle| V::resolve_bundle_reference(bundle, base_url.as_str(), other)),
+		};
+
+		if target.is_none() {
+			tracing::debug!(
+				"Unable to resolve reference {} in Bundled resource",
+				reference.to_string(),
+			);
 		}
+
+		target.cloned()
+	});
+
+	for (reference, resource_type) in unsupported {
+		tracing::warn!("Reference {reference} in Bundled resource refers to resource of unsupported type {resource_type}");
 	}
 
-	let contained = resource.contained_mut();
+	let Some(contained) = V::contained_mut(resource) else {
+		return;
+	};
 
 	if !contained.is_empty() && sibling_contained.is_some() {
 		tracing::warn!("Nested contained resources found while populating reference targets");
@@ -67,20 +77,13 @@ pub(super) fn populate_reference_targets_internal(
 
 	// Populate references in all contained resources as well
 	for c in contained {
-		if let Some(contained_domain_resource) = c.as_domain_resource_mut() {
-			// We pass on the same sibling_contained lookup value since contained resources
-			// are able to refer to siblings but they're not allowed to be nested
-			populate_reference_targets_internal(
-				base_url,
-				contained_domain_resource,
-				bundle,
-				Some(&contained_lookup),
-			);
-		}
+		// We pass on the same sibling_contained lookup value since contained resources
+		// are able to refer to siblings but they're not allowed to be nested
+		populate_reference_targets_internal::<V>(base_url, c, bundle, Some(&contained_lookup));
 	}
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "r5"))]
 mod tests {
 	use super::*;
 
@@ -95,16 +98,21 @@ mod tests {
 	};
 	use uuid::Uuid;
 
-	use crate::Url;
+	use crate::client::FhirR5;
 
 	fn base_url() -> Url {
 		Url::parse("http://my.fhir.server/fhir").unwrap()
 	}
 
+	/// Populate the references of the observation.
+	fn populate(observation: Observation, bundle: Option<&Bundle>) -> Observation {
+		let mut resource = Resource::from(observation);
+		populate_reference_targets::<FhirR5>(&base_url(), &mut resource, bundle);
+		Observation::try_from(resource).unwrap()
+	}
+
 	#[test]
 	fn populates_references_local() {
-		let base_url = base_url();
-
 		let practitioner = Practitioner::builder().id(Uuid::new_v4().to_string()).build().unwrap();
 		let practitioner_ref = local_reference_to(&practitioner).unwrap();
 
@@ -119,7 +127,7 @@ mod tests {
 		observation.contained = vec![patient.clone().into(), practitioner.clone().into()];
 		observation.subject = Some(patient_ref.into());
 
-		populate_reference_targets_internal(&base_url, &mut observation, None, None);
+		let observation = populate(observation, None);
 
 		// Check if the subject field points to the patient
 		assert_eq!(
@@ -165,7 +173,7 @@ mod tests {
 			(&observation_full_url, observation.clone().into()),
 		]);
 
-		populate_reference_targets_internal(&base_url, &mut observation, Some(&bundle), None);
+		let observation = populate(observation, Some(&bundle));
 
 		assert_eq!(
 			observation.subject.as_ref().and_then(|s| s.target.as_ref()),
@@ -193,7 +201,7 @@ mod tests {
 			(&observation_full_url.to_string(), observation.clone().into()),
 		]);
 
-		populate_reference_targets_internal(&base_url, &mut observation, Some(&bundle), None);
+		let observation = populate(observation, Some(&bundle));
 
 		assert_eq!(
 			observation.subject.as_ref().and_then(|s| s.target.as_ref()),
@@ -221,7 +229,7 @@ mod tests {
 			(&observation_full_url.to_string(), observation.clone().into()),
 		]);
 
-		populate_reference_targets_internal(&base_url, &mut observation, Some(&bundle), None);
+		let observation = populate(observation, Some(&bundle));
 
 		assert_eq!(
 			observation.subject.as_ref().and_then(|s| s.target.as_ref()),
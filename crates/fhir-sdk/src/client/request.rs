@@ -1,22 +1,34 @@
 //! HTTP Request implementation.
 
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
-use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
-use tokio_retry::{
-	strategy::{ExponentialBackoff, FixedInterval},
-	RetryIf,
+use reqwest::{
+	header::{self, HeaderMap, HeaderName, HeaderValue},
+	Method, StatusCode,
 };
+use tokio_retry::strategy::{jitter, ExponentialBackoff, FixedInterval};
 
-use super::error::Error;
+use super::{
+	error::Error,
+	transport::{HttpRequest, HttpResponse, Transport},
+};
 
 /// Settings for the HTTP Requests.
 ///
-/// By default, 3 retries are done with a fixed retry_time of 1000 ms.
+/// By default, 3 retries are done with a fixed retry_time of 1000 ms. Requests
+/// are retried on connection errors and on `429 Too Many Requests` and
+/// `503 Service Unavailable` responses, waiting as long as the server requests
+/// via `Retry-After`. Non-conditional `POST` requests are only retried if they
+/// did not reach the server, as they might create resources twice otherwise.
 #[derive(Debug, Clone)]
 pub struct RequestSettings {
 	/// Number of retries to make.
 	retries: usize,
+	/// Response status codes to retry on.
+	retry_statuses: Vec<StatusCode>,
+	/// Maximum `Retry-After` delay to wait for. Responses requesting to wait
+	/// longer are not retried.
+	max_retry_after: Option<Duration>,
 	/// Duration to wait between the requests (either always for fixed or for
 	/// the first time for exponential backoff).
 	retry_time: Duration,
@@ -35,6 +47,8 @@ impl Default for RequestSettings {
 	fn default() -> Self {
 		Self {
 			retries: 3,
+			retry_statuses: vec![StatusCode::TOO_MANY_REQUESTS, StatusCode::SERVICE_UNAVAILABLE],
+			max_retry_after: Some(Duration::from_secs(300)),
 			retry_time: Duration::from_millis(1000),
 			max_retry_time: None,
 			exp_backoff: false,
@@ -61,7 +75,25 @@ impl RequestSettings {
 		self
 	}
 
-	/// Set to use exponential backoff retrying.
+	/// Set the response status codes to retry on. Defaults to
+	/// `429 Too Many Requests` and `503 Service Unavailable`.
+	#[must_use]
+	pub fn retry_statuses(mut self, statuses: impl IntoIterator<Item = StatusCode>) -> Self {
+		self.retry_statuses = statuses.into_iter().collect();
+		self
+	}
+
+	/// Set the maximum `Retry-After` delay to wait for before retrying.
+	/// Responses requesting to wait longer are returned without retrying.
+	/// Defaults to 5 minutes.
+	#[must_use]
+	pub fn max_retry_after(mut self, max_retry_after: Option<Duration>) -> Self {
+		self.max_retry_after = max_retry_after;
+		self
+	}
+
+	/// Set to use exponential backoff retrying. The delays are randomized
+	/// between half and the full delay, so that clients do not retry in sync.
 	#[must_use]
 	pub fn exp_backoff(mut self, start_time: Duration, max_time: Option<Duration>) -> Self {
 		self.exp_backoff = true;
@@ -84,21 +116,21 @@ impl RequestSettings {
 		self
 	}
 
-	/// Make a HTTP request using the settings. Returns the response.
+	/// Make a HTTP request using the settings via the transport. Returns the
+	/// response.
 	pub(crate) async fn make_request(
 		&self,
-		mut request: reqwest::RequestBuilder,
-	) -> Result<reqwest::Response, Error> {
+		transport: &dyn Transport,
+		mut request: HttpRequest,
+	) -> Result<HttpResponse, Error> {
 		if let Some(timeout) = self.timeout {
-			request = request.timeout(timeout);
+			request.timeout = Some(timeout);
 		}
 
 		// Add or override default headers with request headers.
-		let (client, request_result) = request.build_split();
-		let mut request = request_result?;
 		let mut headers = self.headers.clone();
-		headers.extend(request.headers().clone());
-		*request.headers_mut() = headers;
+		headers.extend(request.headers);
+		request.headers = headers;
 
 		// Construct the dynamic retry strategy iterator.
 		let strategy: Box<dyn Iterator<Item = Duration> + Send + Sync> = if self.exp_backoff {
@@ -107,23 +139,62 @@ impl RequestSettings {
 			if let Some(max_backoff) = self.max_retry_time {
 				exp_backoff = exp_backoff.max_delay(max_backoff);
 			}
-			Box::new(exp_backoff)
+			Box::new(exp_backoff.map(|delay| delay / 2 + jitter(delay / 2)))
 		} else {
 			Box::new(FixedInterval::from_millis(self.retry_time.as_millis() as u64))
 		};
+		let mut strategy = strategy.take(self.retries);
+		let retry_safe = is_retry_safe(&request);
 
 		// Send the request, but retry on specific failures.
-		RetryIf::spawn(
-			strategy.take(self.retries),
-			|| async {
-				tracing::debug!("Sending {} request to {}", request.method(), request.url());
-				let request = request.try_clone().ok_or(Error::RequestNotClone)?;
-				let response = client.execute(request).await?;
-				tracing::debug!("Got response: {}", response.status());
-				Ok(response)
-			},
-			Error::should_retry,
-		)
-		.await
+		loop {
+			tracing::debug!("Sending {} request to {}", request.method, request.url);
+			let result = transport.send(request.clone()).await;
+
+			let retry_after = match &result {
+				Ok(response) => {
+					tracing::debug!("Got response: {}", response.status);
+					if !retry_safe || !self.retry_statuses.contains(&response.status) {
+						return result;
+					}
+					parse_retry_after(response)
+				}
+				// The request did not reach the server, so it is always safe to retry.
+				Err(Error::Request(err)) if err.is_connect() => None,
+				Err(err) if retry_safe && err.should_retry() => None,
+				Err(_) => return result,
+			};
+			if retry_after.zip(self.max_retry_after).is_some_and(|(delay, max)| delay > max) {
+				tracing::info!("Server requested to retry after {retry_after:?}, not retrying");
+				return result;
+			}
+			let Some(delay) = strategy.next() else {
+				return result;
+			};
+
+			let delay = retry_after.unwrap_or(delay);
+			tracing::info!("Retrying {} request to {} in {delay:?}", request.method, request.url);
+			tokio::time::sleep(delay).await;
+		}
+	}
+}
+
+/// Whether the request can be sent again without side effects. Only
+/// non-conditional `POST` requests cannot, as they might create resources
+/// twice. `POST` searches are safe.
+fn is_retry_safe(request: &HttpRequest) -> bool {
+	request.method != Method::POST
+		|| request.headers.contains_key("If-None-Exist")
+		|| request.url.path().ends_with("/_search")
+}
+
+/// Parse the `Retry-After` header of the response, given in seconds or as
+/// HTTP-date.
+fn parse_retry_after(response: &HttpResponse) -> Option<Duration> {
+	let value = response.headers.get(header::RETRY_AFTER)?.to_str().ok()?.trim();
+	if let Ok(seconds) = value.parse::<u64>() {
+		return Some(Duration::from_secs(seconds));
 	}
+	let date = httpdate::parse_http_date(value).ok()?;
+	Some(date.duration_since(SystemTime::now()).unwrap_or_default())
 }
@@ -1,24 +1,260 @@
 use std::marker::PhantomData;
-use std::ops::Deref;
 
-use reqwest::Url;
+use fhir_model::{time::OffsetDateTime, GenericResource, Instant};
+use reqwest::{
+	header::{self, HeaderMap},
+	StatusCode, Url,
+};
+
+use serde::Deserialize;
+
+use super::{misc, references::populate_reference_targets, Error, FhirVersion, HttpResponse};
 
 pub struct FhirResponse<V> {
 	pub(super) base_url: Url,
-	pub(super) response: reqwest::Response,
+	pub(super) response: HttpResponse,
 	version: PhantomData<V>,
 }
 
 impl<V> FhirResponse<V> {
-	pub fn new(base_url: Url, response: reqwest::Response) -> Self {
+	pub fn new(base_url: Url, response: HttpResponse) -> Self {
 		FhirResponse { base_url, response, version: PhantomData::default() }
 	}
+
+	/// The response status code.
+	pub fn status(&self) -> StatusCode {
+		self.response.status
+	}
+
+	/// The response headers.
+	pub fn headers(&self) -> &HeaderMap {
+		&self.response.headers
+	}
+}
+
+impl<V: FhirVersion> FhirResponse<V> {
+	/// Attempts to parse the response body as a FHIR resource.
+	/// Returns an [Error] if the request failed or the body could not be
+	/// parsed as `R`.
+	pub async fn body<R: TryFrom<V::Resource>>(self) -> Result<R, Error> {
+		self.body_response().await.map(Response::into_value)
+	}
+
+	/// Attempts to parse the response body as a FHIR resource, keeping the
+	/// response metadata. Returns an [Error] if the request failed or the body
+	/// could not be parsed as `R`.
+	pub async fn body_response<R: TryFrom<V::Resource>>(self) -> Result<Response<V, R>, Error> {
+		if !self.status().is_success() {
+			return Err(self.into_error());
+		}
+
+		let value = parse::<V, R>(&self.base_url, &self.response.text())?;
+		Ok(Response {
+			status: self.response.status,
+			headers: self.response.headers,
+			operation_outcome: None,
+			value,
+		})
+	}
+
+	/// Attempts to parse the response body as searchset Bundle. If `lenient`,
+	/// entries with resources that cannot be parsed, e.g. because `_summary`
+	/// or `_elements` left out required elements, are taken out of the Bundle
+	/// and returned as errors instead of failing the whole page.
+	pub async fn searchset(self, lenient: bool) -> Result<(V::Bundle, Vec<Error>), Error> {
+		if !lenient || !self.status().is_success() {
+			return self.body().await.map(|bundle| (bundle, Vec::new()));
+		}
+
+		let mut errors = Vec::new();
+		let mut bundle: serde_json::Value = serde_json::from_str(&self.response.text())?;
+		if let Some(entries) = bundle.get_mut("entry").and_then(serde_json::Value::as_array_mut) {
+			entries.retain(|entry| {
+				match entry.get("resource").map(V::Resource::deserialize).transpose() {
+					Ok(_) => true,
+					Err(err) => {
+						errors.push(err.into());
+						false
+					}
+				}
+			});
+		}
+
+		let bundle = convert::<V, V::Bundle>(&self.base_url, serde_json::from_value(bundle)?)?;
+		Ok((bundle, errors))
+	}
+
+	/// Check whether the request was successful and ignores the body if it is.
+	/// If not, attempts to parse the body as an `OperationOutcome` and returns
+	/// an [Error]
+	pub async fn successful(self) -> Result<(), Error> {
+		self.successful_response().await.map(Response::into_value)
+	}
+
+	/// Check whether the request was successful and return the response
+	/// metadata if it is, including an `OperationOutcome` returned in the
+	/// body. If not, attempts to parse the body as an `OperationOutcome` and
+	/// returns an [Error].
+	pub async fn successful_response(self) -> Result<Response<V, ()>, Error> {
+		if !self.status().is_success() {
+			return Err(self.into_error());
+		}
+
+		Ok(self.into_response(()))
+	}
+
+	/// Check whether the request was successful and parse the resource
+	/// returned in the body, if any. An `OperationOutcome` in the body is kept
+	/// in the response metadata instead. If the request failed, attempts to
+	/// parse the body as an `OperationOutcome` and returns an [Error].
+	pub async fn returned_resource<R: TryFrom<V::Resource>>(
+		self,
+	) -> Result<Response<V, Option<R>>, Error> {
+		if !self.status().is_success() {
+			return Err(self.into_error());
+		}
+
+		let body = self.response.text();
+		let operation_outcome = parse::<V, V::OperationOutcome>(&self.base_url, &body).ok();
+		let value = if operation_outcome.is_none() && !body.trim().is_empty() {
+			Some(parse::<V, R>(&self.base_url, &body)?)
+		} else {
+			None
+		};
+		Ok(Response {
+			status: self.response.status,
+			headers: self.response.headers,
+			operation_outcome,
+			value,
+		})
+	}
+
+	/// Wrap the value into the response metadata, keeping the response body
+	/// as `OperationOutcome` if it is one.
+	pub fn into_response<T>(self, value: T) -> Response<V, T> {
+		let operation_outcome =
+			parse::<V, V::OperationOutcome>(&self.base_url, &self.response.text()).ok();
+		Response {
+			status: self.response.status,
+			headers: self.response.headers,
+			operation_outcome,
+			value,
+		}
+	}
+
+	/// Convert the failed response into an [Error], using the
+	/// `OperationOutcome` in the body if there is one.
+	pub(super) fn into_error(self) -> Error {
+		let status = self.status();
+		let body = self.response.text();
+		let error = if let Ok(outcome) = parse::<V, V::OperationOutcome>(&self.base_url, &body) {
+			V::operation_outcome_error(status, outcome)
+		} else {
+			Error::Response(status, body)
+		};
+
+		if status == StatusCode::PRECONDITION_FAILED {
+			Error::PreconditionFailed(Box::new(error))
+		} else {
+			error
+		}
+	}
 }
 
-impl<V> Deref for FhirResponse<V> {
-	type Target = reqwest::Response;
+/// Response of the FHIR server to an interaction, carrying the returned value
+/// along with the response metadata, e.g. for optimistic concurrency or audit
+/// logging.
+#[derive(Debug, Clone)]
+pub struct Response<V: FhirVersion, T> {
+	/// The response status code.
+	status: StatusCode,
+	/// The response headers.
+	headers: HeaderMap,
+	/// `OperationOutcome` returned in the body, e.g. with warnings.
+	operation_outcome: Option<V::OperationOutcome>,
+	/// The value returned by the interaction.
+	value: T,
+}
+
+impl<V: FhirVersion, T> Response<V, T> {
+	/// The response status code.
+	#[must_use]
+	pub fn status(&self) -> StatusCode {
+		self.status
+	}
+
+	/// The raw response headers.
+	#[must_use]
+	pub fn headers(&self) -> &HeaderMap {
+		&self.headers
+	}
+
+	/// The version ID of the resource, parsed from the `ETag` header or
+	/// otherwise from the `Location` header.
+	#[must_use]
+	pub fn version_id(&self) -> Option<String> {
+		misc::parse_etag(&self.headers).ok().or_else(|| {
+			misc::parse_location(&self.headers).ok().and_then(|(_, version_id)| version_id)
+		})
+	}
+
+	/// The `Last-Modified` header as `Instant`, if present and valid.
+	#[must_use]
+	pub fn last_modified(&self) -> Option<Instant> {
+		let last_modified = self.headers.get(header::LAST_MODIFIED)?.to_str().ok()?;
+		let last_modified = httpdate::parse_http_date(last_modified).ok()?;
+		Some(Instant(OffsetDateTime::from(last_modified)))
+	}
+
+	/// The `Location` header, if present.
+	#[must_use]
+	pub fn location(&self) -> Option<&str> {
+		self.headers.get(header::LOCATION)?.to_str().ok()
+	}
+
+	/// The `OperationOutcome` returned alongside a successful response, e.g.
+	/// containing warnings.
+	#[must_use]
+	pub fn operation_outcome(&self) -> Option<&V::OperationOutcome> {
+		self.operation_outcome.as_ref()
+	}
+
+	/// The value returned by the interaction.
+	#[must_use]
+	pub fn value(&self) -> &T {
+		&self.value
+	}
+
+	/// Take the value returned by the interaction, dropping the metadata.
+	#[must_use]
+	pub fn into_value(self) -> T {
+		self.value
+	}
 
-	fn deref(&self) -> &Self::Target {
-		&self.response
+	/// Map the value returned by the interaction, keeping the metadata.
+	#[must_use]
+	pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Response<V, U> {
+		Response {
+			status: self.status,
+			headers: self.headers,
+			operation_outcome: self.operation_outcome,
+			value: f(self.value),
+		}
 	}
 }
+
+fn parse<V: FhirVersion, R: TryFrom<V::Resource>>(base_url: &Url, body: &str) -> Result<R, Error> {
+	convert::<V, R>(base_url, serde_json::from_str(body)?)
+}
+
+/// Populate the reference targets of the parsed resource and convert it to `R`.
+fn convert<V: FhirVersion, R: TryFrom<V::Resource>>(
+	base_url: &Url,
+	mut resource: V::Resource,
+) -> Result<R, Error> {
+	let resource_type = resource.resource_type();
+
+	populate_reference_targets::<V>(base_url, &mut resource, None);
+
+	resource.try_into().map_err(|_| Error::UnexpectedResourceType(resource_type.to_string()))
+}
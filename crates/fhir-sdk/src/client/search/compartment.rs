@@ -0,0 +1,112 @@
+//! Search in FHIR compartments.
+
+use async_trait::async_trait;
+use reqwest::Url;
+
+use super::{
+	page_with_cursor,
+	paging::{Page, Unpaged},
+	ExecutableSearch, NextPageCursor, PagedSearchExecutor, Search, SearchExecutor,
+	SearchParameters,
+};
+use crate::client::{Client, Error, FhirResource, FhirVersion};
+
+/// Executor of searches in a compartment, e.g. `Patient/123/Observation`.
+/// Created via [`Client::search_in_compartment`].
+#[derive(Debug, Clone)]
+pub struct Compartment<V: FhirVersion> {
+	/// The FHIR client.
+	client: Client<V>,
+	/// The type of the compartment.
+	compartment_type: V::CompartmentType,
+	/// The ID of the compartment's resource.
+	id: String,
+}
+
+impl<V: FhirVersion> Compartment<V> {
+	/// Get the search URL for the resource type in the compartment.
+	fn url<R: FhirResource<V>>(&self, params: SearchParameters<R>) -> Url {
+		let compartment_type = self.compartment_type.to_string();
+		let resource_type = R::TYPE.to_string();
+		let mut url = self.client.url(&[&compartment_type, &self.id, &resource_type]);
+		url.query_pairs_mut().extend_pairs(params.into_queries()).finish();
+		url
+	}
+}
+
+impl<V: FhirVersion> Client<V> {
+	/// Start constructing a search for FHIR resources of a given type in a
+	/// compartment, e.g. the `Observation`s in the `Patient` compartment of
+	/// a patient. Only returns matches. Populates reference target fields with
+	/// any matching included resources.
+	pub fn search_in_compartment<R>(
+		&self,
+		compartment_type: V::CompartmentType,
+		id: impl Into<String>,
+	) -> Search<Compartment<V>, R>
+	where
+		Compartment<V>: SearchExecutor<R>,
+		<Compartment<V> as SearchExecutor<R>>::Stream: Send,
+		R: Send,
+	{
+		let compartment = Compartment { client: self.clone(), compartment_type, id: id.into() };
+		Search::new().with_executor(compartment)
+	}
+}
+
+#[async_trait]
+impl<V, R> SearchExecutor<R> for Compartment<V>
+where
+	V: FhirVersion,
+	R: FhirResource<V> + 'static,
+{
+	type Stream = Unpaged<V, R>;
+
+	#[allow(refining_impl_trait)]
+	async fn search(self, params: SearchParameters<R>) -> Result<Unpaged<V, R>, Error> {
+		let via_post = params.is_via_post();
+		let url = self.url(params);
+
+		let (searchset, errors) = self.client.fetch_searchset(url, via_post).await?;
+		let page = Page::from_searchset(self.client.clone(), searchset)?.with_errors(errors);
+
+		Ok(Unpaged::from_page(page))
+	}
+}
+
+#[async_trait]
+impl<V, R> PagedSearchExecutor<R> for Compartment<V>
+where
+	V: FhirVersion,
+	R: FhirResource<V> + 'static,
+{
+	type Stream = Page<V, R>;
+
+	#[allow(refining_impl_trait)]
+	async fn search_paged(
+		self,
+		params: SearchParameters<R>,
+		page_size: Option<u32>,
+	) -> Result<(Self::Stream, Option<NextPageCursor<Self, R>>), Error> {
+		let via_post = params.is_via_post();
+		let mut url = self.url(params);
+
+		if let Some(page_size) = page_size {
+			url.query_pairs_mut().append_pair("_count", &page_size.to_string());
+		}
+
+		let (searchset, errors) = self.client.fetch_searchset(url, via_post).await?;
+		let page = Page::from_searchset(self.client.clone(), searchset)?.with_errors(errors);
+		Ok(page_with_cursor(self, page))
+	}
+
+	#[allow(refining_impl_trait)]
+	async fn fetch_next_page(
+		self,
+		url: Url,
+	) -> Result<(Self::Stream, Option<NextPageCursor<Self, R>>), Error> {
+		let (searchset, errors) = self.client.fetch_page(url).await?;
+		let page = Page::from_searchset(self.client.clone(), searchset)?.with_errors(errors);
+		Ok(page_with_cursor(self, page))
+	}
+}
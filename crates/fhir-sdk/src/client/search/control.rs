@@ -0,0 +1,193 @@
+//! Search result parameters, controlling what the server returns.
+
+use std::fmt::{Display, Formatter};
+
+use fhir_model::{
+	OrderedSearchParameter, ResourceElementDefinition, SearchableResource, SelectableResource,
+};
+
+use super::Search;
+
+/// Value of the `_summary` search parameter, returning only a summary of the
+/// matched resources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SummaryMode {
+	/// Return only the elements marked as summary elements.
+	True,
+	/// Return only the text, id, meta and top-level mandatory elements.
+	Text,
+	/// Return all elements except the text element.
+	Data,
+	/// Return only the count of matches, without any resources.
+	Count,
+}
+
+impl SummaryMode {
+	/// Get the parameter value.
+	#[must_use]
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::True => "true",
+			Self::Text => "text",
+			Self::Data => "data",
+			Self::Count => "count",
+		}
+	}
+}
+
+/// Value of the `_total` search parameter, requesting the precision of the
+/// total number of matches in the searchset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TotalMode {
+	/// There is no need to populate the total count.
+	None,
+	/// A rough estimate of the number of matches is sufficient.
+	Estimate,
+	/// The server is requested to return the accurate number of matches.
+	Accurate,
+}
+
+impl TotalMode {
+	/// Get the parameter value.
+	#[must_use]
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::None => "none",
+			Self::Estimate => "estimate",
+			Self::Accurate => "accurate",
+		}
+	}
+}
+
+/// Value of the `_contained` search parameter, selecting whether contained
+/// resources are searched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContainedMode {
+	/// Search contained resources only.
+	True,
+	/// Do not search contained resources.
+	False,
+	/// Search both contained and top-level resources.
+	Both,
+}
+
+impl ContainedMode {
+	/// Get the parameter value.
+	#[must_use]
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::True => "true",
+			Self::False => "false",
+			Self::Both => "both",
+		}
+	}
+}
+
+/// Value of the `_containedType` search parameter, selecting whether matched
+/// contained resources are returned as their container or by themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContainedType {
+	/// Return the resources containing the matches.
+	Container,
+	/// Return the matched contained resources.
+	Contained,
+}
+
+impl ContainedType {
+	/// Get the parameter value.
+	#[must_use]
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Container => "container",
+			Self::Contained => "contained",
+		}
+	}
+}
+
+impl Display for SummaryMode {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl Display for TotalMode {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl Display for ContainedMode {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl Display for ContainedType {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl<E, R> Search<E, R> {
+	/// Add a "_summary" search parameter. The returned resources might lack
+	/// required elements, so they are parsed leniently: matches that cannot be
+	/// parsed are returned as errors without failing the whole page.
+	pub fn summary(self, summary: SummaryMode) -> Self {
+		self.with_raw("_summary", summary)
+	}
+
+	/// Add a "_total" search parameter.
+	pub fn total(self, total: TotalMode) -> Self {
+		self.with_raw("_total", total)
+	}
+
+	/// Add a "_contained" search parameter.
+	pub fn contained(self, contained: ContainedMode) -> Self {
+		self.with_raw("_contained", contained)
+	}
+
+	/// Add a "_containedType" search parameter.
+	pub fn contained_type(self, contained_type: ContainedType) -> Self {
+		self.with_raw("_containedType", contained_type)
+	}
+
+	/// Add a "_count" search parameter, limiting the number of matches per
+	/// page. Use [Search::paged] to set the page size of paged searches.
+	pub fn count(self, count: u32) -> Self {
+		self.with_raw("_count", count)
+	}
+}
+
+impl<E, R> Search<E, R>
+where
+	R: SearchableResource,
+{
+	/// Add a "_sort" search parameter to sort the matches on the server by the
+	/// given parameters, in order of priority. Unlike [Search::order_by], the
+	/// results are not ordered by the client.
+	pub fn sort<I>(self, parameters: I) -> Self
+	where
+		I: IntoIterator<Item = OrderedSearchParameter<R::Params>>,
+	{
+		let sort = parameters.into_iter().map(|parameter| parameter.to_string());
+		self.with_raw("_sort", sort.collect::<Vec<_>>().join(","))
+	}
+}
+
+impl<E, R> Search<E, R>
+where
+	R: SelectableResource,
+{
+	/// Add an "_elements" search parameter, returning only the given
+	/// top-level elements of the matched resources. The returned resources
+	/// might lack required elements, so they are parsed leniently: matches
+	/// that cannot be parsed are returned as errors without failing the whole
+	/// page.
+	pub fn elements<I>(self, elements: I) -> Self
+	where
+		I: IntoIterator<Item = R::Elements>,
+	{
+		let elements = elements.into_iter().map(|element| element.name());
+		self.with_raw("_elements", elements.collect::<Vec<_>>().join(","))
+	}
+}
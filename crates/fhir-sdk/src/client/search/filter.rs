@@ -0,0 +1,277 @@
+//! `_filter` search parameter expressions.
+
+use std::fmt::{Display, Formatter};
+
+use super::IntoQuery;
+
+/// Expression of the `_filter` search parameter, e.g.
+/// `name eq "peter" and (birthdate gt 2000-01-01 or not (active eq true))`.
+/// Serializes to the `_filter` grammar via [Display].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Filter<'a> {
+	/// Comparison of a parameter with a value, e.g. `name eq "peter"`.
+	Compare {
+		/// The parameter path to compare.
+		path: FilterPath<'a>,
+		/// The comparison operation.
+		operator: FilterOperator,
+		/// The value to compare with.
+		value: FilterValue<'a>,
+	},
+	/// Both filters have to match.
+	And(Box<Filter<'a>>, Box<Filter<'a>>),
+	/// Either filter has to match.
+	Or(Box<Filter<'a>>, Box<Filter<'a>>),
+	/// The filter must not match.
+	Not(Box<Filter<'a>>),
+}
+
+impl<'a> Filter<'a> {
+	/// Compare a parameter with a value.
+	pub fn compare(
+		path: impl Into<FilterPath<'a>>,
+		operator: FilterOperator,
+		value: FilterValue<'a>,
+	) -> Self {
+		Self::Compare { path: path.into(), operator, value }
+	}
+
+	/// Combine with another filter, both of which have to match.
+	#[must_use]
+	pub fn and(self, other: Filter<'a>) -> Self {
+		Self::And(Box::new(self), Box::new(other))
+	}
+
+	/// Combine with another filter, either of which has to match.
+	#[must_use]
+	pub fn or(self, other: Filter<'a>) -> Self {
+		Self::Or(Box::new(self), Box::new(other))
+	}
+
+	/// Write the filter as operand of a logical expression, in parentheses
+	/// unless it is a logical expression of the same kind.
+	fn fmt_operand(&self, f: &mut Formatter<'_>, parent: &Filter<'a>) -> std::fmt::Result {
+		match (self, parent) {
+			(Self::And(..), Self::And(..)) | (Self::Or(..), Self::Or(..)) => self.fmt(f),
+			(Self::And(..) | Self::Or(..), _) => write!(f, "({self})"),
+			_ => self.fmt(f),
+		}
+	}
+}
+
+impl<'a> std::ops::Not for Filter<'a> {
+	type Output = Self;
+
+	fn not(self) -> Self::Output {
+		Self::Not(Box::new(self))
+	}
+}
+
+impl<'a> Display for Filter<'a> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Compare { path, operator, value } => write!(f, "{path} {operator} {value}"),
+			Self::And(left, right) => {
+				left.fmt_operand(f, self)?;
+				f.write_str(" and ")?;
+				right.fmt_operand(f, self)
+			}
+			Self::Or(left, right) => {
+				left.fmt_operand(f, self)?;
+				f.write_str(" or ")?;
+				right.fmt_operand(f, self)
+			}
+			Self::Not(filter) => write!(f, "not ({filter})"),
+		}
+	}
+}
+
+impl<'a> IntoQuery for Filter<'a> {
+	fn into_query(self) -> (String, String) {
+		("_filter".to_owned(), self.to_string())
+	}
+}
+
+/// Parameter path in a `_filter` expression, e.g. `name` or
+/// `related[type eq has-member].target`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterPath<'a> {
+	/// Parameter names with an optional filter on the parameter's values.
+	segments: Vec<(&'a str, Option<Filter<'a>>)>,
+}
+
+impl<'a> FilterPath<'a> {
+	/// Create a new path to the parameter.
+	pub fn new(parameter: &'a str) -> Self {
+		Self { segments: vec![(parameter, None)] }
+	}
+
+	/// Filter the values of the last parameter in the path.
+	#[must_use]
+	pub fn filtered(mut self, filter: Filter<'a>) -> Self {
+		if let Some((_, last)) = self.segments.last_mut() {
+			*last = Some(filter);
+		}
+		self
+	}
+
+	/// Continue the path with a parameter of the last parameter.
+	#[must_use]
+	pub fn then(mut self, parameter: &'a str) -> Self {
+		self.segments.push((parameter, None));
+		self
+	}
+}
+
+impl<'a> From<&'a str> for FilterPath<'a> {
+	fn from(parameter: &'a str) -> Self {
+		Self::new(parameter)
+	}
+}
+
+impl<'a> Display for FilterPath<'a> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		for (i, (parameter, filter)) in self.segments.iter().enumerate() {
+			if i > 0 {
+				f.write_str(".")?;
+			}
+			f.write_str(parameter)?;
+			if let Some(filter) = filter {
+				write!(f, "[{filter}]")?;
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Comparison operation in a `_filter` expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FilterOperator {
+	/// The value is equal to the parameter's value.
+	Eq,
+	/// The value is not equal to the parameter's value.
+	Ne,
+	/// The parameter's value contains the value.
+	Co,
+	/// The parameter's value starts with the value.
+	Sw,
+	/// The parameter's value ends with the value.
+	Ew,
+	/// The parameter's value is greater than the value.
+	Gt,
+	/// The parameter's value is less than the value.
+	Lt,
+	/// The parameter's value is greater than or equal to the value.
+	Ge,
+	/// The parameter's value is less than or equal to the value.
+	Le,
+	/// The parameter's value is approximately equal to the value.
+	Ap,
+	/// The parameter's value starts after the value.
+	Sa,
+	/// The parameter's value ends before the value.
+	Eb,
+	/// The parameter is present, given the value `true`, or absent.
+	Pr,
+	/// The parameter's period overlaps with the value.
+	Po,
+	/// The parameter's value subsumes the concept in the value.
+	Ss,
+	/// The parameter's value is subsumed by the concept in the value.
+	Sb,
+	/// The parameter's value is in the value set in the value.
+	In,
+	/// The parameter's value is not in the value set in the value.
+	Ni,
+	/// The parameter's value refers to the resource in the value.
+	Re,
+}
+
+impl FilterOperator {
+	/// Get the operator's code.
+	#[must_use]
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Eq => "eq",
+			Self::Ne => "ne",
+			Self::Co => "co",
+			Self::Sw => "sw",
+			Self::Ew => "ew",
+			Self::Gt => "gt",
+			Self::Lt => "lt",
+			Self::Ge => "ge",
+			Self::Le => "le",
+			Self::Ap => "ap",
+			Self::Sa => "sa",
+			Self::Eb => "eb",
+			Self::Pr => "pr",
+			Self::Po => "po",
+			Self::Ss => "ss",
+			Self::Sb => "sb",
+			Self::In => "in",
+			Self::Ni => "ni",
+			Self::Re => "re",
+		}
+	}
+}
+
+impl Display for FilterOperator {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+/// Value to compare with in a `_filter` expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FilterValue<'a> {
+	/// String value, which is quoted and escaped.
+	String(&'a str),
+	/// Number, date or token value, e.g. `5`, `2000-01-01` or
+	/// `http://loinc.org|1234-5`, which is written as is.
+	Token(&'a str),
+}
+
+impl<'a> Display for FilterValue<'a> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::String(value) => {
+				let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+				write!(f, "\"{escaped}\"")
+			}
+			Self::Token(value) => f.write_str(value),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn filter() {
+		let filter = Filter::compare("name", FilterOperator::Eq, FilterValue::String("pe\"ter"))
+			.and(
+				Filter::compare("birthdate", FilterOperator::Gt, FilterValue::Token("2000-01-01"))
+					.or(!Filter::compare("active", FilterOperator::Eq, FilterValue::Token("true"))),
+			)
+			.and(Filter::compare("gender", FilterOperator::Ne, FilterValue::Token("male")));
+		assert_eq!(
+			filter.into_query(),
+			(
+				"_filter".to_owned(),
+				"name eq \"pe\\\"ter\" and (birthdate gt 2000-01-01 or not (active eq true)) and \
+				 gender ne male"
+					.to_owned()
+			)
+		);
+	}
+
+	#[test]
+	fn filter_path() {
+		let path = FilterPath::new("related")
+			.filtered(Filter::compare("type", FilterOperator::Eq, FilterValue::Token("has-member")))
+			.then("target");
+		let filter = Filter::compare(path, FilterOperator::Pr, FilterValue::Token("true"));
+		assert_eq!(filter.to_string(), "related[type eq has-member].target pr true");
+	}
+}
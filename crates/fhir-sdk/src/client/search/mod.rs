@@ -1,19 +1,33 @@
 //! Search handling.
 
+mod compartment;
+mod control;
+mod filter;
 mod ordered;
+mod paging;
 mod params;
+mod system;
 
+pub use compartment::*;
+pub use control::*;
+pub use filter::*;
 pub use ordered::*;
 pub use params::*;
+pub use system::*;
 
 use std::hash::Hash;
 use std::marker::PhantomData;
 
 use async_trait::async_trait;
 use futures::{Future, Stream};
-use reqwest::Url;
+use reqwest::{
+	header::{self, HeaderValue},
+	Url,
+};
+use tracing::warn;
 
-use super::{Client, Error};
+use self::paging::{Page, Unpaged};
+use super::{Client, Error, FhirResource, FhirVersion, HttpRequest};
 
 /// A FHIR search that automatically resolves next pages
 #[derive(Debug, Clone)]
@@ -93,6 +107,14 @@ impl<E, R> Search<E, R> {
 		self.params.add_raw(key, value);
 	}
 
+	/// Send the search via `POST [type]/_search` with the parameters in a
+	/// form-encoded body instead of the URL. See
+	/// [`SearchParameters::via_post`].
+	pub fn via_post(mut self) -> Self {
+		self.params = self.params.via_post();
+		self
+	}
+
 	/// Add a search parameter as a string. Alias of [Search::with_raw].
 	///
 	/// Prefer [Search::and] if possible.
@@ -270,3 +292,182 @@ impl<V: 'static + Send> Client<V> {
 		Search::new().with_executor(self.clone())
 	}
 }
+
+#[async_trait]
+impl<V, R> PagedSearchExecutor<R> for Client<V>
+where
+	V: FhirVersion,
+	R: FhirResource<V> + 'static,
+{
+	type Stream = Page<V, R>;
+
+	#[allow(refining_impl_trait)]
+	async fn search_paged(
+		self,
+		params: SearchParameters<R>,
+		page_size: Option<u32>,
+	) -> Result<(Self::Stream, Option<NextPageCursor<Self, R>>), Error> {
+		let via_post = params.is_via_post();
+		let resource_type = R::TYPE.to_string();
+		let mut url = self.url(&[&resource_type]);
+		url.query_pairs_mut().extend_pairs(params.into_queries()).finish();
+
+		if let Some(page_size) = page_size {
+			url.query_pairs_mut().append_pair("_count", &page_size.to_string());
+		}
+
+		let (searchset, errors) = self.fetch_searchset(url, via_post).await?;
+		let page = Page::from_searchset(self.clone(), searchset)?.with_errors(errors);
+		Ok(page_with_cursor(self, page))
+	}
+
+	#[allow(refining_impl_trait)]
+	async fn fetch_next_page(
+		self,
+		url: Url,
+	) -> Result<(Self::Stream, Option<NextPageCursor<Self, R>>), Error> {
+		let (searchset, errors) = self.fetch_page(url).await?;
+		let page = Page::from_searchset(self.clone(), searchset)?.with_errors(errors);
+		Ok(page_with_cursor(self, page))
+	}
+}
+
+impl<V: FhirVersion> Client<V> {
+	/// Fetch the first searchset of a search given by the search URL. The
+	/// search is sent via `POST [type]/_search` with the query in a
+	/// form-encoded body if requested or if the URL exceeds the configured
+	/// length threshold.
+	async fn fetch_searchset(
+		&self,
+		mut url: Url,
+		via_post: bool,
+	) -> Result<(V::Bundle, Vec<Error>), Error> {
+		let too_long =
+			self.0.post_search_threshold.is_some_and(|threshold| url.as_str().len() > threshold);
+		if !via_post && !too_long {
+			return self.fetch_page(url).await;
+		}
+
+		let lenient = is_subsetted(&url);
+
+		let body = url.query().unwrap_or_default().to_owned();
+		url.set_query(None);
+		#[allow(clippy::expect_used)] // We made sure of it in the constructor.
+		url.path_segments_mut().expect("Base URL cannot be base").push("_search");
+		let request = HttpRequest::post(url)
+			.header(header::ACCEPT, HeaderValue::from_static(V::JSON_MIME_TYPE))
+			.header(
+				header::CONTENT_TYPE,
+				HeaderValue::from_static("application/x-www-form-urlencoded"),
+			)
+			.body(body);
+
+		self.run_request(request).await?.searchset(lenient).await
+	}
+
+	/// Fetch a searchset page from the URL. Pages of searches restricting the
+	/// returned elements via `_summary` or `_elements` are parsed leniently,
+	/// returning the errors of matches that cannot be parsed alongside the
+	/// Bundle.
+	async fn fetch_page(&self, url: Url) -> Result<(V::Bundle, Vec<Error>), Error> {
+		let lenient = is_subsetted(&url);
+		self.fetch_url(url).await?.searchset(lenient).await
+	}
+
+	/// Search for resources of the resource type with the raw query pairs,
+	/// yielding the matches of all pages. Used by the
+	/// [`AnyClient`](super::AnyClient), which does not know the resource types
+	/// at compile time.
+	pub(crate) async fn search_raw(
+		&self,
+		resource_type: V::ResourceType,
+		queries: &[(&str, &str)],
+	) -> Result<Unpaged<V, V::Resource>, Error> {
+		let mut url = self.url(&[&resource_type.to_string()]);
+		url.query_pairs_mut().extend_pairs(queries).finish();
+
+		let (searchset, errors) = self.fetch_searchset(url, false).await?;
+		Ok(Unpaged::from_page(Page::filtered(self.clone(), searchset)?.with_errors(errors)))
+	}
+}
+
+/// Whether the search URL restricts the returned elements via `_summary` or
+/// `_elements`, so that resources might lack required elements.
+fn is_subsetted(url: &Url) -> bool {
+	url.query_pairs().any(|(key, _)| key == "_summary" || key == "_elements")
+}
+
+/// Add a cursor to fetch the next page using the executor to the page of
+/// search results.
+fn page_with_cursor<V, R, E>(
+	executor: E,
+	page: Page<V, R>,
+) -> (Page<V, R>, Option<NextPageCursor<E, R>>)
+where
+	V: FhirVersion,
+	R: 'static,
+	E: PagedSearchExecutor<R> + 'static,
+{
+	let cursor = match page.next_page_url() {
+		Some(Ok(u)) => Some(NextPageCursor::new(executor, u)),
+		Some(Err(e)) => {
+			warn!("Unable to parse next page URL: {e}");
+
+			None
+		}
+		_ => None,
+	};
+
+	(page, cursor)
+}
+
+#[async_trait]
+impl<V, R> SearchExecutor<R> for Client<V>
+where
+	V: FhirVersion,
+	R: FhirResource<V> + 'static,
+{
+	type Stream = Unpaged<V, R>;
+
+	#[allow(refining_impl_trait)]
+	async fn search(self, params: SearchParameters<R>) -> Result<Unpaged<V, R>, Error> {
+		let via_post = params.is_via_post();
+		let resource_type = R::TYPE.to_string();
+		let mut url = self.url(&[&resource_type]);
+		url.query_pairs_mut().extend_pairs(params.into_queries()).finish();
+
+		let (searchset, errors) = self.fetch_searchset(url, via_post).await?;
+		let page = Page::from_searchset(self.clone(), searchset)?.with_errors(errors);
+
+		Ok(Unpaged::from_page(page))
+	}
+}
+
+/// Find the URL of the next page of the results returned in the Bundle.
+fn find_next_page_url<V: FhirVersion>(bundle: &V::Bundle) -> Option<Result<Url, Error>> {
+	let url_str = V::next_page_url(bundle)?;
+
+	Some(Url::parse(url_str).map_err(|_| Error::UrlParse(url_str.to_owned())))
+}
+
+#[cfg(all(test, feature = "r5"))]
+mod tests {
+	use fhir_model::r5::resources::Observation;
+
+	use crate::client::{Client, ExecutableSearch, FhirR5};
+
+	/// The search code is prone to run into rustc bugs [rust-lang/rust#100013](https://github.com/rust-lang/rust/issues/100013) and
+	/// [rust-lang/rust#102211](https://github.com/rust-lang/rust/issues/102211). We implemented a workaround for it.
+	/// This test is just there to prevent regressions. It doesn't actually test anything, we just need to make sure this compiles
+	#[allow(dead_code)]
+	async fn rustc_bug_workaround_inner() {
+		let client: Client<FhirR5> = Client::builder().build().unwrap();
+
+		fn assert_send<T: Send>(v: T) -> T {
+			v
+		}
+
+		// We don't actually test anything here, we just need to make sure this compiles
+		let _ = assert_send(client.search::<Observation>().send());
+	}
+}
@@ -0,0 +1,348 @@
+//! FHIR search paging functionality
+
+use std::{collections::VecDeque, marker::PhantomData, pin::Pin, task::Poll};
+
+use fhir_model::GenericResource;
+use futures::{future::BoxFuture, ready, Future, FutureExt, Stream, StreamExt};
+use reqwest::Url;
+
+use super::{find_next_page_url, Client, Error, Paged};
+use crate::client::{
+	references::populate_reference_targets, FhirResource, FhirVersion, SearchMatch,
+};
+
+/// Unwraps search pages into a single stream of resources. The resources
+/// can be consumed via the `Stream`/`StreamExt` traits.
+pub struct Unpaged<V: FhirVersion, R> {
+	/// The FHIR client to make further requests for the next pages.
+	client: Client<V>,
+	/// The current page of matches.
+	page: Page<V, R>,
+	/// Current future to retrieve the next page.
+	future_next_page: Option<BoxFuture<'static, Result<Page<V, R>, Error>>>,
+}
+
+impl<V, R> Unpaged<V, R>
+where
+	V: FhirVersion,
+	R: FhirResource<V> + 'static,
+{
+	/// Start up a new Unpaged<R> stream. Fails if the `Bundle` is not a
+	/// `searchset`.
+	pub fn from_searchset(client: Client<V>, searchset: V::Bundle) -> Result<Self, Error> {
+		let page = Page::from_searchset(client.clone(), searchset)?;
+
+		Ok(Self { client, page, future_next_page: None })
+	}
+}
+
+impl<V: FhirVersion, R> Unpaged<V, R> {
+	/// Start up a new Unpaged<R> stream from its first page.
+	pub(super) fn from_page(page: Page<V, R>) -> Self {
+		Self { client: page.client.clone(), page, future_next_page: None }
+	}
+}
+
+impl<V, R> Stream for Unpaged<V, R>
+where
+	V: FhirVersion,
+	R: 'static,
+{
+	type Item = Result<R, Error>;
+
+	fn poll_next(
+		mut self: Pin<&mut Self>,
+		cx: &mut std::task::Context<'_>,
+	) -> Poll<Option<Self::Item>> {
+		let span = tracing::trace_span!("Unpaged::poll_next");
+		let _span_guard = span.enter();
+
+		// If there are still matches left, get the next one
+		if !self.page.is_empty() {
+			tracing::trace!("Unpaged::page is not empty, polling for next match");
+			if let Poll::Ready(res) = self.page.poll_next_unpin(cx) {
+				if let Some(r) = res {
+					tracing::debug!("Next match in Unpaged::matches available");
+					return Poll::Ready(Some(r));
+				} else {
+					tracing::debug!("Unpaged::matches is empty, waiting for next page");
+				}
+			}
+		}
+
+		if let Some(future_next_page) = self.future_next_page.as_mut() {
+			tracing::trace!("Unpaged::future_next_page is set, polling for next page");
+			if let Poll::Ready(next_page) = future_next_page.as_mut().poll(cx) {
+				self.future_next_page = None;
+				tracing::debug!("Next page fetched and ready");
+
+				self.page = match next_page {
+					Ok(page) => page,
+					Err(e) => {
+						tracing::error!("Fetching next page returned error: {}", e);
+
+						return Poll::Ready(Some(Err(e)));
+					}
+				};
+			}
+		// Start retrieving the next page if we have a next URL and there is no next page being fetched.
+		} else if let Some(next_page) = self.page.next_page() {
+			tracing::debug!("Current page has next URL, starting to fetch next page");
+			self.future_next_page = Some(next_page.boxed());
+		}
+
+		// Else check if all resources were consumed or if we are waiting for new
+		// resources to arrive.
+		if !self.page.is_empty() {
+			tracing::trace!("Unpaged results waiting for remaining resources in current page");
+			cx.waker().wake_by_ref();
+			Poll::Pending
+		} else if self.future_next_page.is_some() {
+			tracing::trace!("Unpaged results waiting for response to next URL fetch");
+			cx.waker().wake_by_ref();
+			Poll::Pending
+		} else {
+			tracing::debug!("Unpaged results exhausted");
+			Poll::Ready(None)
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let (page_min, page_max) = self.page.size_hint();
+
+		if self.page.next_page().is_some() {
+			(page_min, None)
+		} else {
+			(page_min, page_max)
+		}
+	}
+}
+
+async fn fetch_resource<V: FhirVersion>(client: Client<V>, url: Url) -> Result<V::Resource, Error> {
+	let url_str = url.to_string();
+
+	// Make sure we are not forwarded to any malicious server.
+	if url.origin() != client.0.base_url.origin() {
+		return Err(Error::DifferentOrigin(url_str));
+	}
+
+	// Fetch a single resource from the given URL.
+	let resource = client.read_generic(url).await?;
+	resource.ok_or_else(|| Error::ResourceNotFound(url_str))
+}
+
+impl<V: FhirVersion, R> std::fmt::Debug for Unpaged<V, R> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("Unpaged")
+			.field("client", &self.client)
+			.field("page", &self.page)
+			.field("future_next_page", &self.future_next_page.as_ref().map(|_| "_"))
+			.finish()
+	}
+}
+
+/// Stream that yields each match entry in a searchset Bundle. Tries to fetch entries from the fullUrl property
+/// if the resource field is empty. Fills resource reference target fields with the referred to resources,
+/// if available in the Bundle.
+pub struct Page<V: FhirVersion, R> {
+	client: Client<V>,
+	bundle: V::Bundle,
+	matches: VecDeque<SearchMatch<V::Resource>>,
+	future_resource: Option<BoxFuture<'static, Result<V::Resource, Error>>>,
+	/// Errors of matches that could not be parsed, yielded before the matches.
+	errors: VecDeque<Error>,
+	/// Conversion of the matched resources to `R`, skipping them on `None`.
+	convert: Converter<V, R>,
+	/// Whether the conversion skips matches, making the number of results unknown.
+	skips_matches: bool,
+	resource_type: PhantomData<fn() -> R>,
+}
+
+/// Conversion of matched resources to the search's resource type. Returns
+/// `None` for matches that are to be skipped.
+type Converter<V, R> = fn(<V as FhirVersion>::Resource) -> Option<Result<R, Error>>;
+
+impl<V, R> Page<V, R>
+where
+	V: FhirVersion,
+	R: FhirResource<V> + 'static,
+{
+	/// Create a page from the searchset. Fails if the `Bundle` is not a
+	/// `searchset`.
+	pub fn from_searchset(client: Client<V>, bundle: V::Bundle) -> Result<Page<V, R>, Error> {
+		Self::with_converter(client, bundle, false, |resource| {
+			let resource_type = resource.resource_type();
+			Some(resource.try_into().map_err(|_| {
+				Error::WrongResourceType(resource_type.to_string(), R::TYPE.to_string())
+			}))
+		})
+	}
+}
+
+impl<V, R> Page<V, R>
+where
+	V: FhirVersion,
+	R: TryFrom<V::Resource> + 'static,
+{
+	/// Create a page from the searchset, skipping all matches that cannot be
+	/// converted to `R`. Fails if the `Bundle` is not a `searchset`.
+	pub(super) fn filtered(client: Client<V>, bundle: V::Bundle) -> Result<Page<V, R>, Error> {
+		Self::with_converter(client, bundle, true, |resource| resource.try_into().ok().map(Ok))
+	}
+}
+
+impl<V: FhirVersion, R> Page<V, R> {
+	/// Create a page from the searchset, converting the matches using the
+	/// given conversion. Fails if the `Bundle` is not a `searchset`.
+	fn with_converter(
+		client: Client<V>,
+		bundle: V::Bundle,
+		skips_matches: bool,
+		convert: Converter<V, R>,
+	) -> Result<Self, Error> {
+		let matches = V::search_matches(&bundle)?.into();
+
+		Ok(Self {
+			client,
+			bundle,
+			matches,
+			future_resource: None,
+			errors: VecDeque::new(),
+			convert,
+			skips_matches,
+			resource_type: PhantomData,
+		})
+	}
+
+	/// Add the errors of matches that could not be parsed, which are yielded
+	/// before the remaining matches.
+	pub(super) fn with_errors(mut self, errors: Vec<Error>) -> Self {
+		self.errors.extend(errors);
+		self
+	}
+
+	fn is_empty(&self) -> bool {
+		self.matches.is_empty() && self.errors.is_empty()
+	}
+
+	/// Get the URL of the next page, if there is one.
+	pub(super) fn next_page_url(&self) -> Option<Result<Url, Error>> {
+		find_next_page_url::<V>(&self.bundle)
+	}
+
+	/// Populate the reference targets of a matched resource and convert it to
+	/// `R`. Returns `None` if the match is skipped.
+	fn resolve_match(&self, mut resource: V::Resource) -> Option<Result<R, Error>> {
+		populate_reference_targets::<V>(&self.client.0.base_url, &mut resource, Some(&self.bundle));
+
+		(self.convert)(resource)
+	}
+}
+
+impl<V, R> Paged<R> for Page<V, R>
+where
+	V: FhirVersion,
+	R: 'static,
+{
+	fn next_page(&self) -> Option<impl Future<Output = Result<Self, Error>> + 'static> {
+		let next_url = self.next_page_url()?;
+		let client = self.client.clone();
+		let (skips_matches, convert) = (self.skips_matches, self.convert);
+
+		let fut = async move {
+			let (searchset, errors) = client.fetch_page(next_url?).await?;
+
+			Ok(Page::with_converter(client, searchset, skips_matches, convert)?.with_errors(errors))
+		};
+
+		Some(fut.boxed())
+	}
+}
+
+impl<V, R> Stream for Page<V, R>
+where
+	V: FhirVersion,
+	R: 'static,
+{
+	type Item = Result<R, Error>;
+
+	fn poll_next(
+		mut self: Pin<&mut Self>,
+		cx: &mut std::task::Context<'_>,
+	) -> Poll<Option<Self::Item>> {
+		let span = tracing::trace_span!("SearchMatches::poll_next");
+		let _span_guard = span.enter();
+
+		// Check on resource fetch future first to output as the next resource.
+		if let Some(future_resource) = self.future_resource.as_mut() {
+			match ready!(future_resource.as_mut().poll(cx)) {
+				Ok(resource) => {
+					tracing::trace!("Next `fullUrl` fetched resource ready");
+
+					self.future_resource = None;
+
+					if let Some(result) = self.resolve_match(resource) {
+						return Poll::Ready(Some(result));
+					}
+				}
+				Err(e) => return Poll::Ready(Some(Err(e))),
+			}
+		}
+
+		if let Some(error) = self.errors.pop_front() {
+			tracing::debug!("Returning error of Bundle entry that could not be parsed");
+
+			return Poll::Ready(Some(Err(error)));
+		}
+
+		// Otherwise get the next match from the list
+		while let Some(entry) = self.matches.pop_front() {
+			if let Some(resource) = entry.resource {
+				if let Some(result) = self.resolve_match(resource) {
+					tracing::debug!("Found next Bundle entry to return");
+
+					return Poll::Ready(Some(result));
+				}
+				tracing::trace!("Skipping Bundle entry of other resource type");
+			} else if let Some(url) = entry.full_url {
+				if let Ok(url) = Url::parse(&url) {
+					tracing::debug!("Next entry needs to be fetched, starting to fetch it");
+
+					self.future_resource = Some(fetch_resource(self.client.clone(), url).boxed());
+					cx.waker().wake_by_ref();
+
+					return Poll::Pending;
+				} else {
+					tracing::error!("Could not parse next entry URL");
+
+					return Poll::Ready(Some(Err(Error::UrlParse(url))));
+				}
+			}
+		}
+
+		tracing::trace!("SearchMatches exhausted");
+		Poll::Ready(None)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let (size, errors) = (self.matches.len(), self.errors.len());
+
+		if self.skips_matches {
+			(errors, Some(errors + size))
+		} else {
+			(errors + size, Some(errors + size))
+		}
+	}
+}
+
+impl<V: FhirVersion, R> std::fmt::Debug for Page<V, R> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("Page")
+			.field("client", &self.client)
+			.field("bundle", &self.bundle)
+			.field("matches", &self.matches)
+			.field("future_resource", &self.future_resource.as_ref().map(|_| "_"))
+			.field("errors", &self.errors)
+			.finish()
+	}
+}
@@ -1,7 +1,17 @@
 //! Search parameters
 
+use std::convert::Infallible;
 use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
+use std::str::FromStr;
+
+use fhir_model::{
+	ParsedReference, ReferenceSearchParameter, ResourceSearchParameterDefinition,
+	SearchableResource,
+};
+use thiserror::Error;
+
+use crate::client::{FhirResource, FhirVersion};
 
 /// A collection of AND-joined search parameters.
 #[derive(Debug)]
@@ -9,12 +19,19 @@ pub struct SearchParameters<R> {
 	/// List of search queries.
 	queries: Vec<(String, String)>,
 
+	/// Whether to send the search via `POST`.
+	via_post: bool,
+
 	resource_type: PhantomData<R>,
 }
 
 impl<R> Clone for SearchParameters<R> {
 	fn clone(&self) -> Self {
-		Self { queries: self.queries.clone(), resource_type: PhantomData::default() }
+		Self {
+			queries: self.queries.clone(),
+			via_post: self.via_post,
+			resource_type: PhantomData::default(),
+		}
 	}
 }
 
@@ -42,7 +59,7 @@ impl<R> SearchParameters<R> {
 	/// Create a new list of [`SearchParameters`].
 	#[must_use]
 	pub fn empty() -> Self {
-		Self { queries: Vec::new(), resource_type: PhantomData }
+		Self { queries: Vec::new(), via_post: false, resource_type: PhantomData }
 	}
 
 	/// Initialize a new [`SearchParameters`] with a parameter
@@ -85,6 +102,21 @@ impl<R> SearchParameters<R> {
 		self
 	}
 
+	/// Send the search via `POST [type]/_search` with the parameters in a
+	/// form-encoded body instead of the URL, e.g. for long queries or to keep
+	/// sensitive values out of URLs. Next pages are still fetched via the
+	/// links returned by the server.
+	#[must_use]
+	pub fn via_post(mut self) -> Self {
+		self.via_post = true;
+		self
+	}
+
+	/// Whether the search is to be sent via `POST`.
+	pub(crate) fn is_via_post(&self) -> bool {
+		self.via_post
+	}
+
 	/// Convert to a list of raw queries.
 	pub(crate) fn into_queries(self) -> Vec<(String, String)> {
 		self.queries
@@ -109,6 +141,7 @@ impl<R> SearchParameters<R> {
 	}
 
 	pub(super) fn add_all(&mut self, parameters: SearchParameters<R>) {
+		self.via_post |= parameters.via_post;
 		for (key, value) in parameters.into_queries() {
 			self.add_raw(key, value);
 		}
@@ -215,6 +248,562 @@ where
 	}
 }
 
+/// Composite search, combining the values of the component search parameters
+/// with `$`, e.g. `code-value-quantity=http://loinc.org|1234$gt5`. Nest
+/// composite parameters for more than two components. Composite parameters
+/// do not support modifiers, so modifiers of the components are ignored.
+#[derive(Debug, Clone, Copy)]
+pub struct CompositeParam<A, B> {
+	/// The first component.
+	pub first: A,
+	/// The second component.
+	pub second: B,
+}
+
+impl<A, B> CompositeParam<A, B> {
+	/// Create a new `[CompositeParam]`
+	pub fn new(first: A, second: B) -> Self {
+		Self { first, second }
+	}
+}
+
+impl<A: SearchParameter, B: SearchParameter> SearchParameter for CompositeParam<A, B> {
+	fn query_value(&self) -> String {
+		format!("{}${}", self.first.query_value(), self.second.query_value())
+	}
+}
+
+/// Search for `Location`s near a geographic position using the special `near`
+/// search parameter, e.g. `near=-83.694810|42.256500|11.20|km`.
+#[derive(Debug, Clone, Copy)]
+pub struct NearParam<'a> {
+	/// Latitude in decimal degrees.
+	pub latitude: &'a str,
+	/// Longitude in decimal degrees.
+	pub longitude: &'a str,
+	/// Maximum distance from the position, in the server's default units if
+	/// no units are given.
+	pub distance: Option<&'a str>,
+	/// UCUM code of the distance's units, e.g. `km`.
+	pub units: Option<&'a str>,
+}
+
+impl<'a> NearParam<'a> {
+	/// Create a new `[NearParam]`
+	pub fn new(latitude: &'a str, longitude: &'a str) -> Self {
+		Self { latitude, longitude, distance: None, units: None }
+	}
+
+	/// Set the maximum distance in the given units
+	pub fn within(mut self, distance: &'a str, units: &'a str) -> Self {
+		self.distance = Some(distance);
+		self.units = Some(units);
+		self
+	}
+}
+
+impl<'a> SearchParameter for NearParam<'a> {
+	fn query_value(&self) -> String {
+		let mut value = format!("{}|{}", escape_value(self.latitude), escape_value(self.longitude));
+
+		if self.distance.is_some() || self.units.is_some() {
+			value = format!("{value}|{}", escape_value(self.distance.unwrap_or_default()));
+		}
+		if let Some(units) = self.units {
+			value = format!("{value}|{}", escape_value(units));
+		}
+
+		value
+	}
+}
+
+/// Number search.
+///
+/// Only implements most common functionality. Refer to adding raw queries when
+/// this does not suffice.
+#[derive(Debug, Clone)]
+pub struct NumberParam<'a, V: FhirVersion> {
+	/// How to compare the value
+	pub comparator: Option<V::SearchComparator>,
+	/// The numeric value
+	pub value: &'a str,
+}
+
+impl<'a, V: FhirVersion> NumberParam<'a, V> {
+	/// Create a new `[NumberParam]`
+	pub fn new(value: &'a str) -> Self {
+		Self { comparator: None, value }
+	}
+
+	/// Set a comparator
+	pub fn comparator(mut self, comparator: V::SearchComparator) -> Self {
+		self.comparator = Some(comparator);
+		self
+	}
+}
+
+impl<'a, V: FhirVersion> SearchParameter for NumberParam<'a, V> {
+	fn query_value(&self) -> String {
+		let value = escape_value(self.value);
+
+		if let Some(comparator) = self.comparator {
+			format!("{}{}", comparator.as_ref(), value)
+		} else {
+			value
+		}
+	}
+}
+
+/// Date search.
+///
+/// Only implements most common functionality. Refer to adding raw queries when
+/// this does not suffice.
+#[derive(Debug, Clone, Copy)]
+pub struct DateParam<'a, V: FhirVersion> {
+	/// Search comparator to compare the date.
+	pub comparator: Option<V::SearchComparator>,
+	/// Value to search for.
+	pub value: &'a str,
+}
+
+impl<'a, V: FhirVersion> DateParam<'a, V> {
+	/// Create a new `[DateParam]`
+	pub fn new(value: &'a str) -> Self {
+		Self { comparator: None, value }
+	}
+
+	/// Set a comparator
+	pub fn comparator(mut self, comparator: V::SearchComparator) -> Self {
+		self.comparator = Some(comparator);
+		self
+	}
+}
+
+impl<'a, V: FhirVersion> SearchParameter for DateParam<'a, V> {
+	fn query_value(&self) -> String {
+		let value = escape_value(self.value);
+
+		if let Some(comparator) = self.comparator {
+			format!("{}{}", comparator.as_ref(), value)
+		} else {
+			value
+		}
+	}
+}
+
+/// String search.
+///
+/// Only implements most common functionality. Refer to adding raw queries when
+/// this does not suffice.
+#[derive(Debug, Clone, Copy)]
+pub enum StringParam<'a> {
+	/// Standard string search. This is a case-insensitive starts-with search.
+	Standard(&'a str),
+	/// Search a string that contains the given value.
+	Contains(&'a str),
+	/// Search a string that matches exactly the value, including casing and
+	/// accents.
+	Exact(&'a str),
+}
+
+impl<'a> SearchParameter for StringParam<'a> {
+	fn modifier(&self) -> Option<&str> {
+		match self {
+			Self::Contains(_) => Some("contains"),
+			Self::Exact(_) => Some("exact"),
+			_ => None,
+		}
+	}
+
+	fn query_value(&self) -> String {
+		match self {
+			Self::Standard(v) => escape_value(v),
+			Self::Contains(v) => escape_value(v),
+			Self::Exact(v) => escape_value(v),
+		}
+	}
+}
+
+/// Search in references. Includes chaining, i.e. querying fields of the target
+/// resource.
+///
+/// Only implements most common functionality. Refer to adding raw queries when
+/// this does not suffice.
+#[derive(Debug, Clone, Copy)]
+pub enum ReferenceParam<'a, V: FhirVersion, I = Infallible> {
+	/// Standard reference search by relative reference.
+	Standard {
+		/// Resource type of the resource.
+		resource_type: V::ResourceType,
+		/// ID of the resource the reference should point to.
+		id: &'a str,
+		/// Historic version id to search for.
+		version_id: Option<&'a str>,
+	},
+	/// Standard reference search by absolute URL.
+	Url {
+		/// The FHIR base URL for FHIR server references, or the whole
+		/// URL for non-FHIR server URLs or URNs
+		base_url: &'a str,
+		/// Resource type
+		resource_type: Option<&'a str>,
+		/// Resource ID
+		id: Option<&'a str>,
+		/// Specific version id to search for.
+		version_id: Option<&'a str>,
+	},
+	/// Reference search by the `.identifier` field. Not available in STU3,
+	/// where `I` is [`Infallible`].
+	Identifier(I),
+}
+
+impl<'a, V: FhirVersion, I> ReferenceParam<'a, V, I> {
+	/// Reference to the resource with the given ID
+	pub fn new(resource_type: V::ResourceType, id: &'a str) -> Self {
+		Self::versioned(resource_type, id, None)
+	}
+
+	/// Create a new versioned reference parameter
+	pub fn versioned(
+		resource_type: V::ResourceType,
+		id: &'a str,
+		version_id: Option<&'a str>,
+	) -> Self {
+		Self::Standard { resource_type, id, version_id }
+	}
+}
+
+/// Local references (to contained resources) cannot be used as search parameters
+#[derive(Debug, Error)]
+#[error("local references cannot be used as FHIR search parameters")]
+pub struct LocalReferenceUnusableAsParameter;
+
+impl<'a, V: FhirVersion, I> TryFrom<ParsedReference<'a>> for ReferenceParam<'a, V, I> {
+	type Error = LocalReferenceUnusableAsParameter;
+
+	fn try_from(reference: ParsedReference<'a>) -> Result<Self, Self::Error> {
+		match reference {
+			ParsedReference::Local { .. } => Err(LocalReferenceUnusableAsParameter),
+			ParsedReference::Relative { resource_type, id, version_id } => {
+				Ok(ReferenceParam::versioned(
+					V::ResourceType::from_str(resource_type).unwrap(),
+					id,
+					version_id,
+				))
+			}
+			ParsedReference::Absolute { base_url, resource_type, id, version_id } => {
+				Ok(ReferenceParam::Url { base_url, resource_type, id, version_id })
+			}
+		}
+	}
+}
+
+impl<'a, V: FhirVersion, I: SearchParameter> SearchParameter for ReferenceParam<'a, V, I> {
+	fn modifier(&self) -> Option<&str> {
+		match self {
+			ReferenceParam::Identifier { .. } => Some("identifier"),
+			_ => None,
+		}
+	}
+
+	fn query_value(&self) -> String {
+		match self {
+			Self::Standard { resource_type, id, version_id: Some(version_id) } => {
+				escape_value(&format!("{resource_type}/{id}/_history/{version_id}"))
+			}
+			Self::Standard { resource_type, id, version_id: None } => {
+				escape_value(&format!("{resource_type}/{id}"))
+			}
+			Self::Url { base_url, resource_type: None, id: None, version_id: None } => {
+				escape_value(base_url)
+			}
+			Self::Url {
+				base_url,
+				resource_type: Some(resource_type),
+				id: Some(id),
+				version_id: None,
+			} => escape_value(&format!("{base_url}/{resource_type}/{id}")),
+			Self::Url {
+				base_url,
+				resource_type: Some(resource_type),
+				id: Some(id),
+				version_id: Some(version_id),
+			} => escape_value(&format!("{base_url}/{resource_type}/{id}/_history/{version_id}")),
+			Self::Url { base_url, resource_type: None, id: None, version_id: Some(version_id) } => {
+				format!("{}|{}", escape_value(base_url), escape_value(version_id))
+			}
+			Self::Identifier(token) => token.query_value(),
+			_ => panic!("Invalid reference parameter"),
+		}
+	}
+}
+
+/// No search parameter, for variants of parameters that are not available in
+/// a FHIR version.
+impl SearchParameter for Infallible {
+	fn query_value(&self) -> String {
+		match *self {}
+	}
+}
+
+/// Search on a quantity.
+///
+/// Only implements most common functionality. Refer to adding raw queries when
+/// this does not suffice.
+#[derive(Debug, Clone, Copy)]
+pub struct QuantityParam<'a, V: FhirVersion> {
+	/// Search comparator to compare the date.
+	pub comparator: Option<V::SearchComparator>,
+	/// Value to search for.
+	pub value: &'a str,
+	/// Optional system.
+	pub system: Option<&'a str>,
+	/// Optional code.
+	pub code: Option<&'a str>,
+}
+
+impl<'a, V: FhirVersion> QuantityParam<'a, V> {
+	/// Quantity with a given value
+	pub fn new(value: &'a str) -> Self {
+		Self { comparator: None, value, system: None, code: None }
+	}
+
+	/// Quantity with a given value
+	pub fn with_comparator(mut self, comparator: V::SearchComparator) -> Self {
+		self.comparator = Some(comparator);
+		self
+	}
+}
+
+impl<'a, V: FhirVersion> SearchParameter for QuantityParam<'a, V> {
+	fn query_value(&self) -> String {
+		let value = if let Some(comparator) = self.comparator {
+			format!("{}{}", comparator.as_ref(), escape_value(self.value))
+		} else {
+			escape_value(self.value)
+		};
+
+		if self.system.is_some() || self.code.is_some() {
+			format!(
+				"{value}|{}|{}",
+				escape_value(self.system.unwrap_or_default()),
+				escape_value(self.code.unwrap_or_default())
+			)
+		} else {
+			value
+		}
+	}
+}
+
+/// Search on a URI.
+///
+/// Only implements most common functionality. Refer to adding raw queries when
+/// this does not suffice.
+#[derive(Debug, Clone, Copy)]
+pub enum UriParam<'a> {
+	/// Standard URI search, that matches exactly.
+	Standard(&'a str),
+	/// Match any URL that is below the given URL path, so contains more URL
+	/// segments.
+	Below(&'a str),
+	/// Match any URL that is above the given URL path, so contains less URL
+	/// segments.
+	Above(&'a str),
+}
+
+impl<'a> UriParam<'a> {
+	/// Create a new URI search parameter
+	pub fn new(value: &'a str) -> Self {
+		Self::Standard(value)
+	}
+}
+
+impl<'a> SearchParameter for UriParam<'a> {
+	fn modifier(&self) -> Option<&str> {
+		match self {
+			UriParam::Below(_) => Some("below"),
+			UriParam::Above(_) => Some("above"),
+			_ => None,
+		}
+	}
+
+	fn query_value(&self) -> String {
+		let uri = match self {
+			Self::Standard(u) => u,
+			Self::Below(u) => u,
+			Self::Above(u) => u,
+		};
+
+		escape_value(uri)
+	}
+}
+
+/// Search on any item whether it is a missing field using the `missing`
+/// modifier.
+#[derive(Debug, Clone, Copy)]
+pub struct MissingParam(bool);
+
+impl SearchParameter for MissingParam {
+	fn modifier(&self) -> Option<&str> {
+		Some("missing")
+	}
+
+	fn query_value(&self) -> String {
+		self.0.to_string()
+	}
+}
+
+/// Chain of reference search parameters to resources of type `T`, e.g.
+/// `subject:Patient`. Search parameters of `T` can be used on the chain via
+/// [Chain::with].
+#[derive(Debug, Clone)]
+pub struct Chain<V: FhirVersion, T> {
+	/// Reference search parameter codes with the respective target resource
+	/// type.
+	links: Vec<(&'static str, V::ResourceType)>,
+	/// Resource type at the end of the chain.
+	target: PhantomData<fn() -> T>,
+}
+
+impl<V, T> Chain<V, T>
+where
+	V: FhirVersion,
+	T: FhirResource<V> + SearchableResource,
+{
+	/// Start a chain at the reference search parameter of the searched
+	/// resource, e.g. `ObservationSubjectSearchReference`. `T` has to be one
+	/// of the resources the reference can refer to.
+	pub fn new<P>(reference: P) -> Self
+	where
+		P: ReferenceSearchParameter,
+		T: Into<P::Target>,
+	{
+		Self { links: vec![(reference.parameter().code(), T::TYPE)], target: PhantomData }
+	}
+
+	/// Continue the chain with a reference search parameter of `T` to
+	/// resources of type `U`, which has to be one of the resources the
+	/// reference can refer to.
+	pub fn then<P, U>(mut self, reference: P) -> Chain<V, U>
+	where
+		P: ReferenceSearchParameter<Resource = T>,
+		U: FhirResource<V> + SearchableResource + Into<P::Target>,
+	{
+		self.links.push((reference.parameter().code(), U::TYPE));
+		Chain { links: self.links, target: PhantomData }
+	}
+
+	/// Search by a search parameter of the resources at the end of the chain.
+	pub fn with<P: SearchParameter>(self, parameter: T::Params, value: P) -> ChainedParam<V, P> {
+		ChainedParam { links: self.links, code: parameter.code(), value }
+	}
+}
+
+/// Chained search parameter, searching by a search parameter of referenced
+/// resources, e.g. `subject:Patient.name=peter`. Created via [Chain::with].
+#[derive(Debug, Clone)]
+pub struct ChainedParam<V: FhirVersion, P> {
+	/// Reference search parameter codes with the respective target resource
+	/// type.
+	links: Vec<(&'static str, V::ResourceType)>,
+	/// Code of the search parameter of the referenced resources.
+	code: &'static str,
+	/// Value of the search parameter.
+	value: P,
+}
+
+impl<V: FhirVersion, P: SearchParameter> IntoQuery for ChainedParam<V, P> {
+	fn into_query(self) -> (String, String) {
+		let mut name = String::new();
+		for (reference, target_type) in self.links {
+			name += &format!("{reference}:{target_type}.");
+		}
+		name += self.code;
+
+		if let Some(modifier) = self.value.modifier() {
+			name = format!("{name}:{modifier}");
+		}
+
+		(name, self.value.query_value())
+	}
+}
+
+/// Reverse chain (`_has`) of resources of type `T` referring to the searched
+/// resources of type `R`, e.g. `_has:Observation:patient`. Search parameters of
+/// `T` can be used on the chain via [ReverseChain::with].
+#[derive(Debug, Clone)]
+pub struct ReverseChain<V: FhirVersion, R, T> {
+	/// Referring resource types with their reference search parameter codes.
+	links: Vec<(V::ResourceType, &'static str)>,
+	/// Searched resource type and resource type at the end of the chain.
+	types: PhantomData<fn() -> (R, T)>,
+}
+
+impl<V, R, T> ReverseChain<V, R, T>
+where
+	V: FhirVersion,
+	T: FhirResource<V> + SearchableResource,
+{
+	/// Start a reverse chain of resources of type `T` referring to the searched
+	/// resources via the reference search parameter of `T`. `R` has to be one
+	/// of the resources the reference can refer to.
+	pub fn new<P>(reference: P) -> Self
+	where
+		P: ReferenceSearchParameter<Resource = T>,
+		R: Into<P::Target>,
+	{
+		Self { links: vec![(T::TYPE, reference.parameter().code())], types: PhantomData }
+	}
+
+	/// Continue the reverse chain with resources of type `U` referring to the
+	/// resources of type `T` via the reference search parameter of `U`. `T`
+	/// has to be one of the resources the reference can refer to.
+	pub fn then<P, U>(mut self, reference: P) -> ReverseChain<V, R, U>
+	where
+		P: ReferenceSearchParameter<Resource = U>,
+		T: Into<P::Target>,
+		U: FhirResource<V> + SearchableResource,
+	{
+		self.links.push((U::TYPE, reference.parameter().code()));
+		ReverseChain { links: self.links, types: PhantomData }
+	}
+
+	/// Search by a search parameter of the resources at the end of the
+	/// reverse chain.
+	pub fn with<P: SearchParameter>(self, parameter: T::Params, value: P) -> HasParam<V, P> {
+		HasParam { links: self.links, code: parameter.code(), value }
+	}
+}
+
+/// Reverse chained search parameter, searching for resources that are
+/// referred to by resources matching a search parameter, e.g.
+/// `_has:Observation:patient:code=1234-5`. Created via [ReverseChain::with].
+#[derive(Debug, Clone)]
+pub struct HasParam<V: FhirVersion, P> {
+	/// Referring resource types with their reference search parameter codes.
+	links: Vec<(V::ResourceType, &'static str)>,
+	/// Code of the search parameter of the referring resources.
+	code: &'static str,
+	/// Value of the search parameter.
+	value: P,
+}
+
+impl<V: FhirVersion, P: SearchParameter> IntoQuery for HasParam<V, P> {
+	fn into_query(self) -> (String, String) {
+		let mut name = String::new();
+		for (source_type, reference) in self.links {
+			name += &format!("_has:{source_type}:{reference}:");
+		}
+		name += self.code;
+
+		if let Some(modifier) = self.value.modifier() {
+			name = format!("{name}:{modifier}");
+		}
+
+		(name, self.value.query_value())
+	}
+}
+
 /// Escape a search parameter value.
 pub(crate) fn escape_value(value: &str) -> String {
 	value.replace('\\', "\\\\").replace('|', "\\|").replace('$', "\\$").replace(',', "\\,")
@@ -247,6 +836,66 @@ mod tests {
 		assert_eq!(params.query_value(), "bla,ble");
 	}
 
+	#[test]
+	fn composite() {
+		let composite = CompositeParam::new(
+			MyParam { modifier: None, value: "http://loinc.org|1234" },
+			MyParam { modifier: Some("mod"), value: "gt5" },
+		);
+		assert_eq!(composite.query_value(), "http://loinc.org|1234$gt5");
+
+		let composite = CompositeParam::new(composite, MyParam { modifier: None, value: "x" });
+		assert_eq!(composite.query_value(), "http://loinc.org|1234$gt5$x");
+	}
+
+	#[test]
+	fn near() {
+		let near = NearParam::new("-83.694810", "42.256500");
+		assert_eq!(near.query_value(), "-83.694810|42.256500");
+
+		let near = near.within("11.20", "km");
+		assert_eq!(near.query_value(), "-83.694810|42.256500|11.20|km");
+
+		let near = NearParam { distance: Some("5"), units: None, ..near };
+		assert_eq!(near.query_value(), "-83.694810|42.256500|5");
+	}
+
+	#[test]
+	fn missing() {
+		let missing = MissingParam(true);
+		assert_eq!(missing.modifier(), Some("missing"));
+		assert_eq!(missing.query_value(), ("true".to_owned()));
+	}
+
+	#[cfg(feature = "r5")]
+	#[test]
+	fn number() {
+		use fhir_model::r5::codes::SearchComparator;
+
+		use crate::client::FhirR5;
+
+		let number = NumberParam::<FhirR5> { comparator: Some(SearchComparator::Lt), value: "60" };
+		assert_eq!(number.query_value(), "lt60".to_owned());
+	}
+
+	#[cfg(feature = "r5")]
+	#[test]
+	fn quantity() {
+		use crate::client::FhirR5;
+
+		let quantity =
+			QuantityParam::<FhirR5> { comparator: None, value: "1.0", system: None, code: None };
+		assert_eq!(quantity.query_value(), "1.0".to_owned());
+
+		let quantity = QuantityParam::<FhirR5> {
+			comparator: None,
+			value: "1.0",
+			system: None,
+			code: Some("g"),
+		};
+		assert_eq!(quantity.query_value(), "1.0||g".to_owned());
+	}
+
 	#[test]
 	#[should_panic]
 	fn or_different_modifiers() {
@@ -0,0 +1,110 @@
+//! System-wide search across resource types.
+
+use async_trait::async_trait;
+use reqwest::Url;
+
+use super::{
+	page_with_cursor,
+	paging::{Page, Unpaged},
+	ExecutableSearch, NextPageCursor, PagedSearchExecutor, Search, SearchExecutor,
+	SearchParameters,
+};
+use crate::client::{Client, Error, FhirVersion};
+
+/// Executor of system-wide searches across all or multiple resource types,
+/// e.g. `[base]?_type=Patient,Practitioner`. Created via
+/// [`Client::search_system`].
+#[derive(Debug, Clone)]
+pub struct SystemSearch<V> {
+	/// The FHIR client.
+	client: Client<V>,
+}
+
+impl<V: FhirVersion> SystemSearch<V> {
+	/// Get the search URL on the server's base.
+	fn url<R>(&self, params: SearchParameters<R>) -> Url {
+		let mut url = self.client.url(&[]);
+		url.query_pairs_mut().extend_pairs(params.into_queries()).finish();
+		url
+	}
+}
+
+impl<V: FhirVersion> Client<V> {
+	/// Start constructing a system-wide search for FHIR resources of the given
+	/// types, or of all types if none are given. Only returns matches.
+	/// Populates reference target fields with any matching included resources.
+	///
+	/// Use `V::Resource` as `R` to get all matches. Any other type convertible
+	/// from `V::Resource`, e.g. an enum of the resources of interest, only
+	/// yields the matches that can be converted and skips the others.
+	pub fn search_system<R>(&self, types: &[V::ResourceType]) -> Search<SystemSearch<V>, R>
+	where
+		SystemSearch<V>: SearchExecutor<R>,
+		<SystemSearch<V> as SearchExecutor<R>>::Stream: Send,
+		R: Send,
+	{
+		let search = Search::new().with_executor(SystemSearch { client: self.clone() });
+		if types.is_empty() {
+			return search;
+		}
+
+		let types = types.iter().map(ToString::to_string).collect::<Vec<_>>().join(",");
+		search.with_raw("_type", types)
+	}
+}
+
+#[async_trait]
+impl<V, R> SearchExecutor<R> for SystemSearch<V>
+where
+	V: FhirVersion,
+	R: TryFrom<V::Resource> + Send + 'static,
+{
+	type Stream = Unpaged<V, R>;
+
+	#[allow(refining_impl_trait)]
+	async fn search(self, params: SearchParameters<R>) -> Result<Unpaged<V, R>, Error> {
+		let via_post = params.is_via_post();
+		let url = self.url(params);
+
+		let (searchset, errors) = self.client.fetch_searchset(url, via_post).await?;
+
+		Ok(Unpaged::from_page(Page::filtered(self.client, searchset)?.with_errors(errors)))
+	}
+}
+
+#[async_trait]
+impl<V, R> PagedSearchExecutor<R> for SystemSearch<V>
+where
+	V: FhirVersion,
+	R: TryFrom<V::Resource> + Send + 'static,
+{
+	type Stream = Page<V, R>;
+
+	#[allow(refining_impl_trait)]
+	async fn search_paged(
+		self,
+		params: SearchParameters<R>,
+		page_size: Option<u32>,
+	) -> Result<(Self::Stream, Option<NextPageCursor<Self, R>>), Error> {
+		let via_post = params.is_via_post();
+		let mut url = self.url(params);
+
+		if let Some(page_size) = page_size {
+			url.query_pairs_mut().append_pair("_count", &page_size.to_string());
+		}
+
+		let (searchset, errors) = self.client.fetch_searchset(url, via_post).await?;
+		let page = Page::filtered(self.client.clone(), searchset)?.with_errors(errors);
+		Ok(page_with_cursor(self, page))
+	}
+
+	#[allow(refining_impl_trait)]
+	async fn fetch_next_page(
+		self,
+		url: Url,
+	) -> Result<(Self::Stream, Option<NextPageCursor<Self, R>>), Error> {
+		let (searchset, errors) = self.client.fetch_page(url).await?;
+		let page = Page::filtered(self.client.clone(), searchset)?.with_errors(errors);
+		Ok(page_with_cursor(self, page))
+	}
+}
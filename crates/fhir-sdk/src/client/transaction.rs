@@ -0,0 +1,199 @@
+//! Implementation of building batch/transaction requests and processing the
+//! response.
+
+use fhir_model::GenericResource;
+use reqwest::header::{self, HeaderValue};
+use serde::Serialize;
+use uuid::Uuid;
+
+use super::{response::Response, Client, Error, FhirVersion, HttpRequest, PreferReturn};
+
+/// `Bundle` of a batch/transaction request.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestBundle<'a, R, E> {
+	/// Always `Bundle`.
+	resource_type: &'static str,
+	/// `batch` or `transaction`.
+	r#type: &'static str,
+	/// The entries.
+	entry: &'a [Entry<R, E>],
+}
+
+/// Entry in a batch/transaction `Bundle`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+enum Entry<R, E> {
+	/// Entry built by [`BatchTransaction`].
+	Request(RequestEntry<R>),
+	/// Raw entry given by the user.
+	Raw(E),
+}
+
+/// `Bundle.entry` built by [`BatchTransaction`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RequestEntry<R> {
+	/// Full URL of the resource.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	full_url: Option<String>,
+	/// The request to execute.
+	request: EntryRequest,
+	/// The resource to send.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	resource: Option<R>,
+}
+
+/// `Bundle.entry.request`.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EntryRequest {
+	/// HTTP verb.
+	method: &'static str,
+	/// URL relative to the base URL.
+	url: String,
+	/// Version ID to match for conditional updates.
+	#[serde(skip_serializing_if = "Option::is_none")]
+	if_match: Option<String>,
+}
+
+/// A batch/transaction request builder.
+#[derive(Debug, Clone)]
+#[must_use = "You probably want to send the batch/transaction"]
+pub struct BatchTransaction<V: FhirVersion> {
+	/// The FHIR client.
+	client: Client<V>,
+	/// If this is a transaction. Otherwise it is a batch request.
+	is_transaction: bool,
+	/// Current entries in the batch or transaction.
+	entries: Vec<Entry<V::Resource, V::BundleEntry>>,
+	/// Content the server is asked to return for the entries.
+	prefer: Option<PreferReturn>,
+}
+
+impl<V: FhirVersion> BatchTransaction<V> {
+	/// Create new batch or transaction builder, given whether it is a
+	/// transaction.
+	pub fn new(client: Client<V>, is_transaction: bool) -> Self {
+		Self { client, is_transaction, entries: Vec::new(), prefer: None }
+	}
+
+	/// Add creation of a resource to the batch/transaction.
+	/// For advanced features like conditional creation via `ifNoneExists`,
+	/// please use `with_raw` for now.
+	///
+	/// Returns the temporary UUID to be used a reference that will be resolved
+	/// by the server for cross-referencing resources inside batch/transaction
+	/// requests.
+	pub fn create(&mut self, resource: impl Into<V::Resource>) -> String {
+		let resource: V::Resource = resource.into();
+		let uuid = format!("urn:uuid:{}", Uuid::new_v4());
+
+		let entry = RequestEntry {
+			full_url: Some(uuid.clone()),
+			request: EntryRequest {
+				method: "POST",
+				url: resource.resource_type().to_string(),
+				if_match: None,
+			},
+			resource: Some(resource),
+		};
+
+		self.entries.push(Entry::Request(entry));
+		uuid
+	}
+
+	/// Add update of a resource to the batch/transaction.
+	/// For advanced features like conditional creation, please use `with_raw`
+	/// for now.
+	pub fn update(
+		&mut self,
+		resource: impl Into<V::Resource>,
+		conditional: bool,
+	) -> Result<(), Error> {
+		let resource: V::Resource = resource.into();
+		let resource_type = resource.resource_type().to_string();
+		let resource_id = resource.resource_id().ok_or(Error::MissingId)?;
+		let full_url = self.client.url(&[&resource_type, resource_id]);
+		let url = format!("{resource_type}/{resource_id}");
+
+		let if_match = if conditional {
+			let version_id = resource.resource_version_id().ok_or(Error::MissingVersionId)?;
+			Some(format!("W/\"{version_id}\""))
+		} else {
+			None
+		};
+
+		let entry = RequestEntry {
+			full_url: Some(full_url.to_string()),
+			request: EntryRequest { method: "PUT", url, if_match },
+			resource: Some(resource),
+		};
+
+		self.entries.push(Entry::Request(entry));
+		Ok(())
+	}
+
+	/// Add deletion of a resource to the batch/transaction.
+	pub fn delete(&mut self, resource_type: V::ResourceType, id: &str) {
+		let url = format!("{resource_type}/{id}");
+
+		let entry = RequestEntry {
+			full_url: None,
+			request: EntryRequest { method: "DELETE", url, if_match: None },
+			resource: None,
+		};
+
+		self.entries.push(Entry::Request(entry));
+	}
+
+	/// Add retrieval of a resource to the batch/transaction.
+	pub fn read(&mut self, resource_type: V::ResourceType, id: &str) {
+		let url = format!("{resource_type}/{id}");
+
+		let entry = RequestEntry {
+			full_url: None,
+			request: EntryRequest { method: "GET", url, if_match: None },
+			resource: None,
+		};
+
+		self.entries.push(Entry::Request(entry));
+	}
+
+	/// Add a raw Bundle entry for more advanced queries.
+	pub fn with_raw(&mut self, entry: V::BundleEntry) {
+		self.entries.push(Entry::Raw(entry));
+	}
+
+	/// Ask the server to return the given content in the response entries via
+	/// the `Prefer` header, i.e. the stored resources in `entry.resource` or
+	/// `OperationOutcome`s in `entry.response.outcome`.
+	pub fn prefer(&mut self, prefer: PreferReturn) {
+		self.prefer = Some(prefer);
+	}
+
+	/// Send the batch or transaction to the server and receive the response.
+	pub async fn send(self) -> Result<V::Bundle, Error> {
+		self.send_with_response().await.map(Response::into_value)
+	}
+
+	/// Send the batch or transaction to the server and receive the response
+	/// `Bundle` along with the response metadata.
+	pub async fn send_with_response(self) -> Result<Response<V, V::Bundle>, Error> {
+		let bundle = RequestBundle {
+			resource_type: "Bundle",
+			r#type: if self.is_transaction { "transaction" } else { "batch" },
+			entry: &self.entries,
+		};
+
+		let url = self.client.url(&[]);
+		let request = HttpRequest::post(url)
+			.header(header::ACCEPT, HeaderValue::from_static(V::JSON_MIME_TYPE))
+			.header(header::CONTENT_TYPE, HeaderValue::from_static(V::JSON_MIME_TYPE))
+			.json(&bundle)?;
+
+		let response = self.client.run_request(PreferReturn::apply(self.prefer, request)).await?;
+
+		response.body_response().await
+	}
+}
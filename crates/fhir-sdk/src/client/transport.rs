@@ -0,0 +1,191 @@
+//! Pluggable HTTP transport of the client.
+//!
+//! The client builds [`HttpRequest`]s and hands them to a [`Transport`] to be
+//! sent. By default, [`ReqwestTransport`] is used, but any other HTTP
+//! implementation, an in-process test server or a recording proxy can be
+//! plugged in via [`ClientBuilder::transport`](super::ClientBuilder::transport).
+
+use std::{fmt::Debug, sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use reqwest::{
+	header::{self, HeaderMap, HeaderName, HeaderValue},
+	Method, StatusCode, Url,
+};
+use serde::Serialize;
+
+use super::Error;
+
+/// HTTP transport to send the client's requests with.
+#[async_trait]
+pub trait Transport: Debug + Send + Sync + 'static {
+	/// Send the request and return the response. Responses with error status
+	/// codes are regular responses and must not be turned into errors. Errors
+	/// are only for failures to get a response at all. Return
+	/// [`Error::Transport`] from custom transports.
+	async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error>;
+}
+
+#[async_trait]
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+	async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+		(**self).send(request).await
+	}
+}
+
+/// HTTP request to be sent via a [`Transport`].
+#[derive(Debug, Clone)]
+pub struct HttpRequest {
+	/// The HTTP method.
+	pub method: Method,
+	/// The request URL.
+	pub url: Url,
+	/// The request headers.
+	pub headers: HeaderMap,
+	/// The request body.
+	pub body: Option<Vec<u8>>,
+	/// Timeout for the whole request.
+	pub timeout: Option<Duration>,
+}
+
+impl HttpRequest {
+	/// Create a new request without headers and body.
+	#[must_use]
+	pub fn new(method: Method, url: Url) -> Self {
+		Self { method, url, headers: HeaderMap::new(), body: None, timeout: None }
+	}
+
+	/// Create a new `GET` request.
+	#[must_use]
+	pub fn get(url: Url) -> Self {
+		Self::new(Method::GET, url)
+	}
+
+	/// Create a new `POST` request.
+	#[must_use]
+	pub fn post(url: Url) -> Self {
+		Self::new(Method::POST, url)
+	}
+
+	/// Create a new `PUT` request.
+	#[must_use]
+	pub fn put(url: Url) -> Self {
+		Self::new(Method::PUT, url)
+	}
+
+	/// Create a new `PATCH` request.
+	#[must_use]
+	pub fn patch(url: Url) -> Self {
+		Self::new(Method::PATCH, url)
+	}
+
+	/// Create a new `DELETE` request.
+	#[must_use]
+	pub fn delete(url: Url) -> Self {
+		Self::new(Method::DELETE, url)
+	}
+
+	/// Set a header, replacing previous values.
+	#[must_use]
+	pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+		self.headers.insert(name, value);
+		self
+	}
+
+	/// Set the request body.
+	#[must_use]
+	pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+		self.body = Some(body.into());
+		self
+	}
+
+	/// Set the request body to the JSON serialization of the value. Sets the
+	/// `Content-Type` header to `application/json` if it is not set already.
+	pub fn json<T: Serialize + ?Sized>(mut self, value: &T) -> Result<Self, Error> {
+		self.body = Some(serde_json::to_vec(value)?);
+		self.headers
+			.entry(header::CONTENT_TYPE)
+			.or_insert(HeaderValue::from_static("application/json"));
+		Ok(self)
+	}
+
+	/// Set the request timeout.
+	#[must_use]
+	pub fn timeout(mut self, timeout: Option<Duration>) -> Self {
+		self.timeout = timeout;
+		self
+	}
+}
+
+/// HTTP response received via a [`Transport`].
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+	/// The response status code.
+	pub status: StatusCode,
+	/// The response headers.
+	pub headers: HeaderMap,
+	/// The full response body.
+	pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+	/// Create a new response without headers and body.
+	#[must_use]
+	pub fn new(status: StatusCode) -> Self {
+		Self { status, headers: HeaderMap::new(), body: Vec::new() }
+	}
+
+	/// Set a header, replacing previous values.
+	#[must_use]
+	pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+		self.headers.insert(name, value);
+		self
+	}
+
+	/// Set the response body.
+	#[must_use]
+	pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+		self.body = body.into();
+		self
+	}
+
+	/// Get the response body as text, replacing invalid UTF-8.
+	#[must_use]
+	pub fn text(&self) -> String {
+		String::from_utf8_lossy(&self.body).into_owned()
+	}
+}
+
+/// Default [`Transport`] using a [`reqwest::Client`].
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+	/// The inner HTTP client.
+	client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+	/// Create a new transport using the given [`reqwest::Client`].
+	#[must_use]
+	pub fn new(client: reqwest::Client) -> Self {
+		Self { client }
+	}
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+	async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+		let mut builder = self.client.request(request.method, request.url).headers(request.headers);
+		if let Some(body) = request.body {
+			builder = builder.body(body);
+		}
+		if let Some(timeout) = request.timeout {
+			builder = builder.timeout(timeout);
+		}
+
+		let response = builder.send().await?;
+		let status = response.status();
+		let headers = response.headers().clone();
+		let body = response.bytes().await?.to_vec();
+		Ok(HttpResponse { status, headers, body })
+	}
+}
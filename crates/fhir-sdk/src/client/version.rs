@@ -0,0 +1,127 @@
+//! Abstraction over the FHIR versions supported by the client.
+//!
+//! The client implementation is generic over [`FhirVersion`], each FHIR
+//! version only provides its model types and the few operations that differ
+//! between the versions' models. Access to resource IDs, meta data and
+//! references is shared with the models via [`FhirRelease`].
+
+use std::{
+	fmt::{Debug, Display},
+	str::FromStr,
+};
+
+use fhir_model::{FhirRelease, GenericReference, GenericResource, ParsedReference};
+use reqwest::StatusCode;
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::Error;
+
+/// Trait implemented by the FHIR version markers to provide the version's
+/// model types and version specific functionality.
+pub trait FhirVersion: Debug + Clone + Send + Sync + Unpin + 'static {
+	/// MIME type used in Accept and Content-Type headers
+	const JSON_MIME_TYPE: &'static str;
+
+	/// The model's marker type of the FHIR version.
+	type Release: FhirRelease<ResourceType = Self::ResourceType>;
+	/// The `Resource` enum of all resources.
+	type Resource: GenericResource<Self::Release>
+		+ Serialize
+		+ DeserializeOwned
+		+ Clone
+		+ Debug
+		+ Send
+		+ Sync
+		+ Unpin
+		+ 'static;
+	/// The `ResourceType` enum of all resource types.
+	type ResourceType: Copy + Debug + Display + FromStr + Send + Sync + 'static;
+	/// The `Bundle` resource.
+	type Bundle: TryFrom<Self::Resource> + Clone + Debug + Send + Sync + Unpin + 'static;
+	/// The `Bundle.entry` element.
+	type BundleEntry: Serialize + Clone + Debug + Send + Sync + 'static;
+	/// The `OperationOutcome` resource.
+	type OperationOutcome: TryFrom<Self::Resource> + Clone + Debug + Send + Sync + 'static;
+	/// The `CompartmentType` code enum.
+	type CompartmentType: Copy + Debug + Display + Send + Sync + 'static;
+	/// The `CapabilityStatement` resource.
+	type CapabilityStatement: TryFrom<Self::Resource> + Send + 'static;
+	/// The `Patient` resource.
+	type Patient: Serialize + Send + Sync + 'static;
+	/// The `Parameters.parameter` element.
+	type ParametersParameter: Serialize + Clone + Debug + Send + Sync + 'static;
+	/// The `Reference` data type.
+	type Reference: GenericReference<Self::Release> + Send + Sync;
+	/// The `SearchComparator` code enum.
+	type SearchComparator: AsRef<str> + Copy + Debug + Send + Sync + 'static;
+
+	/// Create a reference from the reference string, setting the targeted
+	/// resource type if it can be parsed from the string.
+	fn reference(reference: &str) -> Self::Reference;
+
+	/// Wrap an `OperationOutcome` returned by the server into an [`Error`].
+	fn operation_outcome_error(status: StatusCode, outcome: Self::OperationOutcome) -> Error;
+
+	/// Get the diagnostics of all issues in the `OperationOutcome`.
+	fn operation_outcome_diagnostics(outcome: &Self::OperationOutcome) -> Vec<&str>;
+
+	/// Get the contained resources of the resource, if it is a domain
+	/// resource.
+	fn contained(resource: &Self::Resource) -> Option<&Vec<Self::Resource>>;
+
+	/// Get the contained resources of the resource mutably, if it is a domain
+	/// resource.
+	fn contained_mut(resource: &mut Self::Resource) -> Option<&mut Vec<Self::Resource>>;
+
+	/// Set the targets of the resource's references, not including the
+	/// references of its contained resources, to the resources returned by
+	/// `resolve`. Returns the references whose resolved resource is of a type
+	/// the reference cannot target, together with that type.
+	fn set_reference_targets(
+		resource: &mut Self::Resource,
+		resolve: impl FnMut(&ParsedReference<'_>) -> Option<Self::Resource>,
+	) -> Vec<(String, Self::ResourceType)>;
+
+	/// Get the `Bundle`, if the resource is a `Bundle`.
+	fn as_bundle_mut(resource: &mut Self::Resource) -> Option<&mut Self::Bundle>;
+
+	/// Get the resources of the `Bundle`'s entries mutably.
+	fn bundle_resources_mut(bundle: &mut Self::Bundle) -> Vec<&mut Self::Resource>;
+
+	/// Resolve a non-local reference to a resource in the `Bundle`.
+	fn resolve_bundle_reference<'a>(
+		bundle: &'a Self::Bundle,
+		base_url: &str,
+		reference: &ParsedReference<'_>,
+	) -> Option<&'a Self::Resource>;
+
+	/// Get the URL of the next page of the search results in the `Bundle`.
+	fn next_page_url(bundle: &Self::Bundle) -> Option<&str>;
+
+	/// Get the search matches of a `searchset` Bundle. Fails if the `Bundle`
+	/// is not a `searchset`.
+	fn search_matches(bundle: &Self::Bundle) -> Result<Vec<SearchMatch<Self::Resource>>, Error>;
+}
+
+/// A matching entry in a search results `Bundle`.
+#[derive(Debug, Clone)]
+pub struct SearchMatch<R> {
+	/// The matching resource, if included in the `Bundle`.
+	pub resource: Option<R>,
+	/// The full URL of the resource to fetch it from, if the resource itself
+	/// is not included.
+	pub full_url: Option<String>,
+}
+
+/// Trait implemented by all resources of a FHIR version, used by the client
+/// to access the resources' type and to update their version ID. The ID and
+/// the rest of the meta data are accessed via [`GenericResource`].
+pub trait FhirResource<V: FhirVersion>:
+	GenericResource<V::Release> + Serialize + TryFrom<V::Resource> + Send + Sync
+{
+	/// The resource's type.
+	const TYPE: V::ResourceType;
+
+	/// Set the resource's version ID.
+	fn set_resource_version_id(&mut self, version_id: Option<String>);
+}
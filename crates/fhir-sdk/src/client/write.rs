@@ -1,18 +1,16 @@
 //! Home of the ResourceWrite trait.
 
 use async_trait::async_trait;
-#[cfg(feature = "r4b")]
-use fhir_model::r4b;
-#[cfg(feature = "r5")]
-use fhir_model::r5;
-#[cfg(feature = "stu3")]
-use fhir_model::stu3;
+use fhir_model::GenericResource;
+use reqwest::StatusCode;
 use serde::Serialize;
 
-use super::{error::Error, Client};
+use super::{error::Error, misc, Client, FhirResource, FhirVersion, PreferReturn};
 
 /// A trait to write resources to the FHIR server, mutating the interior id and
 /// version_id so that the resource is up to date for future update requests.
+/// The server is asked to return the stored resource, which replaces the local
+/// value if the server does so, including server-populated fields.
 #[async_trait]
 pub trait ResourceWrite<Version>: Serialize + Send + Sync {
 	/// Update the current version of the resource on the server. Returns
@@ -24,135 +22,40 @@ pub trait ResourceWrite<Version>: Serialize + Send + Sync {
 	async fn delete(self, client: &Client<Version>) -> Result<(), Error>;
 }
 
-#[cfg(feature = "r4b")]
 #[async_trait]
-impl<R> ResourceWrite<super::FhirR4B> for R
+impl<V, R> ResourceWrite<V> for R
 where
-	R: r4b::resources::NamedResource + r4b::resources::BaseResource + Serialize + Send + Sync,
+	V: FhirVersion,
+	R: FhirResource<V>,
 {
-	async fn update(
-		&mut self,
-		conditional: bool,
-		client: &Client<super::FhirR4B>,
-	) -> Result<bool, Error> {
-		let (created, version_id) = client.update(self, conditional).await?;
-		if let Some(meta) = self.meta_mut() {
-			meta.version_id = Some(version_id);
-		} else {
-			// Meta does not require any field and will succeed building.
-			#[allow(clippy::unwrap_used)]
-			self.set_meta(Some(
-				r4b::types::Meta::builder().version_id(version_id).build().unwrap(),
-			));
+	async fn update(&mut self, conditional: bool, client: &Client<V>) -> Result<bool, Error> {
+		let response =
+			client.update_returning(self, conditional, PreferReturn::Representation).await?;
+		let created = response.status() == StatusCode::CREATED;
+		let version_id = misc::parse_etag(response.headers());
+		match response.into_value() {
+			Some(resource) => *self = resource,
+			None => self.set_resource_version_id(Some(version_id?)),
 		}
 		Ok(created)
 	}
 
-	async fn create(&mut self, client: &Client<super::FhirR4B>) -> Result<String, Error> {
-		let (id, version_id) = client.create(self).await?;
-		self.set_id(Some(id.clone()));
-		if let Some(meta) = self.meta_mut() {
-			meta.version_id = version_id;
-		} else if let Some(version_id) = version_id {
-			// Meta does not require any field and will succeed building.
-			#[allow(clippy::unwrap_used)]
-			self.set_meta(Some(
-				r4b::types::Meta::builder().version_id(version_id).build().unwrap(),
-			));
+	async fn create(&mut self, client: &Client<V>) -> Result<String, Error> {
+		let response = client.create_returning(self, PreferReturn::Representation).await?;
+		let (id, _) = misc::parse_location(response.headers())?;
+		let version_id = response.version_id();
+		match response.into_value() {
+			Some(resource) => *self = resource,
+			None => {
+				self.set_resource_id(Some(id.clone()));
+				self.set_resource_version_id(version_id);
+			}
 		}
 		Ok(id)
 	}
 
-	async fn delete(self, client: &Client<super::FhirR4B>) -> Result<(), Error> {
-		let id = self.id().as_ref().ok_or(Error::MissingId)?;
-		client.delete(R::TYPE, id).await?;
-		Ok(())
-	}
-}
-
-#[cfg(feature = "r5")]
-#[async_trait]
-impl<R> ResourceWrite<super::FhirR5> for R
-where
-	R: r5::resources::NamedResource + r5::resources::BaseResource + Serialize + Send + Sync,
-{
-	async fn update(
-		&mut self,
-		conditional: bool,
-		client: &Client<super::FhirR5>,
-	) -> Result<bool, Error> {
-		let (created, version_id) = client.update(self, conditional).await?;
-		if let Some(meta) = self.meta_mut() {
-			meta.version_id = Some(version_id);
-		} else {
-			// Meta does not require any field and will succeed building.
-			#[allow(clippy::unwrap_used)]
-			self.set_meta(Some(r5::types::Meta::builder().version_id(version_id).build().unwrap()));
-		}
-		Ok(created)
-	}
-
-	async fn create(&mut self, client: &Client<super::FhirR5>) -> Result<String, Error> {
-		let (id, version_id) = client.create(self).await?;
-		self.set_id(Some(id.clone()));
-		if let Some(meta) = self.meta_mut() {
-			meta.version_id = version_id;
-		} else if let Some(version_id) = version_id {
-			// Meta does not require any field and will succeed building.
-			#[allow(clippy::unwrap_used)]
-			self.set_meta(Some(r5::types::Meta::builder().version_id(version_id).build().unwrap()));
-		}
-		Ok(id)
-	}
-
-	async fn delete(self, client: &Client<super::FhirR5>) -> Result<(), Error> {
-		let id = self.id().as_ref().ok_or(Error::MissingId)?;
-		client.delete(R::TYPE, id).await?;
-		Ok(())
-	}
-}
-
-#[cfg(feature = "stu3")]
-#[async_trait]
-impl<R> ResourceWrite<super::FhirStu3> for R
-where
-	R: stu3::resources::NamedResource + stu3::resources::BaseResource + Serialize + Send + Sync,
-{
-	async fn update(
-		&mut self,
-		conditional: bool,
-		client: &Client<super::FhirStu3>,
-	) -> Result<bool, Error> {
-		let (created, version_id) = client.update(self, conditional).await?;
-		if let Some(meta) = self.meta_mut() {
-			meta.version_id = Some(version_id);
-		} else {
-			// Meta does not require any field and will succeed building.
-			#[allow(clippy::unwrap_used)]
-			self.set_meta(Some(
-				stu3::types::Meta::builder().version_id(version_id).build().unwrap(),
-			));
-		}
-		Ok(created)
-	}
-
-	async fn create(&mut self, client: &Client<super::FhirStu3>) -> Result<String, Error> {
-		let (id, version_id) = client.create(self).await?;
-		self.set_id(Some(id.clone()));
-		if let Some(meta) = self.meta_mut() {
-			meta.version_id = version_id;
-		} else if let Some(version_id) = version_id {
-			// Meta does not require any field and will succeed building.
-			#[allow(clippy::unwrap_used)]
-			self.set_meta(Some(
-				stu3::types::Meta::builder().version_id(version_id).build().unwrap(),
-			));
-		}
-		Ok(id)
-	}
-
-	async fn delete(self, client: &Client<super::FhirStu3>) -> Result<(), Error> {
-		let id = self.id().as_ref().ok_or(Error::MissingId)?;
+	async fn delete(self, client: &Client<V>) -> Result<(), Error> {
+		let id = self.resource_id().ok_or(Error::MissingId)?;
 		client.delete(R::TYPE, id).await?;
 		Ok(())
 	}
@@ -10,7 +10,7 @@ use eyre::Result;
 use fhir_sdk::{
 	client::{
 		r5::search::{DateParam, TokenParam},
-		Client, ExecutableSearch, FhirR5, ResourceWrite,
+		AnyClient, Client, ExecutableSearch, FhirR5, ResourceWrite, VersionedResource,
 	},
 	r5::{
 		codes::{
@@ -142,6 +142,41 @@ async fn read_referenced_inner() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+fn any_client() -> Result<()> {
+	common::RUNTIME.block_on(any_client_inner())
+}
+
+async fn any_client_inner() -> Result<()> {
+	let client = client().await?;
+	let any_client = AnyClient::detect(client).await?;
+	assert!(matches!(any_client, AnyClient::R5(_)));
+
+	let patient = Patient::builder().active(true).build().unwrap();
+	let (id, _version_id) =
+		any_client.create(&VersionedResource::R5(Resource::from(patient))).await?;
+
+	let read = any_client.read("Patient", &id).await?.expect("created patient not found");
+	assert_eq!(read.resource_type(), "Patient");
+	assert_eq!(read.id(), Some(id.as_str()));
+	let found: Vec<VersionedResource> =
+		any_client.search("Patient", &[("_id", &id)]).await?.try_collect().await?;
+	assert_eq!(found, vec![read.clone()]);
+	let VersionedResource::R5(Resource::Patient(mut patient)) = read else {
+		panic!("read resource is not an R5 Patient");
+	};
+
+	patient.active = Some(false);
+	let (created, _version_id) =
+		any_client.update(&VersionedResource::R5(patient.into()), true).await?;
+	assert!(!created);
+
+	any_client.delete("Patient", &id).await?;
+	assert_eq!(any_client.read("Patient", &id).await?, None);
+
+	Ok(())
+}
+
 #[test]
 fn patch_via_fhir() -> Result<()> {
 	common::RUNTIME.block_on(patch_via_fhir_inner())
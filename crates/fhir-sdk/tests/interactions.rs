@@ -0,0 +1,362 @@
+#![cfg(all(feature = "r5", feature = "builders", feature = "client"))]
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+#![recursion_limit = "1024"]
+
+mod mock;
+
+use std::sync::{
+	atomic::{AtomicBool, AtomicUsize, Ordering},
+	Arc,
+};
+
+use fhir_sdk::{
+	client::{
+		header::{self, HeaderName},
+		ConditionalDelete, ConditionalUpdate, CreateIfNoneExist, Error, HeaderValue, HttpResponse,
+		PreferReturn, ResourceWrite, SearchParameters, StatusCode,
+	},
+	r5::{
+		codes::IssueSeverity,
+		resources::{Patient, Resource, ResourceType},
+		search::TokenParam,
+	},
+	time::macros::datetime,
+};
+use mock::{client, json_response, MockTransport};
+use serde_json::json;
+
+/// Mock server answering conditional updates and deletes depending on the
+/// searched identifier: `new` matches nothing, `one` matches a single patient
+/// and `many` matches multiple patients.
+fn conditional_server() -> MockTransport {
+	MockTransport::new(|request| {
+		let identifier = request
+			.url
+			.query_pairs()
+			.find(|(key, _)| key == "identifier")
+			.map(|(_, value)| value.into_owned())
+			.unwrap();
+		let location = HeaderValue::from_static("http://localhost/fhir/Patient/1/_history/1");
+		match (request.method.as_str(), identifier.as_str()) {
+			("PUT", "new") => {
+				HttpResponse::new(StatusCode::CREATED).header(header::LOCATION, location)
+			}
+			("PUT", "one") => HttpResponse::new(StatusCode::OK).header(header::LOCATION, location),
+			("DELETE", "new") => HttpResponse::new(StatusCode::NOT_FOUND),
+			("DELETE", "one") => HttpResponse::new(StatusCode::NO_CONTENT),
+			("DELETE", "many") => json_response(
+				StatusCode::OK,
+				&json!({
+					"resourceType": "OperationOutcome",
+					"issue": [{
+						"severity": "information",
+						"code": "informational",
+						"diagnostics": "Successfully deleted 3 resource(s) in 12ms",
+					}],
+				}),
+			),
+			_ => json_response(
+				StatusCode::PRECONDITION_FAILED,
+				&json!({
+					"resourceType": "OperationOutcome",
+					"issue": [{ "severity": "error", "code": "multiple-matches" }],
+				}),
+			),
+		}
+	})
+}
+
+/// Search parameters for the identifier.
+fn identifier(value: &str) -> SearchParameters<Patient> {
+	SearchParameters::empty()
+		.and("identifier", TokenParam::CodeInAnySystem { code: value, not: false })
+}
+
+#[tokio::test]
+async fn update_and_delete_where() {
+	let client = client(&conditional_server());
+	let patient = Patient::builder().build().unwrap();
+
+	let result = client.update_where(&patient, identifier("new")).await.unwrap();
+	assert_eq!(result, ConditionalUpdate::Created("1".to_owned()));
+	let result = client.update_where(&patient, identifier("one")).await.unwrap();
+	assert_eq!(result, ConditionalUpdate::Updated("1".to_owned()));
+	let result = client.update_where(&patient, identifier("many")).await;
+	assert!(matches!(result, Err(Error::PreconditionFailed(_))));
+
+	let result = client.delete_where(identifier("new")).await.unwrap();
+	assert_eq!(result, ConditionalDelete::NotFound);
+	let result = client.delete_where(identifier("one")).await.unwrap();
+	assert_eq!(result, ConditionalDelete::Deleted(None));
+	let result = client.delete_where(identifier("many")).await.unwrap();
+	assert_eq!(result, ConditionalDelete::Deleted(Some(3)));
+}
+
+/// Mock server creating the patient on the first request only.
+fn create_once_server() -> MockTransport {
+	let exists = Arc::new(AtomicBool::new(false));
+	MockTransport::new(move |_request| {
+		let status =
+			if exists.swap(true, Ordering::SeqCst) { StatusCode::OK } else { StatusCode::CREATED };
+		HttpResponse::new(status).header(
+			header::LOCATION,
+			HeaderValue::from_static("http://localhost/fhir/Patient/1/_history/1"),
+		)
+	})
+}
+
+#[tokio::test]
+async fn create_if_none_exist() {
+	let transport = create_once_server();
+	let client = client(&transport);
+
+	let patient = Patient::builder().build().unwrap();
+	let params = SearchParameters::empty().and(
+		"identifier",
+		TokenParam::CodeInSystem { system: "http://example.com/mrn", code: "123", not: false },
+	);
+
+	let result = client.create_if_none_exist(&patient, params.clone()).await.unwrap();
+	assert_eq!(result, CreateIfNoneExist::Created("1".to_owned()));
+	let result = client.create_if_none_exist(&patient, params).await.unwrap();
+	assert_eq!(result, CreateIfNoneExist::Exists("1".to_owned()));
+	assert_eq!(result.id(), "1");
+	assert!(!result.created());
+
+	let request = &transport.requests()[0];
+	assert_eq!(request.url.path(), "/fhir/Patient");
+	assert_eq!(request.headers["if-none-exist"], "identifier=http%3A%2F%2Fexample.com%2Fmrn%7C123");
+}
+
+/// Mock server storing a patient, whose version is bumped by a concurrent
+/// writer on every update until the given number of conflicts occurred.
+fn conflicting_server(conflicts: usize) -> MockTransport {
+	let version = Arc::new(AtomicUsize::new(1));
+	let conflicted = Arc::new(AtomicUsize::new(0));
+	MockTransport::new(move |request| {
+		let current = version.load(Ordering::SeqCst);
+		if request.method == "GET" {
+			return json_response(
+				StatusCode::OK,
+				&json!({
+					"resourceType": "Patient",
+					"id": "1",
+					"meta": { "versionId": current.to_string() },
+				}),
+			);
+		}
+
+		if conflicted.fetch_add(1, Ordering::SeqCst) < conflicts {
+			version.fetch_add(1, Ordering::SeqCst);
+			return HttpResponse::new(StatusCode::PRECONDITION_FAILED);
+		}
+		assert_eq!(request.headers[header::IF_MATCH], format!("W/\"{current}\""));
+		version.fetch_add(1, Ordering::SeqCst);
+		let etag = HeaderValue::from_str(&format!("W/\"{}\"", current + 1)).unwrap();
+		HttpResponse::new(StatusCode::OK).header(header::ETAG, etag)
+	})
+}
+
+#[tokio::test]
+async fn retries_on_version_conflicts() {
+	let transport = conflicting_server(2);
+	let client = client(&transport);
+
+	let patient = client
+		.modify::<Patient, _>("1", |patient| patient.active = Some(true))
+		.send()
+		.await
+		.unwrap();
+	assert_eq!(patient.active, Some(true));
+	assert_eq!(patient.meta.unwrap().version_id.as_deref(), Some("4"));
+	assert_eq!(transport.requests().len(), 6);
+}
+
+#[tokio::test]
+async fn retry_limit() {
+	let transport = conflicting_server(usize::MAX);
+	let client = client(&transport);
+
+	let result = client
+		.modify::<Patient, _>("1", |patient| patient.active = Some(true))
+		.max_retries(1)
+		.send()
+		.await;
+	assert!(matches!(result, Err(Error::PreconditionFailed(_))));
+	assert_eq!(transport.requests().len(), 4);
+}
+
+/// Mock server storing patients with ID `1`, answering according to the
+/// `Prefer` header. Transactions are answered with an empty `Bundle`.
+fn prefer_server() -> MockTransport {
+	MockTransport::new(|request| {
+		if request.url.path() == "/fhir" {
+			return json_response(
+				StatusCode::OK,
+				&json!({ "resourceType": "Bundle", "type": "transaction-response" }),
+			);
+		}
+
+		let status = if request.method == "POST" { StatusCode::CREATED } else { StatusCode::OK };
+		let response = match request.headers.get("prefer").map(|value| value.to_str().unwrap()) {
+			Some("return=representation") => json_response(
+				status,
+				&json!({
+					"resourceType": "Patient",
+					"id": "1",
+					"meta": { "versionId": "2", "lastUpdated": "2024-01-01T00:00:00Z" },
+					"active": true,
+				}),
+			),
+			Some("return=OperationOutcome") => json_response(
+				status,
+				&json!({
+					"resourceType": "OperationOutcome",
+					"issue": [{ "severity": "information", "code": "informational" }],
+				}),
+			),
+			_ => HttpResponse::new(status),
+		};
+		response
+			.header(
+				header::LOCATION,
+				HeaderValue::from_static("http://localhost/fhir/Patient/1/_history/2"),
+			)
+			.header(header::ETAG, HeaderValue::from_static("W/\"2\""))
+	})
+}
+
+#[tokio::test]
+async fn create_returning() {
+	let transport = prefer_server();
+	let client = client(&transport);
+	let patient = Patient::builder().build().unwrap();
+
+	let response = client.create_returning(&patient, PreferReturn::Representation).await.unwrap();
+	let stored = response.into_value().expect("should return resource");
+	assert!(stored.meta.unwrap().last_updated.is_some());
+
+	let response = client.create_returning(&patient, PreferReturn::Minimal).await.unwrap();
+	assert!(response.value().is_none());
+	assert_eq!(response.version_id().as_deref(), Some("2"));
+
+	let response = client
+		.update_returning(&stored_patient(), false, PreferReturn::OperationOutcome)
+		.await
+		.unwrap();
+	assert!(response.value().is_none());
+	assert!(response.operation_outcome().is_some());
+
+	let requests = transport.requests();
+	assert_eq!(requests[0].headers["prefer"], "return=representation");
+	assert_eq!(requests[1].headers["prefer"], "return=minimal");
+	assert_eq!(requests[2].headers["prefer"], "return=OperationOutcome");
+}
+
+/// A patient as stored on the server.
+fn stored_patient() -> Patient {
+	Patient::builder().id("1".to_owned()).build().unwrap()
+}
+
+#[tokio::test]
+async fn resource_write_replaces_local_value() {
+	let client = client(&prefer_server());
+
+	let mut patient = Patient::builder().build().unwrap();
+	let id = patient.create(&client).await.unwrap();
+	assert_eq!(id, "1");
+	assert_eq!(patient.active, Some(true));
+	assert!(patient.meta.as_ref().unwrap().last_updated.is_some());
+
+	let mut patient = stored_patient();
+	let created = patient.update(false, &client).await.unwrap();
+	assert!(!created);
+	assert_eq!(patient.active, Some(true));
+	assert_eq!(patient.meta.as_ref().unwrap().version_id.as_deref(), Some("2"));
+}
+
+#[tokio::test]
+async fn patch_and_transaction() {
+	let transport = prefer_server();
+	let client = client(&transport);
+
+	let response = client
+		.patch_via_json(ResourceType::Patient, "1")
+		.replace("/active", true)
+		.unwrap()
+		.prefer(PreferReturn::Representation)
+		.send_returning()
+		.await
+		.unwrap();
+	assert!(matches!(response.into_value(), Some(Resource::Patient(_))));
+
+	let mut transaction = client.transaction();
+	transaction.prefer(PreferReturn::OperationOutcome);
+	transaction.delete(ResourceType::Patient, "1");
+	transaction.send().await.unwrap();
+	assert_eq!(transport.requests()[1].headers["prefer"], "return=OperationOutcome");
+}
+
+/// Request ID header used in the tests.
+const REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Mock server answering reads with metadata headers and creates with a
+/// warning `OperationOutcome`.
+fn metadata_server() -> MockTransport {
+	MockTransport::new(|request| match (request.method.as_str(), request.url.path()) {
+		("GET", "/fhir/Patient/1") => {
+			json_response(StatusCode::OK, &json!({ "resourceType": "Patient", "id": "1" }))
+				.header(header::ETAG, HeaderValue::from_static("W/\"3\""))
+				.header(
+					header::LAST_MODIFIED,
+					HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+				)
+				.header(REQUEST_ID, HeaderValue::from_static("abc"))
+		}
+		("POST", "/fhir/Patient") => json_response(
+			StatusCode::CREATED,
+			&json!({
+				"resourceType": "OperationOutcome",
+				"issue": [{ "severity": "warning", "code": "informational" }],
+			}),
+		)
+		.header(
+			header::LOCATION,
+			HeaderValue::from_static("http://localhost/fhir/Patient/2/_history/1"),
+		),
+		_ => HttpResponse::new(StatusCode::NOT_FOUND),
+	})
+}
+
+#[tokio::test]
+async fn read_metadata() {
+	let client = client(&metadata_server());
+
+	let response = client.read_with_response::<Patient>("1").await.unwrap();
+	assert_eq!(response.status(), StatusCode::OK);
+	assert_eq!(response.version_id().as_deref(), Some("3"));
+	assert_eq!(response.last_modified().unwrap().0, datetime!(2015-10-21 07:28:00 UTC));
+	assert_eq!(response.headers()[&REQUEST_ID], "abc");
+	assert!(response.location().is_none());
+	assert!(response.operation_outcome().is_none());
+	assert_eq!(response.into_value().unwrap().id.as_deref(), Some("1"));
+
+	let response = client.read_with_response::<Patient>("2").await.unwrap();
+	assert_eq!(response.status(), StatusCode::NOT_FOUND);
+	assert!(response.value().is_none());
+}
+
+#[tokio::test]
+async fn create_metadata() {
+	let client = client(&metadata_server());
+
+	let patient = Patient::builder().build().unwrap();
+	let response = client.create_with_response(&patient).await.unwrap();
+	assert_eq!(response.status(), StatusCode::CREATED);
+	assert_eq!(response.value(), "2");
+	assert_eq!(response.version_id().as_deref(), Some("1"));
+	assert_eq!(response.location(), Some("http://localhost/fhir/Patient/2/_history/1"));
+	let outcome = response.operation_outcome().expect("should have OperationOutcome");
+	let issue = outcome.issue.iter().flatten().next().unwrap();
+	assert!(matches!(issue.severity, IssueSeverity::Warning));
+}
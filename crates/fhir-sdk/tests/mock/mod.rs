@@ -0,0 +1,67 @@
+//! In-process mock transport to test the client without a FHIR server.
+#![allow(dead_code)] // Not all tests use all helpers.
+
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use fhir_sdk::client::{
+	Client, ClientBuilder, Error, FhirR5, HttpRequest, HttpResponse, StatusCode, Transport,
+};
+
+/// Base URL of the mocked FHIR server.
+pub const BASE_URL: &str = "http://localhost/fhir/";
+
+/// Handler function producing the response to a request.
+type Handler = Box<dyn Fn(&HttpRequest) -> HttpResponse + Send + Sync>;
+
+/// Mock transport answering requests with a handler function and recording
+/// all requests.
+#[derive(Clone)]
+pub struct MockTransport {
+	/// The handler producing the responses.
+	handler: Arc<Handler>,
+	/// All requests that were sent.
+	requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+impl MockTransport {
+	/// Create a new mock transport answering with the handler.
+	pub fn new(handler: impl Fn(&HttpRequest) -> HttpResponse + Send + Sync + 'static) -> Self {
+		Self { handler: Arc::new(Box::new(handler)), requests: Arc::default() }
+	}
+
+	/// Get all requests that were sent so far.
+	pub fn requests(&self) -> Vec<HttpRequest> {
+		self.requests.lock().expect("mutex poisoned").clone()
+	}
+}
+
+#[async_trait]
+impl Transport for MockTransport {
+	async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+		self.requests.lock().expect("mutex poisoned").push(request.clone());
+		Ok((self.handler)(&request))
+	}
+}
+
+impl std::fmt::Debug for MockTransport {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("MockTransport").field("requests", &self.requests).finish()
+	}
+}
+
+/// Create a JSON response with the given status.
+pub fn json_response(status: StatusCode, body: &serde_json::Value) -> HttpResponse {
+	HttpResponse::new(status).body(body.to_string())
+}
+
+/// Client builder for the mocked FHIR server behind the transport.
+pub fn client_builder(transport: impl Transport) -> ClientBuilder<FhirR5> {
+	Client::builder().base_url(BASE_URL.parse().expect("parsing URL")).transport(transport)
+}
+
+/// Client with default settings for the mocked FHIR server behind the
+/// transport.
+pub fn client(transport: &MockTransport) -> Client<FhirR5> {
+	client_builder(transport.clone()).build().expect("building client")
+}
@@ -0,0 +1,328 @@
+#![cfg(all(feature = "r5", feature = "builders", feature = "client"))]
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+#![recursion_limit = "1024"]
+
+mod mock;
+
+use fhir_sdk::{
+	client::{
+		header, AnyClient, Client, ContainedMode, ContainedType, Error, ExecutableSearch, FhirR5,
+		Method, StatusCode, SummaryMode, TotalMode, VersionedResource,
+	},
+	r5::{
+		codes::CompartmentType,
+		params::ObservationSearchParameter,
+		resources::{
+			Observation, ObservationElement, Patient, Practitioner, Resource, ResourceType,
+		},
+		search::TokenParam,
+	},
+	Order, ResourceSearchParameterDefinition, StreamExt, TryStreamExt,
+};
+use mock::{client, client_builder, json_response, MockTransport};
+use serde_json::json;
+
+/// Mock server answering searches with two pages of the given entries,
+/// linking to the second page at the given path.
+fn paged_server(path: &'static str, entries: serde_json::Value) -> MockTransport {
+	MockTransport::new(move |request| {
+		let next_link = (request.url.query_pairs().all(|(key, _)| key != "page")).then(
+			|| json!({ "relation": "next", "url": format!("http://localhost/fhir{path}?page=2") }),
+		);
+		json_response(
+			StatusCode::OK,
+			&json!({
+				"resourceType": "Bundle",
+				"type": "searchset",
+				"link": next_link.into_iter().collect::<Vec<_>>(),
+				"entry": entries,
+			}),
+		)
+	})
+}
+
+#[tokio::test]
+async fn search_in_compartment() {
+	let transport = paged_server(
+		"/Patient/123/Observation",
+		json!([{
+			"resource": {
+				"resourceType": "Observation",
+				"status": "final",
+				"code": { "text": "test" },
+			},
+		}]),
+	);
+	let client = client(&transport);
+
+	let observations: Vec<Observation> = client
+		.search_in_compartment(CompartmentType::Patient, "123")
+		.and("code", TokenParam::CodeInAnySystem { code: "test", not: false })
+		.send()
+		.await
+		.unwrap()
+		.try_collect()
+		.await
+		.unwrap();
+	assert_eq!(observations.len(), 2);
+
+	let requests = transport.requests();
+	assert!(requests.iter().all(|request| request.url.path() == "/fhir/Patient/123/Observation"));
+	assert_eq!(requests[0].url.query(), Some("code=test"));
+	assert_eq!(requests[1].url.query(), Some("page=2"));
+}
+
+/// Mock server answering patient searches with two pages.
+fn patient_server() -> MockTransport {
+	paged_server("/Patient", json!([{ "resource": { "resourceType": "Patient", "id": "1" } }]))
+}
+
+/// Set up a client using the mock transport, posting searches with URLs
+/// longer than the threshold.
+fn post_search_client(transport: &MockTransport, threshold: usize) -> Client<FhirR5> {
+	client_builder(transport.clone()).post_search_threshold(threshold).build().unwrap()
+}
+
+/// Search patients by identifier.
+async fn search_patients(client: &Client<FhirR5>, via_post: bool) -> Vec<Patient> {
+	let mut search = client
+		.search()
+		.and("identifier", TokenParam::CodeInSystem { system: "mrn", code: "123", not: false });
+	if via_post {
+		search = search.via_post();
+	}
+	search.send().await.unwrap().try_collect().await.unwrap()
+}
+
+#[tokio::test]
+async fn search_via_post() {
+	let transport = patient_server();
+	let client = post_search_client(&transport, usize::MAX);
+
+	let patients = search_patients(&client, true).await;
+	assert_eq!(patients.len(), 2);
+
+	let requests = transport.requests();
+	assert_eq!(requests[0].method, Method::POST);
+	assert_eq!(requests[0].url.as_str(), "http://localhost/fhir/Patient/_search");
+	assert_eq!(requests[0].headers[header::CONTENT_TYPE], "application/x-www-form-urlencoded");
+	assert_eq!(requests[0].body.as_deref(), Some(&b"identifier=mrn%7C123"[..]));
+	// Next pages are fetched via the next link.
+	assert_eq!(requests[1].method, Method::GET);
+	assert_eq!(requests[1].url.as_str(), "http://localhost/fhir/Patient?page=2");
+}
+
+#[tokio::test]
+async fn post_search_threshold() {
+	let transport = patient_server();
+	search_patients(&post_search_client(&transport, 100), false).await;
+	search_patients(&post_search_client(&transport, 10), false).await;
+
+	let requests = transport.requests();
+	assert_eq!(requests[0].method, Method::GET);
+	assert_eq!(requests[2].method, Method::POST);
+}
+
+/// Mock server answering observation searches with one complete and one
+/// subsetted observation, lacking the required `code`.
+fn observation_server() -> MockTransport {
+	MockTransport::new(|_request| {
+		json_response(
+			StatusCode::OK,
+			&json!({
+				"resourceType": "Bundle",
+				"type": "searchset",
+				"entry": [
+					{
+						"resource": {
+							"resourceType": "Observation",
+							"id": "1",
+							"status": "final",
+							"code": { "text": "test" },
+						},
+					},
+					{ "resource": { "resourceType": "Observation", "id": "2", "status": "final" } },
+				],
+			}),
+		)
+	})
+}
+
+#[tokio::test]
+async fn result_parameters() {
+	let transport = observation_server();
+	let client = client(&transport);
+
+	let results: Vec<Result<Observation, Error>> = client
+		.search::<Observation>()
+		.sort([
+			ObservationSearchParameter::Date.order(Order::Descending),
+			ObservationSearchParameter::Code.order(Order::Ascending),
+		])
+		.elements([ObservationElement::Status, ObservationElement::Code])
+		.total(TotalMode::Accurate)
+		.contained(ContainedMode::Both)
+		.contained_type(ContainedType::Contained)
+		.count(10)
+		.send()
+		.await
+		.unwrap()
+		.collect()
+		.await;
+	// The subsetted observation cannot be parsed and is returned as error.
+	assert_eq!(results.len(), 2);
+	assert!(matches!(results[0], Err(Error::Json(_))));
+	assert_eq!(results[1].as_ref().unwrap().id.as_deref(), Some("1"));
+
+	let requests = transport.requests();
+	assert_eq!(
+		requests[0].url.query(),
+		Some(
+			"_sort=-date%2Ccode&_elements=status%2Ccode&_total=accurate&_contained=both&\
+			 _containedType=contained&_count=10"
+		)
+	);
+}
+
+#[tokio::test]
+async fn summary() {
+	let transport = observation_server();
+	let client = client(&transport);
+
+	let results: Vec<Result<Observation, Error>> =
+		client.search().summary(SummaryMode::Data).send().await.unwrap().collect().await;
+	assert_eq!(results.len(), 2);
+	assert_eq!(results.iter().filter(|result| result.is_err()).count(), 1);
+	assert_eq!(transport.requests()[0].url.query(), Some("_summary=data"));
+}
+
+#[tokio::test]
+async fn strict_without_subsetting() {
+	let transport = observation_server();
+	let client = client(&transport);
+
+	let result = client.search::<Observation>().send().await;
+	assert!(matches!(result, Err(Error::Json(_))));
+}
+
+/// Resources of interest in the system search.
+#[derive(Debug)]
+enum Person {
+	Patient(Patient),
+	Practitioner(Practitioner),
+}
+
+impl TryFrom<Resource> for Person {
+	type Error = Resource;
+
+	fn try_from(resource: Resource) -> Result<Self, Self::Error> {
+		match resource {
+			Resource::Patient(patient) => Ok(Self::Patient(patient)),
+			Resource::Practitioner(practitioner) => Ok(Self::Practitioner(practitioner)),
+			other => Err(other),
+		}
+	}
+}
+
+/// Mock server answering system searches with two pages of mixed resources.
+fn system_server() -> MockTransport {
+	paged_server(
+		"",
+		json!([
+			{ "resource": { "resourceType": "Patient", "id": "1" } },
+			{ "resource": { "resourceType": "Practitioner", "id": "2" } },
+			{
+				"resource": {
+					"resourceType": "Group",
+					"id": "3",
+					"type": "person",
+					"membership": "enumerated",
+				},
+			},
+		]),
+	)
+}
+
+#[tokio::test]
+async fn search_all_resources() {
+	let transport = system_server();
+	let client = client(&transport);
+
+	let resources: Vec<Resource> = client
+		.search_system(&[])
+		.and_raw("_lastUpdated", "gt2024-01-01")
+		.send()
+		.await
+		.unwrap()
+		.try_collect()
+		.await
+		.unwrap();
+	assert_eq!(resources.len(), 6);
+
+	let requests = transport.requests();
+	assert!(requests.iter().all(|request| request.url.path() == "/fhir"));
+	assert_eq!(requests[0].url.query(), Some("_lastUpdated=gt2024-01-01"));
+	assert_eq!(requests[1].url.query(), Some("page=2"));
+}
+
+#[tokio::test]
+async fn search_typed_resources() {
+	let transport = system_server();
+	let client = client(&transport);
+
+	let people: Vec<Person> = client
+		.search_system(&[ResourceType::Patient, ResourceType::Practitioner])
+		.send()
+		.await
+		.unwrap()
+		.try_collect()
+		.await
+		.unwrap();
+	assert_eq!(people.len(), 4);
+	assert!(matches!(people[0], Person::Patient(_)));
+	assert!(matches!(people[1], Person::Practitioner(_)));
+
+	let requests = transport.requests();
+	assert_eq!(requests[0].url.path(), "/fhir");
+	assert_eq!(requests[0].url.query(), Some("_type=Patient%2CPractitioner"));
+}
+
+#[tokio::test]
+async fn any_client_search() {
+	let transport = MockTransport::new(|request| {
+		let body = if request.url.path() == "/fhir/metadata" {
+			json!({ "resourceType": "CapabilityStatement", "fhirVersion": "5.0.0" })
+		} else {
+			json!({
+				"resourceType": "Bundle",
+				"type": "searchset",
+				"entry": [
+					{ "resource": { "resourceType": "Patient", "id": "1" } },
+					{ "resource": { "resourceType": "Patient", "id": "2" } },
+				],
+			})
+		};
+		json_response(StatusCode::OK, &body)
+	});
+	let client = AnyClient::detect(client(&transport)).await.unwrap();
+
+	let resources: Vec<VersionedResource> =
+		client.search("Patient", &[("name", "peter")]).await.unwrap().try_collect().await.unwrap();
+	assert_eq!(resources.len(), 2);
+	assert!(resources.iter().all(|resource| resource.resource_type() == "Patient"));
+	assert_eq!(transport.requests()[1].url.as_str(), "http://localhost/fhir/Patient?name=peter");
+
+	let result = client.search("NoResource", &[]).await;
+	assert!(matches!(result, Err(Error::UnexpectedResourceType(_))));
+}
+
+#[tokio::test]
+async fn wrong_bundle_type() {
+	let transport = MockTransport::new(|_request| {
+		json_response(StatusCode::OK, &json!({ "resourceType": "Bundle", "type": "collection" }))
+	});
+	let client = client(&transport);
+
+	let result = client.search::<Patient>().send().await;
+	assert!(matches!(result, Err(Error::WrongBundleType(..))));
+}
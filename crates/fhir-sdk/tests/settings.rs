@@ -0,0 +1,198 @@
+#![cfg(all(feature = "r5", feature = "builders", feature = "client"))]
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+#![recursion_limit = "1024"]
+
+mod mock;
+
+use std::{
+	sync::atomic::{AtomicUsize, Ordering},
+	time::{Duration, Instant},
+};
+
+use fhir_sdk::{
+	client::{
+		header::{self, HeaderName},
+		Client, ExecutableSearch, FhirR5, HeaderValue, HttpResponse, RequestSettings, StatusCode,
+	},
+	r5::resources::Patient,
+	TryStreamExt,
+};
+use mock::{client_builder, json_response, MockTransport};
+use serde_json::json;
+
+/// Mock server failing the first requests with the status and `Retry-After`
+/// header, answering with a patient afterwards.
+fn failing_server(
+	failures: usize,
+	status: StatusCode,
+	retry_after: Option<&'static str>,
+) -> MockTransport {
+	let calls = AtomicUsize::new(0);
+	MockTransport::new(move |_request| {
+		if calls.fetch_add(1, Ordering::SeqCst) < failures {
+			let mut response = HttpResponse::new(status);
+			if let Some(retry_after) = retry_after {
+				response =
+					response.header(header::RETRY_AFTER, HeaderValue::from_static(retry_after));
+			}
+			return response;
+		}
+		json_response(StatusCode::CREATED, &json!({ "resourceType": "Patient", "id": "1" }))
+			.header(header::LOCATION, HeaderValue::from_static("http://localhost/fhir/Patient/1"))
+	})
+}
+
+/// Set up a client with the request settings.
+fn retry_client(transport: &MockTransport, settings: RequestSettings) -> Client<FhirR5> {
+	client_builder(transport.clone()).request_settings(settings).build().unwrap()
+}
+
+#[tokio::test]
+async fn retries_rate_limits() {
+	let transport = failing_server(2, StatusCode::TOO_MANY_REQUESTS, Some("0"));
+	let client = retry_client(&transport, RequestSettings::default());
+
+	client.read::<Patient>("1").await.unwrap().expect("should find resource");
+	assert_eq!(transport.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn honors_retry_after() {
+	let transport = failing_server(1, StatusCode::SERVICE_UNAVAILABLE, Some("1"));
+	let client = retry_client(&transport, RequestSettings::default().fixed_retry(Duration::ZERO));
+
+	let start = Instant::now();
+	client.read::<Patient>("1").await.unwrap().expect("should find resource");
+	assert!(start.elapsed() >= Duration::from_secs(1));
+	assert_eq!(transport.requests().len(), 2);
+
+	let transport =
+		failing_server(1, StatusCode::SERVICE_UNAVAILABLE, Some("Wed, 21 Oct 2015 07:28:00 GMT"));
+	let client = retry_client(&transport, RequestSettings::default());
+	let start = Instant::now();
+	client.read::<Patient>("1").await.unwrap().expect("should find resource");
+	assert!(start.elapsed() < Duration::from_secs(1));
+	assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn gives_up() {
+	let transport =
+		failing_server(1, StatusCode::SERVICE_UNAVAILABLE, Some("Fri, 01 Jan 2100 00:00:00 GMT"));
+	let client = retry_client(&transport, RequestSettings::default());
+	assert!(client.read::<Patient>("1").await.is_err());
+	assert_eq!(transport.requests().len(), 1);
+
+	let transport = failing_server(5, StatusCode::TOO_MANY_REQUESTS, Some("0"));
+	let client = retry_client(&transport, RequestSettings::default().retries(2));
+	assert!(client.read::<Patient>("1").await.is_err());
+	assert_eq!(transport.requests().len(), 3);
+
+	let transport = failing_server(1, StatusCode::TOO_MANY_REQUESTS, None);
+	let client = retry_client(&transport, RequestSettings::default().retry_statuses([]));
+	assert!(client.read::<Patient>("1").await.is_err());
+	assert_eq!(transport.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn exp_backoff_with_jitter() {
+	let transport = failing_server(3, StatusCode::SERVICE_UNAVAILABLE, None);
+	let settings = RequestSettings::default().exp_backoff(Duration::from_millis(2), None);
+	let client = retry_client(&transport, settings);
+
+	client.read::<Patient>("1").await.unwrap().expect("should find resource");
+	assert_eq!(transport.requests().len(), 4);
+}
+
+#[tokio::test]
+async fn never_resends_creates() {
+	let transport = failing_server(1, StatusCode::SERVICE_UNAVAILABLE, Some("0"));
+	let client = retry_client(&transport, RequestSettings::default());
+
+	let patient = Patient::builder().build().unwrap();
+	assert!(client.create(&patient).await.is_err());
+	assert_eq!(transport.requests().len(), 1);
+}
+
+/// Tenant header used in the tests.
+const TENANT: HeaderName = HeaderName::from_static("x-request-tenant");
+
+/// Mock server answering patient reads and searches with two pages, failing
+/// everything else.
+fn patient_server() -> MockTransport {
+	MockTransport::new(|request| match request.url.path() {
+		"/fhir/Patient/1" => {
+			json_response(StatusCode::OK, &json!({ "resourceType": "Patient", "id": "1" }))
+		}
+		"/fhir/Patient" => {
+			let next_link = (request.url.query_pairs().all(|(key, _)| key != "page")).then(
+				|| json!({ "relation": "next", "url": "http://localhost/fhir/Patient?page=2" }),
+			);
+			json_response(
+				StatusCode::OK,
+				&json!({
+					"resourceType": "Bundle",
+					"type": "searchset",
+					"link": next_link.into_iter().collect::<Vec<_>>(),
+					"entry": [{ "resource": { "resourceType": "Patient", "id": "1" } }]
+				}),
+			)
+		}
+		_ => HttpResponse::new(StatusCode::SERVICE_UNAVAILABLE),
+	})
+}
+
+#[tokio::test]
+async fn per_call_override() {
+	let transport = patient_server();
+	let client = retry_client(&transport, RequestSettings::default().fixed_retry(Duration::ZERO));
+
+	client
+		.with_settings(|settings| {
+			settings
+				.timeout(Some(Duration::from_secs(300)))
+				.header(TENANT, HeaderValue::from_static("a"))
+		})
+		.read::<Patient>("1")
+		.await
+		.unwrap()
+		.expect("should find resource");
+	client.read::<Patient>("1").await.unwrap().expect("should find resource");
+
+	let requests = transport.requests();
+	assert_eq!(requests[0].timeout, Some(Duration::from_secs(300)));
+	assert_eq!(requests[0].headers[&TENANT], "a");
+	assert_eq!(requests[1].timeout, None);
+	assert!(!requests[1].headers.contains_key(&TENANT));
+}
+
+#[tokio::test]
+async fn overrides_compose() {
+	let transport = patient_server();
+	let client = retry_client(&transport, RequestSettings::default().fixed_retry(Duration::ZERO));
+
+	let tenant = client.with_settings(|settings| {
+		settings.header(TENANT, HeaderValue::from_static("a")).retries(0)
+	});
+	let patients: Vec<Patient> = tenant
+		.with_settings(|settings| settings.timeout(Some(Duration::from_secs(1))))
+		.search()
+		.send()
+		.await
+		.unwrap()
+		.try_collect()
+		.await
+		.unwrap();
+	assert_eq!(patients.len(), 2);
+
+	// Retries are disabled for the handle only.
+	assert!(tenant.read::<Patient>("2").await.is_err());
+	assert_eq!(transport.requests().len(), 3);
+	assert!(client.read::<Patient>("2").await.is_err());
+	assert_eq!(transport.requests().len(), 7);
+
+	let requests = transport.requests();
+	assert!(requests[..3].iter().all(|request| request.headers[&TENANT] == "a"));
+	assert!(requests[..2].iter().all(|request| request.timeout == Some(Duration::from_secs(1))));
+	assert!(requests[3..].iter().all(|request| !request.headers.contains_key(&TENANT)));
+}
@@ -0,0 +1,176 @@
+#![cfg(all(feature = "r5", feature = "builders", feature = "smart"))]
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+#![recursion_limit = "1024"]
+
+mod mock;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use fhir_sdk::{
+	client::{
+		auth::{SigningKey, SmartBackend},
+		header, Client, FhirR5, HttpRequest, HttpResponse, StatusCode,
+	},
+	r5::resources::Patient,
+};
+use mock::{client_builder, json_response, MockTransport};
+use p384::{ecdsa::signature::Verifier, pkcs8::DecodePrivateKey};
+use rsa::sha2::Sha384;
+use serde_json::{json, Value};
+
+/// EC test key on curve P-384.
+const ES384_KEY: &str = include_str!("keys/es384.pem");
+/// RSA test key.
+const RS384_KEY: &str = include_str!("keys/rs384.pem");
+
+/// Mock FHIR server with SMART configuration and token endpoint, rejecting
+/// the given number of first requests as unauthorized.
+fn server(unauthorized: usize) -> MockTransport {
+	let fhir_requests = AtomicUsize::new(0);
+	MockTransport::new(move |request| match request.url.as_str() {
+		"http://localhost/fhir/.well-known/smart-configuration" => json_response(
+			StatusCode::OK,
+			&json!({
+				"token_endpoint": "http://auth.local/token",
+				"grant_types_supported": ["client_credentials"],
+			}),
+		),
+		"http://auth.local/token" | "http://other-auth.local/token" => json_response(
+			StatusCode::OK,
+			&json!({ "access_token": "token", "token_type": "bearer", "expires_in": 300 }),
+		),
+		_ if fhir_requests.fetch_add(1, Ordering::SeqCst) < unauthorized => {
+			HttpResponse::new(StatusCode::UNAUTHORIZED)
+		}
+		_ => json_response(StatusCode::OK, &json!({ "resourceType": "Patient", "id": "1" })),
+	})
+}
+
+/// Set up a client authenticating via SMART Backend Services.
+fn client(transport: &MockTransport, key: SigningKey) -> Client<FhirR5> {
+	let provider = SmartBackend::new("http://localhost/fhir/".parse().unwrap(), "client", key)
+		.scope("system/Patient.rs");
+	client_builder(transport.clone()).auth_provider(provider).build().unwrap()
+}
+
+/// Parse the form body of the token request.
+fn form(request: &HttpRequest) -> Vec<(String, String)> {
+	form_urlencoded::parse(request.body.as_deref().unwrap()).into_owned().collect()
+}
+
+/// Get the form field of the token request.
+fn field<'a>(form: &'a [(String, String)], name: &str) -> &'a str {
+	form.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str()).unwrap()
+}
+
+/// Split the JWT into header, claims, signed message and signature.
+fn split_jwt(jwt: &str) -> (Value, Value, &str, Vec<u8>) {
+	let (message, signature) = jwt.rsplit_once('.').unwrap();
+	let (header, claims) = message.split_once('.').unwrap();
+	let decode = |part: &str| URL_SAFE_NO_PAD.decode(part).unwrap();
+	(
+		serde_json::from_slice(&decode(header)).unwrap(),
+		serde_json::from_slice(&decode(claims)).unwrap(),
+		message,
+		decode(signature),
+	)
+}
+
+#[tokio::test]
+async fn es384_flow() {
+	let transport = server(0);
+	let client = client(&transport, SigningKey::es384_from_pem(ES384_KEY, "key-1").unwrap());
+
+	client.read::<Patient>("1").await.unwrap().expect("should find resource");
+	client.read::<Patient>("1").await.unwrap().expect("should find resource");
+
+	let requests = transport.requests();
+	assert_eq!(requests.len(), 4);
+	assert_eq!(requests[0].url.as_str(), "http://localhost/fhir/.well-known/smart-configuration");
+	assert_eq!(requests[1].url.as_str(), "http://auth.local/token");
+	assert_eq!(requests[2].headers[header::AUTHORIZATION], "Bearer token");
+	assert_eq!(requests[3].headers[header::AUTHORIZATION], "Bearer token");
+
+	let form = form(&requests[1]);
+	assert_eq!(field(&form, "grant_type"), "client_credentials");
+	assert_eq!(field(&form, "scope"), "system/Patient.rs");
+	assert_eq!(
+		field(&form, "client_assertion_type"),
+		"urn:ietf:params:oauth:client-assertion-type:jwt-bearer"
+	);
+
+	let (header, claims, message, signature) = split_jwt(field(&form, "client_assertion"));
+	assert_eq!(header, json!({ "alg": "ES384", "typ": "JWT", "kid": "key-1" }));
+	assert_eq!(claims["iss"], "client");
+	assert_eq!(claims["sub"], "client");
+	assert_eq!(claims["aud"], "http://auth.local/token");
+	assert!(claims["exp"].is_u64());
+	assert!(claims["jti"].is_string());
+
+	let public_key = p384::SecretKey::from_pkcs8_pem(ES384_KEY).unwrap().public_key();
+	let signature = p384::ecdsa::Signature::from_slice(&signature).unwrap();
+	p384::ecdsa::VerifyingKey::from(public_key).verify(message.as_bytes(), &signature).unwrap();
+}
+
+#[tokio::test]
+async fn rs384_signature() {
+	let transport = server(0);
+	let client = client(&transport, SigningKey::rs384_from_pem(RS384_KEY, "key-2").unwrap());
+
+	client.read::<Patient>("1").await.unwrap().expect("should find resource");
+
+	let form = form(&transport.requests()[1]);
+	let (header, _claims, message, signature) = split_jwt(field(&form, "client_assertion"));
+	assert_eq!(header["alg"], "RS384");
+
+	let private_key = rsa::RsaPrivateKey::from_pkcs8_pem(RS384_KEY).unwrap();
+	let verifying_key = rsa::pkcs1v15::VerifyingKey::<Sha384>::new(private_key.to_public_key());
+	let signature = rsa::pkcs1v15::Signature::try_from(signature.as_slice()).unwrap();
+	verifying_key.verify(message.as_bytes(), &signature).unwrap();
+}
+
+#[tokio::test]
+async fn new_assertion_per_token() {
+	let transport = server(1);
+	let key = SigningKey::es384_from_pem(ES384_KEY, "key-1").unwrap();
+	let provider = SmartBackend::new("http://localhost/fhir".parse().unwrap(), "client", key)
+		.token_url("http://other-auth.local/token".parse().unwrap());
+	let client: Client<FhirR5> = Client::builder()
+		.base_url("http://localhost/fhir".parse().unwrap())
+		.transport(transport.clone())
+		.auth_provider(provider)
+		.build()
+		.unwrap();
+
+	// The first token is rejected, so a second one is requested.
+	client.read::<Patient>("1").await.unwrap().expect("should find resource");
+
+	let token_requests: Vec<HttpRequest> = transport
+		.requests()
+		.into_iter()
+		.filter(|request| request.url.as_str() == "http://other-auth.local/token")
+		.collect();
+	assert_eq!(token_requests.len(), 2);
+	let jti: Vec<Value> = token_requests
+		.iter()
+		.map(|request| split_jwt(field(&form(request), "client_assertion")).1["jti"].clone())
+		.collect();
+	assert_ne!(jti[0], jti[1]);
+}
+
+#[test]
+fn jwk_keys() {
+	let ec_jwk = p384::SecretKey::from_pkcs8_pem(ES384_KEY).unwrap().to_jwk_string();
+	let mut ec_jwk: Value = serde_json::from_str(&ec_jwk).unwrap();
+	ec_jwk["kid"] = "ec-key".into();
+	ec_jwk["alg"] = "ES384".into();
+	let key = SigningKey::from_jwk(&ec_jwk.to_string()).unwrap();
+	assert!(format!("{key:?}").contains("ES384"));
+
+	ec_jwk["crv"] = "P-256".into();
+	assert!(SigningKey::from_jwk(&ec_jwk.to_string()).is_err());
+
+	let rsa_jwk = json!({ "kty": "RSA", "kid": "rsa-key", "n": "AQAB" });
+	assert!(SigningKey::from_jwk(&rsa_jwk.to_string()).is_err());
+}
@@ -0,0 +1,183 @@
+#![cfg(all(feature = "r5", feature = "builders", feature = "smart"))]
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+#![recursion_limit = "1024"]
+
+mod mock;
+
+use std::{
+	collections::HashMap,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc,
+	},
+};
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use fhir_sdk::{
+	client::{
+		auth::SmartLaunch, header, Client, Error, FhirR5, HttpRequest, HttpResponse, StatusCode,
+		Url,
+	},
+	r5::resources::Patient,
+};
+use mock::{client_builder, json_response, MockTransport};
+use rsa::sha2::{Digest, Sha256};
+use serde_json::json;
+
+/// Mock FHIR server with SMART configuration, authorization server and some
+/// resources.
+fn server() -> MockTransport {
+	MockTransport::new(respond)
+}
+
+/// Answer requests to the mock FHIR and authorization server.
+fn respond(request: &HttpRequest) -> HttpResponse {
+	match request.url.as_str() {
+		"http://localhost/fhir/.well-known/smart-configuration" => json_response(
+			StatusCode::OK,
+			&json!({
+				"authorization_endpoint": "http://auth.local/authorize",
+				"token_endpoint": "http://auth.local/token",
+			}),
+		),
+		"http://auth.local/token" => match form(request)["grant_type"].as_str() {
+			"authorization_code" => {
+				let claims =
+					URL_SAFE_NO_PAD.encode(json!({ "fhirUser": "Practitioner/9" }).to_string());
+				json_response(
+					StatusCode::OK,
+					&json!({
+						"access_token": "token-1",
+						"token_type": "bearer",
+						"expires_in": 0,
+						"refresh_token": "refresh-1",
+						"scope": "launch openid fhirUser patient/*.rs",
+						"patient": "123",
+						"encounter": "456",
+						"id_token": format!("e30.{claims}.c2ln"),
+					}),
+				)
+			}
+			_ => json_response(
+				StatusCode::OK,
+				&json!({ "access_token": "token-2", "token_type": "bearer", "expires_in": 300 }),
+			),
+		},
+		"http://localhost/fhir/Patient/123" => {
+			json_response(StatusCode::OK, &json!({ "resourceType": "Patient", "id": "123" }))
+		}
+		"http://localhost/fhir/Practitioner/9" => {
+			json_response(StatusCode::OK, &json!({ "resourceType": "Practitioner", "id": "9" }))
+		}
+		_ => json_response(StatusCode::NOT_FOUND, &json!({})),
+	}
+}
+
+/// Parse the form body of the token request.
+fn form(request: &HttpRequest) -> HashMap<String, String> {
+	form_urlencoded::parse(request.body.as_deref().unwrap()).into_owned().collect()
+}
+
+/// Set up the provider and a client authenticating with it.
+fn setup(transport: &MockTransport) -> (Arc<SmartLaunch>, Client<FhirR5>) {
+	let provider = Arc::new(
+		SmartLaunch::new(
+			"http://localhost/fhir/".parse().unwrap(),
+			"app",
+			"http://app.local/callback".parse().unwrap(),
+		)
+		.scope("launch")
+		.scope("patient/*.rs")
+		.transport(transport.clone()),
+	);
+	let client = client_builder(transport.clone()).auth_provider(provider.clone()).build().unwrap();
+	(provider, client)
+}
+
+#[tokio::test]
+async fn ehr_launch_flow() {
+	let transport = server();
+	let (provider, client) = setup(&transport);
+
+	let authorize_url = provider.authorize_url(Some("launch-1")).await.unwrap();
+	assert!(authorize_url.as_str().starts_with("http://auth.local/authorize?"));
+	let params: HashMap<String, String> = authorize_url.query_pairs().into_owned().collect();
+	assert_eq!(params["response_type"], "code");
+	assert_eq!(params["client_id"], "app");
+	assert_eq!(params["redirect_uri"], "http://app.local/callback");
+	assert_eq!(params["scope"], "launch patient/*.rs");
+	assert_eq!(params["aud"], "http://localhost/fhir");
+	assert_eq!(params["launch"], "launch-1");
+	assert_eq!(params["code_challenge_method"], "S256");
+
+	let redirect: Url =
+		format!("http://app.local/callback?code=code-1&state={}", params["state"]).parse().unwrap();
+	let context = provider.handle_redirect(&redirect).await.unwrap();
+	assert_eq!(context.patient.as_deref(), Some("123"));
+	assert_eq!(context.encounter.as_deref(), Some("456"));
+	assert_eq!(context.fhir_user.as_deref(), Some("Practitioner/9"));
+	assert_eq!(provider.context().await, Some(context.clone()));
+
+	let token_request = transport.requests().pop().unwrap();
+	let token_form = form(&token_request);
+	assert_eq!(token_form["grant_type"], "authorization_code");
+	assert_eq!(token_form["code"], "code-1");
+	assert_eq!(token_form["client_id"], "app");
+	assert_eq!(
+		URL_SAFE_NO_PAD.encode(Sha256::digest(&token_form["code_verifier"])),
+		params["code_challenge"]
+	);
+
+	// The first token expired immediately, so it is refreshed.
+	let patient = client.read_referenced(&context.patient_reference::<FhirR5>().unwrap()).await;
+	assert_eq!(patient.unwrap().as_base_resource().id().as_deref(), Some("123"));
+	let user = client.read_referenced(&context.fhir_user_reference::<FhirR5>().unwrap()).await;
+	assert_eq!(user.unwrap().as_base_resource().id().as_deref(), Some("9"));
+
+	let requests = transport.requests();
+	let refresh_form = form(&requests[requests.len() - 3]);
+	assert_eq!(refresh_form["grant_type"], "refresh_token");
+	assert_eq!(refresh_form["refresh_token"], "refresh-1");
+	assert_eq!(requests[requests.len() - 2].headers[header::AUTHORIZATION], "Bearer token-2");
+	assert_eq!(requests[requests.len() - 1].headers[header::AUTHORIZATION], "Bearer token-2");
+}
+
+#[tokio::test]
+async fn invalid_redirects() {
+	let transport = server();
+	let (provider, client) = setup(&transport);
+
+	let result = client.read::<Patient>("123").await;
+	assert!(matches!(result, Err(Error::Auth(_))));
+
+	provider.authorize_url(None).await.unwrap();
+	let redirect: Url = "http://app.local/callback?error=access_denied&state=x".parse().unwrap();
+	assert!(matches!(provider.handle_redirect(&redirect).await, Err(Error::Auth(_))));
+	let result = provider.exchange_code("code-1", "wrong-state").await;
+	assert!(matches!(result, Err(Error::Auth(_))));
+	assert_eq!(provider.context().await, None);
+}
+
+#[tokio::test]
+async fn retry_failed_exchange() {
+	let failed = AtomicBool::new(false);
+	let transport = MockTransport::new(move |request| {
+		if request.url.as_str() == "http://auth.local/token" && !failed.swap(true, Ordering::SeqCst)
+		{
+			return json_response(StatusCode::SERVICE_UNAVAILABLE, &json!({}));
+		}
+		respond(request)
+	});
+	let (provider, _client) = setup(&transport);
+
+	let authorize_url = provider.authorize_url(None).await.unwrap();
+	let params: HashMap<String, String> = authorize_url.query_pairs().into_owned().collect();
+	assert!(provider.exchange_code("code-1", &params["state"]).await.is_err());
+	assert_eq!(provider.context().await, None);
+
+	// The pending authorization is kept, so the exchange can be retried.
+	let context = provider.exchange_code("code-1", &params["state"]).await.unwrap();
+	assert_eq!(context.patient.as_deref(), Some("123"));
+	let result = provider.exchange_code("code-1", &params["state"]).await;
+	assert!(matches!(result, Err(Error::Auth(_))));
+}
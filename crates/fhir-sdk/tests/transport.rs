@@ -0,0 +1,450 @@
+#![cfg(all(feature = "r5", feature = "builders", feature = "client"))]
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+#![recursion_limit = "1024"]
+
+mod mock;
+
+use std::{
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use fhir_sdk::{
+	client::{
+		header::{self, HeaderName},
+		CacheSettings, Client, Error, ExecutableSearch, FhirR5, HeaderValue, HttpRequest,
+		HttpResponse, Method, Middleware, Next, ReadIfModified, ResourceWrite, StatusCode,
+		Transport,
+	},
+	r5::resources::Patient,
+	TryStreamExt,
+};
+use futures::future::try_join_all;
+use mock::{client, client_builder, json_response, MockTransport, BASE_URL};
+use serde_json::json;
+
+#[tokio::test]
+async fn read() {
+	let transport = MockTransport::new(|request| match request.url.path() {
+		"/fhir/Patient/1" => json_response(
+			StatusCode::OK,
+			&json!({ "resourceType": "Patient", "id": "1", "active": true }),
+		),
+		_ => HttpResponse::new(StatusCode::NOT_FOUND),
+	});
+	let client = client(&transport);
+
+	let patient = client.read::<Patient>("1").await.unwrap().expect("should find resource");
+	assert_eq!(patient.id.as_deref(), Some("1"));
+	assert_eq!(patient.active, Some(true));
+	assert!(client.read::<Patient>("2").await.unwrap().is_none());
+
+	let requests = transport.requests();
+	assert_eq!(requests.len(), 2);
+	assert_eq!(requests[0].method, Method::GET);
+	assert_eq!(requests[0].url.as_str(), "http://localhost/fhir/Patient/1");
+	assert_eq!(requests[0].headers[header::ACCEPT], "application/fhir+json; fhirVersion=5.0");
+	assert!(requests[0].headers.contains_key(header::USER_AGENT));
+}
+
+#[tokio::test]
+async fn create() {
+	let transport = MockTransport::new(|_request| {
+		HttpResponse::new(StatusCode::CREATED).header(
+			header::LOCATION,
+			HeaderValue::from_static("http://localhost/fhir/Patient/123/_history/1"),
+		)
+	});
+	let client = client(&transport);
+
+	let mut patient = Patient::builder().active(false).build().unwrap();
+	let id = patient.create(&client).await.unwrap();
+	assert_eq!(id, "123");
+	assert_eq!(patient.id.as_deref(), Some("123"));
+	assert_eq!(patient.meta.as_ref().and_then(|meta| meta.version_id.as_deref()), Some("1"));
+
+	let requests = transport.requests();
+	assert_eq!(requests[0].method, Method::POST);
+	assert_eq!(requests[0].url.as_str(), "http://localhost/fhir/Patient");
+	let body: serde_json::Value =
+		serde_json::from_slice(requests[0].body.as_deref().unwrap()).unwrap();
+	assert_eq!(body, json!({ "resourceType": "Patient", "active": false }));
+}
+
+#[tokio::test]
+async fn error_response() {
+	let transport = MockTransport::new(|_request| {
+		json_response(
+			StatusCode::BAD_REQUEST,
+			&json!({
+				"resourceType": "OperationOutcome",
+				"issue": [{ "severity": "error", "code": "invalid" }]
+			}),
+		)
+	});
+	let client = client(&transport);
+
+	let result = client.read::<Patient>("1").await;
+	assert!(matches!(result, Err(Error::OperationOutcomeR5(StatusCode::BAD_REQUEST, _))));
+}
+
+/// Middleware adding a correlation ID header to all requests.
+#[derive(Debug)]
+struct CorrelationId;
+
+#[async_trait]
+impl Middleware for CorrelationId {
+	async fn handle(&self, request: HttpRequest, next: Next<'_>) -> Result<HttpResponse, Error> {
+		let request = request
+			.header(HeaderName::from_static("x-correlation-id"), HeaderValue::from_static("abc"));
+		next.run(request).await
+	}
+}
+
+/// Middleware counting the requests passing through.
+#[derive(Debug, Clone, Default)]
+struct Counter(Arc<AtomicUsize>);
+
+#[async_trait]
+impl Middleware for Counter {
+	async fn handle(&self, request: HttpRequest, next: Next<'_>) -> Result<HttpResponse, Error> {
+		self.0.fetch_add(1, Ordering::SeqCst);
+		next.run(request).await
+	}
+}
+
+/// Middleware answering requests to `Patient/cached` without sending them.
+#[derive(Debug)]
+struct ShortCircuit;
+
+#[async_trait]
+impl Middleware for ShortCircuit {
+	async fn handle(&self, request: HttpRequest, next: Next<'_>) -> Result<HttpResponse, Error> {
+		if request.url.path().ends_with("/Patient/cached") {
+			return Ok(json_response(
+				StatusCode::OK,
+				&json!({ "resourceType": "Patient", "id": "cached" }),
+			));
+		}
+		next.run(request).await
+	}
+}
+
+/// Middleware retrying server errors once.
+#[derive(Debug)]
+struct RetryOnce;
+
+#[async_trait]
+impl Middleware for RetryOnce {
+	async fn handle(&self, request: HttpRequest, next: Next<'_>) -> Result<HttpResponse, Error> {
+		let response = next.run(request.clone()).await?;
+		if response.status.is_server_error() {
+			return next.run(request).await;
+		}
+		Ok(response)
+	}
+}
+
+/// Mock server answering patient reads, failing every other request with a
+/// server error, and answering searches with two pages.
+fn middleware_server() -> MockTransport {
+	let calls = AtomicUsize::new(0);
+	MockTransport::new(move |request| match request.url.path() {
+		"/fhir/Patient/flaky" if calls.fetch_add(1, Ordering::SeqCst) % 2 == 0 => {
+			HttpResponse::new(StatusCode::SERVICE_UNAVAILABLE)
+		}
+		"/fhir/Patient" => {
+			let next_link = (request.url.query_pairs().all(|(key, _)| key != "page")).then(
+				|| json!({ "relation": "next", "url": "http://localhost/fhir/Patient?page=2" }),
+			);
+			json_response(
+				StatusCode::OK,
+				&json!({
+					"resourceType": "Bundle",
+					"type": "searchset",
+					"link": next_link.into_iter().collect::<Vec<_>>(),
+					"entry": [{
+						"fullUrl": "http://localhost/fhir/Patient/1",
+						"resource": { "resourceType": "Patient", "id": "1" },
+						"search": { "mode": "match" }
+					}]
+				}),
+			)
+		}
+		"/fhir" => json_response(
+			StatusCode::OK,
+			&json!({ "resourceType": "Bundle", "type": "batch-response" }),
+		),
+		path => json_response(
+			StatusCode::OK,
+			&json!({ "resourceType": "Patient", "id": path.rsplit('/').next() }),
+		),
+	})
+}
+
+/// Set up a client with the middleware layers.
+fn middleware_client(transport: &MockTransport, counter: &Counter) -> Client<FhirR5> {
+	client_builder(transport.clone())
+		.middleware(counter.clone())
+		.middleware(CorrelationId)
+		.middleware(ShortCircuit)
+		.middleware(RetryOnce)
+		.build()
+		.unwrap()
+}
+
+#[tokio::test]
+async fn modify_request() {
+	let transport = middleware_server();
+	let counter = Counter::default();
+	let client = middleware_client(&transport, &counter);
+
+	client.read::<Patient>("1").await.unwrap().expect("should find resource");
+
+	let requests = transport.requests();
+	assert_eq!(requests.len(), 1);
+	assert_eq!(requests[0].headers["x-correlation-id"], "abc");
+}
+
+#[tokio::test]
+async fn short_circuit() {
+	let transport = middleware_server();
+	let counter = Counter::default();
+	let client = middleware_client(&transport, &counter);
+
+	let patient = client.read::<Patient>("cached").await.unwrap().expect("should find resource");
+	assert_eq!(patient.id.as_deref(), Some("cached"));
+	assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+	assert!(transport.requests().is_empty());
+}
+
+#[tokio::test]
+async fn retry() {
+	let transport = middleware_server();
+	let counter = Counter::default();
+	let client = middleware_client(&transport, &counter);
+
+	let patient = client.read::<Patient>("flaky").await.unwrap().expect("should find resource");
+	assert_eq!(patient.id.as_deref(), Some("flaky"));
+	assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+	assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn all_requests() {
+	let transport = middleware_server();
+	let counter = Counter::default();
+	let client = middleware_client(&transport, &counter);
+
+	let patients: Vec<Patient> = client.search().send().await.unwrap().try_collect().await.unwrap();
+	assert_eq!(patients.len(), 2);
+	assert_eq!(counter.0.load(Ordering::SeqCst), 2);
+
+	client.transaction().send().await.unwrap();
+	assert_eq!(counter.0.load(Ordering::SeqCst), 3);
+	assert!(transport
+		.requests()
+		.iter()
+		.all(|request| request.headers.contains_key("x-correlation-id")));
+}
+
+/// Transport answering after a delay, tracking the maximum number of requests
+/// in flight at once.
+#[derive(Debug, Clone, Default)]
+struct SlowServer {
+	/// Requests currently in flight.
+	in_flight: Arc<AtomicUsize>,
+	/// Maximum number of requests in flight at once.
+	max_in_flight: Arc<AtomicUsize>,
+	/// Number of requests received.
+	requests: Arc<AtomicUsize>,
+}
+
+#[async_trait]
+impl Transport for SlowServer {
+	async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+		self.requests.fetch_add(1, Ordering::SeqCst);
+		let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+		self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+		tokio::time::sleep(Duration::from_millis(20)).await;
+		self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+		if request.url.path() != "/fhir/Patient" {
+			return Ok(json_response(
+				StatusCode::OK,
+				&json!({ "resourceType": "Patient", "id": "1" }),
+			));
+		}
+		let page: usize = request
+			.url
+			.query_pairs()
+			.find(|(key, _)| key == "page")
+			.map_or(1, |(_, page)| page.parse().unwrap());
+		let next_link = (page < 5).then(|| {
+			json!({
+				"relation": "next",
+				"url": format!("http://localhost/fhir/Patient?page={}", page + 1),
+			})
+		});
+		Ok(json_response(
+			StatusCode::OK,
+			&json!({
+				"resourceType": "Bundle",
+				"type": "searchset",
+				"link": next_link.into_iter().collect::<Vec<_>>(),
+				"entry": [{ "resource": { "resourceType": "Patient", "id": page.to_string() } }],
+			}),
+		))
+	}
+}
+
+#[tokio::test]
+async fn max_in_flight_requests() {
+	let server = SlowServer::default();
+	let client = client_builder(server.clone()).max_in_flight_requests(2).build().unwrap();
+
+	// Clones share the limit.
+	try_join_all((0..10).map(|_| {
+		let client = client.clone();
+		async move { client.read::<Patient>("1").await }
+	}))
+	.await
+	.unwrap();
+
+	assert_eq!(server.requests.load(Ordering::SeqCst), 10);
+	assert_eq!(server.max_in_flight.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn rate_limit() {
+	let server = SlowServer::default();
+	let client = client_builder(server.clone()).rate_limit(20.0, 1).build().unwrap();
+
+	// Page fetches are limited as well, so the 4 pages after the first take at
+	// least 200 ms.
+	let start = Instant::now();
+	let patients: Vec<Patient> = client.search().send().await.unwrap().try_collect().await.unwrap();
+	assert_eq!(patients.len(), 5);
+	assert!(start.elapsed() >= Duration::from_millis(200));
+	assert_eq!(server.requests.load(Ordering::SeqCst), 5);
+}
+
+#[test]
+fn invalid_limits() {
+	let builder = Client::<FhirR5>::builder().base_url(BASE_URL.parse().unwrap());
+	assert!(matches!(
+		builder.clone().rate_limit(0.0, 1).build(),
+		Err(Error::BuilderInvalidField("rate_limit"))
+	));
+	assert!(matches!(
+		builder.clone().rate_limit(1.0, 0).build(),
+		Err(Error::BuilderInvalidField("rate_limit"))
+	));
+	assert!(matches!(
+		builder.max_in_flight_requests(0).build(),
+		Err(Error::BuilderInvalidField("max_in_flight_requests"))
+	));
+}
+
+/// Mock server answering patient reads with the current version, supporting
+/// `If-None-Match`.
+fn versioned_server(version: Arc<AtomicUsize>) -> MockTransport {
+	MockTransport::new(move |request| {
+		let id = request.url.path().rsplit('/').next().unwrap();
+		if id == "missing" {
+			return HttpResponse::new(StatusCode::NOT_FOUND);
+		}
+
+		let version = version.load(Ordering::SeqCst);
+		let etag = HeaderValue::from_str(&format!("W/\"{version}\"")).unwrap();
+		if request.headers.get(header::IF_NONE_MATCH) == Some(&etag) {
+			return HttpResponse::new(StatusCode::NOT_MODIFIED).header(header::ETAG, etag);
+		}
+		json_response(
+			StatusCode::OK,
+			&json!({
+				"resourceType": "Patient",
+				"id": id,
+				"meta": { "versionId": version.to_string() },
+			}),
+		)
+		.header(header::ETAG, etag)
+	})
+}
+
+/// Set up a client with the cache settings.
+fn cache_client(transport: &MockTransport, settings: CacheSettings) -> Client<FhirR5> {
+	client_builder(transport.clone()).cache(settings).build().unwrap()
+}
+
+/// Read the patient, returning its version ID.
+async fn read_version(client: &Client<FhirR5>, id: &str) -> String {
+	let patient = client.read::<Patient>(id).await.unwrap().expect("should find resource");
+	patient.meta.unwrap().version_id.unwrap()
+}
+
+#[tokio::test]
+async fn revalidates_cached_responses() {
+	let version = Arc::new(AtomicUsize::new(1));
+	let transport = versioned_server(version.clone());
+	let client = cache_client(&transport, CacheSettings::default());
+
+	assert_eq!(read_version(&client, "1").await, "1");
+	assert_eq!(read_version(&client, "1").await, "1");
+	version.store(2, Ordering::SeqCst);
+	assert_eq!(read_version(&client, "1").await, "2");
+
+	let requests = transport.requests();
+	assert_eq!(requests.len(), 3);
+	assert!(!requests[0].headers.contains_key(header::IF_NONE_MATCH));
+	assert_eq!(requests[1].headers[header::IF_NONE_MATCH], "W/\"1\"");
+	assert_eq!(requests[2].headers[header::IF_NONE_MATCH], "W/\"1\"");
+}
+
+#[tokio::test]
+async fn cache_limits() {
+	let version = Arc::new(AtomicUsize::new(1));
+	let transport = versioned_server(version);
+
+	let client = cache_client(&transport, CacheSettings::default().max_entries(1));
+	read_version(&client, "1").await;
+	read_version(&client, "2").await;
+	read_version(&client, "1").await;
+	read_version(&client, "1").await;
+	let requests = transport.requests();
+	assert!(!requests[2].headers.contains_key(header::IF_NONE_MATCH));
+	assert!(requests[3].headers.contains_key(header::IF_NONE_MATCH));
+
+	let transport = versioned_server(Arc::new(AtomicUsize::new(1)));
+	let client =
+		cache_client(&transport, CacheSettings::default().ttl(Some(Duration::from_millis(1))));
+	read_version(&client, "1").await;
+	tokio::time::sleep(Duration::from_millis(10)).await;
+	read_version(&client, "1").await;
+	assert!(!transport.requests()[1].headers.contains_key(header::IF_NONE_MATCH));
+}
+
+#[tokio::test]
+async fn read_if_modified() {
+	let version = Arc::new(AtomicUsize::new(1));
+	let transport = versioned_server(version.clone());
+	let client = client(&transport);
+
+	let result = client.read_if_modified::<Patient>("1", "1").await.unwrap();
+	assert_eq!(result, ReadIfModified::NotModified);
+
+	version.store(2, Ordering::SeqCst);
+	let ReadIfModified::Modified(patient) =
+		client.read_if_modified::<Patient>("1", "1").await.unwrap()
+	else {
+		panic!("resource should be modified");
+	};
+	assert_eq!(patient.meta.unwrap().version_id.as_deref(), Some("2"));
+
+	let result = client.read_if_modified::<Patient>("missing", "1").await.unwrap();
+	assert_eq!(result, ReadIfModified::NotFound);
+}
@@ -4,6 +4,7 @@ use quote::{format_ident, quote};
 
 use crate::model::params::SearchParam;
 use crate::model::structures::Type;
+use crate::model::SearchParamType;
 
 pub fn generate_search_param_enums(
 	resource_params: &[(Type, Vec<&SearchParam>)],
@@ -79,3 +80,105 @@ pub fn generate_search_param_enums(
 		})
 		.collect()
 }
+
+/// Generate a type for every reference search parameter of the resources,
+/// implementing `ReferenceSearchParameter` with the resources it can refer to.
+/// Parameters that can refer to any resource target the `Resource` enum.
+pub fn generate_reference_search_parameters(
+	resource_params: &[(Type, Vec<&SearchParam>)],
+	resource_names: &[String],
+) -> Vec<TokenStream> {
+	resource_params
+		.iter()
+		.flat_map(|(res, params)| {
+			params
+				.iter()
+				.filter(|sp| sp.r#type == SearchParamType::Reference)
+				.map(move |sp| generate_reference_search_parameter(res, sp, resource_names))
+		})
+		.collect()
+}
+
+/// Generate the type of a reference search parameter and, if it can refer to
+/// several resources, the enum of its targets.
+fn generate_reference_search_parameter(
+	res: &Type,
+	sp: &SearchParam,
+	resource_names: &[String],
+) -> TokenStream {
+	let res_name = format_ident!("{}", res.name.to_pascal_case());
+	let params_ident = format_ident!("{res_name}SearchParameter");
+	let variant = format_ident!("{}", sp.code.to_pascal_case());
+	let param_ident = format_ident!("{res_name}{variant}SearchReference");
+
+	let targets_any = sp.target.is_empty()
+		|| resource_names.iter().all(|name| sp.target.contains(name))
+		|| sp.target.iter().any(|target| !resource_names.contains(target));
+
+	let (target_type, target_defs) = if targets_any {
+		(quote!(resources::Resource), None)
+	} else if let [target] = sp.target.as_slice() {
+		let target = format_ident!("{}", target.to_pascal_case());
+		(quote!(resources::#target), None)
+	} else {
+		let target_ident = format_ident!("{res_name}{variant}SearchTarget");
+		let enum_doc = format!(
+			" Target resources of the {} reference search parameter of the {} resource",
+			sp.code, res.name
+		);
+
+		let variants = sp.target.iter().map(|ty| {
+			let variant_type = format_ident!("{}", ty.to_pascal_case());
+			let variant_doc = format!(" Variant for {ty} target resources");
+
+			quote! {
+				#[doc = #variant_doc]
+				#variant_type(resources::#variant_type),
+			}
+		});
+
+		let from_impls = sp.target.iter().map(|ty| {
+			let rt = format_ident!("{}", ty.to_pascal_case());
+
+			quote! {
+				impl From<resources::#rt> for #target_ident {
+					fn from(resource: resources::#rt) -> #target_ident {
+						#target_ident::#rt(resource)
+					}
+				}
+			}
+		});
+
+		let target_defs = quote! {
+			#[doc = #enum_doc]
+			#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+			pub enum #target_ident {
+				#(#variants)*
+			}
+
+			#(#from_impls)*
+		};
+
+		(quote!(#target_ident), Some(target_defs))
+	};
+
+	let doc_comment =
+		format!(" The {} reference search parameter of the {} resource", sp.code, res.name);
+
+	quote! {
+		#[doc = #doc_comment]
+		#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+		pub struct #param_ident;
+
+		impl ReferenceSearchParameter for #param_ident {
+			type Resource = resources::#res_name;
+			type Target = #target_type;
+
+			fn parameter(&self) -> #params_ident {
+				#params_ident::#variant
+			}
+		}
+
+		#target_defs
+	}
+}
@@ -107,6 +107,45 @@ pub fn generate_type_struct(
 	})
 }
 
+/// Generate the enum of a resource's top-level elements, which can be
+/// selected via `_elements`.
+pub fn generate_element_enum(ty: &Type) -> TokenStream {
+	let name = &ty.name;
+	let ident = format_ident!("{name}");
+	let ident_elements = format_ident!("{name}Element");
+
+	let names: Vec<_> =
+		ty.elements.fields.iter().map(|field| field.name().replace("[x]", "")).collect();
+	let variants: Vec<_> =
+		names.iter().map(|name| format_ident!("{}", name.to_pascal_case())).collect();
+	let variant_docs: Vec<_> = names.iter().map(|name| format!(" The `{name}` element")).collect();
+
+	let doc_comment = format!(" Top-level elements of the {name} resource");
+
+	quote! {
+		#[doc = #doc_comment]
+		#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+		pub enum #ident_elements {
+			#(
+				#[doc = #variant_docs]
+				#variants,
+			)*
+		}
+
+		impl crate::ResourceElementDefinition for #ident_elements {
+			fn name(&self) -> &'static str {
+				match self {
+					#(Self::#variants => #names,)*
+				}
+			}
+		}
+
+		impl crate::SelectableResource for #ident {
+			type Elements = #ident_elements;
+		}
+	}
+}
+
 /// Implement the LookupReferences trait for a type
 fn lookup_references_impl(ident: &Ident, field: &ObjectField, is_type: bool) -> TokenStream {
 	let refs_pushes: Vec<_> = field
@@ -70,6 +70,9 @@ pub fn generate_search_params(
 	let search_params: Vec<SearchParam> =
 		search_params.into_iter().filter(|sp| sp.r#type != SearchParamType::Special).collect();
 
+	let resource_names: Vec<_> =
+		resources.iter().filter(|r| !r.r#abstract).map(|r| r.name.clone()).collect();
+
 	let resource_params: Vec<_> = resources
 		.into_iter()
 		.filter(|r| !r.r#abstract)
@@ -89,6 +92,9 @@ pub fn generate_search_params(
 
 	let enums = gen_params::generate_search_param_enums(&resource_params);
 
+	let reference_params =
+		gen_params::generate_reference_search_parameters(&resource_params, &resource_names);
+
 	let searchable_resource_impls =
 		gen_traits::generate_searchable_resource_impls(&resource_params);
 
@@ -101,6 +107,8 @@ pub fn generate_search_params(
 
 		#(#enums)*
 
+		#(#reference_params)*
+
 		#(#searchable_resource_impls)*
 	})
 }
@@ -185,6 +193,13 @@ pub fn generate_resources(
 		.map(|ty| gen_types::generate_type_struct(ty, implemented_codes))
 		.collect::<Result<_, _>>()?;
 
+	let element_enums: Vec<TokenStream> = resources
+		.iter()
+		.filter(|ty| !ty.r#abstract)
+		.filter(|ty| ty.kind == StructureDefinitionKind::Resource)
+		.map(gen_types::generate_element_enum)
+		.collect();
+
 	let resource_conversions = resource_conversion_impls(&resource_names);
 	let resource_type_impls = resource_type_impls(&resource_names);
 
@@ -212,6 +227,8 @@ pub fn generate_resources(
 
 		#(#resource_defs)*
 
+		#(#element_enums)*
+
 		/// Generic resource holding any FHIR resources.
 		#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 		#[serde(tag = "resourceType")]
@@ -17,6 +17,9 @@ pub struct SearchParam {
 	/// The type of value that a search parameter may contain, and how the content is interpreted
 	pub r#type: SearchParamType,
 
+	/// Types of resource (if a resource reference) the search parameter can refer to
+	pub target: Vec<String>,
+
 	/// FHIRPath expression resolving to all the fields this search parameter points at
 	#[allow(unused)]
 	pub expression: String,
@@ -11,10 +11,11 @@ impl From<r5::resources::SearchParameter> for SearchParam {
 		let code = param.code;
 		let base = param.base.iter().flatten().map(ToString::to_string).collect();
 		let r#type = param.r#type.into();
+		let target = param.target.iter().flatten().map(ToString::to_string).collect();
 		let expression = param.expression.expect("SearchParameter.expression");
 		let comparators = param.comparator.into_iter().flatten().map(Into::into).collect();
 
-		Self { name, description, code, base, r#type, expression, comparators }
+		Self { name, description, code, base, r#type, target, expression, comparators }
 	}
 }
 
@@ -27,10 +28,11 @@ impl From<r4b::resources::SearchParameter> for SearchParam {
 		let code = param.code;
 		let base = param.base.iter().flatten().map(ToString::to_string).collect();
 		let r#type = param.r#type.into();
+		let target = param.target.iter().flatten().map(ToString::to_string).collect();
 		let expression = param.expression.expect("SearchParameter.expression");
 		let comparators = param.comparator.into_iter().flatten().map(Into::into).collect();
 
-		Self { name, description, code, base, r#type, expression, comparators }
+		Self { name, description, code, base, r#type, target, expression, comparators }
 	}
 }
 
@@ -43,10 +45,11 @@ impl From<stu3::resources::SearchParameter> for SearchParam {
 		let code = param.code;
 		let base = param.base.iter().flatten().map(ToString::to_string).collect();
 		let r#type = param.r#type.into();
+		let target = param.target.iter().flatten().map(ToString::to_string).collect();
 		let expression = param.expression.expect("SearchParameter.expression");
 		let comparators = param.comparator.into_iter().flatten().map(Into::into).collect();
 
-		Self { name, description, code, base, r#type, expression, comparators }
+		Self { name, description, code, base, r#type, target, expression, comparators }
 	}
 }
 
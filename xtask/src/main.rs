@@ -116,6 +116,27 @@ impl Cli {
 			.current_dir(&workspace_path);
 		run_command(command)?;
 
+		// Run the SMART authorization tests.
+		let mut command = Command::new("cargo");
+		command
+			.args(["test", "-p", "fhir-sdk", "--features", "smart"])
+			.current_dir(&workspace_path);
+		run_command(command)?;
+
+		// Run the conversion tests between FHIR versions.
+		let mut command = Command::new("cargo");
+		command
+			.args([
+				"test",
+				"-p",
+				"fhir-model",
+				"--no-default-features",
+				"--features",
+				"stu3,r4b,r5,builders",
+			])
+			.current_dir(&workspace_path);
+		run_command(command)?;
+
 		// Make sure the models compile without builders in all FHIR versions.
 		let mut command = Command::new("cargo");
 		command.args(["clippy", "-p", "fhir-model", "--no-default-features", "--features", "r4b"]);